@@ -93,30 +93,30 @@ use rand::{
 pub mod serde_arrow_arrow {
     use serde::Serialize;
     use serde_arrow::{
-        Result,
         _impl::arrow::{array::ArrayRef, datatypes::FieldRef},
+        Result,
     };
 
     pub fn serialize<T>(fields: &[FieldRef], items: &T) -> Result<Vec<ArrayRef>>
     where
         T: Serialize + ?Sized,
     {
-        serde_arrow::to_arrow(&fields, &items)
+        serde_arrow::to_arrow(fields, items)
     }
 }
 
 pub mod serde_arrow_arrow2 {
     use serde::Serialize;
     use serde_arrow::{
-        Result,
         _impl::arrow2::{array::Array, datatypes::Field},
+        Result,
     };
 
     pub fn serialize<T>(fields: &[Field], items: &T) -> Result<Vec<Box<dyn Array>>>
     where
         T: Serialize + ?Sized,
     {
-        serde_arrow::to_arrow2(&fields, &items)
+        serde_arrow::to_arrow2(fields, items)
     }
 }
 
@@ -132,8 +132,8 @@ pub mod arrow {
     use serde::Serialize;
 
     use serde_arrow::{
-        Error, Result,
         _impl::arrow::{array::ArrayRef, datatypes::FieldRef},
+        Error, Result,
     };
 
     pub fn serialize<T>(fields: &[FieldRef], items: &[T]) -> Result<Vec<ArrayRef>>
@@ -153,7 +153,7 @@ pub mod arrow {
 
 pub mod arrow2_convert {
     use arrow2_convert::serialize::TryIntoArrow;
-    use serde_arrow::{Error, Result, _impl::arrow2::array::Array};
+    use serde_arrow::{_impl::arrow2::array::Array, Error, Result};
 
     pub fn serialize<'a, T, E, F>(_fields: &[F], items: T) -> Result<Box<dyn Array>>
     where
@@ -13,7 +13,7 @@ fn main() {
         println!("cargo:rustc-cfg=has_arrow2_0_{version}");
     }
 
-    let max_arrow_version: Option<usize> = [
+    let arrow_versions: Vec<usize> = vec![
         // arrow-version:insert: #[cfg(feature = "arrow-{version}")]{\n}{version},
         #[cfg(feature = "arrow-53")]
         53,
@@ -49,9 +49,18 @@ fn main() {
         38,
         #[cfg(feature = "arrow-37")]
         37,
-    ]
-    .into_iter()
-    .max();
+    ];
+    let max_arrow_version = arrow_versions.iter().copied().max();
+
+    // the `ipc` and `http` features require the most recent arrow version,
+    // combining them with older versions would silently replace those
+    if cfg!(any(feature = "ipc", feature = "http"))
+        && arrow_versions
+            .iter()
+            .any(|&version| Some(version) != max_arrow_version)
+    {
+        println!("cargo:rustc-cfg=has_arrow_ipc_version_conflict");
+    }
 
     if let Some(version) = max_arrow_version {
         println!("cargo:rustc-cfg=has_arrow");
@@ -60,5 +69,11 @@ fn main() {
         if version >= 47 {
             println!("cargo:rustc-cfg=has_arrow_fixed_binary_support");
         }
+        if version >= 51 {
+            println!("cargo:rustc-cfg=has_arrow_view_support");
+        }
+        if version >= 52 {
+            println!("cargo:rustc-cfg=has_arrow_interval_struct_support");
+        }
     }
 }
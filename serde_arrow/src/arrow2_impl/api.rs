@@ -14,9 +14,13 @@ use crate::{
         error::{fail, Result},
         schema::SerdeArrowSchema,
         serializer::Serializer,
+        session::Session,
+        utils::array_view_ext::in_field,
     },
 };
 
+use super::schema::field_from_arrow2_unchecked;
+
 /// Build arrow2 arrays from the given items  (*requires one of the `arrow2-*`
 /// features*)
 ///
@@ -104,6 +108,22 @@ impl crate::internal::array_builder::ArrayBuilder {
         Self::new(SerdeArrowSchema::try_from(fields)?)
     }
 
+    /// Build an ArrayBuilder from `arrow2` fields without validating them
+    /// (*requires one of the `arrow2-*` features*)
+    ///
+    /// In contrast to [`ArrayBuilder::from_arrow2`], the fields are not
+    /// checked for supported strategies or consistent metadata. It is intended
+    /// for fields that are known to be valid, e.g., because they were
+    /// previously used with [`ArrayBuilder::from_arrow2`]. Invalid fields may
+    /// result in errors during serialization or array construction.
+    pub fn from_arrow2_unchecked(fields: &[ArrowField]) -> Result<Self> {
+        let fields = fields
+            .iter()
+            .map(|field| field_from_arrow2_unchecked(field).map_err(|err| err.in_parent("$")))
+            .collect::<Result<_>>()?;
+        Self::new(SerdeArrowSchema { fields })
+    }
+
     /// Construct `arrow2` arrays and reset the builder (*requires one of the
     /// `arrow2-*` features*)
     pub fn to_arrow2(&mut self) -> Result<Vec<Box<dyn Array>>> {
@@ -112,6 +132,25 @@ impl crate::internal::array_builder::ArrayBuilder {
             .map(Box::<dyn Array>::try_from)
             .collect()
     }
+
+    /// Construct `arrow2` arrays for each chunk of items (*requires one of the
+    /// `arrow2-*` features*)
+    ///
+    /// The builder is reused for all chunks, the schema is only compiled once.
+    /// Each chunk should be a list of records, items pushed before calling
+    /// this function are included in the first chunk.
+    pub fn to_arrow2_many<I>(&mut self, chunks: I) -> Result<Vec<Vec<Box<dyn Array>>>>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let mut result = Vec::new();
+        for chunk in chunks {
+            self.extend(chunk)?;
+            result.push(self.to_arrow2()?);
+        }
+        Ok(result)
+    }
 }
 
 impl<'de> Deserializer<'de> {
@@ -157,13 +196,55 @@ impl<'de> Deserializer<'de> {
 
         let fields = fields
             .iter()
-            .map(Field::try_from)
+            .map(|field| Field::try_from(field).map_err(|err| err.in_parent("$")))
             .collect::<Result<Vec<_>>>()?;
-        let views = arrays
-            .iter()
-            .map(|array| ArrayView::try_from(array.as_ref()))
+        let views = std::iter::zip(&fields, arrays)
+            .map(|(field, array)| in_field(ArrayView::try_from(array.as_ref()), &field.name))
             .collect::<Result<Vec<_>>>()?;
 
         Deserializer::new(&fields, views)
     }
 }
+
+/// Support `arrow2` (*requires one of the `arrow2-*` features*)
+impl Session {
+    /// Construct a session from `arrow2` fields (*requires one of the
+    /// `arrow2-*` features*)
+    pub fn from_arrow2_fields(fields: &[ArrowField]) -> Result<Self> {
+        Self::new(SerdeArrowSchema::try_from(fields)?)
+    }
+
+    /// Build `arrow2` arrays from the given items (*requires one of the
+    /// `arrow2-*` features*)
+    ///
+    /// See [`to_arrow2`] for details.
+    pub fn to_arrow2<T: Serialize>(&mut self, items: T) -> Result<Vec<Box<dyn Array>>> {
+        self.build_arrays(items)?
+            .into_iter()
+            .map(Box::<dyn Array>::try_from)
+            .collect()
+    }
+
+    /// Deserialize items from `arrow2` arrays (*requires one of the `arrow2-*`
+    /// features*)
+    ///
+    /// The arrays are interpreted with the fields of the session. See
+    /// [`from_arrow2`] for details.
+    pub fn from_arrow2<'de, T, A>(&self, arrays: &'de [A]) -> Result<T>
+    where
+        T: Deserialize<'de>,
+        A: AsRef<dyn Array>,
+    {
+        use crate::internal::arrow::ArrayView;
+
+        let mut views = Vec::new();
+        for (idx, array) in arrays.iter().enumerate() {
+            let view = ArrayView::try_from(array.as_ref());
+            views.push(match self.schema().fields.get(idx) {
+                Some(field) => in_field(view, &field.name)?,
+                None => view?,
+            });
+        }
+        T::deserialize(self.deserializer(views)?)
+    }
+}
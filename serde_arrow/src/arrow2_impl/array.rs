@@ -9,19 +9,19 @@ use crate::{
         },
         bitmap::Bitmap,
         buffer::Buffer,
-        datatypes::{DataType, Field, IntegerType, UnionMode},
-        types::{f16, NativeType, Offset},
+        datatypes::{DataType, Field, IntegerType, IntervalUnit, UnionMode},
+        types::{days_ms, f16, i256, months_days_ns, NativeType, Offset},
     },
     internal::{
         arrow::{
-            Array, ArrayView, BitsWithOffset, BooleanArrayView, BytesArrayView, DecimalArrayView,
-            DenseUnionArrayView, DictionaryArrayView, FieldMeta, FixedSizeBinaryArrayView,
-            FixedSizeListArrayView, ListArrayView, NullArrayView,
-            PrimitiveArray as InternalPrimitiveArray, PrimitiveArrayView, StructArrayView,
-            TimeArrayView, TimestampArrayView,
+            Array, ArrayView, BitsWithOffset, BooleanArrayView, BytesArrayView, DayTimeInterval,
+            DecimalArrayView, DenseUnionArrayView, DictionaryArrayView, FieldMeta,
+            FixedSizeBinaryArrayView, FixedSizeListArrayView, ListArrayView, MonthDayNanoInterval,
+            NullArrayView, PrimitiveArray as InternalPrimitiveArray, PrimitiveArrayView,
+            StructArrayView, TimeArrayView, TimestampArrayView, I256,
         },
         error::{fail, Error, Result},
-        utils::meta_from_field,
+        utils::{array_view_ext::unsupported_data_type, meta_from_field, ChildName},
     },
 };
 
@@ -73,15 +73,45 @@ impl TryFrom<Array> for ArrayRef {
                 arr.values,
                 arr.validity,
             ),
+            A::IntervalYearMonth(arr) => build_primitive_array(
+                T::Interval(IntervalUnit::YearMonth),
+                arr.values,
+                arr.validity,
+            ),
+            A::IntervalDayTime(arr) => build_primitive_array(
+                T::Interval(IntervalUnit::DayTime),
+                arr.values
+                    .into_iter()
+                    .map(|v| days_ms(v.days, v.milliseconds))
+                    .collect(),
+                arr.validity,
+            ),
+            A::IntervalMonthDayNano(arr) => build_primitive_array(
+                T::Interval(IntervalUnit::MonthDayNano),
+                arr.values
+                    .into_iter()
+                    .map(|v| months_days_ns(v.months, v.days, v.nanoseconds))
+                    .collect(),
+                arr.validity,
+            ),
             A::Decimal128(arr) => build_primitive_array(
                 T::Decimal(arr.precision as usize, usize::try_from(arr.scale)?),
                 arr.values,
                 arr.validity,
             ),
+            A::Decimal256(arr) => build_primitive_array(
+                T::Decimal256(arr.precision as usize, usize::try_from(arr.scale)?),
+                arr.values
+                    .into_iter()
+                    .map(|v| i256::from_words(v.high, v.low as i128))
+                    .collect(),
+                arr.validity,
+            ),
             A::Utf8(arr) => build_utf8_array(T::Utf8, arr.offsets, arr.data, arr.validity),
             A::LargeUtf8(arr) => {
                 build_utf8_array(T::LargeUtf8, arr.offsets, arr.data, arr.validity)
             }
+            A::Utf8View(_) => fail!("Utf8View arrays are not supported by arrow2"),
             A::Binary(arr) => build_binary_array(T::Binary, arr.offsets, arr.data, arr.validity),
             A::LargeBinary(arr) => {
                 build_binary_array(T::LargeBinary, arr.offsets, arr.data, arr.validity)
@@ -163,6 +193,27 @@ impl TryFrom<Array> for ArrayRef {
                     Some(arr.offsets.into()),
                 )?))
             }
+            A::SparseUnion(arr) => {
+                let mut values = Vec::new();
+                let mut fields = Vec::new();
+                let mut type_ids = Vec::new();
+
+                for (type_id, child, meta) in arr.fields {
+                    let child: ArrayRef = child.try_into()?;
+                    let field = field_from_array_and_meta(child.as_ref(), meta);
+
+                    type_ids.push(type_id.into());
+                    values.push(child);
+                    fields.push(field);
+                }
+
+                Ok(Box::new(UnionArray::try_new(
+                    T::Union(fields, Some(type_ids), UnionMode::Sparse),
+                    arr.types.into(),
+                    values,
+                    None,
+                )?))
+            }
             A::FixedSizeList(arr) => {
                 let child: ArrayRef = (*arr.element).try_into()?;
                 let child_field = field_from_array_and_meta(child.as_ref(), arr.meta);
@@ -220,6 +271,9 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
                     validity: bits_with_offset_from_bitmap(array.validity()),
                     values: array.values().as_slice(),
                 })),
+                T::Interval(IntervalUnit::YearMonth) => {
+                    Ok(V::IntervalYearMonth(view_primitive_array(array)))
+                }
                 dt => fail!("unsupported data type {dt:?} for i32 arrow2 array"),
             }
         } else if let Some(array) = any.downcast_ref::<PrimitiveArray<i64>>() {
@@ -254,6 +308,16 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
                 })),
                 dt => fail!("unsupported data type {dt:?} for i128 arrow2 array"),
             }
+        } else if let Some(array) = any.downcast_ref::<PrimitiveArray<i256>>() {
+            match array.data_type() {
+                T::Decimal256(precision, scale) => Ok(V::Decimal256(DecimalArrayView {
+                    precision: (*precision).try_into()?,
+                    scale: (*scale).try_into()?,
+                    validity: bits_with_offset_from_bitmap(array.validity()),
+                    values: bytemuck::cast_slice::<i256, I256>(array.values().as_slice()),
+                })),
+                dt => fail!("unsupported data type {dt:?} for i256 arrow2 array"),
+            }
         } else if let Some(array) = any.downcast_ref::<PrimitiveArray<u8>>() {
             Ok(V::UInt8(view_primitive_array(array)))
         } else if let Some(array) = any.downcast_ref::<PrimitiveArray<u16>>() {
@@ -267,6 +331,18 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
                 values: bytemuck::cast_slice::<f16, half::f16>(array.values().as_slice()),
                 validity: bits_with_offset_from_bitmap(array.validity()),
             }))
+        } else if let Some(array) = any.downcast_ref::<PrimitiveArray<days_ms>>() {
+            Ok(V::IntervalDayTime(PrimitiveArrayView {
+                values: bytemuck::cast_slice::<days_ms, DayTimeInterval>(array.values().as_slice()),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+            }))
+        } else if let Some(array) = any.downcast_ref::<PrimitiveArray<months_days_ns>>() {
+            Ok(V::IntervalMonthDayNano(PrimitiveArrayView {
+                values: bytemuck::cast_slice::<months_days_ns, MonthDayNanoInterval>(
+                    array.values().as_slice(),
+                ),
+                validity: bits_with_offset_from_bitmap(array.validity()),
+            }))
         } else if let Some(array) = any.downcast_ref::<PrimitiveArray<f32>>() {
             Ok(V::Float32(view_primitive_array(array)))
         } else if let Some(array) = any.downcast_ref::<PrimitiveArray<f64>>() {
@@ -322,7 +398,10 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
                 meta: meta_from_field(field.as_ref().try_into()?),
                 validity: bits_with_offset_from_bitmap(array.validity()),
                 offsets: array.offsets().as_slice(),
-                element: Box::new(array.values().as_ref().try_into()?),
+                element: Box::new(
+                    ArrayView::try_from(array.values().as_ref())
+                        .map_err(|err| err.in_parent(ChildName(&field.name)))?,
+                ),
             }))
         } else if let Some(array) = any.downcast_ref::<ListArray<i64>>() {
             let T::LargeList(field) = array.data_type() else {
@@ -335,7 +414,10 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
                 meta: meta_from_field(field.as_ref().try_into()?),
                 validity: bits_with_offset_from_bitmap(array.validity()),
                 offsets: array.offsets().as_slice(),
-                element: Box::new(array.values().as_ref().try_into()?),
+                element: Box::new(
+                    ArrayView::try_from(array.values().as_ref())
+                        .map_err(|err| err.in_parent(ChildName(&field.name)))?,
+                ),
             }))
         } else if let Some(array) = any.downcast_ref::<StructArray>() {
             let T::Struct(child_fields) = array.data_type() else {
@@ -346,10 +428,10 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
             };
             let mut fields = Vec::new();
             for (child_field, child) in child_fields.iter().zip(array.values()) {
-                fields.push((
-                    child.as_ref().try_into()?,
-                    meta_from_field(child_field.try_into()?),
-                ));
+                let meta = meta_from_field(child_field.try_into()?);
+                let view = ArrayView::try_from(child.as_ref())
+                    .map_err(|err| err.in_parent(ChildName(&child_field.name)))?;
+                fields.push((view, meta));
             }
             Ok(V::Struct(StructArrayView {
                 len: array.len(),
@@ -364,7 +446,8 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
                 );
             };
             let meta = meta_from_field(field.as_ref().try_into()?);
-            let element: ArrayView<'_> = array.field().as_ref().try_into()?;
+            let element = ArrayView::try_from(array.field().as_ref())
+                .map_err(|err| err.in_parent(ChildName(&field.name)))?;
 
             Ok(V::Map(ListArrayView {
                 element: Box::new(element),
@@ -374,7 +457,10 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
             }))
         } else if let Some(array) = any.downcast_ref::<UnionArray>() {
             let T::Union(union_fields, type_ids, UnionMode::Dense) = array.data_type() else {
-                fail!("Invalid data type: only dense unions are supported");
+                return Err(unsupported_data_type(
+                    String::from("Union(Sparse)"),
+                    "only dense unions are supported",
+                ));
             };
 
             let type_ids = if let Some(type_ids) = type_ids.as_ref() {
@@ -396,9 +482,11 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
             for ((type_id, child), child_field) in
                 type_ids.iter().zip(array.fields().iter()).zip(union_fields)
             {
+                let view = ArrayView::try_from(child.as_ref())
+                    .map_err(|err| err.in_parent(ChildName(&child_field.name)))?;
                 fields.push((
                     (*type_id).try_into()?,
-                    child.as_ref().try_into()?,
+                    view,
                     meta_from_field(child_field.try_into()?),
                 ));
             }
@@ -413,7 +501,8 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
                 fail!("Invalid type: expected FixedSizeList");
             };
 
-            let child_view: ArrayView<'_> = array.values().as_ref().try_into()?;
+            let child_view = ArrayView::try_from(array.values().as_ref())
+                .map_err(|err| err.in_parent(ChildName(&field.name)))?;
 
             Ok(V::FixedSizeList(FixedSizeListArrayView {
                 len: array.len(),
@@ -429,10 +518,10 @@ impl<'a> TryFrom<&'a dyn A2Array> for ArrayView<'a> {
                 data: array.values().as_slice(),
             }))
         } else {
-            fail!(
-                "Cannot convert array with data type {:?} into an array view",
-                array.data_type()
-            );
+            Err(unsupported_data_type(
+                format!("{:?}", array.data_type()),
+                "cannot build an array view",
+            ))
         }
     }
 }
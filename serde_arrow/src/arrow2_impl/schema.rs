@@ -1,14 +1,15 @@
 use crate::{
     _impl::arrow2::datatypes::{
-        DataType as ArrowDataType, Field as ArrowField, IntegerType, TimeUnit as ArrowTimeUnit,
-        UnionMode as ArrowUnionMode,
+        DataType as ArrowDataType, Field as ArrowField, IntegerType,
+        IntervalUnit as ArrowIntervalUnit, TimeUnit as ArrowTimeUnit, UnionMode as ArrowUnionMode,
     },
     internal::{
-        arrow::{DataType, Field, TimeUnit, UnionMode},
+        arrow::{DataType, Field, IntervalUnit, TimeUnit, UnionMode},
         error::{fail, Error, Result},
         schema::{
             validate_field, DataTypeDisplay, SchemaLike, Sealed, SerdeArrowSchema, TracingOptions,
         },
+        utils::{array_view_ext::unsupported_data_type, ChildName},
     },
 };
 
@@ -33,7 +34,10 @@ impl<'a> TryFrom<&'a [ArrowField]> for SerdeArrowSchema {
 
     fn try_from(fields: &'a [ArrowField]) -> std::prelude::v1::Result<Self, Self::Error> {
         Ok(Self {
-            fields: fields.iter().map(Field::try_from).collect::<Result<_>>()?,
+            fields: fields
+                .iter()
+                .map(|field| Field::try_from(field).map_err(|err| err.in_parent("$")))
+                .collect::<Result<_>>()?,
         })
     }
 }
@@ -60,7 +64,7 @@ impl TryFrom<&ArrowDataType> for DataType {
     type Error = Error;
 
     fn try_from(value: &ArrowDataType) -> Result<Self> {
-        use {ArrowDataType as AT, DataType as T, Field as F, IntegerType as I};
+        use {ArrowDataType as AT, DataType as T, IntegerType as I};
         match value {
             AT::Null => Ok(T::Null),
             AT::Boolean => Ok(T::Boolean),
@@ -80,6 +84,7 @@ impl TryFrom<&ArrowDataType> for DataType {
             AT::Time32(unit) => Ok(T::Time32((*unit).into())),
             AT::Time64(unit) => Ok(T::Time64((*unit).into())),
             AT::Duration(unit) => Ok(T::Duration((*unit).into())),
+            AT::Interval(unit) => Ok(T::Interval((*unit).into())),
             AT::Timestamp(unit, tz) => Ok(T::Timestamp((*unit).into(), tz.clone())),
             AT::Decimal(precision, scale) => {
                 if *precision > u8::MAX as usize || *scale > i8::MAX as usize {
@@ -87,22 +92,31 @@ impl TryFrom<&ArrowDataType> for DataType {
                 }
                 Ok(T::Decimal128(*precision as u8, *scale as i8))
             }
+            AT::Decimal256(precision, scale) => {
+                if *precision > u8::MAX as usize || *scale > i8::MAX as usize {
+                    fail!("cannot represent precision / scale of the decimal");
+                }
+                Ok(T::Decimal256(*precision as u8, *scale as i8))
+            }
             AT::Utf8 => Ok(T::Utf8),
             AT::LargeUtf8 => Ok(T::LargeUtf8),
             AT::Binary => Ok(T::Binary),
             AT::LargeBinary => Ok(T::LargeBinary),
             AT::FixedSizeBinary(n) => Ok(T::FixedSizeBinary(i32::try_from(*n)?)),
-            AT::List(entry) => Ok(T::List(Box::new(entry.as_ref().try_into()?))),
-            AT::LargeList(entry) => Ok(T::LargeList(Box::new(entry.as_ref().try_into()?))),
+            AT::List(entry) => Ok(T::List(Box::new(field_from_arrow2_unchecked(entry)?))),
+            AT::LargeList(entry) => Ok(T::LargeList(Box::new(field_from_arrow2_unchecked(entry)?))),
             AT::FixedSizeList(entry, n) => Ok(T::FixedSizeList(
-                Box::new(entry.as_ref().try_into()?),
+                Box::new(field_from_arrow2_unchecked(entry)?),
                 i32::try_from(*n)?,
             )),
-            AT::Map(field, sorted) => Ok(T::Map(Box::new(field.as_ref().try_into()?), *sorted)),
+            AT::Map(field, sorted) => Ok(T::Map(
+                Box::new(field_from_arrow2_unchecked(field)?),
+                *sorted,
+            )),
             AT::Struct(fields) => {
                 let mut res_fields = Vec::new();
                 for field in fields {
-                    res_fields.push(Field::try_from(field)?);
+                    res_fields.push(field_from_arrow2_unchecked(field)?);
                 }
                 Ok(T::Struct(res_fields))
             }
@@ -137,11 +151,14 @@ impl TryFrom<&ArrowDataType> for DataType {
 
                 let mut fields = Vec::new();
                 for (type_id, field) in in_type_ids.iter().zip(in_fields) {
-                    fields.push(((*type_id).try_into()?, F::try_from(field)?));
+                    fields.push(((*type_id).try_into()?, field_from_arrow2_unchecked(field)?));
                 }
                 Ok(T::Union(fields, (*mode).into()))
             }
-            dt => fail!("Cannot convert data type {dt:?} to internal data type"),
+            dt => Err(unsupported_data_type(
+                format!("{dt:?}"),
+                "the arrow2 data type is not supported",
+            )),
         }
     }
 }
@@ -150,17 +167,26 @@ impl TryFrom<&ArrowField> for Field {
     type Error = Error;
 
     fn try_from(field: &ArrowField) -> Result<Self> {
-        let field = Field {
-            name: field.name.to_owned(),
-            data_type: DataType::try_from(&field.data_type)?,
-            nullable: field.is_nullable,
-            metadata: field.metadata.clone().into_iter().collect(),
-        };
+        let field = field_from_arrow2_unchecked(field)?;
         validate_field(&field)?;
         Ok(field)
     }
 }
 
+/// Convert an arrow2 field without validating it
+///
+/// Nested fields are not validated either. Any validation is left to the
+/// caller.
+pub fn field_from_arrow2_unchecked(field: &ArrowField) -> Result<Field> {
+    Ok(Field {
+        name: field.name.to_owned(),
+        data_type: DataType::try_from(&field.data_type)
+            .map_err(|err| err.in_parent(ChildName(&field.name)))?,
+        nullable: field.is_nullable,
+        metadata: field.metadata.clone().into_iter().collect(),
+    })
+}
+
 impl TryFrom<&DataType> for ArrowDataType {
     type Error = Error;
 
@@ -183,15 +209,28 @@ impl TryFrom<&DataType> for ArrowDataType {
             T::Date32 => Ok(AT::Date32),
             T::Date64 => Ok(AT::Date64),
             T::Duration(unit) => Ok(AT::Duration((*unit).into())),
+            T::Interval(unit) => Ok(AT::Interval((*unit).into())),
             T::Time32(unit) => Ok(AT::Time32((*unit).into())),
             T::Time64(unit) => Ok(AT::Time64((*unit).into())),
             T::Timestamp(unit, tz) => Ok(AT::Timestamp((*unit).into(), tz.clone())),
+            T::Utf8View | T::BinaryView | T::ListView(_) | T::LargeListView(_) => {
+                fail!(
+                    "{dt} is not supported by arrow2",
+                    dt = DataTypeDisplay(value)
+                )
+            }
             T::Decimal128(precision, scale) => {
                 if *scale < 0 {
                     fail!("arrow2 does not support decimals with negative scale");
                 }
                 Ok(AT::Decimal((*precision).into(), (*scale).try_into()?))
             }
+            T::Decimal256(precision, scale) => {
+                if *scale < 0 {
+                    fail!("arrow2 does not support decimals with negative scale");
+                }
+                Ok(AT::Decimal256((*precision).into(), (*scale).try_into()?))
+            }
             T::Binary => Ok(AT::Binary),
             T::LargeBinary => Ok(AT::LargeBinary),
             T::FixedSizeBinary(n) => Ok(AT::FixedSizeBinary((*n).try_into()?)),
@@ -306,6 +345,26 @@ impl From<ArrowTimeUnit> for TimeUnit {
     }
 }
 
+impl From<IntervalUnit> for ArrowIntervalUnit {
+    fn from(value: IntervalUnit) -> Self {
+        match value {
+            IntervalUnit::YearMonth => Self::YearMonth,
+            IntervalUnit::DayTime => Self::DayTime,
+            IntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
+
+impl From<ArrowIntervalUnit> for IntervalUnit {
+    fn from(value: ArrowIntervalUnit) -> Self {
+        match value {
+            ArrowIntervalUnit::YearMonth => Self::YearMonth,
+            ArrowIntervalUnit::DayTime => Self::DayTime,
+            ArrowIntervalUnit::MonthDayNano => Self::MonthDayNano,
+        }
+    }
+}
+
 impl From<ArrowUnionMode> for UnionMode {
     fn from(value: ArrowUnionMode) -> Self {
         match value {
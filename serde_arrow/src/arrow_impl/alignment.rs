@@ -0,0 +1,103 @@
+//! Align the buffers of arrays to the requirements of the Arrow C data
+//! interface and IPC
+use crate::{
+    _impl::arrow::{
+        _raw::buffer::{BooleanBuffer, MutableBuffer, NullBuffer},
+        array::{make_array, Array, ArrayData, ArrayRef},
+        buffer::Buffer,
+        datatypes::DataType,
+    },
+    internal::error::{fail, Result},
+};
+
+/// The alignment and padding of buffers in bytes
+pub const BUFFER_ALIGNMENT: usize = 64;
+
+fn padded_len(len: usize) -> usize {
+    len.div_ceil(BUFFER_ALIGNMENT) * BUFFER_ALIGNMENT
+}
+
+/// Check whether a buffer starts at an aligned address and its allocation is
+/// padded to a multiple of the alignment
+fn is_aligned(buffer: &Buffer) -> bool {
+    (buffer.as_ptr() as usize).is_multiple_of(BUFFER_ALIGNMENT)
+        && buffer.capacity() >= padded_len(buffer.len())
+}
+
+/// Copy the buffer into an aligned and padded allocation, if required
+fn align_buffer(buffer: &Buffer) -> Buffer {
+    if is_aligned(buffer) {
+        return buffer.clone();
+    }
+    let mut aligned = MutableBuffer::with_capacity(padded_len(buffer.len()));
+    aligned.extend_from_slice(buffer.as_slice());
+    aligned.into()
+}
+
+fn align_data(data: ArrayData) -> Result<ArrayData> {
+    let buffers = data.buffers().iter().map(align_buffer).collect();
+    let child_data = data
+        .child_data()
+        .iter()
+        .map(|child| align_data(child.clone()))
+        .collect::<Result<_>>()?;
+    let nulls = data.nulls().map(|nulls| {
+        let bits = nulls.inner();
+        NullBuffer::new(BooleanBuffer::new(
+            align_buffer(bits.inner()),
+            bits.offset(),
+            bits.len(),
+        ))
+    });
+
+    Ok(data
+        .into_builder()
+        .buffers(buffers)
+        .child_data(child_data)
+        .nulls(nulls)
+        .build()?)
+}
+
+/// Copy all buffers of the array that are not aligned into aligned buffers
+pub fn align_array(array: ArrayRef) -> Result<ArrayRef> {
+    Ok(make_array(align_data(array.to_data())?))
+}
+
+pub fn check_alignment(name: &str, array: &dyn Array) -> Result<()> {
+    check_data_alignment(&format!("$.{name}"), &array.to_data())
+}
+
+fn check_data_alignment(path: &str, data: &ArrayData) -> Result<()> {
+    if let Some(nulls) = data.nulls() {
+        if !is_aligned(nulls.inner().inner()) {
+            fail!("The validity buffer of {path} is not aligned to {BUFFER_ALIGNMENT} bytes");
+        }
+    }
+    for (idx, buffer) in data.buffers().iter().enumerate() {
+        if !is_aligned(buffer) {
+            fail!("Buffer {idx} of {path} is not aligned to {BUFFER_ALIGNMENT} bytes");
+        }
+    }
+    let names = child_names(data.data_type());
+    for (idx, child) in data.child_data().iter().enumerate() {
+        let child_path = match names.get(idx) {
+            Some(name) => format!("{path}.{name}"),
+            None => format!("{path}.{idx}"),
+        };
+        check_data_alignment(&child_path, child)?;
+    }
+    Ok(())
+}
+
+fn child_names(data_type: &DataType) -> Vec<String> {
+    match data_type {
+        DataType::Struct(fields) => fields.iter().map(|f| f.name().to_owned()).collect(),
+        DataType::Union(fields, _) => fields.iter().map(|(_, f)| f.name().to_owned()).collect(),
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => vec![field.name().to_owned()],
+        DataType::Dictionary(_, _) => vec![String::from("values")],
+        _ => Vec::new(),
+    }
+}
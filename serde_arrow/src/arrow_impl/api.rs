@@ -1,11 +1,11 @@
 #![deny(missing_docs)]
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 
 use crate::{
     _impl::arrow::{
-        array::{Array, ArrayRef, RecordBatch},
+        array::{Array, ArrayRef, RecordBatch, RecordBatchOptions},
         datatypes::{FieldRef, Schema},
     },
     internal::{
@@ -14,10 +14,16 @@ use crate::{
         error::{fail, Result},
         schema::SerdeArrowSchema,
         serializer::Serializer,
+        session::Session,
+        shared_dictionaries::{DictionaryUpdate, SharedDictionaries},
+        utils::{
+            array_view_ext::in_field,
+            value::{Value, ValueSerializer},
+        },
     },
 };
 
-use super::type_support::fields_from_field_refs;
+use super::schema::IntoFields;
 
 /// Build arrow arrays from the given items  (*requires one of the `arrow-*`
 /// features*)
@@ -57,7 +63,7 @@ use super::type_support::fields_from_field_refs;
 /// # }
 /// ```
 ///
-pub fn to_arrow<T: Serialize>(fields: &[FieldRef], items: T) -> Result<Vec<ArrayRef>> {
+pub fn to_arrow<T: Serialize>(fields: impl IntoFields, items: T) -> Result<Vec<ArrayRef>> {
     let builder = ArrayBuilder::from_arrow(fields)?;
     items
         .serialize(Serializer::new(builder))?
@@ -93,7 +99,7 @@ pub fn to_arrow<T: Serialize>(fields: &[FieldRef], items: T) -> Result<Vec<Array
 /// # }
 /// ```
 ///
-pub fn from_arrow<'de, T, A>(fields: &[FieldRef], arrays: &'de [A]) -> Result<T>
+pub fn from_arrow<'de, T, A>(fields: impl IntoFields, arrays: &'de [A]) -> Result<T>
 where
     T: Deserialize<'de>,
     A: AsRef<dyn Array>,
@@ -138,7 +144,7 @@ where
 /// # Ok(())
 /// # }
 /// ```
-pub fn to_record_batch<T: Serialize>(fields: &[FieldRef], items: &T) -> Result<RecordBatch> {
+pub fn to_record_batch<T: Serialize>(fields: impl IntoFields, items: &T) -> Result<RecordBatch> {
     let builder = ArrayBuilder::from_arrow(fields)?;
     items
         .serialize(Serializer::new(builder))?
@@ -146,6 +152,482 @@ pub fn to_record_batch<T: Serialize>(fields: &[FieldRef], items: &T) -> Result<R
         .to_record_batch()
 }
 
+/// Build a record batch without any rows (*requires one of the `arrow-*`
+/// features*)
+///
+/// The columns are empty arrays with the data types given by the fields,
+/// including nested types. In contrast to [`to_record_batch`], fields without
+/// any columns are supported as well. This function is useful for writers that
+/// need to emit the schema without any data.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Serialize, Deserialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: Vec<u64>,
+/// }
+///
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// let record_batch = serde_arrow::empty_record_batch(&fields)?;
+///
+/// assert_eq!(record_batch.num_columns(), 2);
+/// assert_eq!(record_batch.num_rows(), 0);
+/// assert_eq!(record_batch.schema().fields()[..], fields[..]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn empty_record_batch(fields: impl IntoFields) -> Result<RecordBatch> {
+    let mut builder = ArrayBuilder::from_arrow(fields)?;
+    let arrays = builder.to_arrow()?;
+    let schema = Schema::new(builder.fields_of_arrays(&arrays)?);
+    let options = RecordBatchOptions::new().with_row_count(Some(0));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::new(schema),
+        arrays,
+        &options,
+    )?)
+}
+
+/// Rebuild a subset of the columns of a record batch from partial items
+/// (*requires one of the `arrow-*` features*)
+///
+/// The items should be a list of records (structs or maps) with one item per
+/// row. The columns to rebuild are the fields present in any of the items, all
+/// other columns are carried over unchanged. The fields of the rebuilt
+/// columns are taken from the record batch, i.e., the items are not traced.
+/// Without items, e.g., for a record batch without rows, the record batch is
+/// returned unchanged. It is an error, if the items contain fields not present
+/// in the record batch or if the number of items does not match the number of
+/// rows.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Serialize, Deserialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     a: u32,
+///     b: String,
+/// }
+///
+/// ##[derive(Serialize)]
+/// struct Patch {
+///     b: String,
+/// }
+///
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// let record_batch = serde_arrow::to_record_batch(&fields, &[
+///     Record { a: 1, b: String::from("foo") },
+///     Record { a: 2, b: String::from("bar") },
+/// ])?;
+///
+/// let record_batch = serde_arrow::patch_record_batch(&record_batch, &[
+///     Patch { b: String::from("FOO") },
+///     Patch { b: String::from("BAR") },
+/// ])?;
+///
+/// let items: Vec<Record> = serde_arrow::from_record_batch(&record_batch)?;
+/// assert_eq!(items, [
+///     Record { a: 1, b: String::from("FOO") },
+///     Record { a: 2, b: String::from("BAR") },
+/// ]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn patch_record_batch<T: Serialize + ?Sized>(
+    record_batch: &RecordBatch,
+    items: &T,
+) -> Result<RecordBatch> {
+    let items = items.serialize(ValueSerializer)?;
+    let (Value::Seq(rows) | Value::Tuple(rows)) = &items else {
+        fail!("Cannot patch record batch: the items must be a sequence of records");
+    };
+
+    let num_rows = record_batch.num_rows();
+    if rows.len() != num_rows {
+        fail!(
+            "Cannot patch record batch with {num_rows} rows using {num_items} items",
+            num_items = rows.len(),
+        );
+    }
+
+    let schema = record_batch.schema();
+    let mut patched = vec![false; schema.fields().len()];
+    for row in rows {
+        for name in record_field_names(row)? {
+            let Some((idx, _)) = schema.column_with_name(name) else {
+                fail!("Cannot patch record batch: field {name:?} does not exist");
+            };
+            patched[idx] = true;
+        }
+    }
+
+    // without items or patched fields, there is nothing to rebuild
+    if !patched.contains(&true) {
+        return Ok(record_batch.clone());
+    }
+
+    let patch_fields = std::iter::zip(schema.fields(), &patched)
+        .filter(|(_, patched)| **patched)
+        .map(|(field, _)| field.clone())
+        .collect::<Vec<_>>();
+
+    let mut builder = ArrayBuilder::from_arrow(&patch_fields)?;
+    builder.extend(rows)?;
+    let mut patch_arrays = builder.to_arrow()?.into_iter();
+
+    let mut columns = Vec::new();
+    for (column, patched) in std::iter::zip(record_batch.columns(), &patched) {
+        if *patched {
+            let Some(array) = patch_arrays.next() else {
+                fail!("Cannot patch record batch: missing patched column");
+            };
+            columns.push(array);
+        } else {
+            columns.push(column.clone());
+        }
+    }
+
+    let fields = with_array_types(schema.fields().iter().cloned(), &columns);
+    let schema = Schema::new(fields).with_metadata(schema.metadata().clone());
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::new(schema),
+        columns,
+        &options,
+    )?)
+}
+
+/// Replace the data types of the fields with the data types of the arrays
+///
+/// The data types differ, if dictionary keys were widened or if strings with the
+/// `AdaptiveString` strategy were built with different offsets.
+pub(crate) fn with_array_types(
+    fields: impl IntoIterator<Item = FieldRef>,
+    arrays: &[ArrayRef],
+) -> Vec<FieldRef> {
+    std::iter::zip(fields, arrays)
+        .map(|(field, array)| {
+            if field.data_type() == array.data_type() {
+                field
+            } else {
+                let field = field.as_ref().clone();
+                Arc::new(field.with_data_type(array.data_type().clone()))
+            }
+        })
+        .collect()
+}
+
+/// The names of the fields of a record given as a struct or a map
+fn record_field_names(record: &Value) -> Result<Vec<&str>> {
+    match record {
+        Value::Struct(_, fields) => Ok(fields.iter().map(|(name, _)| *name).collect()),
+        Value::Map(entries) => {
+            let mut names = Vec::new();
+            for (key, _) in entries {
+                match key {
+                    Value::String(name) => names.push(name.as_str()),
+                    Value::StaticStr(name) => names.push(*name),
+                    _ => fail!("Cannot patch record batch: the keys of records must be strings"),
+                }
+            }
+            Ok(names)
+        }
+        _ => fail!("Cannot patch record batch: the items must be structs or maps"),
+    }
+}
+
+/// Serialize items into a record batch with the schema of a source batch,
+/// passing through unchanged dictionary columns (*requires one of the
+/// `arrow-*` features*)
+///
+/// This function is intended for pipelines that deserialize items from a
+/// record batch, transform them and serialize them again. The items should be
+/// a list of records, serialized with the fields of `source`. The values of
+/// top-level dictionary columns are compared against the rows of `source`
+/// instead of being encoded. If all values of a column are equal to the
+/// source, the original array is spliced into the result without copying
+/// (or a slice of it, if there are less items than rows). Once a value
+/// differs, the column is encoded as usual. Dictionary columns nested in
+/// other fields are always encoded.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Serialize, Deserialize};
+/// use serde_arrow::schema::SchemaLike;
+///
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     category: String,
+///     value: f64,
+/// }
+///
+/// let fields = Vec::<FieldRef>::from_value(serde_json::json!([
+///     {"name": "category", "data_type": "Dictionary", "children": [
+///         {"name": "key", "data_type": "U32"},
+///         {"name": "value", "data_type": "LargeUtf8"},
+///     ]},
+///     {"name": "value", "data_type": "F64"},
+/// ]))?;
+/// let source = serde_arrow::to_record_batch(&fields, &[
+///     Record { category: String::from("a"), value: 1.0 },
+///     Record { category: String::from("b"), value: 2.0 },
+/// ])?;
+///
+/// let mut items: Vec<Record> = serde_arrow::from_record_batch(&source)?;
+/// for item in &mut items {
+///     item.value *= 2.0;
+/// }
+/// let result = serde_arrow::reserialize_record_batch(&source, &items)?;
+///
+/// // the unchanged dictionary column is passed through
+/// assert!(std::sync::Arc::ptr_eq(result.column(0), source.column(0)));
+/// # Ok(())
+/// # }
+/// ```
+pub fn reserialize_record_batch<T: Serialize + ?Sized>(
+    source: &RecordBatch,
+    items: &T,
+) -> Result<RecordBatch> {
+    super::passthrough::reserialize_record_batch(source, items)
+}
+
+/// Concatenate record batches with differing columns into a single batch
+/// (*requires one of the `arrow-*` features*)
+///
+/// The columns are aligned by name, in order of their first occurrence. Columns
+/// missing in a batch are filled with nulls and marked as nullable. Columns
+/// with differing types are promoted to a common type:
+///
+/// - `Null` columns adopt the type of the other batches
+/// - integers are widened, mixed signed and unsigned integers use a signed
+///   type wide enough for both (up to `Int64`)
+/// - floats are widened, mixed integers and floats use `Float64`
+/// - `Utf8` and `LargeUtf8` use `LargeUtf8`, `Binary` and `LargeBinary` use
+///   `LargeBinary`
+///
+/// All other type mismatches, including nested types that differ in any way,
+/// result in an error. This function is useful to combine micro-batches traced
+/// from different samples.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, FieldRef};
+/// use serde::Serialize;
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Serialize)]
+/// struct First {
+///     a: u8,
+/// }
+///
+/// ##[derive(Serialize)]
+/// struct Second {
+///     a: i32,
+///     b: String,
+/// }
+///
+/// let first = [First { a: 1 }];
+/// let fields = Vec::<FieldRef>::from_samples(&first, TracingOptions::default())?;
+/// let first = serde_arrow::to_record_batch(&fields, &first)?;
+///
+/// let second = [Second { a: -2, b: String::from("foo") }];
+/// let fields = Vec::<FieldRef>::from_samples(&second, TracingOptions::default())?;
+/// let second = serde_arrow::to_record_batch(&fields, &second)?;
+///
+/// let batch = serde_arrow::concat_batches_with_union_schema(&[first, second])?;
+/// assert_eq!(batch.num_rows(), 2);
+/// assert_eq!(batch.schema().field(0).data_type(), &DataType::Int32);
+/// assert!(batch.schema().field(1).is_nullable());
+/// # Ok(())
+/// # }
+/// ```
+pub fn concat_batches_with_union_schema(batches: &[RecordBatch]) -> Result<RecordBatch> {
+    super::concat::concat_batches_with_union_schema(batches)
+}
+
+/// Check that all buffers of a record batch are aligned to 64 bytes and padded
+/// to a multiple of 64 bytes (*requires one of the `arrow-*` features*)
+///
+/// Aligned and padded buffers can be exported via the C data interface or
+/// written to IPC without reallocation. Arrays built with
+/// [`SerializerOptions::aligned_buffers`][crate::SerializerOptions::aligned_buffers]
+/// pass this check. The error names the first buffer that is not aligned.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     ArrayBuilder, SerializerOptions,
+/// };
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: u32,
+///     b: Vec<String>,
+/// }
+///
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// let mut builder = ArrayBuilder::from_arrow(&fields)?
+///     .with_options(SerializerOptions::default().aligned_buffers(true));
+/// builder.push(Record { a: 1, b: vec![String::from("foo")] })?;
+///
+/// let batch = builder.to_record_batch()?;
+/// serde_arrow::check_alignment(&batch)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn check_alignment(record_batch: &RecordBatch) -> Result<()> {
+    let schema = record_batch.schema();
+    for (field, array) in std::iter::zip(schema.fields(), record_batch.columns()) {
+        super::alignment::check_alignment(field.name(), array.as_ref())?;
+    }
+    Ok(())
+}
+
+/// Flatten a list of structs column into a batch with one row per struct
+/// (*requires one of the `arrow-*` features*)
+///
+/// The column must be a `List` or `LargeList` of non-null structs. The
+/// returned batch contains a `UInt64` column `"parent_index"` with the index of
+/// the row of `batch` each struct belongs to, followed by the fields of the
+/// structs. Null and empty lists do not result in any rows. Use
+/// [`collect_exploded`] to reverse this operation.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{Field, FieldRef};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Serialize)]
+/// struct Order {
+///     id: u32,
+///     items: Vec<Item>,
+/// }
+///
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Item {
+///     name: String,
+///     quantity: u32,
+/// }
+///
+/// let orders = [
+///     Order { id: 0, items: vec![Item { name: String::from("foo"), quantity: 2 }] },
+///     Order { id: 1, items: vec![] },
+///     Order { id: 2, items: vec![Item { name: String::from("bar"), quantity: 1 }] },
+/// ];
+/// let fields = Vec::<FieldRef>::from_samples(&orders, TracingOptions::default())?;
+/// let batch = serde_arrow::to_record_batch(&fields, &orders)?;
+///
+/// let items = serde_arrow::explode(&batch, "items")?;
+///
+/// ##[derive(Debug, PartialEq, Deserialize)]
+/// struct Row {
+///     parent_index: u64,
+///     name: String,
+///     quantity: u32,
+/// }
+///
+/// let rows: Vec<Row> = serde_arrow::from_record_batch(&items)?;
+/// assert_eq!(rows, [
+///     Row { parent_index: 0, name: String::from("foo"), quantity: 2 },
+///     Row { parent_index: 2, name: String::from("bar"), quantity: 1 },
+/// ]);
+///
+/// ##[derive(Debug, PartialEq, Deserialize)]
+/// struct Items {
+///     items: Vec<Item>,
+/// }
+///
+/// let collected = serde_arrow::collect_exploded(&items, batch.num_rows())?;
+/// let field = Field::new("items", collected.data_type().clone(), false);
+/// let lists: Vec<Items> = serde_arrow::from_arrow(std::slice::from_ref(&field), &[collected])?;
+/// assert_eq!(lists[0].items, [Item { name: String::from("foo"), quantity: 2 }]);
+/// assert_eq!(lists[1].items, []);
+/// # Ok(())
+/// # }
+/// ```
+pub fn explode(batch: &RecordBatch, column: &str) -> Result<RecordBatch> {
+    super::explode::explode(batch, column)
+}
+
+/// Collect the rows of an exploded batch into a list of structs column
+/// (*requires one of the `arrow-*` features*)
+///
+/// This function reverses [`explode`]. The batch must contain a non-nullable
+/// `UInt64` column `"parent_index"` with sorted indices smaller than
+/// `num_rows`. The returned `LargeList` array has `num_rows` entries, each
+/// containing the rows with the corresponding parent index as structs of the
+/// remaining columns. Parent rows without any children result in empty lists.
+pub fn collect_exploded(batch: &RecordBatch, num_rows: usize) -> Result<ArrayRef> {
+    super::explode::collect_exploded(batch, num_rows)
+}
+
+/// Compute the leaf columns required to deserialize records of type `T`
+/// (*requires one of the `arrow-*` features*)
+///
+/// `T` is the type of a single record (e.g., a struct), not of the list of
+/// records. The leaves are numbered in depth-first order, as used by parquet
+/// readers. Each primitive column is one leaf, nested columns contribute the
+/// leaves of their children and maps the leaves of their keys and values. The
+/// result can be used to build a projection mask, e.g., with parquet's
+/// `ProjectionMask::leaves`.
+///
+/// Only fields of nested structs that are not requested by `T` are excluded. Parts
+/// of the schema `T` does not explore in detail (e.g., flattened fields or
+/// unions) are kept completely.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::{DataType, Field, Fields, Schema};
+/// use serde::Deserialize;
+///
+/// let schema = Schema::new(vec![
+///     Field::new("id", DataType::UInt64, false),
+///     Field::new("payload", DataType::LargeBinary, false),
+///     Field::new("meta", DataType::Struct(Fields::from(vec![
+///         Field::new("created", DataType::Int64, false),
+///         Field::new("tags", DataType::Utf8, true),
+///     ])), false),
+/// ]);
+///
+/// ##[derive(Deserialize)]
+/// struct Meta {
+///     created: i64,
+/// }
+///
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     id: u64,
+///     meta: Meta,
+/// }
+///
+/// assert_eq!(serde_arrow::projection_for::<Record>(&schema)?, [0, 2]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn projection_for<'de, T: Deserialize<'de>>(fields: impl IntoFields) -> Result<Vec<usize>> {
+    crate::internal::projection::projection_for::<T>(&fields.into_fields_unchecked()?)
+}
+
 /// Deserialize items from a record batch (*requires one of the `arrow-*`
 /// features*)
 ///
@@ -174,21 +656,293 @@ pub fn from_record_batch<'de, T: Deserialize<'de>>(record_batch: &'de RecordBatc
     T::deserialize(Deserializer::from_record_batch(record_batch)?)
 }
 
+/// Deserialize the rows in the given range of a record batch (*requires one of
+/// the `arrow-*` features*)
+///
+/// This function behaves like [`from_record_batch`] for
+/// `record_batch.slice(range.start, range.len())`, but borrows from the
+/// original batch. The buffers are not copied, the deserializers start reading
+/// at the offset of the range. Together with [`split`] this function allows to
+/// deserialize a single large batch in parallel.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+/// #
+/// use serde::Deserialize;
+///
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let items: Vec<Record> = serde_arrow::from_record_batch_range(&record_batch, 0..1)?;
+/// assert_eq!(items.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_record_batch_range<'de, T: Deserialize<'de>>(
+    record_batch: &'de RecordBatch,
+    range: Range<usize>,
+) -> Result<T> {
+    T::deserialize(Deserializer::from_record_batch_range(record_batch, range)?)
+}
+
+/// Deserialize the records of a record batch in parallel (*requires one of the
+/// `arrow-*` features and the `rayon` feature*)
+///
+/// `T` is the type of a single record (e.g., a struct), not of the list of
+/// records. The batch is split into one row range per thread of the current
+/// rayon thread pool. The ranges are deserialized with
+/// [`from_record_batch_range`] on the pool and the results are concatenated in
+/// order. If deserializing any range fails, one of the errors is returned.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+/// #
+/// use serde::Deserialize;
+///
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let items: Vec<Record> = serde_arrow::from_record_batch_parallel(&record_batch)?;
+/// assert_eq!(items.len(), record_batch.num_rows());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "rayon")]
+pub fn from_record_batch_parallel<'de, T: Deserialize<'de> + Send>(
+    record_batch: &'de RecordBatch,
+) -> Result<Vec<T>> {
+    use rayon::prelude::*;
+
+    let num_rows = record_batch.num_rows();
+    let chunk_size = usize::max(1, num_rows.div_ceil(rayon::current_num_threads()));
+
+    let chunks = (0..num_rows)
+        .step_by(chunk_size)
+        .map(|start| start..usize::min(start + chunk_size, num_rows))
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|range| from_record_batch_range::<Vec<T>>(record_batch, range))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// Build a record batch by serializing groups of columns in parallel
+/// (*requires one of the `arrow-*` features and the `rayon` feature*)
+///
+/// `items` is a slice of records (e.g., structs). The top-level fields are
+/// partitioned into one group of consecutive columns per thread of the current
+/// rayon thread pool. Each group is serialized from all items with its own
+/// builder, ignoring the fields of the other groups, and the resulting columns
+/// are merged into a single record batch in the order of `fields`. The columns
+/// are the same as for [`to_record_batch`]. It pays off for wide tables with
+/// many columns.
+///
+/// All columns are serialized as a single group, if the groups cannot be
+/// serialized independently:
+///
+/// - Fields with the `CatchAll`, `RowHash` or `StringHash` strategies depend
+///   on other fields of the same record
+/// - Records that are not serialized as structs or maps, e.g., tuples or tuple
+///   structs, are matched to the fields by position. The kind of the records
+///   is determined from the first item
+///
+/// In contrast to [`to_record_batch`], empty `fields` result in a record batch
+/// without columns with `items.len()` rows.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::Serialize;
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let items = vec![
+///     Record { a: Some(1.0), b: 2},
+///     // ...
+/// ];
+///
+/// let fields = Vec::<FieldRef>::from_samples(&items, TracingOptions::default())?;
+/// let record_batch = serde_arrow::to_record_batch_parallel(&fields, &items)?;
+///
+/// assert_eq!(record_batch.num_columns(), 2);
+/// assert_eq!(record_batch.num_rows(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "rayon")]
+pub fn to_record_batch_parallel<T: Serialize + Sync>(
+    fields: impl IntoFields,
+    items: &[T],
+) -> Result<RecordBatch> {
+    use rayon::prelude::*;
+
+    use crate::internal::schema::{
+        get_strategy_from_metadata, tracer::Tracer, Strategy, TracingOptions,
+    };
+
+    let fields = fields.into_fields()?;
+
+    let mut has_dependent_fields = false;
+    for field in &fields {
+        if matches!(
+            get_strategy_from_metadata(&field.metadata)?,
+            Some(Strategy::CatchAll | Strategy::RowHash | Strategy::StringHash { .. })
+        ) {
+            has_dependent_fields = true;
+        }
+    }
+
+    if fields.is_empty() {
+        let options = RecordBatchOptions::new().with_row_count(Some(items.len()));
+        return Ok(RecordBatch::try_new_with_options(
+            Arc::new(Schema::empty()),
+            Vec::new(),
+            &options,
+        )?);
+    }
+
+    let is_keyed = match items.first() {
+        Some(item) => matches!(
+            Tracer::from_samples((item,), TracingOptions::default().allow_null_fields(true)),
+            Ok(Tracer::Struct(_))
+        ),
+        None => true,
+    };
+
+    let group_size = if has_dependent_fields || !is_keyed {
+        usize::max(1, fields.len())
+    } else {
+        usize::max(1, fields.len().div_ceil(rayon::current_num_threads()))
+    };
+
+    let batches = fields
+        .chunks(group_size)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|group| {
+            let builder = ArrayBuilder::new(SerdeArrowSchema {
+                fields: group.to_vec(),
+            })?;
+            items
+                .serialize(Serializer::new(builder))?
+                .into_inner()
+                .to_record_batch()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut fields = Vec::new();
+    let mut columns = Vec::new();
+    for batch in batches {
+        fields.extend(batch.schema().fields().iter().cloned());
+        columns.extend(batch.columns().iter().cloned());
+    }
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+/// Split a record batch into batches of at most `chunk_size` rows (*requires
+/// one of the `arrow-*` features*)
+///
+/// The returned batches are zero-copy slices of the original batch. Only the
+/// last batch may contain less than `chunk_size` rows. A batch without rows is
+/// split into no batches.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+/// #
+/// use serde::Deserialize;
+///
+/// ##[derive(Deserialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let chunks = serde_arrow::split(&record_batch, 2)?;
+/// let items = std::thread::scope(|scope| {
+///     let handles = chunks
+///         .iter()
+///         .map(|chunk| scope.spawn(|| serde_arrow::from_record_batch::<Vec<Record>>(chunk)))
+///         .collect::<Vec<_>>();
+///     handles
+///         .into_iter()
+///         .map(|handle| handle.join().unwrap())
+///         .collect::<serde_arrow::Result<Vec<_>>>()
+/// })?;
+/// assert_eq!(items.iter().map(Vec::len).sum::<usize>(), record_batch.num_rows());
+/// # Ok(())
+/// # }
+/// ```
+pub fn split(record_batch: &RecordBatch, chunk_size: usize) -> Result<Vec<RecordBatch>> {
+    if chunk_size == 0 {
+        fail!("Cannot split a record batch into chunks of zero rows");
+    }
+    let num_rows = record_batch.num_rows();
+    let mut batches = Vec::with_capacity(num_rows.div_ceil(chunk_size));
+    for start in (0..num_rows).step_by(chunk_size) {
+        let len = usize::min(chunk_size, num_rows - start);
+        batches.push(record_batch.slice(start, len));
+    }
+    Ok(batches)
+}
+
 /// Support `arrow` (*requires one of the `arrow-*` features*)
 impl crate::internal::array_builder::ArrayBuilder {
     /// Build an ArrayBuilder from `arrow` fields (*requires one of the
     /// `arrow-*` features*)
-    pub fn from_arrow(fields: &[FieldRef]) -> Result<Self> {
-        let fields = fields_from_field_refs(fields)?;
+    pub fn from_arrow(fields: impl IntoFields) -> Result<Self> {
+        let fields = fields.into_fields()?;
+        Self::new(SerdeArrowSchema { fields })
+    }
+
+    /// Build an ArrayBuilder from `arrow` fields without validating them
+    /// (*requires one of the `arrow-*` features*)
+    ///
+    /// In contrast to [`ArrayBuilder::from_arrow`], the fields are not checked
+    /// for supported strategies or consistent metadata. It is intended for
+    /// fields that are known to be valid, e.g., because they were previously
+    /// used with [`ArrayBuilder::from_arrow`]. Invalid fields may result in
+    /// errors during serialization or array construction.
+    pub fn from_arrow_unchecked(fields: impl IntoFields) -> Result<Self> {
+        let fields = fields.into_fields_unchecked()?;
         Self::new(SerdeArrowSchema { fields })
     }
 
     /// Construct `arrow` arrays and reset the builder (*requires one of the
     /// `arrow-*` features*)
+    ///
+    /// With [`SerializerOptions::aligned_buffers`][crate::SerializerOptions::aligned_buffers],
+    /// all buffers are aligned to 64 bytes.
     pub fn to_arrow(&mut self) -> Result<Vec<ArrayRef>> {
-        self.build_arrays()?
+        let arrays = self
+            .build_arrays()?
             .into_iter()
             .map(ArrayRef::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        if !self.options.aligned_buffers {
+            return Ok(arrays);
+        }
+        arrays
+            .into_iter()
+            .map(super::alignment::align_array)
             .collect()
     }
 
@@ -196,10 +950,93 @@ impl crate::internal::array_builder::ArrayBuilder {
     /// `arrow-*` features*)
     pub fn to_record_batch(&mut self) -> Result<RecordBatch> {
         let arrays = self.to_arrow()?;
-        let fields = Vec::<FieldRef>::try_from(&self.schema)?;
-        let schema = Schema::new(fields);
+        let schema = self.arrow_schema(self.fields_of_arrays(&arrays)?);
         Ok(RecordBatch::try_new(Arc::new(schema), arrays)?)
     }
+
+    /// The schema of record batches with the given fields
+    ///
+    /// The schema carries the [`SerializerOptions::schema_metadata`][crate::SerializerOptions::schema_metadata].
+    pub(crate) fn arrow_schema(&self, fields: Vec<FieldRef>) -> Schema {
+        let metadata = self.options.schema_metadata.clone().into_iter().collect();
+        Schema::new(fields).with_metadata(metadata)
+    }
+
+    /// The fields of the schema with the data types of the built arrays
+    fn fields_of_arrays(&self, arrays: &[ArrayRef]) -> Result<Vec<FieldRef>> {
+        let fields = Vec::<FieldRef>::try_from(&self.schema)?;
+        Ok(with_array_types(fields, arrays))
+    }
+
+    /// Construct `arrow` arrays for each chunk of items (*requires one of the
+    /// `arrow-*` features*)
+    ///
+    /// The builder is reused for all chunks, the schema is only compiled once.
+    /// Each chunk should be a list of records, items pushed before calling
+    /// this function are included in the first chunk.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::FieldRef;
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::{ArrayBuilder, schema::{SchemaLike, TracingOptions}};
+    ///
+    /// ##[derive(Serialize, Deserialize)]
+    /// struct Record {
+    ///     a: u32,
+    /// }
+    ///
+    /// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+    /// let mut builder = ArrayBuilder::from_arrow(&fields)?;
+    ///
+    /// let chunks = vec![
+    ///     vec![Record { a: 1 }, Record { a: 2 }],
+    ///     vec![Record { a: 3 }],
+    /// ];
+    /// let arrays = builder.to_arrow_many(&chunks)?;
+    ///
+    /// assert_eq!(arrays.len(), 2);
+    /// assert_eq!(arrays[0][0].len(), 2);
+    /// assert_eq!(arrays[1][0].len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_arrow_many<I>(&mut self, chunks: I) -> Result<Vec<Vec<ArrayRef>>>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let mut result = Vec::new();
+        for chunk in chunks {
+            self.extend(chunk)?;
+            result.push(self.to_arrow()?);
+        }
+        Ok(result)
+    }
+
+    /// Construct a [`RecordBatch`] for each chunk of items (*requires one of
+    /// the `arrow-*` features*)
+    ///
+    /// See [`ArrayBuilder::to_arrow_many`] for details.
+    pub fn to_record_batches<I>(&mut self, chunks: I) -> Result<Vec<RecordBatch>>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let mut schema = Arc::new(self.arrow_schema(Vec::<FieldRef>::try_from(&self.schema)?));
+        let mut result = Vec::new();
+        for chunk in chunks {
+            self.extend(chunk)?;
+            let arrays = self.to_arrow()?;
+            let fields = self.fields_of_arrays(&arrays)?;
+            if schema.fields().iter().ne(fields.iter()) {
+                schema = Arc::new(self.arrow_schema(fields));
+            }
+            result.push(RecordBatch::try_new(schema.clone(), arrays)?);
+        }
+        Ok(result)
+    }
 }
 
 impl<'de> Deserializer<'de> {
@@ -228,12 +1065,13 @@ impl<'de> Deserializer<'de> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn from_arrow<A>(fields: &[FieldRef], arrays: &'de [A]) -> Result<Self>
+    pub fn from_arrow<A>(fields: impl IntoFields, arrays: &'de [A]) -> Result<Self>
     where
         A: AsRef<dyn Array>,
     {
         use crate::internal::arrow::ArrayView;
 
+        let fields = fields.into_fields()?;
         if fields.len() != arrays.len() {
             fail!(
                 "different number of fields ({}) and arrays ({})",
@@ -242,11 +1080,9 @@ impl<'de> Deserializer<'de> {
             );
         }
 
-        let fields = fields_from_field_refs(fields)?;
-
         let mut views = Vec::new();
-        for array in arrays {
-            views.push(ArrayView::try_from(array.as_ref())?);
+        for (field, array) in std::iter::zip(&fields, arrays) {
+            views.push(in_field(ArrayView::try_from(array.as_ref()), &field.name)?);
         }
 
         Deserializer::new(&fields, views)
@@ -255,6 +1091,10 @@ impl<'de> Deserializer<'de> {
     /// Construct a new deserializer from a record batch (*requires one of the
     /// `arrow-*` features*)
     ///
+    /// The number of items is given by the number of rows of the batch. For
+    /// batches without columns, each item is deserialized as a struct without
+    /// fields, i.e., structs with only defaulted fields can be deserialized.
+    ///
     /// Usage:
     ///
     /// ```rust
@@ -277,7 +1117,157 @@ impl<'de> Deserializer<'de> {
     /// ```
     ///
     pub fn from_record_batch(record_batch: &'de RecordBatch) -> Result<Self> {
-        let schema = record_batch.schema();
-        Deserializer::from_arrow(schema.fields(), record_batch.columns())
+        use crate::internal::arrow::ArrayView;
+
+        let fields = record_batch.schema().fields().into_fields()?;
+        let mut views = Vec::new();
+        for (field, array) in std::iter::zip(&fields, record_batch.columns()) {
+            views.push(in_field(ArrayView::try_from(array.as_ref()), &field.name)?);
+        }
+
+        Deserializer::new_with_len(&fields, views, Some(record_batch.num_rows()))
+    }
+
+    /// Construct a new deserializer for the rows in the given range of a record
+    /// batch (*requires one of the `arrow-*` features*)
+    ///
+    /// See [`from_record_batch_range`][crate::from_record_batch_range] for
+    /// details.
+    pub fn from_record_batch_range(
+        record_batch: &'de RecordBatch,
+        range: Range<usize>,
+    ) -> Result<Self> {
+        use crate::internal::{arrow::ArrayView, utils::array_view_ext::ArrayViewExt};
+
+        if range.start > range.end || range.end > record_batch.num_rows() {
+            fail!(
+                "Invalid range {start}..{end} for a record batch with {num_rows} rows",
+                start = range.start,
+                end = range.end,
+                num_rows = record_batch.num_rows(),
+            );
+        }
+
+        let fields = record_batch.schema().fields().into_fields()?;
+        let mut views = Vec::new();
+        for (field, array) in std::iter::zip(&fields, record_batch.columns()) {
+            let view = in_field(ArrayView::try_from(array.as_ref()), &field.name)?;
+            views.push(in_field(view.slice(range.start, range.len()), &field.name)?);
+        }
+
+        Deserializer::new_with_len(&fields, views, Some(range.len()))
+    }
+}
+
+/// Support `arrow` (*requires one of the `arrow-*` features*)
+impl Session {
+    /// Construct a session from `arrow` fields (*requires one of the `arrow-*`
+    /// features*)
+    pub fn from_arrow_fields(fields: impl IntoFields) -> Result<Self> {
+        let fields = fields.into_fields()?;
+        Self::new(SerdeArrowSchema { fields })
+    }
+
+    /// Build `arrow` arrays from the given items (*requires one of the
+    /// `arrow-*` features*)
+    ///
+    /// See [`to_arrow`] for details.
+    pub fn to_arrow<T: Serialize>(&mut self, items: T) -> Result<Vec<ArrayRef>> {
+        self.build_arrays(items)?
+            .into_iter()
+            .map(ArrayRef::try_from)
+            .collect()
+    }
+
+    /// Build a record batch from the given items (*requires one of the
+    /// `arrow-*` features*)
+    ///
+    /// See [`to_record_batch`] for details.
+    pub fn to_record_batch<T: Serialize>(&mut self, items: T) -> Result<RecordBatch> {
+        let arrays = self.to_arrow(items)?;
+        let fields = self.builder.fields_of_arrays(&arrays)?;
+        let schema = self.builder.arrow_schema(fields);
+        Ok(RecordBatch::try_new(Arc::new(schema), arrays)?)
+    }
+
+    /// Deserialize items from `arrow` arrays (*requires one of the `arrow-*`
+    /// features*)
+    ///
+    /// The arrays are interpreted with the fields of the session. See
+    /// [`from_arrow`] for details.
+    pub fn from_arrow<'de, T, A>(&self, arrays: &'de [A]) -> Result<T>
+    where
+        T: Deserialize<'de>,
+        A: AsRef<dyn Array>,
+    {
+        use crate::internal::arrow::ArrayView;
+
+        let mut views = Vec::new();
+        for (idx, array) in arrays.iter().enumerate() {
+            let view = ArrayView::try_from(array.as_ref());
+            views.push(match self.schema().fields.get(idx) {
+                Some(field) => in_field(view, &field.name)?,
+                None => view?,
+            });
+        }
+        T::deserialize(self.deserializer(views)?)
+    }
+
+    /// Deserialize items from a record batch (*requires one of the `arrow-*`
+    /// features*)
+    ///
+    /// The columns are interpreted with the fields of the session. See
+    /// [`from_record_batch`] for details.
+    pub fn from_record_batch<'de, T: Deserialize<'de>>(
+        &self,
+        record_batch: &'de RecordBatch,
+    ) -> Result<T> {
+        self.from_arrow(record_batch.columns())
+    }
+}
+
+/// Support `arrow` (*requires one of the `arrow-*` features*)
+impl SharedDictionaries {
+    /// Add the dictionaries of a record batch (*requires one of the `arrow-*`
+    /// features*)
+    pub fn update_from_record_batch(
+        &mut self,
+        record_batch: &RecordBatch,
+        update: DictionaryUpdate,
+    ) -> Result<()> {
+        self.update_from_arrow(
+            record_batch.schema().fields(),
+            record_batch.columns(),
+            update,
+        )
+    }
+
+    /// Add the dictionaries of `arrow` arrays (*requires one of the `arrow-*`
+    /// features*)
+    pub fn update_from_arrow<A>(
+        &mut self,
+        fields: impl IntoFields,
+        arrays: &[A],
+        update: DictionaryUpdate,
+    ) -> Result<()>
+    where
+        A: AsRef<dyn Array>,
+    {
+        use crate::internal::arrow::ArrayView;
+
+        let fields = fields.into_fields()?;
+        if fields.len() != arrays.len() {
+            fail!(
+                "different number of fields ({}) and arrays ({})",
+                fields.len(),
+                arrays.len()
+            );
+        }
+
+        let mut views = Vec::new();
+        for (field, array) in std::iter::zip(&fields, arrays) {
+            views.push(in_field(ArrayView::try_from(array.as_ref()), &field.name)?);
+        }
+        self.update(&fields, &views, update)
     }
 }
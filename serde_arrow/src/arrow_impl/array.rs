@@ -12,13 +12,15 @@ use crate::{
         },
         buffer::{Buffer, ScalarBuffer},
         datatypes::{
-            ArrowDictionaryKeyType, ArrowNativeType, ArrowPrimitiveType, DataType, Date32Type,
-            Date64Type, Decimal128Type, DurationMicrosecondType, DurationMillisecondType,
-            DurationNanosecondType, DurationSecondType, Field as ArrowField, Float16Type,
-            Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
-            Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType,
-            TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
-            TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type, UnionMode,
+            i256, ArrowDictionaryKeyType, ArrowNativeType, ArrowPrimitiveType, DataType,
+            Date32Type, Date64Type, Decimal128Type, Decimal256Type, DurationMicrosecondType,
+            DurationMillisecondType, DurationNanosecondType, DurationSecondType,
+            Field as ArrowField, Float16Type, Float32Type, Float64Type, Int16Type, Int32Type,
+            Int64Type, Int8Type, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalUnit,
+            IntervalYearMonthType, Time32MillisecondType, Time32SecondType, Time64MicrosecondType,
+            Time64NanosecondType, TimestampMicrosecondType, TimestampMillisecondType,
+            TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt64Type,
+            UInt8Type, UnionMode,
         },
     },
     internal::{
@@ -30,7 +32,7 @@ use crate::{
         },
         arrow::{Field, FieldMeta},
         error::{fail, Error, Result},
-        utils::meta_from_field,
+        utils::{array_view_ext::unsupported_data_type, meta_from_field, ChildName},
     },
 };
 
@@ -95,13 +97,43 @@ impl TryFrom<crate::internal::arrow::Array> for ArrayData {
             A::Duration(arr) => {
                 primitive_into_data(T::Duration(arr.unit.into()), arr.validity, arr.values)
             }
+            A::IntervalYearMonth(arr) => primitive_into_data(
+                T::Interval(IntervalUnit::YearMonth),
+                arr.validity,
+                arr.values,
+            ),
+            A::IntervalDayTime(arr) => primitive_into_data(
+                T::Interval(IntervalUnit::DayTime),
+                arr.validity,
+                arr.values
+                    .into_iter()
+                    .map(|v| IntervalDayTimeType::make_value(v.days, v.milliseconds))
+                    .collect(),
+            ),
+            A::IntervalMonthDayNano(arr) => primitive_into_data(
+                T::Interval(IntervalUnit::MonthDayNano),
+                arr.validity,
+                arr.values
+                    .into_iter()
+                    .map(|v| IntervalMonthDayNanoType::make_value(v.months, v.days, v.nanoseconds))
+                    .collect(),
+            ),
             A::Decimal128(arr) => primitive_into_data(
                 T::Decimal128(arr.precision, arr.scale),
                 arr.validity,
                 arr.values,
             ),
+            A::Decimal256(arr) => primitive_into_data(
+                T::Decimal256(arr.precision, arr.scale),
+                arr.validity,
+                arr.values
+                    .into_iter()
+                    .map(|v| i256::from_parts(v.low, v.high))
+                    .collect(),
+            ),
             A::Utf8(arr) => bytes_into_data(T::Utf8, arr.offsets, arr.data, arr.validity),
             A::LargeUtf8(arr) => bytes_into_data(T::LargeUtf8, arr.offsets, arr.data, arr.validity),
+            A::Utf8View(arr) => utf8_view_into_data(arr.offsets, arr.data, arr.validity),
             A::Binary(arr) => bytes_into_data(T::Binary, arr.offsets, arr.data, arr.validity),
             A::LargeBinary(arr) => {
                 bytes_into_data(T::LargeBinary, arr.offsets, arr.data, arr.validity)
@@ -150,7 +182,7 @@ impl TryFrom<crate::internal::arrow::Array> for ArrayData {
             }
             A::FixedSizeList(arr) => {
                 let child: ArrayData = (*arr.element).try_into()?;
-                if (child.len() % usize::try_from(arr.n)?) != 0 {
+                if !child.len().is_multiple_of(usize::try_from(arr.n)?) {
                     fail!(
                         "Invalid FixedSizeList: number of child elements ({}) not divisible by n ({})",
                         child.len(),
@@ -234,6 +266,27 @@ impl TryFrom<crate::internal::arrow::Array> for ArrayData {
                     child_data,
                 )?)
             }
+            A::SparseUnion(arr) => {
+                let mut fields = Vec::new();
+                let mut child_data = Vec::new();
+
+                for (type_id, array, meta) in arr.fields {
+                    let child: ArrayData = array.try_into()?;
+                    let field = field_from_data_and_meta(&child, meta);
+
+                    fields.push((type_id, Arc::new(field)));
+                    child_data.push(child);
+                }
+
+                Ok(ArrayData::try_new(
+                    DataType::Union(fields.into_iter().collect(), UnionMode::Sparse),
+                    arr.types.len(),
+                    None,
+                    0,
+                    vec![ScalarBuffer::from(arr.types).into_inner()],
+                    child_data,
+                )?)
+            }
         }
     }
 }
@@ -322,6 +375,19 @@ impl<'a> TryFrom<&'a dyn Array> for ArrayView<'a> {
                 validity: get_bits_with_offset(array),
                 values: array.values(),
             }))
+        } else if let Some(array) = any.downcast_ref::<PrimitiveArray<Decimal256Type>>() {
+            let &DataType::Decimal256(precision, scale) = array.data_type() else {
+                fail!(
+                    "Invalid data type for Decimal256 array: {}",
+                    array.data_type()
+                );
+            };
+            Ok(ArrayView::Decimal256(DecimalArrayView {
+                precision,
+                scale,
+                validity: get_bits_with_offset(array),
+                values: bytemuck::try_cast_slice(array.values().inner().as_slice())?,
+            }))
         } else if let Some(array) = any.downcast_ref::<PrimitiveArray<Date32Type>>() {
             Ok(ArrayView::Date32(PrimitiveArrayView {
                 validity: get_bits_with_offset(array),
@@ -408,6 +474,15 @@ impl<'a> TryFrom<&'a dyn Array> for ArrayView<'a> {
                 validity: get_bits_with_offset(array),
                 values: array.values(),
             }))
+        } else if let Some(array) = any.downcast_ref::<PrimitiveArray<IntervalYearMonthType>>() {
+            Ok(ArrayView::IntervalYearMonth(PrimitiveArrayView {
+                validity: get_bits_with_offset(array),
+                values: array.values(),
+            }))
+        } else if let Some(array) = any.downcast_ref::<PrimitiveArray<IntervalDayTimeType>>() {
+            wrap_interval_day_time_array(array)
+        } else if let Some(array) = any.downcast_ref::<PrimitiveArray<IntervalMonthDayNanoType>>() {
+            wrap_interval_month_day_nano_array(array)
         } else if let Some(array) = any.downcast_ref::<GenericStringArray<i32>>() {
             Ok(ArrayView::Utf8(BytesArrayView {
                 validity: get_bits_with_offset(array),
@@ -442,7 +517,10 @@ impl<'a> TryFrom<&'a dyn Array> for ArrayView<'a> {
                 validity: get_bits_with_offset(array),
                 offsets: array.value_offsets(),
                 meta: meta_from_field(field.as_ref().try_into()?),
-                element: Box::new(array.values().as_ref().try_into()?),
+                element: Box::new(
+                    ArrayView::try_from(array.values().as_ref())
+                        .map_err(|err| err.in_parent(ChildName(field.name())))?,
+                ),
             }))
         } else if let Some(array) = any.downcast_ref::<GenericListArray<i64>>() {
             let DataType::LargeList(field) = array.data_type() else {
@@ -452,7 +530,10 @@ impl<'a> TryFrom<&'a dyn Array> for ArrayView<'a> {
                 validity: get_bits_with_offset(array),
                 offsets: array.value_offsets(),
                 meta: meta_from_field(field.as_ref().try_into()?),
-                element: Box::new(array.values().as_ref().try_into()?),
+                element: Box::new(
+                    ArrayView::try_from(array.values().as_ref())
+                        .map_err(|err| err.in_parent(ChildName(field.name())))?,
+                ),
             }))
         } else if let Some(array) = any.downcast_ref::<FixedSizeListArray>() {
             let DataType::FixedSizeList(field, n) = array.data_type() else {
@@ -463,7 +544,10 @@ impl<'a> TryFrom<&'a dyn Array> for ArrayView<'a> {
                 n: *n,
                 validity: get_bits_with_offset(array),
                 meta: meta_from_field(field.as_ref().try_into()?),
-                element: Box::new(array.values().as_ref().try_into()?),
+                element: Box::new(
+                    ArrayView::try_from(array.values().as_ref())
+                        .map_err(|err| err.in_parent(ChildName(field.name())))?,
+                ),
             }))
         } else if let Some(array) = any.downcast_ref::<StructArray>() {
             let DataType::Struct(column_fields) = array.data_type() else {
@@ -472,8 +556,9 @@ impl<'a> TryFrom<&'a dyn Array> for ArrayView<'a> {
 
             let mut fields = Vec::new();
             for (field, array) in std::iter::zip(column_fields, array.columns()) {
-                let view = ArrayView::try_from(array.as_ref())?;
                 let meta = meta_from_field(Field::try_from(field.as_ref())?);
+                let view = ArrayView::try_from(array.as_ref())
+                    .map_err(|err| err.in_parent(ChildName(field.name())))?;
                 fields.push((view, meta));
             }
 
@@ -492,7 +577,10 @@ impl<'a> TryFrom<&'a dyn Array> for ArrayView<'a> {
                 validity: get_bits_with_offset(array),
                 offsets: array.value_offsets(),
                 meta: meta_from_field(Field::try_from(entries_field.as_ref())?),
-                element: Box::new(entries_array.try_into()?),
+                element: Box::new(
+                    ArrayView::try_from(entries_array)
+                        .map_err(|err| err.in_parent(ChildName(entries_field.name())))?,
+                ),
             }))
         } else if let Some(array) = any.downcast_ref::<DictionaryArray<UInt8Type>>() {
             wrap_dictionary_array::<UInt8Type>(array)
@@ -512,13 +600,17 @@ impl<'a> TryFrom<&'a dyn Array> for ArrayView<'a> {
             wrap_dictionary_array::<Int64Type>(array)
         } else if let Some(array) = any.downcast_ref::<UnionArray>() {
             let DataType::Union(union_fields, UnionMode::Dense) = array.data_type() else {
-                fail!("Invalid data type: only dense unions are supported");
+                return Err(unsupported_data_type(
+                    String::from("Union(Sparse)"),
+                    "only dense unions are supported",
+                ));
             };
 
             let mut fields = Vec::new();
             for (type_id, field) in union_fields.iter() {
                 let meta = meta_from_field(Field::try_from(field.as_ref())?);
-                let view: ArrayView = array.child(type_id).as_ref().try_into()?;
+                let view = ArrayView::try_from(array.child(type_id).as_ref())
+                    .map_err(|err| err.in_parent(ChildName(field.name())))?;
                 fields.push((type_id, view, meta));
             }
             let Some(offsets) = array.offsets() else {
@@ -531,10 +623,10 @@ impl<'a> TryFrom<&'a dyn Array> for ArrayView<'a> {
                 fields,
             }))
         } else {
-            fail!(
-                "Cannot build an array view for {dt}",
-                dt = array.data_type()
-            );
+            Err(unsupported_data_type(
+                array.data_type().to_string(),
+                "cannot build an array view",
+            ))
         }
     }
 }
@@ -577,7 +669,46 @@ fn bytes_into_data<O: ArrowNativeType>(
     )?)
 }
 
-fn list_into_data<O: ArrowNativeType>(
+#[cfg(has_arrow_view_support)]
+fn utf8_view_into_data(
+    offsets: Vec<i64>,
+    data: Vec<u8>,
+    validity: Option<Vec<u8>>,
+) -> Result<ArrayData> {
+    use crate::{
+        _impl::arrow::_raw::array::builder::StringViewBuilder,
+        internal::utils::array_ext::get_bit_buffer,
+    };
+
+    let len = offsets.len().saturating_sub(1);
+    let mut builder = StringViewBuilder::with_capacity(len);
+    for (idx, window) in offsets.windows(2).enumerate() {
+        if let Some(validity) = &validity {
+            if !get_bit_buffer(validity, 0, idx)? {
+                builder.append_null();
+                continue;
+            }
+        }
+        let start = usize::try_from(window[0])?;
+        let end = usize::try_from(window[1])?;
+        let Some(value) = data.get(start..end) else {
+            fail!("Invalid Utf8View array: offsets out of bounds");
+        };
+        builder.append_value(std::str::from_utf8(value)?);
+    }
+    Ok(builder.finish().into_data())
+}
+
+#[cfg(not(has_arrow_view_support))]
+fn utf8_view_into_data(
+    _offsets: Vec<i64>,
+    _data: Vec<u8>,
+    _validity: Option<Vec<u8>>,
+) -> Result<ArrayData> {
+    fail!("Utf8View is not supported by the selected arrow version")
+}
+
+pub(crate) fn list_into_data<O: ArrowNativeType>(
     data_type: DataType,
     len: usize,
     offsets: Vec<O>,
@@ -621,6 +752,40 @@ pub fn wrap_fixed_size_binary_array(_array: &FixedSizeBinaryArray) -> Result<Arr
     fail!("FixedSizeBinary arrays are not supported for arrow<=46");
 }
 
+#[cfg(has_arrow_interval_struct_support)]
+pub fn wrap_interval_day_time_array(
+    array: &PrimitiveArray<IntervalDayTimeType>,
+) -> Result<ArrayView<'_>> {
+    Ok(ArrayView::IntervalDayTime(PrimitiveArrayView {
+        validity: get_bits_with_offset(array),
+        values: bytemuck::try_cast_slice(array.values().inner().as_slice())?,
+    }))
+}
+
+#[cfg(not(has_arrow_interval_struct_support))]
+pub fn wrap_interval_day_time_array(
+    _array: &PrimitiveArray<IntervalDayTimeType>,
+) -> Result<ArrayView<'_>> {
+    fail!("Interval(DayTime) arrays are not supported for arrow<52");
+}
+
+#[cfg(has_arrow_interval_struct_support)]
+pub fn wrap_interval_month_day_nano_array(
+    array: &PrimitiveArray<IntervalMonthDayNanoType>,
+) -> Result<ArrayView<'_>> {
+    Ok(ArrayView::IntervalMonthDayNano(PrimitiveArrayView {
+        validity: get_bits_with_offset(array),
+        values: bytemuck::try_cast_slice(array.values().inner().as_slice())?,
+    }))
+}
+
+#[cfg(not(has_arrow_interval_struct_support))]
+pub fn wrap_interval_month_day_nano_array(
+    _array: &PrimitiveArray<IntervalMonthDayNanoType>,
+) -> Result<ArrayView<'_>> {
+    fail!("Interval(MonthDayNano) arrays are not supported for arrow<52");
+}
+
 fn get_bits_with_offset(array: &dyn Array) -> Option<BitsWithOffset<'_>> {
     let validity = array.nulls()?;
     Some(BitsWithOffset {
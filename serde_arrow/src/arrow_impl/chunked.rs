@@ -0,0 +1,129 @@
+use serde::Serialize;
+
+use crate::{
+    _impl::arrow::array::RecordBatch,
+    internal::{
+        array_builder::ArrayBuilder,
+        error::{fail, Result},
+    },
+};
+
+/// Support `arrow` (*requires one of the `arrow-*` features*)
+impl ArrayBuilder {
+    /// Serialize the items of an iterator into record batches of at most
+    /// `batch_size` rows (*requires one of the `arrow-*` features*)
+    ///
+    /// The items are pulled from the iterator lazily: a record batch is
+    /// emitted every `batch_size` items and only the current batch is
+    /// buffered. This allows to serialize iterators that do not fit into
+    /// memory. Items pushed before calling this function are included in the
+    /// first batch. Only the last batch may contain less than `batch_size`
+    /// rows. Each batch is built with [`ArrayBuilder::to_record_batch`],
+    /// which resets the buffers of the builder, but keeps its schema and
+    /// options.
+    ///
+    /// A batch size of zero is an error, reported when the iterator is
+    /// consumed. After an error, the iterator yields no further batches and
+    /// the items of the current batch remain in the builder.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::FieldRef;
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::{ArrayBuilder, schema::{SchemaLike, TracingOptions}};
+    ///
+    /// ##[derive(Serialize, Deserialize)]
+    /// struct Record {
+    ///     a: u32,
+    /// }
+    ///
+    /// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+    /// let mut builder = ArrayBuilder::from_arrow(&fields)?;
+    ///
+    /// let items = (0..5).map(|a| Record { a });
+    /// let mut num_rows = Vec::new();
+    /// for batch in builder.to_record_batch_iter(items, 2) {
+    ///     num_rows.push(batch?.num_rows());
+    /// }
+    ///
+    /// assert_eq!(num_rows, vec![2, 2, 1]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_record_batch_iter<I>(
+        &mut self,
+        items: I,
+        batch_size: usize,
+    ) -> RecordBatchIter<'_, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        RecordBatchIter {
+            builder: self,
+            items: items.into_iter(),
+            batch_size,
+            exhausted: false,
+            finished: false,
+        }
+    }
+}
+
+/// An iterator of record batches serialized from an iterator of items
+///
+/// See [`ArrayBuilder::to_record_batch_iter`].
+#[derive(Debug)]
+pub struct RecordBatchIter<'a, I> {
+    builder: &'a mut ArrayBuilder,
+    items: I,
+    batch_size: usize,
+    /// Whether all items were pulled from the iterator
+    exhausted: bool,
+    /// Whether no further batches are emitted
+    finished: bool,
+}
+
+impl<I> RecordBatchIter<'_, I>
+where
+    I: Iterator,
+    I::Item: Serialize,
+{
+    fn next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        if self.batch_size == 0 {
+            fail!("The batch size must be positive");
+        }
+
+        while self.builder.num_rows() < self.batch_size {
+            let Some(item) = self.items.next() else {
+                self.exhausted = true;
+                break;
+            };
+            self.builder.push(item)?;
+        }
+
+        if self.builder.num_rows() == 0 {
+            return Ok(None);
+        }
+        Ok(Some(self.builder.to_record_batch()?))
+    }
+}
+
+impl<I> Iterator for RecordBatchIter<'_, I>
+where
+    I: Iterator,
+    I::Item: Serialize,
+{
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let res = self.next_batch();
+        if res.is_err() || self.exhausted {
+            self.finished = true;
+        }
+        res.transpose()
+    }
+}
@@ -0,0 +1,250 @@
+//! Concatenate record batches with differing schemas
+use std::{collections::HashMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{
+        _raw::{array::new_null_array, data::transform::MutableArrayData},
+        array::{make_array, Array, ArrayRef, RecordBatch, RecordBatchOptions},
+        datatypes::{DataType, Field, FieldRef, Schema},
+    },
+    internal::{
+        error::{fail, Result},
+        utils::Items,
+    },
+};
+
+use super::api::{from_arrow, to_arrow};
+
+pub fn concat_batches_with_union_schema(batches: &[RecordBatch]) -> Result<RecordBatch> {
+    if batches.is_empty() {
+        fail!("Cannot concat batches: no batches given");
+    }
+
+    let fields = union_fields(batches)?;
+    let num_rows = batches.iter().map(RecordBatch::num_rows).sum();
+
+    let mut columns = Vec::new();
+    for field in &fields {
+        let mut parts = Vec::new();
+        for batch in batches {
+            parts.push(align_column(batch, field)?);
+        }
+
+        let data = parts.iter().map(|part| part.to_data()).collect::<Vec<_>>();
+        let mut concat =
+            MutableArrayData::new(data.iter().collect(), field.is_nullable(), num_rows);
+        for (idx, part) in parts.iter().enumerate() {
+            concat.extend(idx, 0, part.len());
+        }
+        columns.push(make_array(concat.freeze()));
+    }
+
+    let schema = Schema::new(fields);
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::new(schema),
+        columns,
+        &options,
+    )?)
+}
+
+/// Collect the fields of all batches in order of their first occurrence
+///
+/// Fields missing in any batch are nullable, fields with differing types use
+/// the promoted type.
+fn union_fields(batches: &[RecordBatch]) -> Result<Vec<FieldRef>> {
+    let mut fields: Vec<Field> = Vec::new();
+    let mut occurrences: Vec<usize> = Vec::new();
+    let mut positions = HashMap::new();
+
+    for batch in batches {
+        let schema = batch.schema();
+        for (idx, field) in schema.fields().iter().enumerate() {
+            if schema.fields()[..idx]
+                .iter()
+                .any(|prev| prev.name() == field.name())
+            {
+                fail!(
+                    "Cannot concat batches: duplicate column {name:?}",
+                    name = field.name(),
+                );
+            }
+
+            let Some(&pos) = positions.get(field.name()) else {
+                positions.insert(field.name().to_owned(), fields.len());
+                fields.push(field.as_ref().clone());
+                occurrences.push(1);
+                continue;
+            };
+
+            let prev = &fields[pos];
+            let Some(data_type) = promote(prev.data_type(), field.data_type()) else {
+                fail!(
+                    "Cannot concat batches: column {name:?} has incompatible types {prev} and {curr}",
+                    name = field.name(),
+                    prev = prev.data_type(),
+                    curr = field.data_type(),
+                );
+            };
+            let nullable = prev.is_nullable() || field.is_nullable();
+            fields[pos] = prev
+                .clone()
+                .with_data_type(data_type)
+                .with_nullable(nullable);
+            occurrences[pos] += 1;
+        }
+    }
+
+    Ok(fields
+        .into_iter()
+        .zip(occurrences)
+        .map(|(field, occurrences)| {
+            let nullable = field.is_nullable()
+                || occurrences < batches.len()
+                || matches!(field.data_type(), DataType::Null);
+            Arc::new(field.with_nullable(nullable))
+        })
+        .collect())
+}
+
+/// The common type of two column types, if any
+fn promote(left: &DataType, right: &DataType) -> Option<DataType> {
+    use DataType as T;
+
+    fn signed_rank(data_type: &DataType) -> Option<u8> {
+        match data_type {
+            T::Int8 => Some(1),
+            T::Int16 => Some(2),
+            T::Int32 => Some(3),
+            T::Int64 => Some(4),
+            _ => None,
+        }
+    }
+
+    fn unsigned_rank(data_type: &DataType) -> Option<u8> {
+        match data_type {
+            T::UInt8 => Some(1),
+            T::UInt16 => Some(2),
+            T::UInt32 => Some(3),
+            T::UInt64 => Some(4),
+            _ => None,
+        }
+    }
+
+    fn float_rank(data_type: &DataType) -> Option<u8> {
+        match data_type {
+            T::Float16 => Some(1),
+            T::Float32 => Some(2),
+            T::Float64 => Some(3),
+            _ => None,
+        }
+    }
+
+    fn signed(rank: u8) -> DataType {
+        match rank {
+            1 => T::Int8,
+            2 => T::Int16,
+            3 => T::Int32,
+            _ => T::Int64,
+        }
+    }
+
+    fn unsigned(rank: u8) -> DataType {
+        match rank {
+            1 => T::UInt8,
+            2 => T::UInt16,
+            3 => T::UInt32,
+            _ => T::UInt64,
+        }
+    }
+
+    fn float(rank: u8) -> DataType {
+        match rank {
+            1 => T::Float16,
+            2 => T::Float32,
+            _ => T::Float64,
+        }
+    }
+
+    fn is_integer(data_type: &DataType) -> bool {
+        signed_rank(data_type).is_some() || unsigned_rank(data_type).is_some()
+    }
+
+    if left == right {
+        return Some(left.clone());
+    }
+
+    match (left, right) {
+        (T::Null, other) | (other, T::Null) => Some(other.clone()),
+        (T::Utf8 | T::LargeUtf8, T::Utf8 | T::LargeUtf8) => Some(T::LargeUtf8),
+        (T::Binary | T::LargeBinary, T::Binary | T::LargeBinary) => Some(T::LargeBinary),
+        _ => {
+            if let (Some(l), Some(r)) = (signed_rank(left), signed_rank(right)) {
+                Some(signed(l.max(r)))
+            } else if let (Some(l), Some(r)) = (unsigned_rank(left), unsigned_rank(right)) {
+                Some(unsigned(l.max(r)))
+            } else if let (Some(s), Some(u)) = (signed_rank(left), unsigned_rank(right)) {
+                Some(signed(s.max(u + 1)))
+            } else if let (Some(u), Some(s)) = (unsigned_rank(left), signed_rank(right)) {
+                Some(signed(s.max(u + 1)))
+            } else if let (Some(l), Some(r)) = (float_rank(left), float_rank(right)) {
+                Some(float(l.max(r)))
+            } else if (float_rank(left).is_some() && is_integer(right))
+                || (is_integer(left) && float_rank(right).is_some())
+            {
+                Some(T::Float64)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Get the column of the batch with the type of the union field
+fn align_column(batch: &RecordBatch, field: &FieldRef) -> Result<ArrayRef> {
+    let Some(column) = batch.column_by_name(field.name()) else {
+        return Ok(new_null_array(field.data_type(), batch.num_rows()));
+    };
+    if column.data_type() == field.data_type() {
+        return Ok(column.clone());
+    }
+    if matches!(column.data_type(), DataType::Null) {
+        return Ok(new_null_array(field.data_type(), batch.num_rows()));
+    }
+
+    // the builders of the promoted types accept the values of the source types
+    match column.data_type() {
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+            convert::<i64>(column, field)
+        }
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+            convert::<u64>(column, field)
+        }
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => convert::<f64>(column, field),
+        DataType::Utf8 | DataType::LargeUtf8 => convert::<String>(column, field),
+        DataType::Binary | DataType::LargeBinary => convert::<Vec<u8>>(column, field),
+        dt => fail!(
+            "Cannot concat batches: cannot convert column {name:?} from {dt}",
+            name = field.name(),
+        ),
+    }
+}
+
+/// Convert a column by round-tripping its values through `T`
+fn convert<T>(column: &ArrayRef, field: &FieldRef) -> Result<ArrayRef>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let source = Field::new("item", column.data_type().clone(), true);
+    let target = Field::new("item", field.data_type().clone(), true);
+
+    let Items(values): Items<Vec<Option<T>>> =
+        from_arrow(&vec![source], std::slice::from_ref(column))?;
+    let mut arrays = to_arrow(&vec![target], Items(values))?;
+    let Some(array) = arrays.pop() else {
+        fail!("Cannot concat batches: conversion did not produce an array");
+    };
+    Ok(array)
+}
@@ -0,0 +1,186 @@
+//! Flatten list of struct columns into child batches and collect them back
+use std::sync::Arc;
+
+use crate::{
+    _impl::arrow::{
+        _raw::data::transform::MutableArrayData,
+        array::{
+            make_array, Array, ArrayData, ArrayRef, GenericListArray, OffsetSizeTrait,
+            PrimitiveArray, RecordBatch, RecordBatchOptions, StructArray,
+        },
+        datatypes::{DataType, Field, Schema, UInt64Type},
+    },
+    internal::{
+        arrow::{ArrayView, FieldMeta, ListArrayView},
+        error::{fail, Result},
+        utils::{array_ext::get_bit_buffer, Offset},
+    },
+};
+
+use super::array::list_into_data;
+
+/// The name of the column with the parent row indices of exploded batches
+pub const PARENT_INDEX: &str = "parent_index";
+
+pub fn explode(batch: &RecordBatch, column: &str) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let Some((idx, _)) = schema.column_with_name(column) else {
+        fail!("Cannot explode column {column:?}: no such column");
+    };
+    let array = batch.column(idx);
+
+    let (ranges, metas) = match ArrayView::try_from(array.as_ref())? {
+        ArrayView::List(view) => child_ranges(column, view)?,
+        ArrayView::LargeList(view) => child_ranges(column, view)?,
+        _ => fail!("Cannot explode column {column:?}: expected a list of structs"),
+    };
+    let Some(columns) =
+        struct_columns::<i32>(array.as_ref()).or_else(|| struct_columns::<i64>(array.as_ref()))
+    else {
+        fail!("Cannot explode column {column:?}: expected a list of structs");
+    };
+
+    let num_rows = ranges.iter().map(|range| range.end - range.start).sum();
+    let mut parent_index = Vec::with_capacity(num_rows);
+    for range in &ranges {
+        let parent = u64::try_from(range.parent)?;
+        parent_index.resize(parent_index.len() + range.end - range.start, parent);
+    }
+
+    let mut fields = vec![Field::new(PARENT_INDEX, DataType::UInt64, false)];
+    let mut arrays: Vec<ArrayRef> =
+        vec![Arc::new(PrimitiveArray::<UInt64Type>::from(parent_index))];
+
+    for (child, meta) in std::iter::zip(columns, metas) {
+        if meta.name == PARENT_INDEX {
+            fail!("Cannot explode column {column:?}: the structs contain a field {PARENT_INDEX:?}");
+        }
+
+        let data = child.to_data();
+        let mut child = MutableArrayData::new(vec![&data], meta.nullable, num_rows);
+        for range in &ranges {
+            child.extend(0, range.start, range.end);
+        }
+        let child = make_array(child.freeze());
+
+        fields.push(
+            Field::new(meta.name, child.data_type().clone(), meta.nullable)
+                .with_metadata(meta.metadata),
+        );
+        arrays.push(child);
+    }
+
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::new(Schema::new(fields)),
+        arrays,
+        &options,
+    )?)
+}
+
+pub fn collect_exploded(batch: &RecordBatch, num_rows: usize) -> Result<ArrayRef> {
+    let schema = batch.schema();
+    let Some((index_idx, _)) = schema.column_with_name(PARENT_INDEX) else {
+        fail!("Cannot collect exploded batch: no column {PARENT_INDEX:?}");
+    };
+    let ArrayView::UInt64(parent_index) = ArrayView::try_from(batch.column(index_idx).as_ref())?
+    else {
+        fail!("Cannot collect exploded batch: column {PARENT_INDEX:?} must be of type UInt64");
+    };
+    if batch.column(index_idx).null_count() != 0 {
+        fail!("Cannot collect exploded batch: column {PARENT_INDEX:?} contains nulls");
+    }
+
+    let mut offsets = Vec::<i64>::with_capacity(num_rows + 1);
+    offsets.push(0);
+    for (pos, &parent) in parent_index.values.iter().enumerate() {
+        let parent = usize::try_from(parent)?;
+        if parent >= num_rows {
+            fail!("Cannot collect exploded batch: parent index {parent} is out of bounds for {num_rows} rows");
+        }
+        if parent + 1 < offsets.len() {
+            fail!("Cannot collect exploded batch: parent indices must be sorted");
+        }
+        while offsets.len() <= parent {
+            offsets.push(i64::try_form_usize(pos)?);
+        }
+    }
+    while offsets.len() <= num_rows {
+        offsets.push(i64::try_form_usize(parent_index.values.len())?);
+    }
+
+    let mut fields = Vec::new();
+    let mut children = Vec::new();
+    for (idx, field) in schema.fields().iter().enumerate() {
+        if idx == index_idx {
+            continue;
+        }
+        fields.push(
+            Field::new(field.name(), field.data_type().clone(), field.is_nullable())
+                .with_metadata(field.metadata().clone()),
+        );
+        children.push(batch.column(idx).to_data());
+    }
+
+    let struct_type = DataType::Struct(fields.into());
+    let struct_data = ArrayData::try_new(
+        struct_type.clone(),
+        batch.num_rows(),
+        None,
+        0,
+        vec![],
+        children,
+    )?;
+    let list_type = DataType::LargeList(Field::new("element", struct_type, false).into());
+    let data = list_into_data(list_type, num_rows, offsets, struct_data, None)?;
+    Ok(make_array(data))
+}
+
+/// The struct elements of a single parent row
+struct ChildRange {
+    parent: usize,
+    start: usize,
+    end: usize,
+}
+
+/// The ranges of the struct elements for each valid parent row together with
+/// the metadata of the struct fields
+fn child_ranges<O: Offset>(
+    column: &str,
+    view: ListArrayView<'_, O>,
+) -> Result<(Vec<ChildRange>, Vec<FieldMeta>)> {
+    let ArrayView::Struct(element) = *view.element else {
+        fail!("Cannot explode column {column:?}: expected a list of structs");
+    };
+
+    let mut ranges = Vec::new();
+    for (parent, window) in view.offsets.windows(2).enumerate() {
+        if let Some(validity) = &view.validity {
+            if !get_bit_buffer(validity.data, validity.offset, parent)? {
+                continue;
+            }
+        }
+        let start = window[0].try_into_usize()?;
+        let end = window[1].try_into_usize()?;
+        if start == end {
+            continue;
+        }
+        if let Some(validity) = &element.validity {
+            for idx in start..end {
+                if !get_bit_buffer(validity.data, validity.offset, idx)? {
+                    fail!("Cannot explode column {column:?}: null structs are not supported");
+                }
+            }
+        }
+        ranges.push(ChildRange { parent, start, end });
+    }
+
+    let metas = element.fields.into_iter().map(|(_, meta)| meta).collect();
+    Ok((ranges, metas))
+}
+
+fn struct_columns<O: OffsetSizeTrait>(array: &dyn Array) -> Option<Vec<ArrayRef>> {
+    let list = array.as_any().downcast_ref::<GenericListArray<O>>()?;
+    let element = list.values().as_any().downcast_ref::<StructArray>()?;
+    Some(element.columns().to_vec())
+}
@@ -0,0 +1,186 @@
+use std::io::Read;
+
+// arrow-version:replace: use arrow_ipc_{version}::{{reader::StreamReader, writer::StreamWriter}};
+use arrow_ipc_53::{reader::StreamReader, writer::StreamWriter};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    _impl::arrow::{
+        array::RecordBatch,
+        datatypes::{Schema, SchemaRef},
+    },
+    internal::error::{fail, Result},
+    ArrayBuilder,
+};
+
+use super::schema::IntoFields;
+
+/// The media type of the arrow IPC streaming format
+pub const IPC_STREAM_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+
+/// The default number of items per record batch of [`IpcResponseBody`]
+pub const DEFAULT_BATCH_SIZE: usize = 8192;
+
+/// Serialize items into a streaming body in the arrow IPC streaming format
+///
+/// The returned iterator yields the encoded bytes one record batch at a time,
+/// starting with the schema and ending with the end-of-stream marker. Only a
+/// single batch is built at a time. The chunks can be passed to any HTTP
+/// framework that accepts a stream of byte chunks, e.g., via
+/// `axum::body::Body::from_stream(futures::stream::iter(body))`. The content
+/// type of the response should be set to [`IPC_STREAM_CONTENT_TYPE`].
+///
+/// All record batches share the schema given by the fields. Therefore, fields
+/// with the [`AdaptiveString`][crate::schema::Strategy::AdaptiveString]
+/// strategy are built with the data type of the field.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     a: i32,
+///     b: String,
+/// }
+///
+/// let items = vec![
+///     Record { a: 1, b: String::from("foo") },
+///     Record { a: 2, b: String::from("bar") },
+/// ];
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+///
+/// let mut body = Vec::new();
+/// for chunk in serde_arrow::http::to_ipc_response_body(&fields, &items)?.with_batch_size(1) {
+///     body.extend(chunk?);
+/// }
+///
+/// let round_tripped: Vec<Record> = serde_arrow::http::from_ipc_body(body.as_slice())?;
+/// assert_eq!(round_tripped, items);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_ipc_response_body<T: Serialize>(
+    fields: impl IntoFields,
+    items: &[T],
+) -> Result<IpcResponseBody<'_, T>> {
+    let schema = Schema::new(
+        fields
+            .arrow_fields()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>(),
+    );
+    let mut builder = ArrayBuilder::from_arrow(fields)?;
+    // all batches of the stream share the schema
+    builder.disable_adaptive_strings();
+    Ok(IpcResponseBody {
+        builder,
+        schema: SchemaRef::new(schema),
+        items,
+        batch_size: DEFAULT_BATCH_SIZE,
+        writer: None,
+        finished: false,
+    })
+}
+
+/// Deserialize all items of a body in the arrow IPC streaming format
+///
+/// The body can be any reader, e.g., a blocking `reqwest` response or the
+/// bytes of an async response. Record batches are deserialized one at a time.
+/// See [`to_ipc_response_body`] for an example.
+pub fn from_ipc_body<T: DeserializeOwned>(body: impl Read) -> Result<Vec<T>> {
+    let reader = StreamReader::try_new(body, None)?;
+    let mut items = Vec::new();
+    for batch in reader {
+        let batch = batch?;
+        items.extend(crate::from_record_batch::<Vec<T>>(&batch)?);
+    }
+    Ok(items)
+}
+
+/// A streaming body of IPC encoded record batches
+///
+/// Each item is a chunk of bytes. See [`to_ipc_response_body`].
+pub struct IpcResponseBody<'a, T> {
+    builder: ArrayBuilder,
+    schema: SchemaRef,
+    items: &'a [T],
+    batch_size: usize,
+    writer: Option<StreamWriter<Vec<u8>>>,
+    finished: bool,
+}
+
+impl<T> std::fmt::Debug for IpcResponseBody<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IpcResponseBody")
+            .field("schema", &self.schema)
+            .field("remaining_items", &self.items.len())
+            .field("batch_size", &self.batch_size)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<'a, T: Serialize> IpcResponseBody<'a, T> {
+    /// Set the maximum number of items per record batch
+    ///
+    /// Defaults to [`DEFAULT_BATCH_SIZE`]. A batch size of zero is an error,
+    /// reported when the body is consumed.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// The schema of the streamed record batches
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn next_chunk(&mut self) -> Result<Vec<u8>> {
+        if self.batch_size == 0 {
+            fail!("The batch size of an IPC response body must be positive");
+        }
+
+        if self.items.is_empty() {
+            let mut writer = match self.writer.take() {
+                Some(writer) => writer,
+                None => StreamWriter::try_new(Vec::new(), &self.schema)?,
+            };
+            self.finished = true;
+            writer.finish()?;
+            return Ok(writer.into_inner()?);
+        }
+
+        let (chunk, rest) = self.items.split_at(self.batch_size.min(self.items.len()));
+        self.items = rest;
+        self.builder.extend(chunk)?;
+        let batch = RecordBatch::try_new(self.schema.clone(), self.builder.to_arrow()?)?;
+
+        let writer = match self.writer.take() {
+            Some(writer) => writer,
+            None => StreamWriter::try_new(Vec::new(), &self.schema)?,
+        };
+        let writer = self.writer.insert(writer);
+        writer.write(&batch)?;
+        Ok(std::mem::take(writer.get_mut()))
+    }
+}
+
+impl<'a, T: Serialize> Iterator for IpcResponseBody<'a, T> {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let res = self.next_chunk();
+        if res.is_err() {
+            self.finished = true;
+        }
+        Some(res)
+    }
+}
@@ -0,0 +1,251 @@
+use std::{fs::File, marker::PhantomData, path::Path, sync::Arc};
+
+// arrow-version:replace: use arrow_ipc_{version}::{{
+use arrow_ipc_53::{
+    convert::fb_to_schema,
+    reader::{read_footer_length, FileDecoder},
+    root_as_footer, Block,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    _impl::arrow::{buffer::Buffer, datatypes::SchemaRef},
+    internal::error::{fail, Error, Result},
+};
+
+/// Memory-map an arrow IPC file and deserialize its record batches lazily
+///
+/// The returned iterator yields the items of one record batch at a time. The
+/// arrays of the batches reference the mapped file directly and are
+/// deserialized with the borrowing deserializers, so only the current batch
+/// and its deserialized items are resident in memory. Dictionaries are read
+/// when opening the file.
+///
+/// On platforms other than unix, the file is read into memory instead.
+///
+/// # Safety
+///
+/// The file must not be modified or truncated, by this or any other process,
+/// while the iterator or any of the arrays read from it are alive. The arrays
+/// reference the mapped memory directly, modifications result in undefined
+/// behavior, e.g., reading changed data through immutable references or a
+/// `SIGBUS` signal when accessing truncated pages.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// # use arrow::datatypes::FieldRef;
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_arrow::schema::{SchemaLike, TracingOptions};
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     a: i32,
+///     b: String,
+/// }
+///
+/// # let path = std::env::temp_dir().join("serde_arrow_ipc_doctest.arrow");
+/// # let items = vec![Record { a: 1, b: String::from("foo") }];
+/// # let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// # let batch = serde_arrow::to_record_batch(&fields, &items)?;
+/// # let file = std::fs::File::create(&path).unwrap();
+// arrow-version:replace: /// # let mut writer = arrow_ipc_{version}::writer::FileWriter::try_new(file, &batch.schema()).unwrap();
+/// # let mut writer = arrow_ipc_53::writer::FileWriter::try_new(file, &batch.schema()).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.finish().unwrap();
+/// # drop(writer);
+/// // SAFETY: the file is not modified while it is read
+/// for batch in unsafe { serde_arrow::ipc::open_mmap::<Record>(&path)? } {
+///     for record in batch? {
+///         println!("{record:?}");
+///     }
+/// }
+/// # std::fs::remove_file(&path).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub unsafe fn open_mmap<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<IpcBatches<T>> {
+    let path = path.as_ref();
+    let file = File::open(path)
+        .map_err(|err| Error::custom_from(format!("Cannot open {path:?}: {err}"), err))?;
+    // SAFETY: the caller guarantees that the file is not modified
+    let buffer = unsafe { map_file(&file) }
+        .map_err(|err| Error::custom_from(format!("Cannot map {path:?}: {err}"), err))?;
+    IpcBatches::new(buffer)
+}
+
+/// A lazy iterator over the deserialized record batches of an IPC file
+///
+/// Each item contains the deserialized records of a single batch. See
+/// [`open_mmap`].
+pub struct IpcBatches<T> {
+    buffer: Buffer,
+    schema: SchemaRef,
+    decoder: FileDecoder,
+    blocks: std::vec::IntoIter<Block>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> std::fmt::Debug for IpcBatches<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IpcBatches")
+            .field("schema", &self.schema)
+            .field("remaining_batches", &self.blocks.len())
+            .finish()
+    }
+}
+
+impl<T> IpcBatches<T> {
+    fn new(buffer: Buffer) -> Result<Self> {
+        let Some(trailer_start) = buffer.len().checked_sub(10) else {
+            fail!("Invalid IPC file: the file is too short");
+        };
+        let mut trailer = [0; 10];
+        trailer.copy_from_slice(&buffer[trailer_start..]);
+        let footer_len = read_footer_length(trailer)?;
+        let Some(footer_start) = trailer_start.checked_sub(footer_len) else {
+            fail!("Invalid IPC file: the footer exceeds the file");
+        };
+
+        let footer = match root_as_footer(&buffer[footer_start..trailer_start]) {
+            Ok(footer) => footer,
+            Err(err) => fail!("Invalid IPC file: cannot read the footer: {err}"),
+        };
+        let Some(schema) = footer.schema() else {
+            fail!("Invalid IPC file: the footer does not contain a schema");
+        };
+        let schema = SchemaRef::new(fb_to_schema(schema));
+
+        let mut decoder = FileDecoder::new(schema.clone(), footer.version());
+        for block in footer.dictionaries().iter().flatten() {
+            let data = block_data(&buffer, block)?;
+            decoder.read_dictionary(block, &data)?;
+        }
+
+        let blocks = footer
+            .recordBatches()
+            .map(|blocks| blocks.iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        Ok(Self {
+            buffer,
+            schema,
+            decoder,
+            blocks: blocks.into_iter(),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// The schema of the file
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for IpcBatches<T> {
+    type Item = Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let block = self.blocks.next()?;
+            let batch = block_data(&self.buffer, &block)
+                .and_then(|data| Ok(self.decoder.read_record_batch(&block, &data)?));
+            match batch {
+                Ok(Some(batch)) => return Some(crate::from_record_batch(&batch)),
+                Ok(None) => continue,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.blocks.len()))
+    }
+}
+
+fn block_data(buffer: &Buffer, block: &Block) -> Result<Buffer> {
+    let offset = usize::try_from(block.offset())?;
+    let len = usize::try_from(block.metaDataLength())? + usize::try_from(block.bodyLength())?;
+    match offset.checked_add(len) {
+        Some(end) if end <= buffer.len() => Ok(buffer.slice_with_length(offset, len)),
+        _ => fail!("Invalid IPC file: block at offset {offset} with length {len} exceeds the file"),
+    }
+}
+
+/// Map the file into memory
+///
+/// # Safety
+///
+/// The file must not be modified while the returned buffer or any buffer
+/// derived from it is alive.
+#[cfg(unix)]
+unsafe fn map_file(file: &File) -> std::io::Result<Buffer> {
+    use std::{os::unix::io::AsRawFd, ptr::NonNull};
+
+    let len = usize::try_from(file.metadata()?.len())
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+    if len == 0 {
+        return Ok(Buffer::from_vec(Vec::<u8>::new()));
+    }
+
+    // SAFETY: a read-only private mapping of a valid file descriptor. The
+    // mapping stays valid after the file is closed and is released by `Mmap`.
+    // The caller guarantees that the file is not modified
+    let ptr = unsafe {
+        libc::mmap(
+            std::ptr::null_mut(),
+            len,
+            libc::PROT_READ,
+            libc::MAP_PRIVATE,
+            file.as_raw_fd(),
+            0,
+        )
+    };
+    if ptr == libc::MAP_FAILED {
+        return Err(std::io::Error::last_os_error());
+    }
+    let mmap = Mmap { ptr, len };
+
+    let Some(data) = NonNull::new(ptr.cast::<u8>()) else {
+        return Err(std::io::Error::other("mmap returned a null pointer"));
+    };
+    // SAFETY: the region of `len` bytes starting at `data` is owned by `mmap`
+    Ok(unsafe { Buffer::from_custom_allocation(data, len, Arc::new(mmap)) })
+}
+
+/// Read the file into memory
+///
+/// # Safety
+///
+/// Always safe, the function is `unsafe` to match the unix implementation.
+#[cfg(not(unix))]
+unsafe fn map_file(file: &File) -> std::io::Result<Buffer> {
+    use std::io::Read;
+
+    let mut data = Vec::new();
+    (&*file).read_to_end(&mut data)?;
+    Ok(Buffer::from_vec(data))
+}
+
+/// A read-only memory mapping, unmapped on drop
+#[cfg(unix)]
+struct Mmap {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+// SAFETY: the mapping is read-only and not tied to a thread
+#[cfg(unix)]
+unsafe impl Send for Mmap {}
+
+#[cfg(unix)]
+unsafe impl Sync for Mmap {}
+
+#[cfg(unix)]
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` and `len` describe a mapping created by `map_file`
+        unsafe {
+            libc::munmap(self.ptr, self.len);
+        }
+    }
+}
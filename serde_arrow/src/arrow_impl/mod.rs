@@ -4,7 +4,16 @@
 //! `arrow` arrays to Rust objects is not yet supported.
 //!
 #![deny(missing_docs)]
+mod alignment;
 pub(crate) mod api;
 mod array;
-mod schema;
+pub(crate) mod chunked;
+mod concat;
+mod explode;
+#[cfg(feature = "http")]
+pub(crate) mod http;
+#[cfg(feature = "ipc")]
+pub(crate) mod ipc;
+mod passthrough;
+pub(crate) mod schema;
 mod type_support;
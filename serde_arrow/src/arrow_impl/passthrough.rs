@@ -0,0 +1,207 @@
+//! Re-serialize record batches while passing through unchanged dictionary
+//! columns
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, RecordBatch, RecordBatchOptions},
+        datatypes::Schema,
+    },
+    internal::{
+        array_builder::ArrayBuilder,
+        arrow::{ArrayView, DataType, Field},
+        deserializer::Deserializer,
+        error::{fail, Result},
+        serialization::custom_builder::{CustomArrayBuilder, CustomStorage},
+        serializer::Serializer,
+        utils::Item,
+    },
+};
+
+use super::{api::with_array_types, schema::IntoFields};
+
+pub fn reserialize_record_batch<T: Serialize + ?Sized>(
+    source: &RecordBatch,
+    items: &T,
+) -> Result<RecordBatch> {
+    let schema = source.schema();
+    let fields = schema.fields().into_fields()?;
+
+    let mut checks = Vec::new();
+    let mut builder = ArrayBuilder::from_arrow(schema.fields())?;
+    for (idx, field) in fields.iter().enumerate() {
+        let DataType::Dictionary(_, _, _) = &field.data_type else {
+            continue;
+        };
+        let state = Arc::new(Mutex::new(CheckState::new(
+            field,
+            source.column(idx).as_ref(),
+        )?));
+        builder = builder.with_custom_builder(
+            format!("$.{name}", name = field.name),
+            UnchangedCheck(state.clone()),
+        );
+        checks.push((idx, state));
+    }
+
+    let mut builder = items.serialize(Serializer::new(builder))?.into_inner();
+    let num_rows = builder.num_rows();
+    let mut columns = builder.to_arrow()?;
+
+    for (idx, state) in checks {
+        let Ok(state) = state.lock() else {
+            fail!("Cannot re-serialize record batch: poisoned state");
+        };
+        if !state.unchanged {
+            continue;
+        }
+        // the serialized rows are a prefix of the source column
+        columns[idx] = if state.rows == source.num_rows() {
+            source.column(idx).clone()
+        } else {
+            source.column(idx).slice(0, state.rows)
+        };
+    }
+
+    let fields = with_array_types(schema.fields().iter().cloned(), &columns);
+    let schema = Schema::new(fields).with_metadata(schema.metadata().clone());
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::new(schema),
+        columns,
+        &options,
+    )?)
+}
+
+/// The values of a dictionary column of the source record batch, and whether
+/// the serialized values equal them so far
+#[derive(Debug)]
+struct CheckState {
+    keys: Vec<Option<usize>>,
+    values: Vec<Option<String>>,
+    /// The number of rows serialized
+    rows: usize,
+    /// Whether all rows serialized equal the rows of the source
+    unchanged: bool,
+}
+
+impl CheckState {
+    fn new(field: &Field, array: &dyn Array) -> Result<Self> {
+        let DataType::Dictionary(key_type, value_type, _) = &field.data_type else {
+            fail!("Expected a dictionary field");
+        };
+        let ArrayView::Dictionary(view) = ArrayView::try_from(array)? else {
+            fail!("Expected a dictionary array");
+        };
+
+        let keys = decode::<usize>(key_type, *view.indices)?;
+        let values = decode::<String>(value_type, *view.values)?;
+
+        Ok(Self {
+            keys,
+            values,
+            rows: 0,
+            unchanged: true,
+        })
+    }
+
+    /// The value of the source at the given row, `None` if the row does not
+    /// exist
+    fn source_value(&self, row: usize) -> Option<Option<&str>> {
+        let key = self.keys.get(row)?;
+        Some(key.and_then(|key| self.values.get(key)?.as_deref()))
+    }
+}
+
+fn decode<'de, T: Deserialize<'de>>(
+    data_type: &DataType,
+    view: ArrayView<'de>,
+) -> Result<Vec<Option<T>>> {
+    let field = Field {
+        name: String::from("item"),
+        data_type: data_type.clone(),
+        nullable: true,
+        metadata: Default::default(),
+    };
+    let items = Vec::<Item<Option<T>>>::deserialize(Deserializer::new(&[field], vec![view])?)?;
+    Ok(items.into_iter().map(|Item(value)| value).collect())
+}
+
+/// Compare the serialized values against the source and only write them, once
+/// they differ
+#[derive(Debug)]
+struct UnchangedCheck(Arc<Mutex<CheckState>>);
+
+impl UnchangedCheck {
+    /// Check whether the value equals the source, otherwise write all rows
+    /// skipped so far and return `false`
+    fn skip(
+        &mut self,
+        storage: &mut CustomStorage<'_>,
+        value: Option<Option<&str>>,
+    ) -> Result<bool> {
+        let Ok(mut state) = self.0.lock() else {
+            fail!("Cannot re-serialize record batch: poisoned state");
+        };
+        if !state.unchanged {
+            return Ok(false);
+        }
+
+        let row = state.rows;
+        if value.is_some() && state.source_value(row) == value {
+            state.rows += 1;
+            return Ok(true);
+        }
+
+        state.unchanged = false;
+        for prev in 0..row {
+            match state.source_value(prev).flatten() {
+                Some(prev) => storage.push(prev)?,
+                None => storage.push_none()?,
+            }
+        }
+        Ok(false)
+    }
+
+    fn push(&mut self, storage: &mut CustomStorage<'_>, value: Option<&str>) -> Result<()> {
+        if self.skip(storage, Some(value))? {
+            return Ok(());
+        }
+        match value {
+            Some(value) => storage.push(value),
+            None => storage.push_none(),
+        }
+    }
+}
+
+impl CustomArrayBuilder for UnchangedCheck {
+    fn clone_box(&self) -> Box<dyn CustomArrayBuilder> {
+        Box::new(UnchangedCheck(self.0.clone()))
+    }
+
+    fn serialize_default(&mut self, storage: &mut CustomStorage<'_>) -> Result<()> {
+        // defaults are never compared, as they depend on the nullability
+        self.skip(storage, None)?;
+        storage.push_default()
+    }
+
+    fn serialize_none(&mut self, storage: &mut CustomStorage<'_>) -> Result<()> {
+        self.push(storage, None)
+    }
+
+    fn serialize_str(&mut self, storage: &mut CustomStorage<'_>, v: &str) -> Result<()> {
+        self.push(storage, Some(v))
+    }
+
+    fn serialize_unit_variant(
+        &mut self,
+        storage: &mut CustomStorage<'_>,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.push(storage, Some(variant))
+    }
+}
@@ -1,19 +1,30 @@
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    _impl::arrow::datatypes::{
-        DataType as ArrowDataType, Field as ArrowField, FieldRef, TimeUnit as ArrowTimeUnit,
-        UnionMode as ArrowUnionMode,
+    _impl::arrow::{
+        array::RecordBatch,
+        datatypes::{
+            DataType as ArrowDataType, Field as ArrowField, FieldRef, Fields,
+            IntervalUnit as ArrowIntervalUnit, Schema, SchemaRef, TimeUnit as ArrowTimeUnit,
+            UnionMode as ArrowUnionMode,
+        },
     },
     internal::{
-        arrow::{DataType, Field, TimeUnit, UnionMode},
-        error::{fail, Error, Result},
-        schema::{validate_field, SchemaLike, Sealed, SerdeArrowSchema, TracingOptions},
+        arrow::{DataType, Field, IntervalUnit, TimeUnit, UnionMode},
+        error::{Error, Result},
+        schema::{
+            recover_strategies, validate_field, SchemaLike, Sealed, SerdeArrowSchema,
+            TracingOptions,
+        },
+        utils::{array_view_ext::unsupported_data_type, ChildName},
     },
 };
 
+#[cfg(not(has_arrow_view_support))]
+use crate::internal::{error::fail, schema::DataTypeDisplay};
+
 impl TryFrom<SerdeArrowSchema> for Vec<ArrowField> {
     type Error = Error;
 
@@ -55,7 +66,10 @@ impl<'a> TryFrom<&'a [ArrowField]> for SerdeArrowSchema {
 
     fn try_from(fields: &'a [ArrowField]) -> Result<Self> {
         Ok(Self {
-            fields: fields.iter().map(Field::try_from).collect::<Result<_>>()?,
+            fields: fields
+                .iter()
+                .map(|field| Field::try_from(field).map_err(|err| err.in_parent("$")))
+                .collect::<Result<_>>()?,
         })
     }
 }
@@ -67,12 +81,70 @@ impl<'a> TryFrom<&'a [FieldRef]> for SerdeArrowSchema {
         Ok(Self {
             fields: fields
                 .iter()
-                .map(|f| Field::try_from(f.as_ref()))
+                .map(|f| Field::try_from(f.as_ref()).map_err(|err| err.in_parent("$")))
                 .collect::<Result<_>>()?,
         })
     }
 }
 
+impl SerdeArrowSchema {
+    /// Determine the schema of an existing record batch (*requires one of the
+    /// `arrow-*` features*)
+    ///
+    /// The fields are derived from the schema of the batch. Strategies stored
+    /// in the field metadata are kept. For fields without a strategy, the
+    /// strategy is recovered from the data type, if possible:
+    ///
+    /// - `Date64` fields use
+    ///   [`UtcStrAsDate64`][crate::schema::Strategy::UtcStrAsDate64]
+    /// - `Struct` fields with children named `"0"`, `"1"`, ... use
+    ///   [`TupleAsStruct`][crate::schema::Strategy::TupleAsStruct]
+    ///
+    /// This way, batches written by other tools can be round-tripped without
+    /// writing the fields by hand.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::datatypes::FieldRef;
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, Strategy, STRATEGY_KEY};
+    ///
+    /// // a batch written by another tool without serde_arrow metadata
+    /// let fields = Vec::<FieldRef>::from_value(serde_json::json!([
+    ///     {"name": "date", "data_type": "Date64"},
+    /// ]))?;
+    /// let batch = serde_arrow::to_record_batch(&fields, &[(1_700_000_000_000_i64,)])?;
+    ///
+    /// let schema = SerdeArrowSchema::from_record_batch(&batch)?;
+    /// let fields = Vec::<FieldRef>::try_from(&schema)?;
+    /// assert_eq!(
+    ///     fields[0].metadata().get(STRATEGY_KEY),
+    ///     Some(&Strategy::UtcStrAsDate64.to_string()),
+    /// );
+    ///
+    /// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Record {
+    ///     date: String,
+    /// }
+    ///
+    /// let items: Vec<Record> = serde_arrow::from_record_batch(&batch)?;
+    /// let roundtripped = serde_arrow::to_record_batch(&fields, &items)?;
+    /// assert_eq!(roundtripped.column(0), batch.column(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_record_batch(batch: &RecordBatch) -> Result<Self> {
+        let mut fields = Vec::new();
+        for field in batch.schema().fields().iter() {
+            let mut field = Field::try_from(field.as_ref())?;
+            recover_strategies(&mut field)?;
+            fields.push(field);
+        }
+        Ok(Self { fields })
+    }
+}
+
 impl Sealed for Vec<ArrowField> {}
 
 /// Schema support for `Vec<arrow::datatype::Field>` (*requires one of the
@@ -109,11 +181,179 @@ impl SchemaLike for Vec<FieldRef> {
     }
 }
 
+/// A sealed trait for types that can be used as `arrow` fields (*requires one
+/// of the `arrow-*` features*)
+///
+/// It allows to pass the fields in the form most convenient for the caller to
+/// functions such as [`to_arrow`][crate::to_arrow] or
+/// [`to_record_batch`][crate::to_record_batch]. It is implemented for
+///
+/// - `&[FieldRef]`, `&[FieldRef; N]`, `&Vec<FieldRef>`, `&Fields`
+/// - `&Cow<[FieldRef]>`, `&Cow<Vec<FieldRef>>`, `&Arc<[FieldRef]>`, `&Box<[FieldRef]>`
+/// - `&[Field]`, `&[Field; N]`, `&Vec<Field>`
+/// - `&Schema`, `SchemaRef`
+/// - references to any of the above, e.g., `&&[FieldRef]` or `&SchemaRef`
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use std::sync::Arc;
+///
+/// use arrow::datatypes::{FieldRef, Schema};
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let items = vec![Record { a: 1 }, Record { a: 2 }];
+///
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// let schema = Arc::new(Schema::new(fields));
+///
+/// let batch = serde_arrow::to_record_batch(&schema, &items)?;
+/// let arrays = serde_arrow::to_arrow(schema.fields(), &items)?;
+/// # assert_eq!(batch.num_rows(), 2);
+/// # assert_eq!(arrays.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub trait IntoFields: Sealed {
+    #[doc(hidden)]
+    fn arrow_fields(&self) -> Vec<&ArrowField>;
+
+    #[doc(hidden)]
+    fn into_fields(self) -> Result<Vec<Field>>
+    where
+        Self: Sized,
+    {
+        self.arrow_fields()
+            .into_iter()
+            .map(|field| Field::try_from(field).map_err(|err| err.in_parent("$")))
+            .collect()
+    }
+
+    #[doc(hidden)]
+    fn into_fields_unchecked(self) -> Result<Vec<Field>>
+    where
+        Self: Sized,
+    {
+        self.arrow_fields()
+            .into_iter()
+            .map(|field| field_from_arrow_unchecked(field).map_err(|err| err.in_parent("$")))
+            .collect()
+    }
+}
+
+impl Sealed for &[FieldRef] {}
+
+impl IntoFields for &[FieldRef] {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        self.iter().map(|field| field.as_ref()).collect()
+    }
+}
+
+impl Sealed for &Vec<FieldRef> {}
+
+impl IntoFields for &Vec<FieldRef> {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        self.iter().map(|field| field.as_ref()).collect()
+    }
+}
+
+impl Sealed for &Fields {}
+
+impl IntoFields for &Fields {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        self.iter().map(|field| field.as_ref()).collect()
+    }
+}
+
+impl Sealed for &[ArrowField] {}
+
+impl IntoFields for &[ArrowField] {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        self.iter().collect()
+    }
+}
+
+impl Sealed for &Vec<ArrowField> {}
+
+impl IntoFields for &Vec<ArrowField> {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        self.iter().collect()
+    }
+}
+
+impl Sealed for &Schema {}
+
+impl IntoFields for &Schema {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        self.fields().iter().map(|field| field.as_ref()).collect()
+    }
+}
+
+impl Sealed for SchemaRef {}
+
+impl IntoFields for SchemaRef {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        self.fields().iter().map(|field| field.as_ref()).collect()
+    }
+}
+
+impl<const N: usize> Sealed for &[FieldRef; N] {}
+
+impl<const N: usize> IntoFields for &[FieldRef; N] {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        self.iter().map(|field| field.as_ref()).collect()
+    }
+}
+
+impl<const N: usize> Sealed for &[ArrowField; N] {}
+
+impl<const N: usize> IntoFields for &[ArrowField; N] {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        self.iter().collect()
+    }
+}
+
+/// Implement `IntoFields` for references to containers that deref to `[FieldRef]`
+macro_rules! impl_into_fields_for_field_ref_containers {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Sealed for $ty {}
+
+            impl IntoFields for $ty {
+                fn arrow_fields(&self) -> Vec<&ArrowField> {
+                    self.iter().map(|field| field.as_ref()).collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_fields_for_field_ref_containers!(
+    &Cow<'_, [FieldRef]>,
+    &Cow<'_, Vec<FieldRef>>,
+    &Arc<[FieldRef]>,
+    &Box<[FieldRef]>,
+);
+
+impl<T: IntoFields> Sealed for &T {}
+
+impl<T: IntoFields> IntoFields for &T {
+    fn arrow_fields(&self) -> Vec<&ArrowField> {
+        (**self).arrow_fields()
+    }
+}
+
 impl TryFrom<&ArrowDataType> for DataType {
     type Error = Error;
 
     fn try_from(value: &ArrowDataType) -> Result<DataType> {
-        use {ArrowDataType as AT, DataType as T, Field as F};
+        use {ArrowDataType as AT, DataType as T};
         match value {
             AT::Boolean => Ok(T::Boolean),
             AT::Null => Ok(T::Null),
@@ -133,6 +373,7 @@ impl TryFrom<&ArrowDataType> for DataType {
             AT::Date32 => Ok(T::Date32),
             AT::Date64 => Ok(T::Date64),
             AT::Decimal128(precision, scale) => Ok(T::Decimal128(*precision, *scale)),
+            AT::Decimal256(precision, scale) => Ok(T::Decimal256(*precision, *scale)),
             AT::Time32(unit) => Ok(T::Time32(
                 // only some arrow version implement Copy for unit
                 #[allow(clippy::clone_on_copy)]
@@ -154,19 +395,37 @@ impl TryFrom<&ArrowDataType> for DataType {
                 #[allow(clippy::clone_on_copy)]
                 unit.clone().into(),
             )),
+            AT::Interval(unit) => Ok(T::Interval(
+                // only some arrow version implement Copy for unit
+                #[allow(clippy::clone_on_copy)]
+                unit.clone().into(),
+            )),
             AT::Binary => Ok(T::Binary),
             AT::LargeBinary => Ok(T::LargeBinary),
             AT::FixedSizeBinary(n) => Ok(T::FixedSizeBinary(*n)),
-            AT::List(field) => Ok(T::List(F::try_from(field.as_ref())?.into())),
-            AT::LargeList(field) => Ok(T::LargeList(F::try_from(field.as_ref())?.into())),
-            AT::FixedSizeList(field, n) => {
-                Ok(T::FixedSizeList(F::try_from(field.as_ref())?.into(), *n))
+            AT::List(field) => Ok(T::List(field_from_arrow_unchecked(field)?.into())),
+            AT::LargeList(field) => Ok(T::LargeList(field_from_arrow_unchecked(field)?.into())),
+            #[cfg(has_arrow_view_support)]
+            AT::Utf8View => Ok(T::Utf8View),
+            #[cfg(has_arrow_view_support)]
+            AT::BinaryView => Ok(T::BinaryView),
+            #[cfg(has_arrow_view_support)]
+            AT::ListView(field) => Ok(T::ListView(field_from_arrow_unchecked(field)?.into())),
+            #[cfg(has_arrow_view_support)]
+            AT::LargeListView(field) => {
+                Ok(T::LargeListView(field_from_arrow_unchecked(field)?.into()))
+            }
+            AT::FixedSizeList(field, n) => Ok(T::FixedSizeList(
+                field_from_arrow_unchecked(field)?.into(),
+                *n,
+            )),
+            AT::Map(field, sorted) => {
+                Ok(T::Map(field_from_arrow_unchecked(field)?.into(), *sorted))
             }
-            AT::Map(field, sorted) => Ok(T::Map(F::try_from(field.as_ref())?.into(), *sorted)),
             AT::Struct(in_fields) => {
                 let mut fields = Vec::new();
                 for field in in_fields {
-                    fields.push(field.as_ref().try_into()?);
+                    fields.push(field_from_arrow_unchecked(field)?);
                 }
                 Ok(T::Struct(fields))
             }
@@ -178,11 +437,14 @@ impl TryFrom<&ArrowDataType> for DataType {
             AT::Union(in_fields, mode) => {
                 let mut fields = Vec::new();
                 for (type_id, field) in in_fields.iter() {
-                    fields.push((type_id, F::try_from(field.as_ref())?));
+                    fields.push((type_id, field_from_arrow_unchecked(field)?));
                 }
                 Ok(T::Union(fields, (*mode).into()))
             }
-            data_type => fail!("Unsupported arrow data type {data_type}"),
+            data_type => Err(unsupported_data_type(
+                data_type.to_string(),
+                "the arrow data type is not supported",
+            )),
         }
     }
 }
@@ -191,17 +453,26 @@ impl TryFrom<&ArrowField> for Field {
     type Error = Error;
 
     fn try_from(field: &ArrowField) -> Result<Self> {
-        let field = Field {
-            name: field.name().to_owned(),
-            data_type: DataType::try_from(field.data_type())?,
-            metadata: field.metadata().clone(),
-            nullable: field.is_nullable(),
-        };
+        let field = field_from_arrow_unchecked(field)?;
         validate_field(&field)?;
         Ok(field)
     }
 }
 
+/// Convert an arrow field without validating it
+///
+/// Nested fields are not validated either. Any validation is left to the
+/// caller.
+pub fn field_from_arrow_unchecked(field: &ArrowField) -> Result<Field> {
+    Ok(Field {
+        name: field.name().to_owned(),
+        data_type: DataType::try_from(field.data_type())
+            .map_err(|err| err.in_parent(ChildName(field.name())))?,
+        metadata: field.metadata().clone(),
+        nullable: field.is_nullable(),
+    })
+}
+
 impl TryFrom<&DataType> for ArrowDataType {
     type Error = Error;
 
@@ -226,6 +497,7 @@ impl TryFrom<&DataType> for ArrowDataType {
             T::Date32 => Ok(AT::Date32),
             T::Date64 => Ok(AT::Date64),
             T::Decimal128(precision, scale) => Ok(AT::Decimal128(*precision, *scale)),
+            T::Decimal256(precision, scale) => Ok(AT::Decimal256(*precision, *scale)),
             T::Time32(unit) => Ok(AT::Time32((*unit).into())),
             T::Time64(unit) => Ok(AT::Time64((*unit).into())),
             T::Timestamp(unit, tz) => Ok(AT::Timestamp(
@@ -233,11 +505,27 @@ impl TryFrom<&DataType> for ArrowDataType {
                 tz.as_ref().map(|s| s.to_string().into()),
             )),
             T::Duration(unit) => Ok(AT::Duration((*unit).into())),
+            T::Interval(unit) => Ok(AT::Interval((*unit).into())),
             T::Binary => Ok(AT::Binary),
             T::LargeBinary => Ok(AT::LargeBinary),
             T::FixedSizeBinary(n) => Ok(AT::FixedSizeBinary(*n)),
             T::List(field) => Ok(AT::List(AF::try_from(field.as_ref())?.into())),
             T::LargeList(field) => Ok(AT::LargeList(AF::try_from(field.as_ref())?.into())),
+            #[cfg(has_arrow_view_support)]
+            T::Utf8View => Ok(AT::Utf8View),
+            #[cfg(has_arrow_view_support)]
+            T::BinaryView => Ok(AT::BinaryView),
+            #[cfg(has_arrow_view_support)]
+            T::ListView(field) => Ok(AT::ListView(AF::try_from(field.as_ref())?.into())),
+            #[cfg(has_arrow_view_support)]
+            T::LargeListView(field) => Ok(AT::LargeListView(AF::try_from(field.as_ref())?.into())),
+            #[cfg(not(has_arrow_view_support))]
+            T::Utf8View | T::BinaryView | T::ListView(_) | T::LargeListView(_) => {
+                fail!(
+                    "{dt} is not supported by the selected arrow version",
+                    dt = DataTypeDisplay(value)
+                )
+            }
             T::FixedSizeList(field, n) => {
                 Ok(AT::FixedSizeList(AF::try_from(field.as_ref())?.into(), *n))
             }
@@ -309,4 +597,9 @@ impl_from_one_to_one!(
     [Second => Second, Millisecond => Millisecond, Microsecond => Microsecond, Nanosecond => Nanosecond]
 );
 
+impl_from_one_to_one!(
+    IntervalUnit => ArrowIntervalUnit,
+    [YearMonth => YearMonth, DayTime => DayTime, MonthDayNano => MonthDayNano]
+);
+
 impl_from_one_to_one!(UnionMode => ArrowUnionMode, [Sparse => Sparse, Dense => Dense]);
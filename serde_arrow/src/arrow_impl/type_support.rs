@@ -1,7 +1,4 @@
-use crate::_impl::arrow::{
-    datatypes::{Field as ArrowField, FieldRef},
-    error::ArrowError,
-};
+use crate::_impl::arrow::{datatypes::Field as ArrowField, error::ArrowError};
 
 use crate::internal::{
     arrow::Field,
@@ -38,10 +35,3 @@ macro_rules! impl_try_from_ext_type {
 impl_try_from_ext_type!(Bool8Field);
 impl_try_from_ext_type!(FixedShapeTensorField);
 impl_try_from_ext_type!(VariableShapeTensorField);
-
-pub fn fields_from_field_refs(fields: &[FieldRef]) -> Result<Vec<Field>> {
-    fields
-        .iter()
-        .map(|field| Field::try_from(field.as_ref()))
-        .collect()
-}
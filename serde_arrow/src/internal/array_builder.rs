@@ -1,7 +1,20 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
 use serde::Serialize;
 
 use crate::internal::{
-    arrow::Array, error::Result, schema::SerdeArrowSchema, serialization::OuterSequenceBuilder,
+    arrow::{Array, DataType, Field},
+    error::{fail, Result},
+    schema::{restore_nested_metadata, SerdeArrowSchema},
+    serialization::{
+        custom_builder::CustomArrayBuilder, deduplicator::Deduplicator, OuterSequenceBuilder,
+    },
+    serializer_options::{SerializerOptions, StringLengthLimit, UtcOffsetPolicy},
+    sort::{check_sort_keys, sort_arrays},
+    string_interner::StringInterner,
 };
 
 /// Construct arrays by pushing individual records
@@ -52,6 +65,7 @@ pub struct ArrayBuilder {
     pub(crate) builder: OuterSequenceBuilder,
     #[allow(unused)]
     pub(crate) schema: SerdeArrowSchema,
+    pub(crate) options: SerializerOptions,
 }
 
 impl ArrayBuilder {
@@ -60,8 +74,224 @@ impl ArrayBuilder {
         Ok(Self {
             builder: OuterSequenceBuilder::new(&schema)?,
             schema,
+            options: SerializerOptions::default(),
         })
     }
+
+    /// Configure the serialization, see [`SerializerOptions`]
+    pub fn with_options(mut self, options: SerializerOptions) -> Self {
+        if !options.strategy_overrides.is_empty() {
+            let overrides = &options.strategy_overrides;
+            if let Err(err) = self
+                .builder
+                .override_strategies(&self.schema.fields, overrides)
+            {
+                self.builder.set_failure(err);
+            }
+        }
+        let missing_as_default = options.missing_fields_as_default;
+        let interchange = options.map_struct_interchange;
+        let duplicate_fields = options.duplicate_fields;
+        self.builder.for_each_struct_mut(&mut |builder| {
+            builder.missing_as_default = missing_as_default;
+            builder.stringify_keys = interchange;
+            builder.duplicate_fields = duplicate_fields;
+        });
+        let sort_entries = options.deterministic || options.sort_map_keys;
+        let duplicate_keys = options.duplicate_map_keys;
+        self.builder.for_each_map_mut(&mut |builder| {
+            builder.sort_entries = sort_entries;
+            builder.duplicate_keys = duplicate_keys;
+            builder.structs_as_maps = interchange;
+        });
+        let (rounding, overflow) = (options.f16_rounding, options.f16_overflow);
+        self.builder.for_each_f16_mut(&mut |builder| {
+            builder.rounding = rounding;
+            builder.overflow = overflow;
+        });
+        let promote_keys = options.promote_dictionary_keys;
+        if let Err(err) = self.builder.for_each_dictionary_mut(&mut |builder| {
+            builder.promote_keys = promote_keys;
+            Ok(())
+        }) {
+            self.builder.set_failure(err);
+        }
+        if let Err(err) = self.set_string_limits(&options.max_string_lengths) {
+            self.builder.set_failure(err);
+        }
+        if let Err(err) = self.set_utc_offset_policies(&options.utc_offsets) {
+            self.builder.set_failure(err);
+        }
+        if let Err(err) = self.set_union_capacities(&options.union_capacities) {
+            self.builder.set_failure(err);
+        }
+        if let Some(max_depth) = options.max_depth {
+            if let Err(err) = check_max_depth(&self.schema.fields, max_depth) {
+                self.builder.set_failure(err);
+            }
+        }
+        if let Err(err) = check_sort_keys(&self.schema.fields, &options.sort_by) {
+            self.builder.set_failure(err);
+        }
+        self.builder.max_rows = options.max_rows;
+        self.builder.set_max_bytes(options.max_bytes);
+        self.builder.strict_nullability = options.strict_nullability;
+        match Deduplicator::new(&self.schema.fields, &options.deduplication) {
+            Ok(deduplicator) => self.builder.deduplicator = deduplicator,
+            Err(err) => self.builder.set_failure(err),
+        }
+        self.options = options;
+        self.arm_shape_check();
+        self
+    }
+
+    /// Serialize the field with the given path with a custom builder
+    ///
+    /// The path uses the format of error messages, e.g., `$.outer.inner`. The
+    /// builder for the data type of the field is used as the storage of the
+    /// custom builder, see [`CustomArrayBuilder`]. Paths not matching any
+    /// field result in an error when pushing items or building the arrays.
+    /// Custom builders must be set before any items are pushed and after
+    /// options with strategy overrides are applied.
+    pub fn with_custom_builder(
+        mut self,
+        path: impl Into<String>,
+        builder: impl CustomArrayBuilder + 'static,
+    ) -> Self {
+        let path = path.into();
+        if let Err(err) = self.builder.set_custom_builder(&path, Box::new(builder)) {
+            self.builder.set_failure(err);
+        }
+        self
+    }
+
+    fn set_string_limits(&mut self, limits: &BTreeMap<String, StringLengthLimit>) -> Result<()> {
+        for (path, limit) in limits {
+            if let Err(err) = limit.check() {
+                fail!(
+                    "Cannot limit the string length of {path:?}: {msg}",
+                    msg = err.message()
+                );
+            }
+        }
+
+        let mut applied = BTreeSet::new();
+        self.builder.for_each_string_limit_mut(&mut |path, limit| {
+            if let Some((path, new_limit)) = limits.get_key_value(path) {
+                applied.insert(path.as_str());
+                *limit = Some(new_limit.clone());
+            } else {
+                *limit = None;
+            }
+        });
+
+        if let Some(path) = limits.keys().find(|path| !applied.contains(path.as_str())) {
+            fail!("Cannot limit the string length of {path:?}: no string field with this path");
+        }
+        Ok(())
+    }
+
+    fn set_utc_offset_policies(
+        &mut self,
+        policies: &BTreeMap<String, UtcOffsetPolicy>,
+    ) -> Result<()> {
+        #[allow(unused_mut)]
+        let mut applied = BTreeSet::<&str>::new();
+        #[cfg(feature = "types-temporal")]
+        self.builder.for_each_date64_mut(&mut |builder| {
+            let policy = match policies.get_key_value(builder.get_path()) {
+                Some((path, policy)) if builder.utc => {
+                    applied.insert(path.as_str());
+                    *policy
+                }
+                _ => UtcOffsetPolicy::Normalize,
+            };
+            builder.offset_policy = policy;
+        });
+
+        if let Some(path) = policies
+            .keys()
+            .find(|path| !applied.contains(path.as_str()))
+        {
+            fail!("Cannot set the UTC offset policy of {path:?}: no UTC timestamp field with this path");
+        }
+        Ok(())
+    }
+
+    fn set_union_capacities(
+        &mut self,
+        capacities: &BTreeMap<String, BTreeMap<String, usize>>,
+    ) -> Result<()> {
+        #[allow(unused_mut)]
+        let mut applied = BTreeSet::<&str>::new();
+        #[allow(unused_mut)]
+        let mut unknown_variant = None::<(&String, &String)>;
+        #[cfg(feature = "types-union")]
+        self.builder.for_each_union_mut(&mut |builder| {
+            let Some((path, variants)) = capacities.get_key_value(builder.get_path()) else {
+                return;
+            };
+            applied.insert(path.as_str());
+
+            let mut hints = vec![0; builder.fields.len()];
+            for (variant, capacity) in variants {
+                match builder
+                    .fields
+                    .iter()
+                    .position(|(_, meta)| meta.name == *variant)
+                {
+                    Some(idx) => hints[idx] = *capacity,
+                    None => unknown_variant = Some((path, variant)),
+                }
+            }
+            builder.set_capacities(hints);
+        });
+
+        if let Some((path, variant)) = unknown_variant {
+            fail!("Cannot set the capacity of variant {variant:?} of {path:?}: no variant with this name");
+        }
+        if let Some(path) = capacities
+            .keys()
+            .find(|path| !applied.contains(path.as_str()))
+        {
+            fail!("Cannot set the union capacities of {path:?}: no union field with this path");
+        }
+        Ok(())
+    }
+
+    fn arm_shape_check(&mut self) {
+        if self.options.strict_schema {
+            self.builder.check_next_item(self.schema.fields.clone());
+        }
+    }
+}
+
+/// Check that no field is nested deeper than `max_depth`
+fn check_max_depth(fields: &[Field], max_depth: usize) -> Result<()> {
+    fn check(path: String, field: &Field, depth: usize, max_depth: usize) -> Result<()> {
+        if depth > max_depth {
+            fail!("The field {path} exceeds the maximum nesting depth of {max_depth}");
+        }
+        let children: Vec<&Field> = match &field.data_type {
+            DataType::Struct(children) => children.iter().collect(),
+            DataType::List(child)
+            | DataType::LargeList(child)
+            | DataType::FixedSizeList(child, _)
+            | DataType::Map(child, _) => vec![child.as_ref()],
+            DataType::Union(children, _) => children.iter().map(|(_, child)| child).collect(),
+            _ => Vec::new(),
+        };
+        for child in children {
+            let child_path = format!("{path}.{name}", name = child.name);
+            check(child_path, child, depth + 1, max_depth)?;
+        }
+        Ok(())
+    }
+
+    for field in fields {
+        check(format!("$.{name}", name = field.name), field, 1, max_depth)?;
+    }
+    Ok(())
 }
 
 impl std::fmt::Debug for ArrayBuilder {
@@ -83,13 +313,220 @@ impl ArrayBuilder {
         self.builder.extend(items)
     }
 
+    /// Add `n` records with all fields set to null
+    ///
+    /// The nulls are written in bulk, i.e., validity bitmaps are cleared byte
+    /// by byte and the offsets of lists, maps and strings are extended at
+    /// once, e.g., to pad the rows of one source when assembling batches from
+    /// multiple sources as in an outer join. All top-level fields must be
+    /// nullable, otherwise an error is returned and no rows are added.
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use arrow::{array::Array, datatypes::FieldRef};
+    /// use serde::{Deserialize, Serialize};
+    /// use serde_arrow::{schema::{SchemaLike, TracingOptions}, ArrayBuilder};
+    ///
+    /// ##[derive(Serialize, Deserialize)]
+    /// struct Record {
+    ///     a: Option<u32>,
+    ///     b: Option<Vec<String>>,
+    /// }
+    ///
+    /// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+    /// let mut builder = ArrayBuilder::from_arrow(&fields)?;
+    /// builder.push(Record { a: Some(1), b: None })?;
+    /// builder.append_nulls(3)?;
+    ///
+    /// let batch = builder.to_record_batch()?;
+    /// assert_eq!(batch.num_rows(), 4);
+    /// assert_eq!(batch.column(0).null_count(), 3);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() {}
+    /// ```
+    pub fn append_nulls(&mut self, n: usize) -> Result<()> {
+        self.builder.append_nulls(n)
+    }
+
+    /// Keep the dictionaries of dictionary encoded string columns stable
+    ///
+    /// The dictionaries are seeded with the values of the interner and kept
+    /// when building arrays. Subsequent batches built with this builder share
+    /// the same mapping from strings to indices. See [`StringInterner`] for
+    /// details.
+    pub fn intern_strings(&mut self, interner: &StringInterner) -> Result<()> {
+        self.builder.for_each_dictionary_mut(&mut |builder| {
+            let values = interner.get(builder.get_path()).unwrap_or_default();
+            builder.intern(values)
+        })
+    }
+
+    /// Get the current dictionaries of dictionary encoded string columns
+    pub fn string_interner(&self) -> StringInterner {
+        let mut interner = StringInterner::new();
+        self.builder.for_each_dictionary(&mut |builder| {
+            interner
+                .dictionaries
+                .insert(builder.get_path().to_owned(), builder.dictionary_values());
+        });
+        interner
+    }
+
+    /// Get the number of values with non-zero UTC offsets, keyed by the path
+    /// of the field
+    ///
+    /// Only fields with the [`UtcOffsetPolicy::Count`] policy are included, see
+    /// [`SerializerOptions::utc_offsets`]. The counts include values of arrays
+    /// already built.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_arrow::{
+    ///     schema::{SchemaLike, SerdeArrowSchema},
+    ///     ArrayBuilder, SerializerOptions, UtcOffsetPolicy,
+    /// };
+    ///
+    /// let schema = SerdeArrowSchema::from_value(serde_json::json!([
+    ///     {"name": "ts", "data_type": "Timestamp(Second, Some(\"UTC\"))"},
+    /// ]))?;
+    /// let options = SerializerOptions::default().utc_offset_policy("$.ts", UtcOffsetPolicy::Count);
+    ///
+    /// let mut builder = ArrayBuilder::new(schema)?.with_options(options);
+    /// builder.push(("2024-09-30T12:30:00Z",))?;
+    /// builder.push(("2024-09-30T14:30:00+02:00",))?;
+    /// assert_eq!(builder.non_utc_offsets()["$.ts"], 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn non_utc_offsets(&self) -> BTreeMap<String, usize> {
+        #[allow(unused_mut)]
+        let mut counts = BTreeMap::new();
+        #[cfg(feature = "types-temporal")]
+        self.builder.for_each_date64(&mut |builder| {
+            if builder.utc && builder.offset_policy == UtcOffsetPolicy::Count {
+                counts.insert(builder.get_path().to_owned(), builder.non_utc_offsets);
+            }
+        });
+        counts
+    }
+
+    /// Get the number of rows added since the arrays were last built
+    pub fn num_rows(&self) -> usize {
+        self.builder.len()
+    }
+
+    /// Get the number of rows dropped as duplicates
+    ///
+    /// Rows are only dropped with
+    /// [`SerializerOptions::deduplication`][crate::SerializerOptions::deduplication].
+    /// The count includes rows dropped before arrays were built.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_arrow::{
+    ///     schema::{SchemaLike, SerdeArrowSchema},
+    ///     ArrayBuilder, Deduplication, SerializerOptions,
+    /// };
+    ///
+    /// let schema = SerdeArrowSchema::from_value(serde_json::json!([
+    ///     {"name": "id", "data_type": "U32"},
+    ///     {"name": "op", "data_type": "Utf8"},
+    /// ]))?;
+    /// let options = SerializerOptions::default().deduplication(Deduplication::Consecutive);
+    ///
+    /// let mut builder = ArrayBuilder::new(schema)?.with_options(options);
+    /// builder.push((1, "insert"))?;
+    /// builder.push((1, "insert"))?;
+    /// builder.push((1, "update"))?;
+    /// assert_eq!(builder.num_rows(), 2);
+    /// assert_eq!(builder.dropped_rows(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dropped_rows(&self) -> usize {
+        self.builder.dropped_rows()
+    }
+
+    /// Call `callback` after every `every` rows, e.g., to report progress
+    ///
+    /// The callback receives the total number of rows added to the builder,
+    /// including rows of arrays already built. It is called from within
+    /// [`push`][ArrayBuilder::push] and [`extend`][ArrayBuilder::extend] after
+    /// the row was written, and from
+    /// [`append_nulls`][ArrayBuilder::append_nulls] if the appended rows cross
+    /// a multiple of `every`. Rows that fail to serialize are not counted. A
+    /// value of zero for `every` disables the callback.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    /// use serde_arrow::{
+    ///     schema::{SchemaLike, SerdeArrowSchema},
+    ///     ArrayBuilder,
+    /// };
+    ///
+    /// let schema = SerdeArrowSchema::from_value(serde_json::json!([
+    ///     {"name": "a", "data_type": "U32"},
+    /// ]))?;
+    ///
+    /// let processed = Arc::new(AtomicUsize::new(0));
+    /// let reported = Arc::clone(&processed);
+    /// let mut builder = ArrayBuilder::new(schema)?.on_progress(100, move |rows| {
+    ///     reported.store(rows, Ordering::Relaxed);
+    /// });
+    ///
+    /// builder.extend(&(0..250).map(|a| (a,)).collect::<Vec<_>>())?;
+    /// assert_eq!(builder.num_rows(), 250);
+    /// assert_eq!(processed.load(Ordering::Relaxed), 200);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_progress<F>(mut self, every: usize, callback: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.builder.set_progress(every, Arc::new(callback));
+        self
+    }
+
     pub(crate) fn build_arrays(&mut self) -> Result<Vec<Array>> {
         let mut arrays = Vec::new();
-        for field in self.builder.take_records()? {
-            arrays.push(field.into_array()?);
+        for (builder, field) in std::iter::zip(self.builder.take_records()?, &self.schema.fields) {
+            let mut array = builder.into_array()?;
+            if !self.options.strategy_overrides.is_empty() {
+                restore_nested_metadata(&mut array, field);
+            }
+            arrays.push(array);
         }
+        if !self.options.sort_by.is_empty() {
+            arrays = sort_arrays(&self.schema.fields, arrays, &self.options.sort_by)?;
+        }
+        self.arm_shape_check();
         Ok(arrays)
     }
+
+    /// Build the strings of fields with the `AdaptiveString` strategy with the
+    /// data type of the field
+    #[cfg(feature = "http")]
+    pub(crate) fn disable_adaptive_strings(&mut self) {
+        self.builder
+            .for_each_large_utf8_mut(&mut |builder| builder.set_adaptive_offsets(false));
+    }
+
+    /// Discard all pushed items, keep the configuration and the interned
+    /// dictionaries
+    pub(crate) fn clear(&mut self) {
+        // builders with a configuration failure do not contain any items
+        let _ = self.builder.take_records();
+        self.arm_shape_check();
+    }
 }
 
 impl std::convert::AsRef<ArrayBuilder> for ArrayBuilder {
@@ -105,6 +542,6 @@ impl std::convert::AsMut<ArrayBuilder> for ArrayBuilder {
 }
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl AssertSendSync for ArrayBuilder {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ArrayBuilder>();
 };
@@ -1,6 +1,7 @@
 //! Owned versions of the different array types
 use std::collections::HashMap;
 
+use bytemuck::{Pod, Zeroable};
 use half::f16;
 
 use crate::internal::arrow::data_type::TimeUnit;
@@ -27,12 +28,18 @@ pub enum Array {
     Time64(TimeArray<i64>),
     Timestamp(TimestampArray),
     Duration(TimeArray<i64>),
+    IntervalYearMonth(PrimitiveArray<i32>),
+    IntervalDayTime(PrimitiveArray<DayTimeInterval>),
+    IntervalMonthDayNano(PrimitiveArray<MonthDayNanoInterval>),
     Utf8(BytesArray<i32>),
     LargeUtf8(BytesArray<i64>),
+    /// Strings buffered with offsets, converted into views when exported
+    Utf8View(BytesArray<i64>),
     Binary(BytesArray<i32>),
     LargeBinary(BytesArray<i64>),
     FixedSizeBinary(FixedSizeBinaryArray),
     Decimal128(DecimalArray<i128>),
+    Decimal256(DecimalArray<I256>),
     Struct(StructArray),
     List(ListArray<i32>),
     LargeList(ListArray<i64>),
@@ -40,6 +47,7 @@ pub enum Array {
     Dictionary(DictionaryArray),
     Map(ListArray<i32>),
     DenseUnion(DenseUnionArray),
+    SparseUnion(SparseUnionArray),
 }
 
 #[derive(Clone, Debug)]
@@ -64,12 +72,16 @@ pub enum ArrayView<'a> {
     Time64(TimeArrayView<'a, i64>),
     Timestamp(TimestampArrayView<'a>),
     Duration(TimeArrayView<'a, i64>),
+    IntervalYearMonth(PrimitiveArrayView<'a, i32>),
+    IntervalDayTime(PrimitiveArrayView<'a, DayTimeInterval>),
+    IntervalMonthDayNano(PrimitiveArrayView<'a, MonthDayNanoInterval>),
     Utf8(BytesArrayView<'a, i32>),
     LargeUtf8(BytesArrayView<'a, i64>),
     Binary(BytesArrayView<'a, i32>),
     LargeBinary(BytesArrayView<'a, i64>),
     FixedSizeBinary(FixedSizeBinaryArrayView<'a>),
     Decimal128(DecimalArrayView<'a, i128>),
+    Decimal256(DecimalArrayView<'a, I256>),
     Struct(StructArrayView<'a>),
     List(ListArrayView<'a, i32>),
     LargeList(ListArrayView<'a, i64>),
@@ -138,6 +150,10 @@ pub struct TimeArray<T> {
 
 #[derive(Debug, Clone)]
 pub struct TimeArrayView<'a, T> {
+    #[cfg_attr(
+        not(any(feature = "types-temporal", feature = "types-union")),
+        allow(dead_code)
+    )]
     pub unit: TimeUnit,
     pub validity: Option<BitsWithOffset<'a>>,
     pub values: &'a [T],
@@ -155,7 +171,15 @@ pub struct TimestampArray {
 #[derive(Debug, Clone)]
 
 pub struct TimestampArrayView<'a> {
+    #[cfg_attr(
+        not(any(feature = "types-temporal", feature = "types-union")),
+        allow(dead_code)
+    )]
     pub unit: TimeUnit,
+    #[cfg_attr(
+        not(any(feature = "types-temporal", feature = "types-union")),
+        allow(dead_code)
+    )]
     pub timezone: Option<String>,
     pub validity: Option<BitsWithOffset<'a>>,
     pub values: &'a [i64],
@@ -251,12 +275,54 @@ pub struct DecimalArray<T> {
 
 #[derive(Clone, Debug)]
 pub struct DecimalArrayView<'a, T> {
+    #[cfg_attr(not(feature = "types-union"), allow(dead_code))]
     pub precision: u8,
+    #[cfg_attr(
+        not(any(feature = "types-decimal", feature = "types-union")),
+        allow(dead_code)
+    )]
     pub scale: i8,
     pub validity: Option<BitsWithOffset<'a>>,
     pub values: &'a [T],
 }
 
+/// An interval of days and milliseconds, laid out as in arrow
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct DayTimeInterval {
+    pub days: i32,
+    pub milliseconds: i32,
+}
+
+// SAFETY: repr(C) struct of two i32 without padding
+unsafe impl Zeroable for DayTimeInterval {}
+unsafe impl Pod for DayTimeInterval {}
+
+/// An interval of months, days and nanoseconds, laid out as in arrow
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct MonthDayNanoInterval {
+    pub months: i32,
+    pub days: i32,
+    pub nanoseconds: i64,
+}
+
+// SAFETY: repr(C) struct of two i32 and one i64 without padding
+unsafe impl Zeroable for MonthDayNanoInterval {}
+unsafe impl Pod for MonthDayNanoInterval {}
+
+/// A 256 bit signed integer in two's complement, laid out as in arrow
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct I256 {
+    pub low: u128,
+    pub high: i128,
+}
+
+// SAFETY: repr(C) struct of one u128 and one i128 without padding
+unsafe impl Zeroable for I256 {}
+unsafe impl Pod for I256 {}
+
 #[derive(Clone, Debug)]
 pub struct DictionaryArray {
     pub indices: Box<Array>,
@@ -276,6 +342,13 @@ pub struct DenseUnionArray {
     pub fields: Vec<(i8, Array, FieldMeta)>,
 }
 
+/// A union array whose children have one value per element of the union
+#[derive(Clone, Debug)]
+pub struct SparseUnionArray {
+    pub types: Vec<i8>,
+    pub fields: Vec<(i8, Array, FieldMeta)>,
+}
+
 #[derive(Clone, Debug)]
 pub struct DenseUnionArrayView<'a> {
     pub types: &'a [i8],
@@ -30,8 +30,10 @@ pub enum DataType {
     Float64,
     Utf8,
     LargeUtf8,
+    Utf8View,
     Binary,
     LargeBinary,
+    BinaryView,
     FixedSizeBinary(i32),
     Date32,
     Date64,
@@ -39,21 +41,31 @@ pub enum DataType {
     Time32(TimeUnit),
     Time64(TimeUnit),
     Duration(TimeUnit),
+    Interval(IntervalUnit),
     Decimal128(u8, i8),
+    Decimal256(u8, i8),
     Struct(Vec<Field>),
     List(Box<Field>),
     LargeList(Box<Field>),
+    ListView(Box<Field>),
+    LargeListView(Box<Field>),
     FixedSizeList(Box<Field>, i32),
     Map(Box<Field>, bool),
     Dictionary(Box<DataType>, Box<DataType>, bool),
     Union(Vec<(i8, Field)>, UnionMode),
 }
 
+/// The unit of temporal values
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TimeUnit {
+    /// Seconds
     Second,
+    /// Milliseconds
     Millisecond,
+    /// Microseconds
     Microsecond,
+    /// Nanoseconds
     Nanosecond,
 }
 
@@ -82,6 +94,40 @@ impl std::str::FromStr for TimeUnit {
     }
 }
 
+/// The unit of interval values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntervalUnit {
+    /// A number of months
+    YearMonth,
+    /// A number of days and milliseconds
+    DayTime,
+    /// A number of months, days and nanoseconds
+    MonthDayNano,
+}
+
+impl std::fmt::Display for IntervalUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntervalUnit::YearMonth => write!(f, "YearMonth"),
+            IntervalUnit::DayTime => write!(f, "DayTime"),
+            IntervalUnit::MonthDayNano => write!(f, "MonthDayNano"),
+        }
+    }
+}
+
+impl std::str::FromStr for IntervalUnit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "YearMonth" => Ok(Self::YearMonth),
+            "DayTime" => Ok(Self::DayTime),
+            "MonthDayNano" => Ok(Self::MonthDayNano),
+            s => fail!("Invalid IntervalUnit: {s}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UnionMode {
     Sparse,
@@ -5,10 +5,11 @@ mod data_type;
 
 pub use array::{
     Array, ArrayView, BitsWithOffset, BooleanArray, BooleanArrayView, BytesArray, BytesArrayView,
-    DecimalArray, DecimalArrayView, DenseUnionArray, DenseUnionArrayView, DictionaryArray,
-    DictionaryArrayView, FieldMeta, FixedSizeBinaryArray, FixedSizeBinaryArrayView,
-    FixedSizeListArray, FixedSizeListArrayView, ListArray, ListArrayView, NullArray, NullArrayView,
-    PrimitiveArray, PrimitiveArrayView, StructArray, StructArrayView, TimeArray, TimeArrayView,
-    TimestampArray, TimestampArrayView,
+    DayTimeInterval, DecimalArray, DecimalArrayView, DenseUnionArray, DenseUnionArrayView,
+    DictionaryArray, DictionaryArrayView, FieldMeta, FixedSizeBinaryArray,
+    FixedSizeBinaryArrayView, FixedSizeListArray, FixedSizeListArrayView, ListArray, ListArrayView,
+    MonthDayNanoInterval, NullArray, NullArrayView, PrimitiveArray, PrimitiveArrayView,
+    SparseUnionArray, StructArray, StructArrayView, TimeArray, TimeArrayView, TimestampArray,
+    TimestampArrayView, I256,
 };
-pub use data_type::{DataType, Field, TimeUnit, UnionMode};
+pub use data_type::{DataType, Field, IntervalUnit, TimeUnit, UnionMode};
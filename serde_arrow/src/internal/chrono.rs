@@ -29,10 +29,12 @@ pub fn matches_naive_time(s: &str) -> bool {
 }
 
 /// Parse `s` as a span
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
 pub fn parse_span(s: &str) -> Result<Span<'_>> {
     parsing::match_span(s).into_result("Span")
 }
 
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
 impl<'a> parsing::Span<'a> {
     /// Convert the `Span` into an `i64`` with the given `unit`
     pub fn to_arrow_duration(&self, unit: TimeUnit) -> Result<i64> {
@@ -97,7 +99,85 @@ impl<'a> parsing::Span<'a> {
     }
 }
 
+/// The number of values of the given unit per second
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+fn units_per_second(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
+/// Convert whole seconds into a value of the given unit
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn seconds_to_arrow(seconds: i64, unit: TimeUnit) -> Result<i64> {
+    match seconds.checked_mul(units_per_second(unit)) {
+        Some(value) => Ok(value),
+        None => fail!("Cannot represent {seconds} seconds with {unit} resolution"),
+    }
+}
+
+/// Convert fractional seconds into a value of the given unit, rounded to the
+/// nearest value
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn float_seconds_to_arrow(seconds: f64, unit: TimeUnit) -> Result<i64> {
+    let value = (seconds * units_per_second(unit) as f64).round();
+    if !value.is_finite() || value < i64::MIN as f64 || value >= i64::MAX as f64 {
+        fail!("Cannot represent {seconds} seconds with {unit} resolution");
+    }
+    Ok(value as i64)
+}
+
+/// Parse whole or fractional seconds, e.g., `"12"` or `"-1.5"`, into a value
+/// of the given unit
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn parse_seconds(s: &str, unit: TimeUnit) -> Result<i64> {
+    if let Ok(seconds) = s.parse::<i64>() {
+        return seconds_to_arrow(seconds, unit);
+    }
+    match s.parse::<f64>() {
+        Ok(seconds) => float_seconds_to_arrow(seconds, unit),
+        Err(_) => fail!("Cannot parse {s:?} as seconds"),
+    }
+}
+
+/// Convert a value of the given unit into whole seconds, if it has no
+/// fractional part
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn arrow_to_whole_seconds(value: i64, unit: TimeUnit) -> Option<i64> {
+    let per_second = units_per_second(unit);
+    (value % per_second == 0).then_some(value / per_second)
+}
+
+/// Convert a value of the given unit into fractional seconds
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn arrow_to_float_seconds(value: i64, unit: TimeUnit) -> f64 {
+    value as f64 / units_per_second(unit) as f64
+}
+
+/// Format a value of the given unit as whole or fractional seconds, e.g.,
+/// `"12"` or `"-1.5"`
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn format_arrow_as_seconds(value: i64, unit: TimeUnit) -> String {
+    let per_second = units_per_second(unit).unsigned_abs();
+    let sign = if value < 0 { "-" } else { "" };
+    let value = value.unsigned_abs();
+    let (seconds, subsecond) = (value / per_second, value % per_second);
+    if subsecond == 0 {
+        return format!("{sign}{seconds}");
+    }
+    let width = per_second.ilog10() as usize;
+    let subsecond = format!("{subsecond:0width$}");
+    format!(
+        "{sign}{seconds}.{subsecond}",
+        subsecond = subsecond.trim_end_matches('0')
+    )
+}
+
 /// Format a duration in the given unit as a Span string
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
 pub fn format_arrow_duration_as_span(value: i64, unit: TimeUnit) -> String {
     let (value, sign) = if value < 0 {
         (-value, "-")
@@ -125,6 +205,129 @@ pub fn format_arrow_duration_as_span(value: i64, unit: TimeUnit) -> String {
     }
 }
 
+/// Format a duration in the given unit as a humantime string, e.g., `1m 30s`
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn format_arrow_duration_as_humantime(value: i64, unit: TimeUnit) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let value = value.unsigned_abs();
+    let (seconds, subsecond_nanos) = match unit {
+        TimeUnit::Second => (value, 0),
+        TimeUnit::Millisecond => (value / 1_000, (value % 1_000) * 1_000_000),
+        TimeUnit::Microsecond => (value / 1_000_000, (value % 1_000_000) * 1_000),
+        TimeUnit::Nanosecond => (value / 1_000_000_000, value % 1_000_000_000),
+    };
+
+    let parts = [
+        (seconds / 86_400, "d"),
+        (seconds / 3_600 % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+        (subsecond_nanos / 1_000_000, "ms"),
+        (subsecond_nanos / 1_000 % 1_000, "us"),
+        (subsecond_nanos % 1_000, "ns"),
+    ];
+    let parts = parts
+        .iter()
+        .filter(|(value, _)| *value != 0)
+        .map(|(value, suffix)| format!("{value}{suffix}"))
+        .collect::<Vec<_>>();
+
+    if parts.is_empty() {
+        String::from("0s")
+    } else {
+        format!("{sign}{parts}", parts = parts.join(" "))
+    }
+}
+
+/// Parse the string representation of the `time` crate's datetimes
+///
+/// Both `PrimitiveDateTime` (`2024-09-30 12:30:00.0`) and `OffsetDateTime`
+/// (`2024-09-30 12:30:00.0 +02:00:00`) are supported. The returned datetime is
+/// converted to UTC, the offset in seconds is returned if one was given.
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn parse_time_crate_datetime(s: &str) -> Result<(chrono::NaiveDateTime, Option<i32>)> {
+    let (date_time, offset) = match s.rsplit_once(' ') {
+        Some((date_time, offset)) if offset.starts_with(['+', '-']) => (date_time, Some(offset)),
+        _ => (s, None),
+    };
+    let date_time = chrono::NaiveDateTime::parse_from_str(date_time, "%Y-%m-%d %H:%M:%S%.f")?;
+
+    let Some(offset) = offset else {
+        return Ok((date_time, None));
+    };
+    let (sign, offset) = offset.split_at(1);
+    let mut offset_seconds = 0;
+    for (idx, part) in offset.split(':').enumerate() {
+        if idx >= 3 || part.len() != 2 {
+            fail!("Invalid offset in datetime {s:?}");
+        }
+        offset_seconds += part.parse::<i32>()? * [3600, 60, 1][idx];
+    }
+    if sign == "-" {
+        offset_seconds = -offset_seconds;
+    }
+    let date_time = date_time - chrono::Duration::seconds(offset_seconds.into());
+    Ok((date_time, Some(offset_seconds)))
+}
+
+/// Format a UTC datetime as understood by the `time` crate
+///
+/// With offset, the `OffsetDateTime` format is used
+/// (`2024-09-30 12:30:00.0 +00:00:00`), otherwise the `PrimitiveDateTime`
+/// format (`2024-09-30 12:30:00.0`).
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn format_time_crate_datetime(date_time: chrono::NaiveDateTime, with_offset: bool) -> String {
+    format!(
+        "{date} {time}{offset}",
+        date = format_time_crate_date(date_time.date()),
+        time = format_time_crate_time(date_time.time()),
+        offset = if with_offset { " +00:00:00" } else { "" },
+    )
+}
+
+/// Format a date as understood by the `time` crate (`2024-09-30`)
+///
+/// In contrast to the format used for chrono and jiff, negative years are
+/// formatted with 4 digits.
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn format_time_crate_date(date: chrono::NaiveDate) -> String {
+    use chrono::Datelike;
+
+    let year = date.year();
+    let year = if year < 0 {
+        format!("-{:04}", -year)
+    } else if year > 9999 {
+        format!("+{year}")
+    } else {
+        format!("{year:04}")
+    };
+    format!(
+        "{year}-{month:02}-{day:02}",
+        month = date.month(),
+        day = date.day()
+    )
+}
+
+/// Format a time as understood by the `time` crate (`12:30:00.0`)
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub fn format_time_crate_time(time: chrono::NaiveTime) -> String {
+    use chrono::Timelike;
+
+    let nanosecond = time.nanosecond();
+    let subsecond = if nanosecond == 0 {
+        String::from("0")
+    } else {
+        format!("{nanosecond:09}").trim_end_matches('0').to_owned()
+    };
+    format!(
+        "{hour:02}:{minute:02}:{second:02}.{subsecond}",
+        hour = time.hour(),
+        minute = time.minute(),
+        second = time.second(),
+    )
+}
+
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
 fn get_optional_digit_value(s: Option<&str>) -> Result<i64> {
     match s {
         Some(s) => Ok(s.parse()?),
@@ -145,10 +348,11 @@ mod parsing {
         type Output;
 
         fn matches(&self) -> bool;
+        #[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
         fn into_result(self, output_type: &str) -> crate::internal::error::Result<Self::Output>;
     }
 
-    impl<'a, 'e, R> ParseResult for Result<(&'a str, R), &'e str> {
+    impl<R> ParseResult for Result<(&str, R), &str> {
         type Output = R;
 
         fn matches(&self) -> bool {
@@ -198,6 +402,7 @@ mod parsing {
     }
 
     #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    #[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
     pub struct Span<'a> {
         pub sign: Option<char>,
         pub year: Option<&'a str>,
@@ -270,6 +475,7 @@ mod parsing {
         ))
     }
 
+    #[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
     pub fn match_span(s: &str) -> Result<(&str, Span<'_>), &str> {
         let (s, sign) = match_optional_sign(s)?;
         let (s, _) = match_char_case_insensitive(s, 'P')?;
@@ -303,6 +509,7 @@ mod parsing {
         ))
     }
 
+    #[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
     pub fn match_optional_span_seconds(
         s: &str,
     ) -> Result<(&str, Option<&str>, Option<&str>), &str> {
@@ -355,6 +562,7 @@ mod parsing {
     }
 
     /// Match a value in a span
+    #[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
     pub fn match_optional_span_value(s: &str, unit: char) -> Result<(&str, Option<&str>), &str> {
         let Ok((rest, value)) = match_one_or_more_digits(s) else {
             return Ok((s, None));
@@ -400,6 +608,7 @@ mod parsing {
     /// Match a character case insensitive
     ///
     /// Note: `c` must be an ASCII character and must be uppercase
+    #[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
     pub fn match_char_case_insensitive(s: &str, c: char) -> Result<(&str, char), &str> {
         debug_assert!(c.is_ascii());
         debug_assert!(c.is_ascii_uppercase());
@@ -416,6 +625,50 @@ mod parsing {
     }
 }
 
+#[test]
+fn test_time_crate_datetimes() {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    fn dt(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32, ns: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_nano_opt(h, mi, s, ns)
+            .unwrap()
+    }
+
+    assert_eq!(
+        parse_time_crate_datetime("2024-09-30 12:30:00.0").unwrap(),
+        (dt(2024, 9, 30, 12, 30, 0, 0), None)
+    );
+    assert_eq!(
+        parse_time_crate_datetime("2024-09-30 12:30:00.5 +00:00:00").unwrap(),
+        (dt(2024, 9, 30, 12, 30, 0, 500_000_000), Some(0))
+    );
+    assert_eq!(
+        parse_time_crate_datetime("2024-09-30 12:30:00.0 +02:00:00").unwrap(),
+        (dt(2024, 9, 30, 10, 30, 0, 0), Some(7200))
+    );
+    assert_eq!(
+        parse_time_crate_datetime("2024-09-30 00:30:00.0 -01:30:00").unwrap(),
+        (dt(2024, 9, 30, 2, 0, 0, 0), Some(-5400))
+    );
+    assert!(parse_time_crate_datetime("2024-09-30T12:30:00Z").is_err());
+    assert!(parse_time_crate_datetime("2024-09-30 12:30:00.0 +02:00:00:00").is_err());
+
+    assert_eq!(
+        format_time_crate_datetime(dt(2024, 9, 30, 12, 30, 0, 0), true),
+        "2024-09-30 12:30:00.0 +00:00:00"
+    );
+    assert_eq!(
+        format_time_crate_datetime(dt(824, 1, 2, 3, 4, 5, 120_000_000), false),
+        "0824-01-02 03:04:05.12"
+    );
+    assert_eq!(
+        format_time_crate_datetime(dt(-12, 1, 2, 3, 4, 5, 1), false),
+        "-0012-01-02 03:04:05.000000001"
+    );
+}
+
 #[test]
 fn test_match_naive_datetime() {
     // chrono examples
@@ -1,26 +1,71 @@
+use std::collections::HashMap;
+
 use half::f16;
 use serde::de::{Deserialize, DeserializeSeed, VariantAccess, Visitor};
 
 use crate::internal::{
-    arrow::{ArrayView, FieldMeta, PrimitiveArrayView, TimeUnit},
+    arrow::{ArrayView, DataType, FieldMeta},
+    deserializer_options::BinaryFormat,
     error::{fail, Context, Error, Result},
-    schema::{Strategy, STRATEGY_KEY},
-    utils::{ChildName, Mut},
+    schema::{get_default_value, Strategy, STRATEGY_KEY},
+    utils::{array_view_ext::ArrayViewExt, quantization::Quantization, ChildName, Mut},
 };
 
 use super::{
-    binary_deserializer::BinaryDeserializer, bool_deserializer::BoolDeserializer,
-    date32_deserializer::Date32Deserializer, date64_deserializer::Date64Deserializer,
-    decimal_deserializer::DecimalDeserializer, dictionary_deserializer::DictionaryDeserializer,
-    duration_deserializer::DurationDeserializer, enum_deserializer::EnumDeserializer,
+    binary_deserializer::BinaryDeserializer,
+    bool_deserializer::BoolDeserializer,
+    custom_deserializer::{CustomDeserializer, CustomDeserializers},
+    dictionary_deserializer::DictionaryDeserializer,
+    dictionary_overflow_deserializer::DictionaryOverflowDeserializer,
+    enum_one_hot_deserializer::EnumOneHotDeserializer,
     fixed_size_binary_deserializer::FixedSizeBinaryDeserializer,
-    fixed_size_list_deserializer::FixedSizeListDeserializer, float_deserializer::FloatDeserializer,
-    integer_deserializer::IntegerDeserializer, list_deserializer::ListDeserializer,
-    map_deserializer::MapDeserializer, null_deserializer::NullDeserializer,
-    simple_deserializer::SimpleDeserializer, string_deserializer::StringDeserializer,
-    struct_deserializer::StructDeserializer, time_deserializer::TimeDeserializer,
+    fixed_size_list_deserializer::FixedSizeListDeserializer,
+    float_deserializer::FloatDeserializer,
+    integer_deserializer::IntegerDeserializer,
+    list_deserializer::ListDeserializer,
+    map_deserializer::MapDeserializer,
+    null_deserializer::NullDeserializer,
+    simple_deserializer::SimpleDeserializer,
+    strict_null_deserializer::StrictNullDeserializer,
+    string_deserializer::StringDeserializer,
+    struct_deserializer::StructDeserializer,
+    tagged_struct_deserializer::TaggedStructDeserializer,
+    utils::slice_to_offsets,
+};
+
+#[cfg(feature = "types-decimal")]
+use super::decimal_deserializer::DecimalDeserializer;
+#[cfg(feature = "types-union")]
+use super::utils::slice_union_child;
+#[cfg(feature = "types-decimal")]
+use crate::internal::{arrow::I256, deserializer_options::DecimalFormat};
+
+#[cfg(feature = "types-temporal")]
+use super::{
+    date32_deserializer::Date32Deserializer, date64_deserializer::Date64Deserializer,
+    duration_deserializer::DurationDeserializer, interval_deserializer::IntervalDeserializer,
+    seconds_deserializer::SecondsDeserializer, time_deserializer::TimeDeserializer,
 };
 
+#[cfg(feature = "types-temporal")]
+use crate::internal::arrow::{DayTimeInterval, MonthDayNanoInterval, PrimitiveArrayView, TimeUnit};
+
+#[cfg(feature = "types-union")]
+use super::enum_deserializer::EnumDeserializer;
+
+#[cfg(feature = "serde_json")]
+use super::default_value_deserializer::DefaultValueDeserializer;
+
+/// The options used when constructing deserializers
+#[derive(Clone, Default)]
+pub struct BuildOptions {
+    /// The registered custom deserializers
+    pub custom: CustomDeserializers,
+    /// If `true`, null values deserialized into non-optional types are errors
+    pub strict_nullability: bool,
+}
+
+#[derive(Clone)]
 pub enum ArrayDeserializer<'a> {
     Null(NullDeserializer),
     Bool(BoolDeserializer<'a>),
@@ -35,12 +80,28 @@ pub enum ArrayDeserializer<'a> {
     F16(FloatDeserializer<'a, f16>),
     F32(FloatDeserializer<'a, f32>),
     F64(FloatDeserializer<'a, f64>),
+    #[cfg(feature = "types-decimal")]
     Decimal128(DecimalDeserializer<'a>),
+    #[cfg(feature = "types-decimal")]
+    Decimal256(DecimalDeserializer<'a, I256>),
+    #[cfg(feature = "types-temporal")]
     Duration(DurationDeserializer<'a>),
+    #[cfg(feature = "types-temporal")]
+    IntervalYearMonth(IntervalDeserializer<'a, i32>),
+    #[cfg(feature = "types-temporal")]
+    IntervalDayTime(IntervalDeserializer<'a, DayTimeInterval>),
+    #[cfg(feature = "types-temporal")]
+    IntervalMonthDayNano(IntervalDeserializer<'a, MonthDayNanoInterval>),
+    #[cfg(feature = "types-temporal")]
     Date32(Date32Deserializer<'a>),
+    #[cfg(feature = "types-temporal")]
     Date64(Date64Deserializer<'a>),
+    #[cfg(feature = "types-temporal")]
     Time32(TimeDeserializer<'a, i32>),
+    #[cfg(feature = "types-temporal")]
     Time64(TimeDeserializer<'a, i64>),
+    #[cfg(feature = "types-temporal")]
+    Seconds(SecondsDeserializer<'a>),
     Utf8(StringDeserializer<'a, i32>),
     LargeUtf8(StringDeserializer<'a, i64>),
     DictionaryU8I32(DictionaryDeserializer<'a, u8, i32>),
@@ -60,6 +121,9 @@ pub enum ArrayDeserializer<'a> {
     DictionaryI32I64(DictionaryDeserializer<'a, i32, i64>),
     DictionaryI64I64(DictionaryDeserializer<'a, i64, i64>),
     Struct(StructDeserializer<'a>),
+    TaggedStruct(TaggedStructDeserializer<'a>),
+    EnumOneHot(EnumOneHotDeserializer<'a>),
+    DictionaryOverflow(DictionaryOverflowDeserializer<'a>),
     List(ListDeserializer<'a, i32>),
     LargeList(ListDeserializer<'a, i64>),
     FixedSizeList(FixedSizeListDeserializer<'a>),
@@ -67,62 +131,193 @@ pub enum ArrayDeserializer<'a> {
     LargeBinary(BinaryDeserializer<'a, i64>),
     FixedSizeBinary(FixedSizeBinaryDeserializer<'a>),
     Map(MapDeserializer<'a>),
+    #[cfg(feature = "types-union")]
     Enum(EnumDeserializer<'a>),
+    Custom(CustomDeserializer<'a>),
+    #[cfg(feature = "serde_json")]
+    DefaultValue(DefaultValueDeserializer<'a>),
+    StrictNull(StrictNullDeserializer<'a>),
 }
 
 impl<'a> ArrayDeserializer<'a> {
+    #[cfg_attr(not(feature = "types-union"), allow(dead_code))]
     pub fn new(path: String, strategy: Option<&Strategy>, array: ArrayView<'a>) -> Result<Self> {
+        Self::new_with_custom(path, strategy, None, array, &BuildOptions::default())
+    }
+
+    /// Construct a deserializer, using the custom deserializers registered
+    /// for the extension name in `metadata` or for the data type of the array
+    ///
+    /// If `metadata` contains a default value, null values are deserialized
+    /// from it. Otherwise, with strict nullability, arrays containing nulls
+    /// reject null values for non-optional types.
+    pub fn new_with_custom(
+        path: String,
+        strategy: Option<&Strategy>,
+        metadata: Option<&HashMap<String, String>>,
+        array: ArrayView<'a>,
+        options: &BuildOptions,
+    ) -> Result<Self> {
+        let default = match metadata {
+            Some(metadata) => get_default_value(metadata)?,
+            None => None,
+        };
+        let Some(default) = default else {
+            if !options.strict_nullability
+                || matches!(array, ArrayView::Null(_))
+                || !array.has_nulls()?
+            {
+                return Self::new_custom_or_builtin(path, strategy, metadata, array, options);
+            }
+            let inner = Self::new_custom_or_builtin(
+                path.clone(),
+                strategy,
+                metadata,
+                array.clone(),
+                options,
+            )?;
+            return Ok(Self::StrictNull(StrictNullDeserializer::new(
+                path, array, inner,
+            )));
+        };
+
+        #[cfg(feature = "serde_json")]
+        {
+            let inner = Self::new_custom_or_builtin(
+                path.clone(),
+                strategy,
+                metadata,
+                array.clone(),
+                options,
+            )?;
+            Ok(Self::DefaultValue(DefaultValueDeserializer::new(
+                path, array, default, inner,
+            )))
+        }
+        #[cfg(not(feature = "serde_json"))]
+        match default {}
+    }
+
+    fn new_custom_or_builtin(
+        path: String,
+        strategy: Option<&Strategy>,
+        metadata: Option<&HashMap<String, String>>,
+        array: ArrayView<'a>,
+        options: &BuildOptions,
+    ) -> Result<Self> {
+        if !options.custom.is_empty() {
+            let extension_name = metadata
+                .and_then(|metadata| metadata.get(EXTENSION_NAME_KEY))
+                .map(String::as_str);
+            let type_name = array.type_name();
+            if let Some(factory) = options.custom.find(extension_name, type_name) {
+                let storage = Self::new_builtin(path.clone(), strategy, array, options);
+                return Ok(Self::Custom(CustomDeserializer::new(
+                    path, type_name, factory, storage,
+                )));
+            }
+        }
+        Self::new_builtin(path, strategy, array, options)
+    }
+
+    fn new_builtin(
+        path: String,
+        strategy: Option<&Strategy>,
+        array: ArrayView<'a>,
+        options: &BuildOptions,
+    ) -> Result<Self> {
         use {ArrayDeserializer as D, ArrayView as V};
         match array {
             ArrayView::Null(_) => Ok(Self::Null(NullDeserializer::new(path))),
             V::Boolean(view) => Ok(D::Bool(BoolDeserializer::new(path, view))),
-            V::Int8(view) => Ok(D::I8(IntegerDeserializer::new(path, view))),
+            V::Int8(view) => Ok(D::I8(
+                IntegerDeserializer::new(path, view)
+                    .with_quantization(get_quantization(strategy, &DataType::Int8)?),
+            )),
             V::Int16(view) => Ok(D::I16(IntegerDeserializer::new(path, view))),
             V::Int32(view) => Ok(D::I32(IntegerDeserializer::new(path, view))),
             V::Int64(view) => Ok(D::I64(IntegerDeserializer::new(path, view))),
-            V::UInt8(view) => Ok(D::U8(IntegerDeserializer::new(path, view))),
+            V::UInt8(view) => Ok(D::U8(
+                IntegerDeserializer::new(path, view)
+                    .with_quantization(get_quantization(strategy, &DataType::UInt8)?),
+            )),
             V::UInt16(view) => Ok(D::U16(IntegerDeserializer::new(path, view))),
             V::UInt32(view) => Ok(D::U32(IntegerDeserializer::new(path, view))),
             V::UInt64(view) => Ok(D::U64(IntegerDeserializer::new(path, view))),
             V::Float16(view) => Ok(D::F16(FloatDeserializer::new(path, view))),
             V::Float32(view) => Ok(D::F32(FloatDeserializer::new(path, view))),
             V::Float64(view) => Ok(D::F64(FloatDeserializer::new(path, view))),
-            V::Decimal128(view) => Ok(D::Decimal128(DecimalDeserializer::new(path, view))),
+            #[cfg(feature = "types-decimal")]
+            V::Decimal128(view) => Ok(D::Decimal128(DecimalDeserializer::new(
+                path,
+                view,
+                scaled_int_scale(strategy),
+            ))),
+            #[cfg(feature = "types-decimal")]
+            V::Decimal256(view) => Ok(D::Decimal256(DecimalDeserializer::new(
+                path,
+                view,
+                scaled_int_scale(strategy),
+            ))),
+            #[cfg(feature = "types-temporal")]
             ArrayView::Date32(view) => Ok(Self::Date32(Date32Deserializer::new(
                 path,
                 view.values,
                 view.validity,
+                is_time_crate_format(strategy)?,
             ))),
+            #[cfg(feature = "types-temporal")]
             ArrayView::Date64(view) => Ok(Self::Date64(Date64Deserializer::new(
                 path,
                 view.values,
                 view.validity,
                 TimeUnit::Millisecond,
                 is_utc_date64(strategy)?,
+                false,
+            ))),
+            #[cfg(feature = "types-temporal")]
+            V::Time32(view) => Ok(D::Time32(TimeDeserializer::new(
+                path,
+                view,
+                is_time_crate_format(strategy)?,
+            ))),
+            #[cfg(feature = "types-temporal")]
+            V::Time64(view) => Ok(D::Time64(TimeDeserializer::new(
+                path,
+                view,
+                is_time_crate_format(strategy)?,
             ))),
-            V::Time32(view) => Ok(D::Time32(TimeDeserializer::new(path, view))),
-            V::Time64(view) => Ok(D::Time64(TimeDeserializer::new(path, view))),
+            #[cfg(feature = "types-temporal")]
             ArrayView::Timestamp(view) => match strategy {
-                Some(Strategy::NaiveStrAsDate64 | Strategy::UtcStrAsDate64) => {
+                None
+                | Some(
+                    Strategy::NaiveStrAsDate64 | Strategy::UtcStrAsDate64 | Strategy::TimeCrateStr,
+                ) => {
+                    let is_utc = is_utc_timestamp(&path, view.unit, view.timezone.as_deref())?;
                     Ok(Self::Date64(Date64Deserializer::new(
                         path,
                         view.values,
                         view.validity,
                         view.unit,
-                        is_utc_timestamp(view.timezone.as_deref())?,
+                        is_utc,
+                        is_time_crate_format(strategy)?,
                     )))
                 }
-                Some(strategy) => {
-                    fail!("Invalid strategy: {strategy} is not supported for timestamp field")
-                }
-                None => Ok(Self::Date64(Date64Deserializer::new(
+                Some(Strategy::Seconds) => Ok(D::Seconds(SecondsDeserializer::new(
                     path,
+                    view.unit,
                     view.values,
                     view.validity,
-                    view.unit,
-                    is_utc_timestamp(view.timezone.as_deref())?,
                 ))),
+                Some(strategy) => {
+                    fail!("Invalid strategy: {strategy} is not supported for timestamp field")
+                }
             },
+            #[cfg(feature = "types-temporal")]
+            V::Duration(view) if matches!(strategy, Some(Strategy::Seconds)) => Ok(D::Seconds(
+                SecondsDeserializer::new(path, view.unit, view.values, view.validity),
+            )),
+            #[cfg(feature = "types-temporal")]
             V::Duration(view) => Ok(D::Duration(DurationDeserializer::new(
                 path,
                 view.unit,
@@ -131,6 +326,40 @@ impl<'a> ArrayDeserializer<'a> {
                     validity: view.validity,
                 },
             ))),
+            #[cfg(feature = "types-temporal")]
+            V::IntervalYearMonth(view) => {
+                Ok(D::IntervalYearMonth(IntervalDeserializer::new(path, view)))
+            }
+            #[cfg(feature = "types-temporal")]
+            V::IntervalDayTime(view) => {
+                Ok(D::IntervalDayTime(IntervalDeserializer::new(path, view)))
+            }
+            #[cfg(feature = "types-temporal")]
+            V::IntervalMonthDayNano(view) => Ok(D::IntervalMonthDayNano(
+                IntervalDeserializer::new(path, view),
+            )),
+            #[cfg(not(feature = "types-decimal"))]
+            V::Decimal128(_) | V::Decimal256(_) => Err(Error::unsupported_data_type(
+                path,
+                String::from(array.type_name()),
+                Vec::new(),
+                "decimal arrays require the `types-decimal` feature",
+            )),
+            #[cfg(not(feature = "types-temporal"))]
+            V::Date32(_)
+            | V::Date64(_)
+            | V::Time32(_)
+            | V::Time64(_)
+            | V::Timestamp(_)
+            | V::Duration(_)
+            | V::IntervalYearMonth(_)
+            | V::IntervalDayTime(_)
+            | V::IntervalMonthDayNano(_) => Err(Error::unsupported_data_type(
+                path,
+                String::from(array.type_name()),
+                Vec::new(),
+                "temporal arrays require the `types-temporal` feature",
+            )),
             V::Utf8(view) => Ok(D::Utf8(StringDeserializer::new(path, view))),
             V::LargeUtf8(view) => Ok(D::LargeUtf8(StringDeserializer::new(path, view))),
             V::Binary(view) => Ok(D::Binary(BinaryDeserializer::new(path, view))),
@@ -142,10 +371,12 @@ impl<'a> ArrayDeserializer<'a> {
                 let child_path = format!("{path}.{child}", child = ChildName(&view.meta.name));
                 Ok(D::List(ListDeserializer::new(
                     path,
-                    ArrayDeserializer::new(
+                    ArrayDeserializer::new_with_custom(
                         child_path,
                         get_strategy(&view.meta)?.as_ref(),
-                        *view.element,
+                        Some(&view.meta.metadata),
+                        slice_to_offsets(*view.element, view.offsets)?,
+                        options,
                     )?,
                     view.offsets,
                     view.validity,
@@ -155,10 +386,12 @@ impl<'a> ArrayDeserializer<'a> {
                 let child_path = format!("{path}.{child}", child = ChildName(&view.meta.name));
                 Ok(D::LargeList(ListDeserializer::new(
                     path,
-                    ArrayDeserializer::new(
+                    ArrayDeserializer::new_with_custom(
                         child_path,
                         get_strategy(&view.meta)?.as_ref(),
-                        *view.element,
+                        Some(&view.meta.metadata),
+                        slice_to_offsets(*view.element, view.offsets)?,
+                        options,
                     )?,
                     view.offsets,
                     view.validity,
@@ -168,39 +401,148 @@ impl<'a> ArrayDeserializer<'a> {
                 let child_path = format!("{path}.{child}", child = ChildName(&view.meta.name));
                 Ok(D::FixedSizeList(FixedSizeListDeserializer::new(
                     path,
-                    ArrayDeserializer::new(
+                    ArrayDeserializer::new_with_custom(
                         child_path,
                         get_strategy(&view.meta)?.as_ref(),
+                        Some(&view.meta.metadata),
                         *view.element,
+                        options,
                     )?,
                     view.validity,
                     view.n.try_into()?,
                     view.len,
                 )))
             }
+            V::Struct(view) if matches!(strategy, Some(Strategy::EnumAsStructWithTag)) => {
+                let mut fields = view.fields.into_iter();
+                let Some((tag_view, tag_meta)) = fields.next() else {
+                    fail!("EnumAsStructWithTag fields require a tag field");
+                };
+                let tag_path = format!("{path}.{child}", child = ChildName(&tag_meta.name));
+                let tag = ArrayDeserializer::new_with_custom(
+                    tag_path,
+                    None,
+                    Some(&tag_meta.metadata),
+                    tag_view,
+                    options,
+                )?;
+
+                let mut variants = Vec::new();
+                for (field_view, field_meta) in fields {
+                    let strategy = get_strategy(&field_meta)?;
+                    let child_path = format!("{path}.{child}", child = ChildName(&field_meta.name));
+                    let field_deserializer = ArrayDeserializer::new_with_custom(
+                        child_path,
+                        strategy.as_ref(),
+                        Some(&field_meta.metadata),
+                        field_view,
+                        options,
+                    )?;
+                    variants.push((field_meta.name, field_deserializer));
+                }
+
+                Ok(D::TaggedStruct(TaggedStructDeserializer::new(
+                    path,
+                    tag,
+                    variants,
+                    view.validity,
+                    view.len,
+                )))
+            }
+            V::Struct(view) if matches!(strategy, Some(Strategy::EnumOneHot)) => {
+                let mut variants = Vec::new();
+                for (field_view, field_meta) in view.fields {
+                    let V::Boolean(field_view) = field_view else {
+                        fail!(
+                            "EnumOneHot fields require Boolean children, {child} is not",
+                            child = field_meta.name,
+                        );
+                    };
+                    variants.push((field_meta.name, field_view));
+                }
+                Ok(D::EnumOneHot(EnumOneHotDeserializer::new(
+                    path,
+                    variants,
+                    view.validity,
+                    view.len,
+                )?))
+            }
+            V::Struct(view) if matches!(strategy, Some(Strategy::DictionaryOverflow { .. })) => {
+                let Ok([(values_view, values_meta), (overflow_view, overflow_meta)]) =
+                    <[_; 2]>::try_from(view.fields)
+                else {
+                    fail!("DictionaryOverflow fields require exactly two children");
+                };
+                let overflow_validity = match &overflow_view {
+                    V::Utf8(overflow_view) => overflow_view.validity,
+                    V::LargeUtf8(overflow_view) => overflow_view.validity,
+                    _ => fail!(
+                        "DictionaryOverflow fields require a string overflow field, {child} is not",
+                        child = overflow_meta.name,
+                    ),
+                };
+                let values_path = format!("{path}.{child}", child = ChildName(&values_meta.name));
+                let values = ArrayDeserializer::new_with_custom(
+                    values_path,
+                    None,
+                    Some(&values_meta.metadata),
+                    values_view,
+                    options,
+                )?;
+                let overflow_path =
+                    format!("{path}.{child}", child = ChildName(&overflow_meta.name));
+                let overflow = ArrayDeserializer::new_with_custom(
+                    overflow_path,
+                    None,
+                    Some(&overflow_meta.metadata),
+                    overflow_view,
+                    options,
+                )?;
+                Ok(D::DictionaryOverflow(DictionaryOverflowDeserializer::new(
+                    path,
+                    values,
+                    overflow,
+                    overflow_validity,
+                    view.validity,
+                    view.len,
+                )))
+            }
             V::Struct(view) => {
                 let mut fields = Vec::new();
+                let mut catch_all = None;
                 for (field_view, field_meta) in view.fields {
+                    let strategy = get_strategy(&field_meta)?;
+                    if matches!(
+                        strategy,
+                        Some(Strategy::StringHash { .. } | Strategy::RowHash)
+                    ) {
+                        continue;
+                    }
+                    if matches!(strategy, Some(Strategy::CatchAll)) {
+                        catch_all = Some(fields.len());
+                    }
                     let child_path = format!("{path}.{child}", child = ChildName(&field_meta.name));
-                    let field_deserializer = ArrayDeserializer::new(
+                    let field_deserializer = ArrayDeserializer::new_with_custom(
                         child_path,
-                        get_strategy(&field_meta)?.as_ref(),
+                        strategy.as_ref(),
+                        Some(&field_meta.metadata),
                         field_view,
+                        options,
                     )?;
                     let field_name = field_meta.name;
 
                     fields.push((field_name, field_deserializer));
                 }
 
-                Ok(D::Struct(StructDeserializer::new(
-                    path,
-                    fields,
-                    view.validity,
-                    view.len,
-                )))
+                Ok(D::Struct(
+                    StructDeserializer::new(path, fields, view.validity, view.len)
+                        .with_catch_all(catch_all),
+                ))
             }
             V::Map(view) => {
-                let ArrayView::Struct(entries_view) = *view.element else {
+                let ArrayView::Struct(entries_view) =
+                    slice_to_offsets(*view.element, view.offsets)?
+                else {
                     fail!("Invalid entries field in map array");
                 };
                 let Ok(entries_fields) = <[_; 2]>::try_from(entries_view.fields) else {
@@ -208,17 +550,21 @@ impl<'a> ArrayDeserializer<'a> {
                 };
                 let [(keys_view, keys_meta), (values_view, values_meta)] = entries_fields;
                 let keys_path = format!("{path}.{child}", child = ChildName(&keys_meta.name));
-                let keys = ArrayDeserializer::new(
+                let keys = ArrayDeserializer::new_with_custom(
                     keys_path,
                     get_strategy(&keys_meta)?.as_ref(),
+                    Some(&keys_meta.metadata),
                     keys_view,
+                    options,
                 )?;
 
                 let values_path = format!("{path}.{child}", child = ChildName(&values_meta.name));
-                let values = ArrayDeserializer::new(
+                let values = ArrayDeserializer::new_with_custom(
                     values_path,
                     get_strategy(&values_meta)?.as_ref(),
+                    Some(&values_meta.metadata),
                     values_view,
+                    options,
                 )?;
 
                 Ok(D::Map(MapDeserializer::new(
@@ -278,38 +624,378 @@ impl<'a> ArrayDeserializer<'a> {
                 (V::UInt64(keys), V::LargeUtf8(values)) => Ok(D::DictionaryU64I64(
                     DictionaryDeserializer::new(path, keys, values)?,
                 )),
-                _ => fail!("Unsupported dictionary array type"),
+                (keys, values) => {
+                    let (keys, values) = (keys.type_name(), values.type_name());
+                    Err(Error::unsupported_data_type(
+                        path,
+                        format!("Dictionary({keys}, {values})"),
+                        vec![
+                            format!("Dictionary({keys}, Utf8)"),
+                            format!("Dictionary({keys}, LargeUtf8)"),
+                            String::from(values),
+                        ],
+                        "only dictionaries with string values are supported",
+                    ))
+                }
             },
+            #[cfg(feature = "types-union")]
             ArrayView::DenseUnion(view) => {
                 let mut fields = Vec::new();
+                let mut null_variant = None;
                 for (idx, (type_id, field_view, field_meta)) in view.fields.into_iter().enumerate()
                 {
                     if usize::try_from(type_id) != Ok(idx) {
-                        fail!("Only unions with consecutive type ids are currently supported");
+                        return Err(Error::unsupported_data_type(
+                            path,
+                            String::from("Union"),
+                            Vec::new(),
+                            "only unions with consecutive type ids are supported",
+                        ));
+                    }
+                    let strategy = get_strategy(&field_meta)?;
+                    if strategy == Some(Strategy::NullVariant) {
+                        null_variant = Some(idx);
                     }
                     let child_path = format!("{path}.{child}", child = ChildName(&field_meta.name));
-                    let field_deserializer = ArrayDeserializer::new(
+                    let field_deserializer = ArrayDeserializer::new_with_custom(
                         child_path,
-                        get_strategy(&field_meta)?.as_ref(),
-                        field_view,
+                        strategy.as_ref(),
+                        Some(&field_meta.metadata),
+                        slice_union_child(field_view, type_id, view.types, view.offsets)?,
+                        options,
                     )?;
                     fields.push((field_meta.name, field_deserializer))
                 }
 
-                Ok(Self::Enum(EnumDeserializer::new(path, view.types, fields)))
+                Ok(Self::Enum(
+                    EnumDeserializer::new(path, view.types, fields, null_variant)
+                        .with_untagged(strategy == Some(&Strategy::UntaggedUnion)),
+                ))
+            }
+            #[cfg(not(feature = "types-union"))]
+            ArrayView::DenseUnion(_) => Err(Error::unsupported_data_type(
+                path,
+                String::from("Union"),
+                Vec::new(),
+                "union arrays require the `types-union` feature",
+            )),
+        }
+    }
+
+    /// Call `func` with the format of all decimal deserializers, including nested ones
+    #[cfg(feature = "types-decimal")]
+    pub fn for_each_decimal_format_mut(&mut self, func: &mut dyn FnMut(&mut DecimalFormat)) {
+        match self {
+            Self::Decimal128(deserializer) => func(&mut deserializer.format),
+            Self::Decimal256(deserializer) => func(&mut deserializer.format),
+            Self::Struct(deserializer) => {
+                for (_, child) in &mut deserializer.fields {
+                    child.for_each_decimal_format_mut(func);
+                }
+            }
+            Self::TaggedStruct(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_decimal_format_mut(func);
+                }
+            }
+            Self::List(deserializer) => deserializer.item.for_each_decimal_format_mut(func),
+            Self::LargeList(deserializer) => deserializer.item.for_each_decimal_format_mut(func),
+            Self::FixedSizeList(deserializer) => {
+                deserializer.item.for_each_decimal_format_mut(func)
+            }
+            Self::Map(deserializer) => {
+                deserializer.key.for_each_decimal_format_mut(func);
+                deserializer.value.for_each_decimal_format_mut(func);
+            }
+            #[cfg(feature = "types-union")]
+            Self::Enum(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_decimal_format_mut(func);
+                }
+            }
+            Self::StrictNull(deserializer) => deserializer.inner.for_each_decimal_format_mut(func),
+            _ => {}
+        }
+    }
+
+    /// Call `func` with the numeric coercion flag of all integer and float
+    /// deserializers, including nested ones
+    pub fn for_each_numeric_coercion_mut(&mut self, func: &mut dyn FnMut(&mut bool)) {
+        match self {
+            Self::U8(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::U16(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::U32(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::U64(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::I8(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::I16(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::I32(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::I64(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::F16(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::F32(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::F64(deserializer) => func(&mut deserializer.numeric_coercion),
+            Self::Struct(deserializer) => {
+                for (_, child) in &mut deserializer.fields {
+                    child.for_each_numeric_coercion_mut(func);
+                }
+            }
+            Self::TaggedStruct(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_numeric_coercion_mut(func);
+                }
+            }
+            Self::List(deserializer) => deserializer.item.for_each_numeric_coercion_mut(func),
+            Self::LargeList(deserializer) => deserializer.item.for_each_numeric_coercion_mut(func),
+            Self::FixedSizeList(deserializer) => {
+                deserializer.item.for_each_numeric_coercion_mut(func)
+            }
+            Self::Map(deserializer) => {
+                deserializer.key.for_each_numeric_coercion_mut(func);
+                deserializer.value.for_each_numeric_coercion_mut(func);
+            }
+            #[cfg(feature = "types-union")]
+            Self::Enum(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_numeric_coercion_mut(func);
+                }
+            }
+            #[cfg(feature = "serde_json")]
+            Self::DefaultValue(deserializer) => {
+                deserializer.inner.for_each_numeric_coercion_mut(func)
+            }
+            Self::StrictNull(deserializer) => {
+                deserializer.inner.for_each_numeric_coercion_mut(func)
+            }
+            _ => {}
+        }
+    }
+
+    /// Call `func` with the format of all binary deserializers, including nested ones
+    pub fn for_each_binary_format_mut(&mut self, func: &mut dyn FnMut(&mut BinaryFormat)) {
+        match self {
+            Self::Binary(deserializer) => func(&mut deserializer.format),
+            Self::LargeBinary(deserializer) => func(&mut deserializer.format),
+            Self::FixedSizeBinary(deserializer) => func(&mut deserializer.format),
+            Self::Struct(deserializer) => {
+                for (_, child) in &mut deserializer.fields {
+                    child.for_each_binary_format_mut(func);
+                }
+            }
+            Self::TaggedStruct(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_binary_format_mut(func);
+                }
+            }
+            Self::List(deserializer) => deserializer.item.for_each_binary_format_mut(func),
+            Self::LargeList(deserializer) => deserializer.item.for_each_binary_format_mut(func),
+            Self::FixedSizeList(deserializer) => deserializer.item.for_each_binary_format_mut(func),
+            Self::Map(deserializer) => {
+                deserializer.key.for_each_binary_format_mut(func);
+                deserializer.value.for_each_binary_format_mut(func);
+            }
+            #[cfg(feature = "types-union")]
+            Self::Enum(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_binary_format_mut(func);
+                }
+            }
+            Self::StrictNull(deserializer) => deserializer.inner.for_each_binary_format_mut(func),
+            _ => {}
+        }
+    }
+
+    /// Call `func` for all struct deserializers, including nested ones
+    pub fn for_each_struct_mut(&mut self, func: &mut dyn FnMut(&mut StructDeserializer<'a>)) {
+        match self {
+            Self::Struct(deserializer) => {
+                func(deserializer);
+                for (_, child) in &mut deserializer.fields {
+                    child.for_each_struct_mut(func);
+                }
+            }
+            Self::TaggedStruct(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_struct_mut(func);
+                }
             }
+            Self::List(deserializer) => deserializer.item.for_each_struct_mut(func),
+            Self::LargeList(deserializer) => deserializer.item.for_each_struct_mut(func),
+            Self::FixedSizeList(deserializer) => deserializer.item.for_each_struct_mut(func),
+            Self::Map(deserializer) => {
+                deserializer.key.for_each_struct_mut(func);
+                deserializer.value.for_each_struct_mut(func);
+            }
+            #[cfg(feature = "types-union")]
+            Self::Enum(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_struct_mut(func);
+                }
+            }
+            Self::StrictNull(deserializer) => deserializer.inner.for_each_struct_mut(func),
+            _ => {}
+        }
+    }
+
+    /// Call `func` for all map deserializers, including nested ones
+    pub fn for_each_map_mut(&mut self, func: &mut dyn FnMut(&mut MapDeserializer<'a>)) {
+        match self {
+            Self::Struct(deserializer) => {
+                for (_, child) in &mut deserializer.fields {
+                    child.for_each_map_mut(func);
+                }
+            }
+            Self::TaggedStruct(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_map_mut(func);
+                }
+            }
+            Self::List(deserializer) => deserializer.item.for_each_map_mut(func),
+            Self::LargeList(deserializer) => deserializer.item.for_each_map_mut(func),
+            Self::FixedSizeList(deserializer) => deserializer.item.for_each_map_mut(func),
+            Self::Map(deserializer) => {
+                func(deserializer);
+                deserializer.key.for_each_map_mut(func);
+                deserializer.value.for_each_map_mut(func);
+            }
+            #[cfg(feature = "types-union")]
+            Self::Enum(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_map_mut(func);
+                }
+            }
+            Self::StrictNull(deserializer) => deserializer.inner.for_each_map_mut(func),
+            _ => {}
+        }
+    }
+
+    /// Call `func` for all date64 and timestamp deserializers, including nested ones
+    #[cfg(feature = "types-temporal")]
+    pub fn for_each_date64_mut(&mut self, func: &mut dyn FnMut(&mut Date64Deserializer<'a>)) {
+        match self {
+            Self::Date64(deserializer) => func(deserializer),
+            Self::Struct(deserializer) => {
+                for (_, child) in &mut deserializer.fields {
+                    child.for_each_date64_mut(func);
+                }
+            }
+            Self::TaggedStruct(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_date64_mut(func);
+                }
+            }
+            Self::List(deserializer) => deserializer.item.for_each_date64_mut(func),
+            Self::LargeList(deserializer) => deserializer.item.for_each_date64_mut(func),
+            Self::FixedSizeList(deserializer) => deserializer.item.for_each_date64_mut(func),
+            Self::Map(deserializer) => {
+                deserializer.key.for_each_date64_mut(func);
+                deserializer.value.for_each_date64_mut(func);
+            }
+            #[cfg(feature = "types-union")]
+            Self::Enum(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_date64_mut(func);
+                }
+            }
+            Self::StrictNull(deserializer) => deserializer.inner.for_each_date64_mut(func),
+            _ => {}
+        }
+    }
+
+    /// Call `func` for all duration deserializers, including nested ones
+    #[cfg(feature = "types-temporal")]
+    pub fn for_each_duration_mut(&mut self, func: &mut dyn FnMut(&mut DurationDeserializer<'a>)) {
+        match self {
+            Self::Duration(deserializer) => func(deserializer),
+            Self::Struct(deserializer) => {
+                for (_, child) in &mut deserializer.fields {
+                    child.for_each_duration_mut(func);
+                }
+            }
+            Self::TaggedStruct(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_duration_mut(func);
+                }
+            }
+            Self::List(deserializer) => deserializer.item.for_each_duration_mut(func),
+            Self::LargeList(deserializer) => deserializer.item.for_each_duration_mut(func),
+            Self::FixedSizeList(deserializer) => deserializer.item.for_each_duration_mut(func),
+            Self::Map(deserializer) => {
+                deserializer.key.for_each_duration_mut(func);
+                deserializer.value.for_each_duration_mut(func);
+            }
+            #[cfg(feature = "types-union")]
+            Self::Enum(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_duration_mut(func);
+                }
+            }
+            Self::StrictNull(deserializer) => deserializer.inner.for_each_duration_mut(func),
+            _ => {}
+        }
+    }
+
+    /// Call `func` for all enum deserializers, including nested ones
+    #[cfg(feature = "types-union")]
+    pub fn for_each_enum_mut(&mut self, func: &mut dyn FnMut(&mut EnumDeserializer<'a>)) {
+        match self {
+            Self::Struct(deserializer) => {
+                for (_, child) in &mut deserializer.fields {
+                    child.for_each_enum_mut(func);
+                }
+            }
+            Self::TaggedStruct(deserializer) => {
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_enum_mut(func);
+                }
+            }
+            Self::List(deserializer) => deserializer.item.for_each_enum_mut(func),
+            Self::LargeList(deserializer) => deserializer.item.for_each_enum_mut(func),
+            Self::FixedSizeList(deserializer) => deserializer.item.for_each_enum_mut(func),
+            Self::Map(deserializer) => {
+                deserializer.key.for_each_enum_mut(func);
+                deserializer.value.for_each_enum_mut(func);
+            }
+            Self::Enum(deserializer) => {
+                func(deserializer);
+                for (_, child) in &mut deserializer.variants {
+                    child.for_each_enum_mut(func);
+                }
+            }
+            Self::StrictNull(deserializer) => deserializer.inner.for_each_enum_mut(func),
+            _ => {}
         }
     }
 }
 
-fn is_utc_timestamp(timezone: Option<&str>) -> Result<bool> {
+#[cfg(feature = "types-temporal")]
+fn is_utc_timestamp(path: &str, unit: TimeUnit, timezone: Option<&str>) -> Result<bool> {
     match timezone {
         Some(tz) if tz.to_lowercase() == "utc" => Ok(true),
-        Some(tz) => fail!("Unsupported timezone: {} is not supported", tz),
+        Some(tz) => Err(Error::unsupported_data_type(
+            path.to_owned(),
+            format!("Timestamp({unit}, Some({tz:?}))"),
+            vec![
+                format!("Timestamp({unit}, Some(\"UTC\"))"),
+                format!("Timestamp({unit}, None)"),
+                String::from("Int64"),
+            ],
+            &format!("the timezone {tz} is not supported"),
+        )),
         None => Ok(false),
     }
 }
 
+#[cfg(feature = "types-temporal")]
+fn is_time_crate_format(strategy: Option<&Strategy>) -> Result<bool> {
+    match strategy {
+        Some(Strategy::TimeCrateStr) => Ok(true),
+        None | Some(Strategy::UtcStrAsDate64 | Strategy::NaiveStrAsDate64) => Ok(false),
+        Some(strategy) => {
+            fail!("Invalid strategy: {strategy} is not supported for time deserializer")
+        }
+    }
+}
+
+#[cfg(feature = "types-temporal")]
 fn is_utc_date64(strategy: Option<&Strategy>) -> Result<bool> {
     match strategy {
         None | Some(Strategy::UtcStrAsDate64) => Ok(true),
@@ -320,6 +1006,26 @@ fn is_utc_date64(strategy: Option<&Strategy>) -> Result<bool> {
     }
 }
 
+#[cfg(feature = "types-decimal")]
+fn scaled_int_scale(strategy: Option<&Strategy>) -> Option<i8> {
+    match strategy {
+        Some(Strategy::ScaledInt { scale }) => Some(*scale),
+        _ => None,
+    }
+}
+
+fn get_quantization(
+    strategy: Option<&Strategy>,
+    data_type: &DataType,
+) -> Result<Option<Quantization>> {
+    match strategy {
+        Some(Strategy::Quantized { scale, zero_point }) => {
+            Ok(Some(Quantization::new(*scale, *zero_point, data_type)?))
+        }
+        _ => Ok(None),
+    }
+}
+
 fn get_strategy(meta: &FieldMeta) -> Result<Option<Strategy>> {
     let Some(strategy) = meta.metadata.get(STRATEGY_KEY) else {
         return Ok(None);
@@ -327,6 +1033,8 @@ fn get_strategy(meta: &FieldMeta) -> Result<Option<Strategy>> {
     Ok(Some(strategy.parse()?))
 }
 
+const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
 macro_rules! dispatch {
     ($obj:expr, $wrapper:ident($name:ident) => $expr:expr) => {
         match $obj {
@@ -343,15 +1051,34 @@ macro_rules! dispatch {
             $wrapper::F16($name) => $expr,
             $wrapper::F32($name) => $expr,
             $wrapper::F64($name) => $expr,
+            #[cfg(feature = "types-decimal")]
             $wrapper::Decimal128($name) => $expr,
+            #[cfg(feature = "types-decimal")]
+            $wrapper::Decimal256($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Duration($name) => $expr,
+            #[cfg(feature = "types-temporal")]
+            $wrapper::IntervalYearMonth($name) => $expr,
+            #[cfg(feature = "types-temporal")]
+            $wrapper::IntervalDayTime($name) => $expr,
+            #[cfg(feature = "types-temporal")]
+            $wrapper::IntervalMonthDayNano($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Date32($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Date64($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Time32($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Time64($name) => $expr,
+            #[cfg(feature = "types-temporal")]
+            $wrapper::Seconds($name) => $expr,
             $wrapper::Utf8($name) => $expr,
             $wrapper::LargeUtf8($name) => $expr,
             $wrapper::Struct($name) => $expr,
+            $wrapper::TaggedStruct($name) => $expr,
+            $wrapper::EnumOneHot($name) => $expr,
+            $wrapper::DictionaryOverflow($name) => $expr,
             $wrapper::List($name) => $expr,
             $wrapper::FixedSizeList($name) => $expr,
             $wrapper::LargeList($name) => $expr,
@@ -359,6 +1086,7 @@ macro_rules! dispatch {
             $wrapper::LargeBinary($name) => $expr,
             $wrapper::FixedSizeBinary($name) => $expr,
             $wrapper::Map($name) => $expr,
+            #[cfg(feature = "types-union")]
             $wrapper::Enum($name) => $expr,
             $wrapper::DictionaryU8I32($name) => $expr,
             $wrapper::DictionaryU16I32($name) => $expr,
@@ -376,6 +1104,10 @@ macro_rules! dispatch {
             $wrapper::DictionaryI16I64($name) => $expr,
             $wrapper::DictionaryI32I64($name) => $expr,
             $wrapper::DictionaryI64I64($name) => $expr,
+            $wrapper::Custom($name) => $expr,
+            #[cfg(feature = "serde_json")]
+            $wrapper::DefaultValue($name) => $expr,
+            $wrapper::StrictNull($name) => $expr,
         }
     };
 }
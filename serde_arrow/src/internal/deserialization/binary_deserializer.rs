@@ -2,16 +2,25 @@ use serde::de::{SeqAccess, Visitor};
 
 use crate::internal::{
     arrow::BytesArrayView,
+    deserializer_options::BinaryFormat,
     error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
-    utils::{Mut, NamedType, Offset},
+    utils::{
+        encoding::{base64_encode, hex_encode},
+        Mut, NamedType, Offset,
+    },
 };
 
-use super::{simple_deserializer::SimpleDeserializer, utils::bitset_is_set};
+use super::{
+    simple_deserializer::SimpleDeserializer,
+    utils::{bitset_is_set, segment_len},
+};
 
+#[derive(Clone)]
 pub struct BinaryDeserializer<'a, O: Offset> {
     pub path: String,
     pub view: BytesArrayView<'a, O>,
     pub next: (usize, usize),
+    pub format: BinaryFormat,
 }
 
 impl<'a, O: Offset> BinaryDeserializer<'a, O> {
@@ -20,6 +29,7 @@ impl<'a, O: Offset> BinaryDeserializer<'a, O> {
             path,
             view,
             next: (0, 0),
+            format: BinaryFormat::default(),
         }
     }
 
@@ -45,6 +55,7 @@ impl<'a, O: Offset> BinaryDeserializer<'a, O> {
         }
         let end = self.view.offsets[item + 1].try_into_usize()?;
         let start = self.view.offsets[item].try_into_usize()?;
+        segment_len(start, end)?;
         Ok((start, end))
     }
 
@@ -52,7 +63,10 @@ impl<'a, O: Offset> BinaryDeserializer<'a, O> {
         let (start, end) = self.peek_next_slice_range()?;
         let (item, _) = self.next;
         self.next = (item + 1, 0);
-        Ok(&self.view.data[start..end])
+        let Some(data) = self.view.data.get(start..end) else {
+            fail!("Invalid offsets: the range {start}..{end} exceeds the data");
+        };
+        Ok(data)
     }
 }
 
@@ -75,7 +89,11 @@ impl<'a, O: Offset + NamedType> SimpleDeserializer<'a> for BinaryDeserializer<'a
     fn deserialize_any<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.peek_next().ctx(self)? {
-                self.deserialize_bytes(visitor).ctx(self)
+                match self.format {
+                    BinaryFormat::Bytes => self.deserialize_seq(visitor).ctx(self),
+                    BinaryFormat::Base64 => visitor.visit_string(base64_encode(self.next_slice()?)),
+                    BinaryFormat::Hex => visitor.visit_string(hex_encode(self.next_slice()?)),
+                }
             } else {
                 self.consume_next();
                 visitor.visit_none::<Error>().ctx(self)
@@ -107,11 +125,32 @@ impl<'a, O: Offset + NamedType> SimpleDeserializer<'a> for BinaryDeserializer<'a
     fn deserialize_byte_buf<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| visitor.visit_borrowed_bytes::<Error>(self.next_slice()?)).ctx(self)
     }
+
+    fn deserialize_str<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            let bytes = self.next_slice()?;
+            match self.format {
+                BinaryFormat::Bytes => visitor.visit_borrowed_str(std::str::from_utf8(bytes)?),
+                BinaryFormat::Base64 => visitor.visit_string(base64_encode(bytes)),
+                BinaryFormat::Hex => visitor.visit_string(hex_encode(bytes)),
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_string<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| self.deserialize_str(visitor)).ctx(self)
+    }
 }
 
 impl<'de, O: Offset> SeqAccess<'de> for BinaryDeserializer<'de, O> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        let (start, end) = self.peek_next_slice_range().ok()?;
+        Some(end.checked_sub(start)?.saturating_sub(self.next.1))
+    }
+
     fn next_element_seed<T: serde::de::DeserializeSeed<'de>>(
         &mut self,
         seed: T,
@@ -125,7 +164,10 @@ impl<'de, O: Offset> SeqAccess<'de> for BinaryDeserializer<'de, O> {
         }
         self.next = (item, offset + 1);
 
-        let mut item_deserializer = U8Deserializer(self.view.data[start + offset]);
+        let Some(&byte) = self.view.data.get(start + offset) else {
+            fail!("Invalid offsets: the range {start}..{end} exceeds the data");
+        };
+        let mut item_deserializer = U8Deserializer(byte);
         let item = seed.deserialize(Mut(&mut item_deserializer))?;
         Ok(Some(item))
     }
@@ -138,6 +180,10 @@ impl Context for U8Deserializer {
 }
 
 impl<'de> SimpleDeserializer<'de> for U8Deserializer {
+    fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u8(self.0)
+    }
+
     fn deserialize_u8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         visitor.visit_u8(self.0)
     }
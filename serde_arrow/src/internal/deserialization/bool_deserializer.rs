@@ -8,6 +8,7 @@ use crate::internal::{
 
 use super::{simple_deserializer::SimpleDeserializer, utils::bitset_is_set};
 
+#[derive(Clone)]
 pub struct BoolDeserializer<'a> {
     pub path: String,
     pub view: BooleanArrayView<'a>,
@@ -0,0 +1,371 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use serde::de::{
+    value::{MapDeserializer, SeqDeserializer},
+    Deserialize, IntoDeserializer, Visitor,
+};
+
+use crate::internal::{
+    error::{fail, set_default, Context, ContextSupport, Error, Result},
+    utils::Mut,
+};
+
+use super::{array_deserializer::ArrayDeserializer, simple_deserializer::SimpleDeserializer};
+
+/// A user defined deserializer for the values of a single field
+///
+/// Custom deserializers allow to read arrays that cannot be handled by the
+/// builtin deserializers, e.g., extension arrays. They are registered via
+/// [`Deserializer::with_custom_deserializer`][crate::Deserializer::with_custom_deserializer]
+/// for an extension name or a data type name. For each row of the field,
+/// [`next`][CustomArrayDeserializer::next] is called once and returns the
+/// value of the row as a [`CustomValue`], which is then passed on to the
+/// deserialized type.
+///
+/// The values of the array are read via [`CustomSource`], which deserializes
+/// them with the builtin deserializer for the storage of the field. For data
+/// types without builtin support, the source is not available and the custom
+/// deserializer has to produce the values without it.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use std::{collections::HashMap, sync::Arc};
+/// use arrow::{
+///     array::{ArrayRef, FixedSizeBinaryArray, RecordBatch},
+///     datatypes::{DataType, Field, Schema},
+/// };
+/// use serde::Deserialize;
+/// use serde_arrow::{CustomArrayDeserializer, CustomSource, CustomValue, Deserializer};
+///
+/// /// Read UUIDs stored as fixed size binaries as hex strings
+/// struct Uuids;
+///
+/// impl CustomArrayDeserializer for Uuids {
+///     fn next(&mut self, source: &mut CustomSource<'_, '_>) -> serde_arrow::Result<CustomValue> {
+///         let bytes: Option<serde_bytes::ByteBuf> = source.read()?;
+///         Ok(match bytes {
+///             Some(bytes) => CustomValue::String(
+///                 bytes.iter().map(|b| format!("{b:02x}")).collect(),
+///             ),
+///             None => CustomValue::Null,
+///         })
+///     }
+/// }
+///
+/// let metadata = HashMap::from([(
+///     String::from("ARROW:extension:name"),
+///     String::from("arrow.uuid"),
+/// )]);
+/// let field = Field::new("id", DataType::FixedSizeBinary(16), false).with_metadata(metadata);
+/// let array = FixedSizeBinaryArray::try_from_iter([[1_u8; 16]].into_iter())?;
+/// let batch = RecordBatch::try_new(
+///     Arc::new(Schema::new(vec![field])),
+///     vec![Arc::new(array) as ArrayRef],
+/// )?;
+///
+/// #[derive(Deserialize)]
+/// struct Record {
+///     id: String,
+/// }
+///
+/// let deserializer = Deserializer::from_record_batch(&batch)?
+///     .with_custom_deserializer("arrow.uuid", || Box::new(Uuids));
+/// let items = Vec::<Record>::deserialize(deserializer)?;
+/// assert_eq!(items[0].id, "01".repeat(16));
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+pub trait CustomArrayDeserializer: Send + Sync {
+    /// Read the value of the next row
+    fn next(&mut self, source: &mut CustomSource<'_, '_>) -> Result<CustomValue>;
+}
+
+/// A value produced by a [`CustomArrayDeserializer`]
+///
+/// Values are passed to the visitor of the deserialized type: `Null` is
+/// deserialized as `None` or unit, strings can be deserialized as unit
+/// variants of enums, lists as sequences and maps as maps or structs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomValue {
+    Null,
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    List(Vec<CustomValue>),
+    Map(Vec<(CustomValue, CustomValue)>),
+}
+
+/// Access to the storage of a field with a custom deserializer
+pub struct CustomSource<'a, 'de>(Result<&'a mut ArrayDeserializer<'de>, &'a str>);
+
+impl<'a, 'de> CustomSource<'a, 'de> {
+    /// Deserialize the next value of the storage with the builtin deserializer
+    pub fn read<T: Deserialize<'de>>(&mut self) -> Result<T> {
+        match &mut self.0 {
+            Ok(storage) => T::deserialize(Mut(&mut **storage)),
+            Err(reason) => fail!("The storage of the field cannot be read: {reason}"),
+        }
+    }
+}
+
+/// The factory of a custom deserializer, called for every matching field
+pub type CustomDeserializerFactory =
+    Arc<dyn Fn() -> Box<dyn CustomArrayDeserializer> + Send + Sync>;
+
+/// The custom deserializers registered for a deserializer
+#[derive(Clone, Default)]
+pub struct CustomDeserializers(Vec<(String, CustomDeserializerFactory)>);
+
+impl CustomDeserializers {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Register a factory, later registrations take precedence
+    pub fn insert(&mut self, key: String, factory: CustomDeserializerFactory) {
+        self.0.retain(|(k, _)| *k != key);
+        self.0.push((key, factory));
+    }
+
+    /// Find the factory for the given extension name or data type name
+    ///
+    /// Extension names take precedence over data type names.
+    pub fn find(
+        &self,
+        extension_name: Option<&str>,
+        type_name: &str,
+    ) -> Option<&CustomDeserializerFactory> {
+        let find = |name: &str| self.0.iter().find(|(k, _)| k == name).map(|(_, f)| f);
+        extension_name.and_then(find).or_else(|| find(type_name))
+    }
+}
+
+pub struct CustomDeserializer<'a> {
+    path: String,
+    type_name: &'static str,
+    factory: CustomDeserializerFactory,
+    deserializer: Box<dyn CustomArrayDeserializer>,
+    storage: std::result::Result<Box<ArrayDeserializer<'a>>, String>,
+}
+
+/// Clones use a new custom deserializer created by the factory, the storage is
+/// cloned including its position
+impl<'a> Clone for CustomDeserializer<'a> {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            type_name: self.type_name,
+            factory: Arc::clone(&self.factory),
+            deserializer: (self.factory)(),
+            storage: self.storage.clone(),
+        }
+    }
+}
+
+impl<'a> CustomDeserializer<'a> {
+    pub fn new(
+        path: String,
+        type_name: &'static str,
+        factory: &CustomDeserializerFactory,
+        storage: Result<ArrayDeserializer<'a>>,
+    ) -> Self {
+        Self {
+            path,
+            type_name,
+            factory: Arc::clone(factory),
+            deserializer: factory(),
+            storage: storage
+                .map(Box::new)
+                .map_err(|err| err.message().to_owned()),
+        }
+    }
+
+    fn next_value(&mut self) -> Result<CustomValue> {
+        let mut source = CustomSource(match &mut self.storage {
+            Ok(storage) => Ok(storage.as_mut()),
+            Err(reason) => Err(reason.as_str()),
+        });
+        self.deserializer.next(&mut source)
+    }
+}
+
+impl Context for CustomDeserializer<'_> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", self.type_name);
+    }
+}
+
+macro_rules! forward_to_value {
+    ($($name:ident),* $(,)?) => {
+        $(
+            fn $name<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+                let value = self.next_value().ctx(self)?;
+                serde::Deserializer::$name(value, visitor).ctx(self)
+            }
+        )*
+    };
+}
+
+impl<'de> SimpleDeserializer<'de> for CustomDeserializer<'de> {
+    forward_to_value!(
+        deserialize_any,
+        deserialize_ignored_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_map,
+        deserialize_byte_buf,
+        deserialize_bytes,
+        deserialize_identifier,
+        deserialize_option,
+        deserialize_seq,
+        deserialize_unit,
+    );
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let value = self.next_value().ctx(self)?;
+        serde::Deserializer::deserialize_struct(value, name, fields, visitor).ctx(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let value = self.next_value().ctx(self)?;
+        serde::Deserializer::deserialize_enum(value, name, variants, visitor).ctx(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        let value = self.next_value().ctx(self)?;
+        serde::Deserializer::deserialize_newtype_struct(value, name, visitor).ctx(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value> {
+        let value = self.next_value().ctx(self)?;
+        serde::Deserializer::deserialize_tuple(value, len, visitor).ctx(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        let value = self.next_value().ctx(self)?;
+        serde::Deserializer::deserialize_tuple_struct(value, name, len, visitor).ctx(self)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        &mut self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        let value = self.next_value().ctx(self)?;
+        serde::Deserializer::deserialize_unit_struct(value, name, visitor).ctx(self)
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for CustomValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for CustomValue {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self {
+            Self::Null => visitor.visit_unit(),
+            Self::Bool(value) => visitor.visit_bool(value),
+            Self::I64(value) => visitor.visit_i64(value),
+            Self::U64(value) => visitor.visit_u64(value),
+            Self::F64(value) => visitor.visit_f64(value),
+            Self::String(value) => visitor.visit_string(value),
+            Self::Bytes(value) => visitor.visit_byte_buf(value),
+            Self::List(values) => {
+                let mut seq = SeqDeserializer::new(values.into_iter());
+                let res = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(res)
+            }
+            Self::Map(entries) => {
+                let mut map = MapDeserializer::new(entries.into_iter());
+                let res = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(res)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self {
+            Self::Null => visitor.visit_none(),
+            value => visitor.visit_some(value),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self {
+            Self::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Self::Map(entries) if entries.len() == 1 => {
+                let mut map = MapDeserializer::new(entries.into_iter());
+                visitor.visit_enum(serde::de::value::MapAccessDeserializer::new(&mut map))
+            }
+            _ => fail!(
+                "Cannot deserialize an enum from a value other than a string or a single entry map"
+            ),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
@@ -3,22 +3,31 @@ use serde::de::Visitor;
 
 use crate::internal::{
     arrow::BitsWithOffset,
-    error::{set_default, try_, Context, ContextSupport, Error, Result},
+    chrono::format_time_crate_date,
+    error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
     utils::Mut,
 };
 
 use super::{simple_deserializer::SimpleDeserializer, utils::ArrayBufferIterator};
 
+#[derive(Clone)]
 pub struct Date32Deserializer<'a> {
     path: String,
     array: ArrayBufferIterator<'a, i32>,
+    time_crate_format: bool,
 }
 
 impl<'a> Date32Deserializer<'a> {
-    pub fn new(path: String, buffer: &'a [i32], validity: Option<BitsWithOffset<'a>>) -> Self {
+    pub fn new(
+        path: String,
+        buffer: &'a [i32],
+        validity: Option<BitsWithOffset<'a>>,
+        time_crate_format: bool,
+    ) -> Self {
         Self {
             path,
             array: ArrayBufferIterator::new(buffer, validity),
+            time_crate_format,
         }
     }
 
@@ -26,7 +35,13 @@ impl<'a> Date32Deserializer<'a> {
         const UNIX_EPOCH: NaiveDate = NaiveDateTime::UNIX_EPOCH.date();
         #[allow(deprecated)]
         let delta = Duration::days(ts as i64);
-        let date = UNIX_EPOCH + delta;
+        let Some(date) = UNIX_EPOCH.checked_add_signed(delta) else {
+            fail!("Unsupported Date32 value {ts}: the date is out of range");
+        };
+
+        if self.time_crate_format {
+            return Ok(format_time_crate_date(date));
+        }
 
         // special handling of negative dates:
         //
@@ -58,7 +73,7 @@ impl<'de> SimpleDeserializer<'de> for Date32Deserializer<'de> {
     fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.array.peek_next()? {
-                self.deserialize_i32(visitor)
+                self.deserialize_str(visitor)
             } else {
                 self.array.consume_next();
                 visitor.visit_none()
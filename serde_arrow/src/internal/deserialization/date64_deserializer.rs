@@ -3,17 +3,22 @@ use serde::de::Visitor;
 
 use crate::internal::{
     arrow::{BitsWithOffset, TimeUnit},
+    chrono::format_time_crate_datetime,
     error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::Mut,
 };
 
 use super::{simple_deserializer::SimpleDeserializer, utils::ArrayBufferIterator};
 
+#[derive(Clone)]
 pub struct Date64Deserializer<'a> {
     path: String,
     array: ArrayBufferIterator<'a, i64>,
     unit: TimeUnit,
     is_utc: bool,
+    time_crate_format: bool,
+    /// The unit of integers returned, if different from the stored unit
+    pub int_unit: Option<TimeUnit>,
 }
 
 impl<'a> Date64Deserializer<'a> {
@@ -23,12 +28,23 @@ impl<'a> Date64Deserializer<'a> {
         validity: Option<BitsWithOffset<'a>>,
         unit: TimeUnit,
         is_utc: bool,
+        time_crate_format: bool,
     ) -> Self {
         Self {
             path,
             array: ArrayBufferIterator::new(buffer, validity),
             unit,
             is_utc,
+            time_crate_format,
+            int_unit: None,
+        }
+    }
+
+    fn next_int(&mut self) -> Result<i64> {
+        let ts = self.array.next_required()?;
+        match self.int_unit {
+            Some(unit) => convert_time_unit(ts, self.unit, unit),
+            None => Ok(ts),
         }
     }
 
@@ -42,7 +58,12 @@ impl<'a> Date64Deserializer<'a> {
             fail!("Unsupported timestamp value: {ts}");
         };
 
-        if self.is_utc {
+        if self.time_crate_format {
+            Ok(format_time_crate_datetime(
+                date_time.naive_utc(),
+                self.is_utc,
+            ))
+        } else if self.is_utc {
             Ok(self.format_with_suffix(date_time, "Z"))
         } else {
             Ok(self.format_with_suffix(date_time, ""))
@@ -84,7 +105,7 @@ impl<'de> SimpleDeserializer<'de> for Date64Deserializer<'de> {
     fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.array.peek_next()? {
-                self.deserialize_i64(visitor)
+                self.deserialize_str(visitor)
             } else {
                 self.array.consume_next();
                 visitor.visit_none()
@@ -106,7 +127,7 @@ impl<'de> SimpleDeserializer<'de> for Date64Deserializer<'de> {
     }
 
     fn deserialize_i64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_i64(self.array.next_required()?)).ctx(self)
+        try_(|| visitor.visit_i64(self.next_int()?)).ctx(self)
     }
 
     fn deserialize_str<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
@@ -133,3 +154,48 @@ impl<'de> SimpleDeserializer<'de> for Date64Deserializer<'de> {
         .ctx(self)
     }
 }
+
+/// Convert a timestamp between units
+///
+/// Conversions to coarser units round towards negative infinity, i.e., they
+/// return the start of the interval containing the timestamp. Conversions to
+/// finer units fail on overflow.
+fn convert_time_unit(value: i64, from: TimeUnit, to: TimeUnit) -> Result<i64> {
+    fn exponent(unit: TimeUnit) -> u32 {
+        match unit {
+            TimeUnit::Second => 0,
+            TimeUnit::Millisecond => 3,
+            TimeUnit::Microsecond => 6,
+            TimeUnit::Nanosecond => 9,
+        }
+    }
+
+    let (from_exp, to_exp) = (exponent(from), exponent(to));
+    if to_exp >= from_exp {
+        let factor = 10_i64.pow(to_exp - from_exp);
+        let Some(converted) = value.checked_mul(factor) else {
+            fail!("Timestamp {value} ({from}) cannot be represented with unit {to}");
+        };
+        Ok(converted)
+    } else {
+        Ok(value.div_euclid(10_i64.pow(from_exp - to_exp)))
+    }
+}
+
+#[test]
+fn test_convert_time_unit() {
+    use TimeUnit::*;
+
+    assert_eq!(convert_time_unit(1_500, Millisecond, Second).unwrap(), 1);
+    assert_eq!(convert_time_unit(-1_500, Millisecond, Second).unwrap(), -2);
+    assert_eq!(
+        convert_time_unit(1_234_567_891, Nanosecond, Millisecond).unwrap(),
+        1_234
+    );
+    assert_eq!(
+        convert_time_unit(2, Second, Microsecond).unwrap(),
+        2_000_000
+    );
+    assert_eq!(convert_time_unit(7, Microsecond, Microsecond).unwrap(), 7);
+    assert!(convert_time_unit(i64::MAX / 10, Second, Millisecond).is_err());
+}
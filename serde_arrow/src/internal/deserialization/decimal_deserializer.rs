@@ -2,43 +2,63 @@ use serde::de::Visitor;
 
 use crate::internal::{
     arrow::DecimalArrayView,
-    error::{set_default, try_, Context, ContextSupport, Result},
-    utils::{decimal, Mut},
+    deserializer_options::DecimalFormat,
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::{
+        decimal::{self, DecimalValue},
+        Mut,
+    },
 };
 
 use super::{simple_deserializer::SimpleDeserializer, utils::ArrayBufferIterator};
 
-pub struct DecimalDeserializer<'a> {
+#[derive(Clone)]
+pub struct DecimalDeserializer<'a, T: Copy = i128> {
     path: String,
-    inner: ArrayBufferIterator<'a, i128>,
+    inner: ArrayBufferIterator<'a, T>,
     scale: i8,
+    /// The scale of integers, if deserialized with the `ScaledInt` strategy
+    int_scale: Option<i8>,
+    pub format: DecimalFormat,
 }
 
-impl<'a> DecimalDeserializer<'a> {
-    pub fn new(path: String, view: DecimalArrayView<'a, i128>) -> Self {
+impl<'a, T: DecimalValue> DecimalDeserializer<'a, T> {
+    pub fn new(path: String, view: DecimalArrayView<'a, T>, int_scale: Option<i8>) -> Self {
         Self {
             path,
             inner: ArrayBufferIterator::new(view.values, view.validity),
             scale: view.scale,
+            int_scale,
+            format: DecimalFormat::default(),
         }
     }
+
+    fn next_scaled_int(&mut self) -> Result<i128> {
+        let Some(int_scale) = self.int_scale else {
+            fail!("Decimals can only be deserialized into integers with the ScaledInt strategy");
+        };
+        let val = self.inner.next_required()?;
+        val.rescale(self.scale, int_scale)?.to_i128()
+    }
 }
 
-impl<'de> Context for DecimalDeserializer<'de> {
+impl<'de, T: DecimalValue> Context for DecimalDeserializer<'de, T> {
     fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
         set_default(annotations, "field", &self.path);
-        set_default(annotations, "data_type", "Decimal128(..)");
+        set_default(annotations, "data_type", T::DATA_TYPE);
     }
 }
 
-impl<'de> SimpleDeserializer<'de> for DecimalDeserializer<'de> {
+impl<'de, T: DecimalValue> SimpleDeserializer<'de> for DecimalDeserializer<'de, T> {
     fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
-            if self.inner.peek_next()? {
-                self.deserialize_str(visitor)
-            } else {
+            if !self.inner.peek_next()? {
                 self.inner.consume_next();
                 visitor.visit_none()
+            } else if self.int_scale.is_some() {
+                self.deserialize_i64(visitor)
+            } else {
+                self.deserialize_str(visitor)
             }
         })
         .ctx(self)
@@ -56,14 +76,58 @@ impl<'de> SimpleDeserializer<'de> for DecimalDeserializer<'de> {
         .ctx(self)
     }
 
+    fn deserialize_i8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i8(i8::try_from(self.next_scaled_int()?)?)).ctx(self)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i16(i16::try_from(self.next_scaled_int()?)?)).ctx(self)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i32(i32::try_from(self.next_scaled_int()?)?)).ctx(self)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i64(i64::try_from(self.next_scaled_int()?)?)).ctx(self)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u8(u8::try_from(self.next_scaled_int()?)?)).ctx(self)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u16(u16::try_from(self.next_scaled_int()?)?)).ctx(self)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u32(u32::try_from(self.next_scaled_int()?)?)).ctx(self)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u64(u64::try_from(self.next_scaled_int()?)?)).ctx(self)
+    }
+
     fn deserialize_str<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             let val = self.inner.next_required()?;
-            let mut buffer = [0; decimal::BUFFER_SIZE_I128];
-            let formatted = decimal::format_decimal(&mut buffer, val, self.scale);
+            let mut buffer = [0; decimal::FORMAT_BUFFER_SIZE_I256];
+            let formatted = match self.format {
+                DecimalFormat::Exact => decimal::format_decimal(&mut buffer, val, self.scale),
+                DecimalFormat::Normalized => {
+                    decimal::format_decimal_normalized(&mut buffer, val, self.scale)
+                }
+                DecimalFormat::Scientific => {
+                    decimal::format_decimal_scientific(&mut buffer, val, self.scale)
+                }
+            };
 
             visitor.visit_str(formatted)
         })
         .ctx(self)
     }
+
+    fn deserialize_string<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| self.deserialize_str(visitor)).ctx(self)
+    }
 }
@@ -0,0 +1,111 @@
+use serde::{de::IgnoredAny, de::Visitor, Deserializer};
+
+use crate::internal::{
+    arrow::ArrayView,
+    error::{set_default, try_, Context, ContextSupport, Error, Result},
+    schema::DefaultValue,
+    utils::array_view_ext::ArrayViewExt,
+};
+
+use super::{array_deserializer::ArrayDeserializer, simple_deserializer::SimpleDeserializer};
+
+/// Deserialize null values from the default value of the field, see
+/// [`DEFAULT_VALUE_KEY`][crate::schema::DEFAULT_VALUE_KEY]
+#[derive(Clone)]
+pub struct DefaultValueDeserializer<'a> {
+    pub path: String,
+    pub view: ArrayView<'a>,
+    pub default: DefaultValue,
+    pub inner: Box<ArrayDeserializer<'a>>,
+    pub next: usize,
+}
+
+impl<'a> DefaultValueDeserializer<'a> {
+    pub fn new(
+        path: String,
+        view: ArrayView<'a>,
+        default: DefaultValue,
+        inner: ArrayDeserializer<'a>,
+    ) -> Self {
+        Self {
+            path,
+            view,
+            default,
+            inner: Box::new(inner),
+            next: 0,
+        }
+    }
+
+    /// Consume the next row and return the default value, if it is null
+    fn next_default(&mut self) -> Result<Option<DefaultValue>> {
+        let is_valid = self.view.is_valid(self.next)?;
+        if is_valid {
+            self.next += 1;
+            return Ok(None);
+        }
+        self.inner.deserialize_ignored_any(IgnoredAny)?;
+        self.next += 1;
+        Ok(Some(self.default.clone()))
+    }
+}
+
+fn from_default<T>(res: Result<T, serde_json::Error>) -> Result<T> {
+    res.map_err(|err| {
+        Error::custom_from(format!("Cannot deserialize the default value: {err}"), err)
+    })
+}
+
+impl<'de> Context for DefaultValueDeserializer<'de> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        self.inner.annotate(annotations);
+        set_default(annotations, "field", &self.path);
+    }
+}
+
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(&mut self, $($arg: $ty,)* visitor: V) -> Result<V::Value> {
+                try_(|| match self.next_default()? {
+                    Some(default) => from_default(default.$method($($arg,)* visitor)),
+                    None => self.inner.$method($($arg,)* visitor),
+                })
+                .ctx(self)
+            }
+        )*
+    };
+}
+
+impl<'de> SimpleDeserializer<'de> for DefaultValueDeserializer<'de> {
+    forward! {
+        deserialize_any();
+        deserialize_ignored_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_byte_buf();
+        deserialize_bytes();
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_option();
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_tuple(len: usize);
+        deserialize_seq();
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+    }
+}
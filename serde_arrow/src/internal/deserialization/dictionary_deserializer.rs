@@ -11,6 +11,7 @@ use super::{
     simple_deserializer::SimpleDeserializer, utils::ArrayBufferIterator,
 };
 
+#[derive(Clone)]
 pub struct DictionaryDeserializer<'a, K: Integer, V: Offset> {
     path: String,
     keys: ArrayBufferIterator<'a, K>,
@@ -36,7 +37,7 @@ impl<'a, K: Integer, V: Offset> DictionaryDeserializer<'a, K, V> {
         })
     }
 
-    pub fn next_str(&mut self) -> Result<&str> {
+    pub fn next_str(&mut self) -> Result<&'a str> {
         let k: usize = self.keys.next_required()?.into_u64()?.try_into()?;
         let Some(start) = self.offsets.get(k) else {
             fail!("Invalid index");
@@ -48,7 +49,11 @@ impl<'a, K: Integer, V: Offset> DictionaryDeserializer<'a, K, V> {
         };
         let end = end.try_into_usize()?;
 
-        let s = std::str::from_utf8(&self.data[start..end])?;
+        let data: &'a [u8] = self.data;
+        let Some(data) = data.get(start..end) else {
+            fail!("Invalid offsets: the range {start}..{end} exceeds the data");
+        };
+        let s = std::str::from_utf8(data)?;
         Ok(s)
     }
 }
@@ -86,13 +91,17 @@ impl<'de, K: Integer, V: Offset> SimpleDeserializer<'de> for DictionaryDeseriali
     }
 
     fn deserialize_str<VV: Visitor<'de>>(&mut self, visitor: VV) -> Result<VV::Value> {
-        try_(|| visitor.visit_str(self.next_str()?)).ctx(self)
+        try_(|| visitor.visit_borrowed_str(self.next_str()?)).ctx(self)
     }
 
     fn deserialize_string<VV: Visitor<'de>>(&mut self, visitor: VV) -> Result<VV::Value> {
         try_(|| visitor.visit_string(self.next_str()?.to_owned())).ctx(self)
     }
 
+    fn deserialize_bytes<VV: Visitor<'de>>(&mut self, visitor: VV) -> Result<VV::Value> {
+        try_(|| visitor.visit_borrowed_bytes(self.next_str()?.as_bytes())).ctx(self)
+    }
+
     fn deserialize_enum<VV: Visitor<'de>>(
         &mut self,
         _: &'static str,
@@ -0,0 +1,134 @@
+use serde::de::{IgnoredAny, Visitor};
+
+use crate::internal::{
+    arrow::BitsWithOffset,
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::Mut,
+};
+
+use super::{
+    array_deserializer::ArrayDeserializer, simple_deserializer::SimpleDeserializer,
+    utils::bitset_is_set,
+};
+
+/// Deserialize strings stored either in a dictionary or in an overflow field,
+/// see [`Strategy::DictionaryOverflow`][crate::schema::Strategy::DictionaryOverflow]
+#[derive(Clone)]
+pub struct DictionaryOverflowDeserializer<'a> {
+    pub path: String,
+    pub values: Box<ArrayDeserializer<'a>>,
+    pub overflow: Box<ArrayDeserializer<'a>>,
+    pub overflow_validity: Option<BitsWithOffset<'a>>,
+    pub validity: Option<BitsWithOffset<'a>>,
+    pub next: usize,
+    pub len: usize,
+}
+
+impl<'a> DictionaryOverflowDeserializer<'a> {
+    pub fn new(
+        path: String,
+        values: ArrayDeserializer<'a>,
+        overflow: ArrayDeserializer<'a>,
+        overflow_validity: Option<BitsWithOffset<'a>>,
+        validity: Option<BitsWithOffset<'a>>,
+        len: usize,
+    ) -> Self {
+        Self {
+            path,
+            values: Box::new(values),
+            overflow: Box::new(overflow),
+            overflow_validity,
+            validity,
+            next: 0,
+            len,
+        }
+    }
+
+    fn peek_next(&self) -> Result<bool> {
+        if self.next >= self.len {
+            fail!("Exhausted deserializer");
+        }
+        if let Some(validity) = &self.validity {
+            Ok(bitset_is_set(validity, self.next)?)
+        } else {
+            Ok(true)
+        }
+    }
+
+    fn consume_null(&mut self) -> Result<()> {
+        self.values.deserialize_ignored_any(IgnoredAny)?;
+        self.overflow.deserialize_ignored_any(IgnoredAny)?;
+        self.next += 1;
+        Ok(())
+    }
+
+    /// Consume the next row of the unused child and return the child holding
+    /// the value
+    fn next_source(&mut self) -> Result<&mut ArrayDeserializer<'a>> {
+        let in_overflow = match &self.overflow_validity {
+            Some(validity) => bitset_is_set(validity, self.next)?,
+            None => true,
+        };
+        self.next += 1;
+        if in_overflow {
+            self.values.deserialize_ignored_any(IgnoredAny)?;
+            Ok(&mut self.overflow)
+        } else {
+            self.overflow.deserialize_ignored_any(IgnoredAny)?;
+            Ok(&mut self.values)
+        }
+    }
+}
+
+impl<'de> Context for DictionaryOverflowDeserializer<'de> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "Struct(..)");
+    }
+}
+
+impl<'de> SimpleDeserializer<'de> for DictionaryOverflowDeserializer<'de> {
+    fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if self.peek_next()? {
+                self.next_source()?.deserialize_any(visitor)
+            } else {
+                self.consume_null()?;
+                visitor.visit_none()
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if self.peek_next()? {
+                visitor.visit_some(Mut(&mut *self))
+            } else {
+                self.consume_null()?;
+                visitor.visit_none()
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if !self.peek_next()? {
+                fail!("Cannot deserialize a null value into a string, consider using an Option");
+            }
+            self.next_source()?.deserialize_str(visitor)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if !self.peek_next()? {
+                fail!("Cannot deserialize a null value into a string, consider using an Option");
+            }
+            self.next_source()?.deserialize_string(visitor)
+        })
+        .ctx(self)
+    }
+}
@@ -1,18 +1,21 @@
-use serde::de::Visitor;
+use serde::de::{value::SeqDeserializer, Visitor};
 
 use crate::internal::{
     arrow::{PrimitiveArrayView, TimeUnit},
     chrono,
-    error::{set_default, try_, Context, ContextSupport, Result},
+    deserializer_options::DurationFormat,
+    error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
     utils::Mut,
 };
 
 use super::{simple_deserializer::SimpleDeserializer, utils::ArrayBufferIterator};
 
+#[derive(Clone)]
 pub struct DurationDeserializer<'a> {
     path: String,
     unit: TimeUnit,
     array: ArrayBufferIterator<'a, i64>,
+    pub format: DurationFormat,
 }
 
 impl<'a> DurationDeserializer<'a> {
@@ -21,12 +24,47 @@ impl<'a> DurationDeserializer<'a> {
             path,
             unit,
             array: ArrayBufferIterator::new(view.values, view.validity),
+            format: DurationFormat::default(),
         }
     }
 
     pub fn next_string_value_required(&mut self) -> Result<String> {
         let value = self.array.next_required()?;
-        Ok(chrono::format_arrow_duration_as_span(value, self.unit))
+        Ok(match self.format {
+            DurationFormat::Iso8601 => chrono::format_arrow_duration_as_span(value, self.unit),
+            DurationFormat::Humantime => {
+                chrono::format_arrow_duration_as_humantime(value, self.unit)
+            }
+        })
+    }
+
+    /// Split the next value into seconds and nanoseconds, the serde
+    /// representation of `std::time::Duration`
+    ///
+    /// Negative durations cannot be represented and result in an error.
+    pub fn next_secs_nanos_required(&mut self) -> Result<(u64, u32)> {
+        let value = self.array.next_required()?;
+        let Ok(value) = u64::try_from(value) else {
+            fail!("Cannot deserialize negative duration {value} as std::time::Duration");
+        };
+        let (secs, nanos) = match self.unit {
+            TimeUnit::Second => (value, 0),
+            TimeUnit::Millisecond => (value / 1_000, (value % 1_000) * 1_000_000),
+            TimeUnit::Microsecond => (value / 1_000_000, (value % 1_000_000) * 1_000),
+            TimeUnit::Nanosecond => (value / 1_000_000_000, value % 1_000_000_000),
+        };
+        Ok((secs, nanos.try_into()?))
+    }
+
+    fn next_integer_required<T: TryFrom<i64>>(&mut self) -> Result<T> {
+        let value = self.array.next_required()?;
+        match T::try_from(value) {
+            Ok(value) => Ok(value),
+            Err(_) => fail!(
+                "Duration {value} is out of range for {ty}",
+                ty = std::any::type_name::<T>(),
+            ),
+        }
     }
 }
 
@@ -41,7 +79,7 @@ impl<'de> SimpleDeserializer<'de> for DurationDeserializer<'de> {
     fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.array.peek_next()? {
-                self.deserialize_i64(visitor)
+                self.deserialize_str(visitor)
             } else {
                 self.array.consume_next();
                 visitor.visit_none()
@@ -62,10 +100,54 @@ impl<'de> SimpleDeserializer<'de> for DurationDeserializer<'de> {
         .ctx(self)
     }
 
+    fn deserialize_i8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i8(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i16(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i32(self.next_integer_required()?)).ctx(self)
+    }
+
     fn deserialize_i64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| visitor.visit_i64(self.array.next_required()?)).ctx(self)
     }
 
+    fn deserialize_u8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u8(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u16(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u32(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u64(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &mut self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| {
+            let (secs, nanos) = self.next_secs_nanos_required()?;
+            let mut seq = SeqDeserializer::<_, Error>::new([secs, u64::from(nanos)].into_iter());
+            let res = visitor.visit_seq(&mut seq)?;
+            seq.end()?;
+            Ok(res)
+        })
+        .ctx(self)
+    }
+
     fn deserialize_str<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| visitor.visit_str(self.next_string_value_required()?.as_str())).ctx(self)
     }
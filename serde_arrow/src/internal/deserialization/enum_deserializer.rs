@@ -1,18 +1,28 @@
 use std::collections::BTreeMap;
 
-use serde::de::{DeserializeSeed, Deserializer, EnumAccess, Visitor};
+use serde::de::{DeserializeSeed, Deserializer, EnumAccess, MapAccess, Visitor};
 
 use crate::internal::{
     error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
     utils::Mut,
+    variant_extractors::VariantExtractors,
 };
 
 use super::{array_deserializer::ArrayDeserializer, simple_deserializer::SimpleDeserializer};
 
+#[derive(Clone)]
 pub struct EnumDeserializer<'a> {
     pub path: String,
     pub type_ids: &'a [i8],
     pub variants: Vec<(String, ArrayDeserializer<'a>)>,
+    /// The index of the variant used to encode missing values, if any
+    pub null_variant: Option<usize>,
+    /// If `true`, values of variants with data type Null are returned as
+    /// `None` in `deserialize_any`
+    pub null_variants_as_none: bool,
+    /// If `true`, the values of the variants are returned without the variant
+    /// name in `deserialize_any`
+    pub untagged: bool,
     pub next: usize,
 }
 
@@ -21,14 +31,78 @@ impl<'a> EnumDeserializer<'a> {
         path: String,
         type_ids: &'a [i8],
         variants: Vec<(String, ArrayDeserializer<'a>)>,
+        null_variant: Option<usize>,
     ) -> Self {
         Self {
             path,
             type_ids,
             variants,
+            null_variant,
+            null_variants_as_none: false,
+            untagged: false,
             next: 0,
         }
     }
+
+    pub fn with_untagged(mut self, untagged: bool) -> Self {
+        self.untagged = untagged;
+        self
+    }
+
+    /// Consume the next value if it is encoded via the null variant
+    ///
+    /// If `include_null_types` is `true`, values of any variant with data type
+    /// Null are consumed as well.
+    fn consume_null(&mut self, include_null_types: bool) -> Result<bool> {
+        if self.null_variant.is_none() && !include_null_types {
+            return Ok(false);
+        }
+        let Some(type_id) = self.type_ids.get(self.next) else {
+            fail!("Exhausted deserializer");
+        };
+        let variant = usize::try_from(*type_id)?;
+        let is_null = self.null_variant == Some(variant)
+            || (include_null_types
+                && matches!(
+                    self.variants.get(variant),
+                    Some((_, ArrayDeserializer::Null(_)))
+                ));
+        if !is_null {
+            return Ok(false);
+        }
+
+        self.next += 1;
+        self.variants[variant]
+            .1
+            .deserialize_ignored_any(serde::de::IgnoredAny)?;
+        Ok(true)
+    }
+}
+
+impl<'a> EnumDeserializer<'a> {
+    /// Convert the next value with the extractor registered for its variant
+    ///
+    /// Values encoded via the null variant are returned as `None`.
+    pub fn next_extracted<T>(&mut self, extractors: &VariantExtractors<T>) -> Result<Option<T>> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| {
+            if self.consume_null(false)? {
+                return Ok(None);
+            }
+            let Some(type_id) = self.type_ids.get(self.next).copied() else {
+                fail!("Exhausted deserializer");
+            };
+            self.next += 1;
+
+            let Some((name, variant)) = self.variants.get_mut(usize::try_from(type_id)?) else {
+                fail!("Invalid type id {type_id}");
+            };
+            Ok(Some(extractors.extract(name, variant)?))
+        })
+        .ctx(&ctx)
+    }
 }
 
 impl<'de> Context for EnumDeserializer<'de> {
@@ -39,6 +113,50 @@ impl<'de> Context for EnumDeserializer<'de> {
 }
 
 impl<'de> SimpleDeserializer<'de> for EnumDeserializer<'de> {
+    fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        // use the externally tagged representation, i.e., `{variant: value}`,
+        // unless the union is untagged
+        try_(|| {
+            if self.consume_null(self.null_variants_as_none || self.untagged)? {
+                return visitor.visit_none();
+            }
+            if self.untagged {
+                let Some(type_id) = self.type_ids.get(self.next).copied() else {
+                    fail!("Exhausted deserializer");
+                };
+                self.next += 1;
+
+                let Some((_, variant)) = self.variants.get_mut(usize::try_from(type_id)?) else {
+                    fail!("Invalid type id {type_id}");
+                };
+                return Mut(variant).deserialize_any(visitor);
+            }
+            visitor.visit_map(VariantMapAccess {
+                deserializer: self,
+                variant: None,
+                done: false,
+            })
+        })
+        .ctx(&ctx)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| {
+            if self.consume_null(false)? {
+                visitor.visit_none()
+            } else {
+                visitor.visit_some(Mut(&mut *self))
+            }
+        })
+        .ctx(&ctx)
+    }
+
     fn deserialize_enum<V: Visitor<'de>>(
         &mut self,
         _: &'static str,
@@ -63,7 +181,12 @@ impl<'a, 'de> EnumAccess<'de> for &'a mut EnumDeserializer<'de> {
         let type_id = self.type_ids[self.next];
         self.next += 1;
 
-        let (name, variant) = &mut self.variants[type_id as usize];
+        if self.null_variant == Some(usize::try_from(type_id)?) {
+            fail!("Cannot deserialize a null value into an enum, consider using an Option");
+        }
+        let Some((name, variant)) = self.variants.get_mut(usize::try_from(type_id)?) else {
+            fail!("Invalid type id {type_id}");
+        };
 
         let val = seed.deserialize(VariantIdDeserializer { type_id, name })?;
 
@@ -71,6 +194,45 @@ impl<'a, 'de> EnumAccess<'de> for &'a mut EnumDeserializer<'de> {
     }
 }
 
+struct VariantMapAccess<'a, 'de> {
+    deserializer: &'a mut EnumDeserializer<'de>,
+    variant: Option<usize>,
+    done: bool,
+}
+
+impl<'a, 'de> MapAccess<'de> for VariantMapAccess<'a, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.done {
+            return Ok(None);
+        }
+        if self.deserializer.next >= self.deserializer.type_ids.len() {
+            fail!("Exhausted deserializer");
+        }
+        let type_id = self.deserializer.type_ids[self.deserializer.next];
+        self.deserializer.next += 1;
+        self.done = true;
+
+        let variant = usize::try_from(type_id)?;
+        let Some((name, _)) = self.deserializer.variants.get(variant) else {
+            fail!("Invalid type id {type_id}");
+        };
+        self.variant = Some(variant);
+
+        Ok(Some(
+            seed.deserialize(VariantIdDeserializer { type_id, name })?,
+        ))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let Some(variant) = self.variant.take() else {
+            fail!("Invalid state: next_value_seed called before next_key_seed");
+        };
+        seed.deserialize(Mut(&mut self.deserializer.variants[variant].1))
+    }
+}
+
 struct VariantIdDeserializer<'a> {
     type_id: i8,
     name: &'a str,
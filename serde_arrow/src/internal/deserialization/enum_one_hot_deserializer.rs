@@ -0,0 +1,187 @@
+use serde::de::{value::StrDeserializer, DeserializeSeed, EnumAccess, VariantAccess, Visitor};
+
+use crate::internal::{
+    arrow::{BitsWithOffset, BooleanArrayView},
+    error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
+    utils::Mut,
+};
+
+use super::{simple_deserializer::SimpleDeserializer, utils::bitset_is_set};
+
+/// Deserialize unit enums from structs with one boolean flag per variant, see
+/// [`Strategy::EnumOneHot`][crate::schema::Strategy::EnumOneHot]
+#[derive(Clone)]
+pub struct EnumOneHotDeserializer<'a> {
+    pub path: String,
+    pub variants: Vec<(String, BooleanArrayView<'a>)>,
+    pub validity: Option<BitsWithOffset<'a>>,
+    pub next: usize,
+    pub len: usize,
+}
+
+impl<'a> EnumOneHotDeserializer<'a> {
+    pub fn new(
+        path: String,
+        variants: Vec<(String, BooleanArrayView<'a>)>,
+        validity: Option<BitsWithOffset<'a>>,
+        len: usize,
+    ) -> Result<Self> {
+        for (name, view) in &variants {
+            if view.len < len {
+                fail!("The flags of variant {name} are shorter than the struct");
+            }
+        }
+        Ok(Self {
+            path,
+            variants,
+            validity,
+            next: 0,
+            len,
+        })
+    }
+
+    fn peek_next(&self) -> Result<bool> {
+        if self.next >= self.len {
+            fail!("Exhausted deserializer");
+        }
+        if let Some(validity) = &self.validity {
+            Ok(bitset_is_set(validity, self.next)?)
+        } else {
+            Ok(true)
+        }
+    }
+
+    /// Consume the next row and return the index of the variant whose flag
+    /// is set
+    fn next_variant(&mut self) -> Result<usize> {
+        let idx = self.next;
+        let mut selected: Option<usize> = None;
+        for (variant_idx, (name, view)) in self.variants.iter().enumerate() {
+            if let Some(validity) = &view.validity {
+                if !bitset_is_set(validity, idx)? {
+                    continue;
+                }
+            }
+            if !bitset_is_set(&view.values, idx)? {
+                continue;
+            }
+            if let Some(prev) = selected {
+                let prev = &self.variants[prev].0;
+                fail!("The flags of multiple variants are set: {prev} and {name}");
+            }
+            selected = Some(variant_idx);
+        }
+        self.next += 1;
+
+        let Some(selected) = selected else {
+            fail!("None of the variant flags is set");
+        };
+        Ok(selected)
+    }
+}
+
+impl<'de> Context for EnumOneHotDeserializer<'de> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "Struct(..)");
+    }
+}
+
+impl<'de> SimpleDeserializer<'de> for EnumOneHotDeserializer<'de> {
+    fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if self.peek_next()? {
+                let selected = self.next_variant()?;
+                visitor.visit_str(&self.variants[selected].0)
+            } else {
+                self.next += 1;
+                visitor.visit_none()
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if self.peek_next()? {
+                visitor.visit_some(Mut(&mut *self))
+            } else {
+                self.next += 1;
+                visitor.visit_none()
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if !self.peek_next()? {
+                fail!("Cannot deserialize a null value into a string, consider using an Option");
+            }
+            let selected = self.next_variant()?;
+            visitor.visit_str(&self.variants[selected].0)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        &mut self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| {
+            if !self.peek_next()? {
+                fail!("Cannot deserialize a null value into an enum, consider using an Option");
+            }
+            let selected = self.next_variant()?;
+            visitor.visit_enum(UnitVariant(&self.variants[selected].0))
+        })
+        .ctx(self)
+    }
+}
+
+struct UnitVariant<'a>(&'a str);
+
+impl<'a, 'de> EnumAccess<'de> for UnitVariant<'a> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let variant = seed.deserialize(StrDeserializer::<Error>::new(self.0))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'a, 'de> VariantAccess<'de> for UnitVariant<'a> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, _: T) -> Result<T::Value> {
+        fail!(
+            "Variant {name} is stored as a flag and has no data",
+            name = self.0
+        )
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, _: V) -> Result<V::Value> {
+        fail!(
+            "Variant {name} is stored as a flag and has no data",
+            name = self.0
+        )
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, _: &'static [&'static str], _: V) -> Result<V::Value> {
+        fail!(
+            "Variant {name} is stored as a flag and has no data",
+            name = self.0
+        )
+    }
+}
@@ -2,23 +2,32 @@ use serde::de::{SeqAccess, Visitor};
 
 use crate::internal::{
     arrow::FixedSizeBinaryArrayView,
+    deserializer_options::BinaryFormat,
     error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
-    utils::Mut,
+    utils::{
+        encoding::{base64_encode, hex_encode},
+        Mut,
+    },
 };
 
 use super::{simple_deserializer::SimpleDeserializer, utils::bitset_is_set};
 
+#[derive(Clone)]
 pub struct FixedSizeBinaryDeserializer<'a> {
     pub path: String,
     pub view: FixedSizeBinaryArrayView<'a>,
     pub next: (usize, usize),
     pub shape: (usize, usize),
+    pub format: BinaryFormat,
 }
 
 impl<'a> FixedSizeBinaryDeserializer<'a> {
     pub fn new(path: String, view: FixedSizeBinaryArrayView<'a>) -> Result<Self> {
         let n = usize::try_from(view.n)?;
-        if view.data.len() % n != 0 {
+        if n == 0 {
+            fail!("Unsupported: FixedSizeBinary arrays with zero sized elements");
+        }
+        if !view.data.len().is_multiple_of(n) {
             fail!(
                 concat!(
                     "Invalid FixedSizeBinary array: Data of len {len} is not ",
@@ -35,6 +44,7 @@ impl<'a> FixedSizeBinaryDeserializer<'a> {
             view,
             shape,
             next: (0, 0),
+            format: BinaryFormat::default(),
         })
     }
 
@@ -75,7 +85,11 @@ impl<'a> SimpleDeserializer<'a> for FixedSizeBinaryDeserializer<'a> {
     fn deserialize_any<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.peek_next()? {
-                self.deserialize_bytes(visitor)
+                match self.format {
+                    BinaryFormat::Bytes => self.deserialize_seq(visitor),
+                    BinaryFormat::Base64 => visitor.visit_string(base64_encode(self.next_slice()?)),
+                    BinaryFormat::Hex => visitor.visit_string(hex_encode(self.next_slice()?)),
+                }
             } else {
                 self.consume_next();
                 visitor.visit_none()
@@ -107,11 +121,31 @@ impl<'a> SimpleDeserializer<'a> for FixedSizeBinaryDeserializer<'a> {
     fn deserialize_byte_buf<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| visitor.visit_borrowed_bytes(self.next_slice()?)).ctx(self)
     }
+
+    fn deserialize_str<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            let bytes = self.next_slice()?;
+            match self.format {
+                BinaryFormat::Bytes => visitor.visit_borrowed_str(std::str::from_utf8(bytes)?),
+                BinaryFormat::Base64 => visitor.visit_string(base64_encode(bytes)),
+                BinaryFormat::Hex => visitor.visit_string(hex_encode(bytes)),
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_string<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| self.deserialize_str(visitor)).ctx(self)
+    }
 }
 
 impl<'de> SeqAccess<'de> for FixedSizeBinaryDeserializer<'de> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.shape.1.saturating_sub(self.next.1))
+    }
+
     fn next_element_seed<T: serde::de::DeserializeSeed<'de>>(
         &mut self,
         seed: T,
@@ -138,6 +172,10 @@ impl Context for U8Deserializer {
 }
 
 impl<'de> SimpleDeserializer<'de> for U8Deserializer {
+    fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u8(self.0)
+    }
+
     fn deserialize_u8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         visitor.visit_u8(self.0)
     }
@@ -7,10 +7,12 @@ use crate::internal::{
 };
 
 use super::{
-    array_deserializer::ArrayDeserializer, simple_deserializer::SimpleDeserializer,
-    utils::bitset_is_set,
+    array_deserializer::ArrayDeserializer,
+    simple_deserializer::SimpleDeserializer,
+    utils::{bitset_is_set, with_list_length},
 };
 
+#[derive(Clone)]
 pub struct FixedSizeListDeserializer<'a> {
     pub path: String,
     pub item: Box<ArrayDeserializer<'a>>,
@@ -90,13 +92,60 @@ impl<'a> SimpleDeserializer<'a> for FixedSizeListDeserializer<'a> {
     }
 
     fn deserialize_seq<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_seq(&mut *self)).ctx(self)
+        try_(|| {
+            let len = self.shape.1;
+            visitor
+                .visit_seq(&mut *self)
+                .map_err(|err| with_list_length(err, len))
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'a>>(&mut self, len: usize, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if !self.peek_next()? {
+                fail!(
+                    "Cannot deserialize null list {idx} into a fixed size container of length {len}",
+                    idx = self.next.0,
+                );
+            }
+            if self.shape.1 != len {
+                fail!(
+                    "Cannot deserialize list {idx} of length {actual} into a fixed size container of length {len}",
+                    idx = self.next.0,
+                    actual = self.shape.1,
+                );
+            }
+
+            let idx = self.next.0;
+            let res = visitor.visit_seq(&mut *self)?;
+
+            // tuple visitors do not request the end of the sequence
+            if self.next.0 == idx {
+                self.next = (idx + 1, 0);
+            }
+            Ok(res)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'a>>(
+        &mut self,
+        _: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
     }
 }
 
 impl<'de> SeqAccess<'de> for FixedSizeListDeserializer<'de> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.shape.1.saturating_sub(self.next.1))
+    }
+
     fn next_element_seed<T: serde::de::DeserializeSeed<'de>>(
         &mut self,
         seed: T,
@@ -2,7 +2,7 @@ use serde::de::Visitor;
 
 use crate::internal::{
     arrow::PrimitiveArrayView,
-    error::{set_default, try_, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::{Mut, NamedType},
 };
 
@@ -18,9 +18,13 @@ pub trait Float: Copy {
     fn into_f64(self) -> Result<f64>;
 }
 
+#[derive(Clone)]
 pub struct FloatDeserializer<'a, F: Float> {
     path: String,
     array: ArrayBufferIterator<'a, F>,
+    /// If `true`, the values can be deserialized as integers and the
+    /// conversion to `f32` is checked for overflows
+    pub numeric_coercion: bool,
 }
 
 impl<'a, F: Float> FloatDeserializer<'a, F> {
@@ -28,8 +32,41 @@ impl<'a, F: Float> FloatDeserializer<'a, F> {
         Self {
             path,
             array: ArrayBufferIterator::new(view.values, view.validity),
+            numeric_coercion: false,
         }
     }
+
+    /// Consume the next value and convert it into an integer without loss
+    fn next_as_integer<I: TryFrom<i128>>(&mut self, target: &str) -> Result<I> {
+        if !self.numeric_coercion {
+            fail!("Deserializing floats as integers requires numeric coercion, see DeserializerOptions::numeric_coercion");
+        }
+        let row = self.array.next;
+        let value = self.array.next_required()?.into_f64()?;
+        if !value.is_finite() || value.fract() != 0.0 {
+            fail!("Value {value} in row {row} is not an integer");
+        }
+        // values outside the range of i128 saturate, but are out of range of
+        // all targets anyway
+        match I::try_from(value as i128) {
+            Ok(int_value) => Ok(int_value),
+            Err(_) => fail!("Value {value} in row {row} is out of range for {target}"),
+        }
+    }
+
+    fn next_as_f32(&mut self) -> Result<f32> {
+        let row = self.array.next;
+        let value = self.array.next_required()?;
+        if !self.numeric_coercion {
+            return value.into_f32();
+        }
+        let value = value.into_f64()?;
+        let narrowed = value as f32;
+        if value.is_finite() && !narrowed.is_finite() {
+            fail!("Value {value} in row {row} is out of range for f32");
+        }
+        Ok(narrowed)
+    }
 }
 
 impl<'de, F: NamedType + Float> Context for FloatDeserializer<'de, F> {
@@ -74,10 +111,42 @@ impl<'de, F: NamedType + Float> SimpleDeserializer<'de> for FloatDeserializer<'d
     }
 
     fn deserialize_f32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_f32(self.array.next_required()?.into_f32()?)).ctx(self)
+        try_(|| visitor.visit_f32(self.next_as_f32()?)).ctx(self)
     }
 
     fn deserialize_f64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| visitor.visit_f64(self.array.next_required()?.into_f64()?)).ctx(self)
     }
+
+    fn deserialize_i8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i8(self.next_as_integer("i8")?)).ctx(self)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i16(self.next_as_integer("i16")?)).ctx(self)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i32(self.next_as_integer("i32")?)).ctx(self)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i64(self.next_as_integer("i64")?)).ctx(self)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u8(self.next_as_integer("u8")?)).ctx(self)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u16(self.next_as_integer("u16")?)).ctx(self)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u32(self.next_as_integer("u32")?)).ctx(self)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u64(self.next_as_integer("u64")?)).ctx(self)
+    }
 }
@@ -2,19 +2,20 @@ use serde::de::Visitor;
 
 use crate::internal::{
     arrow::PrimitiveArrayView,
-    error::{set_default, try_, Context, ContextSupport, Result},
-    utils::{Mut, NamedType},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::{expects_non_zero, quantization::Quantization, Mut, NamedType},
 };
 
 use super::{simple_deserializer::SimpleDeserializer, utils::ArrayBufferIterator};
 
-pub trait Integer: Sized + Copy {
+pub trait Integer: Sized + Copy + std::fmt::Display {
     fn deserialize_any<'de, S: SimpleDeserializer<'de>, V: Visitor<'de>>(
         deser: &mut S,
         visitor: V,
     ) -> Result<V::Value>;
 
     fn into_bool(self) -> Result<bool>;
+    fn is_zero(self) -> bool;
 
     fn into_i8(self) -> Result<i8>;
     fn into_i16(self) -> Result<i16>;
@@ -25,11 +26,19 @@ pub trait Integer: Sized + Copy {
     fn into_u16(self) -> Result<u16>;
     fn into_u32(self) -> Result<u32>;
     fn into_u64(self) -> Result<u64>;
+
+    fn into_f32(self) -> f32;
+    fn into_f64(self) -> f64;
 }
 
+#[derive(Clone)]
 pub struct IntegerDeserializer<'a, T: Integer> {
     path: String,
     array: ArrayBufferIterator<'a, T>,
+    /// If `true`, the values can be deserialized as floats
+    pub numeric_coercion: bool,
+    /// The quantization of floats, if deserialized with the `Quantized` strategy
+    quantization: Option<Quantization>,
 }
 
 impl<'a, T: Integer> IntegerDeserializer<'a, T> {
@@ -37,7 +46,45 @@ impl<'a, T: Integer> IntegerDeserializer<'a, T> {
         Self {
             path,
             array: ArrayBufferIterator::new(view.values, view.validity),
+            numeric_coercion: false,
+            quantization: None,
+        }
+    }
+
+    pub fn with_quantization(mut self, quantization: Option<Quantization>) -> Self {
+        self.quantization = quantization;
+        self
+    }
+
+    /// Consume the next value and convert it, reporting the row of values out
+    /// of range
+    fn next_as<'de, U, V: Visitor<'de>>(
+        &mut self,
+        visitor: &V,
+        target: &str,
+        convert: fn(T) -> Result<U>,
+    ) -> Result<U> {
+        let row = self.array.next;
+        let value = self.array.next_required()?;
+        if value.is_zero() && expects_non_zero(visitor) {
+            fail!("Value 0 in row {row} cannot be deserialized as a non-zero {target}");
         }
+        match convert(value) {
+            Ok(value) => Ok(value),
+            Err(_) => fail!("Value {value} in row {row} is out of range for {target}"),
+        }
+    }
+
+    fn next_as_float(&mut self) -> Result<T> {
+        if !self.numeric_coercion {
+            fail!("Deserializing integers as floats requires numeric coercion, see DeserializerOptions::numeric_coercion");
+        }
+        self.array.next_required()
+    }
+
+    fn next_dequantized(&mut self, quantization: Quantization) -> Result<f64> {
+        let value = self.array.next_required()?;
+        Ok(quantization.dequantize(value.into_i64()?))
     }
 }
 
@@ -66,6 +113,9 @@ impl<'de, T: NamedType + Integer> SimpleDeserializer<'de> for IntegerDeserialize
     fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.array.peek_next()? {
+                if self.quantization.is_some() {
+                    return self.deserialize_f32(visitor);
+                }
                 T::deserialize_any(&mut *self, visitor)
             } else {
                 self.array.consume_next();
@@ -96,34 +146,86 @@ impl<'de, T: NamedType + Integer> SimpleDeserializer<'de> for IntegerDeserialize
     }
 
     fn deserialize_u8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_u8(self.array.next_required()?.into_u8()?)).ctx(self)
+        try_(|| {
+            let value = self.next_as(&visitor, "u8", T::into_u8)?;
+            visitor.visit_u8(value)
+        })
+        .ctx(self)
     }
 
     fn deserialize_u16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_u16(self.array.next_required()?.into_u16()?)).ctx(self)
+        try_(|| {
+            let value = self.next_as(&visitor, "u16", T::into_u16)?;
+            visitor.visit_u16(value)
+        })
+        .ctx(self)
     }
 
     fn deserialize_u32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_u32(self.array.next_required()?.into_u32()?)).ctx(self)
+        try_(|| {
+            let value = self.next_as(&visitor, "u32", T::into_u32)?;
+            visitor.visit_u32(value)
+        })
+        .ctx(self)
     }
 
     fn deserialize_u64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_u64(self.array.next_required()?.into_u64()?)).ctx(self)
+        try_(|| {
+            let value = self.next_as(&visitor, "u64", T::into_u64)?;
+            visitor.visit_u64(value)
+        })
+        .ctx(self)
     }
 
     fn deserialize_i8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_i8(self.array.next_required()?.into_i8()?)).ctx(self)
+        try_(|| {
+            let value = self.next_as(&visitor, "i8", T::into_i8)?;
+            visitor.visit_i8(value)
+        })
+        .ctx(self)
     }
 
     fn deserialize_i16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_i16(self.array.next_required()?.into_i16()?)).ctx(self)
+        try_(|| {
+            let value = self.next_as(&visitor, "i16", T::into_i16)?;
+            visitor.visit_i16(value)
+        })
+        .ctx(self)
     }
 
     fn deserialize_i32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_i32(self.array.next_required()?.into_i32()?)).ctx(self)
+        try_(|| {
+            let value = self.next_as(&visitor, "i32", T::into_i32)?;
+            visitor.visit_i32(value)
+        })
+        .ctx(self)
     }
 
     fn deserialize_i64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_i64(self.array.next_required()?.into_i64()?)).ctx(self)
+        try_(|| {
+            let value = self.next_as(&visitor, "i64", T::into_i64)?;
+            visitor.visit_i64(value)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if let Some(quantization) = self.quantization {
+                return visitor.visit_f32(self.next_dequantized(quantization)? as f32);
+            }
+            visitor.visit_f32(self.next_as_float()?.into_f32())
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if let Some(quantization) = self.quantization {
+                return visitor.visit_f64(self.next_dequantized(quantization)?);
+            }
+            visitor.visit_f64(self.next_as_float()?.into_f64())
+        })
+        .ctx(self)
     }
 }
@@ -41,6 +41,18 @@ macro_rules! implement_integer_into {
         fn into_bool(self) -> Result<bool> {
             Ok(self != 0)
         }
+
+        fn is_zero(self) -> bool {
+            self == 0
+        }
+
+        fn into_f32(self) -> f32 {
+            self as f32
+        }
+
+        fn into_f64(self) -> f64 {
+            self as f64
+        }
     };
 }
 
@@ -0,0 +1,147 @@
+use serde::de::{value::SeqDeserializer, Visitor};
+
+use crate::internal::{
+    arrow::PrimitiveArrayView,
+    error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
+    utils::{IntervalValue, Mut},
+};
+
+use super::{simple_deserializer::SimpleDeserializer, utils::ArrayBufferIterator};
+
+/// Deserialize interval arrays
+///
+/// Values are visited as sequences of their components, which supports
+/// tuples, tuple structs and structs. Year month intervals are visited as
+/// integers counting months.
+#[derive(Clone)]
+pub struct IntervalDeserializer<'a, T: IntervalValue> {
+    path: String,
+    array: ArrayBufferIterator<'a, T>,
+}
+
+impl<'a, T: IntervalValue> IntervalDeserializer<'a, T> {
+    pub fn new(path: String, view: PrimitiveArrayView<'a, T>) -> Self {
+        Self {
+            path,
+            array: ArrayBufferIterator::new(view.values, view.validity),
+        }
+    }
+
+    fn next_integer_required<I: TryFrom<i64>>(&mut self) -> Result<I> {
+        let components = self.array.next_required()?.to_components();
+        let [value] = components.as_slice() else {
+            fail!(
+                "{data_type} values cannot be deserialized as integers",
+                data_type = T::DATA_TYPE,
+            );
+        };
+        match I::try_from(*value) {
+            Ok(value) => Ok(value),
+            Err(_) => fail!(
+                "Interval {value} is out of range for {ty}",
+                ty = std::any::type_name::<I>(),
+            ),
+        }
+    }
+
+    fn visit_components<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        let components = self.array.next_required()?.to_components();
+        let mut seq = SeqDeserializer::<_, Error>::new(components.into_iter());
+        let res = visitor.visit_seq(&mut seq)?;
+        seq.end()?;
+        Ok(res)
+    }
+}
+
+impl<'a, T: IntervalValue> Context for IntervalDeserializer<'a, T> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", T::DATA_TYPE);
+    }
+}
+
+impl<'a, T: IntervalValue> SimpleDeserializer<'a> for IntervalDeserializer<'a, T> {
+    fn deserialize_any<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if !self.array.peek_next()? {
+                self.array.consume_next();
+                visitor.visit_none()
+            } else if T::COMPONENTS.len() == 1 {
+                visitor.visit_i32(self.next_integer_required()?)
+            } else {
+                self.visit_components(visitor)
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_option<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if self.array.peek_next()? {
+                visitor.visit_some(Mut(&mut *self))
+            } else {
+                self.array.consume_next();
+                visitor.visit_none()
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_i8<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i8(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_i16<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i16(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_i32<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i32(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_i64<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i64(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_u8<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u8(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_u16<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u16(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_u32<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u32(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_u64<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u64(self.next_integer_required()?)).ctx(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| self.visit_components(visitor)).ctx(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'a>>(&mut self, _: usize, visitor: V) -> Result<V::Value> {
+        try_(|| self.visit_components(visitor)).ctx(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'a>>(
+        &mut self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| self.visit_components(visitor)).ctx(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'a>>(
+        &mut self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| self.visit_components(visitor)).ctx(self)
+    }
+}
@@ -9,9 +9,10 @@ use crate::internal::{
 use super::{
     array_deserializer::ArrayDeserializer,
     simple_deserializer::SimpleDeserializer,
-    utils::{bitset_is_set, check_supported_list_layout},
+    utils::{bitset_is_set, check_supported_list_layout, segment_len, with_list_length},
 };
 
+#[derive(Clone)]
 pub struct ListDeserializer<'a, O: Offset> {
     pub path: String,
     pub item: Box<ArrayDeserializer<'a>>,
@@ -52,6 +53,17 @@ impl<'a, O: Offset> ListDeserializer<'a, O> {
     pub fn consume_next(&mut self) {
         self.next = (self.next.0 + 1, 0);
     }
+
+    /// The number of elements of the current list
+    pub fn current_len(&self) -> Result<usize> {
+        let idx = self.next.0;
+        if idx + 1 >= self.offsets.len() {
+            fail!("Exhausted deserializer")
+        }
+        let start = self.offsets[idx].try_into_usize()?;
+        let end = self.offsets[idx + 1].try_into_usize()?;
+        segment_len(start, end)
+    }
 }
 
 impl<'a, O: NamedType + Offset> Context for ListDeserializer<'a, O> {
@@ -95,7 +107,50 @@ impl<'a, O: NamedType + Offset> SimpleDeserializer<'a> for ListDeserializer<'a,
     }
 
     fn deserialize_seq<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_seq(&mut *self)).ctx(self)
+        try_(|| {
+            let len = self.current_len()?;
+            visitor
+                .visit_seq(&mut *self)
+                .map_err(|err| with_list_length(err, len))
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'a>>(&mut self, len: usize, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if !self.peek_next()? {
+                fail!(
+                    "Cannot deserialize null list {idx} into a fixed size container of length {len}",
+                    idx = self.next.0,
+                );
+            }
+            let actual = self.current_len()?;
+            if actual != len {
+                fail!(
+                    "Cannot deserialize list {idx} of length {actual} into a fixed size container of length {len}",
+                    idx = self.next.0,
+                );
+            }
+
+            let idx = self.next.0;
+            let res = visitor.visit_seq(&mut *self)?;
+
+            // tuple visitors do not request the end of the sequence
+            if self.next.0 == idx {
+                self.next = (idx + 1, 0);
+            }
+            Ok(res)
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'a>>(
+        &mut self,
+        _: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
     }
 
     fn deserialize_bytes<V: Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
@@ -110,6 +165,13 @@ impl<'a, O: NamedType + Offset> SimpleDeserializer<'a> for ListDeserializer<'a,
 impl<'de, O: NamedType + Offset> SeqAccess<'de> for ListDeserializer<'de, O> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        let (item, offset) = self.next;
+        let start = self.offsets.get(item)?.try_into_usize().ok()?;
+        let end = self.offsets.get(item + 1)?.try_into_usize().ok()?;
+        Some(end.checked_sub(start)?.saturating_sub(offset))
+    }
+
     fn next_element_seed<T: serde::de::DeserializeSeed<'de>>(
         &mut self,
         seed: T,
@@ -121,7 +183,7 @@ impl<'de, O: NamedType + Offset> SeqAccess<'de> for ListDeserializer<'de, O> {
         let end = self.offsets[item + 1].try_into_usize()?;
         let start = self.offsets[item].try_into_usize()?;
 
-        if offset >= end - start {
+        if offset >= segment_len(start, end)? {
             self.next = (item + 1, 0);
             return Ok(None);
         }
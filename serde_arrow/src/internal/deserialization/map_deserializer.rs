@@ -9,16 +9,19 @@ use crate::internal::{
 use super::{
     array_deserializer::ArrayDeserializer,
     simple_deserializer::SimpleDeserializer,
-    utils::{bitset_is_set, check_supported_list_layout},
+    utils::{bitset_is_set, check_supported_list_layout, segment_len},
 };
 
+#[derive(Clone)]
 pub struct MapDeserializer<'a> {
     path: String,
-    key: Box<ArrayDeserializer<'a>>,
-    value: Box<ArrayDeserializer<'a>>,
+    pub key: Box<ArrayDeserializer<'a>>,
+    pub value: Box<ArrayDeserializer<'a>>,
     offsets: &'a [i32],
     validity: Option<BitsWithOffset<'a>>,
     next: (usize, usize),
+    /// If `true`, the map can be deserialized into structs
+    pub as_struct: bool,
 }
 
 impl<'a> MapDeserializer<'a> {
@@ -38,6 +41,7 @@ impl<'a> MapDeserializer<'a> {
             offsets,
             validity,
             next: (0, 0),
+            as_struct: false,
         })
     }
 
@@ -55,6 +59,30 @@ impl<'a> MapDeserializer<'a> {
     pub fn consume_next(&mut self) {
         self.next = (self.next.0 + 1, 0);
     }
+
+    /// Check whether the current map has further entries, consume it if not
+    pub fn has_next_entry(&mut self) -> Result<bool> {
+        let (item, entry) = self.next;
+        if item + 1 >= self.offsets.len() {
+            fail!(in self, "Exhausted deserializer");
+        }
+        let start: usize = self.offsets[item].try_into()?;
+        let end: usize = self.offsets[item + 1].try_into()?;
+
+        if entry >= segment_len(start, end)? {
+            self.consume_next();
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// The number of entries of the current map not yet deserialized
+    pub fn remaining_entries(&self) -> Option<usize> {
+        let (item, entry) = self.next;
+        let start: usize = (*self.offsets.get(item)?).try_into().ok()?;
+        let end: usize = (*self.offsets.get(item + 1)?).try_into().ok()?;
+        Some(end.checked_sub(start)?.saturating_sub(entry))
+    }
 }
 
 impl<'de> Context for MapDeserializer<'de> {
@@ -92,11 +120,30 @@ impl<'de> SimpleDeserializer<'de> for MapDeserializer<'de> {
     fn deserialize_map<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| visitor.visit_map(&mut *self)).ctx(self)
     }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        &mut self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| {
+            if !self.as_struct {
+                fail!("Cannot deserialize a map into a struct without the `map_as_struct` option");
+            }
+            visitor.visit_map(&mut *self)
+        })
+        .ctx(self)
+    }
 }
 
 impl<'de> MapAccess<'de> for MapDeserializer<'de> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining_entries()
+    }
+
     fn next_key_seed<K: DeserializeSeed<'de>>(
         &mut self,
         seed: K,
@@ -108,7 +155,7 @@ impl<'de> MapAccess<'de> for MapDeserializer<'de> {
         let start: usize = self.offsets[item].try_into()?;
         let end: usize = self.offsets[item + 1].try_into()?;
 
-        if entry >= (end - start) {
+        if entry >= segment_len(start, end)? {
             self.next = (item + 1, 0);
             return Ok(None);
         }
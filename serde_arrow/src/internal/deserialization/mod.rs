@@ -1,12 +1,22 @@
 pub mod array_deserializer;
 pub mod binary_deserializer;
 pub mod bool_deserializer;
+pub mod custom_deserializer;
+#[cfg(feature = "types-temporal")]
 pub mod date32_deserializer;
+#[cfg(feature = "types-temporal")]
 pub mod date64_deserializer;
+#[cfg(feature = "types-decimal")]
 pub mod decimal_deserializer;
+#[cfg(feature = "serde_json")]
+pub mod default_value_deserializer;
 pub mod dictionary_deserializer;
+pub mod dictionary_overflow_deserializer;
+#[cfg(feature = "types-temporal")]
 pub mod duration_deserializer;
+#[cfg(feature = "types-union")]
 pub mod enum_deserializer;
+pub mod enum_one_hot_deserializer;
 pub mod enums_as_string_impl;
 pub mod fixed_size_binary_deserializer;
 pub mod fixed_size_list_deserializer;
@@ -14,13 +24,20 @@ pub mod float_deserializer;
 pub mod float_impls;
 pub mod integer_deserializer;
 pub mod integer_impls;
+#[cfg(feature = "types-temporal")]
+pub mod interval_deserializer;
 pub mod list_deserializer;
 pub mod map_deserializer;
 pub mod null_deserializer;
 pub mod outer_sequence_deserializer;
+#[cfg(feature = "types-temporal")]
+pub mod seconds_deserializer;
 pub mod simple_deserializer;
+pub mod strict_null_deserializer;
 pub mod string_deserializer;
 pub mod struct_deserializer;
+pub mod tagged_struct_deserializer;
+#[cfg(feature = "types-temporal")]
 pub mod time_deserializer;
 pub mod utils;
 
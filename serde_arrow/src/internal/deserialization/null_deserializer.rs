@@ -4,6 +4,12 @@ use crate::internal::error::{set_default, Context, ContextSupport, Error, Result
 
 use super::simple_deserializer::SimpleDeserializer;
 
+/// Deserialize `Null` arrays
+///
+/// Options are always deserialized as `None` independent of the inner type.
+/// This way, columns of type `Null` (e.g., new columns without any values) can
+/// be read into `Option<T>` for arbitrary `T`.
+#[derive(Clone)]
 pub struct NullDeserializer {
     path: String,
 }
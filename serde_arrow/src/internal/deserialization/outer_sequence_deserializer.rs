@@ -1,6 +1,8 @@
 use serde::de::{SeqAccess, Visitor};
 
 use crate::internal::{
+    arrow::TimeUnit,
+    deserializer_options::{BinaryFormat, DecimalFormat, DurationFormat},
     error::{Context, Error, Result},
     utils::Mut,
 };
@@ -10,18 +12,112 @@ use super::{
     struct_deserializer::StructDeserializer,
 };
 
+#[derive(Clone)]
 pub struct OuterSequenceDeserializer<'a> {
     pub item: StructDeserializer<'a>,
     pub next: usize,
     pub len: usize,
+    /// If `true`, annotate errors with the row of the item
+    pub annotate_rows: bool,
 }
 
 impl<'a> OuterSequenceDeserializer<'a> {
-    pub fn new(fields: Vec<(String, ArrayDeserializer<'a>)>, len: usize) -> Self {
+    pub fn new(
+        fields: Vec<(String, ArrayDeserializer<'a>)>,
+        catch_all: Option<usize>,
+        len: usize,
+    ) -> Self {
         Self {
-            item: StructDeserializer::new(String::from("$"), fields, None, len),
+            item: StructDeserializer::new(String::from("$"), fields, None, len)
+                .with_catch_all(catch_all),
             next: 0,
             len,
+            annotate_rows: false,
+        }
+    }
+
+    /// Configure how values of Null variants are returned in `deserialize_any`
+    pub fn set_null_variants_as_none(&mut self, value: bool) {
+        #[cfg(feature = "types-union")]
+        for (_, deserializer) in &mut self.item.fields {
+            deserializer.for_each_enum_mut(&mut |deserializer| {
+                deserializer.null_variants_as_none = value;
+            });
+        }
+        #[cfg(not(feature = "types-union"))]
+        let _ = value;
+    }
+
+    /// Configure how decimals are rendered as strings
+    pub fn set_decimal_format(&mut self, format: DecimalFormat) {
+        #[cfg(feature = "types-decimal")]
+        for (_, deserializer) in &mut self.item.fields {
+            deserializer.for_each_decimal_format_mut(&mut |decimal_format| {
+                *decimal_format = format;
+            });
+        }
+        #[cfg(not(feature = "types-decimal"))]
+        let _ = format;
+    }
+
+    /// Configure the unit of timestamps deserialized as integers
+    pub fn set_timestamp_unit(&mut self, unit: Option<TimeUnit>) {
+        #[cfg(feature = "types-temporal")]
+        for (_, deserializer) in &mut self.item.fields {
+            deserializer.for_each_date64_mut(&mut |deserializer| {
+                deserializer.int_unit = unit;
+            });
+        }
+        #[cfg(not(feature = "types-temporal"))]
+        let _ = unit;
+    }
+
+    /// Configure how durations are rendered as strings
+    pub fn set_duration_format(&mut self, format: DurationFormat) {
+        #[cfg(feature = "types-temporal")]
+        for (_, deserializer) in &mut self.item.fields {
+            deserializer.for_each_duration_mut(&mut |deserializer| {
+                deserializer.format = format;
+            });
+        }
+        #[cfg(not(feature = "types-temporal"))]
+        let _ = format;
+    }
+
+    /// Configure how binary values are represented in self-describing formats
+    pub fn set_binary_format(&mut self, format: BinaryFormat) {
+        for (_, deserializer) in &mut self.item.fields {
+            deserializer.for_each_binary_format_mut(&mut |binary_format| {
+                *binary_format = format;
+            });
+        }
+    }
+
+    /// Configure whether numbers can be deserialized as other numeric types
+    pub fn set_numeric_coercion(&mut self, value: bool) {
+        for (_, deserializer) in &mut self.item.fields {
+            deserializer.for_each_numeric_coercion_mut(&mut |numeric_coercion| {
+                *numeric_coercion = value;
+            });
+        }
+    }
+
+    /// Configure whether map columns can be deserialized into structs
+    pub fn set_map_as_struct(&mut self, value: bool) {
+        for (_, deserializer) in &mut self.item.fields {
+            deserializer.for_each_map_mut(&mut |deserializer| {
+                deserializer.as_struct = value;
+            });
+        }
+    }
+
+    /// Configure whether struct children are matched to fields by position
+    pub fn set_struct_fields_by_position(&mut self, value: bool) {
+        self.item.by_position = value;
+        for (_, deserializer) in &mut self.item.fields {
+            deserializer.for_each_struct_mut(&mut |deserializer| {
+                deserializer.by_position = value;
+            });
         }
     }
 }
@@ -64,6 +160,10 @@ impl<'de> SimpleDeserializer<'de> for OuterSequenceDeserializer<'de> {
 impl<'de> SeqAccess<'de> for OuterSequenceDeserializer<'de> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len.saturating_sub(self.next))
+    }
+
     fn next_element_seed<T: serde::de::DeserializeSeed<'de>>(
         &mut self,
         seed: T,
@@ -71,9 +171,13 @@ impl<'de> SeqAccess<'de> for OuterSequenceDeserializer<'de> {
         if self.next >= self.len {
             return Ok(None);
         }
+        let row = self.next;
         self.next += 1;
 
-        let item = seed.deserialize(Mut(&mut self.item))?;
-        Ok(Some(item))
+        match seed.deserialize(Mut(&mut self.item)) {
+            Ok(item) => Ok(Some(item)),
+            Err(err) if self.annotate_rows => Err(err.with_annotation("row", row.to_string())),
+            Err(err) => Err(err),
+        }
     }
 }
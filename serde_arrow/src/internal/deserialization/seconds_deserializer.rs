@@ -0,0 +1,143 @@
+use serde::de::Visitor;
+
+use crate::internal::{
+    arrow::{BitsWithOffset, TimeUnit},
+    chrono,
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::Mut,
+};
+
+use super::{simple_deserializer::SimpleDeserializer, utils::ArrayBufferIterator};
+
+/// Deserialize durations and timestamps as seconds, see
+/// [`Strategy::Seconds`][crate::schema::Strategy::Seconds]
+#[derive(Clone)]
+pub struct SecondsDeserializer<'a> {
+    path: String,
+    unit: TimeUnit,
+    array: ArrayBufferIterator<'a, i64>,
+}
+
+impl<'a> SecondsDeserializer<'a> {
+    pub fn new(
+        path: String,
+        unit: TimeUnit,
+        values: &'a [i64],
+        validity: Option<BitsWithOffset<'a>>,
+    ) -> Self {
+        Self {
+            path,
+            unit,
+            array: ArrayBufferIterator::new(values, validity),
+        }
+    }
+
+    fn next_whole_seconds<T: TryFrom<i64>>(&mut self) -> Result<T> {
+        let value = self.array.next_required()?;
+        let Some(seconds) = chrono::arrow_to_whole_seconds(value, self.unit) else {
+            fail!(
+                "Value {seconds} cannot be deserialized as whole seconds",
+                seconds = chrono::format_arrow_as_seconds(value, self.unit),
+            );
+        };
+        match T::try_from(seconds) {
+            Ok(seconds) => Ok(seconds),
+            Err(_) => fail!(
+                "Value {seconds} is out of range for {ty}",
+                ty = std::any::type_name::<T>(),
+            ),
+        }
+    }
+
+    fn next_float_seconds(&mut self) -> Result<f64> {
+        let value = self.array.next_required()?;
+        Ok(chrono::arrow_to_float_seconds(value, self.unit))
+    }
+
+    fn next_string_seconds(&mut self) -> Result<String> {
+        let value = self.array.next_required()?;
+        Ok(chrono::format_arrow_as_seconds(value, self.unit))
+    }
+}
+
+impl<'de> Context for SecondsDeserializer<'de> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "strategy", "Seconds");
+    }
+}
+
+impl<'de> SimpleDeserializer<'de> for SecondsDeserializer<'de> {
+    fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            let Some(value) = self.array.next()? else {
+                return visitor.visit_none();
+            };
+            match chrono::arrow_to_whole_seconds(value, self.unit) {
+                Some(seconds) => visitor.visit_i64(seconds),
+                None => visitor.visit_f64(chrono::arrow_to_float_seconds(value, self.unit)),
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if self.array.peek_next()? {
+                visitor.visit_some(Mut(&mut *self))
+            } else {
+                self.array.consume_next();
+                visitor.visit_none()
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i8(self.next_whole_seconds()?)).ctx(self)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i16(self.next_whole_seconds()?)).ctx(self)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i32(self.next_whole_seconds()?)).ctx(self)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_i64(self.next_whole_seconds()?)).ctx(self)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u8(self.next_whole_seconds()?)).ctx(self)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u16(self.next_whole_seconds()?)).ctx(self)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u32(self.next_whole_seconds()?)).ctx(self)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_u64(self.next_whole_seconds()?)).ctx(self)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_f32(self.next_float_seconds()? as f32)).ctx(self)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_f64(self.next_float_seconds()?)).ctx(self)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_str(self.next_string_seconds()?.as_str())).ctx(self)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| visitor.visit_string(self.next_string_seconds()?)).ctx(self)
+    }
+}
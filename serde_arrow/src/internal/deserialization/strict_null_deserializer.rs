@@ -0,0 +1,110 @@
+use serde::de::Visitor;
+
+use crate::internal::{
+    arrow::ArrayView,
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::array_view_ext::ArrayViewExt,
+};
+
+use super::{array_deserializer::ArrayDeserializer, simple_deserializer::SimpleDeserializer};
+
+/// Reject null values deserialized into non-optional types, see
+/// [`DeserializerOptions::strict_nullability`][crate::DeserializerOptions::strict_nullability]
+#[derive(Clone)]
+pub struct StrictNullDeserializer<'a> {
+    pub path: String,
+    pub view: ArrayView<'a>,
+    pub inner: Box<ArrayDeserializer<'a>>,
+    pub next: usize,
+}
+
+impl<'a> StrictNullDeserializer<'a> {
+    pub fn new(path: String, view: ArrayView<'a>, inner: ArrayDeserializer<'a>) -> Self {
+        Self {
+            path,
+            view,
+            inner: Box::new(inner),
+            next: 0,
+        }
+    }
+
+    /// Fail if the next row is null, without consuming it
+    fn check_next(&self) -> Result<()> {
+        if !self.view.is_valid(self.next)? {
+            fail!("Cannot deserialize a null value into a non-optional type, consider using an Option");
+        }
+        Ok(())
+    }
+}
+
+impl<'de> Context for StrictNullDeserializer<'de> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        self.inner.annotate(annotations);
+        set_default(annotations, "field", &self.path);
+    }
+}
+
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(&mut self, $($arg: $ty,)* visitor: V) -> Result<V::Value> {
+                let res = self.inner.$method($($arg,)* visitor);
+                self.next += 1;
+                res
+            }
+        )*
+    };
+}
+
+macro_rules! check {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(&mut self, $($arg: $ty,)* visitor: V) -> Result<V::Value> {
+                try_(|| {
+                    self.check_next()?;
+                    let res = self.inner.$method($($arg,)* visitor);
+                    self.next += 1;
+                    res
+                })
+                .ctx(self)
+            }
+        )*
+    };
+}
+
+impl<'de> SimpleDeserializer<'de> for StrictNullDeserializer<'de> {
+    forward! {
+        deserialize_any();
+        deserialize_ignored_any();
+        deserialize_option();
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+    }
+
+    check! {
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_byte_buf();
+        deserialize_bytes();
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_tuple(len: usize);
+        deserialize_seq();
+        deserialize_tuple_struct(name: &'static str, len: usize);
+    }
+}
@@ -8,6 +8,7 @@ use super::{
     enums_as_string_impl::EnumAccess, simple_deserializer::SimpleDeserializer, utils::bitset_is_set,
 };
 
+#[derive(Clone)]
 pub struct StringDeserializer<'a, O: Offset> {
     pub path: String,
     pub view: BytesArrayView<'a, O>,
@@ -24,7 +25,7 @@ impl<'a, O: Offset> StringDeserializer<'a, O> {
     }
 
     pub fn next(&mut self) -> Result<Option<&'a str>> {
-        if self.next + 1 > self.view.offsets.len() {
+        if self.next + 1 >= self.view.offsets.len() {
             fail!("Exhausted deserializer: tried to deserialize a value from an exhausted StringDeserializer");
         }
 
@@ -36,7 +37,10 @@ impl<'a, O: Offset> StringDeserializer<'a, O> {
 
         let start = self.view.offsets[self.next].try_into_usize()?;
         let end = self.view.offsets[self.next + 1].try_into_usize()?;
-        let s = std::str::from_utf8(&self.view.data[start..end])?;
+        let Some(data) = self.view.data.get(start..end) else {
+            fail!("Invalid offsets: the range {start}..{end} exceeds the data");
+        };
+        let s = std::str::from_utf8(data)?;
 
         self.next += 1;
 
@@ -51,7 +55,7 @@ impl<'a, O: Offset> StringDeserializer<'a, O> {
     }
 
     pub fn peek_next(&self) -> Result<bool> {
-        if self.next + 1 > self.view.offsets.len() {
+        if self.next + 1 >= self.view.offsets.len() {
             fail!("Exhausted deserializer: tried to deserialize a value from an exhausted StringDeserializer");
         }
 
@@ -116,8 +120,15 @@ impl<'a, O: NamedType + Offset> SimpleDeserializer<'a> for StringDeserializer<'a
         try_(|| visitor.visit_string(self.next_required()?.to_owned())).ctx(self)
     }
 
+    fn deserialize_identifier<V: serde::de::Visitor<'a>>(
+        &mut self,
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| self.deserialize_str(visitor)).ctx(self)
+    }
+
     fn deserialize_bytes<V: serde::de::Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
-        try_(|| visitor.visit_bytes(self.next_required()?.as_bytes())).ctx(self)
+        try_(|| visitor.visit_borrowed_bytes(self.next_required()?.as_bytes())).ctx(self)
     }
 
     fn deserialize_byte_buf<V: serde::de::Visitor<'a>>(&mut self, visitor: V) -> Result<V::Value> {
@@ -13,12 +13,20 @@ use super::{
     utils::bitset_is_set,
 };
 
+#[derive(Clone)]
 pub struct StructDeserializer<'a> {
     pub path: String,
     pub fields: Vec<(String, ArrayDeserializer<'a>)>,
     pub validity: Option<BitsWithOffset<'a>>,
     pub next: (usize, usize),
     pub len: usize,
+    /// The index of the map field whose entries are returned as entries of
+    /// the struct, if any
+    pub catch_all: Option<usize>,
+    /// If `true`, the children are matched to the fields of structs by position
+    pub by_position: bool,
+    /// The field names of the struct currently deserialized by position
+    names: Option<&'static [&'static str]>,
 }
 
 impl<'a> StructDeserializer<'a> {
@@ -34,9 +42,17 @@ impl<'a> StructDeserializer<'a> {
             validity,
             len,
             next: (0, 0),
+            catch_all: None,
+            by_position: false,
+            names: None,
         }
     }
 
+    pub fn with_catch_all(mut self, catch_all: Option<usize>) -> Self {
+        self.catch_all = catch_all;
+        self
+    }
+
     pub fn peek_next(&self) -> Result<bool> {
         if self.next.0 >= self.len {
             fail!("Exhausted deserializer");
@@ -98,10 +114,27 @@ impl<'de> SimpleDeserializer<'de> for StructDeserializer<'de> {
     fn deserialize_struct<V: Visitor<'de>>(
         &mut self,
         _: &'static str,
-        _: &'static [&'static str],
+        fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
-        try_(|| visitor.visit_map(&mut *self)).ctx(self)
+        try_(|| {
+            if !self.by_position {
+                return visitor.visit_map(&mut *self);
+            }
+            if fields.len() != self.fields.len() {
+                fail!(
+                    "Cannot match struct fields by position: the struct has {} fields, the array {} children",
+                    fields.len(),
+                    self.fields.len(),
+                );
+            }
+
+            self.names = Some(fields);
+            let res = visitor.visit_map(&mut *self);
+            self.names = None;
+            res
+        })
+        .ctx(self)
     }
 
     fn deserialize_tuple<V: Visitor<'de>>(&mut self, _: usize, visitor: V) -> Result<V::Value> {
@@ -135,31 +168,74 @@ impl<'de> SimpleDeserializer<'de> for StructDeserializer<'de> {
 impl<'de> MapAccess<'de> for StructDeserializer<'de> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        let (_, field) = self.next;
+        let mut remaining = 0;
+        for (idx, (_, deserializer)) in self.fields.iter().enumerate().skip(field) {
+            if Some(idx) == self.catch_all {
+                let ArrayDeserializer::Map(catch_all) = deserializer else {
+                    return None;
+                };
+                remaining += catch_all.remaining_entries()?;
+            } else {
+                remaining += 1;
+            }
+        }
+        Some(remaining)
+    }
+
     fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
-        let (item, field) = self.next;
+        let (item, mut field) = self.next;
         if item >= self.len {
             fail!("Exhausted deserializer");
         }
+        if Some(field) == self.catch_all {
+            let ArrayDeserializer::Map(catch_all) = &mut self.fields[field].1 else {
+                fail!("CatchAll fields must be maps");
+            };
+            if catch_all.has_next_entry()? {
+                return catch_all.next_key_seed(seed);
+            }
+            field += 1;
+            self.next = (item, field);
+        }
         if field >= self.fields.len() {
             self.next = (item + 1, 0);
             return Ok(None);
         }
 
-        let key = seed.deserialize(StrDeserializer::<Error>::new(&self.fields[field].0))?;
+        let name = match self.names {
+            Some(names) => names[field],
+            None => &self.fields[field].0,
+        };
+        let key = seed.deserialize(StrDeserializer::<Error>::new(name))?;
         Ok(Some(key))
     }
 
     fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
         let (item, field) = self.next;
+        if Some(field) == self.catch_all {
+            let ArrayDeserializer::Map(catch_all) = &mut self.fields[field].1 else {
+                fail!("CatchAll fields must be maps");
+            };
+            return catch_all.next_value_seed(seed);
+        }
         self.next = (item, field + 1);
 
-        seed.deserialize(Mut(&mut self.fields[field].1))
+        // annotate errors raised by the `Deserialize` impl of the value itself,
+        // e.g., validations after deserializing a primitive
+        let res = seed.deserialize(Mut(&mut self.fields[field].1));
+        res.ctx(&self.fields[field].1)
     }
 }
 
 impl<'de> SeqAccess<'de> for StructDeserializer<'de> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len().saturating_sub(self.next.1))
+    }
+
     fn next_element_seed<T: DeserializeSeed<'de>>(
         &mut self,
         seed: T,
@@ -173,7 +249,8 @@ impl<'de> SeqAccess<'de> for StructDeserializer<'de> {
             return Ok(None);
         }
 
-        let res = seed.deserialize(Mut(&mut self.fields[field].1))?;
+        let res = seed.deserialize(Mut(&mut self.fields[field].1));
+        let res = res.ctx(&self.fields[field].1)?;
         self.next = (item, field + 1);
 
         Ok(Some(res))
@@ -0,0 +1,168 @@
+use serde::{
+    de::{value::StrDeserializer, DeserializeSeed, EnumAccess, IgnoredAny, VariantAccess, Visitor},
+    Deserialize,
+};
+
+use crate::internal::{
+    arrow::BitsWithOffset,
+    error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
+    utils::Mut,
+};
+
+use super::{
+    array_deserializer::ArrayDeserializer, simple_deserializer::SimpleDeserializer,
+    utils::bitset_is_set,
+};
+
+/// Deserialize enums from structs with a tag field, see
+/// [`Strategy::EnumAsStructWithTag`][crate::schema::Strategy::EnumAsStructWithTag]
+#[derive(Clone)]
+pub struct TaggedStructDeserializer<'a> {
+    pub path: String,
+    pub tag: Box<ArrayDeserializer<'a>>,
+    pub variants: Vec<(String, ArrayDeserializer<'a>)>,
+    pub validity: Option<BitsWithOffset<'a>>,
+    pub next: usize,
+    pub len: usize,
+}
+
+impl<'a> TaggedStructDeserializer<'a> {
+    pub fn new(
+        path: String,
+        tag: ArrayDeserializer<'a>,
+        variants: Vec<(String, ArrayDeserializer<'a>)>,
+        validity: Option<BitsWithOffset<'a>>,
+        len: usize,
+    ) -> Self {
+        Self {
+            path,
+            tag: Box::new(tag),
+            variants,
+            validity,
+            next: 0,
+            len,
+        }
+    }
+
+    fn peek_next(&self) -> Result<bool> {
+        if self.next >= self.len {
+            fail!("Exhausted deserializer");
+        }
+        if let Some(validity) = &self.validity {
+            Ok(bitset_is_set(validity, self.next)?)
+        } else {
+            Ok(true)
+        }
+    }
+
+    fn consume_null(&mut self) -> Result<()> {
+        self.next += 1;
+        self.tag.deserialize_ignored_any(IgnoredAny)?;
+        for (_, variant) in &mut self.variants {
+            variant.deserialize_ignored_any(IgnoredAny)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'de> Context for TaggedStructDeserializer<'de> {
+    fn annotate(&self, annotations: &mut std::collections::BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "Struct(..)");
+    }
+}
+
+impl<'de> SimpleDeserializer<'de> for TaggedStructDeserializer<'de> {
+    fn deserialize_option<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        try_(|| {
+            if self.peek_next()? {
+                visitor.visit_some(Mut(&mut *self))
+            } else {
+                self.consume_null()?;
+                visitor.visit_none()
+            }
+        })
+        .ctx(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        &mut self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        try_(|| {
+            if !self.peek_next()? {
+                fail!("Cannot deserialize a null value into an enum, consider using an Option");
+            }
+            self.next += 1;
+
+            let tag = <&'de str>::deserialize(Mut(self.tag.as_mut()))?;
+            let selected = self.variants.iter().position(|(name, _)| name == tag);
+            let res = visitor.visit_enum(TaggedVariant {
+                tag,
+                data: selected.map(|idx| &mut self.variants[idx].1),
+            })?;
+
+            for (idx, (_, variant)) in self.variants.iter_mut().enumerate() {
+                if Some(idx) != selected {
+                    variant.deserialize_ignored_any(IgnoredAny)?;
+                }
+            }
+            Ok(res)
+        })
+        .ctx(self)
+    }
+}
+
+struct TaggedVariant<'a, 'de> {
+    tag: &'de str,
+    data: Option<&'a mut ArrayDeserializer<'de>>,
+}
+
+impl<'a, 'de> TaggedVariant<'a, 'de> {
+    fn into_data(self) -> Result<&'a mut ArrayDeserializer<'de>> {
+        let Some(data) = self.data else {
+            fail!("Variant {tag} has no data field", tag = self.tag);
+        };
+        Ok(data)
+    }
+}
+
+impl<'a, 'de> EnumAccess<'de> for TaggedVariant<'a, 'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let variant = seed.deserialize(StrDeserializer::<Error>::new(self.tag))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'a, 'de> VariantAccess<'de> for TaggedVariant<'a, 'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        if let Some(data) = self.data {
+            data.deserialize_ignored_any(IgnoredAny)?;
+        }
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(Mut(self.into_data()?))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        self.into_data()?.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.into_data()?
+            .deserialize_struct("UNUSED_ENUM_STRUCT_NAME", fields, visitor)
+    }
+}
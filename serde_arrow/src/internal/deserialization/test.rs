@@ -35,6 +35,7 @@ fn example() {
                 )),
             ),
         ],
+        None,
         3,
     );
 
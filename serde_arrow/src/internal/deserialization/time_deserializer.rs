@@ -3,6 +3,7 @@ use serde::de::Visitor;
 
 use crate::internal::{
     arrow::{TimeArrayView, TimeUnit},
+    chrono::format_time_crate_time,
     error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::{Mut, NamedType},
 };
@@ -12,15 +13,17 @@ use super::{
     utils::ArrayBufferIterator,
 };
 
+#[derive(Clone)]
 pub struct TimeDeserializer<'a, T: Integer> {
     path: String,
     array: ArrayBufferIterator<'a, T>,
     seconds_factor: i64,
     nanoseconds_factor: i64,
+    time_crate_format: bool,
 }
 
 impl<'a, T: Integer> TimeDeserializer<'a, T> {
-    pub fn new(path: String, view: TimeArrayView<'a, T>) -> Self {
+    pub fn new(path: String, view: TimeArrayView<'a, T>, time_crate_format: bool) -> Self {
         let (seconds_factor, nanoseconds_factor) = match view.unit {
             TimeUnit::Nanosecond => (1_000_000_000, 1),
             TimeUnit::Microsecond => (1_000_000, 1_000),
@@ -33,17 +36,22 @@ impl<'a, T: Integer> TimeDeserializer<'a, T> {
             array: ArrayBufferIterator::new(view.values, view.validity),
             seconds_factor,
             nanoseconds_factor,
+            time_crate_format,
         }
     }
 
     pub fn get_string_repr(&self, ts: i64) -> Result<String> {
         let seconds = (ts / self.seconds_factor) as u32;
-        let nanoseconds = ((ts % self.seconds_factor) / self.nanoseconds_factor) as u32;
+        let nanoseconds = ((ts % self.seconds_factor) * self.nanoseconds_factor) as u32;
 
         let Some(res) = NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanoseconds) else {
             fail!("Invalid timestamp");
         };
-        Ok(res.to_string())
+        if self.time_crate_format {
+            Ok(format_time_crate_time(res))
+        } else {
+            Ok(res.to_string())
+        }
     }
 }
 
@@ -66,7 +74,7 @@ impl<'de, T: NamedType + Integer> SimpleDeserializer<'de> for TimeDeserializer<'
     fn deserialize_any<V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
         try_(|| {
             if self.array.peek_next()? {
-                T::deserialize_any(&mut *self, visitor)
+                self.deserialize_str(visitor)
             } else {
                 self.array.consume_next();
                 visitor.visit_none()
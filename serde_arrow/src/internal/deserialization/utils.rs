@@ -1,13 +1,65 @@
 use crate::internal::{
-    arrow::BitsWithOffset,
-    error::{fail, Result},
-    utils::{array_ext::get_bit_buffer, Offset},
+    arrow::{ArrayView, BitsWithOffset},
+    error::{fail, Error, Result},
+    utils::{array_ext::get_bit_buffer, array_view_ext::ArrayViewExt, Offset},
 };
 
 pub fn bitset_is_set(set: &BitsWithOffset<'_>, idx: usize) -> Result<bool> {
     get_bit_buffer(set.data, set.offset, idx)
 }
 
+/// The number of elements of the segment `start..end`, failing for decreasing offsets
+pub fn segment_len(start: usize, end: usize) -> Result<usize> {
+    let Some(len) = end.checked_sub(start) else {
+        fail!("Invalid offsets: the end {end} is smaller than the start {start}");
+    };
+    Ok(len)
+}
+
+/// Restrict the child of a list-like array to the elements referenced by the
+/// offsets
+///
+/// The child deserializers consume their elements in order starting at the
+/// first element. For sliced arrays the first offset may be non-zero.
+pub fn slice_to_offsets<'a, O: Offset>(
+    element: ArrayView<'a>,
+    offsets: &[O],
+) -> Result<ArrayView<'a>> {
+    let (Some(first), Some(last)) = (offsets.first(), offsets.last()) else {
+        return Ok(element);
+    };
+    let start = first.try_into_usize()?;
+    if start == 0 {
+        return Ok(element);
+    }
+    let end = last.try_into_usize()?;
+    element.slice(start, segment_len(start, end)?)
+}
+
+/// Restrict the child of a dense union to the elements starting with the first
+/// one referenced by the rows of the union
+#[cfg(feature = "types-union")]
+pub fn slice_union_child<'a>(
+    child: ArrayView<'a>,
+    type_id: i8,
+    types: &[i8],
+    offsets: &[i32],
+) -> Result<ArrayView<'a>> {
+    let Some(idx) = types.iter().position(|&ty| ty == type_id) else {
+        return Ok(child);
+    };
+    let Some(&start) = offsets.get(idx) else {
+        fail!("Invalid dense union: missing offset for row {idx}");
+    };
+    let start = usize::try_from(start)?;
+    if start == 0 {
+        return Ok(child);
+    }
+    let len = segment_len(start, child.len())?;
+    child.slice(start, len)
+}
+
+#[derive(Clone)]
 pub struct ArrayBufferIterator<'a, T: Copy> {
     pub buffer: &'a [T],
     pub validity: Option<BitsWithOffset<'a>>,
@@ -24,7 +76,7 @@ impl<'a, T: Copy> ArrayBufferIterator<'a, T> {
     }
 
     pub fn next(&mut self) -> Result<Option<T>> {
-        if self.next > self.buffer.len() {
+        if self.next >= self.buffer.len() {
             fail!("Exhausted deserializer");
         }
 
@@ -47,7 +99,7 @@ impl<'a, T: Copy> ArrayBufferIterator<'a, T> {
     }
 
     pub fn peek_next(&self) -> Result<bool> {
-        if self.next > self.buffer.len() {
+        if self.next >= self.buffer.len() {
             fail!("Exhausted deserializer");
         }
 
@@ -64,6 +116,21 @@ impl<'a, T: Copy> ArrayBufferIterator<'a, T> {
     }
 }
 
+/// Add the length of the list to errors raised by the visitor itself
+///
+/// Errors of the visitor, e.g., when a capacity limited container receives too
+/// many elements, do not carry annotations yet. Errors of the child
+/// deserializers are left untouched.
+pub fn with_list_length(mut err: Error, len: usize) -> Error {
+    if err.annotations().is_some_and(|a| a.is_empty()) {
+        err.modify_message(|message| {
+            use std::fmt::Write;
+            let _ = write!(message, " (list length: {len})");
+        });
+    }
+    err
+}
+
 /// Check that the list layout given in terms of validity and offsets is
 /// supported by serde_arrow
 ///
@@ -88,7 +155,7 @@ pub fn check_supported_list_layout<'a, O: Offset>(
     for i in 0..offsets.len().saturating_sub(1) {
         let curr = offsets[i].try_into_usize()?;
         let next = offsets[i + 1].try_into_usize()?;
-        if !bitset_is_set(&validity, i)? && (next - curr) != 0 {
+        if !bitset_is_set(&validity, i)? && segment_len(curr, next)? != 0 {
             fail!("Unsupported: lists with data in null values are currently not supported in deserialization");
         }
     }
@@ -3,12 +3,19 @@ use serde::de::Visitor;
 use crate::internal::{
     arrow::{ArrayView, Field},
     deserialization::{
-        array_deserializer::ArrayDeserializer,
+        array_deserializer::{ArrayDeserializer, BuildOptions},
+        custom_deserializer::CustomArrayDeserializer,
         outer_sequence_deserializer::OuterSequenceDeserializer,
     },
+    deserializer_options::DeserializerOptions,
     error::{fail, Error, Result},
-    schema::get_strategy_from_metadata,
+    schema::{
+        apply_strategy_overrides, apply_strategy_overrides_to_view, get_strategy_from_metadata,
+        Strategy,
+    },
+    shared_dictionaries::SharedDictionaries,
     utils::array_view_ext::ArrayViewExt,
+    variant_extractors::VariantExtractors,
 };
 
 use super::utils::ChildName;
@@ -20,33 +27,324 @@ use super::utils::ChildName;
 #[cfg_attr(has_arrow, doc = r"- [`Deserializer::from_record_batch`]")]
 #[cfg_attr(has_arrow, doc = r"- [`Deserializer::from_arrow`]")]
 #[cfg_attr(has_arrow2, doc = r"- [`Deserializer::from_arrow2`]")]
-pub struct Deserializer<'de>(pub(crate) OuterSequenceDeserializer<'de>);
+///
+/// The deserializer is self-describing: `deserialize_any` visits the batch as
+/// a sequence of maps keyed by the field names, in the order of the fields,
+/// and reports the exact length of all sequences and maps via `size_hint`.
+/// Therefore, it can be used with
+/// [`serde_transcode`](https://docs.rs/serde-transcode) to stream the records
+/// into any serde format without an intermediate `Vec<T>`:
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde_arrow::{schema::{SchemaLike, TracingOptions}, Deserializer};
+///
+/// ##[derive(serde::Serialize)]
+/// struct Record {
+///     a: i32,
+///     b: Option<String>,
+/// }
+///
+/// let items = [Record { a: 1, b: None }, Record { a: 2, b: Some(String::from("x")) }];
+/// let fields = Vec::<FieldRef>::from_samples(&items, TracingOptions::default())?;
+/// let batch = serde_arrow::to_record_batch(&fields, &items)?;
+///
+/// let mut buffer = Vec::new();
+/// let mut serializer = serde_json::Serializer::new(&mut buffer);
+/// serde_transcode::transcode(Deserializer::from_record_batch(&batch)?, &mut serializer)
+///     .unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(buffer).unwrap(),
+///     r#"[{"a":1,"b":null},{"a":2,"b":"x"}]"#,
+/// );
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+///
+/// Cloning a deserializer does not copy any data: the clones borrow the same
+/// buffers and start at the current position of the original. Custom
+/// deserializers are recreated with their factory. Therefore, a deserializer
+/// can be constructed once and the same batch deserialized into different
+/// types, e.g., on different threads:
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # let record_batch = serde_arrow::_impl::docs::defs::example_record_batch();
+/// use serde::Deserialize;
+/// use serde_arrow::Deserializer;
+///
+/// ##[derive(Deserialize)]
+/// struct A {
+///     a: Option<f32>,
+/// }
+///
+/// ##[derive(Deserialize)]
+/// struct B {
+///     b: u64,
+/// }
+///
+/// let deserializer = Deserializer::from_record_batch(&record_batch)?;
+/// let (a, b) = std::thread::scope(|scope| {
+///     let a = scope.spawn(|| Vec::<A>::deserialize(deserializer.clone()));
+///     let b = scope.spawn(|| Vec::<B>::deserialize(deserializer.clone()));
+///     (a.join().unwrap(), b.join().unwrap())
+/// });
+/// assert_eq!(a?.len(), record_batch.num_rows());
+/// assert_eq!(b?.len(), record_batch.num_rows());
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+#[derive(Clone)]
+pub struct Deserializer<'de>(
+    pub(crate) OuterSequenceDeserializer<'de>,
+    pub(crate) DeserializerSource<'de>,
+);
+
+/// The inputs of a deserializer, used to rebuild it with strategy overrides
+pub(crate) struct DeserializerSource<'de> {
+    fields: Vec<Field>,
+    views: Vec<ArrayView<'de>>,
+    /// The number of rows, if known independently of the views
+    len: Option<usize>,
+    /// The options to build the deserializers with, e.g., the registered
+    /// custom deserializers
+    build: BuildOptions,
+    /// The configured options, applied again whenever the deserializer is
+    /// rebuilt
+    options: DeserializerOptions,
+    /// The attached shared dictionaries, applied to the views when building
+    dictionaries: Option<&'de SharedDictionaries>,
+    /// An error while building the deserializer from all inputs, reported
+    /// when deserializing
+    error: Option<Error>,
+}
+
+impl<'de> Clone for DeserializerSource<'de> {
+    fn clone(&self) -> Self {
+        Self {
+            fields: self.fields.clone(),
+            views: self.views.clone(),
+            len: self.len,
+            build: self.build.clone(),
+            options: self.options.clone(),
+            dictionaries: self.dictionaries,
+            error: self.error.as_ref().map(Error::copy),
+        }
+    }
+}
+
+impl<'de> DeserializerSource<'de> {
+    fn check(&mut self) -> Result<()> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
 
 impl<'de> Deserializer<'de> {
     pub(crate) fn new(fields: &[Field], views: Vec<ArrayView<'de>>) -> Result<Self> {
-        let len = match views.first() {
-            Some(view) => view.len(),
-            None => 0,
+        Self::new_with_len(fields, views, None)
+    }
+
+    /// Construct a deserializer with an explicit number of rows
+    ///
+    /// The number of rows is required to deserialize inputs without any
+    /// columns, e.g., record batches with zero columns.
+    pub(crate) fn new_with_len(
+        fields: &[Field],
+        views: Vec<ArrayView<'de>>,
+        len: Option<usize>,
+    ) -> Result<Self> {
+        let source = DeserializerSource {
+            fields: fields.to_vec(),
+            views,
+            len,
+            build: BuildOptions::default(),
+            options: DeserializerOptions::default(),
+            dictionaries: None,
+            error: None,
+        };
+        let deserializer = OuterSequenceDeserializer::new(Vec::new(), None, 0);
+        Ok(Deserializer(deserializer, source).rebuild())
+    }
+
+    /// Build the deserializer from its source with all configured options
+    ///
+    /// As the deserializer is always built from all inputs, the stored error
+    /// is replaced by the result of this build.
+    fn rebuild(mut self) -> Self {
+        let source = &mut self.1;
+        source.build.strict_nullability = source.options.strict_nullability;
+
+        let overrides = &source.options.strategy_overrides;
+        let mut views = source.views.clone();
+        for (field, view) in std::iter::zip(&source.fields, &mut views) {
+            let path = format!("$.{child}", child = ChildName(&field.name));
+            if let Some(dictionaries) = source.dictionaries {
+                dictionaries.apply(&path, view);
+            }
+            apply_strategy_overrides_to_view(view, &path, overrides);
+        }
+
+        // unsupported arrays may still be handled by custom deserializers
+        // registered later, therefore build errors are reported on use
+        let res = apply_strategy_overrides(&source.fields, overrides)
+            .and_then(|fields| Self::build(&fields, views, source.len, &source.build));
+        match res {
+            Ok(deserializer) => {
+                self.0 = deserializer;
+                source.error = None;
+            }
+            Err(err) => {
+                self.0 = OuterSequenceDeserializer::new(Vec::new(), None, 0);
+                source.error = Some(err);
+            }
+        }
+
+        let options = &source.options;
+        self.0
+            .set_null_variants_as_none(options.null_variants_as_none);
+        self.0.set_decimal_format(options.decimal_format);
+        self.0.set_duration_format(options.duration_format);
+        self.0.set_binary_format(options.binary_format);
+        self.0.set_timestamp_unit(options.timestamp_unit);
+        self.0
+            .set_struct_fields_by_position(options.struct_fields_by_position);
+        self.0.set_map_as_struct(options.map_as_struct);
+        self.0.set_numeric_coercion(options.numeric_coercion);
+        self.0.annotate_rows = options.strict_nullability;
+        self
+    }
+
+    fn build(
+        fields: &[Field],
+        views: Vec<ArrayView<'de>>,
+        len: Option<usize>,
+        options: &BuildOptions,
+    ) -> Result<OuterSequenceDeserializer<'de>> {
+        let len = match (len, views.first()) {
+            (Some(len), _) => len,
+            (None, Some(view)) => view.len(),
+            (None, None) => 0,
         };
 
         let mut deserializers = Vec::new();
+        let mut catch_all = None;
         for (field, view) in std::iter::zip(fields, views) {
             if view.len() != len {
                 fail!("Cannot deserialize from arrays with different lengths");
             }
             let strategy = get_strategy_from_metadata(&field.metadata)?;
-            let deserializer = ArrayDeserializer::new(
+            if matches!(
+                strategy,
+                Some(Strategy::StringHash { .. } | Strategy::RowHash)
+            ) {
+                continue;
+            }
+            if matches!(strategy, Some(Strategy::CatchAll)) {
+                catch_all = Some(deserializers.len());
+            }
+            let deserializer = ArrayDeserializer::new_with_custom(
                 format!("$.{child}", child = ChildName(&field.name)),
                 strategy.as_ref(),
+                Some(&field.metadata),
                 view,
+                options,
             )?;
             deserializers.push((field.name.clone(), deserializer));
         }
 
-        let deserializer = OuterSequenceDeserializer::new(deserializers, len);
-        let deserializer = Deserializer(deserializer);
+        Ok(OuterSequenceDeserializer::new(
+            deserializers,
+            catch_all,
+            len,
+        ))
+    }
+
+    /// Configure the deserialization, see [`DeserializerOptions`]
+    pub fn with_options(mut self, options: DeserializerOptions) -> Self {
+        self.1.options = options;
+        self.rebuild()
+    }
+
+    /// Resolve dictionary encoded fields with dictionaries shared between
+    /// batches, see [`SharedDictionaries`]
+    ///
+    /// The values of all dictionary arrays with accumulated dictionaries are
+    /// replaced by the accumulated values, the keys are used as is. The
+    /// shared dictionaries must have been updated with the batch of this
+    /// deserializer.
+    pub fn with_shared_dictionaries(mut self, dictionaries: &'de SharedDictionaries) -> Self {
+        self.1.dictionaries = Some(dictionaries);
+        self.rebuild()
+    }
+
+    /// Use a custom deserializer for all fields with the given extension
+    /// name or data type name, see [`CustomArrayDeserializer`]
+    ///
+    /// The key is first compared to the extension name of the field (the
+    /// `ARROW:extension:name` metadata entry) and then to the name of its
+    /// data type without parameters, e.g., `"Dictionary"` or `"Timestamp"`.
+    /// For each matching field, a new custom deserializer is created with
+    /// `factory`. Options configured via [`Deserializer::with_options`] are
+    /// not applied to the storage of fields with custom deserializers.
+    pub fn with_custom_deserializer<F>(mut self, key: &str, factory: F) -> Self
+    where
+        F: Fn() -> Box<dyn CustomArrayDeserializer> + Send + Sync + 'static,
+    {
+        self.1
+            .build
+            .custom
+            .insert(key.to_owned(), std::sync::Arc::new(factory));
+        self.rebuild()
+    }
+}
+
+impl<'de> Deserializer<'de> {
+    /// Convert the values of a top-level union field with the given extractors
+    ///
+    /// For each row the value is converted with the function registered for
+    /// its variant, see [`VariantExtractors`]. Null values are returned as
+    /// `None`. Variants without registered function result in an error.
+    pub fn extract_union_field<T>(
+        mut self,
+        field: &str,
+        extractors: &VariantExtractors<T>,
+    ) -> Result<Vec<Option<T>>> {
+        self.1.check()?;
+        let len = self.0.len;
+        let Some((_, deserializer)) = self
+            .0
+            .item
+            .fields
+            .iter_mut()
+            .find(|(name, _)| name == field)
+        else {
+            fail!("Cannot extract unknown field {field:?}");
+        };
+
+        #[cfg(feature = "types-union")]
+        if let ArrayDeserializer::Enum(deserializer) = deserializer {
+            let mut values = Vec::with_capacity(len);
+            for _ in 0..len {
+                values.push(deserializer.next_extracted(extractors)?);
+            }
+            return Ok(values);
+        }
+        #[cfg(not(feature = "types-union"))]
+        let _ = (len, deserializer, extractors);
 
-        Ok(deserializer)
+        fail!("Cannot extract field {field:?}: it is not a union field")
     }
 }
 
@@ -61,20 +359,23 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer<'de> {
         self.deserialize_seq(visitor)
     }
 
-    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+    fn deserialize_seq<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        self.1.check()?;
         visitor.visit_seq(self.0)
     }
 
-    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+    fn deserialize_tuple<V: Visitor<'de>>(mut self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.1.check()?;
         visitor.visit_seq(self.0)
     }
 
     fn deserialize_tuple_struct<V: Visitor<'de>>(
-        self,
+        mut self,
         _name: &'static str,
         _len: usize,
         visitor: V,
     ) -> Result<V::Value> {
+        self.1.check()?;
         visitor.visit_seq(self.0)
     }
 
@@ -202,6 +503,6 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer<'de> {
 }
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl<'de> AssertSendSync for Deserializer<'de> {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Deserializer<'static>>();
 };
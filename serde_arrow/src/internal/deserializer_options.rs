@@ -0,0 +1,266 @@
+use std::collections::BTreeMap;
+
+use crate::internal::{arrow::TimeUnit, schema::Strategy};
+
+/// Configure how arrays are deserialized into Rust objects
+///
+/// The options are attached to a [`Deserializer`][crate::Deserializer] via
+/// [`Deserializer::with_options`][crate::Deserializer::with_options]. Usage:
+///
+/// ```rust
+/// # #[cfg(all(has_arrow, feature = "types-union"))]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     utils::Item,
+///     Deserializer, DeserializerOptions,
+/// };
+///
+/// ##[derive(Serialize, Deserialize)]
+/// enum Value {
+///     Empty,
+///     Number(i64),
+/// }
+///
+/// let items = [Item(Value::Number(1)), Item(Value::Empty)];
+/// let fields = Vec::<FieldRef>::from_samples(
+///     &items,
+///     TracingOptions::default().allow_null_fields(true),
+/// )?;
+/// let arrays = serde_arrow::to_arrow(&fields, &items)?;
+///
+/// let deserializer = Deserializer::from_arrow(&fields, &arrays)?
+///     .with_options(DeserializerOptions::default().null_variants_as_none(true));
+/// let values = serde_json::Value::deserialize(deserializer)?;
+///
+/// assert_eq!(
+///     values,
+///     serde_json::json!([{"item": {"Number": 1}}, {"item": null}]),
+/// );
+/// # Ok(())
+/// # }
+/// # #[cfg(not(all(has_arrow, feature = "types-union")))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DeserializerOptions {
+    /// If `true`, values of union variants with data type Null are
+    /// deserialized as `None` in self-describing formats. The default is
+    /// `false`.
+    ///
+    /// Per default, these values are deserialized as enums with the name of the
+    /// variant (e.g., `{"Empty": null}` in JSON). With this option dynamic
+    /// consumers, e.g., JSON dumps, get natural nulls. Deserializing into Rust
+    /// enums is not affected. Children with the
+    /// [`NullVariant`][crate::schema::Strategy::NullVariant] strategy are
+    /// always deserialized as `None`.
+    pub null_variants_as_none: bool,
+
+    /// How `Decimal128` values are rendered when deserialized as strings. The
+    /// default is [`DecimalFormat::Exact`].
+    pub decimal_format: DecimalFormat,
+
+    /// How `Duration` values are rendered when deserialized as strings. The
+    /// default is [`DurationFormat::Iso8601`].
+    pub duration_format: DurationFormat,
+
+    /// How `Binary`, `LargeBinary` and `FixedSizeBinary` values are
+    /// represented in self-describing formats. The default is
+    /// [`BinaryFormat::Bytes`].
+    ///
+    /// The option applies to `deserialize_any`, e.g., when dumping batches
+    /// to `serde_json::Value`. Deserializing into byte types, e.g.,
+    /// `serde_bytes::ByteBuf` or `Vec<u8>`, is not affected.
+    pub binary_format: BinaryFormat,
+
+    /// If given, the unit of `Timestamp` and `Date64` values deserialized as
+    /// integers. The default is `None`, i.e., the stored values are returned
+    /// as is.
+    ///
+    /// Conversions to coarser units round towards negative infinity, e.g.,
+    /// `-1500` milliseconds are converted to `-2` seconds. Conversions to finer
+    /// units that overflow `i64` result in an error. String representations
+    /// are not affected.
+    pub timestamp_unit: Option<TimeUnit>,
+
+    /// Strategies to use instead of the strategies of the fields, keyed by the
+    /// path of the field (e.g., `$.outer.inner`). The default is empty.
+    ///
+    /// A value of `None` removes the strategy of the field, e.g., to read a
+    /// `Date64` field with the `UtcStrAsDate64` strategy as integer
+    /// milliseconds. The fields themselves are not modified. Paths not
+    /// matching any field or strategies not supported by the data type of the
+    /// field result in an error when deserializing.
+    pub strategy_overrides: BTreeMap<String, Option<Strategy>>,
+
+    /// If `true`, the children of struct arrays are matched to the fields of
+    /// Rust structs by position instead of by name. The default is `false`.
+    ///
+    /// Per default, the children are matched by name, and their order in the
+    /// array does not need to agree with the declaration order of the struct.
+    /// With this option, the i-th child is deserialized as the i-th field of
+    /// the struct regardless of its name, e.g., to read arrays with renamed
+    /// columns. Structs with a different number of fields than the array has
+    /// children result in an error. The option applies to the outer batch and
+    /// to nested structs alike. Maps and self-describing formats are not
+    /// affected.
+    pub struct_fields_by_position: bool,
+
+    /// If `true`, map columns can be deserialized into Rust structs. The
+    /// default is `false`.
+    ///
+    /// Each map is deserialized as a struct with its keys as field names, e.g.,
+    /// to read data of producers that store records as `Map(Utf8, X)`.
+    /// Entries without a matching field are ignored, unless the struct denies
+    /// unknown fields. Fields without a matching entry are handled as missing
+    /// fields by serde, i.e., `Option` fields are set to `None` and fields
+    /// with `#[serde(default)]` to their default. Null maps are deserialized
+    /// as maps without entries.
+    pub map_as_struct: bool,
+
+    /// If `true`, integer and float columns can be deserialized into any
+    /// numeric Rust type. The default is `false`.
+    ///
+    /// Per default, integer columns can only be deserialized into integers and
+    /// float columns only into floats. With this option, integers are
+    /// converted to floats and floats to integers, e.g., to read an `Int64`
+    /// column written by another producer into an `f64` field. Floats must be
+    /// finite and without fractional part to be deserialized as integers.
+    /// Values out of the range of the target type, including `f64` values too
+    /// large for `f32`, result in an error that reports the row and the path
+    /// of the field. Conversions between integers are always checked.
+    pub numeric_coercion: bool,
+
+    /// If `true`, deserializing a null value into a non-optional type fails
+    /// with the row of the value. The default is `false`.
+    ///
+    /// Per default, nulls deserialized into non-optional types result in
+    /// errors that depend on the data type (e.g., `Exhausted deserializer`)
+    /// or, for structs and lists, in default values. With this option, the
+    /// first null value deserialized into a type other than `Option` results
+    /// in an error that reports the path of the field. All errors are
+    /// annotated with the row of the item, e.g., `(field: "$.a", row: "3")`.
+    /// Null values replaced by [default values][crate::schema::DEFAULT_VALUE_KEY]
+    /// are not affected.
+    pub strict_nullability: bool,
+}
+
+/// The string representation of decimals, see
+/// [`DeserializerOptions::decimal_format`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecimalFormat {
+    /// Render all digits of the scale, including trailing zeros, e.g., `1.50`
+    /// for a value of `1.5` with scale `2`
+    #[default]
+    Exact,
+    /// Strip trailing zeros of the fractional part, e.g., `1.5`
+    Normalized,
+    /// Use scientific notation without trailing zeros, e.g., `1.5e0`
+    Scientific,
+}
+
+/// The string representation of durations, see
+/// [`DeserializerOptions::duration_format`]
+///
+/// Negative durations are prefixed with `-` in all formats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DurationFormat {
+    /// ISO 8601 durations with fractional seconds, e.g., `PT90.500s`
+    #[default]
+    Iso8601,
+    /// Human readable durations as understood by the `humantime` crate,
+    /// e.g., `1m 30s 500ms`
+    Humantime,
+}
+
+/// The representation of binary values in self-describing formats, see
+/// [`DeserializerOptions::binary_format`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BinaryFormat {
+    /// A sequence of bytes, e.g., `[104, 105]` in JSON
+    #[default]
+    Bytes,
+    /// A base64 string with the standard alphabet and padding, e.g., `"aGk="`
+    Base64,
+    /// A string of lowercase hex digits, e.g., `"6869"`
+    Hex,
+}
+
+impl DeserializerOptions {
+    /// Construct options with the default values
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set [`null_variants_as_none`](#structfield.null_variants_as_none)
+    pub fn null_variants_as_none(mut self, value: bool) -> Self {
+        self.null_variants_as_none = value;
+        self
+    }
+
+    /// Set [`timestamp_unit`](#structfield.timestamp_unit)
+    pub fn timestamp_unit(mut self, value: Option<TimeUnit>) -> Self {
+        self.timestamp_unit = value;
+        self
+    }
+
+    /// Set [`decimal_format`](#structfield.decimal_format)
+    pub fn decimal_format(mut self, value: DecimalFormat) -> Self {
+        self.decimal_format = value;
+        self
+    }
+
+    /// Set [`duration_format`](#structfield.duration_format)
+    pub fn duration_format(mut self, value: DurationFormat) -> Self {
+        self.duration_format = value;
+        self
+    }
+
+    /// Set [`binary_format`](#structfield.binary_format)
+    pub fn binary_format(mut self, value: BinaryFormat) -> Self {
+        self.binary_format = value;
+        self
+    }
+
+    /// Set [`struct_fields_by_position`](#structfield.struct_fields_by_position)
+    pub fn struct_fields_by_position(mut self, value: bool) -> Self {
+        self.struct_fields_by_position = value;
+        self
+    }
+
+    /// Set [`map_as_struct`](#structfield.map_as_struct)
+    pub fn map_as_struct(mut self, value: bool) -> Self {
+        self.map_as_struct = value;
+        self
+    }
+
+    /// Set [`numeric_coercion`](#structfield.numeric_coercion)
+    pub fn numeric_coercion(mut self, value: bool) -> Self {
+        self.numeric_coercion = value;
+        self
+    }
+
+    /// Set [`strict_nullability`](#structfield.strict_nullability)
+    pub fn strict_nullability(mut self, value: bool) -> Self {
+        self.strict_nullability = value;
+        self
+    }
+
+    /// Override the strategy of the field with the given path, see
+    /// [`strategy_overrides`](#structfield.strategy_overrides)
+    pub fn override_strategy(
+        mut self,
+        path: impl Into<String>,
+        strategy: Option<Strategy>,
+    ) -> Self {
+        self.strategy_overrides.insert(path.into(), strategy);
+        self
+    }
+}
@@ -72,8 +72,8 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 /// Common errors during `serde_arrow`'s usage
 ///
-/// At the moment only a generic string error is supported, but it is planned to offer concrete
-/// types to match against.
+/// Most errors are generic string errors. Errors that tooling may want to react to carry an
+/// [`ErrorKind`] with structured information, see [`Error::kind`].
 ///
 /// The error carries a backtrace if `RUST_BACKTRACE=1`, see [`std::backtrace`] for details. This
 /// backtrace is included when printing the error. If the error is caused by another error, that
@@ -99,6 +99,7 @@ impl Error {
             backtrace: Backtrace::capture(),
             cause: None,
             annotations: BTreeMap::new(),
+            kind: ErrorKind::Custom,
         })))
     }
 
@@ -111,8 +112,68 @@ impl Error {
             backtrace: Backtrace::capture(),
             cause: Some(Box::new(cause)),
             annotations: BTreeMap::new(),
+            kind: ErrorKind::Custom,
         })))
     }
+
+    /// Construct an error for a field with a data type that is not supported
+    pub(crate) fn unsupported_data_type(
+        path: String,
+        data_type: String,
+        supported_alternatives: Vec<String>,
+        reason: &str,
+    ) -> Self {
+        let mut message = format!("Unsupported data type {data_type}: {reason}");
+        if !supported_alternatives.is_empty() {
+            message.push_str(&format!(
+                "; supported alternatives: {}",
+                supported_alternatives.join(", ")
+            ));
+        }
+        let Self::Custom(mut err) = Self::custom(message);
+        err.0.kind = ErrorKind::UnsupportedDataType {
+            path,
+            data_type,
+            supported_alternatives,
+        };
+        err.0.annotate_kind();
+        Self::Custom(err)
+    }
+
+    /// Prefix the path of [`ErrorKind::UnsupportedDataType`] errors with the
+    /// path of the parent field, other errors are returned unchanged
+    pub(crate) fn in_parent(self, parent: impl std::fmt::Display) -> Self {
+        let Self::Custom(mut err) = self;
+        if let ErrorKind::UnsupportedDataType { path, .. } = &mut err.0.kind {
+            *path = if path.is_empty() {
+                parent.to_string()
+            } else {
+                format!("{parent}.{path}")
+            };
+            err.0.annotate_kind();
+        }
+        Self::Custom(err)
+    }
+}
+
+/// The kind of an [`Error`], to react programmatically to specific errors
+///
+/// New kinds may be added in future releases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A generic error, described by its message
+    Custom,
+    /// The data type of a field is not supported
+    UnsupportedDataType {
+        /// The path of the field, e.g., `$.outer.inner`
+        path: String,
+        /// The data type of the field
+        data_type: String,
+        /// Supported data types the field could be converted into, e.g., via
+        /// an overwrite of the schema. Empty if there is no direct alternative
+        supported_alternatives: Vec<String>,
+    },
 }
 
 /// Access information about the error
@@ -129,6 +190,47 @@ impl Error {
         }
     }
 
+    /// The kind of the error
+    ///
+    /// ```rust
+    /// # #[cfg(has_arrow)]
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_arrow::_impl::arrow;
+    /// use std::sync::Arc;
+    /// use arrow::{
+    ///     _raw::array::TimestampNanosecondArray,
+    ///     array::ArrayRef,
+    ///     datatypes::{DataType, Field, FieldRef, TimeUnit},
+    /// };
+    /// use serde::Deserialize;
+    /// use serde_arrow::{utils::Item, Deserializer, ErrorKind};
+    ///
+    /// let array = TimestampNanosecondArray::from(vec![0]).with_timezone("Europe/Berlin");
+    /// let fields: Vec<FieldRef> = vec![Arc::new(Field::new(
+    ///     "item",
+    ///     DataType::Timestamp(TimeUnit::Nanosecond, Some("Europe/Berlin".into())),
+    ///     false,
+    /// ))];
+    /// let arrays: Vec<ArrayRef> = vec![Arc::new(array)];
+    ///
+    /// let deserializer = Deserializer::from_arrow(&fields, &arrays)?;
+    /// let err = Vec::<Item<String>>::deserialize(deserializer).unwrap_err();
+    /// let ErrorKind::UnsupportedDataType { path, supported_alternatives, .. } = err.kind() else {
+    ///     panic!("unexpected error: {err}");
+    /// };
+    /// assert_eq!(path, "$.item");
+    /// assert_eq!(supported_alternatives[0], "Timestamp(Nanosecond, Some(\"UTC\"))");
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(has_arrow))]
+    /// # fn main() {}
+    /// ```
+    pub fn kind(&self) -> &ErrorKind {
+        match self {
+            Self::Custom(err) => &err.0.kind,
+        }
+    }
+
     /// Get a reference to the annotations of this error
     pub(crate) fn annotations(&self) -> Option<&BTreeMap<String, String>> {
         match self {
@@ -136,6 +238,27 @@ impl Error {
         }
     }
 
+    /// Copy the message, the annotations and the kind of the error
+    ///
+    /// The cause is not copied and the backtrace is captured anew.
+    pub(crate) fn copy(&self) -> Self {
+        let Error::Custom(this) = self;
+        Self::Custom(CustomError(Box::new(CustomErrorImpl {
+            message: this.0.message.clone(),
+            backtrace: Backtrace::capture(),
+            cause: None,
+            annotations: this.0.annotations.clone(),
+            kind: this.0.kind.clone(),
+        })))
+    }
+
+    /// Add an annotation, if the error is not yet annotated with this key
+    pub(crate) fn with_annotation(self, key: &str, value: impl Into<String>) -> Self {
+        let Error::Custom(mut this) = self;
+        set_default(&mut this.0.annotations, key, value);
+        Error::Custom(this)
+    }
+
     pub(crate) fn modify_message<F: FnOnce(&mut String)>(&mut self, func: F) {
         let Error::Custom(this) = self;
         let inner = this.0.as_mut();
@@ -151,11 +274,29 @@ pub struct CustomErrorImpl {
     pub(crate) backtrace: Backtrace,
     pub(crate) cause: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
     pub(crate) annotations: BTreeMap<String, String>,
+    pub(crate) kind: ErrorKind,
+}
+
+impl CustomErrorImpl {
+    fn annotate_kind(&mut self) {
+        if let ErrorKind::UnsupportedDataType {
+            path, data_type, ..
+        } = &self.kind
+        {
+            if !path.is_empty() {
+                self.annotations.insert(String::from("field"), path.clone());
+            }
+            self.annotations
+                .insert(String::from("data_type"), data_type.clone());
+        }
+    }
 }
 
 impl std::cmp::PartialEq for CustomErrorImpl {
     fn eq(&self, other: &Self) -> bool {
-        self.message == other.message && self.annotations == other.annotations
+        self.message == other.message
+            && self.annotations == other.annotations
+            && self.kind == other.kind
     }
 }
 
@@ -303,6 +444,12 @@ impl From<std::str::Utf8Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::custom_from(format!("std::io::Error: {err}"), err)
+    }
+}
+
 impl From<Infallible> for Error {
     fn from(_: Infallible) -> Self {
         unreachable!()
@@ -338,7 +485,7 @@ fn error_can_be_converted_to_anyhow() {
 }
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl AssertSendSync for Error {}
-    impl<T: Send + Sync> AssertSendSync for Result<T> {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Error>();
+    assert_send_sync::<Result<()>>();
 };
@@ -0,0 +1,429 @@
+//! Deserialize arbitrary inputs, e.g., as a fuzz target
+//!
+//! The arrays are read from the raw buffer without any validation. This way
+//! the deserializers are exercised with corrupt inputs (e.g., offsets out of
+//! bounds, short validity bitmaps or invalid dictionary keys) that cannot be
+//! constructed via the validating constructors of the arrow crates.
+use half::f16;
+use serde::{de::IgnoredAny, Deserialize};
+
+use crate::internal::{
+    arrow::{
+        Array, ArrayView, BitsWithOffset, BooleanArray, BooleanArrayView, BytesArray,
+        BytesArrayView, DataType, DecimalArray, DecimalArrayView, DenseUnionArray,
+        DenseUnionArrayView, DictionaryArray, DictionaryArrayView, Field, FixedSizeBinaryArray,
+        FixedSizeBinaryArrayView, FixedSizeListArray, FixedSizeListArrayView, IntervalUnit,
+        ListArray, ListArrayView, NullArray, NullArrayView, PrimitiveArray, PrimitiveArrayView,
+        StructArray, StructArrayView, TimeArray, TimeArrayView, TimestampArray, TimestampArrayView,
+        UnionMode,
+    },
+    deserializer::Deserializer,
+    error::{fail, Result},
+    schema::{validate_field, SerdeArrowSchema},
+    utils::meta_from_field,
+};
+
+/// Deserialize the arrays encoded in `buffer` with the given schema
+///
+/// This function is intended as the body of fuzz targets: for any schema and
+/// any buffer it either succeeds or returns an error, but never panics. The
+/// buffer is consumed front to back. The number of rows and the lengths of
+/// all buffers (validity bitmaps, offsets, values, ...) are read as
+/// little-endian `u16` values, followed by the contents of the buffer. If the
+/// buffer is exhausted, the remaining buffers are empty. All rows are
+/// deserialized as [`IgnoredAny`], which visits every value.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// use serde_arrow::{_impl::fuzz::deserialize_raw, schema::{SchemaLike, SerdeArrowSchema}};
+///
+/// let schema = SerdeArrowSchema::from_value(serde_json::json!([
+///     {"name": "a", "data_type": "Utf8"},
+/// ]))?;
+///
+/// // one row, two offsets pointing beyond the data of 3 bytes
+/// let buffer = [1, 0, 8, 0, 0, 0, 0, 0, 16, 0, 0, 0, 3, 0, b'f', b'o', b'o'];
+/// assert!(deserialize_raw(&schema, &buffer).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn deserialize_raw(schema: &SerdeArrowSchema, buffer: &[u8]) -> Result<()> {
+    let mut reader = RawReader { buffer };
+    let len = reader.read_len();
+
+    let mut arrays = Vec::new();
+    for field in &schema.fields {
+        validate_field(field)?;
+        arrays.push(reader.read_array(&field.data_type, field.nullable, len)?);
+    }
+
+    let views = arrays.iter().map(view_of).collect::<Result<Vec<_>>>()?;
+    let deserializer = Deserializer::new_with_len(&schema.fields, views, Some(len))?;
+    Vec::<IgnoredAny>::deserialize(deserializer)?;
+    Ok(())
+}
+
+struct RawReader<'a> {
+    buffer: &'a [u8],
+}
+
+impl<'a> RawReader<'a> {
+    fn take(&mut self, n: usize) -> &'a [u8] {
+        let (head, tail) = self.buffer.split_at(n.min(self.buffer.len()));
+        self.buffer = tail;
+        head
+    }
+
+    fn read_len(&mut self) -> usize {
+        match *self.take(2) {
+            [lo, hi] => usize::from(u16::from_le_bytes([lo, hi])),
+            [lo] => usize::from(lo),
+            _ => 0,
+        }
+    }
+
+    fn read_bytes(&mut self) -> Vec<u8> {
+        let len = self.read_len();
+        self.take(len).to_vec()
+    }
+
+    fn read_validity(&mut self, nullable: bool) -> Option<Vec<u8>> {
+        nullable.then(|| self.read_bytes())
+    }
+
+    fn read_values<T, const N: usize>(&mut self, from_le_bytes: fn([u8; N]) -> T) -> Vec<T> {
+        let bytes = self.read_bytes();
+        bytes
+            .chunks_exact(N)
+            .filter_map(|chunk| Some(from_le_bytes(chunk.try_into().ok()?)))
+            .collect()
+    }
+
+    fn read_primitive<T, const N: usize>(
+        &mut self,
+        nullable: bool,
+        from_le_bytes: fn([u8; N]) -> T,
+    ) -> PrimitiveArray<T> {
+        PrimitiveArray {
+            validity: self.read_validity(nullable),
+            values: self.read_values(from_le_bytes),
+        }
+    }
+
+    fn read_time<T, const N: usize>(
+        &mut self,
+        nullable: bool,
+        unit: crate::internal::arrow::TimeUnit,
+        from_le_bytes: fn([u8; N]) -> T,
+    ) -> TimeArray<T> {
+        TimeArray {
+            unit,
+            validity: self.read_validity(nullable),
+            values: self.read_values(from_le_bytes),
+        }
+    }
+
+    fn read_decimal<T, const N: usize>(
+        &mut self,
+        nullable: bool,
+        precision: u8,
+        scale: i8,
+        from_le_bytes: fn([u8; N]) -> T,
+    ) -> DecimalArray<T> {
+        DecimalArray {
+            precision,
+            scale,
+            validity: self.read_validity(nullable),
+            values: self.read_values(from_le_bytes),
+        }
+    }
+
+    fn read_bytes_array<O, const N: usize>(
+        &mut self,
+        nullable: bool,
+        from_le_bytes: fn([u8; N]) -> O,
+    ) -> BytesArray<O> {
+        BytesArray {
+            validity: self.read_validity(nullable),
+            offsets: self.read_values(from_le_bytes),
+            data: self.read_bytes(),
+        }
+    }
+
+    fn read_list<O, const N: usize>(
+        &mut self,
+        field: &Field,
+        nullable: bool,
+        from_le_bytes: fn([u8; N]) -> O,
+    ) -> Result<ListArray<O>> {
+        let validity = self.read_validity(nullable);
+        let offsets = self.read_values(from_le_bytes);
+        let len = self.read_len();
+        Ok(ListArray {
+            validity,
+            offsets,
+            meta: meta_from_field(field.clone()),
+            element: Box::new(self.read_array(&field.data_type, field.nullable, len)?),
+        })
+    }
+
+    fn read_array(&mut self, data_type: &DataType, nullable: bool, len: usize) -> Result<Array> {
+        use {Array as A, DataType as T};
+        Ok(match data_type {
+            T::Null => A::Null(NullArray { len }),
+            T::Boolean => A::Boolean(BooleanArray {
+                len,
+                validity: self.read_validity(nullable),
+                values: self.read_bytes(),
+            }),
+            T::Int8 => A::Int8(self.read_primitive(nullable, i8::from_le_bytes)),
+            T::Int16 => A::Int16(self.read_primitive(nullable, i16::from_le_bytes)),
+            T::Int32 => A::Int32(self.read_primitive(nullable, i32::from_le_bytes)),
+            T::Int64 => A::Int64(self.read_primitive(nullable, i64::from_le_bytes)),
+            T::UInt8 => A::UInt8(self.read_primitive(nullable, u8::from_le_bytes)),
+            T::UInt16 => A::UInt16(self.read_primitive(nullable, u16::from_le_bytes)),
+            T::UInt32 => A::UInt32(self.read_primitive(nullable, u32::from_le_bytes)),
+            T::UInt64 => A::UInt64(self.read_primitive(nullable, u64::from_le_bytes)),
+            T::Float16 => A::Float16(self.read_primitive(nullable, f16::from_le_bytes)),
+            T::Float32 => A::Float32(self.read_primitive(nullable, f32::from_le_bytes)),
+            T::Float64 => A::Float64(self.read_primitive(nullable, f64::from_le_bytes)),
+            T::Date32 => A::Date32(self.read_primitive(nullable, i32::from_le_bytes)),
+            T::Date64 => A::Date64(self.read_primitive(nullable, i64::from_le_bytes)),
+            T::Time32(unit) => A::Time32(self.read_time(nullable, *unit, i32::from_le_bytes)),
+            T::Time64(unit) => A::Time64(self.read_time(nullable, *unit, i64::from_le_bytes)),
+            T::Duration(unit) => A::Duration(self.read_time(nullable, *unit, i64::from_le_bytes)),
+            T::Interval(IntervalUnit::YearMonth) => {
+                A::IntervalYearMonth(self.read_primitive(nullable, i32::from_le_bytes))
+            }
+            T::Interval(IntervalUnit::DayTime) => {
+                A::IntervalDayTime(self.read_primitive(nullable, bytemuck::cast::<[u8; 8], _>))
+            }
+            T::Interval(IntervalUnit::MonthDayNano) => A::IntervalMonthDayNano(
+                self.read_primitive(nullable, bytemuck::cast::<[u8; 16], _>),
+            ),
+            T::Timestamp(unit, timezone) => A::Timestamp(TimestampArray {
+                unit: *unit,
+                timezone: timezone.clone(),
+                validity: self.read_validity(nullable),
+                values: self.read_values(i64::from_le_bytes),
+            }),
+            T::Decimal128(precision, scale) => {
+                A::Decimal128(self.read_decimal(nullable, *precision, *scale, i128::from_le_bytes))
+            }
+            T::Decimal256(precision, scale) => A::Decimal256(self.read_decimal(
+                nullable,
+                *precision,
+                *scale,
+                bytemuck::cast::<[u8; 32], _>,
+            )),
+            T::Utf8 => A::Utf8(self.read_bytes_array(nullable, i32::from_le_bytes)),
+            T::LargeUtf8 => A::LargeUtf8(self.read_bytes_array(nullable, i64::from_le_bytes)),
+            T::Binary => A::Binary(self.read_bytes_array(nullable, i32::from_le_bytes)),
+            T::LargeBinary => A::LargeBinary(self.read_bytes_array(nullable, i64::from_le_bytes)),
+            T::FixedSizeBinary(n) => A::FixedSizeBinary(FixedSizeBinaryArray {
+                n: *n,
+                validity: self.read_validity(nullable),
+                data: self.read_bytes(),
+            }),
+            T::Struct(fields) => {
+                let validity = self.read_validity(nullable);
+                let mut children = Vec::new();
+                for field in fields {
+                    let len = self.read_len();
+                    children.push((
+                        self.read_array(&field.data_type, field.nullable, len)?,
+                        meta_from_field(field.clone()),
+                    ));
+                }
+                A::Struct(StructArray {
+                    len,
+                    validity,
+                    fields: children,
+                })
+            }
+            T::List(field) => A::List(self.read_list(field, nullable, i32::from_le_bytes)?),
+            T::LargeList(field) => {
+                A::LargeList(self.read_list(field, nullable, i64::from_le_bytes)?)
+            }
+            T::Map(field, _) => A::Map(self.read_list(field, nullable, i32::from_le_bytes)?),
+            T::FixedSizeList(field, n) => {
+                let validity = self.read_validity(nullable);
+                let element_len = self.read_len();
+                A::FixedSizeList(FixedSizeListArray {
+                    len,
+                    n: *n,
+                    validity,
+                    meta: meta_from_field(*field.clone()),
+                    element: Box::new(self.read_array(
+                        &field.data_type,
+                        field.nullable,
+                        element_len,
+                    )?),
+                })
+            }
+            T::Dictionary(key, value, _) => {
+                let indices = self.read_array(key, nullable, len)?;
+                let values_len = self.read_len();
+                A::Dictionary(DictionaryArray {
+                    indices: Box::new(indices),
+                    values: Box::new(self.read_array(value, false, values_len)?),
+                })
+            }
+            T::Union(fields, UnionMode::Dense) => {
+                let types = self.read_values(i8::from_le_bytes);
+                let offsets = self.read_values(i32::from_le_bytes);
+                let mut children = Vec::new();
+                for (type_id, field) in fields {
+                    let len = self.read_len();
+                    children.push((
+                        *type_id,
+                        self.read_array(&field.data_type, field.nullable, len)?,
+                        meta_from_field(field.clone()),
+                    ));
+                }
+                A::DenseUnion(DenseUnionArray {
+                    types,
+                    offsets,
+                    fields: children,
+                })
+            }
+            T::Union(_, UnionMode::Sparse) => fail!("Sparse unions are not supported"),
+            T::Utf8View | T::BinaryView | T::ListView(_) | T::LargeListView(_) => {
+                fail!("View types are not supported")
+            }
+        })
+    }
+}
+
+fn bits(validity: &Option<Vec<u8>>) -> Option<BitsWithOffset<'_>> {
+    validity
+        .as_ref()
+        .map(|data| BitsWithOffset { offset: 0, data })
+}
+
+fn view_of(array: &Array) -> Result<ArrayView<'_>> {
+    use {Array as A, ArrayView as V};
+
+    fn primitive<T>(array: &PrimitiveArray<T>) -> PrimitiveArrayView<'_, T> {
+        PrimitiveArrayView {
+            validity: bits(&array.validity),
+            values: &array.values,
+        }
+    }
+
+    fn time<T>(array: &TimeArray<T>) -> TimeArrayView<'_, T> {
+        TimeArrayView {
+            unit: array.unit,
+            validity: bits(&array.validity),
+            values: &array.values,
+        }
+    }
+
+    fn decimal<T>(array: &DecimalArray<T>) -> DecimalArrayView<'_, T> {
+        DecimalArrayView {
+            precision: array.precision,
+            scale: array.scale,
+            validity: bits(&array.validity),
+            values: &array.values,
+        }
+    }
+
+    fn bytes<O>(array: &BytesArray<O>) -> BytesArrayView<'_, O> {
+        BytesArrayView {
+            validity: bits(&array.validity),
+            offsets: &array.offsets,
+            data: &array.data,
+        }
+    }
+
+    fn list<O>(array: &ListArray<O>) -> Result<ListArrayView<'_, O>> {
+        Ok(ListArrayView {
+            validity: bits(&array.validity),
+            offsets: &array.offsets,
+            meta: array.meta.clone(),
+            element: Box::new(view_of(&array.element)?),
+        })
+    }
+
+    Ok(match array {
+        A::Null(array) => V::Null(NullArrayView { len: array.len }),
+        A::Boolean(array) => V::Boolean(BooleanArrayView {
+            len: array.len,
+            validity: bits(&array.validity),
+            values: BitsWithOffset {
+                offset: 0,
+                data: &array.values,
+            },
+        }),
+        A::Int8(array) => V::Int8(primitive(array)),
+        A::Int16(array) => V::Int16(primitive(array)),
+        A::Int32(array) => V::Int32(primitive(array)),
+        A::Int64(array) => V::Int64(primitive(array)),
+        A::UInt8(array) => V::UInt8(primitive(array)),
+        A::UInt16(array) => V::UInt16(primitive(array)),
+        A::UInt32(array) => V::UInt32(primitive(array)),
+        A::UInt64(array) => V::UInt64(primitive(array)),
+        A::Float16(array) => V::Float16(primitive(array)),
+        A::Float32(array) => V::Float32(primitive(array)),
+        A::Float64(array) => V::Float64(primitive(array)),
+        A::Date32(array) => V::Date32(primitive(array)),
+        A::Date64(array) => V::Date64(primitive(array)),
+        A::IntervalYearMonth(array) => V::IntervalYearMonth(primitive(array)),
+        A::IntervalDayTime(array) => V::IntervalDayTime(primitive(array)),
+        A::IntervalMonthDayNano(array) => V::IntervalMonthDayNano(primitive(array)),
+        A::Time32(array) => V::Time32(time(array)),
+        A::Time64(array) => V::Time64(time(array)),
+        A::Duration(array) => V::Duration(time(array)),
+        A::Timestamp(array) => V::Timestamp(TimestampArrayView {
+            unit: array.unit,
+            timezone: array.timezone.clone(),
+            validity: bits(&array.validity),
+            values: &array.values,
+        }),
+        A::Decimal128(array) => V::Decimal128(decimal(array)),
+        A::Decimal256(array) => V::Decimal256(decimal(array)),
+        A::Utf8(array) => V::Utf8(bytes(array)),
+        A::LargeUtf8(array) => V::LargeUtf8(bytes(array)),
+        A::Binary(array) => V::Binary(bytes(array)),
+        A::LargeBinary(array) => V::LargeBinary(bytes(array)),
+        A::FixedSizeBinary(array) => V::FixedSizeBinary(FixedSizeBinaryArrayView {
+            n: array.n,
+            validity: bits(&array.validity),
+            data: &array.data,
+        }),
+        A::Struct(array) => V::Struct(StructArrayView {
+            len: array.len,
+            validity: bits(&array.validity),
+            fields: array
+                .fields
+                .iter()
+                .map(|(child, meta)| Ok((view_of(child)?, meta.clone())))
+                .collect::<Result<_>>()?,
+        }),
+        A::List(array) => V::List(list(array)?),
+        A::LargeList(array) => V::LargeList(list(array)?),
+        A::Map(array) => V::Map(list(array)?),
+        A::FixedSizeList(array) => V::FixedSizeList(FixedSizeListArrayView {
+            len: array.len,
+            n: array.n,
+            validity: bits(&array.validity),
+            meta: array.meta.clone(),
+            element: Box::new(view_of(&array.element)?),
+        }),
+        A::Dictionary(array) => V::Dictionary(DictionaryArrayView {
+            indices: Box::new(view_of(&array.indices)?),
+            values: Box::new(view_of(&array.values)?),
+        }),
+        A::DenseUnion(array) => V::DenseUnion(DenseUnionArrayView {
+            types: &array.types,
+            offsets: &array.offsets,
+            fields: array
+                .fields
+                .iter()
+                .map(|(type_id, child, meta)| Ok((*type_id, view_of(child)?, meta.clone())))
+                .collect::<Result<_>>()?,
+        }),
+        A::SparseUnion(_) => fail!("Sparse unions are not supported"),
+        A::Utf8View(_) => fail!("View types are not supported"),
+    })
+}
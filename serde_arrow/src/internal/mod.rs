@@ -3,11 +3,25 @@ pub mod arrow;
 pub mod chrono;
 pub mod deserialization;
 pub mod deserializer;
+pub mod deserializer_options;
 pub mod error;
+pub mod fuzz;
+#[cfg(has_arrow)]
+pub mod projection;
+pub mod row_format;
+pub mod row_sink;
 pub mod schema;
 pub mod serialization;
 pub mod serializer;
+pub mod serializer_options;
+pub mod session;
+pub mod shared_dictionaries;
+pub mod sort;
+#[cfg(feature = "spill")]
+pub mod spill;
+pub mod string_interner;
 pub mod utils;
+pub mod variant_extractors;
 
 #[cfg(test)]
 pub mod testing;
@@ -0,0 +1,411 @@
+//! Determine the leaf columns required to deserialize a type
+//!
+//! The leaves are numbered in depth-first order, as done by parquet readers:
+//! each primitive field, dictionary or null field is a single leaf, structs,
+//! lists and unions contribute the leaves of their children, maps the leaves
+//! of their keys and values.
+//!
+//! The type is deserialized from a probe deserializer that mirrors the shape of
+//! the schema. Initially all leaves are considered used. Whenever the type
+//! requests a struct with known field names, only the leaves of these fields
+//! are kept. Any part of the schema the type does not explore further (e.g.,
+//! flattened fields or unions) is kept completely.
+use std::cell::RefCell;
+
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::Deserialize;
+
+use crate::internal::{
+    arrow::{DataType, Field},
+    error::{fail, Error, Result},
+};
+
+/// Compute the indices of the leaf columns required to deserialize `T`
+pub fn projection_for<'de, T: Deserialize<'de>>(fields: &[Field]) -> Result<Vec<usize>> {
+    let root = DataType::Struct(fields.to_vec());
+    let used = RefCell::new(vec![true; num_leaves(&root)]);
+
+    // errors only stop the exploration: all leaves not explored remain used
+    let _ = T::deserialize(Probe {
+        data_type: &root,
+        offset: 0,
+        used: &used,
+    });
+
+    let used = used.into_inner();
+    Ok((0..used.len()).filter(|&idx| used[idx]).collect())
+}
+
+fn num_leaves(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Struct(fields) => fields.iter().map(|f| num_leaves(&f.data_type)).sum(),
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            num_leaves(&field.data_type)
+        }
+        DataType::Map(field, _) => num_leaves(&field.data_type),
+        DataType::Union(fields, _) => fields.iter().map(|(_, f)| num_leaves(&f.data_type)).sum(),
+        _ => 1,
+    }
+}
+
+/// A deserializer for a single node of the schema
+#[derive(Clone, Copy)]
+struct Probe<'a> {
+    data_type: &'a DataType,
+    offset: usize,
+    used: &'a RefCell<Vec<bool>>,
+}
+
+impl<'a> Probe<'a> {
+    fn child(&self, field: &'a Field, offset: usize) -> Self {
+        Self {
+            data_type: &field.data_type,
+            offset,
+            used: self.used,
+        }
+    }
+
+    /// The children of a struct together with their probes
+    fn struct_children(&self) -> Option<Vec<(&'a str, Probe<'a>)>> {
+        let DataType::Struct(fields) = self.data_type else {
+            return None;
+        };
+        let mut offset = self.offset;
+        let mut children = Vec::with_capacity(fields.len());
+        for field in fields {
+            children.push((field.name.as_str(), self.child(field, offset)));
+            offset += num_leaves(&field.data_type);
+        }
+        Some(children)
+    }
+
+    /// The element of a list-like node
+    fn element(&self) -> Option<Probe<'a>> {
+        match self.data_type {
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _) => Some(self.child(field, self.offset)),
+            _ => None,
+        }
+    }
+
+    /// The keys and values of a map node
+    fn entry(&self) -> Option<(Probe<'a>, Probe<'a>)> {
+        let DataType::Map(entries, _) = self.data_type else {
+            return None;
+        };
+        let DataType::Struct(fields) = &entries.data_type else {
+            return None;
+        };
+        let [key, value] = fields.as_slice() else {
+            return None;
+        };
+        Some((
+            self.child(key, self.offset),
+            self.child(value, self.offset + num_leaves(&key.data_type)),
+        ))
+    }
+
+    fn mark(&self, used: bool) {
+        let num_leaves = num_leaves(self.data_type);
+        self.used.borrow_mut()[self.offset..self.offset + num_leaves].fill(used);
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for Probe<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if let Some(children) = self.struct_children() {
+            return visitor.visit_map(ProbeMap::from_children(children));
+        }
+        if let Some(element) = self.element() {
+            return visitor.visit_seq(ProbeSeq(vec![element]));
+        }
+        if let Some(entry) = self.entry() {
+            return visitor.visit_map(ProbeMap::from_entry(entry));
+        }
+        match self.data_type {
+            DataType::Null => visitor.visit_unit(),
+            DataType::Boolean => visitor.visit_bool(false),
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                visitor.visit_i64(0)
+            }
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                visitor.visit_u64(0)
+            }
+            DataType::Float16 | DataType::Float32 | DataType::Float64 => visitor.visit_f64(0.0),
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Dictionary(_, _, _) => {
+                visitor.visit_str("")
+            }
+            DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => {
+                visitor.visit_bytes(&[])
+            }
+            DataType::Union(_, _) => fail!("Cannot explore unions"),
+            _ => visitor.visit_i64(0),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        names: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let Some(children) = self.struct_children() else {
+            return self.deserialize_any(visitor);
+        };
+
+        self.mark(false);
+        let mut selected = Vec::new();
+        for (name, child) in children {
+            if names.contains(&name) {
+                child.mark(true);
+                selected.push((name, child));
+            }
+        }
+        visitor.visit_map(ProbeMap::from_children(selected))
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        if let Some(children) = self.struct_children() {
+            return visitor.visit_seq(ProbeSeq(children.into_iter().map(|(_, c)| c).collect()));
+        }
+        if let Some(element) = self.element() {
+            return visitor.visit_seq(ProbeSeq(vec![element; len]));
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        if matches!(self.data_type, DataType::Union(_, _)) {
+            fail!("Cannot explore unions");
+        }
+        let Some(variant) = variants.first() else {
+            fail!("Cannot explore enums without variants");
+        };
+        visitor.visit_enum(ProbeEnum(variant, self))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(false)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i8(0)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i16(0)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i32(0)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i64(0)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u8(0)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u16(0)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u32(0)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u64(0)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f32(0.0)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_f64(0.0)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_char('\0')
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_str("")
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_str("")
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bytes(&[])
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bytes(&[])
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_str("")
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+enum ProbeKey<'a> {
+    Name(&'a str),
+    Probe(Probe<'a>),
+}
+
+struct ProbeMap<'a> {
+    entries: std::vec::IntoIter<(ProbeKey<'a>, Probe<'a>)>,
+    next_value: Option<Probe<'a>>,
+}
+
+impl<'a> ProbeMap<'a> {
+    fn from_children(children: Vec<(&'a str, Probe<'a>)>) -> Self {
+        let entries = children
+            .into_iter()
+            .map(|(name, child)| (ProbeKey::Name(name), child))
+            .collect::<Vec<_>>();
+        Self {
+            entries: entries.into_iter(),
+            next_value: None,
+        }
+    }
+
+    fn from_entry((key, value): (Probe<'a>, Probe<'a>)) -> Self {
+        Self {
+            entries: vec![(ProbeKey::Probe(key), value)].into_iter(),
+            next_value: None,
+        }
+    }
+}
+
+impl<'de, 'a> MapAccess<'de> for ProbeMap<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.next_value = Some(value);
+        match key {
+            ProbeKey::Name(name) => seed.deserialize(name.into_deserializer()).map(Some),
+            ProbeKey::Probe(probe) => seed.deserialize(probe).map(Some),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let Some(value) = self.next_value.take() else {
+            fail!("Invalid call to next_value_seed: no key was requested");
+        };
+        seed.deserialize(value)
+    }
+}
+
+struct ProbeSeq<'a>(Vec<Probe<'a>>);
+
+impl<'de, 'a> SeqAccess<'de> for ProbeSeq<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.0.is_empty() {
+            return Ok(None);
+        }
+        seed.deserialize(self.0.remove(0)).map(Some)
+    }
+}
+
+struct ProbeEnum<'a>(&'static str, Probe<'a>);
+
+impl<'de, 'a> EnumAccess<'de> for ProbeEnum<'a> {
+    type Error = Error;
+    type Variant = Probe<'a>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Probe<'a>)> {
+        let variant = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(self.0))?;
+        Ok((variant, self.1))
+    }
+}
+
+impl<'de, 'a> VariantAccess<'de> for Probe<'a> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        names: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_struct("", names, visitor)
+    }
+}
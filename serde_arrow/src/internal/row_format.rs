@@ -0,0 +1,486 @@
+//! Order preserving binary encoding of rows compatible with `arrow-row`
+//!
+//! The layout follows the `RowConverter` of the `arrow-row` crate with the
+//! default sort options (ascending, nulls first):
+//!
+//! - fixed width values: `0x01` followed by the big endian value with the sign
+//!   bit flipped for signed integers and the total order transform for floats.
+//!   Nulls are encoded as `0x00` followed by zeros
+//! - variable width values: `0x00` for null, `0x01` for empty values and
+//!   `0x02` followed by blocks of data for non-empty values
+//! - structs: `0x01` followed by the encoded children, or `0x00` followed by
+//!   the encoded children of a null row for null values
+//! - dictionaries: the encoding of the referenced value
+use std::cmp::Ordering;
+
+use half::f16;
+use serde::Serialize;
+
+use crate::internal::{
+    array_builder::ArrayBuilder,
+    arrow::{
+        Array, BytesArray, DayTimeInterval, DictionaryArray, MonthDayNanoInterval, PrimitiveArray,
+        StructArray, I256,
+    },
+    error::{fail, Result},
+    schema::SerdeArrowSchema,
+    utils::{array_ext::get_bit_buffer, Offset},
+};
+
+/// The block size of the variable length encoding
+const BLOCK_SIZE: usize = 32;
+
+/// The first block of variable length values is split into mini blocks
+const MINI_BLOCK_SIZE: usize = BLOCK_SIZE / 4;
+
+const BLOCK_CONTINUATION: u8 = 0xFF;
+pub(crate) const NULL_SENTINEL: u8 = 0;
+const VALID_SENTINEL: u8 = 1;
+const EMPTY_SENTINEL: u8 = 1;
+const NON_EMPTY_SENTINEL: u8 = 2;
+
+/// Serialize the items and encode each row as an order preserving binary key
+///
+/// The keys of two rows compare (as byte slices) in the same order as the
+/// rows compare field by field, with nulls sorted first. The encoding matches
+/// the rows produced by the `RowConverter` of the `arrow-row` crate with
+/// default sort options. See [`compare`] for a comparator.
+///
+/// Supported are fields of type `Null`, `Boolean`, integers, floats, dates,
+/// times, timestamps, durations, decimals, strings, binary data,
+/// dictionaries and structs of these types. Lists, maps and unions result in
+/// an error.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     row_format,
+///     schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+/// };
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: i32,
+///     b: String,
+/// }
+///
+/// let items = [
+///     Record { a: 1, b: String::from("foo") },
+///     Record { a: -2, b: String::from("bar") },
+///     Record { a: 1, b: String::from("baz") },
+/// ];
+///
+/// let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default())?;
+/// let mut keys = row_format::encode(&schema, &items)?;
+/// keys.sort_by(|a, b| row_format::compare(a, b));
+///
+/// assert_eq!(keys[0], row_format::encode(&schema, &items[1..2])?[0]);
+/// assert_eq!(keys[1], row_format::encode(&schema, &items[2..3])?[0]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode<T: Serialize>(schema: &SerdeArrowSchema, items: T) -> Result<Vec<Vec<u8>>> {
+    let mut builder = ArrayBuilder::new(schema.clone())?;
+    builder.extend(items)?;
+
+    let mut rows = vec![Vec::new(); builder.num_rows()];
+    for array in builder.build_arrays()? {
+        encode_array(&mut rows, &array)?;
+    }
+    Ok(rows)
+}
+
+/// Compare two rows encoded with [`encode`]
+///
+/// The rows are compared as byte slices.
+pub fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    a.cmp(b)
+}
+
+pub(crate) fn encode_array(rows: &mut [Vec<u8>], array: &Array) -> Result<()> {
+    use Array as A;
+    match array {
+        A::Null(_) => Ok(()),
+        A::Boolean(array) => {
+            for (idx, row) in rows.iter_mut().enumerate() {
+                if is_valid(array.validity.as_deref(), idx)? {
+                    let value = get_bit_buffer(&array.values, 0, idx)?;
+                    row.extend([VALID_SENTINEL, value as u8]);
+                } else {
+                    row.extend([NULL_SENTINEL, 0]);
+                }
+            }
+            Ok(())
+        }
+        A::Int8(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::Int16(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::Int32(array) | A::Date32(array) | A::IntervalYearMonth(array) => {
+            encode_fixed(rows, array.validity.as_deref(), &array.values)
+        }
+        A::Int64(array) | A::Date64(array) => {
+            encode_fixed(rows, array.validity.as_deref(), &array.values)
+        }
+        A::UInt8(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::UInt16(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::UInt32(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::UInt64(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::Float16(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::Float32(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::Float64(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::Time32(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::Time64(array) | A::Duration(array) => {
+            encode_fixed(rows, array.validity.as_deref(), &array.values)
+        }
+        A::Timestamp(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::IntervalDayTime(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::IntervalMonthDayNano(array) => {
+            encode_fixed(rows, array.validity.as_deref(), &array.values)
+        }
+        A::Decimal128(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::Decimal256(array) => encode_fixed(rows, array.validity.as_deref(), &array.values),
+        A::Utf8(array) | A::Binary(array) => encode_bytes(rows, array),
+        A::LargeUtf8(array) | A::Utf8View(array) | A::LargeBinary(array) => {
+            encode_bytes(rows, array)
+        }
+        A::FixedSizeBinary(array) => {
+            let n = usize::try_from(array.n)?;
+            for (idx, row) in rows.iter_mut().enumerate() {
+                if is_valid(array.validity.as_deref(), idx)? {
+                    row.push(VALID_SENTINEL);
+                    row.extend_from_slice(&array.data[idx * n..(idx + 1) * n]);
+                } else {
+                    row.push(NULL_SENTINEL);
+                    row.resize(row.len() + n, 0);
+                }
+            }
+            Ok(())
+        }
+        A::Struct(array) => encode_struct(rows, array),
+        A::Dictionary(array) => encode_dictionary(rows, array),
+        A::List(_)
+        | A::LargeList(_)
+        | A::FixedSizeList(_)
+        | A::Map(_)
+        | A::DenseUnion(_)
+        | A::SparseUnion(_) => {
+            fail!("Row encoding does not support lists, maps or unions")
+        }
+    }
+}
+
+fn encode_fixed<T: FixedEncoding>(
+    rows: &mut [Vec<u8>],
+    validity: Option<&[u8]>,
+    values: &[T],
+) -> Result<()> {
+    if values.len() != rows.len() {
+        fail!(
+            "Invalid array: expected {} values, found {}",
+            rows.len(),
+            values.len()
+        );
+    }
+    for (idx, (row, value)) in rows.iter_mut().zip(values).enumerate() {
+        if is_valid(validity, idx)? {
+            row.push(VALID_SENTINEL);
+            value.encode(row);
+        } else {
+            row.push(NULL_SENTINEL);
+            row.resize(row.len() + T::WIDTH, 0);
+        }
+    }
+    Ok(())
+}
+
+fn encode_bytes<O: Offset>(rows: &mut [Vec<u8>], array: &BytesArray<O>) -> Result<()> {
+    if array.offsets.len() != rows.len() + 1 {
+        fail!(
+            "Invalid array: expected {} offsets, found {}",
+            rows.len() + 1,
+            array.offsets.len()
+        );
+    }
+    for (idx, row) in rows.iter_mut().enumerate() {
+        if is_valid(array.validity.as_deref(), idx)? {
+            let start = array.offsets[idx].try_into_usize()?;
+            let end = array.offsets[idx + 1].try_into_usize()?;
+            encode_variable(row, &array.data[start..end]);
+        } else {
+            row.push(NULL_SENTINEL);
+        }
+    }
+    Ok(())
+}
+
+fn encode_variable(row: &mut Vec<u8>, value: &[u8]) {
+    if value.is_empty() {
+        row.push(EMPTY_SENTINEL);
+        return;
+    }
+
+    row.push(NON_EMPTY_SENTINEL);
+    if value.len() <= BLOCK_SIZE {
+        encode_blocks(row, value, MINI_BLOCK_SIZE);
+    } else {
+        let (head, tail) = value.split_at(BLOCK_SIZE);
+        encode_blocks(row, head, MINI_BLOCK_SIZE);
+        *row.last_mut().unwrap() = BLOCK_CONTINUATION;
+        encode_blocks(row, tail, BLOCK_SIZE);
+    }
+}
+
+/// Write blocks padded with zeros, each followed by a continuation token or
+/// the length of the final block
+fn encode_blocks(row: &mut Vec<u8>, value: &[u8], size: usize) {
+    let mut chunks = value.chunks(size).peekable();
+    while let Some(chunk) = chunks.next() {
+        row.extend_from_slice(chunk);
+        row.resize(row.len() + size - chunk.len(), 0);
+        if chunks.peek().is_some() {
+            row.push(BLOCK_CONTINUATION);
+        } else {
+            row.push(chunk.len() as u8);
+        }
+    }
+}
+
+fn encode_struct(rows: &mut [Vec<u8>], array: &StructArray) -> Result<()> {
+    let mut children = vec![Vec::new(); rows.len()];
+    for (child, _) in &array.fields {
+        encode_array(&mut children, child)?;
+    }
+
+    let mut null = vec![NULL_SENTINEL];
+    for (child, _) in &array.fields {
+        null_row(&mut null, child)?;
+    }
+
+    for (idx, (row, child)) in rows.iter_mut().zip(children).enumerate() {
+        if is_valid(array.validity.as_deref(), idx)? {
+            row.push(VALID_SENTINEL);
+            row.extend(child);
+        } else {
+            row.extend_from_slice(&null);
+        }
+    }
+    Ok(())
+}
+
+fn encode_dictionary(rows: &mut [Vec<u8>], array: &DictionaryArray) -> Result<()> {
+    let (validity, indices) = dictionary_indices(&array.indices)?;
+    if indices.len() != rows.len() {
+        fail!(
+            "Invalid array: expected {} indices, found {}",
+            rows.len(),
+            indices.len()
+        );
+    }
+
+    let mut values = vec![Vec::new(); array_len(&array.values)?];
+    encode_array(&mut values, &array.values)?;
+
+    let mut null = Vec::new();
+    null_row(&mut null, &array.values)?;
+
+    for (idx, (row, index)) in rows.iter_mut().zip(indices).enumerate() {
+        if is_valid(validity, idx)? {
+            let Some(value) = values.get(index) else {
+                fail!("Invalid dictionary index {index}");
+            };
+            row.extend_from_slice(value);
+        } else {
+            row.extend_from_slice(&null);
+        }
+    }
+    Ok(())
+}
+
+/// Write the encoding of a null value of the given array type
+fn null_row(row: &mut Vec<u8>, array: &Array) -> Result<()> {
+    use Array as A;
+    let width = match array {
+        A::Null(_) => return Ok(()),
+        A::Utf8(_) | A::LargeUtf8(_) | A::Utf8View(_) | A::Binary(_) | A::LargeBinary(_) => {
+            row.push(NULL_SENTINEL);
+            return Ok(());
+        }
+        A::Struct(array) => {
+            row.push(NULL_SENTINEL);
+            for (child, _) in &array.fields {
+                null_row(row, child)?;
+            }
+            return Ok(());
+        }
+        A::Dictionary(array) => return null_row(row, &array.values),
+        A::Boolean(_) | A::Int8(_) | A::UInt8(_) => 1,
+        A::Int16(_) | A::UInt16(_) | A::Float16(_) => 2,
+        A::Int32(_) | A::UInt32(_) | A::Float32(_) | A::Date32(_) | A::Time32(_) => 4,
+        A::Int64(_) | A::UInt64(_) | A::Float64(_) | A::Date64(_) | A::Time64(_) => 8,
+        A::Timestamp(_) | A::Duration(_) => 8,
+        A::IntervalYearMonth(_) => 4,
+        A::IntervalDayTime(_) => 8,
+        A::IntervalMonthDayNano(_) => 16,
+        A::Decimal128(_) => 16,
+        A::Decimal256(_) => 32,
+        A::FixedSizeBinary(array) => usize::try_from(array.n)?,
+        A::List(_)
+        | A::LargeList(_)
+        | A::FixedSizeList(_)
+        | A::Map(_)
+        | A::DenseUnion(_)
+        | A::SparseUnion(_) => {
+            fail!("Row encoding does not support lists, maps or unions")
+        }
+    };
+    row.push(NULL_SENTINEL);
+    row.resize(row.len() + width, 0);
+    Ok(())
+}
+
+fn dictionary_indices(indices: &Array) -> Result<(Option<&[u8]>, Vec<usize>)> {
+    fn convert<T: Copy + TryInto<usize>>(array: &PrimitiveArray<T>) -> (Option<&[u8]>, Vec<usize>) {
+        let indices = array
+            .values
+            .iter()
+            .map(|&index| index.try_into().unwrap_or(usize::MAX))
+            .collect();
+        (array.validity.as_deref(), indices)
+    }
+
+    match indices {
+        Array::Int8(array) => Ok(convert(array)),
+        Array::Int16(array) => Ok(convert(array)),
+        Array::Int32(array) => Ok(convert(array)),
+        Array::Int64(array) => Ok(convert(array)),
+        Array::UInt8(array) => Ok(convert(array)),
+        Array::UInt16(array) => Ok(convert(array)),
+        Array::UInt32(array) => Ok(convert(array)),
+        Array::UInt64(array) => Ok(convert(array)),
+        _ => fail!("Invalid dictionary: indices must be integers"),
+    }
+}
+
+fn array_len(array: &Array) -> Result<usize> {
+    use Array as A;
+    Ok(match array {
+        A::Utf8(array) | A::Binary(array) => array.offsets.len().saturating_sub(1),
+        A::LargeUtf8(array) | A::Utf8View(array) | A::LargeBinary(array) => {
+            array.offsets.len().saturating_sub(1)
+        }
+        A::Null(array) => array.len,
+        A::Boolean(array) => array.len,
+        A::Struct(array) => array.len,
+        A::UInt8(array) => array.values.len(),
+        A::UInt16(array) => array.values.len(),
+        A::UInt32(array) => array.values.len(),
+        A::UInt64(array) => array.values.len(),
+        A::Int8(array) => array.values.len(),
+        A::Int16(array) => array.values.len(),
+        A::Int32(array) | A::Date32(array) => array.values.len(),
+        A::Int64(array) | A::Date64(array) => array.values.len(),
+        _ => fail!("Unsupported dictionary values"),
+    })
+}
+
+fn is_valid(validity: Option<&[u8]>, idx: usize) -> Result<bool> {
+    match validity {
+        Some(validity) => get_bit_buffer(validity, 0, idx),
+        None => Ok(true),
+    }
+}
+
+/// A value with a fixed width, order preserving big endian encoding
+trait FixedEncoding: Copy {
+    const WIDTH: usize;
+
+    fn encode(self, row: &mut Vec<u8>);
+}
+
+macro_rules! impl_fixed_encoding_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl FixedEncoding for $ty {
+                const WIDTH: usize = std::mem::size_of::<$ty>();
+
+                fn encode(self, row: &mut Vec<u8>) {
+                    row.extend(self.to_be_bytes());
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_fixed_encoding_signed {
+    ($($ty:ty),*) => {
+        $(
+            impl FixedEncoding for $ty {
+                const WIDTH: usize = std::mem::size_of::<$ty>();
+
+                fn encode(self, row: &mut Vec<u8>) {
+                    let mut bytes = self.to_be_bytes();
+                    bytes[0] ^= 0x80;
+                    row.extend(bytes);
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_encoding_unsigned!(u8, u16, u32, u64, u128);
+impl_fixed_encoding_signed!(i8, i16, i32, i64, i128);
+
+impl FixedEncoding for f16 {
+    const WIDTH: usize = 2;
+
+    fn encode(self, row: &mut Vec<u8>) {
+        let bits = self.to_bits() as i16;
+        (bits ^ (((bits >> 15) as u16) >> 1) as i16).encode(row);
+    }
+}
+
+impl FixedEncoding for f32 {
+    const WIDTH: usize = 4;
+
+    fn encode(self, row: &mut Vec<u8>) {
+        let bits = self.to_bits() as i32;
+        (bits ^ (((bits >> 31) as u32) >> 1) as i32).encode(row);
+    }
+}
+
+impl FixedEncoding for f64 {
+    const WIDTH: usize = 8;
+
+    fn encode(self, row: &mut Vec<u8>) {
+        let bits = self.to_bits() as i64;
+        (bits ^ (((bits >> 63) as u64) >> 1) as i64).encode(row);
+    }
+}
+
+impl FixedEncoding for DayTimeInterval {
+    const WIDTH: usize = 8;
+
+    fn encode(self, row: &mut Vec<u8>) {
+        self.days.encode(row);
+        self.milliseconds.encode(row);
+    }
+}
+
+impl FixedEncoding for MonthDayNanoInterval {
+    const WIDTH: usize = 16;
+
+    fn encode(self, row: &mut Vec<u8>) {
+        self.months.encode(row);
+        self.days.encode(row);
+        self.nanoseconds.encode(row);
+    }
+}
+
+impl FixedEncoding for I256 {
+    const WIDTH: usize = 32;
+
+    fn encode(self, row: &mut Vec<u8>) {
+        self.high.encode(row);
+        self.low.encode(row);
+    }
+}
@@ -0,0 +1,121 @@
+use serde::Serialize;
+
+use crate::internal::{array_builder::ArrayBuilder, error::Result};
+
+/// Push records one at a time and signal when a batch should be emitted
+///
+/// The sink wraps an [`ArrayBuilder`] and compares the number of buffered
+/// records against the configured threshold after each push. It never emits
+/// batches itself: the caller decides when to cut a batch, e.g., after
+/// receiving [`SinkState::Full`] or after a timeout in an async consumer. The
+/// arrays are constructed via the underlying builder, which is accessible via
+/// [`AsMut`].
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     ArrayBuilder, RowSink, SinkState,
+/// };
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: u32,
+/// }
+///
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// let mut sink = RowSink::new(ArrayBuilder::from_arrow(&fields)?, 2);
+///
+/// let mut batches = Vec::new();
+/// for a in 0..5 {
+///     if sink.push(&Record { a })? == SinkState::Full {
+///         batches.push(sink.as_mut().to_record_batch()?);
+///     }
+/// }
+/// if !sink.is_empty() {
+///     batches.push(sink.as_mut().to_record_batch()?);
+/// }
+///
+/// assert_eq!(batches.len(), 3);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+#[derive(Debug)]
+pub struct RowSink {
+    builder: ArrayBuilder,
+    max_rows: usize,
+}
+
+/// The state of a [`RowSink`] after pushing a record
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SinkState {
+    /// The number of buffered records is below the threshold
+    Accepting,
+    /// The number of buffered records reached the threshold and the batch
+    /// should be emitted
+    Full,
+}
+
+impl RowSink {
+    /// Construct a new sink that is full after `max_rows` records
+    pub fn new(builder: ArrayBuilder, max_rows: usize) -> Self {
+        Self { builder, max_rows }
+    }
+
+    /// Push a single record and return the state of the sink
+    ///
+    /// Records can be pushed into a full sink. It is up to the caller to emit
+    /// the batch.
+    pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<SinkState> {
+        self.builder.push(item)?;
+        Ok(self.state())
+    }
+
+    /// The current state of the sink
+    pub fn state(&self) -> SinkState {
+        if self.num_rows() >= self.max_rows {
+            SinkState::Full
+        } else {
+            SinkState::Accepting
+        }
+    }
+
+    /// The number of records buffered since the last batch was built
+    pub fn num_rows(&self) -> usize {
+        self.builder.num_rows()
+    }
+
+    /// Check whether no records are buffered
+    pub fn is_empty(&self) -> bool {
+        self.num_rows() == 0
+    }
+
+    /// The configured maximum number of records per batch
+    pub fn max_rows(&self) -> usize {
+        self.max_rows
+    }
+
+    /// Unwrap the underlying builder
+    pub fn into_inner(self) -> ArrayBuilder {
+        self.builder
+    }
+}
+
+impl std::convert::AsRef<ArrayBuilder> for RowSink {
+    fn as_ref(&self) -> &ArrayBuilder {
+        &self.builder
+    }
+}
+
+impl std::convert::AsMut<ArrayBuilder> for RowSink {
+    fn as_mut(&mut self) -> &mut ArrayBuilder {
+        &mut self.builder
+    }
+}
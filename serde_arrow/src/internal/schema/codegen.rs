@@ -0,0 +1,471 @@
+//! Generate Rust type definitions matching a schema
+use std::{collections::HashSet, fmt::Write};
+
+use crate::internal::{
+    arrow::{DataType, Field, IntervalUnit},
+    error::Result,
+};
+
+use super::{get_strategy_from_metadata, SerdeArrowSchema, Strategy};
+
+const DERIVE: &str = "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]";
+
+/// The name of the generated type of the rows
+const ROOT_NAME: &str = "Record";
+
+/// Generate Rust type definitions for the rows of a schema
+///
+/// The result contains a `Record` struct with one field per field of the
+/// schema, followed by the definitions of nested structs and enums. All
+/// types derive `Serialize` and `Deserialize` and can be used to consume
+/// arrays of this schema. Nullable fields are wrapped in `Option`, lists are
+/// mapped to `Vec` and maps to `HashMap`. Dates, times and timestamps are
+/// mapped to `chrono` types. Names that are not valid Rust identifiers are
+/// renamed via `#[serde(rename = "...")]`. The generated code is meant as a
+/// starting point and may need manual adjustments.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_json::json;
+/// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+///
+/// let schema = SerdeArrowSchema::from_value(json!([
+///     {"name": "id", "data_type": "U32"},
+///     {"name": "createdAt", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+///     {"name": "location", "data_type": "Struct", "nullable": true, "children": [
+///         {"name": "lat", "data_type": "F64"},
+///         {"name": "lon", "data_type": "F64"},
+///     ]},
+/// ]))?;
+///
+/// assert_eq!(
+///     serde_arrow::codegen::rust_types_for(&schema)?,
+///     concat!(
+///         "use serde::{Deserialize, Serialize};\n",
+///         "\n",
+///         "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+///         "pub struct Record {\n",
+///         "    pub id: u32,\n",
+///         "    #[serde(rename = \"createdAt\")]\n",
+///         "    pub created_at: chrono::DateTime<chrono::Utc>,\n",
+///         "    pub location: Option<Location>,\n",
+///         "}\n",
+///         "\n",
+///         "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+///         "pub struct Location {\n",
+///         "    pub lat: f64,\n",
+///         "    pub lon: f64,\n",
+///         "}\n",
+///     ),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn rust_types_for(schema: &SerdeArrowSchema) -> Result<String> {
+    rust_types_for_fields(&schema.fields)
+}
+
+fn rust_types_for_fields(fields: &[Field]) -> Result<String> {
+    let mut generator = Generator::default();
+    let name = generator.type_name(ROOT_NAME);
+    generator.struct_definition(&name, fields)?;
+
+    let mut out = String::from("use serde::{Deserialize, Serialize};\n");
+    for definition in generator.definitions {
+        out.push('\n');
+        out.push_str(&definition);
+    }
+    Ok(out)
+}
+
+#[derive(Default)]
+struct Generator {
+    definitions: Vec<String>,
+    type_names: HashSet<String>,
+}
+
+impl Generator {
+    /// Reserve a unique type name derived from the field name
+    fn type_name(&mut self, hint: &str) -> String {
+        let base = match camel_case(hint) {
+            name if name.is_empty() => String::from("Unnamed"),
+            name if name.starts_with(|c: char| c.is_ascii_digit()) => format!("T{name}"),
+            name => name,
+        };
+
+        let mut name = base.clone();
+        let mut counter = 2;
+        while is_reserved_type_name(&name) || self.type_names.contains(&name) {
+            name = format!("{base}{counter}");
+            counter += 1;
+        }
+        self.type_names.insert(name.clone());
+        name
+    }
+
+    /// Add a definition, nested types are added after their parents
+    fn add_definition(
+        &mut self,
+        build: impl FnOnce(&mut Self, &mut String) -> Result<()>,
+    ) -> Result<()> {
+        let idx = self.definitions.len();
+        self.definitions.push(String::new());
+
+        let mut definition = String::new();
+        build(self, &mut definition)?;
+        self.definitions[idx] = definition;
+        Ok(())
+    }
+
+    fn struct_definition(&mut self, name: &str, fields: &[Field]) -> Result<()> {
+        self.add_definition(|this, out| {
+            writeln!(out, "{DERIVE}")?;
+            writeln!(out, "pub struct {name} {{")?;
+            this.struct_fields(out, fields, "pub ")?;
+            writeln!(out, "}}")?;
+            Ok(())
+        })
+    }
+
+    fn struct_fields(&mut self, out: &mut String, fields: &[Field], vis: &str) -> Result<()> {
+        for field in fields {
+            let strategy = get_strategy_from_metadata(&field.metadata)?;
+            // hash fields are filled during serialization and ignored during
+            // deserialization
+            if matches!(
+                strategy,
+                Some(Strategy::StringHash { .. } | Strategy::RowHash)
+            ) {
+                continue;
+            }
+
+            let ident = field_ident(&field.name);
+            if ident.trim_start_matches("r#") != field.name {
+                writeln!(out, "    #[serde(rename = {:?})]", field.name)?;
+            }
+            if matches!(strategy, Some(Strategy::CatchAll)) {
+                writeln!(out, "    #[serde(flatten)]")?;
+            }
+            let ty = self.field_type(field, &field.name)?;
+            writeln!(out, "    {vis}{ident}: {ty},")?;
+        }
+        Ok(())
+    }
+
+    /// The type of a field, wrapped in an `Option` if it is nullable
+    fn field_type(&mut self, field: &Field, hint: &str) -> Result<String> {
+        let ty = self.data_type(field, hint)?;
+        if field.nullable && !matches!(field.data_type, DataType::Null) {
+            Ok(format!("Option<{ty}>"))
+        } else {
+            Ok(ty)
+        }
+    }
+
+    fn data_type(&mut self, field: &Field, hint: &str) -> Result<String> {
+        use DataType as T;
+
+        let strategy = get_strategy_from_metadata(&field.metadata)?;
+        if matches!(
+            strategy,
+            Some(Strategy::Stringify | Strategy::DictionaryOverflow { .. })
+        ) {
+            return Ok(String::from("String"));
+        }
+
+        Ok(match &field.data_type {
+            T::Int8 | T::UInt8 if matches!(strategy, Some(Strategy::Quantized { .. })) => {
+                String::from("f32")
+            }
+            T::Null => String::from("()"),
+            T::Boolean => String::from("bool"),
+            T::Int8 => String::from("i8"),
+            T::Int16 => String::from("i16"),
+            T::Int32 => String::from("i32"),
+            T::Int64 => String::from("i64"),
+            T::UInt8 => String::from("u8"),
+            T::UInt16 => String::from("u16"),
+            T::UInt32 => String::from("u32"),
+            T::UInt64 => String::from("u64"),
+            T::Float16 | T::Float32 => String::from("f32"),
+            T::Float64 => String::from("f64"),
+            T::Utf8 | T::LargeUtf8 | T::Utf8View => String::from("String"),
+            T::Binary | T::LargeBinary | T::BinaryView | T::FixedSizeBinary(_) => {
+                String::from("Vec<u8>")
+            }
+            T::Date32 | T::Date64 | T::Timestamp(_, _) | T::Time32(_) | T::Time64(_)
+                if matches!(strategy, Some(Strategy::TimeCrateStr)) =>
+            {
+                String::from("String")
+            }
+            T::Date32 => String::from("chrono::NaiveDate"),
+            T::Date64 => match strategy {
+                Some(Strategy::UtcStrAsDate64) => String::from("chrono::DateTime<chrono::Utc>"),
+                Some(Strategy::NaiveStrAsDate64) => String::from("chrono::NaiveDateTime"),
+                _ => String::from("i64"),
+            },
+            T::Timestamp(_, None) => String::from("chrono::NaiveDateTime"),
+            T::Timestamp(_, Some(tz)) if tz.eq_ignore_ascii_case("utc") => {
+                String::from("chrono::DateTime<chrono::Utc>")
+            }
+            T::Timestamp(_, Some(_)) | T::Duration(_) => String::from("i64"),
+            T::Interval(IntervalUnit::YearMonth) => String::from("i32"),
+            T::Interval(IntervalUnit::DayTime) => String::from("(i32, i32)"),
+            T::Interval(IntervalUnit::MonthDayNano) => String::from("(i32, i32, i64)"),
+            T::Time32(_) | T::Time64(_) => String::from("chrono::NaiveTime"),
+            T::Decimal128(_, _) | T::Decimal256(_, _) => {
+                if matches!(strategy, Some(Strategy::ScaledInt { .. })) {
+                    String::from("i64")
+                } else {
+                    String::from("String")
+                }
+            }
+            T::List(item)
+            | T::LargeList(item)
+            | T::ListView(item)
+            | T::LargeListView(item)
+            | T::FixedSizeList(item, _) => format!("Vec<{}>", self.field_type(item, hint)?),
+            T::Map(entries, _) => {
+                let (key, value) = match &entries.data_type {
+                    T::Struct(children) if children.len() == 2 => (&children[0], &children[1]),
+                    _ => return Ok(String::from("std::collections::HashMap<String, ()>")),
+                };
+                format!(
+                    "std::collections::HashMap<{}, {}>",
+                    self.field_type(key, hint)?,
+                    self.field_type(value, hint)?,
+                )
+            }
+            T::Dictionary(_, value, _) => self.data_type(
+                &Field {
+                    name: field.name.clone(),
+                    data_type: value.as_ref().clone(),
+                    nullable: false,
+                    metadata: field.metadata.clone(),
+                },
+                hint,
+            )?,
+            T::Struct(children) => match strategy {
+                Some(Strategy::TupleAsStruct) => {
+                    let mut items = Vec::new();
+                    for child in children {
+                        items.push(self.field_type(child, hint)?);
+                    }
+                    tuple_type(&items)
+                }
+                Some(Strategy::EnumOneHot) => {
+                    let name = self.type_name(hint);
+                    self.one_hot_enum_definition(&name, children)?;
+                    name
+                }
+                Some(Strategy::EnumAsStructWithTag) => {
+                    let name = self.type_name(hint);
+                    let variants = children.iter().skip(1).collect::<Vec<_>>();
+                    self.enum_definition(&name, &variants, false)?;
+                    name
+                }
+                _ => {
+                    let name = self.type_name(hint);
+                    self.struct_definition(&name, children)?;
+                    name
+                }
+            },
+            T::Union(variants, _) => {
+                let name = self.type_name(hint);
+                let variants = variants
+                    .iter()
+                    .map(|(_, variant)| variant)
+                    .collect::<Vec<_>>();
+                let untagged = matches!(strategy, Some(Strategy::UntaggedUnion));
+                self.enum_definition(&name, &variants, untagged)?;
+                name
+            }
+        })
+    }
+
+    fn enum_definition(&mut self, name: &str, variants: &[&Field], untagged: bool) -> Result<()> {
+        self.add_definition(|this, out| {
+            writeln!(out, "{DERIVE}")?;
+            if untagged {
+                writeln!(out, "#[serde(untagged)]")?;
+            }
+            writeln!(out, "pub enum {name} {{")?;
+            for variant in variants {
+                if matches!(
+                    get_strategy_from_metadata(&variant.metadata)?,
+                    Some(Strategy::UnknownVariant | Strategy::NullVariant)
+                ) {
+                    continue;
+                }
+
+                let ident = this.variant_ident(out, &variant.name)?;
+                let strategy = get_strategy_from_metadata(&variant.metadata)?;
+                match &variant.data_type {
+                    DataType::Null => writeln!(out, "    {ident},")?,
+                    DataType::Struct(children) if strategy.is_none() => {
+                        writeln!(out, "    {ident} {{")?;
+                        let mut fields = String::new();
+                        this.struct_fields(&mut fields, children, "")?;
+                        for line in fields.lines() {
+                            writeln!(out, "    {line}")?;
+                        }
+                        writeln!(out, "    }},")?;
+                    }
+                    _ => {
+                        let hint = format!("{name}_{}", variant.name);
+                        let ty = this.data_type(variant, &hint)?;
+                        let ty = if ty.starts_with('(') {
+                            ty
+                        } else {
+                            format!("({ty})")
+                        };
+                        writeln!(out, "    {ident}{ty},")?;
+                    }
+                }
+            }
+            writeln!(out, "}}")?;
+            Ok(())
+        })
+    }
+
+    fn one_hot_enum_definition(&mut self, name: &str, variants: &[Field]) -> Result<()> {
+        self.add_definition(|this, out| {
+            writeln!(out, "{DERIVE}")?;
+            writeln!(out, "pub enum {name} {{")?;
+            for variant in variants {
+                let ident = this.variant_ident(out, &variant.name)?;
+                writeln!(out, "    {ident},")?;
+            }
+            writeln!(out, "}}")?;
+            Ok(())
+        })
+    }
+
+    fn variant_ident(&mut self, out: &mut String, name: &str) -> Result<String> {
+        let ident = match camel_case(name) {
+            ident if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) => {
+                format!("V{ident}")
+            }
+            ident if ident == "Self" => String::from("Self_"),
+            ident => ident,
+        };
+        if ident != name {
+            writeln!(out, "    #[serde(rename = {name:?})]")?;
+        }
+        Ok(ident)
+    }
+}
+
+fn tuple_type(items: &[String]) -> String {
+    match items {
+        [item] => format!("({item},)"),
+        items => format!("({})", items.join(", ")),
+    }
+}
+
+/// Convert a field name into a snake case identifier, keywords are escaped
+fn field_ident(name: &str) -> String {
+    let mut ident = String::new();
+    let mut prev_is_lower = false;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if prev_is_lower {
+                ident.push('_');
+            }
+            ident.push(c.to_ascii_lowercase());
+            prev_is_lower = false;
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            ident.push(c);
+            prev_is_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        } else {
+            ident.push('_');
+            prev_is_lower = false;
+        }
+    }
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if ident == "_" {
+        ident.push('_');
+    }
+    if matches!(ident.as_str(), "self" | "super" | "crate") {
+        ident.push('_');
+    } else if is_keyword(&ident) {
+        ident.insert_str(0, "r#");
+    }
+    ident
+}
+
+/// Convert a name into an upper camel case identifier
+fn camel_case(name: &str) -> String {
+    let mut ident = String::new();
+    for part in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            ident.push(first.to_ascii_uppercase());
+            ident.extend(chars);
+        }
+    }
+    ident
+}
+
+fn is_reserved_type_name(name: &str) -> bool {
+    matches!(
+        name,
+        "Self" | "Option" | "Vec" | "String" | "Box" | "Result" | "Serialize" | "Deserialize"
+    )
+}
+
+fn is_keyword(ident: &str) -> bool {
+    matches!(
+        ident,
+        "as" | "async"
+            | "await"
+            | "break"
+            | "const"
+            | "continue"
+            | "dyn"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "fn"
+            | "for"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "pub"
+            | "ref"
+            | "return"
+            | "static"
+            | "struct"
+            | "trait"
+            | "true"
+            | "type"
+            | "unsafe"
+            | "use"
+            | "where"
+            | "while"
+            | "abstract"
+            | "become"
+            | "box"
+            | "do"
+            | "final"
+            | "gen"
+            | "macro"
+            | "override"
+            | "priv"
+            | "try"
+            | "typeof"
+            | "unsized"
+            | "virtual"
+            | "yield"
+    )
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use crate::internal::error::Result;
+
+/// The metadata key under which to store the JSON encoded default value of a
+/// field
+///
+/// During serialization, the default value is used for fields missing in
+/// structs (e.g., skipped with `#[serde(skip_serializing_if = "...")]`). During
+/// deserialization, null values are replaced by the default value. Default
+/// values require the `serde_json` feature.
+///
+/// ```rust
+/// # #[cfg(feature = "serde_json")]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow::datatypes::FieldRef;
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_arrow::schema::{SchemaLike, DEFAULT_VALUE_KEY};
+/// # use serde_json::json;
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(skip_serializing_if = "Option::is_none")]
+///     retries: Option<u32>,
+/// }
+///
+/// let fields = Vec::<FieldRef>::from_value(json!([{
+///     "name": "retries",
+///     "data_type": "U32",
+///     "nullable": true,
+///     "metadata": {DEFAULT_VALUE_KEY: "3"},
+/// }]))?;
+///
+/// let arrays = serde_arrow::to_arrow(&fields, &[Record { retries: None }])?;
+/// let items: Vec<Record> = serde_arrow::from_arrow(&fields, &arrays)?;
+/// assert_eq!(items, [Record { retries: Some(3) }]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "serde_json"))]
+/// # fn main() {}
+/// ```
+pub const DEFAULT_VALUE_KEY: &str = "SERDE_ARROW:default";
+
+/// The decoded default value of a field
+#[cfg(feature = "serde_json")]
+pub type DefaultValue = serde_json::Value;
+
+/// The decoded default value of a field, cannot be constructed without the
+/// `serde_json` feature
+#[cfg(not(feature = "serde_json"))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DefaultValue {}
+
+#[cfg(not(feature = "serde_json"))]
+impl serde::Serialize for DefaultValue {
+    fn serialize<S: serde::Serializer>(&self, _: S) -> std::result::Result<S::Ok, S::Error> {
+        match *self {}
+    }
+}
+
+/// Read the default value from the field metadata, if present
+pub fn get_default_value(metadata: &HashMap<String, String>) -> Result<Option<DefaultValue>> {
+    let Some(value) = metadata.get(DEFAULT_VALUE_KEY) else {
+        return Ok(None);
+    };
+    Ok(Some(parse_default_value(value)?))
+}
+
+#[cfg(feature = "serde_json")]
+fn parse_default_value(value: &str) -> Result<DefaultValue> {
+    serde_json::from_str(value).map_err(|err| {
+        crate::internal::error::Error::custom_from(
+            format!("Invalid default value {value:?}: {err}"),
+            err,
+        )
+    })
+}
+
+#[cfg(not(feature = "serde_json"))]
+fn parse_default_value(_: &str) -> Result<DefaultValue> {
+    crate::internal::error::fail!("Default values require the serde_json feature");
+}
@@ -8,8 +8,8 @@ pub use fixed_shape_tensor_field::FixedShapeTensorField;
 pub use variable_shape_tensor_field::VariableShapeTensorField;
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl AssertSendSync for Bool8Field {}
-    impl AssertSendSync for FixedShapeTensorField {}
-    impl AssertSendSync for VariableShapeTensorField {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Bool8Field>();
+    assert_send_sync::<FixedShapeTensorField>();
+    assert_send_sync::<VariableShapeTensorField>();
 };
@@ -11,25 +11,78 @@ use crate::internal::{
     chrono,
     error::{fail, try_, Context, ContextSupport, Error, Result},
     schema::{Strategy, TracingMode, TracingOptions},
+    utils::decimal,
 };
 
-use super::tracer::{
-    ListTracer, MapTracer, StructMode, StructTracer, Tracer, TupleTracer, UnionVariant,
-};
+use super::tracer::{ListTracer, StructMode, StructTracer, Tracer, TupleTracer, UnionVariant};
 
 impl Tracer {
     pub fn from_samples<T: Serialize>(samples: T, options: TracingOptions) -> Result<Self> {
+        Ok(Self::from_samples_with_count(samples, options)?.0)
+    }
+
+    /// Trace the samples and return the number of samples actually traced
+    pub fn from_samples_with_count<T: Serialize>(
+        samples: T,
+        options: TracingOptions,
+    ) -> Result<(Self, usize)> {
         let options = options.tracing_mode(TracingMode::FromSamples);
+        let mut budget = SampleBudget {
+            max_samples: options.max_samples,
+            stable_samples: options.stable_samples,
+            used: 0,
+            unchanged: 0,
+        };
         let mut tracer = Tracer::new(String::from("$"), String::from("$"), Arc::new(options));
-        samples.serialize(OuterSequenceSerializer(&mut tracer))?;
+        samples.serialize(OuterSequenceSerializer(&mut tracer, &mut budget))?;
         tracer.finish()?;
         tracer.check()?;
 
-        Ok(tracer)
+        Ok((tracer, budget.used))
+    }
+}
+
+/// Track how many samples were traced and whether tracing should stop
+struct SampleBudget {
+    max_samples: Option<usize>,
+    stable_samples: Option<usize>,
+    used: usize,
+    /// The number of consecutive samples that did not change the traced type
+    unchanged: usize,
+}
+
+impl SampleBudget {
+    fn is_exhausted(&self) -> bool {
+        matches!(self.max_samples, Some(max_samples) if self.used >= max_samples)
+            || matches!(self.stable_samples, Some(stable_samples) if self.unchanged >= stable_samples)
     }
 }
 
-struct OuterSequenceSerializer<'a>(&'a mut Tracer);
+struct OuterSequenceSerializer<'a>(&'a mut Tracer, &'a mut SampleBudget);
+
+impl<'a> OuterSequenceSerializer<'a> {
+    fn trace_sample<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        if self.1.is_exhausted() {
+            return Ok(());
+        }
+
+        let previous = match self.1.stable_samples {
+            Some(_) => self.0.to_field().ok(),
+            None => None,
+        };
+        try_(|| value.serialize(TracerSerializer(&mut *self.0))).ctx(self)?;
+        self.1.used += 1;
+
+        if self.1.stable_samples.is_some() {
+            if previous.is_some() && previous == self.0.to_field().ok() {
+                self.1.unchanged += 1;
+            } else {
+                self.1.unchanged = 0;
+            }
+        }
+        Ok(())
+    }
+}
 
 mod impl_outer_sequence_serializer {
     use super::*;
@@ -208,7 +261,7 @@ impl<'a> serde::ser::SerializeSeq for OuterSequenceSerializer<'a> {
     type Error = Error;
 
     fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
-        try_(|| value.serialize(TracerSerializer(&mut *self.0))).ctx(self)
+        self.trace_sample(value)
     }
 
     fn end(self) -> Result<Self::Ok> {
@@ -221,7 +274,7 @@ impl<'a> serde::ser::SerializeTuple for OuterSequenceSerializer<'a> {
     type Error = Error;
 
     fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
-        try_(|| value.serialize(TracerSerializer(&mut *self.0))).ctx(self)
+        self.trace_sample(value)
     }
 
     fn end(self) -> Result<Self::Ok> {
@@ -234,7 +287,7 @@ impl<'a> serde::ser::SerializeTupleVariant for OuterSequenceSerializer<'a> {
     type Error = Error;
 
     fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
-        try_(|| value.serialize(TracerSerializer(&mut *self.0))).ctx(self)
+        self.trace_sample(value)
     }
 
     fn end(self) -> Result<Self::Ok> {
@@ -335,23 +388,26 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
 
     fn serialize_str(self, s: &str) -> Result<Self::Ok> {
         try_(|| {
-            #[allow(clippy::collapsible_else_if)]
-            let (ty, st) = if !self.0.get_options().guess_dates {
-                (DataType::LargeUtf8, None)
+            let options = self.0.get_options();
+            let (ty, st) = if options.guess_dates && chrono::matches_naive_datetime(s) {
+                (DataType::Date64, Some(Strategy::NaiveStrAsDate64))
+            } else if options.guess_dates && chrono::matches_utc_datetime(s) {
+                (DataType::Date64, Some(Strategy::UtcStrAsDate64))
+            } else if options.guess_dates && chrono::matches_naive_time(s) {
+                (DataType::Time64(TimeUnit::Nanosecond), None)
+            } else if options.guess_dates && chrono::matches_naive_date(s) {
+                (DataType::Date32, None)
+            } else if let Some((precision, scale)) = guess_decimal(options, s) {
+                (DataType::Decimal128(precision, scale), None)
             } else {
-                if chrono::matches_naive_datetime(s) {
-                    (DataType::Date64, Some(Strategy::NaiveStrAsDate64))
-                } else if chrono::matches_utc_datetime(s) {
-                    (DataType::Date64, Some(Strategy::UtcStrAsDate64))
-                } else if chrono::matches_naive_time(s) {
-                    (DataType::Time64(TimeUnit::Nanosecond), None)
-                } else if chrono::matches_naive_date(s) {
-                    (DataType::Date32, None)
-                } else {
-                    (DataType::LargeUtf8, None)
-                }
+                (DataType::LargeUtf8, None)
             };
-            self.0.ensure_primitive_with_strategy(ty, st)
+            let is_string = matches!(ty, DataType::LargeUtf8);
+            self.0.ensure_primitive_with_strategy(ty, st)?;
+            if is_string {
+                self.0.record_string(s);
+            }
+            Ok(())
         })
         .ctx(&self)
     }
@@ -393,19 +449,18 @@ impl<'a> serde::ser::Serializer for TracerSerializer<'a> {
         self.annotate(&mut ctx);
 
         try_(move || {
-            if self.0.get_options().map_as_struct {
+            // maps with non-string keys are traced as maps, even with `map_as_struct`
+            if self.0.get_options().get_map_as_struct(self.0.get_path())
+                && !matches!(self.0, Tracer::Map(_))
+            {
                 self.0.ensure_struct::<&str>(&[], StructMode::Map)?;
-                let Tracer::Struct(tracer) = self.0 else {
-                    unreachable!();
-                };
-                Ok(MapSerializer::AsStruct(tracer, None))
             } else {
                 self.0.ensure_map()?;
-                let Tracer::Map(tracer) = self.0 else {
-                    unreachable!();
-                };
-                Ok(MapSerializer::AsMap(tracer))
             }
+            Ok(MapSerializer {
+                tracer: self.0,
+                next_key: None,
+            })
         })
         .ctx(&ctx)
     }
@@ -696,17 +751,15 @@ impl<'a> serde::ser::SerializeTupleVariant for TupleSerializer<'a> {
     }
 }
 
-enum MapSerializer<'a> {
-    AsStruct(&'a mut StructTracer, Option<String>),
-    AsMap(&'a mut MapTracer),
+/// Trace maps either as structs or as maps, depending on the current tracer
+struct MapSerializer<'a> {
+    tracer: &'a mut Tracer,
+    next_key: Option<String>,
 }
 
 impl<'a> Context for MapSerializer<'a> {
     fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
-        match self {
-            Self::AsStruct(tracer, _) => tracer.annotate(annotations),
-            Self::AsMap(tracer) => tracer.annotate(annotations),
-        }
+        self.tracer.annotate(annotations)
     }
 }
 
@@ -715,37 +768,50 @@ impl<'a> serde::ser::SerializeMap for MapSerializer<'a> {
     type Error = Error;
 
     fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
-        try_(|| match self {
-            Self::AsStruct(_, next_key) => {
-                *next_key = Some(key.serialize(SerializeToString)?);
-                Ok(())
+        try_(|| {
+            if let Tracer::Struct(_) = self.tracer {
+                if let Ok(key) = key.serialize(SerializeToString) {
+                    self.next_key = Some(key);
+                    return Ok(());
+                }
+                self.tracer.ensure_map_with_non_string_keys()?;
             }
-            Self::AsMap(tracer) => key.serialize(TracerSerializer(&mut tracer.key_tracer)),
+            let Tracer::Map(tracer) = &mut *self.tracer else {
+                fail!("Invalid state: map is neither traced as a struct nor as a map");
+            };
+            key.serialize(TracerSerializer(&mut tracer.key_tracer))
         })
         .ctx(self)
     }
 
     fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
-        try_(|| match self {
-            Self::AsStruct(tracer, next_key) => {
-                let Some(next_key) = next_key.take() else {
+        try_(|| match &mut *self.tracer {
+            Tracer::Struct(tracer) => {
+                let Some(next_key) = self.next_key.take() else {
                     fail!("Invalid call to serialization methods: serialize_value called without prior call to serialize_key");
                 };
+                if tracer.is_catch_all_key(&next_key) {
+                    let Some(map_tracer) = tracer.ensure_catch_all()? else {
+                        unreachable!();
+                    };
+                    return value.serialize(TracerSerializer(&mut map_tracer.value_tracer));
+                }
                 let field_idx = tracer.ensure_field(&next_key)?;
                 let Some(field_tracer) = tracer.get_field_tracer_mut(field_idx) else {
                     unreachable!();
                 };
                 value.serialize(TracerSerializer(field_tracer))
             }
-            Self::AsMap(tracer) => value.serialize(TracerSerializer(&mut tracer.value_tracer)),
+            Tracer::Map(tracer) => value.serialize(TracerSerializer(&mut tracer.value_tracer)),
+            _ => fail!("Invalid state: map is neither traced as a struct nor as a map"),
         })
         .ctx(self)
     }
 
     fn end(mut self) -> Result<Self::Ok> {
-        try_(|| match &mut self {
-            Self::AsStruct(tracer, _) => tracer.end(),
-            Self::AsMap(_) => Ok(()),
+        try_(|| match &mut self.tracer {
+            Tracer::Struct(tracer) => tracer.end(),
+            _ => Ok(()),
         })
         .ctx(&self)
     }
@@ -811,6 +877,17 @@ mod impl_serialize_to_string {
     }
 }
 
+fn guess_decimal(options: &TracingOptions, s: &str) -> Option<(u8, i8)> {
+    if !options.guess_decimals {
+        return None;
+    }
+    let (precision, scale) = decimal::infer_precision_and_scale(s)?;
+    if precision > options.max_decimal_precision {
+        return None;
+    }
+    Some((precision, scale))
+}
+
 #[cfg(test)]
 mod test {
     use serde::Serialize;
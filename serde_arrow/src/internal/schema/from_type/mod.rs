@@ -13,6 +13,7 @@ use crate::internal::{
     arrow::DataType,
     error::{fail, try_, Context, ContextSupport, Error, Result},
     schema::{TracingMode, TracingOptions},
+    utils::expects_non_zero,
 };
 
 use super::tracer::{StructField, StructMode, Tracer};
@@ -68,6 +69,11 @@ impl Tracer {
 }
 
 // check for known error messages of non self describing types
+/// The integer to visit during tracing, non-zero integer types reject `0`
+fn sample_integer<'de, T: From<bool>, V: Visitor<'de>>(visitor: &V) -> T {
+    T::from(expects_non_zero(visitor))
+}
+
 fn is_non_self_describing_error(s: &str) -> bool {
     // chrono::*
     s.contains("premature end of input")
@@ -110,7 +116,8 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::Int8)?;
-            visitor.visit_i8(Default::default())
+            let value = sample_integer(&visitor);
+            visitor.visit_i8(value)
         })
         .ctx(&self)
     }
@@ -118,7 +125,8 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::Int16)?;
-            visitor.visit_i16(Default::default())
+            let value = sample_integer(&visitor);
+            visitor.visit_i16(value)
         })
         .ctx(&self)
     }
@@ -126,7 +134,8 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::Int32)?;
-            visitor.visit_i32(Default::default())
+            let value = sample_integer(&visitor);
+            visitor.visit_i32(value)
         })
         .ctx(&self)
     }
@@ -134,7 +143,8 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::Int64)?;
-            visitor.visit_i64(Default::default())
+            let value = sample_integer(&visitor);
+            visitor.visit_i64(value)
         })
         .ctx(&self)
     }
@@ -142,7 +152,8 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::UInt8)?;
-            visitor.visit_u8(Default::default())
+            let value = sample_integer(&visitor);
+            visitor.visit_u8(value)
         })
         .ctx(&self)
     }
@@ -150,7 +161,8 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::UInt16)?;
-            visitor.visit_u16(Default::default())
+            let value = sample_integer(&visitor);
+            visitor.visit_u16(value)
         })
         .ctx(&self)
     }
@@ -158,7 +170,8 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::UInt32)?;
-            visitor.visit_u32(Default::default())
+            let value = sample_integer(&visitor);
+            visitor.visit_u32(value)
         })
         .ctx(&self)
     }
@@ -166,7 +179,8 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
     fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
             self.0.ensure_primitive(DataType::UInt64)?;
-            visitor.visit_u64(Default::default())
+            let value = sample_integer(&visitor);
+            visitor.visit_u64(value)
         })
         .ctx(&self)
     }
@@ -301,7 +315,7 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
 
     fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         try_(|| {
-            if self.0.get_options().map_as_struct {
+            if self.0.get_options().get_map_as_struct(self.0.get_path()) {
                 fail!(concat!(
                     "Cannot trace maps as structs with `from_type`. ",
                     "The struct fields cannot be known from the type alone.",
@@ -448,9 +462,12 @@ impl<'de, 'a> serde::de::MapAccess<'de> for TraceStruct<'a> {
         if self.pos >= self.names.len() {
             return Ok(None);
         }
+        let name = self.names[self.pos];
         let key = seed.deserialize(IdentifierDeserializer {
             idx: self.pos,
-            name: self.names[self.pos],
+            name,
+            // duplicate names cannot be resolved by name, use the index instead
+            by_index: self.names[..self.pos].contains(&name),
         })?;
         Ok(Some(key))
     }
@@ -477,6 +494,7 @@ impl<'de, 'a> serde::de::EnumAccess<'de> for TraceEnum<'a> {
         let variant = seed.deserialize(IdentifierDeserializer {
             idx: self.pos,
             name: self.variant,
+            by_index: false,
         })?;
         Ok((variant, TraceAny(self.tracer)))
     }
@@ -525,6 +543,7 @@ impl<'de, 'a> serde::de::SeqAccess<'de> for TraceSeq<'a> {
 struct IdentifierDeserializer<'a> {
     idx: usize,
     name: &'a str,
+    by_index: bool,
 }
 
 macro_rules! unimplemented {
@@ -539,11 +558,15 @@ impl<'de, 'a> serde::de::Deserializer<'de> for IdentifierDeserializer<'a> {
     type Error = Error;
 
     fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        self.deserialize_str(visitor)
+        if self.by_index {
+            self.deserialize_u64(visitor)
+        } else {
+            self.deserialize_str(visitor)
+        }
     }
 
     fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
-        self.deserialize_str(visitor)
+        self.deserialize_identifier(visitor)
     }
 
     fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
@@ -0,0 +1,316 @@
+//! Export schemas as JSON schemas (draft-07) describing the row type
+use std::fmt::Write;
+
+use crate::internal::{
+    arrow::{DataType, Field, IntervalUnit},
+    error::Result,
+};
+
+use super::{get_strategy_from_metadata, Strategy};
+
+const DRAFT_07: &str = "http://json-schema.org/draft-07/schema#";
+
+/// A minimal JSON document with ordered object keys
+enum Json {
+    Bool(bool),
+    Int(i64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn str(value: &str) -> Self {
+        Self::Str(value.to_owned())
+    }
+
+    fn type_(name: &str) -> Self {
+        Self::Object(vec![(String::from("type"), Self::str(name))])
+    }
+
+    fn push(&mut self, key: &str, value: Json) {
+        if let Self::Object(entries) = self {
+            entries.push((key.to_owned(), value));
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        match self {
+            Self::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Self::Int(value) => {
+                let _ = write!(out, "{value}");
+            }
+            Self::Str(value) => write_str(out, value),
+            Self::Array(items) => {
+                out.push('[');
+                for (idx, item) in items.iter().enumerate() {
+                    if idx != 0 {
+                        out.push(',');
+                    }
+                    item.write(out);
+                }
+                out.push(']');
+            }
+            Self::Object(entries) => {
+                out.push('{');
+                for (idx, (key, value)) in entries.iter().enumerate() {
+                    if idx != 0 {
+                        out.push(',');
+                    }
+                    write_str(out, key);
+                    out.push(':');
+                    value.write(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn write_str(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+pub fn to_json_schema(fields: &[Field]) -> Result<String> {
+    let mut schema = Json::Object(vec![(String::from("$schema"), Json::str(DRAFT_07))]);
+    if let (Json::Object(entries), Json::Object(row_entries)) =
+        (&mut schema, object_schema(fields)?)
+    {
+        entries.extend(row_entries);
+    }
+
+    let mut out = String::new();
+    schema.write(&mut out);
+    Ok(out)
+}
+
+fn object_schema(fields: &[Field]) -> Result<Json> {
+    let mut properties = Vec::new();
+    let mut required = Vec::new();
+    let mut additional_properties = Json::Bool(false);
+    for field in fields {
+        // the entries of catch-all maps are properties of the parent
+        if get_strategy_from_metadata(&field.metadata)? == Some(Strategy::CatchAll) {
+            if let Json::Object(mut entries) = data_type_schema(field)? {
+                if let Some(pos) = entries
+                    .iter()
+                    .position(|(key, _)| key == "additionalProperties")
+                {
+                    additional_properties = entries.swap_remove(pos).1;
+                }
+            }
+            continue;
+        }
+        properties.push((field.name.clone(), field_schema(field)?));
+        required.push(Json::str(&field.name));
+    }
+
+    let mut schema = Json::type_("object");
+    schema.push("properties", Json::Object(properties));
+    schema.push("required", Json::Array(required));
+    schema.push("additionalProperties", additional_properties);
+    Ok(schema)
+}
+
+fn field_schema(field: &Field) -> Result<Json> {
+    let mut schema = data_type_schema(field)?;
+    if !field.nullable || matches!(field.data_type, DataType::Null) {
+        return Ok(schema);
+    }
+
+    // add `"null"` to the type if possible, otherwise allow it via `anyOf`
+    if let Json::Object(entries) = &mut schema {
+        if let Some((_, ty)) = entries.iter_mut().find(|(key, _)| key == "type") {
+            let prev = std::mem::replace(ty, Json::Array(Vec::new()));
+            *ty = Json::Array(vec![prev, Json::str("null")]);
+            return Ok(schema);
+        }
+        if entries.is_empty() {
+            return Ok(schema);
+        }
+    }
+    Ok(Json::Object(vec![(
+        String::from("anyOf"),
+        Json::Array(vec![schema, Json::type_("null")]),
+    )]))
+}
+
+fn data_type_schema(field: &Field) -> Result<Json> {
+    use DataType as T;
+
+    let strategy = get_strategy_from_metadata(&field.metadata)?;
+    if matches!(strategy, Some(Strategy::InconsistentTypes)) {
+        return Ok(Json::Object(Vec::new()));
+    }
+
+    Ok(match &field.data_type {
+        T::Int8 | T::UInt8 if matches!(strategy, Some(Strategy::Quantized { .. })) => {
+            Json::type_("number")
+        }
+        T::Null => Json::type_("null"),
+        T::Boolean => Json::type_("boolean"),
+        T::Int8 | T::Int16 | T::Int32 | T::Int64 => Json::type_("integer"),
+        T::UInt8 | T::UInt16 | T::UInt32 | T::UInt64 => {
+            let mut schema = Json::type_("integer");
+            schema.push("minimum", Json::Int(0));
+            schema
+        }
+        T::Float16 | T::Float32 | T::Float64 => Json::type_("number"),
+        T::Utf8 | T::LargeUtf8 | T::Utf8View | T::Time32(_) | T::Time64(_) | T::Duration(_) => {
+            Json::type_("string")
+        }
+        T::Date32 => {
+            let mut schema = Json::type_("string");
+            schema.push("format", Json::str("date"));
+            schema
+        }
+        T::Date64 | T::Timestamp(_, _) => {
+            let mut schema = Json::type_("string");
+            let is_utc = match &field.data_type {
+                T::Timestamp(_, Some(tz)) => tz.eq_ignore_ascii_case("utc"),
+                _ => matches!(strategy, Some(Strategy::UtcStrAsDate64)),
+            };
+            if is_utc {
+                schema.push("format", Json::str("date-time"));
+            }
+            schema
+        }
+        T::Decimal128(_, _) | T::Decimal256(_, _) => {
+            if matches!(strategy, Some(Strategy::ScaledInt { .. })) {
+                Json::type_("integer")
+            } else {
+                Json::type_("string")
+            }
+        }
+        T::Interval(IntervalUnit::YearMonth) => Json::type_("integer"),
+        T::Interval(unit) => {
+            let len = match unit {
+                IntervalUnit::DayTime => 2,
+                _ => 3,
+            };
+            let mut schema = Json::type_("array");
+            schema.push("items", Json::type_("integer"));
+            schema.push("minItems", Json::Int(len));
+            schema.push("maxItems", Json::Int(len));
+            schema
+        }
+        T::Binary | T::LargeBinary | T::BinaryView => bytes_schema(None),
+        T::FixedSizeBinary(n) => bytes_schema(Some(*n)),
+        T::Struct(children) => match strategy {
+            Some(Strategy::TupleAsStruct) => {
+                let mut items = Vec::new();
+                for child in children {
+                    items.push(field_schema(child)?);
+                }
+                let len = i64::try_from(children.len())?;
+                let mut schema = Json::type_("array");
+                schema.push("items", Json::Array(items));
+                schema.push("minItems", Json::Int(len));
+                schema.push("maxItems", Json::Int(len));
+                schema
+            }
+            _ => object_schema(children)?,
+        },
+        T::List(item) | T::LargeList(item) | T::ListView(item) | T::LargeListView(item) => {
+            let mut schema = Json::type_("array");
+            schema.push("items", field_schema(item)?);
+            schema
+        }
+        T::FixedSizeList(item, n) => {
+            let mut schema = Json::type_("array");
+            schema.push("items", field_schema(item)?);
+            schema.push("minItems", Json::Int((*n).into()));
+            schema.push("maxItems", Json::Int((*n).into()));
+            schema
+        }
+        T::Map(entries, _) => {
+            let mut schema = Json::type_("object");
+            if let T::Struct(children) = &entries.data_type {
+                if let Some(value) = children.get(1) {
+                    schema.push("additionalProperties", field_schema(value)?);
+                }
+            }
+            schema
+        }
+        T::Dictionary(_, value, _) => data_type_schema(&Field {
+            name: field.name.clone(),
+            data_type: value.as_ref().clone(),
+            nullable: false,
+            metadata: field.metadata.clone(),
+        })?,
+        T::Union(variants, _) if matches!(strategy, Some(Strategy::UntaggedUnion)) => {
+            let mut any_of = Vec::new();
+            for (_, variant) in variants {
+                if matches!(
+                    get_strategy_from_metadata(&variant.metadata)?,
+                    Some(Strategy::UnknownVariant | Strategy::NullVariant)
+                ) {
+                    continue;
+                }
+                any_of.push(data_type_schema(variant)?);
+            }
+            Json::Object(vec![(String::from("anyOf"), Json::Array(any_of))])
+        }
+        T::Union(variants, _) => {
+            let mut one_of = Vec::new();
+            for (_, variant) in variants {
+                if matches!(
+                    get_strategy_from_metadata(&variant.metadata)?,
+                    Some(Strategy::UnknownVariant)
+                ) {
+                    continue;
+                }
+                one_of.push(variant_schema(variant)?);
+            }
+            Json::Object(vec![(String::from("oneOf"), Json::Array(one_of))])
+        }
+    })
+}
+
+/// Enums are externally tagged: unit variants are strings, all other
+/// variants single-key objects
+fn variant_schema(variant: &Field) -> Result<Json> {
+    if matches!(variant.data_type, DataType::Null) {
+        return Ok(Json::Object(vec![(
+            String::from("const"),
+            Json::str(&variant.name),
+        )]));
+    }
+
+    let mut schema = Json::type_("object");
+    schema.push(
+        "properties",
+        Json::Object(vec![(variant.name.clone(), field_schema(variant)?)]),
+    );
+    schema.push("required", Json::Array(vec![Json::str(&variant.name)]));
+    schema.push("additionalProperties", Json::Bool(false));
+    Ok(schema)
+}
+
+fn bytes_schema(len: Option<i32>) -> Json {
+    let mut item = Json::type_("integer");
+    item.push("minimum", Json::Int(0));
+    item.push("maximum", Json::Int(255));
+
+    let mut schema = Json::type_("array");
+    schema.push("items", item);
+    if let Some(len) = len {
+        schema.push("minItems", Json::Int(len.into()));
+        schema.push("maxItems", Json::Int(len.into()));
+    }
+    schema
+}
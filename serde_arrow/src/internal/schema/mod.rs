@@ -1,6 +1,12 @@
+pub mod codegen;
+mod default_value;
 pub mod extensions;
 mod from_samples;
 mod from_type;
+mod json_schema;
+mod overrides;
+#[cfg(has_arrow)]
+mod recover;
 mod serde;
 mod strategy;
 pub mod tracer;
@@ -12,15 +18,28 @@ mod test;
 use crate::internal::{
     arrow::{TimeUnit, UnionMode},
     error::{fail, Result},
-    utils::value,
+    utils::{quantization::Quantization, value},
 };
 
 use ::serde::{Deserialize, Serialize};
 
+pub use default_value::{get_default_value, DefaultValue, DEFAULT_VALUE_KEY};
+pub use overrides::{
+    apply_strategy_overrides, apply_strategy_overrides_to_view, restore_nested_metadata,
+};
+#[cfg(has_arrow)]
+pub use recover::recover_strategies;
 pub use serde::serialize::PrettyField;
-pub use strategy::{get_strategy_from_metadata, Strategy, STRATEGY_KEY};
+pub use strategy::{
+    get_strategy_from_metadata, set_strategy_in_metadata, strategy_key, MetadataStrategy, Strategy,
+    STRATEGY_KEY,
+};
 use tracer::Tracer;
-pub use tracing_options::{Overwrites, TracingMode, TracingOptions};
+pub(crate) use tracing_options::ListNullability;
+pub use tracing_options::{
+    DuplicateFieldPolicy, ListType, MixedTypePolicy, Overwrites, SecondsType, StringType,
+    TracingMode, TracingOptions,
+};
 
 use super::arrow::{DataType, Field};
 
@@ -113,8 +132,10 @@ pub trait SchemaLike: Sized + Sealed {
     /// - signed integers: `"I8"`, `"I16"`, `"I32"`, `"I64"`
     /// - unsigned integers: `"U8"`, `"U16"`, `"U32"`, `"U64"`
     /// - floats: `"F16"`, `"F32"`, `"F64"`
-    /// - strings: `"Utf8"`, `"LargeUtf8"`
-    /// - decimals: `"Decimal128(precision, scale)"`, as in `"Decimal128(5, 2)"`
+    /// - strings: `"Utf8"`, `"LargeUtf8"`, `"Utf8View"`
+    /// - binary data: `"Binary"`, `"LargeBinary"`, `"BinaryView"`
+    /// - decimals: `"Decimal128(precision, scale)"`, as in `"Decimal128(5, 2)"`,
+    ///   and `"Decimal256(precision, scale)"` with at most 38 and 76 digits
     /// - date objects: `"Date32"`
     /// - date time objects: , `"Date64"`, `"Timestamp(unit, timezone)"` with
     ///   unit being one of `Second`, `Millisecond`, `Microsecond`,
@@ -123,8 +144,11 @@ pub trait SchemaLike: Sized + Sealed {
     ///   of `Second`, `Millisecond`, `Microsecond`, `Nanosecond`.
     /// - durations: `"Duration(unit)"` with unit being one of `Second`,
     ///   `Millisecond`, `Microsecond`, `Nanosecond`.
-    /// - lists: `"List"`, `"LargeList"`. `"children"` must contain a single
-    ///   field named `"element"` that describes the element type
+    /// - intervals: `"Interval(unit)"` with unit being one of `YearMonth`,
+    ///   `DayTime`, `MonthDayNano`.
+    /// - lists: `"List"`, `"LargeList"`, `"ListView"`, `"LargeListView"`.
+    ///   `"children"` must contain a single field named `"element"` that
+    ///   describes the element type
     /// - structs: `"Struct"`. `"children"` must contain the child fields
     /// - maps: `"Map"`. `"children"` must contain two fields, named `"key"` and
     ///   `"value"` that encode the key and value types
@@ -299,6 +323,119 @@ impl SchemaLike for SerdeArrowSchema {
     }
 }
 
+impl SerdeArrowSchema {
+    /// Determine the schema from samples and return the number of traced
+    /// samples
+    ///
+    /// This function behaves as
+    /// [`from_samples`][SchemaLike::from_samples], but also reports how many
+    /// samples were actually traced. With
+    /// [`max_samples`][TracingOptions::max_samples] or
+    /// [`stable_samples`][TracingOptions::stable_samples], tracing may stop
+    /// before all samples are consumed.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_arrow::{schema::{SerdeArrowSchema, TracingOptions}, utils::Items};
+    ///
+    /// let samples = Items((0..1000_u32).collect::<Vec<_>>());
+    /// let (_schema, num_samples) = SerdeArrowSchema::from_samples_with_count(
+    ///     &samples,
+    ///     TracingOptions::default().stable_samples(Some(10)),
+    /// )?;
+    /// assert_eq!(num_samples, 11);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_samples_with_count<T: Serialize>(
+        samples: T,
+        options: TracingOptions,
+    ) -> Result<(Self, usize)> {
+        let (tracer, num_samples) = Tracer::from_samples_with_count(samples, options)?;
+        Ok((tracer.to_schema()?, num_samples))
+    }
+
+    /// Determine a stable schema from samples of maps with heterogeneous keys
+    ///
+    /// The samples are traced as with [`from_samples`][SchemaLike::from_samples],
+    /// e.g., from a `Vec<HashMap<String, T>>`. The resulting fields are the
+    /// union of all keys, sorted by name and nullable. In contrast to
+    /// `from_samples`, the order of the fields does not depend on the
+    /// iteration order of the maps and the schema accepts records missing
+    /// any of the keys. Records can then be serialized directly without any
+    /// wrapper types.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use std::collections::HashMap;
+    /// # use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    ///
+    /// let samples = vec![
+    ///     HashMap::from([("b", 1_i64), ("a", 2)]),
+    ///     HashMap::from([("c", 3_i64)]),
+    /// ];
+    /// let schema = SerdeArrowSchema::from_map_samples(&samples, TracingOptions::default())?;
+    ///
+    /// let expected = SerdeArrowSchema::from_value(json!([
+    ///     {"name": "a", "data_type": "I64", "nullable": true},
+    ///     {"name": "b", "data_type": "I64", "nullable": true},
+    ///     {"name": "c", "data_type": "I64", "nullable": true},
+    /// ]))?;
+    /// assert_eq!(schema, expected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_map_samples<T: Serialize>(samples: T, options: TracingOptions) -> Result<Self> {
+        let mut schema = Self::from_samples(samples, options)?;
+        schema.fields.sort_by(|a, b| a.name.cmp(&b.name));
+        for field in &mut schema.fields {
+            field.nullable = true;
+        }
+        Ok(schema)
+    }
+
+    /// Generate a JSON schema (draft-07) describing the rows of this schema
+    ///
+    /// The JSON schema describes each row as an object with one property per
+    /// field, matching the values produced by deserializing arrays of this
+    /// schema into self-describing formats. Nullable fields also accept
+    /// `null`, structs are nested objects, lists are arrays, and enums are
+    /// externally tagged. Decimals and temporal values are described as
+    /// strings. The result is a compact JSON document.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use serde_json::json;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema};
+    ///
+    /// let schema = SerdeArrowSchema::from_value(json!([
+    ///     {"name": "id", "data_type": "U32"},
+    ///     {"name": "name", "data_type": "LargeUtf8", "nullable": true},
+    /// ]))?;
+    /// let json_schema = schema.to_json_schema()?;
+    ///
+    /// assert_eq!(
+    ///     serde_json::from_str::<serde_json::Value>(&json_schema).unwrap(),
+    ///     json!({
+    ///         "$schema": "http://json-schema.org/draft-07/schema#",
+    ///         "type": "object",
+    ///         "properties": {
+    ///             "id": {"type": "integer", "minimum": 0},
+    ///             "name": {"type": ["string", "null"]},
+    ///         },
+    ///         "required": ["id", "name"],
+    ///         "additionalProperties": false,
+    ///     }),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_json_schema(&self) -> Result<String> {
+        json_schema::to_json_schema(&self.fields)
+    }
+}
+
 impl Sealed for Vec<Field> {}
 
 impl SchemaLike for Vec<Field> {
@@ -329,26 +466,28 @@ pub fn transmute_field(field: impl Serialize) -> Result<Field> {
 pub fn validate_field(field: &Field) -> Result<()> {
     match &field.data_type {
         DataType::Null => validate_null_field(field),
+        DataType::UInt8 | DataType::Int8 => validate_int8_field(field),
         DataType::Boolean
-        | DataType::UInt8
         | DataType::UInt16
         | DataType::UInt32
-        | DataType::UInt64
-        | DataType::Int8
         | DataType::Int16
         | DataType::Int32
         | DataType::Int64
         | DataType::Float16
         | DataType::Float32
         | DataType::Float64
-        | DataType::Utf8
-        | DataType::LargeUtf8
-        | DataType::Decimal128(_, _)
-        | DataType::Date32
         | DataType::Binary
         | DataType::LargeBinary
-        | DataType::Duration(_) => validate_primitive_field(field),
+        | DataType::BinaryView
+        | DataType::Interval(_) => validate_primitive_field(field),
+        DataType::Duration(_) => validate_duration_field(field),
+        DataType::UInt64 => validate_uint64_field(field),
+        DataType::Utf8 | DataType::LargeUtf8 => validate_utf8_field(field),
+        DataType::Utf8View => validate_utf8_view_field(field),
+        DataType::Decimal128(precision, _) => validate_decimal_field(field, *precision, 38),
+        DataType::Decimal256(precision, _) => validate_decimal_field(field, *precision, 76),
         DataType::FixedSizeBinary(n) => validate_fixed_size_binary_field(field, *n),
+        DataType::Date32 => validate_date32_field(field),
         DataType::Date64 => validate_date64_field(field),
         DataType::Timestamp(unit, tz) => validate_timestamp_field(field, *unit, tz.as_deref()),
         DataType::Time32(unit) => validate_time32_field(field, *unit),
@@ -357,6 +496,8 @@ pub fn validate_field(field: &Field) -> Result<()> {
         DataType::Map(entry, _) => validate_map_field(field, entry.as_ref()),
         DataType::List(entry) => validate_list_field(field, entry.as_ref()),
         DataType::LargeList(entry) => validate_list_field(field, entry.as_ref()),
+        DataType::ListView(entry) => validate_list_field(field, entry.as_ref()),
+        DataType::LargeListView(entry) => validate_list_field(field, entry.as_ref()),
         DataType::FixedSizeList(entry, n) => {
             validate_fixed_size_list_field(field, entry.as_ref(), *n)
         }
@@ -369,7 +510,10 @@ pub fn validate_field(field: &Field) -> Result<()> {
 
 fn validate_null_field(field: &Field) -> Result<()> {
     match get_strategy_from_metadata(&field.metadata)? {
-        None | Some(Strategy::InconsistentTypes) | Some(Strategy::UnknownVariant) => Ok(()),
+        None
+        | Some(Strategy::InconsistentTypes)
+        | Some(Strategy::UnknownVariant)
+        | Some(Strategy::NullVariant) => Ok(()),
         Some(strategy) => fail!("invalid strategy for Null field: {strategy}"),
     }
 }
@@ -384,11 +528,25 @@ fn validate_primitive_field(field: &Field) -> Result<()> {
     Ok(())
 }
 
+fn validate_duration_field(field: &Field) -> Result<()> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::Seconds) => Ok(()),
+        Some(strategy) => fail!(
+            "invalid strategy for {data_type}: {strategy}",
+            data_type = DataTypeDisplay(&field.data_type),
+        ),
+    }
+}
+
 fn validate_fixed_size_binary_field(field: &Field, n: i32) -> Result<()> {
     if n < 0 {
         fail!("Invalid FixedSizedBinary with negative number of elements");
     }
-    validate_primitive_field(field)
+    match get_strategy_from_metadata(&field.metadata)? {
+        None => Ok(()),
+        Some(Strategy::RowHash) if n == 8 => Ok(()),
+        Some(strategy) => fail!("invalid strategy for FixedSizeBinary({n}) field: {strategy}"),
+    }
 }
 
 fn validate_fixed_size_list_field(field: &Field, child: &Field, n: i32) -> Result<()> {
@@ -434,6 +592,70 @@ fn validate_dictionary_field(field: &Field, key: &DataType, value: &DataType) ->
     Ok(())
 }
 
+fn validate_utf8_field(field: &Field) -> Result<()> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::Stringify | Strategy::AdaptiveString) => Ok(()),
+        Some(strategy) => fail!(
+            "invalid strategy for {data_type}: {strategy}",
+            data_type = DataTypeDisplay(&field.data_type),
+        ),
+    }
+}
+
+fn validate_utf8_view_field(field: &Field) -> Result<()> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::AdaptiveString) => Ok(()),
+        Some(strategy) => fail!(
+            "invalid strategy for {data_type}: {strategy}",
+            data_type = DataTypeDisplay(&field.data_type),
+        ),
+    }
+}
+
+fn validate_int8_field(field: &Field) -> Result<()> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        None => Ok(()),
+        Some(Strategy::Quantized { scale, zero_point }) => {
+            Quantization::new(scale, zero_point, &field.data_type)?;
+            Ok(())
+        }
+        Some(strategy) => fail!(
+            "invalid strategy for {data_type}: {strategy}",
+            data_type = DataTypeDisplay(&field.data_type),
+        ),
+    }
+}
+
+fn validate_uint64_field(field: &Field) -> Result<()> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::StringHash { .. } | Strategy::RowHash) => Ok(()),
+        Some(strategy) => fail!("invalid strategy for UInt64 field: {strategy}"),
+    }
+}
+
+fn validate_decimal_field(field: &Field, precision: u8, max_precision: u8) -> Result<()> {
+    if precision > max_precision {
+        fail!(
+            "invalid precision for {data_type}: at most {max_precision} digits are supported",
+            data_type = DataTypeDisplay(&field.data_type),
+        );
+    }
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::ScaledInt { .. }) => Ok(()),
+        Some(strategy) => fail!(
+            "invalid strategy for {data_type}: {strategy}",
+            data_type = DataTypeDisplay(&field.data_type),
+        ),
+    }
+}
+
+fn validate_date32_field(field: &Field) -> Result<()> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::TimeCrateStr) => Ok(()),
+        Some(strategy) => fail!("invalid strategy for Date32 field: {strategy}"),
+    }
+}
+
 fn validate_date64_field(field: &Field) -> Result<()> {
     match get_strategy_from_metadata(&field.metadata)? {
         None | Some(Strategy::UtcStrAsDate64) | Some(Strategy::NaiveStrAsDate64) => Ok(()),
@@ -444,24 +666,20 @@ fn validate_date64_field(field: &Field) -> Result<()> {
 fn validate_timestamp_field(field: &Field, unit: TimeUnit, tz: Option<&str>) -> Result<()> {
     match get_strategy_from_metadata(&field.metadata)? {
         None => {}
-        Some(strategy @ Strategy::UtcStrAsDate64) => {
-            if !matches!(tz, Some(tz) if tz.to_uppercase() == "UTC") {
-                fail!("invalid strategy for Timestamp({unit}, {tz:?}) field: {strategy}");
-            }
-        }
-        Some(strategy @ Strategy::NaiveStrAsDate64) => {
-            if tz.is_some() {
-                fail!("invalid strategy for Timestamp({unit}, {tz:?}) field: {strategy}");
-            }
-        }
+        Some(Strategy::UtcStrAsDate64) if matches!(tz, Some(tz) if tz.to_uppercase() == "UTC") => {}
+        Some(Strategy::NaiveStrAsDate64) if tz.is_none() => {}
+        Some(Strategy::TimeCrateStr) if tz.is_none() => {}
+        Some(Strategy::TimeCrateStr) if matches!(tz, Some(tz) if tz.to_uppercase() == "UTC") => {}
+        Some(Strategy::Seconds) => {}
         Some(strategy) => fail!("invalid strategy for Timestamp({unit}, {tz:?}) field: {strategy}"),
     }
     Ok(())
 }
 
 fn validate_time32_field(field: &Field, unit: TimeUnit) -> Result<()> {
-    if let Some(strategy) = get_strategy_from_metadata(&field.metadata)? {
-        fail!("invalid strategy for Time32({unit}) field: {strategy}");
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::TimeCrateStr) => {}
+        Some(strategy) => fail!("invalid strategy for Time32({unit}) field: {strategy}"),
     }
     if !matches!(unit, TimeUnit::Second | TimeUnit::Millisecond) {
         fail!("Time32 field must have Second or Millisecond unit");
@@ -470,8 +688,9 @@ fn validate_time32_field(field: &Field, unit: TimeUnit) -> Result<()> {
 }
 
 fn validate_time64_field(field: &Field, unit: TimeUnit) -> Result<()> {
-    if let Some(strategy) = get_strategy_from_metadata(&field.metadata)? {
-        fail!("invalid strategy for Time64({unit}) field: {strategy}");
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::TimeCrateStr) => {}
+        Some(strategy) => fail!("invalid strategy for Time64({unit}) field: {strategy}"),
     }
     if !matches!(unit, TimeUnit::Microsecond | TimeUnit::Nanosecond) {
         fail!("Time64 field must have Microsecond or Nanosecond unit");
@@ -483,36 +702,121 @@ fn validate_struct_field(field: &Field, children: &[Field]) -> Result<()> {
     // NOTE: do not check number of children: arrow-rs can 0 children, arrow2 not
     match get_strategy_from_metadata(&field.metadata)? {
         None | Some(Strategy::MapAsStruct) | Some(Strategy::TupleAsStruct) => {}
+        Some(Strategy::EnumAsStructWithTag) => validate_enum_tag(field, children.first())?,
+        Some(Strategy::EnumOneHot) => validate_enum_one_hot(field, children)?,
+        Some(Strategy::DictionaryOverflow { .. }) => validate_dictionary_overflow(field, children)?,
         Some(strategy) => fail!("invalid strategy for Struct field: {strategy}"),
     }
+    let mut num_catch_alls = 0;
     for child in children {
         validate_field(child)?;
+        if get_strategy_from_metadata(&child.metadata)? == Some(Strategy::CatchAll) {
+            num_catch_alls += 1;
+        }
+    }
+    if num_catch_alls > 1 {
+        fail!(
+            "Struct field {name} has more than one CatchAll child",
+            name = field.name
+        );
     }
     Ok(())
 }
 
-fn validate_map_field(field: &Field, _entry: &Field) -> Result<()> {
-    if let Some(strategy) = get_strategy_from_metadata(&field.metadata)? {
-        fail!("invalid strategy for Map field: {strategy}");
+fn validate_enum_tag(field: &Field, tag: Option<&Field>) -> Result<()> {
+    let is_string =
+        |data_type: &DataType| matches!(data_type, DataType::Utf8 | DataType::LargeUtf8);
+    let is_valid = match tag.map(|tag| &tag.data_type) {
+        Some(DataType::Dictionary(_, value, _)) => is_string(value),
+        Some(data_type) => is_string(data_type),
+        None => false,
+    };
+    if !is_valid {
+        fail!(
+            "EnumAsStructWithTag field {name} must have a string or dictionary tag as its first child",
+            name = field.name,
+        );
     }
-    let DataType::Map(entry, _) = &field.data_type else {
-        fail!("Invalid data type for map child, expected a map");
+    Ok(())
+}
+
+fn validate_enum_one_hot(field: &Field, children: &[Field]) -> Result<()> {
+    for child in children {
+        if child.data_type != DataType::Boolean {
+            fail!(
+                "EnumOneHot field {name} must only have Boolean children, {child} is {data_type}",
+                name = field.name,
+                child = child.name,
+                data_type = DataTypeDisplay(&child.data_type),
+            );
+        }
+    }
+    Ok(())
+}
+
+fn validate_dictionary_overflow(field: &Field, children: &[Field]) -> Result<()> {
+    let is_string =
+        |data_type: &DataType| matches!(data_type, DataType::Utf8 | DataType::LargeUtf8);
+    let is_valid = match children {
+        [values, overflow] => {
+            values.nullable
+                && overflow.nullable
+                && matches!(&values.data_type, DataType::Dictionary(_, value, _) if is_string(value))
+                && is_string(&overflow.data_type)
+        }
+        _ => false,
     };
+    if !is_valid {
+        fail!(
+            "DictionaryOverflow field {name} must have a nullable string dictionary and a nullable string overflow field as its children",
+            name = field.name,
+        );
+    }
+    Ok(())
+}
+
+fn validate_map_field(field: &Field, entry: &Field) -> Result<()> {
+    let strategy = get_strategy_from_metadata(&field.metadata)?;
+    if let Some(strategy) = strategy.as_ref().filter(|s| **s != Strategy::CatchAll) {
+        fail!("invalid strategy for Map field: {strategy}");
+    }
     let DataType::Struct(entry_fields) = &entry.data_type else {
         fail!("Invalid child data type for map, expected struct with 2 fields");
     };
     if entry_fields.len() != 2 {
         fail!("Invalid child data type for map, expected struct with 2 fields");
     }
-    Ok(())
+    if entry_fields[0].data_type == DataType::Null {
+        fail!("Invalid key data type for map: keys must not be of type Null");
+    }
+    if strategy.is_some()
+        && !matches!(
+            entry_fields[0].data_type,
+            DataType::Utf8 | DataType::LargeUtf8
+        )
+    {
+        fail!("CatchAll Map fields must have Utf8 or LargeUtf8 keys");
+    }
+    validate_field(entry)
 }
 
 fn validate_union_field(field: &Field, children: &[(i8, Field)], _mode: UnionMode) -> Result<()> {
-    if let Some(strategy) = get_strategy_from_metadata(&field.metadata)? {
-        fail!("invalid strategy for Union field: {strategy}");
+    match get_strategy_from_metadata(&field.metadata)? {
+        None | Some(Strategy::UntaggedUnion) => {}
+        Some(strategy) => fail!("invalid strategy for Union field: {strategy}"),
     }
+    let mut num_null_variants = 0;
     for (_, child) in children {
         validate_field(child)?;
+        if get_strategy_from_metadata(&child.metadata)? == Some(Strategy::NullVariant) {
+            num_null_variants += 1;
+        }
+    }
+    if num_null_variants > 1 {
+        fail!(
+            "Union field {name} has more than one NullVariant child",
+            name = field.name
+        );
     }
     Ok(())
 }
@@ -537,8 +841,10 @@ impl<'a> std::fmt::Display for DataTypeDisplay<'a> {
             DataType::Float64 => write!(f, "Float64"),
             DataType::Utf8 => write!(f, "Utf8"),
             DataType::LargeUtf8 => write!(f, "LargeUtf8"),
+            DataType::Utf8View => write!(f, "Utf8View"),
             DataType::Binary => write!(f, "Binary"),
             DataType::LargeBinary => write!(f, "LargeBinary"),
+            DataType::BinaryView => write!(f, "BinaryView"),
             DataType::FixedSizeBinary(n) => write!(f, "FixedSizeBinary({n})"),
             DataType::Date32 => write!(f, "Date32"),
             DataType::Date64 => write!(f, "Date64"),
@@ -546,10 +852,14 @@ impl<'a> std::fmt::Display for DataTypeDisplay<'a> {
             DataType::Time64(unit) => write!(f, "Time64({unit})"),
             DataType::Timestamp(unit, tz) => write!(f, "Timestamp({unit}, {tz:?})"),
             DataType::Duration(unit) => write!(f, "Duration({unit})"),
+            DataType::Interval(unit) => write!(f, "Interval({unit})"),
             DataType::List(_) => write!(f, "List"),
             DataType::LargeList(_) => write!(f, "LargeList"),
+            DataType::ListView(_) => write!(f, "ListView"),
+            DataType::LargeListView(_) => write!(f, "LargeListView"),
             DataType::FixedSizeList(_, n) => write!(f, "FixedSizeList({n})"),
             DataType::Decimal128(precision, scale) => write!(f, "Decimal128({precision}, {scale}"),
+            DataType::Decimal256(precision, scale) => write!(f, "Decimal256({precision}, {scale})"),
             DataType::Struct(_) => write!(f, "Struct"),
             DataType::Map(_, sorted) => write!(f, "Map({sorted})"),
             DataType::Dictionary(key, value, sorted) => write!(
@@ -564,9 +874,9 @@ impl<'a> std::fmt::Display for DataTypeDisplay<'a> {
 }
 
 const _: () = {
-    trait AssertSendSync: Send + Sync {}
-    impl AssertSendSync for SerdeArrowSchema {}
-    impl AssertSendSync for TracingOptions {}
-    impl AssertSendSync for Strategy {}
-    impl AssertSendSync for Overwrites {}
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SerdeArrowSchema>();
+    assert_send_sync::<TracingOptions>();
+    assert_send_sync::<Strategy>();
+    assert_send_sync::<Overwrites>();
 };
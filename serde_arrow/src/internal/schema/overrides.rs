@@ -0,0 +1,200 @@
+//! Replace the strategies of fields for a single conversion
+use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::internal::{
+    arrow::{Array, ArrayView, DataType, Field},
+    error::{fail, Result},
+    utils::ChildName,
+};
+
+use super::{set_strategy_in_metadata, validate_field, MetadataStrategy, Strategy};
+
+/// Return a copy of the fields with the strategies of the given paths replaced
+///
+/// Paths use the format of error messages, e.g., `$.outer.inner`. A strategy
+/// of `None` removes the strategy of the field. Paths not matching any field
+/// result in an error.
+pub fn apply_strategy_overrides(
+    fields: &[Field],
+    overrides: &BTreeMap<String, Option<Strategy>>,
+) -> Result<Vec<Field>> {
+    let mut fields = fields.to_vec();
+    let mut applied = BTreeSet::new();
+    for field in &mut fields {
+        let path = format!("$.{child}", child = ChildName(&field.name));
+        apply(field, path, overrides, &mut applied)?;
+    }
+
+    if let Some(path) = overrides
+        .keys()
+        .find(|path| !applied.contains(path.as_str()))
+    {
+        fail!("Cannot override the strategy of {path:?}: no field with this path");
+    }
+    Ok(fields)
+}
+
+fn apply<'a>(
+    field: &mut Field,
+    path: String,
+    overrides: &'a BTreeMap<String, Option<Strategy>>,
+    applied: &mut BTreeSet<&'a str>,
+) -> Result<()> {
+    match &mut field.data_type {
+        DataType::Struct(children) => {
+            for child in children.iter_mut() {
+                let child_path = format!("{path}.{child}", child = ChildName(&child.name));
+                apply(child, child_path, overrides, applied)?;
+            }
+        }
+        DataType::List(child)
+        | DataType::LargeList(child)
+        | DataType::ListView(child)
+        | DataType::LargeListView(child)
+        | DataType::FixedSizeList(child, _)
+        | DataType::Map(child, _) => {
+            let child_path = format!("{path}.{child}", child = ChildName(&child.name));
+            apply(child, child_path, overrides, applied)?;
+        }
+        DataType::Union(children, _) => {
+            for (_, child) in children.iter_mut() {
+                let child_path = format!("{path}.{child}", child = ChildName(&child.name));
+                apply(child, child_path, overrides, applied)?;
+            }
+        }
+        _ => {}
+    }
+
+    let Some((key, strategy)) = overrides.get_key_value(&path) else {
+        return Ok(());
+    };
+    applied.insert(key.as_str());
+
+    set_strategy(&mut field.metadata, strategy.as_ref());
+    if let Err(err) = validate_field(field) {
+        fail!(
+            "Cannot override the strategy of {path:?}: {msg}",
+            msg = err.message()
+        );
+    }
+    Ok(())
+}
+
+/// Replace the strategies of the nested fields of a view
+///
+/// The strategies of nested fields are read from the views during
+/// deserialization. The overrides are assumed to be validated by
+/// [`apply_strategy_overrides`] for the corresponding fields.
+pub fn apply_strategy_overrides_to_view(
+    view: &mut ArrayView<'_>,
+    path: &str,
+    overrides: &BTreeMap<String, Option<Strategy>>,
+) {
+    let mut children = Vec::new();
+    match view {
+        ArrayView::Struct(view) => {
+            for (child, meta) in &mut view.fields {
+                children.push((child, &mut meta.name, &mut meta.metadata));
+            }
+        }
+        ArrayView::List(view) | ArrayView::Map(view) => {
+            children.push((
+                &mut view.element,
+                &mut view.meta.name,
+                &mut view.meta.metadata,
+            ));
+        }
+        ArrayView::LargeList(view) => {
+            children.push((
+                &mut view.element,
+                &mut view.meta.name,
+                &mut view.meta.metadata,
+            ));
+        }
+        ArrayView::FixedSizeList(view) => {
+            children.push((
+                &mut view.element,
+                &mut view.meta.name,
+                &mut view.meta.metadata,
+            ));
+        }
+        ArrayView::DenseUnion(view) => {
+            for (_, child, meta) in &mut view.fields {
+                children.push((child, &mut meta.name, &mut meta.metadata));
+            }
+        }
+        _ => {}
+    }
+
+    for (child, name, metadata) in children {
+        let child_path = format!("{path}.{child}", child = ChildName(name));
+        if let Some(strategy) = overrides.get(&child_path) {
+            set_strategy(metadata, strategy.as_ref());
+        }
+        apply_strategy_overrides_to_view(child, &child_path, overrides);
+    }
+}
+
+/// Copy the metadata of the nested fields of `field` to the array
+///
+/// The builders write the metadata of the fields they were built from. With
+/// strategy overrides, this function restores the metadata of the original
+/// fields.
+pub fn restore_nested_metadata(array: &mut Array, field: &Field) {
+    let mut children = Vec::new();
+    match (array, &field.data_type) {
+        (Array::Struct(array), DataType::Struct(fields)) => {
+            for ((child, meta), field) in std::iter::zip(&mut array.fields, fields) {
+                children.push((child, &mut meta.metadata, field));
+            }
+        }
+        (Array::List(array), DataType::List(field))
+        | (Array::Map(array), DataType::Map(field, _)) => {
+            children.push((
+                array.element.as_mut(),
+                &mut array.meta.metadata,
+                field.as_ref(),
+            ));
+        }
+        (Array::LargeList(array), DataType::LargeList(field)) => {
+            children.push((
+                array.element.as_mut(),
+                &mut array.meta.metadata,
+                field.as_ref(),
+            ));
+        }
+        (Array::FixedSizeList(array), DataType::FixedSizeList(field, _)) => {
+            children.push((
+                array.element.as_mut(),
+                &mut array.meta.metadata,
+                field.as_ref(),
+            ));
+        }
+        (Array::DenseUnion(array), DataType::Union(fields, _)) => {
+            for ((_, child, meta), (_, field)) in std::iter::zip(&mut array.fields, fields) {
+                children.push((child, &mut meta.metadata, field));
+            }
+        }
+        (Array::SparseUnion(array), DataType::Union(fields, _)) => {
+            for ((_, child, meta), (_, field)) in std::iter::zip(&mut array.fields, fields) {
+                children.push((child, &mut meta.metadata, field));
+            }
+        }
+        _ => {}
+    }
+
+    for (child, metadata, field) in children {
+        metadata.clone_from(&field.metadata);
+        restore_nested_metadata(child, field);
+    }
+}
+
+fn set_strategy(metadata: &mut HashMap<String, String>, strategy: Option<&Strategy>) {
+    match strategy {
+        Some(strategy) => set_strategy_in_metadata(metadata, strategy),
+        None => {
+            Strategy::remove_from_metadata(metadata);
+        }
+    }
+}
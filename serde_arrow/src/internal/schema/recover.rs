@@ -0,0 +1,62 @@
+//! Recover the strategies of fields written without `serde_arrow` metadata
+use crate::internal::{
+    arrow::{DataType, Field},
+    error::Result,
+};
+
+use super::{get_strategy_from_metadata, Strategy, STRATEGY_KEY};
+
+/// Add strategies to fields without strategy metadata, if they can be derived
+/// from the data type
+///
+/// Existing strategies are kept. Children of nested fields are updated
+/// recursively.
+pub fn recover_strategies(field: &mut Field) -> Result<()> {
+    match &mut field.data_type {
+        DataType::Struct(children) => {
+            for child in children.iter_mut() {
+                recover_strategies(child)?;
+            }
+        }
+        DataType::List(child)
+        | DataType::LargeList(child)
+        | DataType::ListView(child)
+        | DataType::LargeListView(child)
+        | DataType::FixedSizeList(child, _)
+        | DataType::Map(child, _) => recover_strategies(child)?,
+        DataType::Union(children, _) => {
+            for (_, child) in children.iter_mut() {
+                recover_strategies(child)?;
+            }
+        }
+        _ => {}
+    }
+
+    if get_strategy_from_metadata(&field.metadata)?.is_some() {
+        return Ok(());
+    }
+    if let Some(strategy) = guess_strategy(field) {
+        field
+            .metadata
+            .insert(STRATEGY_KEY.to_owned(), strategy.to_string());
+    }
+    Ok(())
+}
+
+fn guess_strategy(field: &Field) -> Option<Strategy> {
+    match &field.data_type {
+        // Date64 fields without strategy are interpreted as UTC datetimes
+        DataType::Date64 => Some(Strategy::UtcStrAsDate64),
+        DataType::Struct(children) if is_tuple_like(children) => Some(Strategy::TupleAsStruct),
+        _ => None,
+    }
+}
+
+/// Check whether the fields are named `"0"`, `"1"`, ... in order
+fn is_tuple_like(fields: &[Field]) -> bool {
+    !fields.is_empty()
+        && fields
+            .iter()
+            .enumerate()
+            .all(|(idx, field)| field.name == idx.to_string())
+}
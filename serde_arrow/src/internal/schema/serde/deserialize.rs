@@ -130,7 +130,7 @@ impl ArrowOrCustomDataType {
     }
 }
 
-fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType> {
+pub fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType> {
     use DataType as T;
 
     let res = match Term::from_str(&data_type)?.as_call()? {
@@ -138,6 +138,7 @@ fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType>
         ("Bool" | "Boolean", []) => T::Boolean,
         ("Utf8", []) => T::Utf8,
         ("LargeUtf8", []) => T::LargeUtf8,
+        ("Utf8View", []) => T::Utf8View,
         ("U8" | "UInt8", []) => T::UInt8,
         ("U16" | "UInt16", []) => T::UInt16,
         ("U32" | "UInt32", []) => T::UInt32,
@@ -153,6 +154,7 @@ fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType>
         ("Date64", []) => T::Date64,
         ("Binary", []) => T::Binary,
         ("LargeBinary", []) => T::LargeBinary,
+        ("BinaryView", []) => T::BinaryView,
         ("FixedSizeBinary", [n]) => T::FixedSizeBinary(n.as_ident()?.parse()?),
         ("Timestamp", [unit, timezone]) => {
             let unit: TimeUnit = unit.as_ident()?.parse()?;
@@ -165,9 +167,13 @@ fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType>
         ("Time32", [unit]) => T::Time32(unit.as_ident()?.parse()?),
         ("Time64", [unit]) => T::Time64(unit.as_ident()?.parse()?),
         ("Duration", [unit]) => T::Duration(unit.as_ident()?.parse()?),
+        ("Interval", [unit]) => T::Interval(unit.as_ident()?.parse()?),
         ("Decimal128", [precision, scale]) => {
             T::Decimal128(precision.as_ident()?.parse()?, scale.as_ident()?.parse()?)
         }
+        ("Decimal256", [precision, scale]) => {
+            T::Decimal256(precision.as_ident()?.parse()?, scale.as_ident()?.parse()?)
+        }
         ("Struct", []) => T::Struct(children),
         ("List", []) => {
             let Ok([child]) = <[_; 1]>::try_from(children) else {
@@ -181,6 +187,18 @@ fn build_data_type(data_type: String, children: Vec<Field>) -> Result<DataType>
             };
             T::LargeList(Box::new(child))
         }
+        ("ListView", []) => {
+            let Ok([child]) = <[_; 1]>::try_from(children) else {
+                fail!("Invalid children for ListView: expected one child");
+            };
+            T::ListView(Box::new(child))
+        }
+        ("LargeListView", []) => {
+            let Ok([child]) = <[_; 1]>::try_from(children) else {
+                fail!("Invalid children for LargeListView: expected one child");
+            };
+            T::LargeListView(Box::new(child))
+        }
         ("FixedSizeList", [n]) => {
             let Ok([child]) = <[_; 1]>::try_from(children) else {
                 fail!("Invalid children for LargeList: expected one child");
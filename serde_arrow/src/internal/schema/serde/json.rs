@@ -0,0 +1,411 @@
+//! Strict conversions between schemas and `serde_json::Value`
+//!
+//! In contrast to [`SchemaLike::from_value`][crate::schema::SchemaLike::from_value],
+//! unknown keys are rejected, data types must be given as strings and errors
+//! are annotated with the JSON pointer of the offending value.
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::{Map, Value};
+
+use crate::internal::{
+    arrow::{DataType, Field, UnionMode},
+    error::{fail, set_default, Context, ContextSupport, Error, Result},
+    schema::{validate_field, SerdeArrowSchema, Strategy},
+};
+
+use super::{
+    deserialize::{build_data_type, merge_strategy_with_metadata},
+    serialize::PrettyField,
+};
+
+const FIELD_KEYS: &[&str] = &[
+    "name",
+    "data_type",
+    "nullable",
+    "strategy",
+    "children",
+    "metadata",
+];
+
+/// Strict conversion from JSON (*requires the `serde_json` feature*)
+///
+/// The value must be either a list of fields or an object with a single key
+/// `"fields"`. Unknown keys are rejected and data types must be given as
+/// strings. Errors are annotated with the JSON pointer of the invalid value.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// use serde_arrow::schema::SerdeArrowSchema;
+/// use serde_json::json;
+///
+/// let schema = SerdeArrowSchema::try_from(json!([
+///     {"name": "id", "data_type": "U64"},
+///     {"name": "tags", "data_type": "List", "children": [
+///         {"name": "element", "data_type": "Utf8"},
+///     ]},
+/// ]))?;
+///
+/// let err = SerdeArrowSchema::try_from(json!([
+///     {"name": "id", "data_type": "U64", "nulable": true},
+/// ])).unwrap_err();
+/// assert!(err.to_string().contains(r#"pointer: "/0/nulable""#));
+/// # Ok(())
+/// # }
+/// ```
+impl TryFrom<&Value> for SerdeArrowSchema {
+    type Error = Error;
+
+    fn try_from(value: &Value) -> Result<Self> {
+        let (fields, pointer) = match value {
+            Value::Array(fields) => (fields, String::new()),
+            Value::Object(object) => {
+                check_keys(object, "", &["fields"])?;
+                let Some(fields) = object.get("fields") else {
+                    fail!(in Pointer("/fields"), "Missing key `fields`");
+                };
+                let Value::Array(fields) = fields else {
+                    fail!(in Pointer("/fields"), "Expected an array of fields");
+                };
+                (fields, String::from("/fields"))
+            }
+            _ => fail!(
+                in Pointer(""),
+                "Expected an array of fields or an object with key `fields`"
+            ),
+        };
+
+        let mut result = Vec::new();
+        for (idx, field) in fields.iter().enumerate() {
+            result.push(field_from_value(field, &format!("{pointer}/{idx}"))?);
+        }
+        Ok(SerdeArrowSchema { fields: result })
+    }
+}
+
+/// Strict conversion from JSON (*requires the `serde_json` feature*)
+///
+/// See the implementation for `&Value` for details.
+impl TryFrom<Value> for SerdeArrowSchema {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+/// Lossless conversion into JSON (*requires the `serde_json` feature*)
+///
+/// The result is an object with a single key `"fields"` that is converted back
+/// into an equal schema by the `TryFrom<Value>` implementation. Data types that
+/// cannot be represented without losing information (sorted dictionaries or
+/// maps, sparse unions and unions with non-sequential type ids) result in an
+/// error.
+impl TryFrom<&SerdeArrowSchema> for Value {
+    type Error = Error;
+
+    fn try_from(schema: &SerdeArrowSchema) -> Result<Self> {
+        let mut fields = Vec::new();
+        for (idx, field) in schema.fields.iter().enumerate() {
+            check_lossless(field, &format!("/fields/{idx}"))?;
+            let value = serde_json::to_value(PrettyField(field))
+                .map_err(|err| Error::custom_from(format!("Cannot serialize field: {err}"), err))
+                .ctx(&Pointer(&format!("/fields/{idx}")))?;
+            fields.push(value);
+        }
+
+        let mut object = Map::new();
+        object.insert(String::from("fields"), Value::Array(fields));
+        Ok(Value::Object(object))
+    }
+}
+
+/// Lossless conversion into JSON (*requires the `serde_json` feature*)
+///
+/// See the implementation for `&SerdeArrowSchema` for details.
+impl TryFrom<SerdeArrowSchema> for Value {
+    type Error = Error;
+
+    fn try_from(schema: SerdeArrowSchema) -> Result<Self> {
+        Self::try_from(&schema)
+    }
+}
+
+/// Annotate errors with a JSON pointer
+struct Pointer<'a>(&'a str);
+
+impl<'a> Context for Pointer<'a> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "pointer", self.0);
+    }
+}
+
+fn check_keys(object: &Map<String, Value>, pointer: &str, allowed: &[&str]) -> Result<()> {
+    for key in object.keys() {
+        if !allowed.contains(&key.as_str()) {
+            fail!(
+                in Pointer(&format!("{pointer}/{key}", key = escape(key))),
+                "Unknown key {key:?}, expected one of {allowed:?}",
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Escape a key for use in a JSON pointer (RFC 6901)
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn field_from_value(value: &Value, pointer: &str) -> Result<Field> {
+    let Value::Object(object) = value else {
+        fail!(in Pointer(pointer), "Expected a field object");
+    };
+    check_keys(object, pointer, FIELD_KEYS)?;
+
+    let name = match object.get("name") {
+        Some(Value::String(name)) => name.to_owned(),
+        Some(_) => fail!(in Pointer(&format!("{pointer}/name")), "Expected a string"),
+        None => fail!(in Pointer(pointer), "Missing key `name`"),
+    };
+    let data_type = match object.get("data_type") {
+        Some(Value::String(data_type)) => data_type.to_owned(),
+        Some(_) => fail!(in Pointer(&format!("{pointer}/data_type")), "Expected a string"),
+        None => fail!(in Pointer(pointer), "Missing key `data_type`"),
+    };
+    let nullable = match object.get("nullable") {
+        Some(Value::Bool(nullable)) => *nullable,
+        Some(_) => fail!(in Pointer(&format!("{pointer}/nullable")), "Expected a boolean"),
+        None => false,
+    };
+    let strategy = match object.get("strategy") {
+        Some(Value::String(strategy)) => Some(
+            strategy
+                .parse::<Strategy>()
+                .ctx(&Pointer(&format!("{pointer}/strategy")))?,
+        ),
+        Some(_) => fail!(in Pointer(&format!("{pointer}/strategy")), "Expected a string"),
+        None => None,
+    };
+
+    let mut metadata = HashMap::new();
+    match object.get("metadata") {
+        Some(Value::Object(entries)) => {
+            for (key, value) in entries {
+                let Value::String(value) = value else {
+                    fail!(
+                        in Pointer(&format!("{pointer}/metadata/{key}", key = escape(key))),
+                        "Expected a string",
+                    );
+                };
+                metadata.insert(key.to_owned(), value.to_owned());
+            }
+        }
+        Some(_) => fail!(in Pointer(&format!("{pointer}/metadata")), "Expected an object"),
+        None => {}
+    }
+
+    let mut children = Vec::new();
+    match object.get("children") {
+        Some(Value::Array(values)) => {
+            for (idx, child) in values.iter().enumerate() {
+                children.push(field_from_value(
+                    child,
+                    &format!("{pointer}/children/{idx}"),
+                )?);
+            }
+        }
+        Some(_) => fail!(in Pointer(&format!("{pointer}/children")), "Expected an array"),
+        None => {}
+    }
+
+    let data_type_pointer = format!("{pointer}/data_type");
+    let num_children = children.len();
+    let data_type = build_data_type(data_type, children).ctx(&Pointer(&data_type_pointer))?;
+    if num_children != 0 && !has_children(&data_type) {
+        fail!(
+            in Pointer(&format!("{pointer}/children")),
+            "Data type {data_type:?} does not support children",
+        );
+    }
+
+    let metadata = merge_strategy_with_metadata(metadata, strategy).ctx(&Pointer(pointer))?;
+    let nullable = match &data_type {
+        DataType::Null => true,
+        _ => nullable,
+    };
+
+    let field = Field {
+        name,
+        data_type,
+        nullable,
+        metadata,
+    };
+    validate_field(&field).ctx(&Pointer(pointer))?;
+    Ok(field)
+}
+
+fn has_children(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Struct(_)
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::ListView(_)
+            | DataType::LargeListView(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
+            | DataType::Dictionary(_, _, _)
+            | DataType::Union(_, _)
+    )
+}
+
+fn check_lossless(field: &Field, pointer: &str) -> Result<()> {
+    match &field.data_type {
+        DataType::Dictionary(_, _, true) => {
+            fail!(in Pointer(pointer), "Sorted dictionaries cannot be converted to JSON")
+        }
+        DataType::Map(_, true) => {
+            fail!(in Pointer(pointer), "Sorted maps cannot be converted to JSON")
+        }
+        DataType::Map(entry, _)
+        | DataType::List(entry)
+        | DataType::LargeList(entry)
+        | DataType::ListView(entry)
+        | DataType::LargeListView(entry)
+        | DataType::FixedSizeList(entry, _) => {
+            check_lossless(entry, &format!("{pointer}/children/0"))
+        }
+        DataType::Struct(fields) => {
+            for (idx, child) in fields.iter().enumerate() {
+                check_lossless(child, &format!("{pointer}/children/{idx}"))?;
+            }
+            Ok(())
+        }
+        DataType::Union(fields, mode) => {
+            if *mode != UnionMode::Dense {
+                fail!(in Pointer(pointer), "Sparse unions cannot be converted to JSON");
+            }
+            for (idx, (type_id, child)) in fields.iter().enumerate() {
+                if usize::try_from(*type_id).ok() != Some(idx) {
+                    fail!(
+                        in Pointer(pointer),
+                        "Unions with non-sequential type ids cannot be converted to JSON",
+                    );
+                }
+                check_lossless(child, &format!("{pointer}/children/{idx}"))?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    use serde_json::json;
+
+    let value = json!({
+        "fields": [
+            {"name": "a", "data_type": "U64", "nullable": true, "metadata": {"foo": "bar"}},
+            {"name": "b", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+            {"name": "c", "data_type": "Struct", "strategy": "MapAsStruct", "children": [
+                {"name": "x", "data_type": "Dictionary", "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "LargeUtf8"},
+                ]},
+            ]},
+            {"name": "d", "data_type": "Union", "children": [
+                {"name": "A", "data_type": "Null", "nullable": true},
+                {"name": "B", "data_type": "I32"},
+            ]},
+        ],
+    });
+
+    let schema = SerdeArrowSchema::try_from(&value).unwrap();
+    let actual = Value::try_from(&schema).unwrap();
+    assert_eq!(actual, value);
+    assert_eq!(SerdeArrowSchema::try_from(actual).unwrap(), schema);
+}
+
+#[test]
+fn test_unknown_keys() {
+    use crate::internal::testing::assert_error_contains;
+    use serde_json::json;
+
+    let res = SerdeArrowSchema::try_from(json!({"fields": [], "version": 2}));
+    assert_error_contains(&res, "Unknown key \"version\"");
+    assert_error_contains(&res, "pointer: \"/version\"");
+
+    let res = SerdeArrowSchema::try_from(json!([
+        {"name": "a", "data_type": "Struct", "children": [
+            {"name": "b", "data_type": "I32", "nulable": true},
+        ]},
+    ]));
+    assert_error_contains(&res, "Unknown key \"nulable\"");
+    assert_error_contains(&res, "pointer: \"/0/children/0/nulable\"");
+}
+
+#[test]
+fn test_invalid_values() {
+    use crate::internal::testing::assert_error_contains;
+    use serde_json::json;
+
+    let res = SerdeArrowSchema::try_from(json!([{"name": "a", "data_type": "Int99"}]));
+    assert_error_contains(&res, "pointer: \"/0/data_type\"");
+
+    let res = SerdeArrowSchema::try_from(json!([{"name": "a", "data_type": {"Int32": null}}]));
+    assert_error_contains(&res, "Expected a string");
+    assert_error_contains(&res, "pointer: \"/0/data_type\"");
+
+    let res = SerdeArrowSchema::try_from(json!([{"name": "a", "data_type": "I32", "nullable": 1}]));
+    assert_error_contains(&res, "pointer: \"/0/nullable\"");
+
+    let res = SerdeArrowSchema::try_from(json!({"fields": [
+        {"name": "a", "data_type": "I32", "strategy": "Unknown"},
+    ]}));
+    assert_error_contains(&res, "pointer: \"/fields/0/strategy\"");
+
+    let res = SerdeArrowSchema::try_from(json!([
+        {"name": "a", "data_type": "I32", "children": [{"name": "b", "data_type": "I32"}]},
+    ]));
+    assert_error_contains(&res, "does not support children");
+    assert_error_contains(&res, "pointer: \"/0/children\"");
+
+    let res = SerdeArrowSchema::try_from(json!([
+        {"name": "a", "data_type": "List", "children": []},
+    ]));
+    assert_error_contains(&res, "expected one child");
+    assert_error_contains(&res, "pointer: \"/0/data_type\"");
+
+    let res = SerdeArrowSchema::try_from(json!([
+        {"name": "a", "data_type": "Date64", "strategy": "TupleAsStruct"},
+    ]));
+    assert_error_contains(&res, "pointer: \"/0\"");
+}
+
+#[test]
+fn test_lossy_data_types_are_rejected() {
+    use crate::internal::testing::assert_error_contains;
+
+    let schema = SerdeArrowSchema {
+        fields: vec![Field {
+            name: String::from("a"),
+            data_type: DataType::List(Box::new(Field {
+                name: String::from("element"),
+                data_type: DataType::Dictionary(
+                    Box::new(DataType::UInt32),
+                    Box::new(DataType::Utf8),
+                    true,
+                ),
+                nullable: false,
+                metadata: HashMap::new(),
+            })),
+            nullable: false,
+            metadata: HashMap::new(),
+        }],
+    };
+
+    let res = Value::try_from(&schema);
+    assert_error_contains(&res, "Sorted dictionaries");
+    assert_error_contains(&res, "pointer: \"/fields/0/children/0\"");
+}
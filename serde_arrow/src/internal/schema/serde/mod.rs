@@ -1,4 +1,6 @@
 //! Group all serialization / deserialization related functionality
 //!
 pub mod deserialize;
+#[cfg(feature = "serde_json")]
+pub mod json;
 pub mod serialize;
@@ -98,14 +98,20 @@ impl<'a> serde::Serialize for PrettyFieldDataType<'a> {
             T::Float64 => "F64".serialize(serializer),
             T::Utf8 => "Utf8".serialize(serializer),
             T::LargeUtf8 => "LargeUtf8".serialize(serializer),
+            T::Utf8View => "Utf8View".serialize(serializer),
             T::Binary => "Binary".serialize(serializer),
             T::LargeBinary => "LargeBinary".serialize(serializer),
+            T::BinaryView => "BinaryView".serialize(serializer),
             T::Date32 => "Date32".serialize(serializer),
             T::Date64 => "Date64".serialize(serializer),
             T::Decimal128(precision, scale) => {
                 format!("Decimal128({precision}, {scale})").serialize(serializer)
             }
+            T::Decimal256(precision, scale) => {
+                format!("Decimal256({precision}, {scale})").serialize(serializer)
+            }
             T::Duration(unit) => format!("Duration({unit})").serialize(serializer),
+            T::Interval(unit) => format!("Interval({unit})").serialize(serializer),
             T::Time32(unit) => format!("Time32({unit})").serialize(serializer),
             T::Time64(unit) => format!("Time64({unit})").serialize(serializer),
             T::Timestamp(unit, tz) => format!("Timestamp({unit}, {tz:?})").serialize(serializer),
@@ -117,6 +123,8 @@ impl<'a> serde::Serialize for PrettyFieldDataType<'a> {
             T::Dictionary(_, _, _) => "Dictionary".serialize(serializer),
             T::LargeList(_) => "LargeList".serialize(serializer),
             T::List(_) => "List".serialize(serializer),
+            T::ListView(_) => "ListView".serialize(serializer),
+            T::LargeListView(_) => "LargeListView".serialize(serializer),
         }
     }
 }
@@ -131,7 +139,9 @@ impl<'a> serde::Serialize for PrettyFieldChildren<'a> {
             T::FixedSizeList(entry, _)
             | T::Map(entry, _)
             | T::LargeList(entry)
-            | T::List(entry) => {
+            | T::List(entry)
+            | T::LargeListView(entry)
+            | T::ListView(entry) => {
                 let mut s = serializer.serialize_seq(Some(1))?;
                 s.serialize_element(&PrettyField(entry.as_ref()))?;
                 s.end()
@@ -183,5 +193,7 @@ fn is_data_type_with_children(data_type: &DataType) -> bool {
             | T::Dictionary(_, _, _)
             | T::LargeList(_)
             | T::List(_)
+            | T::LargeListView(_)
+            | T::ListView(_)
     )
 }
@@ -40,6 +40,112 @@ pub enum Strategy {
     /// additional configuration. As they are serialized as strings.
     ///
     NaiveStrAsDate64,
+    /// Use the string representation of the `time` crate for timestamps,
+    /// dates and times
+    ///
+    /// This strategy applies to `Timestamp` fields without timezone or with
+    /// UTC timezone, `Date32` fields and `Time32` / `Time64` fields. It makes
+    /// sense for the `time` crate's `OffsetDateTime`, `PrimitiveDateTime`,
+    /// `Date` and `Time` types, which are serialized as strings (e.g.,
+    /// `"2024-09-30 12:30:00.0 +00:00:00"`). Offsets are converted to UTC
+    /// during serialization. During deserialization, strings in the `time`
+    /// format are emitted.
+    ///
+    TimeCrateStr,
+    /// Serialize Rust integers as decimals with the given scale
+    ///
+    /// This strategy applies to decimal fields. The integer is
+    /// interpreted as a fixed point number with `scale` decimal digits, e.g.,
+    /// with a scale of 2 an integer of cents `1234` corresponds to `12.34`.
+    /// The value is rescaled to the scale of the field without any floating
+    /// point operations. Values that do not fit into the precision of the
+    /// field or that would lose digits result in an error. During
+    /// deserialization, the decimals are converted back into integers with the
+    /// same scale.
+    ///
+    /// The strategy is written as `"ScaledInt(2)"`. It is typically configured
+    /// via overwrites, as tracing does not know about the scale.
+    ///
+    ScaledInt {
+        /// The number of decimal digits of the integer
+        scale: i8,
+    },
+    /// Quantize floats linearly into 8 bit integers
+    ///
+    /// This strategy applies to `UInt8` and `Int8` fields, e.g., to store
+    /// quantized embeddings in feature stores. A float `x` is stored as the
+    /// integer `round(x / scale) + zero_point`. Values outside of the range of
+    /// the field are clamped to its minimum or maximum. NaN values result in an
+    /// error. During deserialization into floats, an integer `q` is converted
+    /// back into `(q - zero_point) * scale`. Integers are serialized and
+    /// deserialized without any conversion.
+    ///
+    /// The strategy is written as `"Quantized(0.05, 128)"`, with the scale
+    /// followed by the zero point. The scale must be positive and the zero
+    /// point must be in the range of the field.
+    ///
+    Quantized {
+        /// The difference of the floats represented by consecutive integers
+        scale: f32,
+        /// The integer that represents `0.0`
+        zero_point: i32,
+    },
+    /// Fill the field with the hash of a sibling string field
+    ///
+    /// This strategy applies to `UInt64` fields. The field is not part of the
+    /// Rust objects. Instead, whenever the sibling field `source` is
+    /// serialized, the 64 bit xxHash (XXH64, seed 0) of its UTF-8 bytes is
+    /// written into this field in the same pass. The source field must be a
+    /// `Utf8`, `LargeUtf8` or dictionary encoded string field of the same
+    /// struct. Null values of the source are hashed as null. The hash is
+    /// stable and can be used as a join key or in bloom filters by query
+    /// engines. During deserialization, fields with this strategy are ignored.
+    ///
+    /// The strategy is written as `"StringHash(name)"`, where `name` is the
+    /// name of the source field.
+    ///
+    StringHash {
+        /// The name of the string field to hash
+        source: String,
+    },
+    /// Fill the field with a digest of the other fields of the parent struct
+    ///
+    /// This strategy applies to `UInt64` and `FixedSizeBinary(8)` fields. The
+    /// field is not part of the Rust objects. Instead, the serialized values of
+    /// all other fields of the parent struct, including their names, are
+    /// encoded in a canonical byte stream and the 64 bit xxHash (XXH64, seed
+    /// 0) of this stream is written into this field in the same pass. For
+    /// `FixedSizeBinary(8)` fields, the big endian bytes of the hash are
+    /// written. For top-level fields, the digest covers the full row, which
+    /// makes it useful for deduplication or change data capture.
+    ///
+    /// The digest depends on the order in which the fields are serialized.
+    /// Structs always serialize their fields in the same order, but maps
+    /// without a defined order (e.g., `HashMap`) may result in different
+    /// digests for equal values. Fields of the Rust objects that are not part
+    /// of the schema are not included. During deserialization, fields with
+    /// this strategy are ignored.
+    ///
+    RowHash,
+    /// Store long strings outside of a dictionary
+    ///
+    /// This strategy keeps dictionaries of mostly short, repeated strings
+    /// small, if some values are long and unique (e.g., free text). It applies
+    /// only to `Struct` fields with exactly two nullable children: a
+    /// dictionary with `Utf8` or `LargeUtf8` values and a `Utf8` or
+    /// `LargeUtf8` overflow field. Strings with at most `max_len` bytes are
+    /// written into the dictionary and the overflow field is null. Longer
+    /// strings are written into the overflow field and the dictionary key is
+    /// null, which marks the value as overflowed. Null values are written as
+    /// null structs. In deserialization, the struct is read as a single
+    /// string from the child holding the value.
+    ///
+    /// The strategy is written as `"DictionaryOverflow(64)"`.
+    ///
+    DictionaryOverflow {
+        /// The maximum number of bytes of strings stored in the dictionary
+        max_len: usize,
+    },
     /// Serialize Rust tuples as Arrow structs with numeric field names starting
     /// at `"0"`
     ///
@@ -69,6 +175,93 @@ pub enum Strategy {
     /// serialization or deserialization of such a field is attempted, it will
     /// result in an error.
     UnknownVariant,
+    /// Mark the child of a nullable union used to encode missing values
+    ///
+    /// Arrow unions do not have a validity bitmap. Therefore, `serde_arrow`
+    /// encodes `None` values of optional enums (e.g., `Option<MyEnum>`) by
+    /// selecting a dedicated child with this strategy. This strategy applies
+    /// only to fields with DataType Null that are children of unions. During
+    /// deserialization, values of this child are returned as `None`.
+    NullVariant,
+    /// Collect the unknown keys of the parent struct in this map
+    ///
+    /// This strategy supports catch-all fields, as in `#[serde(flatten)]
+    /// extra: HashMap<String, T>`. It applies only to `Map` fields with string
+    /// keys that are children of a struct. In serialization, entries of the
+    /// parent without a matching field are written into this map. In
+    /// deserialization, the entries of the map are returned as entries of the
+    /// parent. Each struct may contain at most one field with this strategy.
+    /// See [`TracingOptions::catch_all`][crate::schema::TracingOptions::catch_all]
+    /// to trace these fields.
+    CatchAll,
+    /// Read Rust enums from structs with a tag field
+    ///
+    /// This strategy supports compact enum encodings written by other tools.
+    /// It applies only to `Struct` fields. The first child is the tag with the
+    /// name of the variant as a `Utf8` or `LargeUtf8` value, or as a
+    /// dictionary of these types. The remaining children are named after the
+    /// variants and hold their data. Unit variants do not require a child.
+    /// Fields with this strategy are only supported in deserialization.
+    EnumAsStructWithTag,
+    /// Serialize unit enums as structs with one boolean flag per variant
+    ///
+    /// This strategy supports one-hot encoded features, e.g., for machine
+    /// learning pipelines. It applies only to `Struct` fields whose children
+    /// are `Boolean` fields named after the variants. In serialization, the
+    /// flag of the selected variant is set to `true` and all others to
+    /// `false`. Null values are written as null structs with all flags set
+    /// to `false`. Only unit variants are supported. In deserialization,
+    /// exactly one flag of each non-null struct must be set, null flags are
+    /// treated as `false`.
+    EnumOneHot,
+    /// Serialize booleans and numbers as their string representation
+    ///
+    /// This strategy applies only to `Utf8` and `LargeUtf8` fields. Strings
+    /// are serialized as is. During deserialization, the stored strings are
+    /// emitted. See
+    /// [`MixedTypePolicy::StringifyAll`][crate::schema::MixedTypePolicy::StringifyAll]
+    /// to trace these fields.
+    Stringify,
+    /// Select the offset width of strings when the arrays are built
+    ///
+    /// This strategy applies only to `Utf8`, `LargeUtf8` and `Utf8View`
+    /// fields. During serialization, the strings are buffered with 64 bit
+    /// offsets. When the arrays are built, `Utf8` arrays are returned if the
+    /// total number of bytes fits into 32 bit offsets, and `LargeUtf8` arrays
+    /// otherwise. Therefore, the data type of the built arrays may differ from
+    /// the data type of the field. The schemas of the built record batches use
+    /// the data types of the arrays. `Utf8View` fields, which require an arrow
+    /// version with view types, are always built as `Utf8View` arrays, as
+    /// views do not limit the total number of bytes. During deserialization,
+    /// `Utf8` and `LargeUtf8` arrays are supported.
+    AdaptiveString,
+    /// Serialize numbers and numeric strings as seconds
+    ///
+    /// This strategy supports the output of adapters counting seconds, e.g.,
+    /// `serde_with::DurationSeconds`, `DurationSecondsWithFrac`,
+    /// `TimestampSeconds` or `TimestampSecondsWithFrac`. It applies only to
+    /// `Duration` and `Timestamp` fields. Timestamps count the seconds since
+    /// the UNIX epoch. In serialization, integers, floats and strings
+    /// holding numbers (e.g., `"12"` or `"1.5"`) are interpreted as seconds and
+    /// converted into the unit of the field. Fractional values are rounded to
+    /// the nearest value of the unit. In deserialization, the values are
+    /// emitted as seconds: as integers if requested (fractional seconds result
+    /// in an error), as floats, or as strings. Self-describing formats receive
+    /// integers for whole seconds and floats otherwise. See
+    /// [`TracingOptions::seconds_at`][crate::schema::TracingOptions::seconds_at]
+    /// to trace these fields.
+    Seconds,
+    /// Select the child of a union by the type of the value
+    ///
+    /// This strategy applies only to `Union` fields. In serialization,
+    /// booleans, numbers and strings are written into the first child of a
+    /// matching data type, instead of selecting the child by the enum
+    /// variant. In self-describing deserialization (e.g., into
+    /// `serde_json::Value`), the value of the child is emitted without the
+    /// variant name. See
+    /// [`MixedTypePolicy::Union`][crate::schema::MixedTypePolicy::Union] to
+    /// trace these fields.
+    UntaggedUnion,
 }
 
 impl std::fmt::Display for Strategy {
@@ -77,9 +270,23 @@ impl std::fmt::Display for Strategy {
             Self::InconsistentTypes => write!(f, "InconsistentTypes"),
             Self::UtcStrAsDate64 => write!(f, "UtcStrAsDate64"),
             Self::NaiveStrAsDate64 => write!(f, "NaiveStrAsDate64"),
+            Self::TimeCrateStr => write!(f, "TimeCrateStr"),
+            Self::ScaledInt { scale } => write!(f, "ScaledInt({scale})"),
+            Self::Quantized { scale, zero_point } => write!(f, "Quantized({scale}, {zero_point})"),
+            Self::StringHash { source } => write!(f, "StringHash({source})"),
+            Self::DictionaryOverflow { max_len } => write!(f, "DictionaryOverflow({max_len})"),
+            Self::RowHash => write!(f, "RowHash"),
             Self::TupleAsStruct => write!(f, "TupleAsStruct"),
             Self::MapAsStruct => write!(f, "MapAsStruct"),
             Self::UnknownVariant => write!(f, "UnknownVariant"),
+            Self::NullVariant => write!(f, "NullVariant"),
+            Self::CatchAll => write!(f, "CatchAll"),
+            Self::EnumAsStructWithTag => write!(f, "EnumAsStructWithTag"),
+            Self::EnumOneHot => write!(f, "EnumOneHot"),
+            Self::Stringify => write!(f, "Stringify"),
+            Self::AdaptiveString => write!(f, "AdaptiveString"),
+            Self::Seconds => write!(f, "Seconds"),
+            Self::UntaggedUnion => write!(f, "UntaggedUnion"),
         }
     }
 }
@@ -106,10 +313,65 @@ impl FromStr for Strategy {
             "InconsistentTypes" => Ok(Self::InconsistentTypes),
             "UtcStrAsDate64" => Ok(Self::UtcStrAsDate64),
             "NaiveStrAsDate64" => Ok(Self::NaiveStrAsDate64),
+            "TimeCrateStr" => Ok(Self::TimeCrateStr),
+            "RowHash" => Ok(Self::RowHash),
             "TupleAsStruct" => Ok(Self::TupleAsStruct),
             "MapAsStruct" => Ok(Self::MapAsStruct),
             "UnknownVariant" => Ok(Self::UnknownVariant),
-            _ => fail!("Unknown strategy {s}"),
+            "NullVariant" => Ok(Self::NullVariant),
+            "CatchAll" => Ok(Self::CatchAll),
+            "EnumAsStructWithTag" => Ok(Self::EnumAsStructWithTag),
+            "EnumOneHot" => Ok(Self::EnumOneHot),
+            "Stringify" => Ok(Self::Stringify),
+            "AdaptiveString" => Ok(Self::AdaptiveString),
+            "Seconds" => Ok(Self::Seconds),
+            "UntaggedUnion" => Ok(Self::UntaggedUnion),
+            _ => {
+                if let Some(scale) = s
+                    .strip_prefix("ScaledInt(")
+                    .and_then(|s| s.strip_suffix(')'))
+                {
+                    let Ok(scale) = scale.trim().parse::<i8>() else {
+                        fail!("Invalid scale in strategy {s}");
+                    };
+                    return Ok(Self::ScaledInt { scale });
+                }
+                if let Some(args) = s
+                    .strip_prefix("Quantized(")
+                    .and_then(|s| s.strip_suffix(')'))
+                {
+                    let Some((scale, zero_point)) = args.split_once(',') else {
+                        fail!(
+                            "Invalid arguments in strategy {s}, expected a scale and a zero point"
+                        );
+                    };
+                    let Ok(scale) = scale.trim().parse::<f32>() else {
+                        fail!("Invalid scale in strategy {s}");
+                    };
+                    let Ok(zero_point) = zero_point.trim().parse::<i32>() else {
+                        fail!("Invalid zero point in strategy {s}");
+                    };
+                    return Ok(Self::Quantized { scale, zero_point });
+                }
+                if let Some(source) = s
+                    .strip_prefix("StringHash(")
+                    .and_then(|s| s.strip_suffix(')'))
+                {
+                    return Ok(Self::StringHash {
+                        source: source.to_owned(),
+                    });
+                }
+                if let Some(max_len) = s
+                    .strip_prefix("DictionaryOverflow(")
+                    .and_then(|s| s.strip_suffix(')'))
+                {
+                    let Ok(max_len) = max_len.trim().parse::<usize>() else {
+                        fail!("Invalid maximum length in strategy {s}");
+                    };
+                    return Ok(Self::DictionaryOverflow { max_len });
+                }
+                fail!("Unknown strategy {s}")
+            }
         }
     }
 }
@@ -117,7 +379,7 @@ impl FromStr for Strategy {
 impl From<Strategy> for BTreeMap<String, String> {
     fn from(value: Strategy) -> Self {
         let mut res = BTreeMap::new();
-        res.insert(STRATEGY_KEY.to_string(), value.to_string());
+        res.insert(value.metadata_key(), value.to_metadata_value());
         res
     }
 }
@@ -125,14 +387,122 @@ impl From<Strategy> for BTreeMap<String, String> {
 impl From<Strategy> for HashMap<String, String> {
     fn from(value: Strategy) -> Self {
         let mut res = HashMap::new();
-        res.insert(STRATEGY_KEY.to_string(), value.to_string());
+        set_strategy_in_metadata(&mut res, &value);
         res
     }
 }
 
+/// The metadata key under which strategies of the given namespace are stored
+///
+/// The key has the form `"{namespace}:strategy"`. The strategies of
+/// `serde_arrow` use the `SERDE_ARROW` namespace, i.e., the key
+/// [`STRATEGY_KEY`].
+///
+/// ```rust
+/// # use serde_arrow::schema::{strategy_key, STRATEGY_KEY};
+/// assert_eq!(strategy_key("SERDE_ARROW"), STRATEGY_KEY);
+/// assert_eq!(strategy_key("my_crate"), "my_crate:strategy");
+/// ```
+pub fn strategy_key(namespace: &str) -> String {
+    format!("{namespace}:strategy")
+}
+
+/// A strategy stored in the field metadata under a namespaced key
+///
+/// Implementing this trait allows to store custom strategies next to the
+/// strategies of `serde_arrow`, without interfering with them. `serde_arrow`
+/// only interprets the strategies of its own namespace and keeps the metadata
+/// of other namespaces unchanged.
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use serde_arrow::{
+/// #     Result,
+/// #     schema::{get_strategy_from_metadata, MetadataStrategy, Strategy},
+/// # };
+/// #[derive(Debug, PartialEq)]
+/// struct Compressed(String);
+///
+/// impl MetadataStrategy for Compressed {
+///     const NAMESPACE: &'static str = "my_crate";
+///
+///     fn to_metadata_value(&self) -> String {
+///         self.0.clone()
+///     }
+///
+///     fn from_metadata_value(value: &str) -> Result<Self> {
+///         Ok(Compressed(value.to_owned()))
+///     }
+/// }
+///
+/// let mut metadata = HashMap::<String, String>::from(Strategy::UtcStrAsDate64);
+/// Compressed(String::from("zstd")).write_metadata(&mut metadata);
+///
+/// assert_eq!(metadata["my_crate:strategy"], "zstd");
+/// assert_eq!(
+///     Compressed::from_metadata(&metadata)?,
+///     Some(Compressed(String::from("zstd"))),
+/// );
+/// assert_eq!(
+///     get_strategy_from_metadata(&metadata)?,
+///     Some(Strategy::UtcStrAsDate64),
+/// );
+/// # Ok::<(), serde_arrow::Error>(())
+/// ```
+pub trait MetadataStrategy: Sized {
+    /// The namespace of the metadata key, see [`strategy_key`]
+    const NAMESPACE: &'static str;
+
+    /// Encode the strategy as the value of the metadata entry
+    fn to_metadata_value(&self) -> String;
+
+    /// Decode the strategy from the value of the metadata entry
+    fn from_metadata_value(value: &str) -> Result<Self>;
+
+    /// The metadata key of the strategy
+    fn metadata_key(&self) -> String {
+        strategy_key(Self::NAMESPACE)
+    }
+
+    /// Read the strategy of this namespace from the metadata, if present
+    fn from_metadata(metadata: &HashMap<String, String>) -> Result<Option<Self>> {
+        let Some(value) = metadata.get(&strategy_key(Self::NAMESPACE)) else {
+            return Ok(None);
+        };
+        Ok(Some(Self::from_metadata_value(value)?))
+    }
+
+    /// Store the strategy in the metadata, replacing any previous strategy of
+    /// the same namespace
+    fn write_metadata(&self, metadata: &mut HashMap<String, String>) {
+        metadata.insert(self.metadata_key(), self.to_metadata_value());
+    }
+
+    /// Remove the strategy of this namespace from the metadata
+    fn remove_from_metadata(metadata: &mut HashMap<String, String>) -> Option<String> {
+        metadata.remove(&strategy_key(Self::NAMESPACE))
+    }
+}
+
+impl MetadataStrategy for Strategy {
+    const NAMESPACE: &'static str = "SERDE_ARROW";
+
+    fn to_metadata_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_metadata_value(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+/// Read the strategy of `serde_arrow` from the field metadata, if present
+///
+/// Strategies of other namespaces, see [`MetadataStrategy`], are ignored.
 pub fn get_strategy_from_metadata(metadata: &HashMap<String, String>) -> Result<Option<Strategy>> {
-    let Some(strategy) = metadata.get(STRATEGY_KEY) else {
-        return Ok(None);
-    };
-    Ok(Some(strategy.parse()?))
+    Strategy::from_metadata(metadata)
+}
+
+pub fn set_strategy_in_metadata(metadata: &mut HashMap<String, String>, strategy: &Strategy) {
+    strategy.write_metadata(metadata);
 }
@@ -10,7 +10,7 @@ use crate::internal::{
 };
 
 fn type_from_str(s: &str) -> DataType {
-    let schema = SerdeArrowSchema::from_value(&json!([{"name": "item", "data_type": s}])).unwrap();
+    let schema = SerdeArrowSchema::from_value(json!([{"name": "item", "data_type": s}])).unwrap();
     schema.fields[0].data_type.clone()
 }
 
@@ -267,7 +267,7 @@ fn example_without_wrapper() {
     };
 
     let input = r#"[{"name":"foo","data_type":"U8"},{"name":"bar","data_type":"Utf8"}]"#;
-    let actual: SerdeArrowSchema = serde_json::from_str(&input).unwrap();
+    let actual: SerdeArrowSchema = serde_json::from_str(input).unwrap();
     assert_eq!(actual, expected);
 }
 
@@ -306,7 +306,7 @@ fn doc_schema() {
         ]
     "#;
 
-    let actual: SerdeArrowSchema = serde_json::from_str(&schema).unwrap();
+    let actual: SerdeArrowSchema = serde_json::from_str(schema).unwrap();
 
     let expected = SerdeArrowSchema {
         fields: vec![
@@ -360,7 +360,7 @@ fn date64_with_strategy() {
 
 #[test]
 fn test_metadata_strategy_from_explicit() {
-    let schema = SerdeArrowSchema::from_value(&json!([
+    let schema = SerdeArrowSchema::from_value(json!([
         {
             "name": "example",
             "data_type": "Date64",
@@ -398,7 +398,7 @@ fn test_metadata_strategy_from_explicit() {
 
 #[test]
 fn test_metadata_strategy_from_metadata() {
-    let schema = SerdeArrowSchema::from_value(&json!([
+    let schema = SerdeArrowSchema::from_value(json!([
         {
             "name": "example",
             "data_type": "Date64",
@@ -438,7 +438,7 @@ fn test_metadata_strategy_from_metadata() {
 #[test]
 fn test_invalid_metadata() {
     // strategies cannot be given both in metadata and strategy field
-    let res = SerdeArrowSchema::from_value(&json!([
+    let res = SerdeArrowSchema::from_value(json!([
         {
             "name": "example",
             "data_type": "Date64",
@@ -476,6 +476,19 @@ fn test_long_form_types() {
     );
 }
 
+#[test]
+fn test_decimal_precision_is_validated() {
+    let res = SerdeArrowSchema::from_value(json!([
+        {"name": "item", "data_type": "Decimal128(39, 2)"},
+    ]));
+    assert_error_contains(&res, "at most 38 digits");
+
+    let res = SerdeArrowSchema::from_value(json!([
+        {"name": "item", "data_type": "Decimal128(38, 2)"},
+    ]));
+    assert!(res.is_ok());
+}
+
 macro_rules! test_short_form_type {
     ($name:ident, $data_type:expr, $s:expr) => {
         #[test]
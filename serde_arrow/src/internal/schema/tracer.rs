@@ -4,12 +4,13 @@ use std::{
 };
 
 use crate::internal::{
-    arrow::{DataType, Field, UnionMode},
+    arrow::{DataType, Field, TimeUnit, UnionMode},
     error::{fail, set_default, Context, Result},
     schema::{
-        DataTypeDisplay, Overwrites, SerdeArrowSchema, Strategy, TracingMode, TracingOptions,
-        STRATEGY_KEY,
+        DataTypeDisplay, DuplicateFieldPolicy, ListNullability, MixedTypePolicy, Overwrites,
+        SecondsType, SerdeArrowSchema, Strategy, TracingMode, TracingOptions, STRATEGY_KEY,
     },
+    utils::{decimal::merge_precision_and_scale, xxhash::xxh64},
 };
 
 // TODO: allow to customize
@@ -17,19 +18,50 @@ const MAX_TYPE_DEPTH: usize = 20;
 const RECURSIVE_TYPE_WARNING: &str =
     "Too deeply nested type detected: recursive types are not supported in schema tracing";
 
-fn default_dictionary_field(name: &str, nullable: bool) -> Field {
+fn default_dictionary_field(name: &str, nullable: bool, options: &TracingOptions) -> Field {
     Field {
         name: name.to_owned(),
         nullable,
         metadata: HashMap::new(),
         data_type: DataType::Dictionary(
             Box::new(DataType::UInt32),
-            Box::new(DataType::LargeUtf8),
+            Box::new(options.default_string_type.to_data_type()),
             false,
         ),
     }
 }
 
+fn resolve_duplicate_field_names<I: IntoIterator<Item = String>>(
+    names: I,
+    policy: DuplicateFieldPolicy,
+) -> Result<Vec<String>> {
+    let mut seen = HashSet::new();
+    let mut result = Vec::new();
+    for name in names {
+        if !seen.contains(&name) {
+            seen.insert(name.clone());
+            result.push(name);
+            continue;
+        }
+
+        match policy {
+            DuplicateFieldPolicy::Error => fail!("{}", DuplicateFieldMessage(&name)),
+            DuplicateFieldPolicy::Merge => result.push(name),
+            DuplicateFieldPolicy::Suffix => {
+                let mut occurrence = 1;
+                let mut candidate = format!("{name}_{occurrence}");
+                while seen.contains(&candidate) {
+                    occurrence += 1;
+                    candidate = format!("{name}_{occurrence}");
+                }
+                seen.insert(candidate.clone());
+                result.push(candidate);
+            }
+        }
+    }
+    Ok(result)
+}
+
 fn unknown_variant_field() -> Field {
     let mut metadata = HashMap::new();
     metadata.insert(STRATEGY_KEY.into(), Strategy::UnknownVariant.into());
@@ -41,6 +73,17 @@ fn unknown_variant_field() -> Field {
     }
 }
 
+fn null_variant_field() -> Field {
+    let mut metadata = HashMap::new();
+    metadata.insert(STRATEGY_KEY.into(), Strategy::NullVariant.into());
+    Field {
+        name: String::from(""),
+        nullable: true,
+        data_type: DataType::Null,
+        metadata,
+    }
+}
+
 struct NullFieldMessage<'a>(&'a str);
 
 impl<'a> std::fmt::Display for NullFieldMessage<'a> {
@@ -56,6 +99,21 @@ impl<'a> std::fmt::Display for NullFieldMessage<'a> {
     }
 }
 
+struct DuplicateFieldMessage<'a>(&'a str);
+
+impl<'a> std::fmt::Display for DuplicateFieldMessage<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            concat!(
+                "Encountered duplicate field {name}. ",
+                "Duplicate fields can be renamed or merged by setting `duplicate_fields` in `TracingOptions`.",
+            ),
+            name = self.0
+        )
+    }
+}
+
 struct EnumWithoutDataMessage<'a>(&'a str);
 
 impl<'a> std::fmt::Display for EnumWithoutDataMessage<'a> {
@@ -167,6 +225,10 @@ impl Tracer {
         dispatch_tracer!(self, tracer => &tracer.options)
     }
 
+    pub fn get_path(&self) -> &str {
+        dispatch_tracer!(self, tracer => &tracer.path)
+    }
+
     pub fn finish(&mut self) -> Result<()> {
         dispatch_tracer!(self, tracer => tracer.finish())
     }
@@ -180,16 +242,38 @@ impl Tracer {
             fail!("Check must be called on the root tracer");
         }
         let options = self.get_options();
-        self.check_overwrites(&options.overwrites)
+        self.check_overwrites(&options.overwrites)?;
+        self.check_list_nullabilities(&options.list_nullabilities)
+    }
+
+    pub fn check_list_nullabilities(
+        &self,
+        list_nullabilities: &HashMap<String, ListNullability>,
+    ) -> Result<()> {
+        let mut tracers = HashMap::new();
+        self.collect_tracers(&mut tracers);
+
+        let mut missing = Vec::new();
+        for path in list_nullabilities.keys() {
+            if !matches!(tracers.get(path), Some(Tracer::List(_))) {
+                missing.push(path.strip_prefix("$.").unwrap_or(path));
+            }
+        }
+
+        if !missing.is_empty() {
+            missing.sort();
+            fail!("List nullability configured for fields that are not lists: {missing:?}");
+        }
+        Ok(())
     }
 
     pub fn check_overwrites(&self, overwrites: &Overwrites) -> Result<()> {
-        let mut paths = HashSet::new();
-        self.collect_paths(&mut paths);
+        let mut tracers = HashMap::new();
+        self.collect_tracers(&mut tracers);
 
         let mut missing = Vec::new();
         for key in overwrites.0.keys() {
-            if !paths.contains(key) {
+            if !tracers.contains_key(key) {
                 let key = key.strip_prefix("$.").unwrap_or(key);
                 missing.push(key);
             }
@@ -198,8 +282,8 @@ impl Tracer {
         if !missing.is_empty() {
             missing.sort();
 
-            let mut paths = paths
-                .into_iter()
+            let mut paths = tracers
+                .into_keys()
                 .map(|p| p.strip_prefix("$.").unwrap_or(p))
                 .collect::<Vec<_>>();
             paths.sort();
@@ -210,42 +294,42 @@ impl Tracer {
         Ok(())
     }
 
-    pub fn collect_paths<'this>(&'this self, target: &mut HashSet<&'this String>) {
+    pub fn collect_tracers<'this>(&'this self, target: &mut HashMap<&'this String, &'this Tracer>) {
         match self {
             Self::Unknown(tracer) => {
-                target.insert(&tracer.path);
+                target.insert(&tracer.path, self);
             }
             Self::Primitive(tracer) => {
-                target.insert(&tracer.path);
+                target.insert(&tracer.path, self);
             }
             Self::Map(tracer) => {
-                target.insert(&tracer.path);
-                tracer.key_tracer.collect_paths(target);
-                tracer.value_tracer.collect_paths(target)
+                target.insert(&tracer.path, self);
+                tracer.key_tracer.collect_tracers(target);
+                tracer.value_tracer.collect_tracers(target)
             }
             Self::List(tracer) => {
-                target.insert(&tracer.path);
-                tracer.item_tracer.collect_paths(target);
+                target.insert(&tracer.path, self);
+                tracer.item_tracer.collect_tracers(target);
             }
             Self::Struct(tracer) => {
-                target.insert(&tracer.path);
+                target.insert(&tracer.path, self);
                 for field in &tracer.fields {
-                    field.tracer.collect_paths(target);
+                    field.tracer.collect_tracers(target);
                 }
             }
             Self::Union(tracer) => {
-                target.insert(&tracer.path);
+                target.insert(&tracer.path, self);
                 for variant in &tracer.variants {
                     let Some(variant) = variant else {
                         continue;
                     };
-                    variant.tracer.collect_paths(target);
+                    variant.tracer.collect_tracers(target);
                 }
             }
             Self::Tuple(tracer) => {
-                target.insert(&tracer.path);
+                target.insert(&tracer.path, self);
                 for field in &tracer.field_tracers {
-                    field.collect_paths(target);
+                    field.collect_tracers(target);
                 }
             }
         }
@@ -275,21 +359,20 @@ impl Tracer {
             this if matches!(this, Self::Unknown(_))
                 || matches!(this, Self::Primitive(ref tracer) if tracer.item_type == DataType::Null) =>
             {
-                let field_names = fields
-                    .iter()
-                    .map(|field| field.to_string())
-                    .collect::<Vec<_>>();
-                let index = field_names
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, name)| (name.to_string(), idx))
-                    .collect::<HashMap<_, _>>();
+                let field_names = resolve_duplicate_field_names(
+                    fields.iter().map(|field| field.to_string()),
+                    this.get_options().duplicate_fields,
+                )?;
+                let mut index = HashMap::new();
+                for (idx, name) in field_names.iter().enumerate() {
+                    index.entry(name.to_owned()).or_insert(idx);
+                }
 
                 let tracer = dispatch_tracer!(this, tracer => StructTracer {
                     name: tracer.name.clone(),
                     path: tracer.path.clone(),
                     options: tracer.options.clone(),
-                    fields: fields
+                    fields: field_names
                         .iter()
                         .map(|field| {
                             let field = field.to_string();
@@ -380,6 +463,7 @@ impl Tracer {
                         })
                         .collect(),
                     nullable: tracer.nullable,
+                    untagged: false,
                 });
                 *this = Self::Union(tracer);
             }
@@ -456,6 +540,34 @@ impl Tracer {
         Ok(())
     }
 
+    /// Trace a map with non-string keys, that was so far traced as a struct
+    ///
+    /// This is only possible if no string keys were encountered before.
+    pub fn ensure_map_with_non_string_keys(&mut self) -> Result<()> {
+        if let Self::Struct(tracer) = self {
+            if tracer.mode != StructMode::Map
+                || !tracer.fields.is_empty()
+                || tracer.options.get_catch_all(&tracer.path).is_some()
+            {
+                fail!(concat!(
+                    "Cannot trace a map with non-string keys, as it was previously traced as a ",
+                    "struct with string keys. Consider setting `map_as_struct` to `false`.",
+                ));
+            }
+            let nullable = tracer.nullable;
+            let mut tracer = Tracer::new(
+                tracer.name.clone(),
+                tracer.path.clone(),
+                tracer.options.clone(),
+            );
+            if nullable {
+                tracer.mark_nullable();
+            }
+            *self = tracer;
+        }
+        self.ensure_map()
+    }
+
     pub fn ensure_utf8(&mut self, item_type: DataType, strategy: Option<Strategy>) -> Result<()> {
         self.ensure_primitive_with_strategy(item_type, strategy)
     }
@@ -483,9 +595,13 @@ impl Tracer {
                     nullable: tracer.nullable || is_null_type,
                     item_type,
                     strategy,
+                    string_stats: StringStats::default(),
                 });
                 *this = Self::Primitive(tracer);
             }
+            Self::Union(tracer) if tracer.untagged => {
+                tracer.ensure_untagged_primitive(item_type, strategy)?;
+            }
             this @ (Self::List(_)
             | Self::Map(_)
             | Self::Struct(_)
@@ -501,10 +617,34 @@ impl Tracer {
                 }
             }
             Self::Primitive(tracer) => {
+                let policy = tracer.options.get_mixed_type_policy(&tracer.path);
+                if policy == MixedTypePolicy::Union
+                    && is_mixable(&tracer.item_type)
+                    && is_mixable(&item_type)
+                    && !is_compatible(tracer, &item_type, strategy.as_ref())
+                {
+                    let mut union = UnionTracer {
+                        name: tracer.name.clone(),
+                        path: tracer.path.clone(),
+                        options: tracer.options.clone(),
+                        nullable: tracer.nullable,
+                        variants: Vec::new(),
+                        untagged: true,
+                    };
+                    union.ensure_untagged_primitive(
+                        tracer.item_type.clone(),
+                        tracer.strategy.clone(),
+                    )?;
+                    union.ensure_untagged_primitive(item_type, strategy)?;
+                    *self = Self::Union(union);
+                    return Ok(());
+                }
+
                 let (item_type, nullable, strategy) = coerce_primitive_type(
                     (&tracer.item_type, tracer.nullable, tracer.strategy.as_ref()),
                     (item_type, strategy),
                     tracer.options.as_ref(),
+                    policy,
                 )?;
 
                 tracer.item_type = item_type;
@@ -516,22 +656,52 @@ impl Tracer {
     }
 }
 
+impl Tracer {
+    /// Record a string value for the cardinality based dictionary decision
+    pub fn record_string(&mut self, value: &str) {
+        if let Self::Primitive(tracer) = self {
+            if tracer.options.dictionary_threshold.is_some() {
+                tracer.string_stats.record(value);
+            }
+        }
+    }
+}
+
 impl Context for Tracer {
     fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
         dispatch_tracer!(self, tracer => tracer.annotate(annotations))
     }
 }
 
+/// Whether the value can be accepted by the primitive tracer without applying
+/// the mixed type policy
+fn is_compatible(
+    tracer: &PrimitiveTracer,
+    item_type: &DataType,
+    strategy: Option<&Strategy>,
+) -> bool {
+    coerce_primitive_type(
+        (&tracer.item_type, tracer.nullable, tracer.strategy.as_ref()),
+        (item_type.clone(), strategy.cloned()),
+        tracer.options.as_ref(),
+        MixedTypePolicy::Error,
+    )
+    .is_ok()
+}
+
 fn coerce_primitive_type(
     prev: (&DataType, bool, Option<&Strategy>),
     curr: (DataType, Option<Strategy>),
     options: &TracingOptions,
+    policy: MixedTypePolicy,
 ) -> Result<(DataType, bool, Option<Strategy>)> {
     use DataType::{
-        Date64, Float32, Float64, Int16, Int32, Int64, Int8, LargeUtf8, Null, UInt16, UInt32,
-        UInt64, UInt8,
+        Date64, Decimal128, Float32, Float64, Int16, Int32, Int64, Int8, LargeUtf8, Null, UInt16,
+        UInt32, UInt64, UInt8,
     };
 
+    let coerce_numbers = options.coerce_numbers || policy == MixedTypePolicy::WidestNumeric;
+
     let res = match (prev, curr) {
         ((prev_ty, nullable, prev_st), (curr_ty, curr_st))
             if prev_ty == &curr_ty && prev_st == curr_st.as_ref() =>
@@ -544,48 +714,74 @@ fn coerce_primitive_type(
         (
             (UInt8 | UInt16 | UInt32 | UInt64, nullable, _),
             (UInt8 | UInt16 | UInt32 | UInt64, _),
-        ) if options.coerce_numbers => (UInt64, nullable, None),
+        ) if coerce_numbers => (UInt64, nullable, None),
         // signed x signed -> i64
         ((Int8 | Int16 | Int32 | Int64, nullable, _), (Int8 | Int16 | Int32 | Int64, _))
-            if options.coerce_numbers =>
+            if coerce_numbers =>
         {
             (Int64, nullable, None)
         }
         // signed x unsigned -> i64
         ((Int8 | Int16 | Int32 | Int64, nullable, _), (UInt8 | UInt16 | UInt32 | UInt64, _))
-            if options.coerce_numbers =>
+            if coerce_numbers =>
         {
             (Int64, nullable, None)
         }
         // unsigned x signed -> i64
         ((UInt8 | UInt16 | UInt32 | UInt64, nullable, _), (Int8 | Int16 | Int32 | Int64, _))
-            if options.coerce_numbers =>
+            if coerce_numbers =>
         {
             (Int64, nullable, None)
         }
         // float x float -> f64
-        ((Float32 | Float64, nullable, _), (Float32 | Float64, _)) if options.coerce_numbers => {
+        ((Float32 | Float64, nullable, _), (Float32 | Float64, _)) if coerce_numbers => {
             (Float64, nullable, None)
         }
         // int x float -> f64
         (
             (Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64, nullable, _),
             (Float32 | Float64, _),
-        ) if options.coerce_numbers => (Float64, nullable, None),
+        ) if coerce_numbers => (Float64, nullable, None),
         // float x int -> f64
         (
             (Float32 | Float64, nullable, _),
             (Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64, _),
-        ) if options.coerce_numbers => (Float64, nullable, None),
+        ) if coerce_numbers => (Float64, nullable, None),
+        // decimal x decimal -> decimal that fits both
+        ((Decimal128(prev_p, prev_s), nullable, _), (Decimal128(curr_p, curr_s), _))
+            if options.guess_decimals =>
+        {
+            match merge_precision_and_scale((*prev_p, *prev_s), (curr_p, curr_s)) {
+                Some((p, s)) if p <= options.max_decimal_precision => {
+                    (Decimal128(p, s), nullable, None)
+                }
+                _ => (LargeUtf8, nullable, None),
+            }
+        }
         // incompatible formats, coerce to string
+        ((Decimal128(_, _), nullable, _), (LargeUtf8, _)) if options.guess_decimals => {
+            (LargeUtf8, nullable, None)
+        }
+        ((LargeUtf8, nullable, _), (Decimal128(_, _), _)) if options.guess_decimals => {
+            (LargeUtf8, nullable, None)
+        }
         ((Date64, nullable, _), (LargeUtf8, _)) => (LargeUtf8, nullable, None),
         ((LargeUtf8, nullable, _), (Date64, _)) => (LargeUtf8, nullable, None),
         ((Date64, nullable, prev_st), (Date64, curr_st)) if prev_st != curr_st.as_ref() => {
             (LargeUtf8, nullable, None)
         }
+        ((prev_ty, nullable, _), (curr_ty, _))
+            if policy == MixedTypePolicy::StringifyAll
+                && is_mixable(prev_ty)
+                && is_mixable(&curr_ty) =>
+        {
+            (LargeUtf8, nullable, Some(Strategy::Stringify))
+        }
         ((prev_ty, _, prev_st), (curr_ty, curr_st)) => {
             let extra = if is_numeric(prev_ty) && is_numeric(&curr_ty) {
                 ": consider setting `coerce_numbers` to `true` to coerce different numeric types."
+            } else if is_mixable(prev_ty) && is_mixable(&curr_ty) {
+                ": consider setting `mixed_type_policy` to handle fields with mixed types."
             } else {
                 ""
             };
@@ -610,6 +806,11 @@ impl<'a> std::fmt::Display for OptionalStrategyDisplay<'a> {
     }
 }
 
+/// Whether values of this type are subject to the mixed type policy
+fn is_mixable(dt: &DataType) -> bool {
+    is_numeric(dt) || matches!(dt, DataType::Boolean | DataType::Utf8 | DataType::LargeUtf8)
+}
+
 fn is_numeric(dt: &DataType) -> bool {
     matches!(
         dt,
@@ -767,11 +968,17 @@ impl ListTracer {
     }
 
     pub fn to_field(&self) -> Result<Field> {
+        let mut nullable = self.nullable;
+        let mut item_field = self.item_tracer.to_field()?;
+        if let Some(nullability) = self.options.list_nullabilities.get(&self.path) {
+            nullable = nullability.list;
+            item_field.nullable = nullability.elements;
+        }
         Ok(Field {
             name: self.name.to_owned(),
-            nullable: self.nullable,
+            nullable,
             metadata: HashMap::new(),
-            data_type: DataType::LargeList(Box::new(self.item_tracer.to_field()?)),
+            data_type: self.options.default_list_type.to_data_type(item_field),
         })
     }
 
@@ -887,6 +1094,37 @@ impl StructTracer {
     }
 
     pub fn ensure_field(&mut self, key: &str) -> Result<usize> {
+        if !self.is_seen_in_current_sample(key) {
+            return self.ensure_field_unchecked(key);
+        }
+
+        match self.options.duplicate_fields {
+            DuplicateFieldPolicy::Error => fail!("{}", DuplicateFieldMessage(key)),
+            DuplicateFieldPolicy::Merge => self.ensure_field_unchecked(key),
+            DuplicateFieldPolicy::Suffix => {
+                let mut occurrence = 1;
+                loop {
+                    let name = format!("{key}_{occurrence}");
+                    if !self.is_seen_in_current_sample(&name) {
+                        return self.ensure_field_unchecked(&name);
+                    }
+                    occurrence += 1;
+                }
+            }
+        }
+    }
+
+    fn is_seen_in_current_sample(&self, key: &str) -> bool {
+        let Some(&field_idx) = self.index.get(key) else {
+            return false;
+        };
+        let Some(field) = self.fields.get(field_idx) else {
+            return false;
+        };
+        field.last_seen_in_sample == self.seen_samples
+    }
+
+    fn ensure_field_unchecked(&mut self, key: &str) -> Result<usize> {
         if let Some(&field_idx) = self.index.get(key) {
             let Some(field) = self.fields.get_mut(field_idx) else {
                 fail!("Invalid state: no tracer found for field with name {key}");
@@ -917,7 +1155,35 @@ impl StructTracer {
         }
     }
 
+    /// Check whether `key` is collected by a catch-all field
+    pub fn is_catch_all_key(&self, key: &str) -> bool {
+        match self.options.get_catch_all(&self.path) {
+            Some(catch_all) => !catch_all.known_fields.iter().any(|field| field == key),
+            None => false,
+        }
+    }
+
+    /// Get the tracer of the catch-all field, if configured
+    pub fn ensure_catch_all(&mut self) -> Result<Option<&mut MapTracer>> {
+        let Some(catch_all) = self.options.get_catch_all(&self.path) else {
+            return Ok(None);
+        };
+        let name = catch_all.name.clone();
+        let field_idx = self.ensure_field_unchecked(&name)?;
+        let Some(tracer) = self.get_field_tracer_mut(field_idx) else {
+            fail!("Invalid state: no tracer found for field with name {name}");
+        };
+        tracer.ensure_map()?;
+        let Tracer::Map(tracer) = tracer else {
+            fail!("Invalid state: catch-all field {name} is not traced as a map");
+        };
+        tracer.key_tracer.ensure_primitive(DataType::LargeUtf8)?;
+        Ok(Some(tracer))
+    }
+
     pub fn end(&mut self) -> Result<()> {
+        // the catch-all field is present in all samples, possibly empty
+        self.ensure_catch_all()?;
         for field in &mut self.fields {
             // field. was not seen in this sample
             if field.last_seen_in_sample != self.seen_samples {
@@ -937,9 +1203,29 @@ impl StructTracer {
     }
 
     pub fn to_field(&self) -> Result<Field> {
-        let mut fields = Vec::new();
+        let catch_all = self.options.get_catch_all(&self.path);
+        let mut fields: Vec<Field> = Vec::new();
         for field in &self.fields {
-            fields.push(field.tracer.to_field()?);
+            let mut field = field.tracer.to_field()?;
+            if catch_all.is_some_and(|catch_all| catch_all.name == field.name) {
+                field
+                    .metadata
+                    .insert(STRATEGY_KEY.to_string(), Strategy::CatchAll.to_string());
+            }
+
+            // duplicates are only present with `DuplicateFieldPolicy::Merge`
+            if let Some(prev) = fields.iter().find(|prev| prev.name == field.name) {
+                if *prev != field {
+                    fail!(
+                        "Cannot merge duplicate fields {name:?} with different types ({prev}, {curr})",
+                        name = field.name,
+                        prev = DataTypeDisplay(&prev.data_type),
+                        curr = DataTypeDisplay(&field.data_type),
+                    );
+                }
+                continue;
+            }
+            fields.push(field);
         }
 
         let mut metadata = HashMap::new();
@@ -976,6 +1262,9 @@ pub struct UnionTracer {
     pub options: Arc<TracingOptions>,
     pub nullable: bool,
     pub variants: Vec<Option<UnionVariant>>,
+    /// If `true`, the variants were traced from values of different types,
+    /// see [`MixedTypePolicy::Union`]
+    pub untagged: bool,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -1033,6 +1322,51 @@ impl UnionTracer {
         Ok(())
     }
 
+    /// Trace a primitive value of an untagged union
+    ///
+    /// The value is added to the first variant that accepts it. If no variant
+    /// accepts the value, a new variant named after its type is added.
+    pub fn ensure_untagged_primitive(
+        &mut self,
+        item_type: DataType,
+        strategy: Option<Strategy>,
+    ) -> Result<()> {
+        if matches!(item_type, DataType::Null) {
+            self.nullable = true;
+            return Ok(());
+        }
+        if !is_mixable(&item_type) {
+            fail!("Cannot accept {item_type:?} in a union traced from values of mixed types");
+        }
+
+        for variant in self.variants.iter_mut().flatten() {
+            let Tracer::Primitive(tracer) = &mut variant.tracer else {
+                continue;
+            };
+            if let Ok((item_type, nullable, strategy)) = coerce_primitive_type(
+                (&tracer.item_type, tracer.nullable, tracer.strategy.as_ref()),
+                (item_type.clone(), strategy.clone()),
+                tracer.options.as_ref(),
+                MixedTypePolicy::Error,
+            ) {
+                tracer.item_type = item_type;
+                tracer.strategy = strategy;
+                tracer.nullable = nullable;
+                return Ok(());
+            }
+        }
+
+        let name = DataTypeDisplay(&item_type).to_string();
+        let mut tracer = Tracer::new(
+            name.clone(),
+            format!("{path}.{name}", path = self.path),
+            self.options.clone(),
+        );
+        tracer.ensure_primitive_with_strategy(item_type, strategy)?;
+        self.variants.push(Some(UnionVariant { name, tracer }));
+        Ok(())
+    }
+
     pub fn get_path(&self) -> &str {
         &self.path
     }
@@ -1051,7 +1385,11 @@ impl UnionTracer {
     pub fn to_field(&self) -> Result<Field> {
         if self.is_without_data() {
             if self.options.enums_without_data_as_strings {
-                return Ok(default_dictionary_field(&self.name, self.nullable));
+                return Ok(default_dictionary_field(
+                    &self.name,
+                    self.nullable,
+                    &self.options,
+                ));
             }
             if !self.options.allow_null_fields {
                 fail!("{}", EnumWithoutDataMessage(&self.name));
@@ -1066,12 +1404,24 @@ impl UnionTracer {
                 fields.push((i8::try_from(idx)?, unknown_variant_field()));
             };
         }
+        if self.nullable {
+            // unions cannot store nulls directly, use a separate variant
+            fields.push((i8::try_from(fields.len())?, null_variant_field()));
+        }
+
+        let mut metadata = HashMap::new();
+        if self.untagged {
+            metadata.insert(
+                STRATEGY_KEY.to_string(),
+                Strategy::UntaggedUnion.to_string(),
+            );
+        }
 
         Ok(Field {
             name: self.name.to_owned(),
             data_type: DataType::Union(fields, UnionMode::Dense),
             nullable: self.nullable,
-            metadata: HashMap::new(),
+            metadata,
         })
     }
 
@@ -1104,6 +1454,40 @@ pub struct PrimitiveTracer {
     pub nullable: bool,
     pub strategy: Option<Strategy>,
     pub item_type: DataType,
+    pub string_stats: StringStats,
+}
+
+/// The cardinality and size of the string values of a field traced from
+/// samples
+///
+/// Only the hashes of the distinct values are kept to bound the memory
+/// required for fields with many long values.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct StringStats {
+    pub count: usize,
+    /// The total number of bytes of all values
+    pub bytes: usize,
+    /// The total number of bytes of the distinct values
+    pub distinct_bytes: usize,
+    pub distinct: HashSet<u64>,
+}
+
+impl StringStats {
+    pub fn record(&mut self, value: &str) {
+        self.count += 1;
+        self.bytes += value.len();
+        if self.distinct.insert(xxh64(value.as_bytes(), 0)) {
+            self.distinct_bytes += value.len();
+        }
+    }
+
+    /// Whether the ratios of distinct to all values and of the bytes of the
+    /// distinct values to the bytes of all values are at most the threshold
+    pub fn is_below(&self, threshold: f64) -> bool {
+        self.count > 0
+            && (self.distinct.len() as f64) <= threshold * (self.count as f64)
+            && (self.distinct_bytes as f64) <= threshold * (self.bytes as f64)
+    }
 }
 
 impl Context for PrimitiveTracer {
@@ -1132,18 +1516,42 @@ impl PrimitiveTracer {
                 nullable: true,
                 metadata: HashMap::new(),
             }),
-            dt @ (D::LargeUtf8 | D::Utf8) => {
-                if !self.options.string_dictionary_encoding {
+            dt if self.options.get_seconds_type(&self.path).is_some() => self.to_seconds_field(dt),
+            D::LargeUtf8 | D::Utf8 if self.strategy == Some(Strategy::Stringify) => Ok(Field {
+                name: self.name.to_owned(),
+                data_type: self.options.default_string_type.to_data_type(),
+                nullable: self.nullable,
+                metadata: Strategy::Stringify.into(),
+            }),
+            D::LargeUtf8 | D::Utf8 => {
+                let dictionary_encoding = self.options.string_dictionary_encoding
+                    || matches!(
+                        self.options.dictionary_threshold,
+                        Some(threshold) if self.string_stats.is_below(threshold)
+                    );
+                if !dictionary_encoding {
                     Ok(Field {
                         name: self.name.to_owned(),
-                        data_type: dt.clone(),
+                        data_type: self.options.default_string_type.to_data_type(),
                         nullable: self.nullable,
-                        metadata: HashMap::new(),
+                        metadata: self.options.default_string_type.to_metadata(),
                     })
                 } else {
-                    Ok(default_dictionary_field(&self.name, self.nullable))
+                    Ok(default_dictionary_field(
+                        &self.name,
+                        self.nullable,
+                        &self.options,
+                    ))
                 }
             }
+            D::Float16 | D::Float32 | D::Float64 if self.options.is_forced_f16(&self.path) => {
+                Ok(Field {
+                    name: self.name.to_owned(),
+                    data_type: D::Float16,
+                    nullable: self.nullable,
+                    metadata: HashMap::new(),
+                })
+            }
             dt => {
                 let mut metadata = HashMap::new();
                 if let Some(strategy) = self.strategy.as_ref() {
@@ -1160,6 +1568,34 @@ impl PrimitiveTracer {
     }
 }
 
+impl PrimitiveTracer {
+    /// Build a duration or timestamp field for values traced as seconds, see
+    /// [`TracingOptions::seconds_at`]
+    fn to_seconds_field(&self, data_type: &DataType) -> Result<Field> {
+        type D = DataType;
+
+        let unit = match data_type {
+            D::Int8 | D::Int16 | D::Int32 | D::Int64 => TimeUnit::Second,
+            D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64 => TimeUnit::Second,
+            D::Float16 | D::Float32 | D::Float64 | D::Utf8 | D::LargeUtf8 => TimeUnit::Microsecond,
+            dt => fail!(
+                "Cannot trace {dt} values as seconds, only numbers and strings are supported",
+                dt = DataTypeDisplay(dt),
+            ),
+        };
+        let data_type = match self.options.get_seconds_type(&self.path) {
+            Some(SecondsType::Timestamp) => D::Timestamp(unit, Some(String::from("UTC"))),
+            _ => D::Duration(unit),
+        };
+        Ok(Field {
+            name: self.name.to_owned(),
+            data_type,
+            nullable: self.nullable,
+            metadata: Strategy::Seconds.into(),
+        })
+    }
+}
+
 impl PrimitiveTracer {
     pub fn get_path(&self) -> &str {
         &self.path
@@ -2,7 +2,11 @@ use std::collections::HashMap;
 
 use serde::Serialize;
 
-use crate::internal::{arrow::Field, error::Result, schema::transmute_field};
+use crate::internal::{
+    arrow::{DataType, Field},
+    error::Result,
+    schema::{transmute_field, Strategy},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TracingMode {
@@ -11,6 +15,122 @@ pub enum TracingMode {
     FromSamples,
 }
 
+/// Configure how duplicate field names in structs are handled during tracing
+///
+/// Duplicate field names can occur, e.g., when multiple fields are renamed to
+/// the same name or when maps with repeated keys are traced as structs.
+///
+/// When serializing, the policy is configured via
+/// [`SerializerOptions::duplicate_fields`][crate::SerializerOptions::duplicate_fields].
+/// Use the same policy as for tracing to serialize the traced items.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DuplicateFieldPolicy {
+    /// Fail with an error (the default)
+    #[default]
+    Error,
+    /// Append the index of the occurrence to duplicate names, e.g., `a`,
+    /// `a_1`, `a_2`. When serializing, repeated values are written to the
+    /// first suffixed field without a value. Values without such a field are
+    /// ignored, as are unknown fields.
+    Suffix,
+    /// Merge duplicate fields into a single field, if their types are
+    /// identical. Fail with an error otherwise. When serializing, the first
+    /// value is written and repeated values are ignored.
+    Merge,
+}
+
+/// Configure how fields with values of different primitive types are traced
+///
+/// Mixed types are common in loosely typed data, e.g., JSON with fields that
+/// contain both strings and integers. The policy only applies to primitive
+/// values (booleans, numbers and strings). See
+/// [`TracingOptions::mixed_type_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MixedTypePolicy {
+    /// Fail with an error (the default)
+    Error,
+    /// Trace the field as a dense union with one child per type, marked with
+    /// the [`UntaggedUnion`][crate::schema::Strategy::UntaggedUnion] strategy
+    Union,
+    /// Trace the field as a string, marked with the
+    /// [`Stringify`][crate::schema::Strategy::Stringify] strategy
+    StringifyAll,
+    /// Coerce different numeric types to the widest numeric type with the
+    /// rules of [`TracingOptions::coerce_numbers`]. Fail with an error for
+    /// other types.
+    WidestNumeric,
+}
+
+/// The data type used for strings during tracing
+///
+/// See [`TracingOptions::default_string_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StringType {
+    /// Strings with 32 bit offsets
+    Utf8,
+    /// Strings with 64 bit offsets (the default)
+    LargeUtf8,
+    /// `LargeUtf8` fields with the
+    /// [`AdaptiveString`][crate::schema::Strategy::AdaptiveString] strategy,
+    /// built as `Utf8` arrays if the data fits into 32 bit offsets
+    Adaptive,
+}
+
+impl StringType {
+    pub(crate) fn to_data_type(self) -> DataType {
+        match self {
+            Self::Utf8 => DataType::Utf8,
+            Self::LargeUtf8 | Self::Adaptive => DataType::LargeUtf8,
+        }
+    }
+
+    /// The metadata of traced string fields
+    pub(crate) fn to_metadata(self) -> HashMap<String, String> {
+        match self {
+            Self::Utf8 | Self::LargeUtf8 => HashMap::new(),
+            Self::Adaptive => Strategy::AdaptiveString.into(),
+        }
+    }
+}
+
+/// The data type of fields holding seconds, see [`TracingOptions::seconds_at`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SecondsType {
+    /// `Duration` fields, e.g., for `serde_with::DurationSeconds`
+    Duration,
+    /// `Timestamp` fields in UTC, e.g., for `serde_with::TimestampSeconds`
+    Timestamp,
+}
+
+/// The data type used for sequences during tracing
+///
+/// See [`TracingOptions::default_list_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ListType {
+    /// Lists with 32 bit offsets
+    List,
+    /// Lists with 64 bit offsets (the default)
+    LargeList,
+    /// Lists with the given number of elements. Serializing sequences of a
+    /// different length results in an error.
+    FixedSizeList(i32),
+}
+
+impl ListType {
+    pub(crate) fn to_data_type(self, item: Field) -> DataType {
+        match self {
+            Self::List => DataType::List(Box::new(item)),
+            Self::LargeList => DataType::LargeList(Box::new(item)),
+            Self::FixedSizeList(n) => DataType::FixedSizeList(Box::new(item), n),
+        }
+    }
+}
+
 /// Configure how the schema is traced
 ///
 /// Example:
@@ -25,16 +145,25 @@ pub enum TracingMode {
 /// The defaults are:
 ///
 /// ```rust
-/// # use serde_arrow::schema::TracingOptions;
+/// # use serde_arrow::schema::{DuplicateFieldPolicy, ListType, MixedTypePolicy, StringType, TracingOptions};
 /// assert_eq!(
 ///     TracingOptions::default(),
 ///     TracingOptions::new()
 ///         .allow_null_fields(false)
 ///         .map_as_struct(true)
 ///         .string_dictionary_encoding(false)
+///         .dictionary_threshold(None)
 ///         .coerce_numbers(false)
 ///         .guess_dates(false)
-///         .from_type_budget(100),
+///         .guess_decimals(false)
+///         .max_decimal_precision(38)
+///         .from_type_budget(100)
+///         .duplicate_fields(DuplicateFieldPolicy::Error)
+///         .mixed_type_policy(MixedTypePolicy::Error)
+///         .default_string_type(StringType::LargeUtf8)
+///         .default_list_type(ListType::LargeList)
+///         .max_samples(None)
+///         .stable_samples(None),
 /// );
 /// ```
 #[derive(Debug, Clone, PartialEq)]
@@ -47,17 +176,42 @@ pub struct TracingOptions {
 
     /// If `true` serialize maps as structs (the default). See
     /// [`Strategy::MapAsStruct`][crate::schema::Strategy] for details.
+    ///
+    /// Maps with non-string keys (e.g., integers) cannot be represented as
+    /// structs. They are traced as `Map` fields with the key type inferred
+    /// from the samples, as long as no string keys were encountered for the
+    /// same field before.
+    ///
+    /// Structs cannot distinguish missing keys from keys with null values:
+    /// both are deserialized as null entries. Use `Map` fields, e.g., via
+    /// [`map_as_struct_at`][TracingOptions::map_as_struct_at], if this
+    /// distinction matters.
     pub map_as_struct: bool,
 
     /// If `true` serialize strings dictionary encoded. The default is `false`.
     ///
     /// If `true`, strings are traced as `Dictionary(UInt32, LargeUtf8)`. If
-    /// `false`, strings are traced as `LargeUtf8`.
+    /// `false`, strings are traced as `LargeUtf8`. The value type follows
+    /// [`default_string_type`](#structfield.default_string_type).
     ///
     /// Note: the 32 bit offsets are chosen, as they are supported by the
     /// default polars package.
     pub string_dictionary_encoding: bool,
 
+    /// If given, choose the dictionary encoding of string fields traced from
+    /// samples based on their cardinality. The default is `None`.
+    ///
+    /// A string field is traced as a dictionary, if the ratio of its distinct
+    /// values to the number of its values is at most the threshold. E.g., a
+    /// threshold of `0.5` dictionary encodes fields, in which each value
+    /// occurs on average at least twice. The same bound applies to the ratio
+    /// of the bytes of the distinct values to the bytes of all values, to
+    /// avoid dictionaries dominated by long unique values. Fields without any
+    /// string values are not dictionary encoded. The threshold has no effect if
+    /// [`string_dictionary_encoding`](#structfield.string_dictionary_encoding)
+    /// is `true` and is ignored when tracing from types.
+    pub dictionary_threshold: Option<f64>,
+
     /// If `true`, coerce different numeric types.
     ///
     /// This option may be helpful when dealing with data formats that do not
@@ -83,6 +237,23 @@ pub struct TracingOptions {
     /// [`UtcStrAsDate64`][crate::schema::Strategy::UtcStrAsDate64].
     pub guess_dates: bool,
 
+    /// If `true`, try to auto detect decimals in string columns
+    ///
+    /// Strings of the form `[+-]digits.digits` (e.g., `"12.50"`, the default
+    /// serialization of `rust_decimal::Decimal`) are understood. For string
+    /// fields where all values are either missing or decimals, the data type is
+    /// set to `Decimal128(precision, scale)` with the minimal precision and
+    /// scale that fit all samples. If more than
+    /// [`max_decimal_precision`](#structfield.max_decimal_precision) digits
+    /// are required, the field is traced as a string.
+    pub guess_decimals: bool,
+
+    /// The maximum precision of decimals detected with
+    /// [`guess_decimals`](#structfield.guess_decimals)
+    ///
+    /// The default is 38, the maximum precision supported by `Decimal128`.
+    pub max_decimal_precision: u8,
+
     /// How many tracing iterations to perform in `from_type`.
     ///
     /// The default value may be too conservative for deeply nested types or
@@ -124,6 +295,47 @@ pub struct TracingOptions {
     /// ```
     pub enums_without_data_as_strings: bool,
 
+    /// How to handle duplicate field names in structs
+    ///
+    /// See [`DuplicateFieldPolicy`] for the available options. Per default,
+    /// tracing fails for structs with duplicate field names.
+    pub duplicate_fields: DuplicateFieldPolicy,
+
+    /// How to handle fields with values of different primitive types
+    ///
+    /// See [`MixedTypePolicy`] for the available options. Per default,
+    /// tracing fails for fields with incompatible types. The policy of
+    /// individual fields can be set with
+    /// [`mixed_type_policy_at`](#method.mixed_type_policy_at).
+    pub mixed_type_policy: MixedTypePolicy,
+
+    /// The data type used for strings, the default is `LargeUtf8`
+    ///
+    /// Setting it to [`StringType::Utf8`] avoids per-field overwrites when
+    /// targeting engines that expect 32 bit offsets.
+    pub default_string_type: StringType,
+
+    /// The data type used for sequences, the default is `LargeList`
+    ///
+    /// Overwrites still take precedence for individual fields.
+    pub default_list_type: ListType,
+
+    /// The maximum number of samples to trace in `from_samples`, the default
+    /// is `None` (all samples)
+    ///
+    /// Remaining samples are skipped without being traced. Note that fields
+    /// only present in skipped samples are not part of the schema.
+    pub max_samples: Option<usize>,
+
+    /// Stop tracing in `from_samples` once the schema did not change for the
+    /// given number of consecutive samples, the default is `None` (never stop
+    /// early)
+    ///
+    /// Use
+    /// [`SerdeArrowSchema::from_samples_with_count`][crate::schema::SerdeArrowSchema::from_samples_with_count]
+    /// to query how many samples were traced.
+    pub stable_samples: Option<usize>,
+
     /// A mapping of field paths to field definitions
     ///
     /// Overwrites can be added with `options.overwrite(path, field)`. The
@@ -207,6 +419,24 @@ pub struct TracingOptions {
     ///
     pub overwrites: Overwrites,
 
+    /// Catch-all fields for unknown keys, by the path of their parent
+    pub(crate) catch_alls: HashMap<String, CatchAll>,
+
+    /// Paths of fields whose float values are traced as `Float16`
+    pub(crate) f16_paths: Vec<String>,
+
+    /// Mixed type policies of individual fields, by their path
+    pub(crate) mixed_type_policies: HashMap<String, MixedTypePolicy>,
+
+    /// Overwrites of `map_as_struct` for individual fields, by their path
+    pub(crate) map_as_struct_paths: HashMap<String, bool>,
+
+    /// Explicit nullability of list fields and their elements, by their path
+    pub(crate) list_nullabilities: HashMap<String, ListNullability>,
+
+    /// Fields whose values are traced as seconds, by their path
+    pub(crate) seconds_fields: HashMap<String, SecondsType>,
+
     /// Internal field to improve error messages for the different tracing
     /// functions
     pub(crate) tracing_mode: TracingMode,
@@ -218,11 +448,26 @@ impl Default for TracingOptions {
             allow_null_fields: false,
             map_as_struct: true,
             string_dictionary_encoding: false,
+            dictionary_threshold: None,
             coerce_numbers: false,
             guess_dates: false,
+            guess_decimals: false,
+            max_decimal_precision: 38,
             from_type_budget: 100,
             enums_without_data_as_strings: false,
+            duplicate_fields: DuplicateFieldPolicy::Error,
+            mixed_type_policy: MixedTypePolicy::Error,
+            default_string_type: StringType::LargeUtf8,
+            default_list_type: ListType::LargeList,
+            max_samples: None,
+            stable_samples: None,
             overwrites: Overwrites::default(),
+            catch_alls: HashMap::new(),
+            f16_paths: Vec::new(),
+            mixed_type_policies: HashMap::new(),
+            map_as_struct_paths: HashMap::new(),
+            list_nullabilities: HashMap::new(),
+            seconds_fields: HashMap::new(),
             tracing_mode: TracingMode::Unknown,
         }
     }
@@ -251,6 +496,12 @@ impl TracingOptions {
         self
     }
 
+    /// Set [`dictionary_threshold`](#structfield.dictionary_threshold)
+    pub fn dictionary_threshold(mut self, value: Option<f64>) -> Self {
+        self.dictionary_threshold = value;
+        self
+    }
+
     /// Set [`coerce_numbers`](#structfield.coerce_numbers)
     pub fn coerce_numbers(mut self, value: bool) -> Self {
         self.coerce_numbers = value;
@@ -263,6 +514,18 @@ impl TracingOptions {
         self
     }
 
+    /// Set [`guess_decimals`](#structfield.guess_decimals)
+    pub fn guess_decimals(mut self, value: bool) -> Self {
+        self.guess_decimals = value;
+        self
+    }
+
+    /// Set [`max_decimal_precision`](#structfield.max_decimal_precision)
+    pub fn max_decimal_precision(mut self, value: u8) -> Self {
+        self.max_decimal_precision = value;
+        self
+    }
+
     /// Set [`from_type_budget`](#structfield.from_type_budget)
     pub fn from_type_budget(mut self, value: usize) -> Self {
         self.from_type_budget = value;
@@ -275,6 +538,42 @@ impl TracingOptions {
         self
     }
 
+    /// Set [`duplicate_fields`](#structfield.duplicate_fields)
+    pub fn duplicate_fields(mut self, value: DuplicateFieldPolicy) -> Self {
+        self.duplicate_fields = value;
+        self
+    }
+
+    /// Set [`mixed_type_policy`](#structfield.mixed_type_policy)
+    pub fn mixed_type_policy(mut self, value: MixedTypePolicy) -> Self {
+        self.mixed_type_policy = value;
+        self
+    }
+
+    /// Set [`default_string_type`](#structfield.default_string_type)
+    pub fn default_string_type(mut self, value: StringType) -> Self {
+        self.default_string_type = value;
+        self
+    }
+
+    /// Set [`default_list_type`](#structfield.default_list_type)
+    pub fn default_list_type(mut self, value: ListType) -> Self {
+        self.default_list_type = value;
+        self
+    }
+
+    /// Set [`max_samples`](#structfield.max_samples)
+    pub fn max_samples(mut self, value: Option<usize>) -> Self {
+        self.max_samples = value;
+        self
+    }
+
+    /// Set [`stable_samples`](#structfield.stable_samples)
+    pub fn stable_samples(mut self, value: Option<usize>) -> Self {
+        self.stable_samples = value;
+        self
+    }
+
     /// Add an overwrite to [`overwrites`](#structfield.overwrites)
     pub fn overwrite<P: Into<String>, F: Serialize>(mut self, path: P, field: F) -> Result<Self> {
         self.overwrites.0.insert(
@@ -284,6 +583,240 @@ impl TracingOptions {
         Ok(self)
     }
 
+    /// Collect unknown keys of a map traced as a struct into a map field
+    ///
+    /// This option supports the `#[serde(flatten)] extra: HashMap<String, T>`
+    /// pattern in [`from_samples`][crate::schema::SchemaLike::from_samples].
+    /// `path` is the dotted path of the catch-all field, e.g., `"extra"` or
+    /// `"foo.extra"`. All keys of the parent not listed in `known_fields` are
+    /// traced as the entries of a `Map` field with the
+    /// [`CatchAll`][crate::schema::Strategy::CatchAll] strategy, instead of as
+    /// individual fields.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// # use std::collections::HashMap;
+    /// use serde::Serialize;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    ///
+    /// ##[derive(Serialize)]
+    /// struct Record {
+    ///     id: u32,
+    ///     #[serde(flatten)]
+    ///     extra: HashMap<String, String>,
+    /// }
+    ///
+    /// let items = vec![Record {
+    ///     id: 0,
+    ///     extra: HashMap::from([(String::from("color"), String::from("red"))]),
+    /// }];
+    /// let options = TracingOptions::default().catch_all("extra", &["id"]);
+    /// let schema = SerdeArrowSchema::from_samples(&items, options)?;
+    ///
+    /// let schema = serde_json::to_value(&schema).unwrap();
+    /// assert_eq!(schema["fields"][0]["name"], "extra");
+    /// assert_eq!(schema["fields"][0]["data_type"], "Map");
+    /// assert_eq!(schema["fields"][0]["strategy"], "CatchAll");
+    /// assert_eq!(schema["fields"][1]["name"], "id");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn catch_all<P: Into<String>>(mut self, path: P, known_fields: &[&str]) -> Self {
+        let path = format!("$.{path}", path = path.into());
+        let (parent, name) = path.rsplit_once('.').unwrap_or(("$", path.as_str()));
+        self.catch_alls.insert(
+            parent.to_owned(),
+            CatchAll {
+                name: name.to_owned(),
+                known_fields: known_fields.iter().map(|&field| field.to_owned()).collect(),
+            },
+        );
+        self
+    }
+
+    /// Trace the float values of a field as `Float16`
+    ///
+    /// `path` is the dotted path of the field, e.g., `"embedding"` or
+    /// `"foo.embedding"`. The option applies to `f32` and `f64` values of the
+    /// field itself and of any nested field, e.g., the elements of a list.
+    /// When serializing, the values are converted according to
+    /// [`SerializerOptions::f16_rounding`][crate::SerializerOptions::f16_rounding]
+    /// and [`SerializerOptions::f16_overflow`][crate::SerializerOptions::f16_overflow].
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde::Serialize;
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    ///
+    /// ##[derive(Serialize)]
+    /// struct Record {
+    ///     id: u32,
+    ///     embedding: Vec<f32>,
+    /// }
+    ///
+    /// let items = vec![Record { id: 0, embedding: vec![0.5, -1.25] }];
+    /// let options = TracingOptions::default().force_f16("embedding");
+    /// let schema = SerdeArrowSchema::from_samples(&items, options)?;
+    ///
+    /// let schema = serde_json::to_value(&schema).unwrap();
+    /// assert_eq!(schema["fields"][1]["children"][0]["data_type"], "F16");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn force_f16<P: Into<String>>(mut self, path: P) -> Self {
+        self.f16_paths.push(format!("$.{path}", path = path.into()));
+        self
+    }
+
+    /// Use a different [`MixedTypePolicy`] for a single field
+    ///
+    /// `path` is the dotted path of the field, e.g., `"value"` or
+    /// `"foo.value"`. The policy applies to the values of the field itself,
+    /// but not to nested fields.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_arrow::schema::{MixedTypePolicy, SchemaLike, SerdeArrowSchema, TracingOptions};
+    ///
+    /// let items = serde_json::json!([
+    ///     {"id": 1, "value": 13},
+    ///     {"id": 2, "value": "foo"},
+    /// ]);
+    /// let options = TracingOptions::default()
+    ///     .mixed_type_policy_at("value", MixedTypePolicy::StringifyAll);
+    /// let schema = SerdeArrowSchema::from_samples(&items, options)?;
+    ///
+    /// let schema = serde_json::to_value(&schema).unwrap();
+    /// assert_eq!(schema["fields"][1]["data_type"], "LargeUtf8");
+    /// assert_eq!(schema["fields"][1]["strategy"], "Stringify");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mixed_type_policy_at<P: Into<String>>(
+        mut self,
+        path: P,
+        policy: MixedTypePolicy,
+    ) -> Self {
+        self.mixed_type_policies
+            .insert(format!("$.{path}", path = path.into()), policy);
+        self
+    }
+
+    /// Set whether the maps of a single field are traced as structs
+    ///
+    /// `path` is the dotted path of the field, e.g., `"tags"` or
+    /// `"foo.tags"`. The setting overwrites
+    /// [`map_as_struct`](#structfield.map_as_struct) for this field, but not
+    /// for nested fields. Tracing a field as a `Map` keeps the difference
+    /// between missing keys, null values, and empty lists in round trips.
+    /// Map values are addressed as `"tags.value"`, e.g., to configure
+    /// [`list_nullability`][TracingOptions::list_nullability].
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    ///
+    /// let items = serde_json::json!([
+    ///     {"meta": {"a": 1}, "tags": {"a": [], "b": null}},
+    ///     {"meta": {"a": 2}, "tags": {"c": ["x"]}},
+    /// ]);
+    /// let options = TracingOptions::default().map_as_struct_at("tags", false);
+    /// let schema = SerdeArrowSchema::from_samples(&items, options)?;
+    ///
+    /// let schema = serde_json::to_value(&schema).unwrap();
+    /// assert_eq!(schema["fields"][0]["data_type"], "Struct");
+    /// assert_eq!(schema["fields"][1]["data_type"], "Map");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn map_as_struct_at<P: Into<String>>(mut self, path: P, value: bool) -> Self {
+        self.map_as_struct_paths
+            .insert(format!("$.{path}", path = path.into()), value);
+        self
+    }
+
+    /// Set the nullability of a list field and of its elements explicitly
+    ///
+    /// `path` is the dotted path of the field, e.g., `"values"` or
+    /// `"foo.values"`. Samples often cannot distinguish between a nullable
+    /// list (`Option<Vec<T>>`) and a list with nullable elements
+    /// (`Vec<Option<T>>`), e.g., if no nulls are observed or if JSON values
+    /// are traced. With this option, `list` determines whether the list itself
+    /// is nullable and `elements` whether its elements are nullable,
+    /// independent of the traced values. Values violating the configured
+    /// nullability result in an error when serializing. Paths not referring
+    /// to a traced list field result in an error when tracing.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_arrow::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+    ///
+    /// let items = serde_json::json!([
+    ///     {"values": [1, 2]},
+    ///     {"values": [3]},
+    /// ]);
+    /// let options = TracingOptions::default().list_nullability("values", true, false);
+    /// let schema = SerdeArrowSchema::from_samples(&items, options)?;
+    ///
+    /// let schema = serde_json::to_value(&schema).unwrap();
+    /// assert_eq!(schema["fields"][0]["nullable"], true);
+    /// assert_eq!(schema["fields"][0]["children"][0].get("nullable"), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_nullability<P: Into<String>>(
+        mut self,
+        path: P,
+        list: bool,
+        elements: bool,
+    ) -> Self {
+        self.list_nullabilities.insert(
+            format!("$.{path}", path = path.into()),
+            ListNullability { list, elements },
+        );
+        self
+    }
+
+    /// Trace the values of a field as seconds of a duration or a timestamp
+    ///
+    /// `path` is the dotted path of the field, e.g., `"timeout"` or
+    /// `"foo.timeout"`. Adapters such as `serde_with::DurationSeconds` or
+    /// `serde_with::TimestampSeconds` serialize durations and timestamps as
+    /// plain numbers or strings, which are otherwise traced as numeric or
+    /// string fields. With this option, the field is traced as a `Duration`
+    /// or a `Timestamp` field in UTC with the
+    /// [`Seconds`][crate::schema::Strategy::Seconds] strategy. Integer values
+    /// result in second resolution, floats and strings (e.g., of
+    /// `DurationSecondsWithFrac`) in microsecond resolution. Other values
+    /// result in an error when tracing.
+    ///
+    /// ```rust
+    /// # fn main() -> serde_arrow::Result<()> {
+    /// use serde_arrow::schema::{SchemaLike, SecondsType, SerdeArrowSchema, TracingOptions};
+    ///
+    /// let items = serde_json::json!([
+    ///     {"timeout": 30, "created": 1700000000.5},
+    ///     {"timeout": 60, "created": 1700000060.0},
+    /// ]);
+    /// let options = TracingOptions::default()
+    ///     .seconds_at("timeout", SecondsType::Duration)
+    ///     .seconds_at("created", SecondsType::Timestamp);
+    /// let schema = SerdeArrowSchema::from_samples(&items, options)?;
+    ///
+    /// let schema = serde_json::to_value(&schema).unwrap();
+    /// assert_eq!(schema["fields"][0]["data_type"], "Timestamp(Microsecond, Some(\"UTC\"))");
+    /// assert_eq!(schema["fields"][0]["strategy"], "Seconds");
+    /// assert_eq!(schema["fields"][1]["data_type"], "Duration(Second)");
+    /// assert_eq!(schema["fields"][1]["strategy"], "Seconds");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn seconds_at<P: Into<String>>(mut self, path: P, seconds_type: SecondsType) -> Self {
+        self.seconds_fields
+            .insert(format!("$.{path}", path = path.into()), seconds_type);
+        self
+    }
+
     pub(crate) fn tracing_mode(mut self, value: TracingMode) -> Self {
         self.tracing_mode = value;
         self
@@ -292,6 +825,49 @@ impl TracingOptions {
     pub(crate) fn get_overwrite(&self, path: &str) -> Option<&Field> {
         self.overwrites.0.get(path)
     }
+
+    pub(crate) fn get_catch_all(&self, parent_path: &str) -> Option<&CatchAll> {
+        self.catch_alls.get(parent_path)
+    }
+
+    pub(crate) fn get_mixed_type_policy(&self, path: &str) -> MixedTypePolicy {
+        self.mixed_type_policies
+            .get(path)
+            .copied()
+            .unwrap_or(self.mixed_type_policy)
+    }
+
+    pub(crate) fn get_map_as_struct(&self, path: &str) -> bool {
+        self.map_as_struct_paths
+            .get(path)
+            .copied()
+            .unwrap_or(self.map_as_struct)
+    }
+
+    pub(crate) fn get_seconds_type(&self, path: &str) -> Option<SecondsType> {
+        self.seconds_fields.get(path).copied()
+    }
+
+    pub(crate) fn is_forced_f16(&self, path: &str) -> bool {
+        self.f16_paths.iter().any(|f16_path| {
+            path.strip_prefix(f16_path.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
+    }
+}
+
+/// The configuration of a catch-all field, see [`TracingOptions::catch_all`]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CatchAll {
+    pub name: String,
+    pub known_fields: Vec<String>,
+}
+
+/// The nullability of a list field, see [`TracingOptions::list_nullability`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ListNullability {
+    pub list: bool,
+    pub elements: bool,
 }
 
 /// An opaque mapping of field paths to field definitions
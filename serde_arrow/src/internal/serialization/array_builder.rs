@@ -6,20 +6,36 @@ use serde::Serialize;
 use crate::internal::{
     arrow::Array,
     error::{Context, Result},
+    serializer_options::StringLengthLimit,
 };
 
 use super::{
-    binary_builder::BinaryBuilder, bool_builder::BoolBuilder, date32_builder::Date32Builder,
-    date64_builder::Date64Builder, decimal_builder::DecimalBuilder,
-    dictionary_utf8_builder::DictionaryUtf8Builder, duration_builder::DurationBuilder,
+    binary_builder::BinaryBuilder, bool_builder::BoolBuilder, byte_budget::ByteBudget,
+    custom_builder::CustomBuilder, dictionary_utf8_builder::DictionaryUtf8Builder,
     fixed_size_binary_builder::FixedSizeBinaryBuilder,
     fixed_size_list_builder::FixedSizeListBuilder, float_builder::FloatBuilder,
     int_builder::IntBuilder, list_builder::ListBuilder, map_builder::MapBuilder,
     null_builder::NullBuilder, simple_serializer::SimpleSerializer, struct_builder::StructBuilder,
-    time_builder::TimeBuilder, union_builder::UnionBuilder,
-    unknown_variant_builder::UnknownVariantBuilder, utf8_builder::Utf8Builder,
+    utf8_builder::Utf8Builder,
 };
 
+#[cfg(feature = "types-decimal")]
+use super::{decimal256_builder::Decimal256Builder, decimal_builder::DecimalBuilder};
+
+#[cfg(feature = "types-temporal")]
+use super::{
+    date32_builder::Date32Builder, date64_builder::Date64Builder,
+    duration_builder::DurationBuilder, interval_builder::IntervalBuilder,
+    time_builder::TimeBuilder,
+};
+#[cfg(feature = "types-temporal")]
+use crate::internal::arrow::{DayTimeInterval, MonthDayNanoInterval};
+
+#[cfg(feature = "types-union")]
+use super::{union_builder::UnionBuilder, unknown_variant_builder::UnknownVariantBuilder};
+#[cfg(feature = "types-union")]
+use crate::internal::arrow::UnionMode;
+
 #[derive(Debug, Clone)]
 pub enum ArrayBuilder {
     Null(NullBuilder),
@@ -35,12 +51,26 @@ pub enum ArrayBuilder {
     F16(FloatBuilder<f16>),
     F32(FloatBuilder<f32>),
     F64(FloatBuilder<f64>),
+    #[cfg(feature = "types-temporal")]
     Date32(Date32Builder),
+    #[cfg(feature = "types-temporal")]
     Date64(Date64Builder),
+    #[cfg(feature = "types-temporal")]
     Time32(TimeBuilder<i32>),
+    #[cfg(feature = "types-temporal")]
     Time64(TimeBuilder<i64>),
+    #[cfg(feature = "types-temporal")]
     Duration(DurationBuilder),
+    #[cfg(feature = "types-temporal")]
+    IntervalYearMonth(IntervalBuilder<i32>),
+    #[cfg(feature = "types-temporal")]
+    IntervalDayTime(IntervalBuilder<DayTimeInterval>),
+    #[cfg(feature = "types-temporal")]
+    IntervalMonthDayNano(IntervalBuilder<MonthDayNanoInterval>),
+    #[cfg(feature = "types-decimal")]
     Decimal128(DecimalBuilder),
+    #[cfg(feature = "types-decimal")]
+    Decimal256(Decimal256Builder),
     List(ListBuilder<i32>),
     LargeList(ListBuilder<i64>),
     FixedSizedList(FixedSizeListBuilder),
@@ -52,8 +82,11 @@ pub enum ArrayBuilder {
     Utf8(Utf8Builder<i32>),
     LargeUtf8(Utf8Builder<i64>),
     DictionaryUtf8(DictionaryUtf8Builder),
+    #[cfg(feature = "types-union")]
     Union(UnionBuilder),
+    #[cfg(feature = "types-union")]
     UnknownVariant(UnknownVariantBuilder),
+    Custom(CustomBuilder),
 }
 
 macro_rules! dispatch {
@@ -72,12 +105,26 @@ macro_rules! dispatch {
             $wrapper::F16($name) => $expr,
             $wrapper::F32($name) => $expr,
             $wrapper::F64($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Date32($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Date64($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Time32($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Time64($name) => $expr,
+            #[cfg(feature = "types-temporal")]
             $wrapper::Duration($name) => $expr,
+            #[cfg(feature = "types-temporal")]
+            $wrapper::IntervalYearMonth($name) => $expr,
+            #[cfg(feature = "types-temporal")]
+            $wrapper::IntervalDayTime($name) => $expr,
+            #[cfg(feature = "types-temporal")]
+            $wrapper::IntervalMonthDayNano($name) => $expr,
+            #[cfg(feature = "types-decimal")]
             $wrapper::Decimal128($name) => $expr,
+            #[cfg(feature = "types-decimal")]
+            $wrapper::Decimal256($name) => $expr,
             $wrapper::Utf8($name) => $expr,
             $wrapper::LargeUtf8($name) => $expr,
             $wrapper::List($name) => $expr,
@@ -89,8 +136,11 @@ macro_rules! dispatch {
             $wrapper::Map($name) => $expr,
             $wrapper::Struct($name) => $expr,
             $wrapper::DictionaryUtf8($name) => $expr,
+            #[cfg(feature = "types-union")]
             $wrapper::Union($name) => $expr,
+            #[cfg(feature = "types-union")]
             $wrapper::UnknownVariant($name) => $expr,
+            $wrapper::Custom($name) => $expr,
         }
     };
 }
@@ -100,9 +150,85 @@ impl ArrayBuilder {
         dispatch!(self, Self(builder) => builder.is_nullable())
     }
 
+    /// The approximate number of bytes of the buffered values
+    pub fn byte_size(&self) -> usize {
+        dispatch!(self, Self(builder) => builder.byte_size())
+    }
+
+    /// Reserve capacity for at least `additional` more values, where supported
+    pub fn reserve(&mut self, additional: usize) {
+        match self {
+            Self::I8(builder) => builder.reserve(additional),
+            Self::I16(builder) => builder.reserve(additional),
+            Self::I32(builder) => builder.reserve(additional),
+            Self::I64(builder) => builder.reserve(additional),
+            Self::U8(builder) => builder.reserve(additional),
+            Self::U16(builder) => builder.reserve(additional),
+            Self::U32(builder) => builder.reserve(additional),
+            Self::U64(builder) => builder.reserve(additional),
+            Self::F16(builder) => builder.reserve(additional),
+            Self::F32(builder) => builder.reserve(additional),
+            Self::F64(builder) => builder.reserve(additional),
+            Self::Utf8(builder) => builder.reserve(additional),
+            Self::LargeUtf8(builder) => builder.reserve(additional),
+            Self::Binary(builder) => builder.reserve(additional),
+            Self::LargeBinary(builder) => builder.reserve(additional),
+            Self::List(builder) => builder.reserve(additional),
+            Self::LargeList(builder) => builder.reserve(additional),
+            Self::Struct(builder) => builder.reserve(additional),
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => builder.reserve(additional),
+            _ => {}
+        }
+    }
+
     pub fn into_array(self) -> Result<Array> {
         dispatch!(self, Self(builder) => builder.into_array())
     }
+
+    /// The approximate number of bits written per value, excluding variable
+    /// sized data (string and binary bytes, list elements and map entries)
+    pub fn fixed_bits(&self) -> usize {
+        let validity = usize::from(self.is_nullable());
+        let values = match self {
+            Self::Null(_) => 0,
+            #[cfg(feature = "types-union")]
+            Self::UnknownVariant(_) => 0,
+            Self::Bool(_) => 1,
+            Self::I8(_) | Self::U8(_) => 8,
+            Self::I16(_) | Self::U16(_) | Self::F16(_) => 16,
+            Self::I32(_) | Self::U32(_) | Self::F32(_) => 32,
+            Self::I64(_) | Self::U64(_) | Self::F64(_) => 64,
+            #[cfg(feature = "types-temporal")]
+            Self::Date32(_) | Self::Time32(_) | Self::IntervalYearMonth(_) => 32,
+            #[cfg(feature = "types-temporal")]
+            Self::Date64(_) | Self::Time64(_) | Self::Duration(_) | Self::IntervalDayTime(_) => 64,
+            #[cfg(feature = "types-temporal")]
+            Self::IntervalMonthDayNano(_) => 128,
+            #[cfg(feature = "types-decimal")]
+            Self::Decimal128(_) => 128,
+            #[cfg(feature = "types-decimal")]
+            Self::Decimal256(_) => 256,
+            Self::Utf8(_) | Self::Binary(_) | Self::List(_) | Self::Map(_) => 32,
+            Self::LargeUtf8(_) | Self::LargeBinary(_) | Self::LargeList(_) => 64,
+            Self::FixedSizeBinary(builder) => 8 * builder.n,
+            Self::FixedSizedList(builder) => builder.n * builder.element.fixed_bits(),
+            Self::Struct(builder) => builder.fields.iter().map(|(f, _)| f.fixed_bits()).sum(),
+            Self::DictionaryUtf8(builder) => return builder.indices.fixed_bits(),
+            // type ids and offsets, dense unions write a single child per
+            // value, sparse unions all children
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                let children = builder.fields.iter().map(|(f, _)| f.fixed_bits());
+                match builder.mode {
+                    UnionMode::Dense => 8 + 32 + children.max().unwrap_or_default(),
+                    UnionMode::Sparse => 8 + children.sum::<usize>(),
+                }
+            }
+            Self::Custom(builder) => return builder.storage.fixed_bits(),
+        };
+        validity + values
+    }
 }
 
 impl ArrayBuilder {
@@ -111,6 +237,333 @@ impl ArrayBuilder {
     }
 }
 
+impl ArrayBuilder {
+    /// Call `func` for this builder and all its children that are dictionary builders
+    pub fn for_each_dictionary(&self, func: &mut dyn FnMut(&DictionaryUtf8Builder)) {
+        match self {
+            Self::DictionaryUtf8(builder) => func(builder),
+            Self::List(builder) => builder.element.for_each_dictionary(func),
+            Self::LargeList(builder) => builder.element.for_each_dictionary(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_dictionary(func),
+            Self::Map(builder) => builder.entry.for_each_dictionary(func),
+            Self::Struct(builder) => {
+                for (child, _) in &builder.fields {
+                    child.for_each_dictionary(func);
+                }
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &builder.fields {
+                    child.for_each_dictionary(func);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Call `func` for this builder and all its children that are dictionary builders
+    pub fn for_each_dictionary_mut(
+        &mut self,
+        func: &mut dyn FnMut(&mut DictionaryUtf8Builder) -> Result<()>,
+    ) -> Result<()> {
+        match self {
+            Self::DictionaryUtf8(builder) => func(builder),
+            Self::List(builder) => builder.element.for_each_dictionary_mut(func),
+            Self::LargeList(builder) => builder.element.for_each_dictionary_mut(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_dictionary_mut(func),
+            Self::Map(builder) => builder.entry.for_each_dictionary_mut(func),
+            Self::Struct(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_dictionary_mut(func)?;
+                }
+                Ok(())
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_dictionary_mut(func)?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Call `func` for all struct builders, including nested ones
+    pub fn for_each_struct_mut(&mut self, func: &mut dyn FnMut(&mut StructBuilder)) {
+        match self {
+            Self::List(builder) => builder.element.for_each_struct_mut(func),
+            Self::LargeList(builder) => builder.element.for_each_struct_mut(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_struct_mut(func),
+            Self::Map(builder) => builder.entry.for_each_struct_mut(func),
+            Self::Struct(builder) => {
+                func(builder);
+                for (child, _) in &mut builder.fields {
+                    child.for_each_struct_mut(func);
+                }
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_struct_mut(func);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Call `func` for all map builders, including nested ones
+    pub fn for_each_map_mut(&mut self, func: &mut dyn FnMut(&mut MapBuilder)) {
+        match self {
+            Self::List(builder) => builder.element.for_each_map_mut(func),
+            Self::LargeList(builder) => builder.element.for_each_map_mut(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_map_mut(func),
+            Self::Map(builder) => {
+                func(builder);
+                builder.entry.for_each_map_mut(func);
+            }
+            Self::Struct(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_map_mut(func);
+                }
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_map_mut(func);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Call `func` for all `Float16` builders, including nested ones
+    pub fn for_each_f16_mut(&mut self, func: &mut dyn FnMut(&mut FloatBuilder<f16>)) {
+        match self {
+            Self::F16(builder) => func(builder),
+            Self::List(builder) => builder.element.for_each_f16_mut(func),
+            Self::LargeList(builder) => builder.element.for_each_f16_mut(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_f16_mut(func),
+            Self::Map(builder) => builder.entry.for_each_f16_mut(func),
+            Self::Struct(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_f16_mut(func);
+                }
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_f16_mut(func);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Call `func` for all `LargeUtf8` builders, including nested ones
+    pub fn for_each_large_utf8_mut(&mut self, func: &mut dyn FnMut(&mut Utf8Builder<i64>)) {
+        match self {
+            Self::LargeUtf8(builder) => func(builder),
+            Self::List(builder) => builder.element.for_each_large_utf8_mut(func),
+            Self::LargeList(builder) => builder.element.for_each_large_utf8_mut(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_large_utf8_mut(func),
+            Self::Map(builder) => builder.entry.for_each_large_utf8_mut(func),
+            Self::Struct(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_large_utf8_mut(func);
+                }
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_large_utf8_mut(func);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(feature = "types-temporal")]
+    /// Call `func` for all `Date64` and `Timestamp` builders, including nested ones
+    pub fn for_each_date64(&self, func: &mut dyn FnMut(&Date64Builder)) {
+        match self {
+            Self::Date64(builder) => func(builder),
+            Self::List(builder) => builder.element.for_each_date64(func),
+            Self::LargeList(builder) => builder.element.for_each_date64(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_date64(func),
+            Self::Map(builder) => builder.entry.for_each_date64(func),
+            Self::Struct(builder) => {
+                for (child, _) in &builder.fields {
+                    child.for_each_date64(func);
+                }
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &builder.fields {
+                    child.for_each_date64(func);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(feature = "types-union")]
+    /// Call `func` for all union builders, including nested ones
+    pub fn for_each_union_mut(&mut self, func: &mut dyn FnMut(&mut UnionBuilder)) {
+        match self {
+            Self::List(builder) => builder.element.for_each_union_mut(func),
+            Self::LargeList(builder) => builder.element.for_each_union_mut(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_union_mut(func),
+            Self::Map(builder) => builder.entry.for_each_union_mut(func),
+            Self::Struct(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_union_mut(func);
+                }
+            }
+            Self::Union(builder) => {
+                func(builder);
+                for (child, _) in &mut builder.fields {
+                    child.for_each_union_mut(func);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(feature = "types-temporal")]
+    /// Call `func` for all `Date64` and `Timestamp` builders, including nested ones
+    pub fn for_each_date64_mut(&mut self, func: &mut dyn FnMut(&mut Date64Builder)) {
+        match self {
+            Self::Date64(builder) => func(builder),
+            Self::List(builder) => builder.element.for_each_date64_mut(func),
+            Self::LargeList(builder) => builder.element.for_each_date64_mut(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_date64_mut(func),
+            Self::Map(builder) => builder.entry.for_each_date64_mut(func),
+            Self::Struct(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_date64_mut(func);
+                }
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_date64_mut(func);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl ArrayBuilder {
+    /// Call `func` with the path and the length limit of all string builders,
+    /// including nested ones
+    pub fn for_each_string_limit_mut(
+        &mut self,
+        func: &mut dyn FnMut(&str, &mut Option<StringLengthLimit>),
+    ) {
+        match self {
+            Self::Utf8(builder) => {
+                let (path, limit) = builder.path_and_limit_mut();
+                func(path, limit)
+            }
+            Self::LargeUtf8(builder) => {
+                let (path, limit) = builder.path_and_limit_mut();
+                func(path, limit)
+            }
+            Self::DictionaryUtf8(builder) => {
+                let (path, limit) = builder.path_and_limit_mut();
+                func(path, limit)
+            }
+            Self::List(builder) => builder.element.for_each_string_limit_mut(func),
+            Self::LargeList(builder) => builder.element.for_each_string_limit_mut(func),
+            Self::FixedSizedList(builder) => builder.element.for_each_string_limit_mut(func),
+            Self::Map(builder) => builder.entry.for_each_string_limit_mut(func),
+            Self::Struct(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_string_limit_mut(func);
+                }
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.for_each_string_limit_mut(func);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl ArrayBuilder {
+    /// Count the buffered bytes of this builder and all its children with the
+    /// given budget
+    pub fn set_byte_budget(&mut self, budget: Option<&ByteBudget>) {
+        match self {
+            Self::Utf8(builder) => builder.budget = budget.cloned(),
+            Self::LargeUtf8(builder) => builder.budget = budget.cloned(),
+            Self::Binary(builder) => builder.budget = budget.cloned(),
+            Self::LargeBinary(builder) => builder.budget = budget.cloned(),
+            Self::List(builder) => {
+                builder.element.set_byte_budget(budget);
+                builder.budget = budget.map(|b| (b.clone(), builder.element.fixed_bits()));
+            }
+            Self::LargeList(builder) => {
+                builder.element.set_byte_budget(budget);
+                builder.budget = budget.map(|b| (b.clone(), builder.element.fixed_bits()));
+            }
+            Self::FixedSizedList(builder) => builder.element.set_byte_budget(budget),
+            Self::Map(builder) => {
+                builder.entry.set_byte_budget(budget);
+                builder.budget = budget.map(|b| (b.clone(), builder.entry.fixed_bits()));
+            }
+            Self::DictionaryUtf8(builder) => builder.values.set_byte_budget(budget),
+            Self::Struct(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.set_byte_budget(budget);
+                }
+            }
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => {
+                for (child, _) in &mut builder.fields {
+                    child.set_byte_budget(budget);
+                }
+            }
+            Self::Custom(builder) => builder.storage.set_byte_budget(budget),
+            _ => {}
+        }
+    }
+}
+
+impl ArrayBuilder {
+    /// Find the builder of the field with the given path, including nested
+    /// ones
+    pub fn find_mut(&mut self, path: &str) -> Option<&mut ArrayBuilder> {
+        let mut annotations = BTreeMap::new();
+        self.annotate(&mut annotations);
+        if annotations.get("field").map(String::as_str) == Some(path) {
+            return Some(self);
+        }
+
+        match self {
+            Self::List(builder) => builder.element.find_mut(path),
+            Self::LargeList(builder) => builder.element.find_mut(path),
+            Self::FixedSizedList(builder) => builder.element.find_mut(path),
+            Self::Map(builder) => builder.entry.find_mut(path),
+            Self::Struct(builder) => builder
+                .fields
+                .iter_mut()
+                .find_map(|(child, _)| child.find_mut(path)),
+            #[cfg(feature = "types-union")]
+            Self::Union(builder) => builder
+                .fields
+                .iter_mut()
+                .find_map(|(child, _)| child.find_mut(path)),
+            _ => None,
+        }
+    }
+}
+
 impl Context for ArrayBuilder {
     fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
         dispatch!(self, Self(builder) => builder.annotate(annotations))
@@ -131,6 +584,14 @@ impl SimpleSerializer for ArrayBuilder {
         dispatch!(self, Self(builder) => builder.serialize_none())
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        dispatch!(self, Self(builder) => builder.serialize_defaults(n))
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        dispatch!(self, Self(builder) => builder.serialize_nones(n))
+    }
+
     fn serialize_some<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
         dispatch!(self, Self(builder) => builder.serialize_some(value))
     }
@@ -4,20 +4,24 @@ use serde::Serialize;
 
 use crate::internal::{
     arrow::{Array, BytesArray},
-    error::{set_default, Context, ContextSupport, Result},
+    error::{set_default, try_, Context, ContextSupport, Result},
     utils::{
         array_ext::{new_bytes_array, ArrayExt, ScalarArrayExt, SeqArrayExt},
         Mut, NamedType, Offset,
     },
 };
 
-use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+use super::{
+    array_builder::ArrayBuilder, byte_budget::ByteBudget, simple_serializer::SimpleSerializer,
+};
 
 #[derive(Debug, Clone)]
 
 pub struct BinaryBuilder<O> {
     path: String,
     array: BytesArray<O>,
+    /// The count of the buffered bytes, if limited
+    pub budget: Option<ByteBudget>,
 }
 
 impl<O: Offset> BinaryBuilder<O> {
@@ -25,6 +29,7 @@ impl<O: Offset> BinaryBuilder<O> {
         Self {
             path,
             array: new_bytes_array(is_nullable),
+            budget: None,
         }
     }
 
@@ -32,12 +37,21 @@ impl<O: Offset> BinaryBuilder<O> {
         Self {
             path: self.path.clone(),
             array: self.array.take(),
+            budget: self.budget.clone(),
         }
     }
 
     pub fn is_nullable(&self) -> bool {
         self.array.validity.is_some()
     }
+
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.array.reserve(additional);
+    }
 }
 
 impl BinaryBuilder<i32> {
@@ -69,6 +83,7 @@ impl<O: Offset> BinaryBuilder<O> {
         let mut u8_serializer = U8Serializer(0);
         value.serialize(Mut(&mut u8_serializer))?;
 
+        self.reserve_bytes(1)?;
         self.array.data.push(u8_serializer.0);
         self.array.push_seq_elements(1)
     }
@@ -76,6 +91,13 @@ impl<O: Offset> BinaryBuilder<O> {
     fn end(&mut self) -> Result<()> {
         Ok(())
     }
+
+    fn reserve_bytes(&self, bytes: usize) -> Result<()> {
+        match &self.budget {
+            Some(budget) => budget.reserve_bytes(bytes),
+            None => Ok(()),
+        }
+    }
 }
 
 impl<O: NamedType> Context for BinaryBuilder<O> {
@@ -102,6 +124,14 @@ impl<O: NamedType + Offset> SimpleSerializer for BinaryBuilder<O> {
         self.array.push_scalar_none().ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        self.array.push_scalar_defaults(n).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        self.array.push_scalar_nones(n).ctx(self)
+    }
+
     fn serialize_seq_start(&mut self, _: Option<usize>) -> Result<()> {
         self.start().ctx(self)
     }
@@ -139,7 +169,11 @@ impl<O: NamedType + Offset> SimpleSerializer for BinaryBuilder<O> {
     }
 
     fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
-        self.array.push_scalar_value(v).ctx(self)
+        try_(|| {
+            self.reserve_bytes(v.len())?;
+            self.array.push_scalar_value(v)
+        })
+        .ctx(self)
     }
 }
 
@@ -3,7 +3,10 @@ use std::collections::BTreeMap;
 use crate::internal::{
     arrow::{Array, BooleanArray},
     error::{set_default, try_, Context, ContextSupport, Result},
-    utils::array_ext::{set_bit_buffer, set_validity, set_validity_default},
+    utils::array_ext::{
+        clear_bit_buffer, set_bit_buffer, set_validity, set_validity_default,
+        set_validity_defaults, set_validity_nulls, validity_size,
+    },
 };
 
 use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
@@ -41,6 +44,10 @@ impl BoolBuilder {
         self.array.validity.is_some()
     }
 
+    pub fn byte_size(&self) -> usize {
+        validity_size(self.array.validity.as_ref()) + self.array.values.len()
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::Boolean(self.array))
     }
@@ -74,6 +81,26 @@ impl SimpleSerializer for BoolBuilder {
         .ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| {
+            set_validity_defaults(self.array.validity.as_mut(), self.array.len, n);
+            clear_bit_buffer(&mut self.array.values, self.array.len, n);
+            self.array.len += n;
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| {
+            set_validity_nulls(self.array.validity.as_mut(), self.array.len, n)?;
+            clear_bit_buffer(&mut self.array.values, self.array.len, n);
+            self.array.len += n;
+            Ok(())
+        })
+        .ctx(self)
+    }
+
     fn serialize_bool(&mut self, v: bool) -> Result<()> {
         try_(|| {
             set_validity(self.array.validity.as_mut(), self.array.len, true)?;
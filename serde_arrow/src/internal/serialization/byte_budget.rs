@@ -0,0 +1,81 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use crate::internal::error::{fail, Result};
+
+/// A running count of the buffered bytes, see
+/// [`SerializerOptions::max_bytes`][crate::SerializerOptions::max_bytes]
+///
+/// The count is shared by all builders of a batch. Variable sized data
+/// (string and binary bytes, list elements and map entries) is reserved by
+/// the builders before it is written, fixed sized values are counted after
+/// each row. The count is kept in bits to account for validity bitmaps.
+#[derive(Debug, Clone)]
+pub struct ByteBudget {
+    max_bytes: usize,
+    bits: Arc<AtomicUsize>,
+}
+
+impl ByteBudget {
+    pub fn new(max_bytes: usize, bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            bits: Arc::new(AtomicUsize::new(bytes.saturating_mul(8))),
+        }
+    }
+
+    /// A copy of the budget with the same count that is counted separately
+    pub fn detach(&self) -> Self {
+        Self {
+            max_bytes: self.max_bytes,
+            bits: Arc::new(AtomicUsize::new(self.bits.load(Ordering::Relaxed))),
+        }
+    }
+
+    /// Set the count to the given number of bytes
+    pub fn reset(&self, bytes: usize) {
+        self.bits.store(bytes.saturating_mul(8), Ordering::Relaxed);
+    }
+
+    /// The number of counted bytes
+    pub fn bytes(&self) -> usize {
+        self.bits.load(Ordering::Relaxed).div_ceil(8)
+    }
+
+    /// Count the given number of bits without checking the limit
+    pub fn add_bits(&self, bits: usize) {
+        let _ = self
+            .bits
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some(current.saturating_add(bits))
+            });
+    }
+
+    /// Count the given number of bits and fail if the limit is exceeded
+    ///
+    /// The bits are counted even if the limit is exceeded, to fail subsequent
+    /// writes until the count is reset.
+    pub fn reserve_bits(&self, bits: usize) -> Result<()> {
+        self.add_bits(bits);
+        self.check()
+    }
+
+    /// Count the given number of bytes and fail if the limit is exceeded
+    pub fn reserve_bytes(&self, bytes: usize) -> Result<()> {
+        self.reserve_bits(bytes.saturating_mul(8))
+    }
+
+    /// Fail if the counted bytes exceed the limit
+    pub fn check(&self) -> Result<()> {
+        let bytes = self.bytes();
+        if bytes > self.max_bytes {
+            fail!(
+                "The buffered data of {bytes} bytes exceeds the limit of {max_bytes} bytes",
+                max_bytes = self.max_bytes,
+            );
+        }
+        Ok(())
+    }
+}
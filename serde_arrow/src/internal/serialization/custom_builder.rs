@@ -0,0 +1,522 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::internal::{
+    arrow::Array,
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::Mut,
+};
+
+use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+
+/// A user defined builder for the values of a single field
+///
+/// Custom builders allow to support types that cannot be expressed with the
+/// builtin builders, e.g., extension arrays. They are attached to a field via
+/// [`ArrayBuilder::with_custom_builder`][crate::ArrayBuilder::with_custom_builder].
+/// The serde calls for the field are forwarded to the custom builder, which
+/// writes the resulting values into the storage of the field via
+/// [`CustomStorage`]. The storage is built from the data type of the field.
+///
+/// Compound values are forwarded as a stream of calls: after
+/// [`serialize_seq_start`][CustomArrayBuilder::serialize_seq_start], each
+/// element is forwarded to the same builder, followed by
+/// [`serialize_seq_end`][CustomArrayBuilder::serialize_seq_end]. Tuples are
+/// forwarded as sequences. Struct fields are announced by
+/// [`serialize_struct_field`][CustomArrayBuilder::serialize_struct_field]
+/// before their value, map keys and values are forwarded alternately.
+/// Options, newtype structs and newtype variants are forwarded as their inner
+/// value. By default, all calls except nulls and defaults fail.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::Serialize;
+/// use serde_arrow::{schema::SchemaLike, ArrayBuilder, CustomArrayBuilder, CustomStorage};
+///
+/// /// Write the components of a struct as a fixed size list
+/// #[derive(Debug, Clone, Default)]
+/// struct Components(Vec<f64>);
+///
+/// impl CustomArrayBuilder for Components {
+///     fn clone_box(&self) -> Box<dyn CustomArrayBuilder> {
+///         Box::new(Components::default())
+///     }
+///
+///     fn serialize_struct_start(
+///         &mut self,
+///         _: &mut CustomStorage<'_>,
+///         _: &'static str,
+///         _: usize,
+///     ) -> serde_arrow::Result<()> {
+///         self.0.clear();
+///         Ok(())
+///     }
+///
+///     fn serialize_struct_field(
+///         &mut self,
+///         _: &mut CustomStorage<'_>,
+///         _: &'static str,
+///     ) -> serde_arrow::Result<()> {
+///         Ok(())
+///     }
+///
+///     fn serialize_f64(&mut self, _: &mut CustomStorage<'_>, v: f64) -> serde_arrow::Result<()> {
+///         self.0.push(v);
+///         Ok(())
+///     }
+///
+///     fn serialize_struct_end(&mut self, storage: &mut CustomStorage<'_>) -> serde_arrow::Result<()> {
+///         storage.push(&self.0)
+///     }
+/// }
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     point: Point,
+/// }
+///
+/// ##[derive(Serialize)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let fields = Vec::<FieldRef>::from_value(serde_json::json!([
+///     {"name": "point", "data_type": "FixedSizeList(2)", "children": [
+///         {"name": "element", "data_type": "F64"},
+///     ]},
+/// ]))?;
+///
+/// let mut builder = ArrayBuilder::from_arrow(&fields)?
+///     .with_custom_builder("$.point", Components::default());
+/// builder.push(Record { point: Point { x: 1.0, y: 2.0 } })?;
+/// let arrays = builder.to_arrow()?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+#[allow(unused_variables)]
+pub trait CustomArrayBuilder: std::fmt::Debug + Send + Sync {
+    /// Construct a new builder with the same configuration
+    ///
+    /// It is used when the builders are cloned or when the arrays are taken
+    /// from the builder.
+    fn clone_box(&self) -> Box<dyn CustomArrayBuilder>;
+
+    /// Write the default value for missing fields
+    fn serialize_default(&mut self, storage: &mut CustomStorage<'_>) -> Result<()> {
+        storage.push_default()
+    }
+
+    /// Write a null value
+    fn serialize_none(&mut self, storage: &mut CustomStorage<'_>) -> Result<()> {
+        storage.push_none()
+    }
+
+    /// Write a unit value, by default as a null value
+    fn serialize_unit(&mut self, storage: &mut CustomStorage<'_>) -> Result<()> {
+        self.serialize_none(storage)
+    }
+
+    /// Write a boolean
+    fn serialize_bool(&mut self, storage: &mut CustomStorage<'_>, v: bool) -> Result<()> {
+        fail!("serialize_bool is not supported")
+    }
+
+    /// Write an `i8`
+    fn serialize_i8(&mut self, storage: &mut CustomStorage<'_>, v: i8) -> Result<()> {
+        fail!("serialize_i8 is not supported")
+    }
+
+    /// Write an `i16`
+    fn serialize_i16(&mut self, storage: &mut CustomStorage<'_>, v: i16) -> Result<()> {
+        fail!("serialize_i16 is not supported")
+    }
+
+    /// Write an `i32`
+    fn serialize_i32(&mut self, storage: &mut CustomStorage<'_>, v: i32) -> Result<()> {
+        fail!("serialize_i32 is not supported")
+    }
+
+    /// Write an `i64`
+    fn serialize_i64(&mut self, storage: &mut CustomStorage<'_>, v: i64) -> Result<()> {
+        fail!("serialize_i64 is not supported")
+    }
+
+    /// Write a `u8`
+    fn serialize_u8(&mut self, storage: &mut CustomStorage<'_>, v: u8) -> Result<()> {
+        fail!("serialize_u8 is not supported")
+    }
+
+    /// Write a `u16`
+    fn serialize_u16(&mut self, storage: &mut CustomStorage<'_>, v: u16) -> Result<()> {
+        fail!("serialize_u16 is not supported")
+    }
+
+    /// Write a `u32`
+    fn serialize_u32(&mut self, storage: &mut CustomStorage<'_>, v: u32) -> Result<()> {
+        fail!("serialize_u32 is not supported")
+    }
+
+    /// Write a `u64`
+    fn serialize_u64(&mut self, storage: &mut CustomStorage<'_>, v: u64) -> Result<()> {
+        fail!("serialize_u64 is not supported")
+    }
+
+    /// Write an `f32`
+    fn serialize_f32(&mut self, storage: &mut CustomStorage<'_>, v: f32) -> Result<()> {
+        fail!("serialize_f32 is not supported")
+    }
+
+    /// Write an `f64`
+    fn serialize_f64(&mut self, storage: &mut CustomStorage<'_>, v: f64) -> Result<()> {
+        fail!("serialize_f64 is not supported")
+    }
+
+    /// Write a `char`
+    fn serialize_char(&mut self, storage: &mut CustomStorage<'_>, v: char) -> Result<()> {
+        fail!("serialize_char is not supported")
+    }
+
+    /// Write a string
+    fn serialize_str(&mut self, storage: &mut CustomStorage<'_>, v: &str) -> Result<()> {
+        fail!("serialize_str is not supported")
+    }
+
+    /// Write bytes
+    fn serialize_bytes(&mut self, storage: &mut CustomStorage<'_>, v: &[u8]) -> Result<()> {
+        fail!("serialize_bytes is not supported")
+    }
+
+    /// Write a unit struct
+    fn serialize_unit_struct(
+        &mut self,
+        storage: &mut CustomStorage<'_>,
+        name: &'static str,
+    ) -> Result<()> {
+        fail!("serialize_unit_struct is not supported")
+    }
+
+    /// Write an enum variant without data
+    fn serialize_unit_variant(
+        &mut self,
+        storage: &mut CustomStorage<'_>,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        fail!("serialize_unit_variant is not supported")
+    }
+
+    /// Start a sequence or tuple, the elements are forwarded to this builder
+    fn serialize_seq_start(
+        &mut self,
+        storage: &mut CustomStorage<'_>,
+        len: Option<usize>,
+    ) -> Result<()> {
+        fail!("serialize_seq_start is not supported")
+    }
+
+    /// End a sequence or tuple
+    fn serialize_seq_end(&mut self, storage: &mut CustomStorage<'_>) -> Result<()> {
+        fail!("serialize_seq_end is not supported")
+    }
+
+    /// Start a struct
+    fn serialize_struct_start(
+        &mut self,
+        storage: &mut CustomStorage<'_>,
+        name: &'static str,
+        len: usize,
+    ) -> Result<()> {
+        fail!("serialize_struct_start is not supported")
+    }
+
+    /// Announce a struct field, its value is forwarded to this builder
+    fn serialize_struct_field(
+        &mut self,
+        storage: &mut CustomStorage<'_>,
+        key: &'static str,
+    ) -> Result<()> {
+        fail!("serialize_struct_field is not supported")
+    }
+
+    /// End a struct
+    fn serialize_struct_end(&mut self, storage: &mut CustomStorage<'_>) -> Result<()> {
+        fail!("serialize_struct_end is not supported")
+    }
+
+    /// Start a map, the keys and values are forwarded to this builder
+    fn serialize_map_start(
+        &mut self,
+        storage: &mut CustomStorage<'_>,
+        len: Option<usize>,
+    ) -> Result<()> {
+        fail!("serialize_map_start is not supported")
+    }
+
+    /// End a map
+    fn serialize_map_end(&mut self, storage: &mut CustomStorage<'_>) -> Result<()> {
+        fail!("serialize_map_end is not supported")
+    }
+}
+
+/// The storage of a field with a [`CustomArrayBuilder`]
+///
+/// Values are written with the builtin builder for the data type of the
+/// field.
+pub struct CustomStorage<'a>(&'a mut ArrayBuilder);
+
+impl<'a> CustomStorage<'a> {
+    /// Write a value to the storage
+    pub fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(Mut(&mut *self.0))
+    }
+
+    /// Write a null value to the storage
+    pub fn push_none(&mut self) -> Result<()> {
+        self.0.serialize_none()
+    }
+
+    /// Write the default value of the data type to the storage
+    pub fn push_default(&mut self) -> Result<()> {
+        self.0.serialize_default()
+    }
+}
+
+impl std::fmt::Debug for CustomStorage<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CustomStorage {{ .. }}")
+    }
+}
+
+#[derive(Debug)]
+pub struct CustomBuilder {
+    path: String,
+    pub builder: Box<dyn CustomArrayBuilder>,
+    pub storage: Box<ArrayBuilder>,
+}
+
+impl Clone for CustomBuilder {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            builder: self.builder.clone_box(),
+            storage: self.storage.clone(),
+        }
+    }
+}
+
+impl CustomBuilder {
+    pub fn new(path: String, builder: Box<dyn CustomArrayBuilder>, storage: ArrayBuilder) -> Self {
+        Self {
+            path,
+            builder,
+            storage: Box::new(storage),
+        }
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::Custom(Self {
+            path: self.path.clone(),
+            builder: self.builder.clone_box(),
+            storage: Box::new(self.storage.take()),
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.storage.is_nullable()
+    }
+
+    pub fn byte_size(&self) -> usize {
+        self.storage.byte_size()
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        self.storage.into_array()
+    }
+
+    fn forward(
+        &mut self,
+        func: impl FnOnce(&mut dyn CustomArrayBuilder, &mut CustomStorage<'_>) -> Result<()>,
+    ) -> Result<()> {
+        let mut storage = CustomStorage(self.storage.as_mut());
+        try_(|| func(self.builder.as_mut(), &mut storage)).ctx(self)
+    }
+}
+
+impl Context for CustomBuilder {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "Custom");
+    }
+}
+
+impl SimpleSerializer for CustomBuilder {
+    fn serialize_default(&mut self) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_default(storage))
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_none(storage))
+    }
+
+    fn serialize_unit(&mut self) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_unit(storage))
+    }
+
+    fn serialize_bool(&mut self, v: bool) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_bool(storage, v))
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_i8(storage, v))
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_i16(storage, v))
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_i32(storage, v))
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_i64(storage, v))
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_u8(storage, v))
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_u16(storage, v))
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_u32(storage, v))
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_u64(storage, v))
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_f32(storage, v))
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_f64(storage, v))
+    }
+
+    fn serialize_char(&mut self, v: char) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_char(storage, v))
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_str(storage, v))
+    }
+
+    fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_bytes(storage, v))
+    }
+
+    fn serialize_unit_struct(&mut self, name: &'static str) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_unit_struct(storage, name))
+    }
+
+    fn serialize_unit_variant(
+        &mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.forward(|builder, storage| {
+            builder.serialize_unit_variant(storage, name, variant_index, variant)
+        })
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        &mut self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        value.serialize(Mut(self))
+    }
+
+    fn serialize_seq_start(&mut self, len: Option<usize>) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_seq_start(storage, len))
+    }
+
+    fn serialize_seq_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(Mut(self))
+    }
+
+    fn serialize_seq_end(&mut self) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_seq_end(storage))
+    }
+
+    fn serialize_tuple_start(&mut self, len: usize) -> Result<()> {
+        self.serialize_seq_start(Some(len))
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(Mut(self))
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        self.serialize_seq_end()
+    }
+
+    fn serialize_tuple_struct_start(&mut self, _: &'static str, len: usize) -> Result<()> {
+        self.serialize_seq_start(Some(len))
+    }
+
+    fn serialize_tuple_struct_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(Mut(self))
+    }
+
+    fn serialize_tuple_struct_end(&mut self) -> Result<()> {
+        self.serialize_seq_end()
+    }
+
+    fn serialize_struct_start(&mut self, name: &'static str, len: usize) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_struct_start(storage, name, len))
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_struct_field(storage, key))?;
+        value.serialize(Mut(self))
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_struct_end(storage))
+    }
+
+    fn serialize_map_start(&mut self, len: Option<usize>) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_map_start(storage, len))
+    }
+
+    fn serialize_map_key<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<()> {
+        key.serialize(Mut(self))
+    }
+
+    fn serialize_map_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(Mut(self))
+    }
+
+    fn serialize_map_end(&mut self) -> Result<()> {
+        self.forward(|builder, storage| builder.serialize_map_end(storage))
+    }
+}
@@ -35,6 +35,10 @@ impl Date32Builder {
         self.array.validity.is_some()
     }
 
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::Date32(self.array))
     }
@@ -66,6 +70,14 @@ impl SimpleSerializer for Date32Builder {
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
     fn serialize_str(&mut self, v: &str) -> Result<()> {
         try_(|| {
             let days_since_epoch = self.parse_str_to_days_since_epoch(v)?;
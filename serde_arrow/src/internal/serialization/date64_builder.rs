@@ -2,7 +2,9 @@ use std::collections::BTreeMap;
 
 use crate::internal::{
     arrow::{Array, PrimitiveArray, TimeUnit, TimestampArray},
+    chrono::{self as chrono_ext, parse_time_crate_datetime},
     error::{fail, set_default, try_, Context, ContextSupport, Result},
+    serializer_options::UtcOffsetPolicy,
     utils::array_ext::{new_primitive_array, ArrayExt, ScalarArrayExt},
 };
 
@@ -14,6 +16,16 @@ pub struct Date64Builder {
     pub meta: Option<(TimeUnit, Option<String>)>,
     pub utc: bool,
     pub array: PrimitiveArray<i64>,
+    /// How non-zero UTC offsets are handled, only used if `utc` is `true`
+    pub offset_policy: UtcOffsetPolicy,
+    /// The number of values with non-zero offsets, counted across batches
+    pub non_utc_offsets: usize,
+    /// If `true`, numbers and numeric strings are interpreted as seconds since
+    /// the UNIX epoch
+    pub seconds: bool,
+    /// If `true`, strings in the formats of the `time` crate are accepted, see
+    /// [`Strategy::TimeCrateStr`][crate::schema::Strategy::TimeCrateStr]
+    pub time_crate_str: bool,
 }
 
 impl Date64Builder {
@@ -28,22 +40,48 @@ impl Date64Builder {
             meta,
             utc,
             array: new_primitive_array(is_nullable),
+            offset_policy: UtcOffsetPolicy::Normalize,
+            non_utc_offsets: 0,
+            seconds: false,
+            time_crate_str: false,
         }
     }
 
+    pub fn with_seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    pub fn with_time_crate_str(mut self, time_crate_str: bool) -> Self {
+        self.time_crate_str = time_crate_str;
+        self
+    }
+
     pub fn take(&mut self) -> ArrayBuilder {
         ArrayBuilder::Date64(Self {
             path: self.path.clone(),
             meta: self.meta.clone(),
             utc: self.utc,
             array: self.array.take(),
+            offset_policy: self.offset_policy,
+            non_utc_offsets: self.non_utc_offsets,
+            seconds: self.seconds,
+            time_crate_str: self.time_crate_str,
         })
     }
 
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
     pub fn is_nullable(&self) -> bool {
         self.array.validity.is_some()
     }
 
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+
     pub fn into_array(self) -> Result<Array> {
         if let Some((unit, timezone)) = self.meta {
             Ok(Array::Timestamp(TimestampArray {
@@ -62,13 +100,37 @@ impl Date64Builder {
 }
 
 impl Date64Builder {
-    fn parse_str_to_timestamp(&self, s: &str) -> Result<i64> {
-        use chrono::{DateTime, NaiveDateTime, Utc};
+    fn parse_str_to_timestamp(&mut self, s: &str) -> Result<i64> {
+        use chrono::{DateTime, FixedOffset, NaiveDateTime};
 
         let date_time = if self.utc {
-            s.parse::<DateTime<Utc>>()?
+            let (date_time, offset_seconds) = match s.parse::<DateTime<FixedOffset>>() {
+                Ok(date_time) => (date_time.naive_utc(), date_time.offset().local_minus_utc()),
+                Err(err) if !self.time_crate_str => return Err(err.into()),
+                Err(err) => match parse_time_crate_datetime(s) {
+                    Ok((date_time, Some(offset_seconds))) => (date_time, offset_seconds),
+                    _ => return Err(err.into()),
+                },
+            };
+            if offset_seconds != 0 {
+                match self.offset_policy {
+                    UtcOffsetPolicy::Normalize => {}
+                    UtcOffsetPolicy::Count => self.non_utc_offsets += 1,
+                    UtcOffsetPolicy::Error => {
+                        fail!("Datetime {s:?} has a non-UTC offset of {offset_seconds} seconds")
+                    }
+                }
+            }
+            date_time.and_utc()
         } else {
-            s.parse::<NaiveDateTime>()?.and_utc()
+            match s.parse::<NaiveDateTime>() {
+                Ok(date_time) => date_time.and_utc(),
+                Err(err) if !self.time_crate_str => return Err(err.into()),
+                Err(err) => match parse_time_crate_datetime(s) {
+                    Ok((date_time, None)) => date_time.and_utc(),
+                    _ => return Err(err.into()),
+                },
+            }
         };
 
         match self.meta.as_ref() {
@@ -90,6 +152,28 @@ impl Date64Builder {
     }
 }
 
+impl Date64Builder {
+    /// The unit of values interpreted as seconds
+    fn seconds_unit(&self, name: &str) -> Result<TimeUnit> {
+        match &self.meta {
+            Some((unit, _)) if self.seconds => Ok(*unit),
+            _ => fail!("{name} is not supported"),
+        }
+    }
+
+    fn push_seconds(&mut self, name: &str, v: i64) -> Result<()> {
+        let unit = self.seconds_unit(name)?;
+        self.array
+            .push_scalar_value(chrono_ext::seconds_to_arrow(v, unit)?)
+    }
+
+    fn push_float_seconds(&mut self, name: &str, v: f64) -> Result<()> {
+        let unit = self.seconds_unit(name)?;
+        self.array
+            .push_scalar_value(chrono_ext::float_seconds_to_arrow(v, unit)?)
+    }
+}
+
 impl Context for Date64Builder {
     fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
         set_default(annotations, "field", &self.path);
@@ -114,15 +198,69 @@ impl SimpleSerializer for Date64Builder {
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
     fn serialize_str(&mut self, v: &str) -> Result<()> {
         try_(|| {
-            let timestamp = self.parse_str_to_timestamp(v)?;
+            let timestamp = match &self.meta {
+                Some((unit, _)) if self.seconds => chrono_ext::parse_seconds(v, *unit)?,
+                _ => self.parse_str_to_timestamp(v)?,
+            };
             self.array.push_scalar_value(timestamp)
         })
         .ctx(self)
     }
 
     fn serialize_i64(&mut self, v: i64) -> Result<()> {
-        try_(|| self.array.push_scalar_value(v)).ctx(self)
+        try_(|| {
+            if self.seconds {
+                self.push_seconds("serialize_i64", v)
+            } else {
+                self.array.push_scalar_value(v)
+            }
+        })
+        .ctx(self)
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        try_(|| self.push_seconds("serialize_i8", i64::from(v))).ctx(self)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        try_(|| self.push_seconds("serialize_i16", i64::from(v))).ctx(self)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        try_(|| self.push_seconds("serialize_i32", i64::from(v))).ctx(self)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        try_(|| self.push_seconds("serialize_u8", i64::from(v))).ctx(self)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        try_(|| self.push_seconds("serialize_u16", i64::from(v))).ctx(self)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        try_(|| self.push_seconds("serialize_u32", i64::from(v))).ctx(self)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        try_(|| self.push_seconds("serialize_u64", i64::try_from(v)?)).ctx(self)
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        try_(|| self.push_float_seconds("serialize_f32", f64::from(v))).ctx(self)
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        try_(|| self.push_float_seconds("serialize_f64", v)).ctx(self)
     }
 }
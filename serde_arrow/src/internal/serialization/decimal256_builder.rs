@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use crate::internal::{
+    arrow::{Array, DecimalArray, PrimitiveArray, I256},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::{
+        array_ext::{new_primitive_array, ArrayExt, ScalarArrayExt},
+        decimal::{self, DecimalParser},
+    },
+};
+
+use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+
+#[derive(Debug, Clone)]
+pub struct Decimal256Builder {
+    path: String,
+    pub precision: u8,
+    pub scale: i8,
+    pub f64_factor: f64,
+    pub parser: DecimalParser,
+    /// The scale of integers, if serialized with the `ScaledInt` strategy
+    pub int_scale: Option<i8>,
+    pub array: PrimitiveArray<I256>,
+}
+
+impl Decimal256Builder {
+    pub fn new(
+        path: String,
+        precision: u8,
+        scale: i8,
+        int_scale: Option<i8>,
+        is_nullable: bool,
+    ) -> Self {
+        Self {
+            path,
+            precision,
+            scale,
+            f64_factor: (10.0_f64).powi(scale as i32),
+            parser: DecimalParser::new(precision, scale, true),
+            int_scale,
+            array: new_primitive_array(is_nullable),
+        }
+    }
+
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::Decimal256(Self {
+            path: self.path.clone(),
+            precision: self.precision,
+            scale: self.scale,
+            f64_factor: self.f64_factor,
+            parser: self.parser,
+            int_scale: self.int_scale,
+            array: self.array.take(),
+        })
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.array.validity.is_some()
+    }
+
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+
+    fn push_scaled_int(&mut self, v: i128) -> Result<()> {
+        let Some(int_scale) = self.int_scale else {
+            fail!("Integers can only be serialized into decimals with the ScaledInt strategy");
+        };
+        let val = decimal::rescale_i256(I256::from_i128(v), int_scale, self.scale)?;
+        decimal::check_precision_i256(val, self.precision)?;
+        self.array.push_scalar_value(val)
+    }
+
+    fn push_float(&mut self, v: f64) -> Result<()> {
+        let Some(val) = I256::from_f64(v * self.f64_factor) else {
+            fail!(
+                "Value {v} cannot be represented as a 256 bit decimal with scale {scale}",
+                scale = self.scale
+            );
+        };
+        decimal::check_precision_i256(val, self.precision)?;
+        self.array.push_scalar_value(val)
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        Ok(Array::Decimal256(DecimalArray {
+            precision: self.precision,
+            scale: self.scale,
+            validity: self.array.validity,
+            values: self.array.values,
+        }))
+    }
+}
+
+impl Context for Decimal256Builder {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", "Decimal256(..)");
+    }
+}
+
+impl SimpleSerializer for Decimal256Builder {
+    fn serialize_default(&mut self) -> Result<()> {
+        try_(|| self.array.push_scalar_default()).ctx(self)
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        try_(|| self.array.push_scalar_none()).ctx(self)
+    }
+
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        try_(|| self.push_float(f64::from(v))).ctx(self)
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        try_(|| self.push_float(v)).ctx(self)
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        try_(|| {
+            let mut parse_buffer = [0; decimal::BUFFER_SIZE_I256];
+            let val = self
+                .parser
+                .parse_decimal256(&mut parse_buffer, v.as_bytes())?;
+
+            self.array.push_scalar_value(val)
+        })
+        .ctx(self)
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use crate::internal::{
     arrow::{Array, DecimalArray, PrimitiveArray},
-    error::{set_default, try_, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::{
         array_ext::{new_primitive_array, ArrayExt, ScalarArrayExt},
         decimal::{self, DecimalParser},
@@ -19,11 +19,19 @@ pub struct DecimalBuilder {
     pub f32_factor: f32,
     pub f64_factor: f64,
     pub parser: DecimalParser,
+    /// The scale of integers, if serialized with the `ScaledInt` strategy
+    pub int_scale: Option<i8>,
     pub array: PrimitiveArray<i128>,
 }
 
 impl DecimalBuilder {
-    pub fn new(path: String, precision: u8, scale: i8, is_nullable: bool) -> Self {
+    pub fn new(
+        path: String,
+        precision: u8,
+        scale: i8,
+        int_scale: Option<i8>,
+        is_nullable: bool,
+    ) -> Self {
         Self {
             path,
             precision,
@@ -31,6 +39,7 @@ impl DecimalBuilder {
             f32_factor: (10.0_f32).powi(scale as i32),
             f64_factor: (10.0_f64).powi(scale as i32),
             parser: DecimalParser::new(precision, scale, true),
+            int_scale,
             array: new_primitive_array(is_nullable),
         }
     }
@@ -43,6 +52,7 @@ impl DecimalBuilder {
             f32_factor: self.f32_factor,
             f64_factor: self.f64_factor,
             parser: self.parser,
+            int_scale: self.int_scale,
             array: self.array.take(),
         })
     }
@@ -51,6 +61,19 @@ impl DecimalBuilder {
         self.array.validity.is_some()
     }
 
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+
+    fn push_scaled_int(&mut self, v: i128) -> Result<()> {
+        let Some(int_scale) = self.int_scale else {
+            fail!("Integers can only be serialized into decimals with the ScaledInt strategy");
+        };
+        let val = decimal::rescale(v, int_scale, self.scale)?;
+        decimal::check_precision(val, self.precision)?;
+        self.array.push_scalar_value(val)
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::Decimal128(DecimalArray {
             precision: self.precision,
@@ -77,6 +100,46 @@ impl SimpleSerializer for DecimalBuilder {
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        try_(|| self.push_scaled_int(i128::from(v))).ctx(self)
+    }
+
     fn serialize_f32(&mut self, v: f32) -> Result<()> {
         try_(|| self.array.push_scalar_value((v * self.f32_factor) as i128)).ctx(self)
     }
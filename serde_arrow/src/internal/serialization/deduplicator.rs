@@ -0,0 +1,103 @@
+use std::collections::{HashSet, VecDeque};
+
+use serde::Serialize;
+
+use crate::internal::{
+    arrow::Field,
+    error::{fail, Result},
+    serializer_options::Deduplication,
+};
+
+use super::row_digest::{KeyDigest, RowDigest};
+
+/// Detect duplicate rows, see [`Deduplication`]
+#[derive(Debug, Clone)]
+pub struct Deduplicator {
+    /// The encoded names of the key fields, `None` to compare whole rows with
+    /// the previous row
+    keys: Option<Vec<Vec<u8>>>,
+    capacity: usize,
+    /// The encoding of the previously written row
+    previous: Option<Vec<u8>>,
+    /// The remembered keys, in the order they were written
+    seen: HashSet<Vec<u8>>,
+    order: VecDeque<Vec<u8>>,
+    /// The number of dropped rows
+    pub dropped: usize,
+}
+
+impl Deduplicator {
+    pub fn new(fields: &[Field], deduplication: &Deduplication) -> Result<Option<Self>> {
+        let (keys, capacity) = match deduplication {
+            Deduplication::None => return Ok(None),
+            Deduplication::Consecutive => (None, 1),
+            Deduplication::ByKey { keys, capacity } => {
+                if keys.is_empty() {
+                    fail!("Cannot deduplicate rows by key: no key fields given");
+                }
+                if *capacity == 0 {
+                    fail!("Cannot deduplicate rows by key: the capacity must be positive");
+                }
+                let mut names = Vec::new();
+                for path in keys {
+                    let Some(field) = path
+                        .strip_prefix("$.")
+                        .and_then(|name| fields.iter().find(|field| field.name == name))
+                    else {
+                        fail!("Cannot deduplicate rows by {path:?}: no top-level field with this path");
+                    };
+                    names.push(field.name.clone());
+                }
+                (Some(KeyDigest::encode_keys(&names)?), *capacity)
+            }
+        };
+        Ok(Some(Self {
+            keys,
+            capacity,
+            previous: None,
+            seen: HashSet::new(),
+            order: VecDeque::new(),
+            dropped: 0,
+        }))
+    }
+
+    /// Encode the row, return `None` if it is a duplicate
+    pub fn check<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<Option<Vec<u8>>> {
+        let mut encoded = Vec::new();
+        let is_duplicate = match self.keys.as_ref() {
+            Some(keys) => {
+                KeyDigest::encode(keys, value, &mut encoded)?;
+                self.seen.contains(&encoded)
+            }
+            None => {
+                RowDigest::push(&mut encoded, value)?;
+                self.previous.as_ref() == Some(&encoded)
+            }
+        };
+        if is_duplicate {
+            self.dropped += 1;
+            return Ok(None);
+        }
+        Ok(Some(encoded))
+    }
+
+    /// Remember a written row
+    pub fn insert(&mut self, encoded: Vec<u8>) {
+        if self.keys.is_none() {
+            self.previous = Some(encoded);
+            return;
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(encoded.clone());
+        self.order.push_back(encoded);
+    }
+
+    /// Forget the previous row, e.g., after appending null rows
+    pub fn clear_previous(&mut self) {
+        self.previous = None;
+    }
+}
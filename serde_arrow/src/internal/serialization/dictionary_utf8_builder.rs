@@ -5,6 +5,7 @@ use serde::Serialize;
 use crate::internal::{
     arrow::{Array, DictionaryArray},
     error::{fail, set_default, try_, Context, ContextSupport, Result},
+    serializer_options::StringLengthLimit,
     utils::Mut,
 };
 
@@ -16,6 +17,12 @@ pub struct DictionaryUtf8Builder {
     pub indices: Box<ArrayBuilder>,
     pub values: Box<ArrayBuilder>,
     pub index: HashMap<String, usize>,
+    /// If true, the dictionary is kept when taking the builder
+    pub interned: bool,
+    /// If true, the indices are widened when the dictionary outgrows them
+    pub promote_keys: bool,
+    /// The maximum length of the values, if limited
+    limit: Option<StringLengthLimit>,
 }
 
 impl DictionaryUtf8Builder {
@@ -25,22 +32,106 @@ impl DictionaryUtf8Builder {
             indices: Box::new(indices),
             values: Box::new(values),
             index: HashMap::new(),
+            interned: false,
+            promote_keys: false,
+            limit: None,
         }
     }
 
     pub fn take(&mut self) -> ArrayBuilder {
+        let (values, index) = if self.interned {
+            (self.values.as_ref().clone(), self.index.clone())
+        } else {
+            (self.values.take(), std::mem::take(&mut self.index))
+        };
         ArrayBuilder::DictionaryUtf8(Self {
             path: self.path.clone(),
             indices: Box::new(self.indices.take()),
-            values: Box::new(self.values.take()),
-            index: std::mem::take(&mut self.index),
+            values: Box::new(values),
+            index,
+            interned: self.interned,
+            promote_keys: self.promote_keys,
+            limit: self.limit.clone(),
         })
     }
 
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn path_and_limit_mut(&mut self) -> (&str, &mut Option<StringLengthLimit>) {
+        (&self.path, &mut self.limit)
+    }
+
+    /// Keep the dictionary across batches and seed it with the given values
+    ///
+    /// Values already contained in the dictionary must be a prefix of the
+    /// given values.
+    pub fn intern(&mut self, values: &[String]) -> Result<()> {
+        try_(|| {
+            for (idx, value) in values.iter().enumerate() {
+                if idx < self.index.len() {
+                    if self.index.get(value) != Some(&idx) {
+                        fail!("Interned values are not compatible with the current dictionary");
+                    }
+                } else {
+                    self.values.serialize_str(value)?;
+                    self.index.insert(value.to_owned(), idx);
+                }
+            }
+            self.interned = true;
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    /// The values of the dictionary in the order of their indices
+    pub fn dictionary_values(&self) -> Vec<String> {
+        let mut values = self.index.iter().collect::<Vec<_>>();
+        values.sort_by_key(|(_, idx)| **idx);
+        values
+            .into_iter()
+            .map(|(value, _)| value.to_owned())
+            .collect()
+    }
+
     pub fn is_nullable(&self) -> bool {
         self.indices.is_nullable()
     }
 
+    pub fn byte_size(&self) -> usize {
+        self.indices.byte_size() + self.values.byte_size()
+    }
+
+    /// Widen the indices until they can address the given index
+    fn reserve_index(&mut self, idx: usize) -> Result<()> {
+        use ArrayBuilder as A;
+        loop {
+            let max = match self.indices.as_ref() {
+                A::U8(_) => usize::from(u8::MAX),
+                A::U16(_) => usize::from(u16::MAX),
+                A::U32(_) => u32::MAX as usize,
+                A::I8(_) => i8::MAX as usize,
+                A::I16(_) => i16::MAX as usize,
+                A::I32(_) => i32::MAX as usize,
+                _ => return Ok(()),
+            };
+            if idx <= max {
+                return Ok(());
+            }
+            let widened = match self.indices.as_mut() {
+                A::U8(builder) => A::U16(builder.widen()),
+                A::U16(builder) => A::U32(builder.widen()),
+                A::U32(builder) => A::U64(builder.widen()),
+                A::I8(builder) => A::I16(builder.widen()),
+                A::I16(builder) => A::I32(builder.widen()),
+                A::I32(builder) => A::I64(builder.widen()),
+                _ => fail!("Cannot widen the keys of the dictionary"),
+            };
+            *self.indices = widened;
+        }
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::Dictionary(DictionaryArray {
             indices: Box::new((*self.indices).into_array()?),
@@ -65,12 +156,28 @@ impl SimpleSerializer for DictionaryUtf8Builder {
         try_(|| self.indices.serialize_none().ctx(self)).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.indices.serialize_nones(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.indices.serialize_nones(n).ctx(self)).ctx(self)
+    }
+
     fn serialize_str(&mut self, v: &str) -> Result<()> {
         try_(|| {
+            let v = match &self.limit {
+                Some(limit) => limit.apply(v)?,
+                None => std::borrow::Cow::Borrowed(v),
+            };
+            let v = v.as_ref();
             let idx = match self.index.get(v) {
                 Some(idx) => *idx,
                 None => {
                     let idx = self.index.len();
+                    if self.promote_keys {
+                        self.reserve_index(idx)?;
+                    }
                     self.values.serialize_str(v)?;
                     self.index.insert(v.to_string(), idx);
                     idx
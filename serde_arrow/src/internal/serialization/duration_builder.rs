@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use crate::internal::{
     arrow::{Array, PrimitiveArray, TimeArray, TimeUnit},
     chrono,
-    error::{set_default, try_, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
     utils::array_ext::{new_primitive_array, ArrayExt, ScalarArrayExt},
 };
 
@@ -14,6 +14,8 @@ pub struct DurationBuilder {
     path: String,
     pub unit: TimeUnit,
     pub array: PrimitiveArray<i64>,
+    /// If `true`, numbers and numeric strings are interpreted as seconds
+    pub seconds: bool,
 }
 
 impl DurationBuilder {
@@ -22,14 +24,21 @@ impl DurationBuilder {
             path,
             unit,
             array: new_primitive_array(is_nullable),
+            seconds: false,
         }
     }
 
+    pub fn with_seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
     pub fn take(&mut self) -> ArrayBuilder {
         ArrayBuilder::Duration(Self {
             path: self.path.clone(),
             unit: self.unit,
             array: self.array.take(),
+            seconds: self.seconds,
         })
     }
 
@@ -37,6 +46,10 @@ impl DurationBuilder {
         self.array.validity.is_some()
     }
 
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::Duration(TimeArray {
             unit: self.unit,
@@ -46,6 +59,25 @@ impl DurationBuilder {
     }
 }
 
+impl DurationBuilder {
+    fn push_integer(&mut self, v: i64) -> Result<()> {
+        if self.seconds {
+            let value = chrono::seconds_to_arrow(v, self.unit)?;
+            self.array.push_scalar_value(value)
+        } else {
+            self.array.push_scalar_value(v)
+        }
+    }
+
+    fn push_float(&mut self, v: f64) -> Result<()> {
+        if !self.seconds {
+            fail!("Floats can only be serialized into durations with the Seconds strategy");
+        }
+        let value = chrono::float_seconds_to_arrow(v, self.unit)?;
+        self.array.push_scalar_value(value)
+    }
+}
+
 impl Context for DurationBuilder {
     fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
         set_default(annotations, "field", &self.path);
@@ -62,41 +94,61 @@ impl SimpleSerializer for DurationBuilder {
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
     fn serialize_i8(&mut self, v: i8) -> Result<()> {
-        try_(|| self.array.push_scalar_value(i64::from(v))).ctx(self)
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
     }
 
     fn serialize_i16(&mut self, v: i16) -> Result<()> {
-        try_(|| self.array.push_scalar_value(i64::from(v))).ctx(self)
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
     }
 
     fn serialize_i32(&mut self, v: i32) -> Result<()> {
-        try_(|| self.array.push_scalar_value(i64::from(v))).ctx(self)
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
     }
 
     fn serialize_i64(&mut self, v: i64) -> Result<()> {
-        try_(|| self.array.push_scalar_value(v)).ctx(self)
+        try_(|| self.push_integer(v)).ctx(self)
     }
 
     fn serialize_u8(&mut self, v: u8) -> Result<()> {
-        try_(|| self.array.push_scalar_value(i64::from(v))).ctx(self)
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
     }
 
     fn serialize_u16(&mut self, v: u16) -> Result<()> {
-        try_(|| self.array.push_scalar_value(i64::from(v))).ctx(self)
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
     }
 
     fn serialize_u32(&mut self, v: u32) -> Result<()> {
-        try_(|| self.array.push_scalar_value(i64::from(v))).ctx(self)
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
     }
 
     fn serialize_u64(&mut self, v: u64) -> Result<()> {
-        try_(|| self.array.push_scalar_value(i64::try_from(v)?)).ctx(self)
+        try_(|| self.push_integer(i64::try_from(v)?)).ctx(self)
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        try_(|| self.push_float(f64::from(v))).ctx(self)
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        try_(|| self.push_float(v)).ctx(self)
     }
 
     fn serialize_str(&mut self, v: &str) -> Result<()> {
         try_(|| {
-            let value = chrono::parse_span(v)?.to_arrow_duration(self.unit)?;
+            let value = if self.seconds {
+                chrono::parse_seconds(v, self.unit)?
+            } else {
+                chrono::parse_span(v)?.to_arrow_duration(self.unit)?
+            };
             self.array.push_scalar_value(value)
         })
         .ctx(self)
@@ -48,6 +48,10 @@ impl FixedSizeBinaryBuilder {
         self.seq.validity.is_some()
     }
 
+    pub fn byte_size(&self) -> usize {
+        self.seq.byte_size() + self.buffer.len()
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::FixedSizeBinary(FixedSizeBinaryArray {
             n: self.n.try_into()?,
@@ -57,6 +57,10 @@ impl FixedSizeListBuilder {
         self.seq.validity.is_some()
     }
 
+    pub fn byte_size(&self) -> usize {
+        self.seq.byte_size() + self.element.byte_size()
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::FixedSizeList(FixedSizeListArray {
             len: self.seq.len,
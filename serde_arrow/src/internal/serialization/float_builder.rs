@@ -4,7 +4,8 @@ use half::f16;
 
 use crate::internal::{
     arrow::{Array, PrimitiveArray},
-    error::{set_default, try_, Context, ContextSupport, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    serializer_options::{F16Overflow, F16Rounding},
     utils::{
         array_ext::{new_primitive_array, ArrayExt, ScalarArrayExt},
         Mut,
@@ -17,6 +18,10 @@ use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
 pub struct FloatBuilder<I> {
     path: String,
     array: PrimitiveArray<I>,
+    /// The rounding of wider floats, only used for `Float16` arrays
+    pub rounding: F16Rounding,
+    /// The handling of out of range values, only used for `Float16` arrays
+    pub overflow: F16Overflow,
 }
 
 impl<F: Default + 'static> FloatBuilder<F> {
@@ -24,6 +29,8 @@ impl<F: Default + 'static> FloatBuilder<F> {
         Self {
             path,
             array: new_primitive_array(is_nullable),
+            rounding: F16Rounding::default(),
+            overflow: F16Overflow::default(),
         }
     }
 
@@ -31,12 +38,22 @@ impl<F: Default + 'static> FloatBuilder<F> {
         Self {
             path: self.path.clone(),
             array: self.array.take(),
+            rounding: self.rounding,
+            overflow: self.overflow,
         }
     }
 
     pub fn is_nullable(&self) -> bool {
         self.array.validity.is_some()
     }
+
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.array.reserve(additional);
+    }
 }
 
 macro_rules! impl_into_array {
@@ -87,6 +104,14 @@ impl SimpleSerializer for FloatBuilder<f32> {
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
     fn serialize_some<V: serde::Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
         try_(|| value.serialize(Mut(&mut *self))).ctx(self)
     }
@@ -141,6 +166,14 @@ impl SimpleSerializer for FloatBuilder<f64> {
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
     fn serialize_i8(&mut self, v: i8) -> Result<()> {
         try_(|| self.array.push_scalar_value(v as f64)).ctx(self)
     }
@@ -191,11 +224,53 @@ impl SimpleSerializer for FloatBuilder<f16> {
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
     fn serialize_f32(&mut self, v: f32) -> Result<()> {
-        try_(|| self.array.push_scalar_value(f16::from_f32(v))).ctx(self)
+        try_(|| {
+            let v = self.convert(f64::from(v))?;
+            self.array.push_scalar_value(v)
+        })
+        .ctx(self)
     }
 
     fn serialize_f64(&mut self, v: f64) -> Result<()> {
-        try_(|| self.array.push_scalar_value(f16::from_f64(v))).ctx(self)
+        try_(|| {
+            let v = self.convert(v)?;
+            self.array.push_scalar_value(v)
+        })
+        .ctx(self)
+    }
+}
+
+impl FloatBuilder<f16> {
+    /// Convert a wider float according to the configured rounding and overflow
+    fn convert(&self, v: f64) -> Result<f16> {
+        // f16::from_f64 rounds to nearest, ties to even
+        let nearest = f16::from_f64(v);
+        if !v.is_finite() {
+            return Ok(nearest);
+        }
+        if nearest.is_infinite() && self.overflow == F16Overflow::Error {
+            fail!("Value {v} is outside the range of Float16");
+        }
+
+        match self.rounding {
+            F16Rounding::Nearest => Ok(nearest),
+            F16Rounding::TowardZero if nearest.is_infinite() => {
+                Ok(if v > 0.0 { f16::MAX } else { f16::MIN })
+            }
+            F16Rounding::TowardZero if f64::from(nearest).abs() > v.abs() => {
+                // step the magnitude one unit in the last place towards zero
+                Ok(f16::from_bits(nearest.to_bits() - 1))
+            }
+            F16Rounding::TowardZero => Ok(nearest),
+        }
     }
 }
@@ -2,9 +2,10 @@ use std::collections::BTreeMap;
 
 use crate::internal::{
     arrow::{Array, PrimitiveArray},
-    error::{set_default, try_, Context, ContextSupport, Error, Result},
+    error::{fail, set_default, try_, Context, ContextSupport, Error, Result},
     utils::{
         array_ext::{new_primitive_array, ArrayExt, ScalarArrayExt},
+        quantization::Quantization,
         NamedType,
     },
 };
@@ -15,6 +16,8 @@ use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
 pub struct IntBuilder<I> {
     path: String,
     array: PrimitiveArray<I>,
+    /// The quantization of floats, if serialized with the `Quantized` strategy
+    quantization: Option<Quantization>,
 }
 
 impl<I: Default + 'static> IntBuilder<I> {
@@ -22,19 +25,47 @@ impl<I: Default + 'static> IntBuilder<I> {
         Self {
             path,
             array: new_primitive_array(is_nullable),
+            quantization: None,
         }
     }
 
+    pub fn with_quantization(mut self, quantization: Option<Quantization>) -> Self {
+        self.quantization = quantization;
+        self
+    }
+
     pub fn take_self(&mut self) -> Self {
         Self {
             path: self.path.clone(),
             array: self.array.take(),
+            quantization: self.quantization,
         }
     }
 
     pub fn is_nullable(&self) -> bool {
         self.array.validity.is_some()
     }
+
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.array.reserve(additional);
+    }
+
+    /// Move the values into a builder of a wider integer type
+    pub fn widen<J: From<I>>(&mut self) -> IntBuilder<J> {
+        let values = std::mem::take(&mut self.array.values);
+        IntBuilder {
+            path: self.path.clone(),
+            array: PrimitiveArray {
+                validity: self.array.validity.take(),
+                values: values.into_iter().map(J::from).collect(),
+            },
+            quantization: self.quantization,
+        }
+    }
 }
 
 macro_rules! impl_into_array {
@@ -111,6 +142,14 @@ where
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
     fn serialize_bool(&mut self, v: bool) -> Result<()> {
         try_(|| {
             let v: u8 = if v { 1 } else { 0 };
@@ -154,4 +193,19 @@ where
     fn serialize_char(&mut self, v: char) -> Result<()> {
         try_(|| self.array.push_scalar_value(I::try_from(u32::from(v))?)).ctx(self)
     }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        try_(|| {
+            let Some(quantization) = &self.quantization else {
+                fail!("Floats can only be serialized into integers with the Quantized strategy");
+            };
+            self.array
+                .push_scalar_value(I::try_from(quantization.quantize(v)?)?)
+        })
+        .ctx(self)
+    }
 }
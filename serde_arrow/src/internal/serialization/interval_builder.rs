@@ -0,0 +1,293 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::internal::{
+    arrow::{Array, DayTimeInterval, MonthDayNanoInterval, PrimitiveArray},
+    error::{fail, set_default, try_, Context, ContextSupport, Result},
+    utils::{
+        array_ext::{new_primitive_array, ArrayExt, ScalarArrayExt},
+        IntervalValue, Mut,
+    },
+};
+
+use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+
+/// Build interval arrays
+///
+/// Values are accepted as sequences of their components (e.g., `(days,
+/// milliseconds)` for `Interval(DayTime)`) or as structs with fields named as
+/// the components. Missing struct fields default to zero. Year month intervals
+/// are also accepted as plain integers counting months.
+#[derive(Debug, Clone)]
+pub struct IntervalBuilder<T> {
+    path: String,
+    pub array: PrimitiveArray<T>,
+    /// The components of the value currently being serialized
+    components: Vec<i64>,
+}
+
+impl<T: IntervalValue> IntervalBuilder<T> {
+    pub fn new(path: String, is_nullable: bool) -> Self {
+        Self {
+            path,
+            array: new_primitive_array(is_nullable),
+            components: Vec::new(),
+        }
+    }
+
+    fn take_self(&mut self) -> Self {
+        Self {
+            path: self.path.clone(),
+            array: self.array.take(),
+            components: std::mem::take(&mut self.components),
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.array.validity.is_some()
+    }
+
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+}
+
+impl IntervalBuilder<i32> {
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::IntervalYearMonth(self.take_self())
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        Ok(Array::IntervalYearMonth(self.array))
+    }
+}
+
+impl IntervalBuilder<DayTimeInterval> {
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::IntervalDayTime(self.take_self())
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        Ok(Array::IntervalDayTime(self.array))
+    }
+}
+
+impl IntervalBuilder<MonthDayNanoInterval> {
+    pub fn take(&mut self) -> ArrayBuilder {
+        ArrayBuilder::IntervalMonthDayNano(self.take_self())
+    }
+
+    pub fn into_array(self) -> Result<Array> {
+        Ok(Array::IntervalMonthDayNano(self.array))
+    }
+}
+
+impl<T: IntervalValue> IntervalBuilder<T> {
+    fn push_integer(&mut self, v: i64) -> Result<()> {
+        if T::COMPONENTS.len() != 1 {
+            fail!(
+                "{data_type} values must be given as a sequence of ({components}) or a struct",
+                data_type = T::DATA_TYPE,
+                components = T::COMPONENTS.join(", "),
+            );
+        }
+        self.array.push_scalar_value(T::from_components(&[v])?)
+    }
+
+    fn start_seq(&mut self) -> Result<()> {
+        self.components.clear();
+        Ok(())
+    }
+
+    fn seq_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        let mut serializer = ComponentSerializer(0);
+        value.serialize(Mut(&mut serializer))?;
+        self.components.push(serializer.0);
+        Ok(())
+    }
+
+    fn start_struct(&mut self) -> Result<()> {
+        self.components.clear();
+        self.components.resize(T::COMPONENTS.len(), 0);
+        Ok(())
+    }
+
+    fn struct_field<V: Serialize + ?Sized>(&mut self, key: &str, value: &V) -> Result<()> {
+        let Some(idx) = T::COMPONENTS.iter().position(|component| *component == key) else {
+            fail!(
+                "Unknown field {key:?} for {data_type}, expected one of {components:?}",
+                data_type = T::DATA_TYPE,
+                components = T::COMPONENTS,
+            );
+        };
+        let mut serializer = ComponentSerializer(0);
+        value.serialize(Mut(&mut serializer))?;
+        self.components[idx] = serializer.0;
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<()> {
+        if self.components.len() != T::COMPONENTS.len() {
+            fail!(
+                "Invalid number of components for {data_type}: got {actual}, expected {expected} ({components})",
+                data_type = T::DATA_TYPE,
+                actual = self.components.len(),
+                expected = T::COMPONENTS.len(),
+                components = T::COMPONENTS.join(", "),
+            );
+        }
+        let value = T::from_components(&self.components)?;
+        self.array.push_scalar_value(value)
+    }
+}
+
+impl<T: IntervalValue> Context for IntervalBuilder<T> {
+    fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
+        set_default(annotations, "field", &self.path);
+        set_default(annotations, "data_type", T::DATA_TYPE);
+    }
+}
+
+impl<T: IntervalValue> SimpleSerializer for IntervalBuilder<T> {
+    fn serialize_default(&mut self) -> Result<()> {
+        try_(|| self.array.push_scalar_default()).ctx(self)
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        try_(|| self.array.push_scalar_none()).ctx(self)
+    }
+
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        try_(|| self.push_integer(v)).ctx(self)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        try_(|| self.push_integer(i64::from(v))).ctx(self)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        try_(|| self.push_integer(i64::try_from(v)?)).ctx(self)
+    }
+
+    fn serialize_seq_start(&mut self, _: Option<usize>) -> Result<()> {
+        try_(|| self.start_seq()).ctx(self)
+    }
+
+    fn serialize_seq_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.seq_element(value)).ctx(self)
+    }
+
+    fn serialize_seq_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        try_(|| self.start_seq()).ctx(self)
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.seq_element(value)).ctx(self)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
+
+    fn serialize_tuple_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        try_(|| self.start_seq()).ctx(self)
+    }
+
+    fn serialize_tuple_struct_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        try_(|| self.seq_element(value)).ctx(self)
+    }
+
+    fn serialize_tuple_struct_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
+
+    fn serialize_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        try_(|| self.start_struct()).ctx(self)
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        try_(|| self.struct_field(key, value)).ctx(self)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        try_(|| self.end()).ctx(self)
+    }
+}
+
+struct ComponentSerializer(i64);
+
+impl Context for ComponentSerializer {
+    fn annotate(&self, _: &mut BTreeMap<String, String>) {}
+}
+
+impl SimpleSerializer for ComponentSerializer {
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.0 = v;
+        Ok(())
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.serialize_i64(v.try_into()?)
+    }
+}
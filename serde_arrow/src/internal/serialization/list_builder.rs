@@ -11,7 +11,9 @@ use crate::internal::{
     },
 };
 
-use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+use super::{
+    array_builder::ArrayBuilder, byte_budget::ByteBudget, simple_serializer::SimpleSerializer,
+};
 
 #[derive(Debug, Clone)]
 
@@ -20,6 +22,9 @@ pub struct ListBuilder<O> {
     pub meta: FieldMeta,
     pub element: Box<ArrayBuilder>,
     pub offsets: OffsetsArray<O>,
+    /// The count of the buffered bytes and the bits of each element, if
+    /// limited
+    pub budget: Option<(ByteBudget, usize)>,
 }
 
 impl<O: Offset> ListBuilder<O> {
@@ -29,6 +34,7 @@ impl<O: Offset> ListBuilder<O> {
             meta,
             element: Box::new(element),
             offsets: OffsetsArray::new(is_nullable),
+            budget: None,
         }
     }
 
@@ -38,12 +44,21 @@ impl<O: Offset> ListBuilder<O> {
             meta: self.meta.clone(),
             offsets: self.offsets.take(),
             element: Box::new(self.element.take()),
+            budget: self.budget.clone(),
         }
     }
 
     pub fn is_nullable(&self) -> bool {
         self.offsets.validity.is_some()
     }
+
+    pub fn byte_size(&self) -> usize {
+        self.offsets.byte_size() + self.element.byte_size()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+    }
 }
 
 impl ListBuilder<i32> {
@@ -82,6 +97,9 @@ impl<O: NamedType + Offset> ListBuilder<O> {
     }
 
     fn element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        if let Some((budget, element_bits)) = &self.budget {
+            budget.reserve_bits(*element_bits)?;
+        }
         self.offsets.push_seq_elements(1)?;
         value.serialize(Mut(self.element.as_mut()))
     }
@@ -115,6 +133,14 @@ impl<O: NamedType + Offset> SimpleSerializer for ListBuilder<O> {
         try_(|| self.offsets.push_seq_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.offsets.push_seq_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.offsets.push_seq_nones(n)).ctx(self)
+    }
+
     fn serialize_seq_start(&mut self, _: Option<usize>) -> Result<()> {
         try_(|| self.start()).ctx(self)
     }
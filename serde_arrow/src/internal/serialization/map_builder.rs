@@ -5,10 +5,16 @@ use serde::Serialize;
 use crate::internal::{
     arrow::{Array, FieldMeta, ListArray},
     error::{fail, set_default, try_, Context, ContextSupport, Result},
-    utils::array_ext::{ArrayExt, OffsetsArray, SeqArrayExt},
+    serializer_options::DuplicateMapKeyPolicy,
+    utils::{
+        array_ext::{ArrayExt, OffsetsArray, SeqArrayExt},
+        value::{compare_keys, Value, ValueSerializer},
+    },
 };
 
-use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+use super::{
+    array_builder::ArrayBuilder, byte_budget::ByteBudget, simple_serializer::SimpleSerializer,
+};
 
 #[derive(Debug, Clone)]
 pub struct MapBuilder {
@@ -16,6 +22,17 @@ pub struct MapBuilder {
     pub meta: FieldMeta,
     pub entry: Box<ArrayBuilder>,
     pub offsets: OffsetsArray<i32>,
+    /// If `true`, buffer the entries of each map and write them sorted by key
+    pub sort_entries: bool,
+    /// How entries with equal keys are handled, any policy but `Keep` buffers
+    /// the entries of each map
+    pub duplicate_keys: DuplicateMapKeyPolicy,
+    /// If `true`, write the fields of structs as entries keyed by field name
+    pub structs_as_maps: bool,
+    pending_key: Option<Value>,
+    pending: Vec<(Value, Value)>,
+    /// The count of the buffered bytes and the bits of each entry, if limited
+    pub budget: Option<(ByteBudget, usize)>,
 }
 
 impl MapBuilder {
@@ -31,6 +48,12 @@ impl MapBuilder {
             meta,
             offsets: OffsetsArray::new(is_nullable),
             entry: Box::new(entry),
+            sort_entries: false,
+            duplicate_keys: DuplicateMapKeyPolicy::Keep,
+            structs_as_maps: false,
+            pending_key: None,
+            pending: Vec::new(),
+            budget: None,
         })
     }
 
@@ -50,6 +73,12 @@ impl MapBuilder {
             meta: self.meta.clone(),
             offsets: self.offsets.take(),
             entry: Box::new(self.entry.take()),
+            sort_entries: self.sort_entries,
+            duplicate_keys: self.duplicate_keys,
+            structs_as_maps: self.structs_as_maps,
+            pending_key: None,
+            pending: Vec::new(),
+            budget: self.budget.clone(),
         })
     }
 
@@ -57,6 +86,10 @@ impl MapBuilder {
         self.offsets.validity.is_some()
     }
 
+    pub fn byte_size(&self) -> usize {
+        self.offsets.byte_size() + self.entry.byte_size()
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::Map(ListArray {
             meta: self.meta,
@@ -65,6 +98,78 @@ impl MapBuilder {
             offsets: self.offsets.offsets,
         }))
     }
+
+    fn push_entry<K: Serialize + ?Sized, V: Serialize + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &V,
+    ) -> Result<()> {
+        self.start_entry()?;
+        self.entry.serialize_tuple_start(2)?;
+        self.entry.serialize_tuple_element(key)?;
+        self.entry.serialize_tuple_element(value)?;
+        self.entry.serialize_tuple_end()
+    }
+
+    fn start_entry(&mut self) -> Result<()> {
+        if let Some((budget, entry_bits)) = &self.budget {
+            budget.reserve_bits(*entry_bits)?;
+        }
+        self.offsets.push_seq_elements(1)
+    }
+
+    fn buffers_entries(&self) -> bool {
+        self.sort_entries || self.duplicate_keys != DuplicateMapKeyPolicy::Keep
+    }
+
+    fn flush_pending_entries(&mut self) -> Result<()> {
+        let mut pending = std::mem::take(&mut self.pending);
+
+        // a stable sort of the indices keeps entries with equal keys in order
+        let mut order = (0..pending.len()).collect::<Vec<_>>();
+        let mut error = None;
+        order.sort_by(|&a, &b| match compare_keys(&pending[a].0, &pending[b].0) {
+            Ok(ordering) => ordering,
+            Err(err) => {
+                error.get_or_insert(err);
+                std::cmp::Ordering::Equal
+            }
+        });
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        if self.duplicate_keys != DuplicateMapKeyPolicy::Keep {
+            let mut kept = Vec::with_capacity(order.len());
+            for group in order.chunk_by(|&a, &b| {
+                matches!(
+                    compare_keys(&pending[a].0, &pending[b].0),
+                    Ok(std::cmp::Ordering::Equal)
+                )
+            }) {
+                match (self.duplicate_keys, group) {
+                    (_, [idx]) => kept.push(*idx),
+                    (DuplicateMapKeyPolicy::First, [idx, ..]) => kept.push(*idx),
+                    (DuplicateMapKeyPolicy::Last, [.., idx]) => kept.push(*idx),
+                    _ => fail!("Duplicate map key {key:?}", key = pending[group[0]].0),
+                }
+            }
+            if !self.sort_entries {
+                kept.sort_unstable();
+            }
+            order = kept;
+        }
+
+        for &idx in &order {
+            let (key, value) = &pending[idx];
+            self.push_entry(key, value)?;
+        }
+
+        // reuse the allocation for the next map
+        pending.clear();
+        self.pending = pending;
+        Ok(())
+    }
 }
 
 impl Context for MapBuilder {
@@ -83,13 +188,30 @@ impl SimpleSerializer for MapBuilder {
         try_(|| self.offsets.push_seq_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.offsets.push_seq_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.offsets.push_seq_nones(n)).ctx(self)
+    }
+
     fn serialize_map_start(&mut self, _: Option<usize>) -> Result<()> {
-        try_(|| self.offsets.start_seq()).ctx(self)
+        try_(|| {
+            self.pending_key = None;
+            self.pending.clear();
+            self.offsets.start_seq()
+        })
+        .ctx(self)
     }
 
     fn serialize_map_key<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<()> {
         try_(|| {
-            self.offsets.push_seq_elements(1)?;
+            if self.buffers_entries() {
+                self.pending_key = Some(key.serialize(ValueSerializer)?);
+                return Ok(());
+            }
+            self.start_entry()?;
             self.entry.serialize_tuple_start(2)?;
             self.entry.serialize_tuple_element(key)
         })
@@ -98,6 +220,13 @@ impl SimpleSerializer for MapBuilder {
 
     fn serialize_map_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
         try_(|| {
+            if self.buffers_entries() {
+                let Some(key) = self.pending_key.take() else {
+                    fail!("serialize_map_value must be preceded by serialize_map_key");
+                };
+                self.pending.push((key, value.serialize(ValueSerializer)?));
+                return Ok(());
+            }
             self.entry.serialize_tuple_element(value)?;
             self.entry.serialize_tuple_end()
         })
@@ -105,6 +234,46 @@ impl SimpleSerializer for MapBuilder {
     }
 
     fn serialize_map_end(&mut self) -> Result<()> {
-        try_(|| self.offsets.end_seq()).ctx(self)
+        try_(|| {
+            if self.buffers_entries() {
+                self.flush_pending_entries()?;
+            }
+            self.offsets.end_seq()
+        })
+        .ctx(self)
+    }
+
+    fn serialize_struct_start(&mut self, _: &'static str, len: usize) -> Result<()> {
+        if !self.structs_as_maps {
+            fail!(
+                in self,
+                "Cannot serialize a struct into a Map field without the `map_struct_interchange` option",
+            );
+        }
+        self.serialize_map_start(Some(len))
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| {
+            if let Err(err) = self.serialize_map_key(key) {
+                fail!(
+                    "Cannot write struct field {key:?} as map key: {err}",
+                    err = err.message(),
+                );
+            }
+            self.serialize_map_value(value)
+        })
+        .ctx(&ctx)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        self.serialize_map_end()
     }
 }
@@ -3,23 +3,39 @@
 pub mod array_builder;
 pub mod binary_builder;
 pub mod bool_builder;
+pub mod byte_budget;
+pub mod custom_builder;
+#[cfg(feature = "types-temporal")]
 pub mod date32_builder;
+#[cfg(feature = "types-temporal")]
 pub mod date64_builder;
+#[cfg(feature = "types-decimal")]
+pub mod decimal256_builder;
+#[cfg(feature = "types-decimal")]
 pub mod decimal_builder;
+pub mod deduplicator;
 pub mod dictionary_utf8_builder;
+#[cfg(feature = "types-temporal")]
 pub mod duration_builder;
 pub mod fixed_size_binary_builder;
 pub mod fixed_size_list_builder;
 pub mod float_builder;
 pub mod int_builder;
+#[cfg(feature = "types-temporal")]
+pub mod interval_builder;
 pub mod list_builder;
 pub mod map_builder;
 pub mod null_builder;
 pub mod outer_sequence_builder;
+pub mod row_digest;
+pub mod shape_check;
 pub mod simple_serializer;
 pub mod struct_builder;
+#[cfg(feature = "types-temporal")]
 pub mod time_builder;
+#[cfg(feature = "types-union")]
 pub mod union_builder;
+#[cfg(feature = "types-union")]
 pub mod unknown_variant_builder;
 pub mod utf8_builder;
 
@@ -29,6 +29,10 @@ impl NullBuilder {
         true
     }
 
+    pub fn byte_size(&self) -> usize {
+        0
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::Null(NullArray { len: self.count }))
     }
@@ -52,6 +56,16 @@ impl SimpleSerializer for NullBuilder {
         Ok(())
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        self.count += n;
+        Ok(())
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        self.count += n;
+        Ok(())
+    }
+
     fn serialize_unit_struct(&mut self, _: &'static str) -> Result<()> {
         self.count += 1;
         Ok(())
@@ -1,49 +1,363 @@
-use std::collections::{BTreeMap, HashMap};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
+use half::f16;
 use serde::Serialize;
 
 use crate::internal::{
-    arrow::{DataType, Field, TimeUnit},
-    error::{fail, Context, ContextSupport, Result},
-    schema::{get_strategy_from_metadata, SerdeArrowSchema, Strategy},
+    arrow::{DataType, Field},
+    error::{fail, Context, ContextSupport, Error, Result},
+    schema::{
+        apply_strategy_overrides, get_strategy_from_metadata, DataTypeDisplay, SerdeArrowSchema,
+        Strategy,
+    },
+    serialization::custom_builder::{CustomArrayBuilder, CustomBuilder},
     serialization::{
-        binary_builder::BinaryBuilder, duration_builder::DurationBuilder,
-        fixed_size_binary_builder::FixedSizeBinaryBuilder,
+        binary_builder::BinaryBuilder, fixed_size_binary_builder::FixedSizeBinaryBuilder,
         fixed_size_list_builder::FixedSizeListBuilder,
     },
-    utils::{btree_map, meta_from_field, ChildName, Mut},
+    serializer_options::StringLengthLimit,
+    utils::{
+        array_ext::SeqArrayExt, array_view_ext::supported_alternatives, btree_map, meta_from_field,
+        quantization::Quantization, ChildName, Mut,
+    },
+};
+
+use super::{
+    bool_builder::BoolBuilder, byte_budget::ByteBudget, deduplicator::Deduplicator,
+    dictionary_utf8_builder::DictionaryUtf8Builder, float_builder::FloatBuilder,
+    int_builder::IntBuilder, list_builder::ListBuilder, map_builder::MapBuilder,
+    null_builder::NullBuilder, shape_check::check_item_shape, simple_serializer::SimpleSerializer,
+    struct_builder::StructBuilder, utf8_builder::Utf8Builder, ArrayBuilder,
 };
 
+#[cfg(feature = "types-decimal")]
+use super::{decimal256_builder::Decimal256Builder, decimal_builder::DecimalBuilder};
+
+#[cfg(feature = "types-temporal")]
 use super::{
-    bool_builder::BoolBuilder, date32_builder::Date32Builder, date64_builder::Date64Builder,
-    decimal_builder::DecimalBuilder, dictionary_utf8_builder::DictionaryUtf8Builder,
-    float_builder::FloatBuilder, int_builder::IntBuilder, list_builder::ListBuilder,
-    map_builder::MapBuilder, null_builder::NullBuilder, simple_serializer::SimpleSerializer,
-    struct_builder::StructBuilder, time_builder::TimeBuilder, union_builder::UnionBuilder,
-    unknown_variant_builder::UnknownVariantBuilder, utf8_builder::Utf8Builder, ArrayBuilder,
+    date32_builder::Date32Builder, date64_builder::Date64Builder,
+    duration_builder::DurationBuilder, interval_builder::IntervalBuilder,
+    time_builder::TimeBuilder,
 };
 
-#[derive(Debug, Clone)]
-pub struct OuterSequenceBuilder(StructBuilder);
+#[cfg(feature = "types-temporal")]
+use crate::internal::arrow::{IntervalUnit, TimeUnit};
+
+#[cfg(feature = "types-union")]
+use super::{union_builder::UnionBuilder, unknown_variant_builder::UnknownVariantBuilder};
+
+#[derive(Debug)]
+pub struct OuterSequenceBuilder {
+    builder: StructBuilder,
+    /// The fields to check the next item against, if any
+    shape_check: Option<Vec<Field>>,
+    progress: Option<Progress>,
+    /// An error while configuring the builder, reported when it is used. It is
+    /// shared to keep the builder cloneable
+    failure: Option<Arc<Error>>,
+    /// The maximum number of rows, if any
+    pub max_rows: Option<usize>,
+    /// The count of the buffered bytes, if limited. It is shared with the
+    /// child builders
+    budget: Option<ByteBudget>,
+    /// The bits written per row for fixed sized values
+    row_bits: usize,
+    /// If `true`, annotate errors with the row of the item
+    pub strict_nullability: bool,
+    /// The detection of duplicate rows, if any
+    pub deduplicator: Option<Deduplicator>,
+}
+
+/// Report the number of processed rows in regular intervals
+#[derive(Clone)]
+struct Progress {
+    every: usize,
+    processed: usize,
+    callback: Arc<dyn Fn(usize) + Send + Sync>,
+}
+
+impl std::fmt::Debug for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Progress")
+            .field("every", &self.every)
+            .field("processed", &self.processed)
+            .finish_non_exhaustive()
+    }
+}
 
 impl OuterSequenceBuilder {
     pub fn new(schema: &SerdeArrowSchema) -> Result<Self> {
-        Ok(Self(build_struct(
-            String::from("$"),
-            &schema.fields,
-            false,
-        )?))
+        Ok(Self {
+            builder: build_struct(String::from("$"), &schema.fields, false)?,
+            shape_check: None,
+            progress: None,
+            failure: None,
+            max_rows: None,
+            budget: None,
+            row_bits: 0,
+            strict_nullability: false,
+            deduplicator: None,
+        })
+    }
+
+    /// Rebuild the builders with the strategies of the fields replaced
+    pub fn override_strategies(
+        &mut self,
+        fields: &[Field],
+        overrides: &BTreeMap<String, Option<Strategy>>,
+    ) -> Result<()> {
+        if self.len() != 0 {
+            fail!("Cannot override strategies of a builder that already contains items");
+        }
+        let fields = apply_strategy_overrides(fields, overrides)?;
+        self.builder = build_struct(String::from("$"), &fields, false)?;
+        self.install_budget(self.budget.clone());
+        Ok(())
+    }
+
+    /// Replace the builder of the field with the given path by a custom builder
+    pub fn set_custom_builder(
+        &mut self,
+        path: &str,
+        builder: Box<dyn CustomArrayBuilder>,
+    ) -> Result<()> {
+        if self.len() != 0 {
+            fail!("Cannot set a custom builder for a builder that already contains items");
+        }
+        let Some(target) = self
+            .builder
+            .fields
+            .iter_mut()
+            .find_map(|(child, _)| child.find_mut(path))
+        else {
+            fail!("Cannot set a custom builder for {path:?}: no field with this path");
+        };
+        let storage = match &mut *target {
+            ArrayBuilder::Custom(custom) => custom.storage.take(),
+            other => other.take(),
+        };
+        *target = ArrayBuilder::Custom(CustomBuilder::new(path.to_owned(), builder, storage));
+        self.install_budget(self.budget.clone());
+        Ok(())
+    }
+
+    /// Report the given error on the next use of the builder
+    pub fn set_failure(&mut self, err: Error) {
+        self.failure = Some(Arc::new(err));
+    }
+
+    fn check_failure(&self) -> Result<()> {
+        match self.failure.as_ref() {
+            Some(err) => Err(err.copy()),
+            None => Ok(()),
+        }
+    }
+
+    /// Call `callback` with the number of processed items after every `every`
+    /// items, a value of zero disables the callback
+    pub fn set_progress(&mut self, every: usize, callback: Arc<dyn Fn(usize) + Send + Sync>) {
+        self.progress = (every != 0).then(|| Progress {
+            every,
+            processed: 0,
+            callback,
+        });
+    }
+
+    /// Check the shape of the next item against the given fields
+    pub fn check_next_item(&mut self, fields: Vec<Field>) {
+        self.shape_check = Some(fields);
+    }
+
+    /// The number of records pushed into the builder
+    pub fn len(&self) -> usize {
+        self.builder.seq.len
+    }
+
+    /// The number of rows dropped as duplicates
+    pub fn dropped_rows(&self) -> usize {
+        self.deduplicator.as_ref().map_or(0, |dedup| dedup.dropped)
+    }
+
+    /// Limit the number of buffered bytes, `None` removes the limit
+    pub fn set_max_bytes(&mut self, max_bytes: Option<usize>) {
+        let budget =
+            max_bytes.map(|max_bytes| ByteBudget::new(max_bytes, self.builder.byte_size()));
+        self.install_budget(budget);
+    }
+
+    /// Share the budget with all builders, a new budget is not shared with
+    /// any other builder
+    fn install_budget(&mut self, budget: Option<ByteBudget>) {
+        for (builder, _) in &mut self.builder.fields {
+            builder.set_byte_budget(budget.as_ref());
+        }
+        self.row_bits = self
+            .builder
+            .fields
+            .iter()
+            .map(|(f, _)| f.fixed_bits())
+            .sum();
+        self.budget = budget;
+    }
+
+    fn check_limits(&self) -> Result<()> {
+        if let Some(max_rows) = self.max_rows {
+            if self.len() >= max_rows {
+                fail!("Cannot push item: the batch is limited to {max_rows} rows");
+            }
+        }
+        self.check_max_bytes()
+    }
+
+    fn check_max_bytes(&self) -> Result<()> {
+        match self.budget.as_ref() {
+            Some(budget) => budget.check(),
+            None => Ok(()),
+        }
+    }
+
+    /// Count the fixed sized values of the given number of rows
+    fn count_rows(&self, n: usize) {
+        if let Some(budget) = self.budget.as_ref() {
+            budget.add_bits(self.row_bits.saturating_mul(n));
+        }
+    }
+
+    /// Call `func` for all dictionary builders
+    pub fn for_each_dictionary(&self, func: &mut dyn FnMut(&DictionaryUtf8Builder)) {
+        for (builder, _) in &self.builder.fields {
+            builder.for_each_dictionary(func);
+        }
+    }
+
+    /// Call `func` for all dictionary builders
+    pub fn for_each_dictionary_mut(
+        &mut self,
+        func: &mut dyn FnMut(&mut DictionaryUtf8Builder) -> Result<()>,
+    ) -> Result<()> {
+        for (builder, _) in &mut self.builder.fields {
+            builder.for_each_dictionary_mut(func)?;
+        }
+        Ok(())
+    }
+
+    /// Call `func` for all struct builders, including the outer one
+    pub fn for_each_struct_mut(&mut self, func: &mut dyn FnMut(&mut StructBuilder)) {
+        func(&mut self.builder);
+        for (builder, _) in &mut self.builder.fields {
+            builder.for_each_struct_mut(func);
+        }
+    }
+
+    /// Call `func` for all map builders, including nested ones
+    pub fn for_each_map_mut(&mut self, func: &mut dyn FnMut(&mut MapBuilder)) {
+        for (builder, _) in &mut self.builder.fields {
+            builder.for_each_map_mut(func);
+        }
+    }
+
+    /// Call `func` for all `Float16` builders, including nested ones
+    pub fn for_each_f16_mut(&mut self, func: &mut dyn FnMut(&mut FloatBuilder<f16>)) {
+        for (builder, _) in &mut self.builder.fields {
+            builder.for_each_f16_mut(func);
+        }
+    }
+
+    /// Call `func` for all `LargeUtf8` builders, including nested ones
+    #[cfg(feature = "http")]
+    pub fn for_each_large_utf8_mut(&mut self, func: &mut dyn FnMut(&mut Utf8Builder<i64>)) {
+        for (builder, _) in &mut self.builder.fields {
+            builder.for_each_large_utf8_mut(func);
+        }
+    }
+
+    /// Call `func` for all `Date64` and `Timestamp` builders
+    #[cfg(feature = "types-temporal")]
+    pub fn for_each_date64(&self, func: &mut dyn FnMut(&Date64Builder)) {
+        for (builder, _) in &self.builder.fields {
+            builder.for_each_date64(func);
+        }
+    }
+
+    /// Call `func` for all union builders, including nested ones
+    #[cfg(feature = "types-union")]
+    pub fn for_each_union_mut(&mut self, func: &mut dyn FnMut(&mut UnionBuilder)) {
+        for (builder, _) in &mut self.builder.fields {
+            builder.for_each_union_mut(func);
+        }
+    }
+
+    /// Call `func` for all `Date64` and `Timestamp` builders
+    #[cfg(feature = "types-temporal")]
+    pub fn for_each_date64_mut(&mut self, func: &mut dyn FnMut(&mut Date64Builder)) {
+        for (builder, _) in &mut self.builder.fields {
+            builder.for_each_date64_mut(func);
+        }
+    }
+
+    /// Call `func` with the path and the length limit of all string builders
+    pub fn for_each_string_limit_mut(
+        &mut self,
+        func: &mut dyn FnMut(&str, &mut Option<StringLengthLimit>),
+    ) {
+        for (builder, _) in &mut self.builder.fields {
+            builder.for_each_string_limit_mut(func);
+        }
     }
 
     /// Extract the contained struct fields
     pub fn take_records(&mut self) -> Result<Vec<ArrayBuilder>> {
+        self.check_failure()?;
         let mut result = Vec::new();
-        for (builder, _) in self.0.take_self().fields {
+        for (builder, _) in self.builder.take_self().fields {
             result.push(builder);
         }
+        if let Some(budget) = self.budget.as_ref() {
+            budget.reset(self.builder.byte_size());
+        }
         Ok(result)
     }
 
+    /// Append `n` records with all fields set to null
+    pub fn append_nulls(&mut self, n: usize) -> Result<()> {
+        self.check_failure()?;
+        if let Some(max_rows) = self.max_rows {
+            if self.len() + n > max_rows {
+                fail!("Cannot append {n} null rows: the batch is limited to {max_rows} rows");
+            }
+        }
+        self.check_max_bytes()?;
+        for (builder, meta) in &self.builder.fields {
+            if !builder.is_nullable() {
+                fail!(
+                    "Cannot append null rows: field $.{name} is not nullable",
+                    name = ChildName(&meta.name),
+                );
+            }
+        }
+
+        for (builder, _) in &mut self.builder.fields {
+            builder.serialize_nones(n)?;
+        }
+        self.builder.seq.push_seq_defaults(n)?;
+        self.count_rows(n);
+        if let Some(dedup) = self.deduplicator.as_mut() {
+            dedup.clear_previous();
+        }
+
+        if let Some(progress) = self.progress.as_mut() {
+            let reported = progress.processed / progress.every;
+            progress.processed += n;
+            if progress.processed / progress.every != reported {
+                (progress.callback)(progress.processed);
+            }
+        }
+        Ok(())
+    }
+
     /// Extend the builder with a sequence of items
     pub fn extend<T: Serialize>(&mut self, value: T) -> Result<()> {
         value.serialize(Mut(self))
@@ -57,19 +371,71 @@ impl OuterSequenceBuilder {
 
 impl OuterSequenceBuilder {
     fn element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
-        value.serialize(Mut(&mut self.0))
+        self.check_failure()?;
+        let encoded = match self.deduplicator.as_mut() {
+            Some(dedup) => match dedup.check(value)? {
+                Some(encoded) => Some(encoded),
+                None => return Ok(()),
+            },
+            None => None,
+        };
+        self.check_limits()?;
+        if let Some(fields) = self.shape_check.as_ref() {
+            check_item_shape(fields, value)?;
+            self.shape_check = None;
+        }
+        let row = self.len();
+        if let Err(err) = value.serialize(Mut(&mut self.builder)) {
+            if self.strict_nullability {
+                return Err(err.with_annotation("row", row.to_string()));
+            }
+            return Err(err);
+        }
+        self.count_rows(1);
+        if let (Some(dedup), Some(encoded)) = (self.deduplicator.as_mut(), encoded) {
+            dedup.insert(encoded);
+        }
+
+        if let Some(progress) = self.progress.as_mut() {
+            progress.processed += 1;
+            if progress.processed % progress.every == 0 {
+                (progress.callback)(progress.processed);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Clone for OuterSequenceBuilder {
+    fn clone(&self) -> Self {
+        let mut this = Self {
+            builder: self.builder.clone(),
+            shape_check: self.shape_check.clone(),
+            progress: self.progress.clone(),
+            failure: self.failure.clone(),
+            max_rows: self.max_rows,
+            budget: None,
+            row_bits: self.row_bits,
+            strict_nullability: self.strict_nullability,
+            deduplicator: self.deduplicator.clone(),
+        };
+        // the clone counts its buffered bytes separately
+        if let Some(budget) = self.budget.as_ref() {
+            this.install_budget(Some(budget.detach()));
+        }
+        this
     }
 }
 
 impl Context for OuterSequenceBuilder {
     fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
-        self.0.annotate(annotations)
+        self.builder.annotate(annotations)
     }
 }
 
 impl SimpleSerializer for OuterSequenceBuilder {
     fn serialize_none(&mut self) -> Result<()> {
-        self.0.serialize_none()
+        self.builder.serialize_none()
     }
 
     fn serialize_seq_start(&mut self, _: Option<usize>) -> Result<()> {
@@ -127,55 +493,141 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
 
     let builder = match &field.data_type {
         T::Null => match get_strategy_from_metadata(&field.metadata)? {
+            #[cfg(feature = "types-union")]
             Some(Strategy::UnknownVariant) => A::UnknownVariant(UnknownVariantBuilder::new(path)),
+            #[cfg(not(feature = "types-union"))]
+            Some(Strategy::UnknownVariant) => {
+                fail!(in ctx, "The UnknownVariant strategy requires the `types-union` feature")
+            }
             _ => A::Null(NullBuilder::new(path)),
         },
         T::Boolean => A::Bool(BoolBuilder::new(path, field.nullable)),
-        T::Int8 => A::I8(IntBuilder::new(path, field.nullable)),
+        T::Int8 => {
+            A::I8(IntBuilder::new(path, field.nullable).with_quantization(get_quantization(field)?))
+        }
         T::Int16 => A::I16(IntBuilder::new(path, field.nullable)),
         T::Int32 => A::I32(IntBuilder::new(path, field.nullable)),
         T::Int64 => A::I64(IntBuilder::new(path, field.nullable)),
-        T::UInt8 => A::U8(IntBuilder::new(path, field.nullable)),
+        T::UInt8 => {
+            A::U8(IntBuilder::new(path, field.nullable).with_quantization(get_quantization(field)?))
+        }
         T::UInt16 => A::U16(IntBuilder::new(path, field.nullable)),
         T::UInt32 => A::U32(IntBuilder::new(path, field.nullable)),
         T::UInt64 => A::U64(IntBuilder::new(path, field.nullable)),
         T::Float16 => A::F16(FloatBuilder::new(path, field.nullable)),
         T::Float32 => A::F32(FloatBuilder::new(path, field.nullable)),
         T::Float64 => A::F64(FloatBuilder::new(path, field.nullable)),
+        #[cfg(feature = "types-temporal")]
         T::Date32 => A::Date32(Date32Builder::new(path, field.nullable)),
+        #[cfg(feature = "types-temporal")]
         T::Date64 => A::Date64(Date64Builder::new(
             path,
             None,
             is_utc_strategy(get_strategy_from_metadata(&field.metadata)?.as_ref())?,
             field.nullable,
         )),
-        T::Timestamp(unit, tz) => A::Date64(Date64Builder::new(
-            path,
-            Some((*unit, tz.clone())),
-            is_utc_tz(tz.as_deref()).ctx(&ctx)?,
-            field.nullable,
-        )),
+        #[cfg(feature = "types-temporal")]
+        T::Timestamp(unit, tz) => A::Date64(
+            Date64Builder::new(
+                path,
+                Some((*unit, tz.clone())),
+                is_utc_tz(tz.as_deref()).ctx(&ctx)?,
+                field.nullable,
+            )
+            .with_seconds(is_seconds_strategy(&field.metadata)?)
+            .with_time_crate_str(is_time_crate_str_strategy(&field.metadata)?),
+        ),
+        #[cfg(feature = "types-temporal")]
         T::Time32(unit) => {
             if !matches!(unit, TimeUnit::Second | TimeUnit::Millisecond) {
                 fail!(in ctx, "Time32 only supports second or millisecond resolutions");
             }
             A::Time32(TimeBuilder::new(path, *unit, field.nullable))
         }
+        #[cfg(feature = "types-temporal")]
         T::Time64(unit) => {
             if !matches!(unit, TimeUnit::Nanosecond | TimeUnit::Microsecond) {
                 fail!(in ctx, "Time64 only supports nanosecond or microsecond resolutions");
             }
             A::Time64(TimeBuilder::new(path, *unit, field.nullable))
         }
-        T::Duration(unit) => A::Duration(DurationBuilder::new(path, *unit, field.nullable)),
+        #[cfg(feature = "types-temporal")]
+        T::Duration(unit) => A::Duration(
+            DurationBuilder::new(path, *unit, field.nullable)
+                .with_seconds(is_seconds_strategy(&field.metadata)?),
+        ),
+        #[cfg(feature = "types-temporal")]
+        T::Interval(unit) => match unit {
+            IntervalUnit::YearMonth => {
+                A::IntervalYearMonth(IntervalBuilder::new(path, field.nullable))
+            }
+            IntervalUnit::DayTime => A::IntervalDayTime(IntervalBuilder::new(path, field.nullable)),
+            IntervalUnit::MonthDayNano => {
+                A::IntervalMonthDayNano(IntervalBuilder::new(path, field.nullable))
+            }
+        },
+        #[cfg(feature = "types-decimal")]
         T::Decimal128(precision, scale) => A::Decimal128(DecimalBuilder::new(
             path,
             *precision,
             *scale,
+            match get_strategy_from_metadata(&field.metadata)? {
+                Some(Strategy::ScaledInt { scale }) => Some(scale),
+                _ => None,
+            },
             field.nullable,
         )),
-        T::Utf8 => A::Utf8(Utf8Builder::new(path, field.nullable)),
-        T::LargeUtf8 => A::LargeUtf8(Utf8Builder::new(path, field.nullable)),
+        #[cfg(feature = "types-decimal")]
+        T::Decimal256(precision, scale) => A::Decimal256(Decimal256Builder::new(
+            path,
+            *precision,
+            *scale,
+            match get_strategy_from_metadata(&field.metadata)? {
+                Some(Strategy::ScaledInt { scale }) => Some(scale),
+                _ => None,
+            },
+            field.nullable,
+        )),
+        #[cfg(not(feature = "types-temporal"))]
+        T::Date32
+        | T::Date64
+        | T::Timestamp(_, _)
+        | T::Time32(_)
+        | T::Time64(_)
+        | T::Duration(_)
+        | T::Interval(_) => {
+            fail!(in ctx, "Data type {dt} requires the `types-temporal` feature", dt = crate::internal::schema::DataTypeDisplay(&field.data_type))
+        }
+        #[cfg(not(feature = "types-decimal"))]
+        T::Decimal128(_, _) | T::Decimal256(_, _) => {
+            fail!(in ctx, "Data type {dt} requires the `types-decimal` feature", dt = crate::internal::schema::DataTypeDisplay(&field.data_type))
+        }
+        T::Utf8 | T::LargeUtf8 if is_adaptive_string_strategy(&field.metadata)? => A::LargeUtf8(
+            Utf8Builder::new(path, field.nullable)
+                .with_adaptive_offsets(true)
+                .with_narrow(matches!(field.data_type, T::Utf8)),
+        ),
+        T::Utf8 => A::Utf8(
+            Utf8Builder::new(path, field.nullable)
+                .with_stringify(is_stringify_strategy(&field.metadata)?),
+        ),
+        T::LargeUtf8 => A::LargeUtf8(
+            Utf8Builder::new(path, field.nullable)
+                .with_stringify(is_stringify_strategy(&field.metadata)?),
+        ),
+        #[cfg(has_arrow_view_support)]
+        T::Utf8View if is_adaptive_string_strategy(&field.metadata)? => {
+            A::LargeUtf8(Utf8Builder::new(path, field.nullable).with_views(true))
+        }
+        T::Utf8View | T::BinaryView | T::ListView(_) | T::LargeListView(_) => {
+            let data_type = DataTypeDisplay(&field.data_type).to_string();
+            return Err(Error::unsupported_data_type(
+                path,
+                data_type.clone(),
+                supported_alternatives(&data_type),
+                "view types cannot be built",
+            ));
+        }
         T::List(child) => {
             let child_path = format!("{path}.{child_name}", child_name = ChildName(&child.name));
             A::List(ListBuilder::new(
@@ -226,7 +678,27 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
                 .ctx(&ctx)?,
             )
         }
-        T::Struct(children) => A::Struct(build_struct(path, children, field.nullable)?),
+        T::Struct(_)
+            if get_strategy_from_metadata(&field.metadata)?
+                == Some(Strategy::EnumAsStructWithTag) =>
+        {
+            fail!(in ctx, "The EnumAsStructWithTag strategy is only supported in deserialization")
+        }
+        T::Struct(children)
+            if get_strategy_from_metadata(&field.metadata)? == Some(Strategy::EnumOneHot) =>
+        {
+            let mut builder = build_struct(path, children, field.nullable)?;
+            builder.one_hot = true;
+            A::Struct(builder)
+        }
+        T::Struct(children) => {
+            let strategy = get_strategy_from_metadata(&field.metadata)?;
+            let mut builder = build_struct(path, children, field.nullable)?;
+            if let Some(Strategy::DictionaryOverflow { max_len }) = strategy {
+                builder.overflow_max_len = Some(max_len);
+            }
+            A::Struct(builder)
+        }
         T::Dictionary(key, value, _) => {
             let key_path = format!("{path}.key");
             let key_field = Field {
@@ -250,7 +722,8 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
                 build_builder(value_path, &value_field)?,
             ))
         }
-        T::Union(union_fields, _) => {
+        #[cfg(feature = "types-union")]
+        T::Union(union_fields, mode) => {
             let mut fields = Vec::new();
             for (idx, (type_id, field)) in union_fields.iter().enumerate() {
                 if usize::try_from(*type_id) != Ok(idx) {
@@ -264,12 +737,50 @@ fn build_builder(path: String, field: &Field) -> Result<ArrayBuilder> {
                 ));
             }
 
-            A::Union(UnionBuilder::new(path, fields))
+            let untagged =
+                get_strategy_from_metadata(&field.metadata)? == Some(Strategy::UntaggedUnion);
+            A::Union(
+                UnionBuilder::new(path, fields)?
+                    .with_untagged(untagged)
+                    .with_mode(*mode),
+            )
         }
+        #[cfg(not(feature = "types-union"))]
+        T::Union(_, _) => fail!(in ctx, "Data type Union requires the `types-union` feature"),
     };
     Ok(builder)
 }
 
+fn is_stringify_strategy(metadata: &HashMap<String, String>) -> Result<bool> {
+    Ok(get_strategy_from_metadata(metadata)? == Some(Strategy::Stringify))
+}
+
+#[cfg(feature = "types-temporal")]
+fn is_seconds_strategy(metadata: &HashMap<String, String>) -> Result<bool> {
+    Ok(get_strategy_from_metadata(metadata)? == Some(Strategy::Seconds))
+}
+
+#[cfg(feature = "types-temporal")]
+fn is_time_crate_str_strategy(metadata: &HashMap<String, String>) -> Result<bool> {
+    Ok(get_strategy_from_metadata(metadata)? == Some(Strategy::TimeCrateStr))
+}
+
+fn is_adaptive_string_strategy(metadata: &HashMap<String, String>) -> Result<bool> {
+    Ok(get_strategy_from_metadata(metadata)? == Some(Strategy::AdaptiveString))
+}
+
+fn get_quantization(field: &Field) -> Result<Option<Quantization>> {
+    match get_strategy_from_metadata(&field.metadata)? {
+        Some(Strategy::Quantized { scale, zero_point }) => Ok(Some(Quantization::new(
+            scale,
+            zero_point,
+            &field.data_type,
+        )?)),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(feature = "types-temporal")]
 fn is_utc_tz(tz: Option<&str>) -> Result<bool> {
     match tz {
         None => Ok(false),
@@ -278,6 +789,7 @@ fn is_utc_tz(tz: Option<&str>) -> Result<bool> {
     }
 }
 
+#[cfg(feature = "types-temporal")]
 fn is_utc_strategy(strategy: Option<&Strategy>) -> Result<bool> {
     match strategy {
         Some(Strategy::UtcStrAsDate64) | None => Ok(true),
@@ -0,0 +1,591 @@
+//! Collect a canonical byte stream of serialized values for fields with the
+//! `RowHash` strategy and to detect duplicate rows
+use serde::{
+    ser::{
+        Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+        SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    },
+    Serialize, Serializer,
+};
+
+use crate::internal::error::{fail, Error, Result};
+
+/// Append the encoding of serde values to a byte buffer
+///
+/// Each value is prefixed with a tag identifying its kind and strings and
+/// bytes are length prefixed. Therefore, different sequences of values result
+/// in different byte streams.
+pub struct RowDigest<'a>(pub &'a mut Vec<u8>);
+
+impl<'a> RowDigest<'a> {
+    pub fn push<V: Serialize + ?Sized>(buffer: &'a mut Vec<u8>, value: &V) -> Result<()> {
+        value.serialize(&mut RowDigest(buffer))
+    }
+
+    fn tag(&mut self, tag: u8) {
+        self.0.push(tag);
+    }
+
+    fn bytes(&mut self, tag: u8, data: &[u8]) {
+        self.0.push(tag);
+        self.0.extend((data.len() as u64).to_le_bytes());
+        self.0.extend(data);
+    }
+}
+
+impl<'a, 'b> Serializer for &'b mut RowDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.0.extend([b'b', u8::from(v)]);
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.tag(b'1');
+        self.0.extend(v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.tag(b'2');
+        self.0.extend(v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.tag(b'3');
+        self.0.extend(v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.tag(b'4');
+        self.0.extend(v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.tag(b'5');
+        self.0.extend(v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.tag(b'6');
+        self.0.extend(v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.tag(b'7');
+        self.0.extend(v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.tag(b'8');
+        self.0.extend(v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.tag(b'f');
+        self.0.extend(v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.tag(b'd');
+        self.0.extend(v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.tag(b'c');
+        self.0.extend(u32::from(v).to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.bytes(b's', v.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.bytes(b'y', v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.tag(b'n');
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        self.tag(b'S');
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.tag(b'u');
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<()> {
+        self.tag(b'u');
+        Ok(())
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<()> {
+        self.bytes(b'v', variant.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.bytes(b'V', variant.as_bytes());
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self> {
+        self.tag(b'[');
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self> {
+        self.tag(b'[');
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self> {
+        self.tag(b'[');
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self> {
+        self.bytes(b'V', variant.as_bytes());
+        self.tag(b'[');
+        Ok(self)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self> {
+        self.tag(b'{');
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self> {
+        self.tag(b'{');
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self> {
+        self.bytes(b'V', variant.as_bytes());
+        self.tag(b'{');
+        Ok(self)
+    }
+}
+
+impl<'a, 'b> SerializeSeq for &'b mut RowDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        self.tag(b']');
+        Ok(())
+    }
+}
+
+impl<'a, 'b> SerializeTuple for &'b mut RowDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        self.tag(b']');
+        Ok(())
+    }
+}
+
+impl<'a, 'b> SerializeTupleStruct for &'b mut RowDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        self.tag(b']');
+        Ok(())
+    }
+}
+
+impl<'a, 'b> SerializeTupleVariant for &'b mut RowDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        self.tag(b']');
+        Ok(())
+    }
+}
+
+impl<'a, 'b> SerializeMap for &'b mut RowDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        self.tag(b'}');
+        Ok(())
+    }
+}
+
+impl<'a, 'b> SerializeStruct for &'b mut RowDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.bytes(b's', key.as_bytes());
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        self.tag(b'}');
+        Ok(())
+    }
+}
+
+impl<'a, 'b> SerializeStructVariant for &'b mut RowDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.bytes(b's', key.as_bytes());
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        self.tag(b'}');
+        Ok(())
+    }
+}
+
+/// Encode the values of selected top-level fields of a record
+///
+/// The values are encoded with [`RowDigest`] in the order of `keys`,
+/// independent of the order of the fields in the record. Missing fields are
+/// encoded with a separate tag. Records must be structs or maps.
+pub struct KeyDigest<'a> {
+    /// The encoded names of the key fields
+    keys: &'a [Vec<u8>],
+    /// The encoded values of the key fields found so far
+    values: Vec<Option<Vec<u8>>>,
+    /// The index of the key of the current map entry, if it is a key field
+    next: Option<usize>,
+}
+
+impl<'a> KeyDigest<'a> {
+    /// Encode the names of the key fields for use with [`KeyDigest::encode`]
+    pub fn encode_keys(names: &[String]) -> Result<Vec<Vec<u8>>> {
+        let mut keys = Vec::new();
+        for name in names {
+            let mut key = Vec::new();
+            RowDigest::push(&mut key, name.as_str())?;
+            keys.push(key);
+        }
+        Ok(keys)
+    }
+
+    /// Encode the key fields of `value` into `buffer`
+    pub fn encode<V: Serialize + ?Sized>(
+        keys: &'a [Vec<u8>],
+        value: &V,
+        buffer: &mut Vec<u8>,
+    ) -> Result<()> {
+        let mut digest = KeyDigest {
+            keys,
+            values: vec![None; keys.len()],
+            next: None,
+        };
+        value.serialize(&mut digest)?;
+
+        for value in digest.values {
+            match value {
+                Some(value) => buffer.extend(value),
+                None => buffer.push(b'-'),
+            }
+        }
+        Ok(())
+    }
+
+    fn find_key<K: Serialize + ?Sized>(&self, key: &K) -> Result<Option<usize>> {
+        let mut encoded = Vec::new();
+        RowDigest::push(&mut encoded, key)?;
+        Ok(self.keys.iter().position(|candidate| *candidate == encoded))
+    }
+
+    fn push_value<V: Serialize + ?Sized>(&mut self, idx: usize, value: &V) -> Result<()> {
+        let mut encoded = Vec::new();
+        RowDigest::push(&mut encoded, value)?;
+        self.values[idx] = Some(encoded);
+        Ok(())
+    }
+
+    fn unsupported<T>(name: &str) -> Result<T> {
+        fail!("Cannot deduplicate rows by key: records must be structs or maps, found {name}")
+    }
+}
+
+impl<'a, 'b> Serializer for &'b mut KeyDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Impossible<(), Error>;
+    type SerializeTuple = Impossible<(), Error>;
+    type SerializeTupleStruct = Impossible<(), Error>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    fn serialize_bool(self, _: bool) -> Result<()> {
+        KeyDigest::unsupported("bool")
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<()> {
+        KeyDigest::unsupported("i8")
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<()> {
+        KeyDigest::unsupported("i16")
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<()> {
+        KeyDigest::unsupported("i32")
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<()> {
+        KeyDigest::unsupported("i64")
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<()> {
+        KeyDigest::unsupported("u8")
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<()> {
+        KeyDigest::unsupported("u16")
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<()> {
+        KeyDigest::unsupported("u32")
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<()> {
+        KeyDigest::unsupported("u64")
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<()> {
+        KeyDigest::unsupported("f32")
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<()> {
+        KeyDigest::unsupported("f64")
+    }
+
+    fn serialize_char(self, _: char) -> Result<()> {
+        KeyDigest::unsupported("char")
+    }
+
+    fn serialize_str(self, _: &str) -> Result<()> {
+        KeyDigest::unsupported("str")
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<()> {
+        KeyDigest::unsupported("bytes")
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        KeyDigest::unsupported("none")
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        KeyDigest::unsupported("unit")
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<()> {
+        KeyDigest::unsupported("unit struct")
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<()> {
+        KeyDigest::unsupported("unit variant")
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<()> {
+        KeyDigest::unsupported("newtype variant")
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq> {
+        KeyDigest::unsupported("seq")
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple> {
+        KeyDigest::unsupported("tuple")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        KeyDigest::unsupported("tuple struct")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        KeyDigest::unsupported("tuple variant")
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        KeyDigest::unsupported("struct variant")
+    }
+}
+
+impl<'a, 'b> SerializeMap for &'b mut KeyDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.next = self.find_key(key)?;
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        if let Some(idx) = self.next.take() {
+            self.push_value(idx, value)?;
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b> SerializeStruct for &'b mut KeyDigest<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        if let Some(idx) = self.find_key(key)? {
+            self.push_value(idx, value)?;
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
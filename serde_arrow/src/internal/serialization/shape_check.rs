@@ -0,0 +1,164 @@
+//! Check the shape of items against the fields before serializing them
+use serde::Serialize;
+
+use crate::internal::{
+    arrow::{DataType, Field},
+    error::{fail, Result},
+    schema::{get_strategy_from_metadata, tracer::Tracer, Strategy, TracingOptions},
+};
+
+/// Check that the item is compatible with the fields
+///
+/// The item is traced and compared to the fields: non-nullable fields must be
+/// present and nested types (structs, lists, maps, unions) must be compatible.
+/// Additional fields of the item are ignored, as the builders ignore them. Primitive
+/// types are not compared, as the builders support various conversions. All
+/// mismatches are reported in a single error.
+pub fn check_item_shape<V: Serialize + ?Sized>(fields: &[Field], item: &V) -> Result<()> {
+    let options = TracingOptions::default().allow_null_fields(true);
+    let traced = match Tracer::from_samples((item,), options).and_then(|t| t.to_schema()) {
+        Ok(schema) => schema.fields,
+        Err(err) => {
+            fail!("Strict schema check failed: cannot determine the shape of the item: {err}")
+        }
+    };
+
+    let mut mismatches = Vec::new();
+    check_fields("$", fields, &traced, &mut mismatches);
+
+    if !mismatches.is_empty() {
+        fail!(
+            "Strict schema check failed: the item does not match the schema: {}",
+            mismatches.join("; ")
+        );
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape {
+    Null,
+    Primitive,
+    Binary,
+    List,
+    Struct,
+    Tuple,
+    Map,
+    Union,
+}
+
+impl Shape {
+    fn of(field: &Field) -> Self {
+        match &field.data_type {
+            DataType::Null => Self::Null,
+            DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => Self::Binary,
+            DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _) => {
+                Self::List
+            }
+            DataType::Struct(_) => {
+                if matches!(
+                    get_strategy_from_metadata(&field.metadata),
+                    Ok(Some(Strategy::TupleAsStruct))
+                ) {
+                    Self::Tuple
+                } else {
+                    Self::Struct
+                }
+            }
+            DataType::Map(_, _) => Self::Map,
+            DataType::Union(_, _) => Self::Union,
+            _ => Self::Primitive,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Primitive => "primitive",
+            Self::Binary => "binary",
+            Self::List => "list",
+            Self::Struct => "struct",
+            Self::Tuple => "tuple",
+            Self::Map => "map",
+            Self::Union => "enum",
+        }
+    }
+}
+
+fn check_fields(path: &str, expected: &[Field], actual: &[Field], mismatches: &mut Vec<String>) {
+    for field in expected {
+        if matches!(
+            get_strategy_from_metadata(&field.metadata),
+            Ok(Some(Strategy::StringHash { .. } | Strategy::RowHash))
+        ) {
+            // filled from other fields, not part of the item
+            continue;
+        }
+        match actual.iter().find(|f| f.name == field.name) {
+            Some(actual) => {
+                check_field(&format!("{path}.{}", field.name), field, actual, mismatches)
+            }
+            None if !field.nullable => mismatches.push(format!(
+                "missing non-nullable field {path}.{name}",
+                name = field.name
+            )),
+            None => {}
+        }
+    }
+}
+
+fn check_field(path: &str, expected: &Field, actual: &Field, mismatches: &mut Vec<String>) {
+    use Shape as S;
+
+    let expected_shape = Shape::of(expected);
+    let actual_shape = Shape::of(actual);
+
+    let compatible = match (expected_shape, actual_shape) {
+        (_, S::Null) => true,
+        (S::Struct, S::Struct) => {
+            if let (DataType::Struct(expected), DataType::Struct(actual)) =
+                (&expected.data_type, &actual.data_type)
+            {
+                check_fields(path, expected, actual, mismatches);
+            }
+            true
+        }
+        (S::Struct | S::Tuple, S::Tuple) => true,
+        (S::Struct | S::Map, S::Struct | S::Map) => true,
+        (S::List, S::List) => {
+            if let (Some(expected), Some(actual)) = (
+                list_element(&expected.data_type),
+                list_element(&actual.data_type),
+            ) {
+                check_field(
+                    &format!("{path}.{}", expected.name),
+                    expected,
+                    actual,
+                    mismatches,
+                );
+            }
+            true
+        }
+        (S::List, S::Tuple | S::Binary) => true,
+        (S::Binary, S::Binary | S::List | S::Tuple) => true,
+        (S::Union, S::Union) => true,
+        (S::Primitive, S::Primitive | S::Union) => true,
+        _ => false,
+    };
+
+    if !compatible {
+        mismatches.push(format!(
+            "field {path} expected {expected}, found {actual}",
+            expected = expected_shape.name(),
+            actual = actual_shape.name(),
+        ));
+    }
+}
+
+fn list_element(data_type: &DataType) -> Option<&Field> {
+    match data_type {
+        DataType::List(element) | DataType::LargeList(element) => Some(element),
+        DataType::FixedSizeList(element, _) => Some(element),
+        _ => None,
+    }
+}
@@ -35,6 +35,22 @@ pub trait SimpleSerializer: Sized + Context {
         fail!(in self, "serialize_unit/serialize_none is not supported");
     }
 
+    /// Serialize `n` default values, equivalent to `n` calls of `serialize_default`
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.serialize_default()?;
+        }
+        Ok(())
+    }
+
+    /// Serialize `n` nulls, equivalent to `n` calls of `serialize_none`
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.serialize_none()?;
+        }
+        Ok(())
+    }
+
     fn serialize_some<V: serde::Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
         value.serialize(Mut(self))
     }
@@ -5,15 +5,22 @@ use serde::Serialize;
 use crate::internal::{
     arrow::{Array, FieldMeta, StructArray},
     error::{fail, set_default, try_, Context, ContextSupport, Result},
+    schema::{
+        get_default_value, get_strategy_from_metadata, DefaultValue, DuplicateFieldPolicy, Strategy,
+    },
     utils::{
         array_ext::{ArrayExt, CountArray, SeqArrayExt},
+        xxhash::xxh64,
         Mut,
     },
 };
 
-use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+use super::{
+    array_builder::ArrayBuilder, row_digest::RowDigest, simple_serializer::SimpleSerializer,
+};
 
 const UNKNOWN_KEY: usize = usize::MAX;
+const CATCH_ALL_KEY: usize = usize::MAX - 1;
 
 #[derive(Debug, Clone)]
 pub struct StructBuilder {
@@ -23,6 +30,29 @@ pub struct StructBuilder {
     pub next: usize,
     pub seen: Vec<bool>,
     pub seq: CountArray,
+    /// If `true`, write default values for missing non-nullable fields
+    /// instead of failing
+    pub missing_as_default: bool,
+    /// If `true`, match non-string map keys by their string representation
+    pub stringify_keys: bool,
+    /// How repeated fields of an item are written
+    pub duplicate_fields: DuplicateFieldPolicy,
+    /// For each field, the indices of the fields filled with its hash
+    pub hash_targets: Vec<Vec<usize>>,
+    /// The index of the map field collecting unknown keys, if any
+    pub catch_all: Option<usize>,
+    /// The indices of the fields filled with the digest of the row
+    pub row_hash_targets: Vec<usize>,
+    /// The encoded fields of the current row, used to compute the digest
+    pub row_digest: Vec<u8>,
+    /// If `true`, serialize unit variants by setting the flag of the field
+    /// named after the variant
+    pub one_hot: bool,
+    /// If set, serialize strings into the dictionary or, if longer, into the
+    /// overflow field
+    pub overflow_max_len: Option<usize>,
+    /// For each field, the value to write if the field is missing
+    pub defaults: Vec<Option<DefaultValue>>,
 }
 
 impl StructBuilder {
@@ -32,6 +62,19 @@ impl StructBuilder {
         is_nullable: bool,
     ) -> Result<Self> {
         let lookup = FieldLookup::new(fields.iter().map(|(_, meta)| meta.name.clone()).collect())?;
+        let hash_targets = build_hash_targets(&fields, &lookup)?;
+
+        let mut catch_all = None;
+        let mut row_hash_targets = Vec::new();
+        let mut defaults = Vec::new();
+        for (idx, (_, meta)) in fields.iter().enumerate() {
+            defaults.push(get_default_value(&meta.metadata)?);
+            match get_strategy_from_metadata(&meta.metadata)? {
+                Some(Strategy::CatchAll) => catch_all = Some(idx),
+                Some(Strategy::RowHash) => row_hash_targets.push(idx),
+                _ => {}
+            }
+        }
 
         Ok(Self {
             path,
@@ -40,6 +83,16 @@ impl StructBuilder {
             next: 0,
             lookup,
             fields,
+            missing_as_default: false,
+            stringify_keys: false,
+            duplicate_fields: DuplicateFieldPolicy::Error,
+            hash_targets,
+            catch_all,
+            row_hash_targets,
+            row_digest: Vec::new(),
+            one_hot: false,
+            overflow_max_len: None,
+            defaults,
         })
     }
 
@@ -55,6 +108,16 @@ impl StructBuilder {
             seen: std::mem::replace(&mut self.seen, vec![false; self.fields.len()]),
             seq: self.seq.take(),
             next: std::mem::take(&mut self.next),
+            missing_as_default: self.missing_as_default,
+            stringify_keys: self.stringify_keys,
+            duplicate_fields: self.duplicate_fields,
+            hash_targets: self.hash_targets.clone(),
+            catch_all: self.catch_all,
+            row_hash_targets: self.row_hash_targets.clone(),
+            row_digest: Vec::new(),
+            one_hot: self.one_hot,
+            overflow_max_len: self.overflow_max_len,
+            defaults: self.defaults.clone(),
         }
     }
 
@@ -66,6 +129,22 @@ impl StructBuilder {
         self.seq.validity.is_some()
     }
 
+    pub fn byte_size(&self) -> usize {
+        self.seq.byte_size()
+            + self
+                .fields
+                .iter()
+                .map(|(builder, _)| builder.byte_size())
+                .sum::<usize>()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.seq.reserve(additional);
+        for (builder, _) in &mut self.fields {
+            builder.reserve(additional);
+        }
+    }
+
     pub fn into_array(self) -> Result<Array> {
         let mut fields = Vec::new();
         for (builder, meta) in self.fields {
@@ -84,44 +163,143 @@ impl StructBuilder {
     fn start(&mut self) -> Result<()> {
         self.seq.start_seq()?;
         self.reset();
+        if let Some(idx) = self.catch_all {
+            self.fields[idx].0.serialize_map_start(None)?;
+        }
         Ok(())
     }
 
     fn reset(&mut self) {
         self.seen.fill(false);
         self.next = 0;
+        self.row_digest.clear();
     }
 
     fn end(&mut self) -> Result<()> {
         self.seq.end_seq()?;
+        if let Some(idx) = self.catch_all {
+            self.fields[idx].0.serialize_map_end()?;
+            self.seen[idx] = true;
+        }
+        if !self.row_hash_targets.is_empty() {
+            let hash = xxh64(&self.row_digest, 0);
+            for &target in &self.row_hash_targets {
+                if self.seen[target] {
+                    fail!(in self, "Duplicate field {key}", key = self.fields[target].1.name);
+                }
+                match &mut self.fields[target].0 {
+                    ArrayBuilder::FixedSizeBinary(builder) => {
+                        builder.serialize_bytes(&hash.to_be_bytes())?
+                    }
+                    builder => builder.serialize_u64(hash)?,
+                }
+                self.seen[target] = true;
+            }
+        }
         for (idx, seen) in self.seen.iter_mut().enumerate() {
             if !*seen {
-                if !self.fields[idx].1.nullable {
+                if let Some(default) = &self.defaults[idx] {
+                    default.serialize(Mut(&mut self.fields[idx].0))?;
+                } else if self.fields[idx].1.nullable {
+                    self.fields[idx].0.serialize_none()?;
+                } else if self.missing_as_default {
+                    self.fields[idx].0.serialize_default()?;
+                } else {
                     fail!(
                         "Missing non-nullable field {:?} in struct",
                         self.fields[idx].1.name
                     );
                 }
-
-                self.fields[idx].0.serialize_none()?;
             }
         }
         Ok(())
     }
 
+    /// Select the field written for a value of the field `idx`, `None` if the
+    /// value is ignored
+    fn resolve_duplicate(&self, idx: usize) -> Result<Option<usize>> {
+        if !self.seen[idx] {
+            return Ok(Some(idx));
+        }
+        match self.duplicate_fields {
+            DuplicateFieldPolicy::Error => {
+                fail!(in self, "Duplicate field {key}", key = self.fields[idx].1.name)
+            }
+            DuplicateFieldPolicy::Merge => Ok(None),
+            DuplicateFieldPolicy::Suffix => {
+                let name = &self.fields[idx].1.name;
+                let mut occurrence = 1;
+                while let Some(&candidate) = self.lookup.index.get(&format!("{name}_{occurrence}"))
+                {
+                    if !self.seen[candidate] {
+                        return Ok(Some(candidate));
+                    }
+                    occurrence += 1;
+                }
+                Ok(None)
+            }
+        }
+    }
+
     fn element<T: Serialize + ?Sized>(&mut self, idx: usize, value: &T) -> Result<()> {
+        let Some(idx) = self.resolve_duplicate(idx)? else {
+            return Ok(());
+        };
         self.seq.push_seq_elements(1)?;
-        if self.seen[idx] {
-            fail!(in self, "Duplicate field {key}", key = self.fields[idx].1.name);
-        }
 
         value.serialize(Mut(&mut self.fields[idx].0))?;
         self.seen[idx] = true;
         self.next = idx + 1;
+
+        if !self.row_hash_targets.is_empty() {
+            RowDigest::push(&mut self.row_digest, self.fields[idx].1.name.as_str())?;
+            RowDigest::push(&mut self.row_digest, value)?;
+        }
+
+        for &target in &self.hash_targets[idx] {
+            if self.seen[target] {
+                fail!(in self, "Duplicate field {key}", key = self.fields[target].1.name);
+            }
+            match StringHashSerializer::hash(value)? {
+                Some(hash) => self.fields[target].0.serialize_u64(hash)?,
+                None => self.fields[target].0.serialize_none()?,
+            }
+            self.seen[target] = true;
+        }
         Ok(())
     }
 }
 
+fn build_hash_targets(
+    fields: &[(ArrayBuilder, FieldMeta)],
+    lookup: &FieldLookup,
+) -> Result<Vec<Vec<usize>>> {
+    let mut hash_targets = vec![Vec::new(); fields.len()];
+    for (target, (_, meta)) in fields.iter().enumerate() {
+        let Some(Strategy::StringHash { source }) = get_strategy_from_metadata(&meta.metadata)?
+        else {
+            continue;
+        };
+        let Some(&source_idx) = lookup.index.get(&source) else {
+            fail!(
+                "Source field {source:?} of field {target:?} with strategy StringHash does not exist",
+                target = meta.name,
+            );
+        };
+        if !matches!(
+            fields[source_idx].0,
+            ArrayBuilder::Utf8(_) | ArrayBuilder::LargeUtf8(_) | ArrayBuilder::DictionaryUtf8(_)
+        ) {
+            fail!(
+                "Source field {source:?} of field {target:?} with strategy StringHash must be a string field",
+                target = meta.name,
+            );
+        }
+        hash_targets[source_idx].push(target);
+    }
+    Ok(hash_targets)
+}
+
 impl Context for StructBuilder {
     fn annotate(&self, annotations: &mut BTreeMap<String, String>) {
         set_default(annotations, "field", &self.path);
@@ -153,6 +331,76 @@ impl SimpleSerializer for StructBuilder {
         .ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| {
+            self.seq.push_seq_defaults(n)?;
+            for (builder, _) in &mut self.fields {
+                builder.serialize_defaults(n)?;
+            }
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| {
+            self.seq.push_seq_nones(n)?;
+            for (builder, _) in &mut self.fields {
+                builder.serialize_defaults(n)?;
+            }
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_unit_variant(
+        &mut self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        try_(|| {
+            if !self.one_hot {
+                fail!(
+                    "Cannot serialize enum variants into structs without the EnumOneHot strategy"
+                );
+            }
+            let Some(&selected) = self.lookup.index.get(variant) else {
+                fail!("Unknown variant {variant}");
+            };
+            self.seq.start_seq()?;
+            self.seq.end_seq()?;
+            for (idx, (builder, _)) in self.fields.iter_mut().enumerate() {
+                builder.serialize_bool(idx == selected)?;
+            }
+            Ok(())
+        })
+        .ctx(self)
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        try_(|| {
+            let Some(max_len) = self.overflow_max_len else {
+                fail!(
+                    "Cannot serialize strings into structs without the DictionaryOverflow strategy"
+                );
+            };
+            let [(values, _), (overflow, _)] = self.fields.as_mut_slice() else {
+                fail!("DictionaryOverflow fields require exactly two children");
+            };
+            self.seq.start_seq()?;
+            self.seq.end_seq()?;
+            if v.len() <= max_len {
+                values.serialize_str(v)?;
+                overflow.serialize_none()
+            } else {
+                values.serialize_none()?;
+                overflow.serialize_str(v)
+            }
+        })
+        .ctx(self)
+    }
+
     fn serialize_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
         try_(|| self.start()).ctx(self)
     }
@@ -167,6 +415,9 @@ impl SimpleSerializer for StructBuilder {
                 // ignore unknown fields
                 return Ok(());
             };
+            if Some(idx) == self.catch_all {
+                return Ok(());
+            }
             self.element(idx, value)
         })
         .ctx(self)
@@ -219,7 +470,19 @@ impl SimpleSerializer for StructBuilder {
 
     fn serialize_map_key<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<()> {
         try_(|| {
-            self.next = self.lookup.lookup_serialize(key)?.unwrap_or(UNKNOWN_KEY);
+            self.next = self
+                .lookup
+                .lookup_serialize(key, self.stringify_keys)?
+                .unwrap_or(UNKNOWN_KEY);
+            if let Some(idx) = self.catch_all {
+                if self.next == UNKNOWN_KEY || self.next == idx {
+                    self.fields[idx].0.serialize_map_key(key)?;
+                    self.next = CATCH_ALL_KEY;
+                    if !self.row_hash_targets.is_empty() {
+                        RowDigest::push(&mut self.row_digest, key)?;
+                    }
+                }
+            }
             Ok(())
         })
         .ctx(self)
@@ -227,7 +490,14 @@ impl SimpleSerializer for StructBuilder {
 
     fn serialize_map_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
         try_(|| {
-            if self.next != UNKNOWN_KEY {
+            if self.next == CATCH_ALL_KEY {
+                if let Some(idx) = self.catch_all {
+                    self.fields[idx].0.serialize_map_value(value)?;
+                    if !self.row_hash_targets.is_empty() {
+                        RowDigest::push(&mut self.row_digest, value)?;
+                    }
+                }
+            } else if self.next != UNKNOWN_KEY {
                 self.element(self.next, value)?;
             }
             // see serialize_map_start
@@ -293,14 +563,19 @@ impl FieldLookup {
         }
     }
 
-    pub fn lookup_serialize<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<Option<usize>> {
-        KeyLookupSerializer::lookup(&self.index, key)
+    pub fn lookup_serialize<V: Serialize + ?Sized>(
+        &mut self,
+        key: &V,
+        stringify: bool,
+    ) -> Result<Option<usize>> {
+        KeyLookupSerializer::lookup(&self.index, key, stringify)
     }
 }
 
 #[derive(Debug)]
 pub struct KeyLookupSerializer<'a> {
     index: &'a BTreeMap<String, usize>,
+    stringify: bool,
     result: Option<usize>,
 }
 
@@ -308,9 +583,11 @@ impl<'a> KeyLookupSerializer<'a> {
     pub fn lookup<K: Serialize + ?Sized>(
         index: &'a BTreeMap<String, usize>,
         key: &K,
+        stringify: bool,
     ) -> Result<Option<usize>> {
         let mut this = Self {
             index,
+            stringify,
             result: None,
         };
         key.serialize(Mut(&mut this))?;
@@ -322,9 +599,107 @@ impl<'a> Context for KeyLookupSerializer<'a> {
     fn annotate(&self, _: &mut BTreeMap<String, String>) {}
 }
 
+impl<'a> KeyLookupSerializer<'a> {
+    fn lookup_display(&mut self, ty: &str, v: impl std::fmt::Display) -> Result<()> {
+        if !self.stringify {
+            fail!("Cannot use {ty} keys for struct fields without the `map_struct_interchange` option");
+        }
+        self.result = self.index.get(&v.to_string()).copied();
+        Ok(())
+    }
+}
+
 impl<'a> SimpleSerializer for KeyLookupSerializer<'a> {
     fn serialize_str(&mut self, v: &str) -> Result<()> {
         self.result = self.index.get(v).copied();
         Ok(())
     }
+
+    fn serialize_bool(&mut self, v: bool) -> Result<()> {
+        self.lookup_display("bool", v)
+    }
+
+    fn serialize_char(&mut self, v: char) -> Result<()> {
+        self.lookup_display("char", v)
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.lookup_display("i8", v)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.lookup_display("i16", v)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.lookup_display("i32", v)
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.lookup_display("i64", v)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.lookup_display("u8", v)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.lookup_display("u16", v)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.lookup_display("u32", v)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.lookup_display("u64", v)
+    }
+
+    fn serialize_unit_variant(
+        &mut self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.lookup_display("enum", variant)
+    }
+}
+
+/// Compute the hash of string values for fields with the `StringHash` strategy
+#[derive(Debug, Default)]
+pub struct StringHashSerializer {
+    result: Option<u64>,
+}
+
+impl StringHashSerializer {
+    pub fn hash<V: Serialize + ?Sized>(value: &V) -> Result<Option<u64>> {
+        let mut this = Self::default();
+        value.serialize(Mut(&mut this))?;
+        Ok(this.result)
+    }
+}
+
+impl Context for StringHashSerializer {
+    fn annotate(&self, _: &mut BTreeMap<String, String>) {}
+}
+
+impl SimpleSerializer for StringHashSerializer {
+    fn serialize_none(&mut self) -> Result<()> {
+        self.result = None;
+        Ok(())
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        self.result = Some(xxh64(v.as_bytes(), 0));
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        &mut self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.serialize_str(variant)
+    }
 }
@@ -40,6 +40,10 @@ impl<I: Default + 'static> TimeBuilder<I> {
     pub fn is_nullable(&self) -> bool {
         self.array.validity.is_some()
     }
+
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
 }
 
 impl TimeBuilder<i32> {
@@ -99,6 +103,14 @@ where
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
     fn serialize_str(&mut self, v: &str) -> Result<()> {
         try_(|| {
             let (seconds_factor, nanoseconds_factor) = match self.unit {
@@ -1,9 +1,10 @@
 use std::collections::BTreeMap;
 
 use crate::internal::{
-    arrow::{Array, DenseUnionArray, FieldMeta},
+    arrow::{Array, DenseUnionArray, FieldMeta, SparseUnionArray, UnionMode},
     error::{fail, set_default, try_, Context, ContextSupport, Result},
-    utils::Mut,
+    schema::{get_strategy_from_metadata, Strategy},
+    utils::{array_ext::array_len, Mut},
 };
 
 use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
@@ -15,21 +16,84 @@ pub struct UnionBuilder {
     pub types: Vec<i8>,
     pub offsets: Vec<i32>,
     pub current_offset: Vec<i32>,
+    /// The index of the variant used to encode missing values, if any
+    pub null_variant: Option<usize>,
+    /// If `true`, primitive values select the child by their type
+    pub untagged: bool,
+    /// For sparse unions, all children receive a value for every element
+    pub mode: UnionMode,
+    /// The child selected by the last untagged value, keyed by the serialize
+    /// method, to skip the search for consecutive values of the same type
+    last_untagged: Option<(&'static str, usize)>,
+    /// The expected number of values per variant, reserved for each batch
+    capacities: Vec<usize>,
 }
 
 impl UnionBuilder {
-    pub fn new(path: String, fields: Vec<(ArrayBuilder, FieldMeta)>) -> Self {
-        Self {
+    pub fn new(path: String, fields: Vec<(ArrayBuilder, FieldMeta)>) -> Result<Self> {
+        let mut null_variant = None;
+        for (idx, (_, meta)) in fields.iter().enumerate() {
+            if get_strategy_from_metadata(&meta.metadata)? == Some(Strategy::NullVariant) {
+                null_variant = Some(idx);
+            }
+        }
+
+        Ok(Self {
             path,
             current_offset: vec![0; fields.len()],
             types: Vec::new(),
             offsets: Vec::new(),
             fields,
+            null_variant,
+            untagged: false,
+            mode: UnionMode::Dense,
+            last_untagged: None,
+            capacities: Vec::new(),
+        })
+    }
+
+    pub fn with_untagged(mut self, untagged: bool) -> Self {
+        self.untagged = untagged;
+        self
+    }
+
+    pub fn with_mode(mut self, mode: UnionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    /// Set the expected number of values per variant and reserve the buffers
+    /// of this and of all following batches accordingly
+    pub fn set_capacities(&mut self, capacities: Vec<usize>) {
+        self.capacities = capacities;
+        self.reserve_capacities();
+    }
+
+    fn reserve_capacities(&mut self) {
+        let total = self.capacities.iter().sum::<usize>();
+        self.reserve(total);
+        for (idx, (child, _)) in self.fields.iter_mut().enumerate() {
+            match self.mode {
+                UnionMode::Dense => child.reserve(self.capacities.get(idx).copied().unwrap_or(0)),
+                UnionMode::Sparse => child.reserve(total),
+            }
+        }
+    }
+
+    /// Reserve the types and offsets for at least `additional` more values
+    pub fn reserve(&mut self, additional: usize) {
+        self.types.reserve(additional);
+        if matches!(self.mode, UnionMode::Dense) {
+            self.offsets.reserve(additional);
         }
     }
 
     pub fn take(&mut self) -> ArrayBuilder {
-        ArrayBuilder::Union(Self {
+        let taken = ArrayBuilder::Union(Self {
             path: self.path.clone(),
             fields: self
                 .fields
@@ -39,39 +103,233 @@ impl UnionBuilder {
             types: std::mem::take(&mut self.types),
             offsets: std::mem::take(&mut self.offsets),
             current_offset: std::mem::replace(&mut self.current_offset, vec![0; self.fields.len()]),
-        })
+            null_variant: self.null_variant,
+            untagged: self.untagged,
+            mode: self.mode,
+            last_untagged: self.last_untagged,
+            capacities: self.capacities.clone(),
+        });
+        self.reserve_capacities();
+        taken
     }
 
     pub fn is_nullable(&self) -> bool {
-        false
+        self.null_variant.is_some()
+    }
+
+    pub fn byte_size(&self) -> usize {
+        self.types.len()
+            + std::mem::size_of_val(self.offsets.as_slice())
+            + self
+                .fields
+                .iter()
+                .map(|(builder, _)| builder.byte_size())
+                .sum::<usize>()
     }
 
     pub fn into_array(self) -> Result<Array> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
         let mut fields = Vec::new();
         for (idx, (builder, meta)) in self.fields.into_iter().enumerate() {
             fields.push((idx.try_into()?, builder.into_array()?, meta));
         }
 
-        Ok(Array::DenseUnion(DenseUnionArray {
-            types: self.types,
-            offsets: self.offsets,
-            fields,
-        }))
+        match self.mode {
+            UnionMode::Dense => {
+                validate_dense(&self.types, &self.offsets, &fields).ctx(&ctx)?;
+                Ok(Array::DenseUnion(DenseUnionArray {
+                    types: self.types,
+                    offsets: self.offsets,
+                    fields,
+                }))
+            }
+            UnionMode::Sparse => {
+                validate_sparse(&self.types, &fields).ctx(&ctx)?;
+                Ok(Array::SparseUnion(SparseUnionArray {
+                    types: self.types,
+                    fields,
+                }))
+            }
+        }
+    }
+}
+
+/// Check that the types and offsets of a dense union are consistent with its
+/// children
+fn validate_dense(types: &[i8], offsets: &[i32], fields: &[(i8, Array, FieldMeta)]) -> Result<()> {
+    if types.len() != offsets.len() {
+        fail!(
+            "Dense union has {} types but {} offsets",
+            types.len(),
+            offsets.len()
+        );
+    }
+
+    let mut counts = vec![0_usize; fields.len()];
+    for (row, (&type_id, &offset)) in std::iter::zip(types, offsets).enumerate() {
+        let Some((_, child, meta)) = usize::try_from(type_id)
+            .ok()
+            .and_then(|idx| fields.get(idx))
+        else {
+            fail!("Invalid type id {type_id} in row {row} of dense union");
+        };
+        let count = &mut counts[type_id as usize];
+        if usize::try_from(offset).ok() != Some(*count) {
+            fail!(
+                "Invalid offset {offset} for variant {name:?} in row {row} of dense union: expected {count}",
+                name = meta.name,
+            );
+        }
+        if *count >= array_len(child) {
+            fail!(
+                "Variant {name:?} has no value for row {row} of dense union: the child has {len} elements",
+                name = meta.name,
+                len = array_len(child),
+            );
+        }
+        *count += 1;
+    }
+
+    for ((_, child, meta), count) in std::iter::zip(fields, counts) {
+        let len = array_len(child);
+        if len != count {
+            fail!(
+                "Variant {name:?} of dense union has {len} elements, but is referenced by {count} rows",
+                name = meta.name,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Check that the children of a sparse union have one value per row
+fn validate_sparse(types: &[i8], fields: &[(i8, Array, FieldMeta)]) -> Result<()> {
+    for (row, &type_id) in types.iter().enumerate() {
+        if usize::try_from(type_id)
+            .ok()
+            .filter(|idx| *idx < fields.len())
+            .is_none()
+        {
+            fail!("Invalid type id {type_id} in row {row} of sparse union");
+        }
+    }
+    for (_, child, meta) in fields {
+        let len = array_len(child);
+        if len != types.len() {
+            fail!(
+                "Variant {name:?} of sparse union has {len} elements, expected {rows}: row {row} is missing",
+                name = meta.name,
+                rows = types.len(),
+                row = len.min(types.len()),
+            );
+        }
     }
+    Ok(())
 }
 
 impl UnionBuilder {
     pub fn serialize_variant(&mut self, variant_index: u32) -> Result<&mut ArrayBuilder> {
         let variant_index = variant_index as usize;
-        let Some((variant_builder, _)) = self.fields.get_mut(variant_index) else {
+        if self.null_variant == Some(variant_index) {
             fail!("Could not find variant {variant_index} in Union");
-        };
+        }
+        self.push_variant(variant_index)
+    }
 
-        self.offsets.push(self.current_offset[variant_index]);
-        self.types.push(i8::try_from(variant_index)?);
-        self.current_offset[variant_index] += 1;
+    fn push_variant(&mut self, variant_index: usize) -> Result<&mut ArrayBuilder> {
+        self.push_types(variant_index, 1)?;
+        Ok(&mut self.fields[variant_index].0)
+    }
 
-        Ok(variant_builder)
+    /// Record `n` consecutive rows of the given variant, the values of the
+    /// variant itself have to be pushed by the caller
+    fn push_types(&mut self, variant_index: usize, n: usize) -> Result<()> {
+        if variant_index >= self.fields.len() {
+            fail!("Could not find variant {variant_index} in Union");
+        }
+        let type_id = i8::try_from(variant_index)?;
+        let n_offsets = i32::try_from(n)?;
+
+        if self.mode == UnionMode::Sparse {
+            self.pad_children(variant_index, n)?;
+        } else {
+            let start = self.current_offset[variant_index];
+            self.offsets.extend(start..start + n_offsets);
+        }
+        self.types.resize(self.types.len() + n, type_id);
+        self.current_offset[variant_index] += n_offsets;
+
+        Ok(())
+    }
+
+    /// Push `n` placeholders into all children of a sparse union, except the
+    /// selected one
+    fn pad_children(&mut self, variant_index: usize, n: usize) -> Result<()> {
+        let row = self.types.len();
+        for (idx, (builder, meta)) in self.fields.iter_mut().enumerate() {
+            if idx == variant_index {
+                continue;
+            }
+            let res = if builder.is_nullable() {
+                builder.serialize_nones(n)
+            } else {
+                builder.serialize_defaults(n)
+            };
+            if let Err(err) = res {
+                fail!(
+                    "Cannot pad variant {name:?} of sparse union in row {row}: {err}",
+                    name = meta.name,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Serialize a primitive value into the first child accepted by the
+    /// candidates, in order of the candidates
+    fn serialize_untagged(
+        &mut self,
+        name: &'static str,
+        candidates: &[fn(&ArrayBuilder) -> bool],
+        func: impl FnOnce(&mut ArrayBuilder) -> Result<()>,
+    ) -> Result<()> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| {
+            if !self.untagged {
+                fail!("{name} is not supported");
+            }
+            let idx = match self.last_untagged {
+                Some((last, idx)) if last == name => idx,
+                _ => {
+                    let Some(idx) = candidates.iter().find_map(|candidate| {
+                        self.fields
+                            .iter()
+                            .position(|(builder, _)| candidate(builder))
+                    }) else {
+                        fail!("Cannot {name}: the union has no child of a matching type");
+                    };
+                    self.last_untagged = Some((name, idx));
+                    idx
+                }
+            };
+            func(self.push_variant(idx)?)
+        })
+        .ctx(&ctx)
+    }
+
+    fn push_nulls(&mut self, n: usize) -> Result<()> {
+        if n == 0 {
+            return Ok(());
+        }
+        let Some(null_variant) = self.null_variant else {
+            fail!("Cannot push null for union without a NullVariant child");
+        };
+        self.push_types(null_variant, n)?;
+        self.fields[null_variant].0.serialize_nones(n)
     }
 }
 
@@ -82,7 +340,110 @@ impl Context for UnionBuilder {
     }
 }
 
+fn is_bool(builder: &ArrayBuilder) -> bool {
+    matches!(builder, ArrayBuilder::Bool(_))
+}
+
+fn is_signed(builder: &ArrayBuilder) -> bool {
+    use ArrayBuilder as A;
+    matches!(builder, A::I8(_) | A::I16(_) | A::I32(_) | A::I64(_))
+}
+
+fn is_unsigned(builder: &ArrayBuilder) -> bool {
+    use ArrayBuilder as A;
+    matches!(builder, A::U8(_) | A::U16(_) | A::U32(_) | A::U64(_))
+}
+
+fn is_float(builder: &ArrayBuilder) -> bool {
+    use ArrayBuilder as A;
+    matches!(builder, A::F16(_) | A::F32(_) | A::F64(_))
+}
+
+fn is_string(builder: &ArrayBuilder) -> bool {
+    use ArrayBuilder as A;
+    matches!(builder, A::Utf8(_) | A::LargeUtf8(_) | A::DictionaryUtf8(_))
+}
+
+const SIGNED: &[fn(&ArrayBuilder) -> bool] = &[is_signed, is_unsigned, is_float];
+const UNSIGNED: &[fn(&ArrayBuilder) -> bool] = &[is_unsigned, is_signed, is_float];
+
 impl SimpleSerializer for UnionBuilder {
+    fn serialize_bool(&mut self, v: bool) -> Result<()> {
+        self.serialize_untagged("serialize_bool", &[is_bool], |b| b.serialize_bool(v))
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.serialize_untagged("serialize_i8", SIGNED, |b| b.serialize_i8(v))
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.serialize_untagged("serialize_i16", SIGNED, |b| b.serialize_i16(v))
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.serialize_untagged("serialize_i32", SIGNED, |b| b.serialize_i32(v))
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.serialize_untagged("serialize_i64", SIGNED, |b| b.serialize_i64(v))
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.serialize_untagged("serialize_u8", UNSIGNED, |b| b.serialize_u8(v))
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.serialize_untagged("serialize_u16", UNSIGNED, |b| b.serialize_u16(v))
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.serialize_untagged("serialize_u32", UNSIGNED, |b| b.serialize_u32(v))
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.serialize_untagged("serialize_u64", UNSIGNED, |b| b.serialize_u64(v))
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        self.serialize_untagged("serialize_f32", &[is_float], |b| b.serialize_f32(v))
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        self.serialize_untagged("serialize_f64", &[is_float], |b| b.serialize_f64(v))
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        self.serialize_untagged("serialize_str", &[is_string], |b| b.serialize_str(v))
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| self.push_nulls(1)).ctx(&ctx)
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| self.push_nulls(1)).ctx(&ctx)
+    }
+
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| self.push_nulls(n)).ctx(&ctx)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        let mut ctx = BTreeMap::new();
+        self.annotate(&mut ctx);
+
+        try_(|| self.push_nulls(n)).ctx(&ctx)
+    }
+
     fn serialize_unit_variant(
         &mut self,
         _: &'static str,
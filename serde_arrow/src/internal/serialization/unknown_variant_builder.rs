@@ -29,6 +29,10 @@ impl UnknownVariantBuilder {
         false
     }
 
+    pub fn byte_size(&self) -> usize {
+        0
+    }
+
     pub fn into_array(self) -> Result<Array> {
         Ok(Array::Null(NullArray { len: 0 }))
     }
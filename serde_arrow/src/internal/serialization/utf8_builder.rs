@@ -3,18 +3,35 @@ use std::collections::BTreeMap;
 use crate::internal::{
     arrow::{Array, BytesArray},
     error::{fail, set_default, try_, Context, ContextSupport, Result},
+    serializer_options::StringLengthLimit,
     utils::{
         array_ext::{new_bytes_array, ArrayExt, ScalarArrayExt},
         NamedType, Offset,
     },
 };
 
-use super::{array_builder::ArrayBuilder, simple_serializer::SimpleSerializer};
+use super::{
+    array_builder::ArrayBuilder, byte_budget::ByteBudget, simple_serializer::SimpleSerializer,
+};
 
 #[derive(Debug, Clone)]
 pub struct Utf8Builder<O> {
     path: String,
     array: BytesArray<O>,
+    /// If `true`, booleans and numbers are serialized as strings
+    stringify: bool,
+    /// The maximum length of the values, if limited
+    limit: Option<StringLengthLimit>,
+    /// If `true`, the offsets are narrowed to `i32` when building the array,
+    /// if the data fits
+    adaptive_offsets: bool,
+    /// If `true`, `i64` offsets are narrowed to `i32` even without adaptive
+    /// offsets, i.e., the field is declared as `Utf8`
+    narrow: bool,
+    /// If `true`, a `Utf8View` array is built
+    views: bool,
+    /// The count of the buffered bytes, if limited
+    pub budget: Option<ByteBudget>,
 }
 
 impl<O: Offset> Utf8Builder<O> {
@@ -22,19 +39,57 @@ impl<O: Offset> Utf8Builder<O> {
         Self {
             path,
             array: new_bytes_array(is_nullable),
+            stringify: false,
+            limit: None,
+            adaptive_offsets: false,
+            narrow: false,
+            views: false,
+            budget: None,
         }
     }
 
+    pub fn with_stringify(mut self, stringify: bool) -> Self {
+        self.stringify = stringify;
+        self
+    }
+
+    pub fn with_adaptive_offsets(mut self, adaptive_offsets: bool) -> Self {
+        self.adaptive_offsets = adaptive_offsets;
+        self
+    }
+
+    pub fn set_adaptive_offsets(&mut self, adaptive_offsets: bool) {
+        self.adaptive_offsets = adaptive_offsets;
+    }
+
     pub fn take_self(&mut self) -> Self {
         Self {
             path: self.path.clone(),
             array: self.array.take(),
+            stringify: self.stringify,
+            limit: self.limit.clone(),
+            adaptive_offsets: self.adaptive_offsets,
+            narrow: self.narrow,
+            views: self.views,
+            budget: self.budget.clone(),
         }
     }
 
     pub fn is_nullable(&self) -> bool {
         self.array.validity.is_some()
     }
+
+    pub fn byte_size(&self) -> usize {
+        self.array.byte_size()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.array.reserve(additional);
+    }
+
+    pub fn path_and_limit_mut(&mut self) -> (&str, &mut Option<StringLengthLimit>) {
+        (&self.path, &mut self.limit)
+    }
 }
 
 impl Utf8Builder<i32> {
@@ -52,9 +107,46 @@ impl Utf8Builder<i64> {
         ArrayBuilder::LargeUtf8(self.take_self())
     }
 
+    pub fn with_narrow(mut self, narrow: bool) -> Self {
+        self.narrow = narrow;
+        self
+    }
+
+    pub fn with_views(mut self, views: bool) -> Self {
+        self.views = views;
+        self
+    }
+
     pub fn into_array(self) -> Result<Array> {
-        Ok(Array::LargeUtf8(self.array))
+        if self.views {
+            return Ok(Array::Utf8View(self.array));
+        }
+        if !self.adaptive_offsets && !self.narrow {
+            return Ok(Array::LargeUtf8(self.array));
+        }
+        match narrow_offsets(self.array) {
+            Ok(array) => Ok(Array::Utf8(array)),
+            Err(array) if self.adaptive_offsets => Ok(Array::LargeUtf8(array)),
+            Err(_) => fail!(
+                "Cannot build the Utf8 array of {path}: the strings do not fit into 32 bit offsets",
+                path = self.path,
+            ),
+        }
+    }
+}
+
+/// Narrow the offsets to `i32`, if the data fits, or return the array unchanged
+fn narrow_offsets(array: BytesArray<i64>) -> Result<BytesArray<i32>, BytesArray<i64>> {
+    let total_bytes = array.offsets.last().copied().unwrap_or_default();
+    if i32::try_from(total_bytes).is_err() {
+        return Err(array);
     }
+    Ok(BytesArray {
+        validity: array.validity,
+        // cannot overflow: the offsets are non-decreasing up to `total_bytes`
+        offsets: array.offsets.into_iter().map(|o| o as i32).collect(),
+        data: array.data,
+    })
 }
 
 impl<O: NamedType> Context for Utf8Builder<O> {
@@ -63,7 +155,9 @@ impl<O: NamedType> Context for Utf8Builder<O> {
         set_default(
             annotations,
             "data_type",
-            if O::NAME == "i32" {
+            if self.views {
+                "Utf8View"
+            } else if O::NAME == "i32" {
                 "Utf8"
             } else {
                 "LargeUtf8"
@@ -72,6 +166,32 @@ impl<O: NamedType> Context for Utf8Builder<O> {
     }
 }
 
+impl<O: NamedType + Offset> Utf8Builder<O> {
+    fn push_str(&mut self, v: &str) -> Result<()> {
+        match &self.limit {
+            Some(limit) => self.push_bytes(limit.apply(v)?.as_bytes()),
+            None => self.push_bytes(v.as_bytes()),
+        }
+    }
+
+    fn push_bytes(&mut self, v: &[u8]) -> Result<()> {
+        if let Some(budget) = &self.budget {
+            budget.reserve_bytes(v.len())?;
+        }
+        self.array.push_scalar_value(v)
+    }
+
+    fn serialize_display<T: std::fmt::Display>(&mut self, name: &str, v: T) -> Result<()> {
+        try_(|| {
+            if !self.stringify {
+                fail!("{name} is not supported");
+            }
+            self.push_str(&v.to_string())
+        })
+        .ctx(self)
+    }
+}
+
 impl<O: NamedType + Offset> SimpleSerializer for Utf8Builder<O> {
     fn serialize_default(&mut self) -> Result<()> {
         try_(|| self.array.push_scalar_default()).ctx(self)
@@ -81,8 +201,60 @@ impl<O: NamedType + Offset> SimpleSerializer for Utf8Builder<O> {
         try_(|| self.array.push_scalar_none()).ctx(self)
     }
 
+    fn serialize_defaults(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_defaults(n)).ctx(self)
+    }
+
+    fn serialize_nones(&mut self, n: usize) -> Result<()> {
+        try_(|| self.array.push_scalar_nones(n)).ctx(self)
+    }
+
     fn serialize_str(&mut self, v: &str) -> Result<()> {
-        try_(|| self.array.push_scalar_value(v.as_bytes())).ctx(self)
+        try_(|| self.push_str(v)).ctx(self)
+    }
+
+    fn serialize_bool(&mut self, v: bool) -> Result<()> {
+        self.serialize_display("serialize_bool", v)
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.serialize_display("serialize_i8", v)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.serialize_display("serialize_i16", v)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.serialize_display("serialize_i32", v)
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.serialize_display("serialize_i64", v)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.serialize_display("serialize_u8", v)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.serialize_display("serialize_u16", v)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.serialize_display("serialize_u32", v)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.serialize_display("serialize_u64", v)
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        self.serialize_display("serialize_f32", v)
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        self.serialize_display("serialize_f64", v)
     }
 
     fn serialize_unit_variant(
@@ -91,7 +263,7 @@ impl<O: NamedType + Offset> SimpleSerializer for Utf8Builder<O> {
         _: u32,
         variant: &'static str,
     ) -> Result<()> {
-        try_(|| self.array.push_scalar_value(variant.as_bytes())).ctx(self)
+        try_(|| self.push_str(variant)).ctx(self)
     }
 
     fn serialize_tuple_variant_start<'this>(
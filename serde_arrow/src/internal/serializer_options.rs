@@ -0,0 +1,607 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use crate::internal::{
+    error::{fail, Result},
+    schema::{DuplicateFieldPolicy, Strategy},
+    utils::xxhash::xxh64,
+};
+
+/// Configure how items are serialized into arrays
+///
+/// The options are attached to an [`ArrayBuilder`][crate::ArrayBuilder] via
+/// [`ArrayBuilder::with_options`][crate::ArrayBuilder::with_options]. Usage:
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::Serialize;
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     ArrayBuilder, SerializerOptions,
+/// };
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: u32,
+///     b: Vec<u32>,
+/// }
+///
+/// let fields = Vec::<FieldRef>::from_value(serde_json::json!([
+///     {"name": "a", "data_type": "U32"},
+///     {"name": "b", "data_type": "U32"},
+/// ]))?;
+///
+/// let mut builder = ArrayBuilder::from_arrow(&fields)?
+///     .with_options(SerializerOptions::default().strict_schema(true));
+///
+/// let err = builder.push(Record { a: 1, b: vec![2] }).unwrap_err();
+/// assert!(err.to_string().contains("field $.b expected primitive, found list"));
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct SerializerOptions {
+    /// If `true`, check the first item of each batch against the fields before
+    /// serializing it. The default is `false`.
+    ///
+    /// The check compares field names and the nesting of the item (structs,
+    /// lists, maps, enums) against the fields and reports all mismatches in a
+    /// single error. Fields of the item without a matching field are ignored,
+    /// as when serializing. As the item is rejected before any data is written, the
+    /// builder is not left partially filled. Primitive types are not compared,
+    /// as the builders support various conversions.
+    pub strict_schema: bool,
+
+    /// If `true`, write default values for non-nullable fields missing from
+    /// an item. The default is `false`.
+    ///
+    /// Missing nullable fields are always written as nulls. Non-nullable
+    /// fields are filled with the default value of their data type (e.g., `0`
+    /// for numbers or `""` for strings). This option allows to use
+    /// `#[serde(skip_serializing_if = "...")]` with non-nullable fields.
+    /// Without it, serializing an item without a non-nullable field fails.
+    pub missing_fields_as_default: bool,
+
+    /// If `true`, guarantee bit-for-bit identical arrays for identical items.
+    /// The default is `false`.
+    ///
+    /// With this option the entries of `Map` fields are written sorted by key,
+    /// as the iteration order of maps like `HashMap` differs between runs.
+    /// Entries with equal keys keep their order. Supported keys are integers,
+    /// strings, chars, bools, bytes and unit enum variants. Sorting requires
+    /// to buffer the entries of each map.
+    ///
+    /// All other outputs are deterministic independent of this option:
+    /// dictionaries list their values in order of first occurrence (or
+    /// continue the dictionaries of a [`StringInterner`][crate::StringInterner]),
+    /// maps serialized as structs follow the field order of the schema, and
+    /// null or default values are written as zeroed values (e.g., zero bytes
+    /// for `FixedSizeBinary` fields).
+    pub deterministic: bool,
+
+    /// If `true`, write the entries of `Map` fields sorted by key. The default
+    /// is `false`.
+    ///
+    /// Some engines require the keys of maps to be sorted. Entries with equal
+    /// keys keep their order, unless they are removed according to
+    /// [`duplicate_map_keys`](#structfield.duplicate_map_keys). The supported
+    /// keys are the same as for [`deterministic`](#structfield.deterministic),
+    /// which implies this option.
+    pub sort_map_keys: bool,
+
+    /// How entries of `Map` fields with equal keys are handled. The default is
+    /// [`DuplicateMapKeyPolicy::Keep`].
+    ///
+    /// Any policy other than `Keep` requires to buffer the entries of each map
+    /// and to compare their keys. Keys are compared by value, e.g., the
+    /// integers `1_u8` and `1_i64` are considered equal.
+    pub duplicate_map_keys: DuplicateMapKeyPolicy,
+
+    /// How repeated fields of structs are handled, e.g., maps with repeated
+    /// keys written to `Struct` fields. The default is
+    /// [`DuplicateFieldPolicy::Error`].
+    ///
+    /// Schemas traced with [`TracingOptions::duplicate_fields`][crate::schema::TracingOptions::duplicate_fields]
+    /// require the same policy to serialize the traced items.
+    pub duplicate_fields: DuplicateFieldPolicy,
+
+    /// How `f32` and `f64` values are rounded when written to `Float16`
+    /// fields. The default is [`F16Rounding::Nearest`].
+    pub f16_rounding: F16Rounding,
+
+    /// How finite values too large for `Float16`, i.e., values that round to
+    /// infinity, are handled. The default is [`F16Overflow::Infinity`].
+    ///
+    /// Non-finite inputs (infinities and NaNs) are always written as is.
+    pub f16_overflow: F16Overflow,
+
+    /// If `true`, widen the keys of dictionary fields when the dictionary
+    /// outgrows the key type. The default is `false`.
+    ///
+    /// The keys are widened to the next integer type of the same signedness,
+    /// e.g., from `UInt8` to `UInt16`, and the keys already written are
+    /// converted. The data type of the resulting arrays then differs from the
+    /// field, record batches use the data types of the arrays. Once widened,
+    /// the keys keep their width for later batches. Without this option,
+    /// dictionaries with more values than the key type can address result in
+    /// an error.
+    pub promote_dictionary_keys: bool,
+
+    /// If `true`, allow to serialize structs into `Map` fields and maps with
+    /// non-string keys into `Struct` fields. The default is `false`.
+    ///
+    /// Custom `#[serde(serialize_with = "...")]` implementations often switch
+    /// between both representations. Struct fields are written as map entries
+    /// keyed by the field name, which requires a key field accepting strings.
+    /// Maps are always accepted by `Struct` fields for keys that are strings;
+    /// with this option integer, bool, char and unit variant keys are matched
+    /// against the field names by their string representation, e.g., the key
+    /// `1` selects the field `"1"`.
+    pub map_struct_interchange: bool,
+
+    /// Strategies to use instead of the strategies of the fields, keyed by the
+    /// path of the field (e.g., `$.outer.inner`). The default is empty.
+    ///
+    /// A value of `None` removes the strategy of the field, e.g., to write
+    /// integers to a `Date64` field with the `UtcStrAsDate64` strategy. The
+    /// schema itself, and the fields of the built arrays, are not modified.
+    /// The options must be set before any items are pushed. Paths not matching
+    /// any field or strategies not supported by the data type of the field
+    /// result in an error when pushing items or building the arrays.
+    pub strategy_overrides: BTreeMap<String, Option<Strategy>>,
+
+    /// Maximum lengths of string fields, keyed by the path of the field
+    /// (e.g., `$.outer.inner`). The default is empty.
+    ///
+    /// The limits are enforced while serializing, for `Utf8`, `LargeUtf8` and
+    /// dictionary encoded string fields. The length is measured in bytes of
+    /// the UTF-8 encoding. Paths not matching any string field or limits not
+    /// compatible with their policy result in an error when pushing items or
+    /// building the arrays.
+    pub max_string_lengths: BTreeMap<String, StringLengthLimit>,
+
+    /// The handling of UTC offsets when parsing strings into UTC timestamps,
+    /// keyed by the path of the field (e.g., `$.outer.inner`). The default is
+    /// empty, i.e., all offsets are normalized to UTC.
+    ///
+    /// The policies apply to `Timestamp` fields with a UTC timezone and to
+    /// `Date64` fields with the `UtcStrAsDate64` strategy. Strings without
+    /// offset (e.g., `2024-09-30T12:30:00Z`) and strings with a zero offset
+    /// (e.g., `2024-09-30T12:30:00+00:00`) are always accepted. Paths not
+    /// matching any of these fields result in an error when pushing items or
+    /// building the arrays.
+    pub utc_offsets: BTreeMap<String, UtcOffsetPolicy>,
+
+    /// The maximum number of rows per batch. The default is `None`, i.e., no
+    /// limit.
+    ///
+    /// Pushing an item into a builder that already contains `max_rows` items
+    /// results in an error and the item is not written. Building the arrays
+    /// resets the count.
+    pub max_rows: Option<usize>,
+
+    /// The maximum nesting depth of the fields. The default is `None`, i.e.,
+    /// no limit.
+    ///
+    /// Top-level fields have a depth of `1`, each level of structs, lists,
+    /// maps (including their entries) and unions adds one. As values are
+    /// written according to the fields, they cannot be nested deeper than the
+    /// fields. Schemas exceeding the limit result in an error when pushing
+    /// items or building the arrays.
+    pub max_depth: Option<usize>,
+
+    /// The maximum number of bytes buffered by the builder. The default is
+    /// `None`, i.e., no limit.
+    ///
+    /// The size is approximated by the size of the buffers of the arrays
+    /// (values, offsets and validity bitmaps), without allocation overheads.
+    /// It is kept as a running count: string and binary bytes, list elements
+    /// and map entries are counted before they are written, fixed sized
+    /// values after each item. Data exceeding the limit is not written and the
+    /// item is left partially written, as for other errors while writing an
+    /// item. Subsequent pushes fail without writing the item until the arrays
+    /// are built.
+    pub max_bytes: Option<usize>,
+
+    /// If `true`, the buffers of `arrow` arrays are aligned to 64 bytes and
+    /// padded to a multiple of 64 bytes. The default is `false`.
+    ///
+    /// Aligned buffers, as recommended by the Arrow specification, can be
+    /// exported via the C data interface or written to IPC without
+    /// reallocation. By default, the buffers of the built vectors are reused
+    /// as is. With this option, buffers that are not aligned are copied when
+    /// the arrays are built. Use [`check_alignment`][crate::check_alignment]
+    /// to verify the alignment of arrays.
+    pub aligned_buffers: bool,
+
+    /// The key fields to sort the rows of the built arrays by, given by their
+    /// path (e.g., `$.outer.inner`) and order. The default is empty, i.e., the
+    /// rows are kept in the order they were pushed.
+    ///
+    /// The rows are compared by the first key, ties are broken by the
+    /// following keys and rows with equal keys keep their order. Nulls are
+    /// sorted first for both orders. The keys must be top-level fields or
+    /// nested in structs and be of a type supported by
+    /// [`row_format::encode`][crate::row_format::encode], i.e., not lists,
+    /// maps or unions. All columns, including nested ones, are reordered
+    /// consistently. Invalid keys result in an error when pushing items or
+    /// building the arrays.
+    pub sort_by: Vec<(String, SortOrder)>,
+
+    /// If `true`, report the row of items that write nulls into non-nullable
+    /// fields. The default is `false`.
+    ///
+    /// Writing a null into a non-nullable field is always an error that
+    /// reports the path of the field. With this option, errors while
+    /// serializing an item are also annotated with the row of the item in the
+    /// current batch, e.g., `(field: "$.a", row: "3")`.
+    pub strict_nullability: bool,
+
+    /// Key-value metadata attached to the schema of built record batches. The
+    /// default is empty.
+    ///
+    /// The metadata is intended for application-level information that should
+    /// travel with the data, e.g., the job that produced the data, its source
+    /// or a version. It is written into the schema of record batches built
+    /// with [`ArrayBuilder::to_record_batch`][crate::ArrayBuilder::to_record_batch]
+    /// and can be read back via `record_batch.schema().metadata()`. The
+    /// metadata of the fields and arrays built with `to_arrow` are not
+    /// affected.
+    pub schema_metadata: BTreeMap<String, String>,
+
+    /// Drop duplicate rows while serializing. The default is
+    /// [`Deduplication::None`], i.e., all rows are written.
+    ///
+    /// Duplicates are detected by comparing the serialized values of the
+    /// items, before they are written into the arrays. Dropped rows are
+    /// counted, see [`ArrayBuilder::dropped_rows`][crate::ArrayBuilder::dropped_rows],
+    /// but do not count towards [`max_rows`](#structfield.max_rows) or the
+    /// progress callback. The rows seen so far are kept when building the
+    /// arrays, i.e., duplicates across batches are dropped as well. Key
+    /// fields that are not top-level fields result in an error when pushing
+    /// items or building the arrays.
+    pub deduplication: Deduplication,
+
+    /// The expected number of values per variant of union fields, keyed by
+    /// the path of the union field (e.g., `$.event`) and the name of the
+    /// variant. The default is empty, i.e., no buffers are preallocated.
+    ///
+    /// The types and offsets of the union and the buffers of its variants are
+    /// reserved with these capacities for each batch, avoiding reallocations
+    /// while serializing enum-heavy data. Only the buffers of numeric, string,
+    /// binary, list and struct variants are reserved. Paths not matching any
+    /// union field or unknown variants result in an error when pushing items
+    /// or building the arrays.
+    pub union_capacities: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+/// The detection of duplicate rows, see [`SerializerOptions::deduplication`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Deduplication {
+    /// Write all rows
+    #[default]
+    None,
+    /// Drop rows equal to the previously written row, e.g., repeated events of
+    /// change data capture streams. Rows appended with
+    /// [`ArrayBuilder::append_nulls`][crate::ArrayBuilder::append_nulls] are
+    /// never equal to pushed rows.
+    Consecutive,
+    /// Drop rows whose key fields, given by their path (e.g., `$.id`), equal
+    /// the key fields of a previously written row. Only the keys of the last
+    /// `capacity` written rows are remembered, older keys are evicted first.
+    /// Missing key fields compare equal to each other, but not to nulls.
+    ByKey {
+        /// The paths of the key fields
+        keys: Vec<String>,
+        /// The maximum number of remembered keys
+        capacity: usize,
+    },
+}
+
+/// The maximum length of a string field, see
+/// [`SerializerOptions::max_string_lengths`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringLengthLimit {
+    /// The maximum number of bytes of each value
+    pub max_len: usize,
+    /// How values exceeding the maximum length are handled
+    pub policy: OversizedStringPolicy,
+}
+
+/// The handling of strings exceeding their maximum length, see
+/// [`SerializerOptions::max_string_lengths`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OversizedStringPolicy {
+    /// Fail with an error
+    #[default]
+    Error,
+    /// Truncate the value at a character boundary and append the marker, such
+    /// that the result, including the marker, fits into the maximum length
+    Truncate {
+        /// The marker appended to truncated values, e.g., `"..."`
+        marker: String,
+    },
+    /// Replace the value with the hex encoded 64 bit xxhash of its bytes,
+    /// i.e., a string of 16 characters
+    Hash,
+}
+
+impl StringLengthLimit {
+    pub(crate) fn check(&self) -> Result<()> {
+        let min_len = match &self.policy {
+            OversizedStringPolicy::Error => 0,
+            OversizedStringPolicy::Truncate { marker } => marker.len(),
+            OversizedStringPolicy::Hash => 16,
+        };
+        if self.max_len < min_len {
+            fail!(
+                "the maximum length of {max_len} is shorter than the {min_len} bytes required by the policy {policy:?}",
+                max_len = self.max_len,
+                policy = self.policy,
+            );
+        }
+        Ok(())
+    }
+
+    /// Apply the limit to a single value
+    pub(crate) fn apply<'a>(&self, value: &'a str) -> Result<Cow<'a, str>> {
+        if value.len() <= self.max_len {
+            return Ok(Cow::Borrowed(value));
+        }
+        match &self.policy {
+            OversizedStringPolicy::Error => fail!(
+                "String of {len} bytes exceeds the maximum length of {max_len} bytes",
+                len = value.len(),
+                max_len = self.max_len,
+            ),
+            OversizedStringPolicy::Truncate { marker } => {
+                let mut end = self.max_len.saturating_sub(marker.len());
+                while !value.is_char_boundary(end) {
+                    end -= 1;
+                }
+                Ok(Cow::Owned(format!("{}{marker}", &value[..end])))
+            }
+            OversizedStringPolicy::Hash => {
+                Ok(Cow::Owned(format!("{:016x}", xxh64(value.as_bytes(), 0))))
+            }
+        }
+    }
+}
+
+/// The handling of non-zero UTC offsets, see
+/// [`SerializerOptions::utc_offsets`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UtcOffsetPolicy {
+    /// Convert the value to UTC
+    #[default]
+    Normalize,
+    /// Convert the value to UTC and count the values with non-zero offsets,
+    /// see [`ArrayBuilder::non_utc_offsets`][crate::ArrayBuilder::non_utc_offsets]
+    Count,
+    /// Fail with an error
+    Error,
+}
+
+/// The order of a sort key, see [`SerializerOptions::sort_by`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// Sort from the smallest to the largest value
+    #[default]
+    Ascending,
+    /// Sort from the largest to the smallest value
+    Descending,
+}
+
+/// The handling of map entries with equal keys, see
+/// [`SerializerOptions::duplicate_map_keys`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DuplicateMapKeyPolicy {
+    /// Write all entries
+    #[default]
+    Keep,
+    /// Write only the first entry for each key, at its position
+    First,
+    /// Write only the last entry for each key, at its position
+    Last,
+    /// Fail with an error
+    Error,
+}
+
+/// The rounding of values written to `Float16` fields, see
+/// [`SerializerOptions::f16_rounding`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum F16Rounding {
+    /// Round to the nearest representable value, ties to even
+    #[default]
+    Nearest,
+    /// Round towards zero, i.e., truncate the magnitude. Finite values
+    /// outside the range of `Float16` saturate to the largest finite value,
+    /// unless [`F16Overflow::Error`] is selected
+    TowardZero,
+}
+
+/// The handling of finite values too large for `Float16`, see
+/// [`SerializerOptions::f16_overflow`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum F16Overflow {
+    /// Write the value resulting from the rounding mode, i.e., infinity for
+    /// [`F16Rounding::Nearest`]
+    #[default]
+    Infinity,
+    /// Fail with an error
+    Error,
+}
+
+impl SerializerOptions {
+    /// Construct options with the default values
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set [`strict_schema`](#structfield.strict_schema)
+    pub fn strict_schema(mut self, value: bool) -> Self {
+        self.strict_schema = value;
+        self
+    }
+
+    /// Set [`missing_fields_as_default`](#structfield.missing_fields_as_default)
+    pub fn missing_fields_as_default(mut self, value: bool) -> Self {
+        self.missing_fields_as_default = value;
+        self
+    }
+
+    /// Set [`deterministic`](#structfield.deterministic)
+    pub fn deterministic(mut self, value: bool) -> Self {
+        self.deterministic = value;
+        self
+    }
+
+    /// Set [`sort_map_keys`](#structfield.sort_map_keys)
+    pub fn sort_map_keys(mut self, value: bool) -> Self {
+        self.sort_map_keys = value;
+        self
+    }
+
+    /// Set [`duplicate_map_keys`](#structfield.duplicate_map_keys)
+    pub fn duplicate_map_keys(mut self, value: DuplicateMapKeyPolicy) -> Self {
+        self.duplicate_map_keys = value;
+        self
+    }
+
+    /// Set [`duplicate_fields`](#structfield.duplicate_fields)
+    pub fn duplicate_fields(mut self, value: DuplicateFieldPolicy) -> Self {
+        self.duplicate_fields = value;
+        self
+    }
+
+    /// Set [`f16_rounding`](#structfield.f16_rounding)
+    pub fn f16_rounding(mut self, value: F16Rounding) -> Self {
+        self.f16_rounding = value;
+        self
+    }
+
+    /// Set [`f16_overflow`](#structfield.f16_overflow)
+    pub fn f16_overflow(mut self, value: F16Overflow) -> Self {
+        self.f16_overflow = value;
+        self
+    }
+
+    /// Set [`promote_dictionary_keys`](#structfield.promote_dictionary_keys)
+    pub fn promote_dictionary_keys(mut self, value: bool) -> Self {
+        self.promote_dictionary_keys = value;
+        self
+    }
+
+    /// Set [`map_struct_interchange`](#structfield.map_struct_interchange)
+    pub fn map_struct_interchange(mut self, value: bool) -> Self {
+        self.map_struct_interchange = value;
+        self
+    }
+
+    /// Set [`max_rows`](#structfield.max_rows)
+    pub fn max_rows(mut self, value: Option<usize>) -> Self {
+        self.max_rows = value;
+        self
+    }
+
+    /// Set [`max_depth`](#structfield.max_depth)
+    pub fn max_depth(mut self, value: Option<usize>) -> Self {
+        self.max_depth = value;
+        self
+    }
+
+    /// Set [`max_bytes`](#structfield.max_bytes)
+    pub fn max_bytes(mut self, value: Option<usize>) -> Self {
+        self.max_bytes = value;
+        self
+    }
+
+    /// Set [`aligned_buffers`](#structfield.aligned_buffers)
+    pub fn aligned_buffers(mut self, value: bool) -> Self {
+        self.aligned_buffers = value;
+        self
+    }
+
+    /// Set [`strict_nullability`](#structfield.strict_nullability)
+    pub fn strict_nullability(mut self, value: bool) -> Self {
+        self.strict_nullability = value;
+        self
+    }
+
+    /// Set [`schema_metadata`](#structfield.schema_metadata)
+    pub fn schema_metadata(mut self, value: BTreeMap<String, String>) -> Self {
+        self.schema_metadata = value;
+        self
+    }
+
+    /// Set [`deduplication`](#structfield.deduplication)
+    pub fn deduplication(mut self, value: Deduplication) -> Self {
+        self.deduplication = value;
+        self
+    }
+
+    /// Set the handling of UTC offsets for the field with the given path, see
+    /// [`utc_offsets`](#structfield.utc_offsets)
+    pub fn utc_offset_policy(mut self, path: impl Into<String>, policy: UtcOffsetPolicy) -> Self {
+        self.utc_offsets.insert(path.into(), policy);
+        self
+    }
+
+    /// Add the field with the given path as a sort key, see
+    /// [`sort_by`](#structfield.sort_by)
+    pub fn sort_by(mut self, path: impl Into<String>, order: SortOrder) -> Self {
+        self.sort_by.push((path.into(), order));
+        self
+    }
+
+    /// Override the strategy of the field with the given path, see
+    /// [`strategy_overrides`](#structfield.strategy_overrides)
+    pub fn override_strategy(
+        mut self,
+        path: impl Into<String>,
+        strategy: Option<Strategy>,
+    ) -> Self {
+        self.strategy_overrides.insert(path.into(), strategy);
+        self
+    }
+
+    /// Limit the length of the string field with the given path, see
+    /// [`max_string_lengths`](#structfield.max_string_lengths)
+    pub fn max_string_length(
+        mut self,
+        path: impl Into<String>,
+        max_len: usize,
+        policy: OversizedStringPolicy,
+    ) -> Self {
+        self.max_string_lengths
+            .insert(path.into(), StringLengthLimit { max_len, policy });
+        self
+    }
+
+    /// Set the expected number of values of a variant of the union field with
+    /// the given path, see [`union_capacities`](#structfield.union_capacities)
+    pub fn union_capacity(
+        mut self,
+        path: impl Into<String>,
+        variant: impl Into<String>,
+        capacity: usize,
+    ) -> Self {
+        self.union_capacities
+            .entry(path.into())
+            .or_default()
+            .insert(variant.into(), capacity);
+        self
+    }
+}
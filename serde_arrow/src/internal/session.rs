@@ -0,0 +1,152 @@
+use serde::Serialize;
+
+use crate::internal::{
+    array_builder::ArrayBuilder,
+    arrow::{Array, ArrayView},
+    deserializer::Deserializer,
+    deserializer_options::DeserializerOptions,
+    error::{fail, Result},
+    schema::SerdeArrowSchema,
+    serializer_options::SerializerOptions,
+    string_interner::StringInterner,
+};
+
+/// Convert between Rust objects and arrays with a fixed schema and options
+///
+/// A session bundles the schema, the [`SerializerOptions`], the
+/// [`DeserializerOptions`] and the dictionaries of a [`StringInterner`]. It
+/// allows to convert multiple batches without passing the configuration to
+/// every call. The schema is compiled once when the session is constructed
+/// and reused for all conversions.
+///
+/// It can be constructed via
+///
+/// - [`Session::new`]
+#[cfg_attr(has_arrow, doc = r"- [`Session::from_arrow_fields`]")]
+#[cfg_attr(has_arrow2, doc = r"- [`Session::from_arrow2_fields`]")]
+///
+/// Usage:
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     SerializerOptions, Session,
+/// };
+///
+/// ##[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Record {
+///     a: u32,
+///     b: String,
+/// }
+///
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// let mut session = Session::from_arrow_fields(&fields)?
+///     .with_serializer_options(SerializerOptions::default().strict_schema(true));
+///
+/// for chunk in [[1, 2], [3, 4]] {
+///     let items = chunk.map(|a| Record { a, b: a.to_string() });
+///     let batch = session.to_record_batch(&items)?;
+///
+///     let roundtripped: Vec<Record> = session.from_record_batch(&batch)?;
+///     assert_eq!(roundtripped, items);
+/// }
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+pub struct Session {
+    pub(crate) builder: ArrayBuilder,
+    pub(crate) deserializer_options: DeserializerOptions,
+}
+
+impl Session {
+    /// Construct a session from a [`SerdeArrowSchema`]
+    pub fn new(schema: SerdeArrowSchema) -> Result<Self> {
+        Ok(Self {
+            builder: ArrayBuilder::new(schema)?,
+            deserializer_options: DeserializerOptions::default(),
+        })
+    }
+
+    /// Configure the serialization, see [`SerializerOptions`]
+    pub fn with_serializer_options(mut self, options: SerializerOptions) -> Self {
+        self.builder = self.builder.with_options(options);
+        self
+    }
+
+    /// Configure the deserialization, see [`DeserializerOptions`]
+    pub fn with_deserializer_options(mut self, options: DeserializerOptions) -> Self {
+        self.deserializer_options = options;
+        self
+    }
+
+    /// Keep the dictionaries of dictionary encoded string columns stable
+    /// across calls, see [`ArrayBuilder::intern_strings`]
+    pub fn intern_strings(&mut self, interner: &StringInterner) -> Result<()> {
+        self.builder.intern_strings(interner)
+    }
+
+    /// Get the current dictionaries of dictionary encoded string columns
+    pub fn string_interner(&self) -> StringInterner {
+        self.builder.string_interner()
+    }
+
+    /// Get the schema of the session
+    pub fn schema(&self) -> &SerdeArrowSchema {
+        &self.builder.schema
+    }
+
+    /// Get the serializer options of the session
+    pub fn serializer_options(&self) -> &SerializerOptions {
+        &self.builder.options
+    }
+
+    /// Get the deserializer options of the session
+    pub fn deserializer_options(&self) -> &DeserializerOptions {
+        &self.deserializer_options
+    }
+}
+
+impl Session {
+    /// Serialize the items and build the arrays
+    ///
+    /// If the serialization fails, the partially written items are discarded,
+    /// the interned dictionaries are kept.
+    pub(crate) fn build_arrays<T: Serialize>(&mut self, items: T) -> Result<Vec<Array>> {
+        match self.builder.extend(items) {
+            Ok(()) => self.builder.build_arrays(),
+            Err(err) => {
+                self.builder.clear();
+                Err(err)
+            }
+        }
+    }
+
+    /// Construct a deserializer for arrays of the session schema
+    pub(crate) fn deserializer<'de>(
+        &self,
+        views: Vec<ArrayView<'de>>,
+    ) -> Result<Deserializer<'de>> {
+        let fields = &self.builder.schema.fields;
+        if fields.len() != views.len() {
+            fail!(
+                "different number of fields ({}) and arrays ({})",
+                fields.len(),
+                views.len()
+            );
+        }
+        Ok(Deserializer::new(fields, views)?.with_options(self.deserializer_options.clone()))
+    }
+}
+
+impl std::fmt::Debug for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Session {{ .. }}")
+    }
+}
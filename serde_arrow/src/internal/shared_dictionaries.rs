@@ -0,0 +1,264 @@
+use std::collections::BTreeMap;
+
+use crate::internal::arrow::{ArrayView, BytesArray, BytesArrayView, DictionaryArrayView};
+
+#[cfg(has_arrow)]
+use crate::internal::{
+    arrow::Field,
+    error::{fail, Result},
+    utils::{
+        array_ext::{get_bit_buffer, new_bytes_array, ScalarArrayExt},
+        ChildName, Offset,
+    },
+};
+
+/// The dictionaries of dictionary encoded string columns shared between
+/// batches when deserializing
+///
+/// Streams of batches, e.g., arrow IPC streams with delta dictionaries, may
+/// contain batches whose dictionary arrays only contain the values added since
+/// the previous batch, while the keys index into the accumulated dictionary.
+/// These batches cannot be deserialized on their own. The shared dictionaries
+/// accumulate the dictionary values batch by batch and are attached to the
+/// [`Deserializer`][crate::Deserializer] of each batch via
+/// [`Deserializer::with_shared_dictionaries`][crate::Deserializer::with_shared_dictionaries].
+///
+/// When a batch is added, the caller states how the values of its dictionary
+/// arrays relate to the accumulated values, see [`DictionaryUpdate`]. For arrow
+/// IPC streams this information is given by the `isDelta` flag of the
+/// dictionary batches.
+///
+/// The dictionaries are identified by the path of the field, e.g., `$.a` for a
+/// top-level field `a`. Dictionaries with `Utf8` and `LargeUtf8` values are
+/// supported.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// # use std::sync::Arc;
+/// use arrow::{
+///     array::{DictionaryArray, PrimitiveArray, RecordBatch, StringArray},
+///     datatypes::{DataType, Field, Schema, UInt32Type},
+/// };
+/// use serde::Deserialize;
+/// use serde_arrow::{DictionaryUpdate, Deserializer, SharedDictionaries};
+///
+/// ##[derive(Debug, PartialEq, Deserialize)]
+/// struct Record {
+///     a: String,
+/// }
+///
+/// let schema = Arc::new(Schema::new(vec![Field::new(
+///     "a",
+///     DataType::Dictionary(Box::new(DataType::UInt32), Box::new(DataType::Utf8)),
+///     false,
+/// )]));
+/// let batch = |keys: Vec<u32>, values: Vec<&str>| {
+///     let keys = PrimitiveArray::<UInt32Type>::from(keys);
+///     let values = Arc::new(StringArray::from(values));
+///     // the keys of delta batches index into the accumulated dictionary, as
+///     // produced by readers of IPC streams with delta dictionaries
+///     let array = unsafe { DictionaryArray::<UInt32Type>::new_unchecked(keys, values) };
+///     RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+/// };
+///
+/// // the second batch only contains the new value "baz" in its dictionary
+/// let batches = [
+///     (DictionaryUpdate::Replacement, batch(vec![0, 1], vec!["foo", "bar"])),
+///     (DictionaryUpdate::Delta, batch(vec![2, 0], vec!["baz"])),
+/// ];
+///
+/// let mut dictionaries = SharedDictionaries::new();
+/// let mut items = Vec::new();
+/// for (update, batch) in &batches {
+///     dictionaries.update_from_record_batch(batch, *update)?;
+///     let deserializer = Deserializer::from_record_batch(batch)?
+///         .with_shared_dictionaries(&dictionaries);
+///     items.extend(Vec::<Record>::deserialize(deserializer)?);
+/// }
+///
+/// let values: Vec<_> = items.iter().map(|item| item.a.as_str()).collect();
+/// assert_eq!(values, ["foo", "bar", "baz", "foo"]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SharedDictionaries {
+    dictionaries: BTreeMap<String, BytesArray<i64>>,
+}
+
+/// How the dictionary values of a batch relate to the accumulated values, see
+/// [`SharedDictionaries`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryUpdate {
+    /// The values form a complete dictionary replacing the accumulated values,
+    /// e.g., for the first batch of a stream or batches with self-contained
+    /// dictionaries
+    Replacement,
+    /// The values are appended to the accumulated values
+    Delta,
+}
+
+impl SharedDictionaries {
+    /// Construct empty shared dictionaries
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of accumulated values of the dictionary for the field with
+    /// the given path
+    pub fn len(&self, path: &str) -> Option<usize> {
+        Some(self.dictionaries.get(path)?.offsets.len() - 1)
+    }
+
+    /// Check whether no dictionaries were accumulated
+    pub fn is_empty(&self) -> bool {
+        self.dictionaries.is_empty()
+    }
+
+    /// Remove all dictionaries, e.g., when a stream is restarted
+    pub fn clear(&mut self) {
+        self.dictionaries.clear();
+    }
+
+    #[cfg(has_arrow)]
+    pub(crate) fn update(
+        &mut self,
+        fields: &[Field],
+        views: &[ArrayView<'_>],
+        update: DictionaryUpdate,
+    ) -> Result<()> {
+        for (field, view) in std::iter::zip(fields, views) {
+            let path = format!("$.{child}", child = ChildName(&field.name));
+            for_each_dictionary(view, &path, &mut |path, view| {
+                let values = dictionary_values(path, view)?;
+                let accumulated = self
+                    .dictionaries
+                    .entry(path.to_owned())
+                    .or_insert_with(|| new_bytes_array(false));
+                if update == DictionaryUpdate::Replacement {
+                    *accumulated = new_bytes_array(false);
+                }
+                for value in values {
+                    accumulated.push_scalar_value(value)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Replace the values of all dictionaries with accumulated values
+    pub(crate) fn apply<'a>(&'a self, path: &str, view: &mut ArrayView<'a>) {
+        for_each_dictionary_mut(view, path, &mut |path, view| {
+            let Some(accumulated) = self.dictionaries.get(path) else {
+                return;
+            };
+            *view.values = ArrayView::LargeUtf8(BytesArrayView {
+                validity: None,
+                offsets: &accumulated.offsets,
+                data: &accumulated.data,
+            });
+        });
+    }
+}
+
+#[cfg(has_arrow)]
+fn dictionary_values<'a>(path: &str, view: &DictionaryArrayView<'a>) -> Result<Vec<&'a [u8]>> {
+    match view.values.as_ref() {
+        ArrayView::Utf8(values) => bytes_values(path, values),
+        ArrayView::LargeUtf8(values) => bytes_values(path, values),
+        _ => fail!("Cannot share the dictionary of {path}: only string values are supported"),
+    }
+}
+
+#[cfg(has_arrow)]
+fn bytes_values<'a, O: Offset>(path: &str, view: &BytesArrayView<'a, O>) -> Result<Vec<&'a [u8]>> {
+    let mut values = Vec::new();
+    for idx in 0..view.offsets.len().saturating_sub(1) {
+        if let Some(validity) = view.validity {
+            if !get_bit_buffer(validity.data, validity.offset, idx)? {
+                fail!("Cannot share the dictionary of {path}: dictionaries do not support nullable values");
+            }
+        }
+        let start = view.offsets[idx].try_into_usize()?;
+        let end = view.offsets[idx + 1].try_into_usize()?;
+        let Some(value) = view.data.get(start..end) else {
+            fail!("Invalid offsets: the range {start}..{end} exceeds the data");
+        };
+        values.push(value);
+    }
+    Ok(values)
+}
+
+#[cfg(has_arrow)]
+fn for_each_dictionary<'a>(
+    view: &ArrayView<'a>,
+    path: &str,
+    func: &mut dyn FnMut(&str, &DictionaryArrayView<'a>) -> Result<()>,
+) -> Result<()> {
+    match view {
+        ArrayView::Dictionary(view) => func(path, view),
+        ArrayView::Struct(view) => {
+            for (child, meta) in &view.fields {
+                for_each_dictionary(child, &format!("{path}.{name}", name = meta.name), func)?;
+            }
+            Ok(())
+        }
+        ArrayView::List(view) | ArrayView::Map(view) => {
+            let child_path = format!("{path}.{name}", name = view.meta.name);
+            for_each_dictionary(&view.element, &child_path, func)
+        }
+        ArrayView::LargeList(view) => {
+            let child_path = format!("{path}.{name}", name = view.meta.name);
+            for_each_dictionary(&view.element, &child_path, func)
+        }
+        ArrayView::FixedSizeList(view) => {
+            let child_path = format!("{path}.{name}", name = view.meta.name);
+            for_each_dictionary(&view.element, &child_path, func)
+        }
+        ArrayView::DenseUnion(view) => {
+            for (_, child, meta) in &view.fields {
+                for_each_dictionary(child, &format!("{path}.{name}", name = meta.name), func)?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn for_each_dictionary_mut<'a>(
+    view: &mut ArrayView<'a>,
+    path: &str,
+    func: &mut dyn FnMut(&str, &mut DictionaryArrayView<'a>),
+) {
+    match view {
+        ArrayView::Dictionary(view) => func(path, view),
+        ArrayView::Struct(view) => {
+            for (child, meta) in &mut view.fields {
+                for_each_dictionary_mut(child, &format!("{path}.{name}", name = meta.name), func);
+            }
+        }
+        ArrayView::List(view) | ArrayView::Map(view) => {
+            let child_path = format!("{path}.{name}", name = view.meta.name);
+            for_each_dictionary_mut(&mut view.element, &child_path, func);
+        }
+        ArrayView::LargeList(view) => {
+            let child_path = format!("{path}.{name}", name = view.meta.name);
+            for_each_dictionary_mut(&mut view.element, &child_path, func);
+        }
+        ArrayView::FixedSizeList(view) => {
+            let child_path = format!("{path}.{name}", name = view.meta.name);
+            for_each_dictionary_mut(&mut view.element, &child_path, func);
+        }
+        ArrayView::DenseUnion(view) => {
+            for (_, child, meta) in &mut view.fields {
+                for_each_dictionary_mut(child, &format!("{path}.{name}", name = meta.name), func);
+            }
+        }
+        _ => {}
+    }
+}
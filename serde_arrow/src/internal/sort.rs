@@ -0,0 +1,365 @@
+//! Reorder the rows of built arrays by key fields
+//!
+//! The keys are encoded with the row format and compared as bytes. The
+//! resulting permutation is applied to each column separately, nested arrays
+//! are reordered by gathering the referenced child elements.
+use std::cmp::Ordering;
+
+use crate::internal::{
+    arrow::{
+        Array, BooleanArray, BytesArray, DataType, DecimalArray, DenseUnionArray, DictionaryArray,
+        Field, FixedSizeBinaryArray, FixedSizeListArray, ListArray, NullArray, PrimitiveArray,
+        SparseUnionArray, StructArray, TimeArray, TimestampArray,
+    },
+    error::{fail, Result},
+    row_format::{encode_array, NULL_SENTINEL},
+    serializer_options::SortOrder,
+    utils::{
+        array_ext::{array_len, get_bit_buffer, set_bit_buffer},
+        ChildName, Offset,
+    },
+};
+
+/// Check that all keys refer to fields supported as sort keys
+pub fn check_sort_keys(fields: &[Field], keys: &[(String, SortOrder)]) -> Result<()> {
+    for (path, _) in keys {
+        let Some(field) = find_field(fields, path) else {
+            fail!("Cannot sort by {path:?}: no field with this path outside of lists, maps and unions");
+        };
+        if let Some(name) = unsupported_key_type(&field.data_type) {
+            fail!("Cannot sort by {path:?}: {name} fields are not supported as keys");
+        }
+    }
+    Ok(())
+}
+
+/// Sort the rows of the arrays built for the given fields by the keys
+pub fn sort_arrays(
+    fields: &[Field],
+    arrays: Vec<Array>,
+    keys: &[(String, SortOrder)],
+) -> Result<Vec<Array>> {
+    let Some(len) = arrays.first().map(array_len) else {
+        return Ok(arrays);
+    };
+    if keys.is_empty() || len < 2 {
+        return Ok(arrays);
+    }
+
+    let mut encoded = Vec::new();
+    for (path, order) in keys {
+        let Some(array) = find_array(fields, &arrays, path) else {
+            fail!("Cannot sort by {path:?}: no field with this path outside of lists, maps and unions");
+        };
+        let mut rows = vec![Vec::new(); len];
+        encode_array(&mut rows, array)?;
+        encoded.push((rows, *order));
+    }
+
+    let mut indices = (0..len).collect::<Vec<_>>();
+    indices.sort_by(|&a, &b| compare_rows(&encoded, a, b));
+    if indices.iter().enumerate().all(|(pos, &idx)| pos == idx) {
+        return Ok(arrays);
+    }
+
+    arrays.iter().map(|array| take(array, &indices)).collect()
+}
+
+fn compare_rows(keys: &[(Vec<Vec<u8>>, SortOrder)], a: usize, b: usize) -> Ordering {
+    for (rows, order) in keys {
+        let (a, b) = (&rows[a], &rows[b]);
+        let ordering = match (is_null(a), is_null(b)) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => match order {
+                SortOrder::Ascending => a.cmp(b),
+                SortOrder::Descending => b.cmp(a),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Encoded values start with the null sentinel for nulls, the values of
+/// `Null` fields are not encoded at all
+fn is_null(row: &[u8]) -> bool {
+    matches!(row.first(), None | Some(&NULL_SENTINEL))
+}
+
+/// The name of the first data type not supported by the row format, if any
+fn unsupported_key_type(data_type: &DataType) -> Option<&'static str> {
+    match data_type {
+        DataType::List(_) => Some("List"),
+        DataType::LargeList(_) => Some("LargeList"),
+        DataType::FixedSizeList(_, _) => Some("FixedSizeList"),
+        DataType::Map(_, _) => Some("Map"),
+        DataType::Union(_, _) => Some("Union"),
+        DataType::Struct(fields) => fields
+            .iter()
+            .find_map(|field| unsupported_key_type(&field.data_type)),
+        _ => None,
+    }
+}
+
+fn find_field<'a>(fields: &'a [Field], path: &str) -> Option<&'a Field> {
+    fn find<'a>(prefix: &str, fields: &'a [Field], path: &str) -> Option<&'a Field> {
+        for field in fields {
+            let field_path = format!("{prefix}.{child}", child = ChildName(&field.name));
+            if field_path == path {
+                return Some(field);
+            }
+            if let DataType::Struct(children) = &field.data_type {
+                if let Some(field) = find(&field_path, children, path) {
+                    return Some(field);
+                }
+            }
+        }
+        None
+    }
+    find("$", fields, path)
+}
+
+fn find_array<'a>(fields: &[Field], arrays: &'a [Array], path: &str) -> Option<&'a Array> {
+    fn find<'a>(prefix: &str, name: &str, array: &'a Array, path: &str) -> Option<&'a Array> {
+        let array_path = format!("{prefix}.{child}", child = ChildName(name));
+        if array_path == path {
+            return Some(array);
+        }
+        let Array::Struct(array) = array else {
+            return None;
+        };
+        array
+            .fields
+            .iter()
+            .find_map(|(child, meta)| find(&array_path, &meta.name, child, path))
+    }
+    std::iter::zip(fields, arrays).find_map(|(field, array)| find("$", &field.name, array, path))
+}
+
+/// Build a new array with the rows of the given indices
+fn take(array: &Array, indices: &[usize]) -> Result<Array> {
+    use Array as A;
+    let array = match array {
+        A::Null(_) => A::Null(NullArray { len: indices.len() }),
+        A::Boolean(array) => A::Boolean(BooleanArray {
+            len: indices.len(),
+            validity: take_validity(array.validity.as_deref(), indices)?,
+            values: take_bits(&array.values, indices)?,
+        }),
+        A::Int8(array) => A::Int8(take_primitive(array, indices)?),
+        A::Int16(array) => A::Int16(take_primitive(array, indices)?),
+        A::Int32(array) => A::Int32(take_primitive(array, indices)?),
+        A::Int64(array) => A::Int64(take_primitive(array, indices)?),
+        A::UInt8(array) => A::UInt8(take_primitive(array, indices)?),
+        A::UInt16(array) => A::UInt16(take_primitive(array, indices)?),
+        A::UInt32(array) => A::UInt32(take_primitive(array, indices)?),
+        A::UInt64(array) => A::UInt64(take_primitive(array, indices)?),
+        A::Float16(array) => A::Float16(take_primitive(array, indices)?),
+        A::Float32(array) => A::Float32(take_primitive(array, indices)?),
+        A::Float64(array) => A::Float64(take_primitive(array, indices)?),
+        A::Date32(array) => A::Date32(take_primitive(array, indices)?),
+        A::Date64(array) => A::Date64(take_primitive(array, indices)?),
+        A::Time32(array) => A::Time32(take_time(array, indices)?),
+        A::Time64(array) => A::Time64(take_time(array, indices)?),
+        A::Duration(array) => A::Duration(take_time(array, indices)?),
+        A::IntervalYearMonth(array) => A::IntervalYearMonth(take_primitive(array, indices)?),
+        A::IntervalDayTime(array) => A::IntervalDayTime(take_primitive(array, indices)?),
+        A::IntervalMonthDayNano(array) => A::IntervalMonthDayNano(take_primitive(array, indices)?),
+        A::Timestamp(array) => A::Timestamp(TimestampArray {
+            unit: array.unit,
+            timezone: array.timezone.clone(),
+            validity: take_validity(array.validity.as_deref(), indices)?,
+            values: take_values(&array.values, indices)?,
+        }),
+        A::Decimal128(array) => A::Decimal128(take_decimal(array, indices)?),
+        A::Decimal256(array) => A::Decimal256(take_decimal(array, indices)?),
+        A::Utf8(array) => A::Utf8(take_bytes(array, indices)?),
+        A::LargeUtf8(array) => A::LargeUtf8(take_bytes(array, indices)?),
+        A::Utf8View(array) => A::Utf8View(take_bytes(array, indices)?),
+        A::Binary(array) => A::Binary(take_bytes(array, indices)?),
+        A::LargeBinary(array) => A::LargeBinary(take_bytes(array, indices)?),
+        A::FixedSizeBinary(array) => {
+            let n = usize::try_from(array.n)?;
+            let mut data = Vec::with_capacity(indices.len() * n);
+            for &idx in indices {
+                let Some(value) = array.data.get(idx * n..(idx + 1) * n) else {
+                    fail!("Invalid fixed size binary array: missing value {idx}");
+                };
+                data.extend_from_slice(value);
+            }
+            A::FixedSizeBinary(FixedSizeBinaryArray {
+                n: array.n,
+                validity: take_validity(array.validity.as_deref(), indices)?,
+                data,
+            })
+        }
+        A::Struct(array) => {
+            let mut fields = Vec::with_capacity(array.fields.len());
+            for (child, meta) in &array.fields {
+                fields.push((take(child, indices)?, meta.clone()));
+            }
+            A::Struct(StructArray {
+                len: indices.len(),
+                validity: take_validity(array.validity.as_deref(), indices)?,
+                fields,
+            })
+        }
+        A::List(array) => A::List(take_list(array, indices)?),
+        A::LargeList(array) => A::LargeList(take_list(array, indices)?),
+        A::Map(array) => A::Map(take_list(array, indices)?),
+        A::FixedSizeList(array) => {
+            let n = usize::try_from(array.n)?;
+            let child_indices = indices
+                .iter()
+                .flat_map(|&idx| idx * n..(idx + 1) * n)
+                .collect::<Vec<_>>();
+            A::FixedSizeList(FixedSizeListArray {
+                len: indices.len(),
+                n: array.n,
+                validity: take_validity(array.validity.as_deref(), indices)?,
+                meta: array.meta.clone(),
+                element: Box::new(take(&array.element, &child_indices)?),
+            })
+        }
+        A::Dictionary(array) => A::Dictionary(DictionaryArray {
+            indices: Box::new(take(&array.indices, indices)?),
+            values: array.values.clone(),
+        }),
+        A::DenseUnion(array) => A::DenseUnion(take_dense_union(array, indices)?),
+        A::SparseUnion(array) => {
+            let mut fields = Vec::with_capacity(array.fields.len());
+            for (type_id, child, meta) in &array.fields {
+                fields.push((*type_id, take(child, indices)?, meta.clone()));
+            }
+            A::SparseUnion(SparseUnionArray {
+                types: take_values(&array.types, indices)?,
+                fields,
+            })
+        }
+    };
+    Ok(array)
+}
+
+fn take_values<T: Clone>(values: &[T], indices: &[usize]) -> Result<Vec<T>> {
+    let mut result = Vec::with_capacity(indices.len());
+    for &idx in indices {
+        let Some(value) = values.get(idx) else {
+            fail!(
+                "Invalid array: cannot take value {idx} of {len} values",
+                len = values.len()
+            );
+        };
+        result.push(value.clone());
+    }
+    Ok(result)
+}
+
+fn take_bits(bits: &[u8], indices: &[usize]) -> Result<Vec<u8>> {
+    let mut result = Vec::with_capacity(indices.len().div_ceil(8));
+    for (pos, &idx) in indices.iter().enumerate() {
+        set_bit_buffer(&mut result, pos, get_bit_buffer(bits, 0, idx)?);
+    }
+    Ok(result)
+}
+
+fn take_validity(validity: Option<&[u8]>, indices: &[usize]) -> Result<Option<Vec<u8>>> {
+    validity.map(|bits| take_bits(bits, indices)).transpose()
+}
+
+fn take_primitive<T: Clone>(
+    array: &PrimitiveArray<T>,
+    indices: &[usize],
+) -> Result<PrimitiveArray<T>> {
+    Ok(PrimitiveArray {
+        validity: take_validity(array.validity.as_deref(), indices)?,
+        values: take_values(&array.values, indices)?,
+    })
+}
+
+fn take_time<T: Clone>(array: &TimeArray<T>, indices: &[usize]) -> Result<TimeArray<T>> {
+    Ok(TimeArray {
+        unit: array.unit,
+        validity: take_validity(array.validity.as_deref(), indices)?,
+        values: take_values(&array.values, indices)?,
+    })
+}
+
+fn take_decimal<T: Clone>(array: &DecimalArray<T>, indices: &[usize]) -> Result<DecimalArray<T>> {
+    Ok(DecimalArray {
+        precision: array.precision,
+        scale: array.scale,
+        validity: take_validity(array.validity.as_deref(), indices)?,
+        values: take_values(&array.values, indices)?,
+    })
+}
+
+/// The offsets of the taken segments and the indices of their elements
+fn take_segments<O: Offset>(offsets: &[O], indices: &[usize]) -> Result<(Vec<O>, Vec<usize>)> {
+    let mut new_offsets = Vec::with_capacity(indices.len() + 1);
+    let mut elements = Vec::new();
+    new_offsets.push(O::default());
+    for &idx in indices {
+        let (Some(start), Some(end)) = (offsets.get(idx), offsets.get(idx + 1)) else {
+            fail!("Invalid offsets: cannot take segment {idx}");
+        };
+        let (start, end) = (start.try_into_usize()?, end.try_into_usize()?);
+        if end < start {
+            fail!("Invalid offsets: the end {end} is smaller than the start {start}");
+        }
+        elements.extend(start..end);
+        new_offsets.push(O::try_form_usize(elements.len())?);
+    }
+    Ok((new_offsets, elements))
+}
+
+fn take_bytes<O: Offset>(array: &BytesArray<O>, indices: &[usize]) -> Result<BytesArray<O>> {
+    let (offsets, elements) = take_segments(&array.offsets, indices)?;
+    Ok(BytesArray {
+        validity: take_validity(array.validity.as_deref(), indices)?,
+        offsets,
+        data: take_values(&array.data, &elements)?,
+    })
+}
+
+fn take_list<O: Offset>(array: &ListArray<O>, indices: &[usize]) -> Result<ListArray<O>> {
+    let (offsets, elements) = take_segments(&array.offsets, indices)?;
+    Ok(ListArray {
+        validity: take_validity(array.validity.as_deref(), indices)?,
+        offsets,
+        meta: array.meta.clone(),
+        element: Box::new(take(&array.element, &elements)?),
+    })
+}
+
+/// Gather the referenced elements of each child in the new order, such that
+/// the offsets of each child are again consecutive
+fn take_dense_union(array: &DenseUnionArray, indices: &[usize]) -> Result<DenseUnionArray> {
+    let mut child_indices = vec![Vec::new(); array.fields.len()];
+    let mut types = Vec::with_capacity(indices.len());
+    let mut offsets = Vec::with_capacity(indices.len());
+    for &idx in indices {
+        let (Some(&type_id), Some(&offset)) = (array.types.get(idx), array.offsets.get(idx)) else {
+            fail!("Invalid union array: cannot take row {idx}");
+        };
+        let Some(pos) = array.fields.iter().position(|(id, _, _)| *id == type_id) else {
+            fail!("Invalid union array: unknown type id {type_id}");
+        };
+        types.push(type_id);
+        offsets.push(i32::try_from(child_indices[pos].len())?);
+        child_indices[pos].push(usize::try_from(offset)?);
+    }
+
+    let mut fields = Vec::with_capacity(array.fields.len());
+    for ((type_id, child, meta), child_indices) in std::iter::zip(&array.fields, child_indices) {
+        fields.push((*type_id, take(child, &child_indices)?, meta.clone()));
+    }
+    Ok(DenseUnionArray {
+        types,
+        offsets,
+        fields,
+    })
+}
@@ -0,0 +1,240 @@
+use std::{
+    io::{Read, Write},
+    marker::PhantomData,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::internal::{
+    array_builder::ArrayBuilder,
+    error::{fail, Error, Result},
+};
+
+/// Write records to temporary storage in a compact row-wise format
+///
+/// Each record is encoded as [CBOR](https://cbor.io) and prefixed with its
+/// length in bytes as a little endian `u32`. The records can be read back with
+/// a [`SpillReader`] and pushed into an [`ArrayBuilder`] with the existing
+/// schema, e.g., to build batches out-of-core when records arrive faster than
+/// they can be processed:
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     spill::{SpillReader, SpillWriter},
+///     ArrayBuilder,
+/// };
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: u32,
+///     b: Option<String>,
+/// }
+///
+/// let mut writer = SpillWriter::new(Vec::<u8>::new());
+/// for a in 0..5 {
+///     writer.push(&Record { a, b: None })?;
+/// }
+/// let spilled = writer.finish()?;
+///
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// let mut builder = ArrayBuilder::from_arrow(&fields)?;
+/// let mut reader = SpillReader::<_, Record>::new(spilled.as_slice());
+///
+/// let mut batches = Vec::new();
+/// while reader.read_into(&mut builder, 2)? != 0 {
+///     batches.push(builder.to_record_batch()?);
+/// }
+///
+/// assert_eq!(batches.len(), 3);
+/// assert_eq!(batches[2].num_rows(), 1);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+#[derive(Debug)]
+pub struct SpillWriter<W> {
+    writer: W,
+    buffer: Vec<u8>,
+    len: usize,
+}
+
+impl<W: Write> SpillWriter<W> {
+    /// Construct a new spill writer writing into `writer`
+    ///
+    /// The writer is used as is. Wrap unbuffered writers, e.g., files, in a
+    /// [`BufWriter`][std::io::BufWriter].
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buffer: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Write a single record
+    pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
+        self.buffer.clear();
+        if let Err(err) = ciborium::into_writer(item, &mut self.buffer) {
+            fail!("Cannot encode record {idx} as CBOR: {err}", idx = self.len);
+        }
+        let Ok(len) = u32::try_from(self.buffer.len()) else {
+            fail!(
+                "Cannot spill record {idx} of {len} bytes: records must be smaller than 4 GiB",
+                idx = self.len,
+                len = self.buffer.len(),
+            );
+        };
+        self.writer.write_all(&len.to_le_bytes())?;
+        self.writer.write_all(&self.buffer)?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Write multiple records
+    pub fn extend<'a, T: Serialize + 'a>(
+        &mut self,
+        items: impl IntoIterator<Item = &'a T>,
+    ) -> Result<()> {
+        for item in items {
+            self.push(item)?;
+        }
+        Ok(())
+    }
+
+    /// The number of records written so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether no records were written
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Flush and return the underlying writer
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Read records written by a [`SpillWriter`]
+///
+/// The records are decoded into `T`, the type used when spilling them, and
+/// then either returned by iterating over the reader or pushed directly into
+/// an [`ArrayBuilder`] via [`read_into`][SpillReader::read_into]. See
+/// [`SpillWriter`] for an example.
+#[derive(Debug)]
+pub struct SpillReader<R, T> {
+    reader: R,
+    buffer: Vec<u8>,
+    len: usize,
+    _item: PhantomData<fn() -> T>,
+}
+
+impl<R: Read, T: DeserializeOwned> SpillReader<R, T> {
+    /// Construct a new spill reader reading from `reader`
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            len: 0,
+            _item: PhantomData,
+        }
+    }
+
+    /// Read the next record, return `None` once all records were read
+    pub fn read_next(&mut self) -> Result<Option<T>> {
+        let mut header = [0_u8; 4];
+        let mut filled = 0;
+        while filled < header.len() {
+            match self.reader.read(&mut header[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => fail!("Truncated header of spilled record {idx}", idx = self.len),
+                Ok(n) => filled += n,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(Error::from(err)),
+            }
+        }
+
+        // do not trust the header to allocate the buffer, as corrupted inputs
+        // may announce arbitrarily large records
+        let len = u32::from_le_bytes(header);
+        self.buffer.clear();
+        if let Err(err) = (&mut self.reader)
+            .take(u64::from(len))
+            .read_to_end(&mut self.buffer)
+        {
+            fail!(
+                "Cannot read spilled record {idx} of {len} bytes: {err}",
+                idx = self.len,
+            );
+        }
+        if self.buffer.len() != usize::try_from(len)? {
+            fail!(
+                "Cannot read spilled record {idx} of {len} bytes: unexpected end of input after {read} bytes",
+                idx = self.len,
+                read = self.buffer.len(),
+            );
+        }
+        let item = match ciborium::from_reader(self.buffer.as_slice()) {
+            Ok(item) => item,
+            Err(err) => fail!("Cannot decode spilled record {idx}: {err}", idx = self.len),
+        };
+        self.len += 1;
+        Ok(Some(item))
+    }
+
+    /// Push up to `max_rows` records into `builder` and return the number of
+    /// records pushed
+    ///
+    /// A return value of `0` signals that all records were read. If a record
+    /// cannot be pushed into the builder, the error is returned and the record
+    /// is skipped, i.e., it is not pushed again by the next call. To handle
+    /// such records, read them with [`read_next`][SpillReader::read_next] and
+    /// push them individually.
+    pub fn read_into(&mut self, builder: &mut ArrayBuilder, max_rows: usize) -> Result<usize>
+    where
+        T: Serialize,
+    {
+        let mut num_rows = 0;
+        while num_rows < max_rows {
+            let Some(item) = self.read_next()? else {
+                break;
+            };
+            builder.push(&item)?;
+            num_rows += 1;
+        }
+        Ok(num_rows)
+    }
+
+    /// The number of records read so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether no records were read
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Unwrap the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read, T: DeserializeOwned> Iterator for SpillReader<R, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_next().transpose()
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+/// The dictionaries of dictionary encoded string columns shared between builders
+///
+/// Per default, each array built by an [`ArrayBuilder`][crate::ArrayBuilder]
+/// uses its own dictionary. The interner allows to keep dictionaries stable
+/// across batches: the same string is always mapped to the same index and new
+/// strings are appended to the dictionary. The interner is loaded into a
+/// builder via
+/// [`ArrayBuilder::intern_strings`][crate::ArrayBuilder::intern_strings] and
+/// updated via
+/// [`ArrayBuilder::string_interner`][crate::ArrayBuilder::string_interner].
+///
+/// The dictionaries are identified by the path of the field, e.g., `$.a` for a
+/// top-level field `a`.
+///
+/// ```rust
+/// # #[cfg(has_arrow)]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     ArrayBuilder, StringInterner,
+/// };
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: String,
+/// }
+///
+/// let options = TracingOptions::default().string_dictionary_encoding(true);
+/// let fields = Vec::<FieldRef>::from_type::<Record>(options)?;
+///
+/// let mut interner = StringInterner::new();
+/// for batch in [["foo", "bar"], ["baz", "foo"]] {
+///     let mut builder = ArrayBuilder::from_arrow(&fields)?;
+///     builder.intern_strings(&interner)?;
+///     for a in batch {
+///         builder.push(&Record { a: a.to_string() })?;
+///     }
+///     let _arrays = builder.to_arrow()?;
+///     interner = builder.string_interner();
+/// }
+///
+/// assert_eq!(
+///     interner.get("$.a"),
+///     Some(&[String::from("foo"), String::from("bar"), String::from("baz")][..]),
+/// );
+/// # Ok(())
+/// # }
+/// # #[cfg(not(has_arrow))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StringInterner {
+    pub(crate) dictionaries: BTreeMap<String, Vec<String>>,
+}
+
+impl StringInterner {
+    /// Construct an empty interner
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the values of the dictionary for the field with the given path
+    pub fn get(&self, path: &str) -> Option<&[String]> {
+        Some(self.dictionaries.get(path)?.as_slice())
+    }
+
+    /// Check whether the interner contains no dictionaries
+    pub fn is_empty(&self) -> bool {
+        self.dictionaries.is_empty()
+    }
+
+    /// Remove all dictionaries
+    pub fn clear(&mut self) {
+        self.dictionaries.clear();
+    }
+}
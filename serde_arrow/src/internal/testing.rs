@@ -34,6 +34,7 @@ pub(crate) use hash_map;
 
 use super::utils::array_ext::get_bit_buffer;
 
+#[cfg_attr(not(all(has_arrow, has_arrow2)), allow(dead_code))]
 pub(crate) trait ArrayAccess {
     fn get_utf8(&self, idx: usize) -> Result<Option<&str>>;
 }
@@ -48,6 +49,7 @@ impl ArrayAccess for Array {
     }
 }
 
+#[cfg_attr(not(all(has_arrow, has_arrow2)), allow(dead_code))]
 fn get_utf8_impl<O>(array: &BytesArray<O>, idx: usize) -> Result<Option<&str>>
 where
     O: Copy,
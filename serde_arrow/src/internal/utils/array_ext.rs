@@ -1,13 +1,19 @@
 //! Extension of the array types
 
 use crate::internal::{
-    arrow::{BytesArray, PrimitiveArray},
+    arrow::{Array, BytesArray, PrimitiveArray},
     error::{fail, Result},
     utils::Offset,
 };
 
 pub trait ArrayExt: Sized + 'static {
     fn take(&mut self) -> Self;
+
+    /// The number of bytes of the buffers
+    fn byte_size(&self) -> usize;
+
+    /// Reserve capacity for at least `additional` more elements
+    fn reserve(&mut self, additional: usize);
 }
 
 pub trait ScalarArrayExt<'value>: ArrayExt {
@@ -16,6 +22,22 @@ pub trait ScalarArrayExt<'value>: ArrayExt {
     fn push_scalar_default(&mut self) -> Result<()>;
     fn push_scalar_none(&mut self) -> Result<()>;
     fn push_scalar_value(&mut self, value: Self::Value) -> Result<()>;
+
+    /// Push `n` default values, equivalent to `n` calls of `push_scalar_default`
+    fn push_scalar_defaults(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.push_scalar_default()?;
+        }
+        Ok(())
+    }
+
+    /// Push `n` nulls, equivalent to `n` calls of `push_scalar_none`
+    fn push_scalar_nones(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.push_scalar_none()?;
+        }
+        Ok(())
+    }
 }
 
 /// An array that models a sequence
@@ -28,6 +50,70 @@ pub trait SeqArrayExt: ArrayExt {
     fn start_seq(&mut self) -> Result<()>;
     fn push_seq_elements(&mut self, n: usize) -> Result<()>;
     fn end_seq(&mut self) -> Result<()>;
+
+    /// Push `n` default sequences, equivalent to `n` calls of `push_seq_default`
+    fn push_seq_defaults(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.push_seq_default()?;
+        }
+        Ok(())
+    }
+
+    /// Push `n` null sequences, equivalent to `n` calls of `push_seq_none`
+    fn push_seq_nones(&mut self, n: usize) -> Result<()> {
+        for _ in 0..n {
+            self.push_seq_none()?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of elements of an array
+pub fn array_len(array: &Array) -> usize {
+    use Array as A;
+    match array {
+        A::Null(array) => array.len,
+        A::Boolean(array) => array.len,
+        A::Int8(array) => array.values.len(),
+        A::Int16(array) => array.values.len(),
+        A::Int32(array) => array.values.len(),
+        A::Int64(array) => array.values.len(),
+        A::UInt8(array) => array.values.len(),
+        A::UInt16(array) => array.values.len(),
+        A::UInt32(array) => array.values.len(),
+        A::UInt64(array) => array.values.len(),
+        A::Float16(array) => array.values.len(),
+        A::Float32(array) => array.values.len(),
+        A::Float64(array) => array.values.len(),
+        A::Date32(array) => array.values.len(),
+        A::Date64(array) => array.values.len(),
+        A::Time32(array) => array.values.len(),
+        A::Time64(array) => array.values.len(),
+        A::Timestamp(array) => array.values.len(),
+        A::Duration(array) => array.values.len(),
+        A::IntervalYearMonth(array) => array.values.len(),
+        A::IntervalDayTime(array) => array.values.len(),
+        A::IntervalMonthDayNano(array) => array.values.len(),
+        A::Decimal128(array) => array.values.len(),
+        A::Decimal256(array) => array.values.len(),
+        A::Utf8(array) => array.offsets.len().saturating_sub(1),
+        A::LargeUtf8(array) => array.offsets.len().saturating_sub(1),
+        A::Utf8View(array) => array.offsets.len().saturating_sub(1),
+        A::Binary(array) => array.offsets.len().saturating_sub(1),
+        A::LargeBinary(array) => array.offsets.len().saturating_sub(1),
+        A::FixedSizeBinary(array) => match usize::try_from(array.n) {
+            Ok(n) if n > 0 => array.data.len() / n,
+            _ => 0,
+        },
+        A::Struct(array) => array.len,
+        A::List(array) => array.offsets.len().saturating_sub(1),
+        A::LargeList(array) => array.offsets.len().saturating_sub(1),
+        A::FixedSizeList(array) => array.len,
+        A::Map(array) => array.offsets.len().saturating_sub(1),
+        A::Dictionary(array) => array_len(&array.indices),
+        A::DenseUnion(array) => array.types.len(),
+        A::SparseUnion(array) => array.types.len(),
+    }
 }
 
 pub fn new_primitive_array<T>(is_nullable: bool) -> PrimitiveArray<T> {
@@ -44,6 +130,15 @@ impl<T: Default + 'static> ArrayExt for PrimitiveArray<T> {
             values: std::mem::take(&mut self.values),
         }
     }
+
+    fn byte_size(&self) -> usize {
+        validity_size(self.validity.as_ref()) + std::mem::size_of_val(self.values.as_slice())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        reserve_validity(self.validity.as_mut(), self.values.len(), additional);
+        self.values.reserve(additional);
+    }
 }
 
 impl<T: Default + 'static> ScalarArrayExt<'static> for PrimitiveArray<T> {
@@ -66,6 +161,18 @@ impl<T: Default + 'static> ScalarArrayExt<'static> for PrimitiveArray<T> {
         self.values.push(value);
         Ok(())
     }
+
+    fn push_scalar_defaults(&mut self, n: usize) -> Result<()> {
+        set_validity_defaults(self.validity.as_mut(), self.values.len(), n);
+        self.values.resize_with(self.values.len() + n, T::default);
+        Ok(())
+    }
+
+    fn push_scalar_nones(&mut self, n: usize) -> Result<()> {
+        set_validity_nulls(self.validity.as_mut(), self.values.len(), n)?;
+        self.values.resize_with(self.values.len() + n, T::default);
+        Ok(())
+    }
 }
 
 pub fn new_bytes_array<O: Offset>(is_nullable: bool) -> BytesArray<O> {
@@ -84,6 +191,18 @@ impl<O: Offset> ArrayExt for BytesArray<O> {
             offsets: std::mem::replace(&mut self.offsets, vec![O::default()]),
         }
     }
+
+    fn byte_size(&self) -> usize {
+        validity_size(self.validity.as_ref())
+            + std::mem::size_of_val(self.offsets.as_slice())
+            + self.data.len()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let len = self.offsets.len().saturating_sub(1);
+        reserve_validity(self.validity.as_mut(), len, additional);
+        self.offsets.reserve(additional);
+    }
 }
 
 impl<O: Offset> SeqArrayExt for BytesArray<O> {
@@ -145,6 +264,18 @@ impl<'s, O: Offset> ScalarArrayExt<'s> for BytesArray<O> {
         self.data.extend(value);
         Ok(())
     }
+
+    fn push_scalar_defaults(&mut self, n: usize) -> Result<()> {
+        let len = self.offsets.len().saturating_sub(1);
+        set_validity_defaults(self.validity.as_mut(), len, n);
+        repeat_last(&mut self.offsets, n)
+    }
+
+    fn push_scalar_nones(&mut self, n: usize) -> Result<()> {
+        let len = self.offsets.len().saturating_sub(1);
+        set_validity_nulls(self.validity.as_mut(), len, n)?;
+        repeat_last(&mut self.offsets, n)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -169,6 +300,16 @@ impl<O: Offset> ArrayExt for OffsetsArray<O> {
             offsets: std::mem::replace(&mut self.offsets, vec![O::default()]),
         }
     }
+
+    fn byte_size(&self) -> usize {
+        validity_size(self.validity.as_ref()) + std::mem::size_of_val(self.offsets.as_slice())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        let len = self.offsets.len().saturating_sub(1);
+        reserve_validity(self.validity.as_mut(), len, additional);
+        self.offsets.reserve(additional);
+    }
 }
 
 impl<O: Offset> SeqArrayExt for OffsetsArray<O> {
@@ -206,6 +347,18 @@ impl<O: Offset> SeqArrayExt for OffsetsArray<O> {
     fn end_seq(&mut self) -> Result<()> {
         Ok(())
     }
+
+    fn push_seq_defaults(&mut self, n: usize) -> Result<()> {
+        let len = self.offsets.len().saturating_sub(1);
+        set_validity_defaults(self.validity.as_mut(), len, n);
+        repeat_last(&mut self.offsets, n)
+    }
+
+    fn push_seq_nones(&mut self, n: usize) -> Result<()> {
+        let len = self.offsets.len().saturating_sub(1);
+        set_validity_nulls(self.validity.as_mut(), len, n)?;
+        repeat_last(&mut self.offsets, n)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -230,6 +383,14 @@ impl ArrayExt for CountArray {
             validity: self.validity.as_mut().map(std::mem::take),
         }
     }
+
+    fn byte_size(&self) -> usize {
+        validity_size(self.validity.as_ref())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        reserve_validity(self.validity.as_mut(), self.len, additional);
+    }
 }
 
 impl SeqArrayExt for CountArray {
@@ -258,6 +419,31 @@ impl SeqArrayExt for CountArray {
     fn end_seq(&mut self) -> Result<()> {
         Ok(())
     }
+
+    fn push_seq_defaults(&mut self, n: usize) -> Result<()> {
+        set_validity_defaults(self.validity.as_mut(), self.len, n);
+        self.len += n;
+        Ok(())
+    }
+
+    fn push_seq_nones(&mut self, n: usize) -> Result<()> {
+        set_validity_nulls(self.validity.as_mut(), self.len, n)?;
+        self.len += n;
+        Ok(())
+    }
+}
+
+/// The number of bytes of an optional validity buffer
+pub fn validity_size(validity: Option<&Vec<u8>>) -> usize {
+    validity.map_or(0, Vec::len)
+}
+
+/// Reserve the bytes required for `additional` bits after the first `len` bits
+pub fn reserve_validity(validity: Option<&mut Vec<u8>>, len: usize, additional: usize) {
+    if let Some(validity) = validity {
+        let required = len.saturating_add(additional).div_ceil(8);
+        validity.reserve(required.saturating_sub(validity.len()));
+    }
 }
 
 pub fn duplicate_last<T: Clone>(vec: &mut Vec<T>) -> Result<()> {
@@ -268,6 +454,15 @@ pub fn duplicate_last<T: Clone>(vec: &mut Vec<T>) -> Result<()> {
     Ok(())
 }
 
+/// Append `n` copies of the last element, e.g., to push empty sequences
+pub fn repeat_last<T: Clone>(vec: &mut Vec<T>, n: usize) -> Result<()> {
+    let Some(last) = vec.last().cloned() else {
+        fail!("Invalid offset array: expected at least a single element")
+    };
+    vec.resize(vec.len() + n, last);
+    Ok(())
+}
+
 pub fn increment_last<O: Offset>(vec: &mut [O], inc: usize) -> Result<()> {
     let Some(last) = vec.last_mut() else {
         fail!("Invalid offset array: expected at least a single element")
@@ -294,6 +489,52 @@ pub fn set_validity_default(buffer: Option<&mut Vec<u8>>, idx: usize) {
     }
 }
 
+/// Mark `n` elements starting at `idx` as null
+pub fn set_validity_nulls(buffer: Option<&mut Vec<u8>>, idx: usize, n: usize) -> Result<()> {
+    if let Some(buffer) = buffer {
+        clear_bit_buffer(buffer, idx, n);
+        Ok(())
+    } else if n == 0 {
+        Ok(())
+    } else {
+        fail!("Cannot push null for non-nullable array");
+    }
+}
+
+/// In contrast to `set_validity_nulls` nulls for non-nullable fields are not an error
+pub fn set_validity_defaults(buffer: Option<&mut Vec<u8>>, idx: usize, n: usize) {
+    if let Some(buffer) = buffer {
+        clear_bit_buffer(buffer, idx, n);
+    }
+}
+
+/// Unset the bits `idx..idx + n`, equivalent to `n` calls of `set_bit_buffer`
+/// with `false`, but operating on whole bytes
+pub fn clear_bit_buffer(buffer: &mut Vec<u8>, idx: usize, n: usize) {
+    if n == 0 {
+        return;
+    }
+    let end = idx + n;
+    if buffer.len() < end.div_ceil(8) {
+        buffer.resize(end.div_ceil(8), 0);
+    }
+
+    let mut pos = idx;
+    while pos < end && !pos.is_multiple_of(8) {
+        buffer[pos / 8] &= !(1 << (pos % 8));
+        pos += 1;
+    }
+    let full_end = end / 8 * 8;
+    if pos < full_end {
+        buffer[pos / 8..full_end / 8].fill(0);
+        pos = full_end;
+    }
+    while pos < end {
+        buffer[pos / 8] &= !(1 << (pos % 8));
+        pos += 1;
+    }
+}
+
 pub fn set_bit_buffer(buffer: &mut Vec<u8>, idx: usize, value: bool) {
     while idx / 8 >= buffer.len() {
         buffer.push(0);
@@ -336,3 +577,19 @@ fn test_set_bit_buffer() {
     set_bit_buffer(&mut buffer, 4, false);
     assert_eq!(buffer, vec![0b_0010_0001, 0b_0000_0000, 0b_0000_0100]);
 }
+
+#[test]
+fn test_clear_bit_buffer() {
+    for idx in 0..20 {
+        for n in 0..30 {
+            let mut expected = vec![0xff_u8; 3];
+            for i in idx..idx + n {
+                set_bit_buffer(&mut expected, i, false);
+            }
+
+            let mut actual = vec![0xff_u8; 3];
+            clear_bit_buffer(&mut actual, idx, n);
+            assert_eq!(actual, expected, "idx={idx}, n={n}");
+        }
+    }
+}
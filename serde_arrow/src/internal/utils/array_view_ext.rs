@@ -1,7 +1,32 @@
-use crate::internal::arrow::ArrayView;
+use crate::internal::{
+    arrow::{ArrayView, BitsWithOffset},
+    error::{fail, Error, Result},
+    utils::{array_ext::get_bit_buffer, ChildName},
+};
 
 pub trait ArrayViewExt {
     fn len(&self) -> usize;
+
+    /// The name of the data type of the array, without its parameters
+    fn type_name(&self) -> &'static str;
+
+    /// A view of the rows `start..start + len` sharing the buffers of this view
+    fn slice(&self, start: usize, len: usize) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Whether the row `idx` is not null
+    fn is_valid(&self, idx: usize) -> Result<bool>;
+
+    /// Whether any row of the array is null
+    fn has_nulls(&self) -> Result<bool> {
+        for idx in 0..self.len() {
+            if !self.is_valid(idx)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
 }
 
 impl<'a> ArrayViewExt for ArrayView<'a> {
@@ -27,7 +52,11 @@ impl<'a> ArrayViewExt for ArrayView<'a> {
             V::Time64(view) => view.values.len(),
             V::Timestamp(view) => view.values.len(),
             V::Duration(view) => view.values.len(),
+            V::IntervalYearMonth(view) => view.values.len(),
+            V::IntervalDayTime(view) => view.values.len(),
+            V::IntervalMonthDayNano(view) => view.values.len(),
             V::Decimal128(view) => view.values.len(),
+            V::Decimal256(view) => view.values.len(),
             V::Utf8(view) => view.offsets.len().saturating_sub(1),
             V::LargeUtf8(view) => view.offsets.len().saturating_sub(1),
             V::Binary(view) => view.offsets.len().saturating_sub(1),
@@ -45,4 +74,260 @@ impl<'a> ArrayViewExt for ArrayView<'a> {
             V::Dictionary(view) => view.indices.len(),
         }
     }
+
+    fn type_name(&self) -> &'static str {
+        use ArrayView as V;
+        match self {
+            V::Null(_) => "Null",
+            V::Boolean(_) => "Boolean",
+            V::Int8(_) => "Int8",
+            V::Int16(_) => "Int16",
+            V::Int32(_) => "Int32",
+            V::Int64(_) => "Int64",
+            V::UInt8(_) => "UInt8",
+            V::UInt16(_) => "UInt16",
+            V::UInt32(_) => "UInt32",
+            V::UInt64(_) => "UInt64",
+            V::Float16(_) => "Float16",
+            V::Float32(_) => "Float32",
+            V::Float64(_) => "Float64",
+            V::Date32(_) => "Date32",
+            V::Date64(_) => "Date64",
+            V::Time32(_) => "Time32",
+            V::Time64(_) => "Time64",
+            V::Timestamp(_) => "Timestamp",
+            V::Duration(_) => "Duration",
+            V::IntervalYearMonth(_) => "Interval",
+            V::IntervalDayTime(_) => "Interval",
+            V::IntervalMonthDayNano(_) => "Interval",
+            V::Decimal128(_) => "Decimal128",
+            V::Decimal256(_) => "Decimal256",
+            V::Utf8(_) => "Utf8",
+            V::LargeUtf8(_) => "LargeUtf8",
+            V::Binary(_) => "Binary",
+            V::LargeBinary(_) => "LargeBinary",
+            V::FixedSizeBinary(_) => "FixedSizeBinary",
+            V::FixedSizeList(_) => "FixedSizeList",
+            V::List(_) => "List",
+            V::LargeList(_) => "LargeList",
+            V::DenseUnion(_) => "Union",
+            V::Map(_) => "Map",
+            V::Struct(_) => "Struct",
+            V::Dictionary(_) => "Dictionary",
+        }
+    }
+
+    fn is_valid(&self, idx: usize) -> Result<bool> {
+        use ArrayView as V;
+
+        let validity = match self {
+            V::Null(_) => return Ok(false),
+            V::DenseUnion(_) => return Ok(true),
+            V::Dictionary(view) => return view.indices.is_valid(idx),
+            V::Boolean(view) => view.validity,
+            V::Int8(view) => view.validity,
+            V::Int16(view) => view.validity,
+            V::Int32(view) => view.validity,
+            V::Int64(view) => view.validity,
+            V::UInt8(view) => view.validity,
+            V::UInt16(view) => view.validity,
+            V::UInt32(view) => view.validity,
+            V::UInt64(view) => view.validity,
+            V::Float16(view) => view.validity,
+            V::Float32(view) => view.validity,
+            V::Float64(view) => view.validity,
+            V::Date32(view) => view.validity,
+            V::Date64(view) => view.validity,
+            V::Time32(view) => view.validity,
+            V::Time64(view) => view.validity,
+            V::Timestamp(view) => view.validity,
+            V::Duration(view) => view.validity,
+            V::IntervalYearMonth(view) => view.validity,
+            V::IntervalDayTime(view) => view.validity,
+            V::IntervalMonthDayNano(view) => view.validity,
+            V::Decimal128(view) => view.validity,
+            V::Decimal256(view) => view.validity,
+            V::Utf8(view) => view.validity,
+            V::LargeUtf8(view) => view.validity,
+            V::Binary(view) => view.validity,
+            V::LargeBinary(view) => view.validity,
+            V::FixedSizeBinary(view) => view.validity,
+            V::FixedSizeList(view) => view.validity,
+            V::List(view) => view.validity,
+            V::LargeList(view) => view.validity,
+            V::Map(view) => view.validity,
+            V::Struct(view) => view.validity,
+        };
+        match validity {
+            Some(validity) => get_bit_buffer(validity.data, validity.offset, idx),
+            None => Ok(true),
+        }
+    }
+
+    fn slice(&self, start: usize, len: usize) -> Result<Self> {
+        use ArrayView as V;
+
+        let Some(end) = start.checked_add(len) else {
+            fail!("Invalid slice {start}..{start}+{len}");
+        };
+        if end > self.len() {
+            fail!(
+                "Cannot slice rows {start}..{end} of an array with {len} rows",
+                len = self.len()
+            );
+        }
+
+        let view = match self {
+            V::Null(view) => {
+                let mut view = view.clone();
+                view.len = len;
+                V::Null(view)
+            }
+            V::Boolean(view) => {
+                let mut view = view.clone();
+                view.len = len;
+                view.validity = slice_bits(view.validity, start);
+                view.values.offset += start;
+                V::Boolean(view)
+            }
+            V::Int8(view) => V::Int8(slice_primitive!(view, start, end)),
+            V::Int16(view) => V::Int16(slice_primitive!(view, start, end)),
+            V::Int32(view) => V::Int32(slice_primitive!(view, start, end)),
+            V::Int64(view) => V::Int64(slice_primitive!(view, start, end)),
+            V::UInt8(view) => V::UInt8(slice_primitive!(view, start, end)),
+            V::UInt16(view) => V::UInt16(slice_primitive!(view, start, end)),
+            V::UInt32(view) => V::UInt32(slice_primitive!(view, start, end)),
+            V::UInt64(view) => V::UInt64(slice_primitive!(view, start, end)),
+            V::Float16(view) => V::Float16(slice_primitive!(view, start, end)),
+            V::Float32(view) => V::Float32(slice_primitive!(view, start, end)),
+            V::Float64(view) => V::Float64(slice_primitive!(view, start, end)),
+            V::Date32(view) => V::Date32(slice_primitive!(view, start, end)),
+            V::Date64(view) => V::Date64(slice_primitive!(view, start, end)),
+            V::Time32(view) => V::Time32(slice_primitive!(view, start, end)),
+            V::Time64(view) => V::Time64(slice_primitive!(view, start, end)),
+            V::Timestamp(view) => V::Timestamp(slice_primitive!(view, start, end)),
+            V::Duration(view) => V::Duration(slice_primitive!(view, start, end)),
+            V::IntervalYearMonth(view) => V::IntervalYearMonth(slice_primitive!(view, start, end)),
+            V::IntervalDayTime(view) => V::IntervalDayTime(slice_primitive!(view, start, end)),
+            V::IntervalMonthDayNano(view) => {
+                V::IntervalMonthDayNano(slice_primitive!(view, start, end))
+            }
+            V::Decimal128(view) => V::Decimal128(slice_primitive!(view, start, end)),
+            V::Decimal256(view) => V::Decimal256(slice_primitive!(view, start, end)),
+            // offsets are absolute positions into the data, only the offsets
+            // themselves need to be sliced
+            V::Utf8(view) => V::Utf8(slice_offsets!(view, start, end)),
+            V::LargeUtf8(view) => V::LargeUtf8(slice_offsets!(view, start, end)),
+            V::Binary(view) => V::Binary(slice_offsets!(view, start, end)),
+            V::LargeBinary(view) => V::LargeBinary(slice_offsets!(view, start, end)),
+            V::List(view) => V::List(slice_offsets!(view, start, end)),
+            V::LargeList(view) => V::LargeList(slice_offsets!(view, start, end)),
+            V::Map(view) => V::Map(slice_offsets!(view, start, end)),
+            V::FixedSizeBinary(view) => {
+                let mut view = view.clone();
+                let n = usize::try_from(view.n)?;
+                view.validity = slice_bits(view.validity, start);
+                view.data = slice_values(view.data, start * n, end * n)?;
+                V::FixedSizeBinary(view)
+            }
+            V::FixedSizeList(view) => {
+                let mut view = view.clone();
+                let n = usize::try_from(view.n)?;
+                view.len = len;
+                view.validity = slice_bits(view.validity, start);
+                view.element = Box::new(view.element.slice(start * n, len * n)?);
+                V::FixedSizeList(view)
+            }
+            V::Struct(view) => {
+                let mut view = view.clone();
+                view.len = len;
+                view.validity = slice_bits(view.validity, start);
+                for (field, _) in &mut view.fields {
+                    *field = field.slice(start, len)?;
+                }
+                V::Struct(view)
+            }
+            V::Dictionary(view) => {
+                let mut view = view.clone();
+                view.indices = Box::new(view.indices.slice(start, len)?);
+                V::Dictionary(view)
+            }
+            V::DenseUnion(view) => {
+                // the offsets point into the children, which are kept as is
+                let mut view = view.clone();
+                view.types = slice_values(view.types, start, end)?;
+                view.offsets = slice_values(view.offsets, start, end)?;
+                V::DenseUnion(view)
+            }
+        };
+        Ok(view)
+    }
+}
+
+macro_rules! slice_primitive {
+    ($view:expr, $start:expr, $end:expr) => {{
+        let mut view = $view.clone();
+        view.validity = slice_bits(view.validity, $start);
+        view.values = slice_values(view.values, $start, $end)?;
+        view
+    }};
+}
+
+use slice_primitive;
+
+macro_rules! slice_offsets {
+    ($view:expr, $start:expr, $end:expr) => {{
+        let mut view = $view.clone();
+        view.validity = slice_bits(view.validity, $start);
+        view.offsets = slice_values(view.offsets, $start, $end + 1)?;
+        view
+    }};
+}
+
+use slice_offsets;
+
+fn slice_bits(bits: Option<BitsWithOffset<'_>>, start: usize) -> Option<BitsWithOffset<'_>> {
+    bits.map(|bits| BitsWithOffset {
+        offset: bits.offset + start,
+        data: bits.data,
+    })
+}
+
+fn slice_values<T>(values: &[T], start: usize, end: usize) -> Result<&[T]> {
+    match values.get(start..end) {
+        Some(values) => Ok(values),
+        None => fail!(
+            "Cannot slice {start}..{end} of a buffer with {len} elements",
+            len = values.len()
+        ),
+    }
+}
+
+/// Supported data types to use instead of an unsupported data type
+///
+/// The data type is given by its display representation, only the name up to
+/// the first parenthesis is considered.
+pub fn supported_alternatives(data_type: &str) -> Vec<String> {
+    let name = data_type.split('(').next().unwrap_or_default().trim();
+    let alternatives: &[&str] = match name {
+        "Utf8View" => &["Utf8", "LargeUtf8"],
+        "BinaryView" => &["Binary", "LargeBinary"],
+        "ListView" => &["List"],
+        "LargeListView" => &["LargeList"],
+        "Union" => &["Union(Dense)"],
+        _ => &[],
+    };
+    alternatives.iter().map(|s| String::from(*s)).collect()
+}
+
+/// The error for an array whose data type cannot be read
+pub fn unsupported_data_type(data_type: String, reason: &str) -> Error {
+    let alternatives = supported_alternatives(&data_type);
+    Error::unsupported_data_type(String::new(), data_type, alternatives, reason)
+}
+
+/// Attach the path of the top-level field with the given name to errors of
+/// unsupported data types
+pub fn in_field<T>(res: Result<T>, name: &str) -> Result<T> {
+    res.map_err(|err| err.in_parent(format!("$.{child}", child = ChildName(name))))
 }
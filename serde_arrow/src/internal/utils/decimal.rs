@@ -4,10 +4,188 @@
 //! characterized by a precision, the total number of digits, and the scale, the
 //! position of the decimal point.
 
-use crate::internal::error::{fail, Result};
+use std::fmt::Display;
 
+use crate::internal::{
+    arrow::I256,
+    error::{fail, Result},
+};
+
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
 pub const BUFFER_SIZE_I128: usize = 64;
 
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub const BUFFER_SIZE_I256: usize = 128;
+
+/// The buffer size required to format any `i128` or 256 bit integer with any
+/// `i8` scale
+///
+/// The longest outputs are 78 characters for the minimal 256 bit integer
+/// followed by 128 zeros for a scale of `-128`.
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub const FORMAT_BUFFER_SIZE_I256: usize = 78 + 128;
+
+/// The storage type of the unscaled values of a decimal array
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub trait DecimalValue: Copy + Display {
+    const DATA_TYPE: &'static str;
+
+    fn to_i128(self) -> Result<i128>;
+
+    /// Rescale the unscaled value, see [`rescale`]
+    fn rescale(self, from_scale: i8, to_scale: i8) -> Result<Self>;
+}
+
+impl DecimalValue for i128 {
+    const DATA_TYPE: &'static str = "Decimal128(..)";
+
+    fn to_i128(self) -> Result<i128> {
+        Ok(self)
+    }
+
+    fn rescale(self, from_scale: i8, to_scale: i8) -> Result<Self> {
+        rescale(self, from_scale, to_scale)
+    }
+}
+
+impl DecimalValue for I256 {
+    const DATA_TYPE: &'static str = "Decimal256(..)";
+
+    fn to_i128(self) -> Result<i128> {
+        let low = self.low as i128;
+        if self.high != low >> 127 {
+            fail!("Value {self} does not fit into i128");
+        }
+        Ok(low)
+    }
+
+    fn rescale(self, from_scale: i8, to_scale: i8) -> Result<Self> {
+        rescale_i256(self, from_scale, to_scale)
+    }
+}
+
+impl I256 {
+    pub fn from_i128(value: i128) -> Self {
+        Self {
+            low: value as u128,
+            high: value >> 127,
+        }
+    }
+
+    fn wrapping_neg(self) -> Self {
+        let low = (!self.low).wrapping_add(1);
+        let high = (!self.high).wrapping_add(if low == 0 { 1 } else { 0 });
+        Self { low, high }
+    }
+
+    /// The absolute value as little endian 64 bit limbs
+    fn unsigned_abs_limbs(self) -> [u64; 4] {
+        let abs = if self.high < 0 {
+            self.wrapping_neg()
+        } else {
+            self
+        };
+        let high = abs.high as u128;
+        [
+            abs.low as u64,
+            (abs.low >> 64) as u64,
+            high as u64,
+            (high >> 64) as u64,
+        ]
+    }
+
+    #[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+    fn from_limbs(limbs: [u64; 4]) -> Self {
+        Self {
+            low: u128::from(limbs[0]) | (u128::from(limbs[1]) << 64),
+            high: (u128::from(limbs[2]) | (u128::from(limbs[3]) << 64)) as i128,
+        }
+    }
+
+    /// The value with the given sign and absolute value, `None` if it does
+    /// not fit
+    #[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+    fn from_sign_and_limbs(negative: bool, limbs: [u64; 4]) -> Option<Self> {
+        if limbs[3] >> 63 != 0 {
+            return None;
+        }
+        let abs = Self::from_limbs(limbs);
+        Some(if negative { abs.wrapping_neg() } else { abs })
+    }
+
+    /// Convert a float truncated towards zero, `None` if it is not finite or
+    /// does not fit
+    #[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+    pub fn from_f64(value: f64) -> Option<Self> {
+        const LIMB: f64 = 18_446_744_073_709_551_616.0;
+
+        let mut rest = value.abs().trunc();
+        if !rest.is_finite() {
+            return None;
+        }
+        // all operations are exact, as the divisor is a power of two
+        let mut limbs = [0_u64; 4];
+        for limb in limbs.iter_mut() {
+            *limb = (rest % LIMB) as u64;
+            rest = (rest / LIMB).trunc();
+        }
+        if rest != 0.0 {
+            return None;
+        }
+        Self::from_sign_and_limbs(value < 0.0, limbs)
+    }
+}
+
+impl Display for I256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+        let mut limbs = self.unsigned_abs_limbs();
+        // 2^256 has 78 decimal digits, i.e., at most 5 chunks of 19 digits
+        let mut chunks = [0_u64; 5];
+        let mut num_chunks = 0;
+        loop {
+            chunks[num_chunks] = limbs_div_rem(&mut limbs, CHUNK);
+            num_chunks += 1;
+            if limbs == [0; 4] {
+                break;
+            }
+        }
+
+        if self.high < 0 {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks[num_chunks - 1])?;
+        for chunk in chunks[..num_chunks - 1].iter().rev() {
+            write!(f, "{chunk:019}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compute `limbs * mul + add` in place, return whether the result overflowed
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+fn limbs_mul_add(limbs: &mut [u64; 4], mul: u64, add: u64) -> bool {
+    let mut carry = u128::from(add);
+    for limb in limbs.iter_mut() {
+        let val = u128::from(*limb) * u128::from(mul) + carry;
+        *limb = val as u64;
+        carry = val >> 64;
+    }
+    carry != 0
+}
+
+/// Compute `limbs / div` in place and return the remainder
+fn limbs_div_rem(limbs: &mut [u64; 4], div: u64) -> u64 {
+    let mut rem = 0_u128;
+    for limb in limbs.iter_mut().rev() {
+        let val = (rem << 64) | u128::from(*limb);
+        *limb = (val / u128::from(div)) as u64;
+        rem = val % u128::from(div);
+    }
+    rem as u64
+}
+
 /// Helper to parse decimals
 ///
 /// This enum maps the tree major cases:
@@ -16,6 +194,7 @@ pub const BUFFER_SIZE_I128: usize = 64;
 /// - fraction only: `------.---XXX---`
 /// - mixed: `-----XXX.XXX----`
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
 pub enum DecimalParser {
     IntegerOnly(usize, usize),
     IntegerOnlyTruncated(usize, usize),
@@ -25,6 +204,7 @@ pub enum DecimalParser {
     FractionOnlyTruncated(usize, usize),
 }
 
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
 impl DecimalParser {
     pub fn new(precision: u8, scale: i8, truncated: bool) -> Self {
         if scale <= 0 && !truncated {
@@ -49,6 +229,33 @@ impl DecimalParser {
         Ok(val)
     }
 
+    pub fn parse_decimal256(self, buffer: &mut [u8], s: &[u8]) -> Result<I256> {
+        let (s, sign) = parse_sign(s);
+        let digits = self.copy_digits(buffer, s)?;
+        if digits.is_empty() {
+            fail!("Invalid decimal: missing digits");
+        }
+
+        let mut limbs = [0_u64; 4];
+        for chunk in digits.as_bytes().chunks(18) {
+            let mut mul = 1_u64;
+            let mut add = 0_u64;
+            for digit in chunk {
+                mul *= 10;
+                add = add * 10 + u64::from(*digit - b'0');
+            }
+            if limbs_mul_add(&mut limbs, mul, add) || limbs[3] >> 63 != 0 {
+                fail!("Invalid decimal: the value does not fit into 256 bits");
+            }
+        }
+
+        let val = I256::from_limbs(limbs);
+        Ok(match sign {
+            Sign::Minus => val.wrapping_neg(),
+            _ => val,
+        })
+    }
+
     pub fn copy_digits<'b>(self, buffer: &'b mut [u8], s: &[u8]) -> Result<&'b str> {
         use DecimalParser::*;
         match self {
@@ -72,6 +279,202 @@ impl DecimalParser {
     }
 }
 
+/// Determine the minimal precision and scale to represent `s` as a decimal
+///
+/// Only strings of the form `[+-]digits.digits` are recognized. Leading zeros
+/// of the integer part do not count towards the precision, trailing zeros of
+/// the fractional part do.
+pub fn infer_precision_and_scale(s: &str) -> Option<(u8, i8)> {
+    let (s, _) = parse_sign(s.as_bytes());
+    let (before_period, after_period) = find_period(s);
+    if before_period == s.len() {
+        return None;
+    }
+
+    let integer = &s[..before_period];
+    let fraction = &s[after_period..];
+    if integer.is_empty() || fraction.is_empty() {
+        return None;
+    }
+    if check_all_ascii_digit(integer).is_err() || check_all_ascii_digit(fraction).is_err() {
+        return None;
+    }
+
+    let integer_digits = integer.iter().skip_while(|c| **c == b'0').count();
+    let scale = i8::try_from(fraction.len()).ok()?;
+    let precision = u8::try_from(integer_digits + fraction.len()).ok()?;
+    Some((precision.max(1), scale))
+}
+
+/// Determine the minimal precision and scale to represent values of both
+/// decimal types
+pub fn merge_precision_and_scale(a: (u8, i8), b: (u8, i8)) -> Option<(u8, i8)> {
+    let integer_digits = i16::max(a.0 as i16 - a.1 as i16, b.0 as i16 - b.1 as i16);
+    let scale = i8::max(a.1, b.1);
+    let precision = u8::try_from(integer_digits + scale as i16).ok()?;
+    Some((precision.max(1), scale))
+}
+
+/// Rescale an unscaled decimal value from one scale to another
+///
+/// Fails if the result overflows or if digits would be lost.
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub fn rescale(value: i128, from_scale: i8, to_scale: i8) -> Result<i128> {
+    let diff = i32::from(to_scale) - i32::from(from_scale);
+    let factor = 10_i128.checked_pow(diff.unsigned_abs());
+
+    if diff >= 0 {
+        match factor.and_then(|factor| value.checked_mul(factor)) {
+            Some(res) => Ok(res),
+            None if value == 0 => Ok(0),
+            None => {
+                fail!("Overflow when rescaling {value} from scale {from_scale} to scale {to_scale}")
+            }
+        }
+    } else {
+        match factor {
+            Some(factor) if value % factor == 0 => Ok(value / factor),
+            None if value == 0 => Ok(0),
+            _ => fail!(
+                "Cannot rescale {value} from scale {from_scale} to scale {to_scale} without losing digits"
+            ),
+        }
+    }
+}
+
+/// Rescale a 256 bit value, see [`rescale`]
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub fn rescale_i256(value: I256, from_scale: i8, to_scale: i8) -> Result<I256> {
+    let diff = i32::from(to_scale) - i32::from(from_scale);
+    let mut limbs = value.unsigned_abs_limbs();
+    if limbs == [0; 4] {
+        return Ok(value);
+    }
+
+    if diff >= 0 {
+        for _ in 0..diff {
+            if limbs_mul_add(&mut limbs, 10, 0) {
+                fail!(
+                    "Overflow when rescaling {value} from scale {from_scale} to scale {to_scale}"
+                );
+            }
+        }
+    } else {
+        for _ in 0..diff.unsigned_abs() {
+            if limbs_div_rem(&mut limbs, 10) != 0 {
+                fail!("Cannot rescale {value} from scale {from_scale} to scale {to_scale} without losing digits");
+            }
+        }
+    }
+    match I256::from_sign_and_limbs(value.high < 0, limbs) {
+        Some(res) => Ok(res),
+        None => {
+            fail!("Overflow when rescaling {value} from scale {from_scale} to scale {to_scale}")
+        }
+    }
+}
+
+/// Check that the unscaled 256 bit value has at most `precision` digits
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub fn check_precision_i256(value: I256, precision: u8) -> Result<()> {
+    let mut bound = [1, 0, 0, 0];
+    for _ in 0..precision {
+        if limbs_mul_add(&mut bound, 10, 0) {
+            // 10^precision exceeds the range of 256 bit integers
+            return Ok(());
+        }
+    }
+    // compare the limbs starting with the most significant one
+    let abs = value.unsigned_abs_limbs();
+    if !abs.iter().rev().lt(bound.iter().rev()) {
+        fail!("Value {value} does not fit into a decimal with precision {precision}");
+    }
+    Ok(())
+}
+
+/// Check that the unscaled value has at most `precision` digits
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub fn check_precision(value: i128, precision: u8) -> Result<()> {
+    let fits = match 10_i128.checked_pow(u32::from(precision)) {
+        Some(bound) => value.unsigned_abs() < bound.unsigned_abs(),
+        None => true,
+    };
+    if !fits {
+        fail!("Value {value} does not fit into a decimal with precision {precision}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_rescale() {
+    assert_eq!(rescale(1234, 2, 2).unwrap(), 1234);
+    assert_eq!(rescale(1234, 2, 4).unwrap(), 123400);
+    assert_eq!(rescale(-1200, 2, 0).unwrap(), -12);
+    assert!(rescale(12, 0, -1).is_err());
+    assert_eq!(rescale(0, 0, 100).unwrap(), 0);
+    assert!(rescale(1, 0, 40).is_err());
+    assert!(rescale(i128::MAX, 0, 1).is_err());
+}
+
+#[test]
+fn test_check_precision() {
+    assert!(check_precision(99_999, 5).is_ok());
+    assert!(check_precision(-99_999, 5).is_ok());
+    assert!(check_precision(100_000, 5).is_err());
+    assert!(check_precision(-100_000, 5).is_err());
+    assert!(check_precision(i128::MAX, 38).is_err());
+}
+
+#[test]
+fn test_rescale_i256() {
+    let value = I256::from_i128(-1234);
+    assert_eq!(rescale_i256(value, 2, 4).unwrap(), I256::from_i128(-123400));
+    assert_eq!(
+        rescale_i256(value, 2, 1).unwrap_err().message(),
+        "Cannot rescale -1234 from scale 2 to scale 1 without losing digits"
+    );
+    assert_eq!(
+        rescale_i256(I256::from_i128(i128::MAX), 0, 2)
+            .unwrap()
+            .to_string(),
+        format!("{}00", i128::MAX),
+    );
+    assert_eq!(
+        rescale_i256(I256::from_i128(1), 0, 76).unwrap().to_string(),
+        format!("1{}", "0".repeat(76)),
+    );
+    assert!(rescale_i256(I256::from_i128(1), 0, 77).is_err());
+    assert_eq!(
+        rescale_i256(I256::from_i128(0), 0, 100).unwrap(),
+        I256::from_i128(0)
+    );
+}
+
+#[test]
+fn test_check_precision_i256() {
+    let large = rescale_i256(I256::from_i128(-99_999), 0, 40).unwrap();
+    assert!(check_precision_i256(large, 45).is_ok());
+    assert!(check_precision_i256(large, 44).is_err());
+    assert!(check_precision_i256(I256::from_i128(i128::MAX), 39).is_ok());
+    assert!(check_precision_i256(I256::from_i128(i128::MAX), 38).is_err());
+}
+
+#[test]
+fn test_i256_from_f64() {
+    assert_eq!(I256::from_f64(-12.7), Some(I256::from_i128(-12)));
+    assert_eq!(
+        I256::from_f64(1e40).unwrap().to_string(),
+        "10000000000000000303786028427003666890752",
+    );
+    assert_eq!(
+        I256::from_f64(-1e40).unwrap().to_string(),
+        "-10000000000000000303786028427003666890752",
+    );
+    assert_eq!(I256::from_f64(1e77), None);
+    assert_eq!(I256::from_f64(f64::NAN), None);
+    assert_eq!(I256::from_f64(f64::INFINITY), None);
+}
+
 fn parse_sign(s: &[u8]) -> (&[u8], Sign) {
     match s.first() {
         Some(b'+') => (&s[1..], Sign::Plus),
@@ -88,6 +491,7 @@ enum Sign {
 }
 
 impl Sign {
+    #[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
     fn apply_i128(self, val: i128) -> i128 {
         match self {
             Self::Minus => -val,
@@ -96,6 +500,7 @@ impl Sign {
     }
 }
 
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
 fn copy_digits_integer_only<'b>(
     buffer: &'b mut [u8],
     s: &[u8],
@@ -122,6 +527,7 @@ fn copy_digits_integer_only<'b>(
     Ok(res)
 }
 
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
 fn copy_digits_fraction_only<'b>(
     buffer: &'b mut [u8],
     s: &[u8],
@@ -153,6 +559,7 @@ fn copy_digits_fraction_only<'b>(
     Ok(res)
 }
 
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
 fn copy_digits_mixed<'b>(
     buffer: &'b mut [u8],
     s: &[u8],
@@ -195,6 +602,7 @@ fn find_period(s: &[u8]) -> (usize, usize) {
     }
 }
 
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
 fn check_all_ascii_zero(s: &[u8], leading: bool) -> Result<()> {
     if s.iter().any(|c| *c != b'0') {
         if leading {
@@ -355,8 +763,9 @@ fn test_copy_digits() {
     assert_eq!(copy_digits_str("42.00", 4, 2).unwrap(), "4200");
 }
 
-pub fn format_decimal(buffer: &mut [u8], val: i128, scale: i8) -> &str {
-    fn write_val(buffer: &mut [u8], val: i128) -> usize {
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub fn format_decimal<V: Display>(buffer: &mut [u8], val: V, scale: i8) -> &str {
+    fn write_val<V: Display>(buffer: &mut [u8], val: V) -> usize {
         use std::io::Write;
 
         let initial_length = buffer.len();
@@ -366,21 +775,19 @@ pub fn format_decimal(buffer: &mut [u8], val: i128, scale: i8) -> &str {
         initial_length - buffer.len()
     }
 
+    let num_bytes_written = write_val(buffer, val);
     let res = if scale == 0 {
-        let num_bytes_written = write_val(buffer, val);
         &buffer[..num_bytes_written]
-    } else if scale < 0 && val == 0 {
+    } else if scale < 0 && &buffer[..num_bytes_written] == b"0" {
         b"0"
     } else if scale < 0 {
-        let scale = -scale as usize;
-        let num_bytes_written = write_val(buffer, val);
+        let scale = usize::from(scale.unsigned_abs());
 
         buffer[num_bytes_written..][..scale].fill(b'0');
         &buffer[..num_bytes_written + scale]
     } else {
         let scale = scale as usize;
-        let num_bytes_written = write_val(buffer, val);
-        let num_sign_bytes = if val >= 0 { 0 } else { 1 };
+        let num_sign_bytes = if buffer[0] == b'-' { 1 } else { 0 };
         let num_digits_written = num_bytes_written - num_sign_bytes;
 
         if num_digits_written <= scale {
@@ -409,6 +816,59 @@ pub fn format_decimal(buffer: &mut [u8], val: i128, scale: i8) -> &str {
     std::str::from_utf8(res).unwrap()
 }
 
+/// Format the decimal without trailing zeros in the fractional part
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub fn format_decimal_normalized<V: Display>(buffer: &mut [u8], val: V, scale: i8) -> &str {
+    let formatted = format_decimal(buffer, val, scale);
+    if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        formatted
+    }
+}
+
+/// Format the decimal in scientific notation, e.g., `1.23e-2`
+///
+/// The mantissa does not include trailing zeros, zero is formatted as `0e0`.
+#[cfg_attr(not(feature = "types-decimal"), allow(dead_code))]
+pub fn format_decimal_scientific<V: Display>(buffer: &mut [u8], val: V, scale: i8) -> &str {
+    use std::io::Write;
+
+    let mut digits = [0; BUFFER_SIZE_I256];
+    let num_bytes = {
+        let mut digits = &mut digits[..];
+        write!(digits, "{val}").unwrap();
+        BUFFER_SIZE_I256 - digits.len()
+    };
+    // safety only ASCII characters used -> conversion into str is safe
+    let digits = std::str::from_utf8(&digits[..num_bytes]).unwrap();
+    let (is_negative, digits) = match digits.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, digits),
+    };
+    let num_digits = digits.len();
+
+    let num_bytes_written = {
+        let initial_length = buffer.len();
+        let mut out = &mut *buffer;
+        if digits == "0" {
+            write!(out, "0e0").unwrap();
+        } else {
+            let exponent = num_digits as i32 - 1 - scale as i32;
+            let sign = if is_negative { "-" } else { "" };
+            let (head, tail) = digits.trim_end_matches('0').split_at(1);
+            if tail.is_empty() {
+                write!(out, "{sign}{head}e{exponent}").unwrap();
+            } else {
+                write!(out, "{sign}{head}.{tail}e{exponent}").unwrap();
+            }
+        }
+        initial_length - out.len()
+    };
+
+    std::str::from_utf8(&buffer[..num_bytes_written]).unwrap()
+}
+
 #[test]
 fn test_format_decimal() {
     fn format_decimal_str(val: i128, scale: i8) -> String {
@@ -449,3 +909,151 @@ fn test_format_decimal() {
 
     assert_eq!(format_decimal_str(12345, 3), "12.345");
 }
+
+#[test]
+fn test_format_decimal_extreme_scales() {
+    let mut buffer = [0; FORMAT_BUFFER_SIZE_I256];
+    let formatted = format_decimal(&mut buffer, i128::MIN, -128);
+    assert_eq!(formatted.len(), 40 + 128);
+
+    let mut buffer = [0; FORMAT_BUFFER_SIZE_I256];
+    let formatted = format_decimal(&mut buffer, I256::from_limbs([0, 0, 0, 1 << 63]), -128);
+    assert_eq!(formatted.len(), FORMAT_BUFFER_SIZE_I256);
+
+    let mut buffer = [0; FORMAT_BUFFER_SIZE_I256];
+    let formatted = format_decimal(&mut buffer, -1, 127);
+    assert_eq!(formatted.len(), 1 + 2 + 127);
+
+    let mut buffer = [0; FORMAT_BUFFER_SIZE_I256];
+    let formatted = format_decimal_scientific(&mut buffer, i128::MIN, -128);
+    assert!(formatted.ends_with("e166"));
+}
+
+#[test]
+fn test_format_decimal_normalized() {
+    fn format(val: i128, scale: i8) -> String {
+        let mut buffer = [0; BUFFER_SIZE_I128];
+        format_decimal_normalized(&mut buffer, val, scale).to_owned()
+    }
+
+    assert_eq!(format(0, 2), "0");
+    assert_eq!(format(100, 2), "1");
+    assert_eq!(format(120, 2), "1.2");
+    assert_eq!(format(-123, 2), "-1.23");
+    assert_eq!(format(-10, 3), "-0.01");
+    assert_eq!(format(1200, 0), "1200");
+    assert_eq!(format(12, -2), "1200");
+}
+
+#[test]
+fn test_format_decimal_scientific() {
+    fn format(val: i128, scale: i8) -> String {
+        let mut buffer = [0; BUFFER_SIZE_I128];
+        format_decimal_scientific(&mut buffer, val, scale).to_owned()
+    }
+
+    assert_eq!(format(0, 2), "0e0");
+    assert_eq!(format(1, 0), "1e0");
+    assert_eq!(format(100, 2), "1e0");
+    assert_eq!(format(12300, 2), "1.23e2");
+    assert_eq!(format(-123, 4), "-1.23e-2");
+    assert_eq!(format(12, -2), "1.2e3");
+    assert_eq!(
+        format(i128::MIN, 0),
+        "-1.70141183460469231731687303715884105728e38"
+    );
+}
+
+#[test]
+fn test_infer_precision_and_scale() {
+    assert_eq!(infer_precision_and_scale("1.23"), Some((3, 2)));
+    assert_eq!(infer_precision_and_scale("-1.23"), Some((3, 2)));
+    assert_eq!(infer_precision_and_scale("+10.5"), Some((3, 1)));
+    assert_eq!(infer_precision_and_scale("0.50"), Some((2, 2)));
+    assert_eq!(infer_precision_and_scale("007.0"), Some((2, 1)));
+    assert_eq!(infer_precision_and_scale("0.0"), Some((1, 1)));
+
+    assert_eq!(infer_precision_and_scale("123"), None);
+    assert_eq!(infer_precision_and_scale("1."), None);
+    assert_eq!(infer_precision_and_scale(".5"), None);
+    assert_eq!(infer_precision_and_scale("1.2.3"), None);
+    assert_eq!(infer_precision_and_scale("1e5"), None);
+    assert_eq!(infer_precision_and_scale("foo"), None);
+    assert_eq!(infer_precision_and_scale(""), None);
+}
+
+#[test]
+fn test_merge_precision_and_scale() {
+    assert_eq!(merge_precision_and_scale((3, 2), (3, 2)), Some((3, 2)));
+    assert_eq!(merge_precision_and_scale((3, 2), (5, 1)), Some((6, 2)));
+    assert_eq!(merge_precision_and_scale((2, 2), (3, 0)), Some((5, 2)));
+}
+
+#[test]
+fn test_i256_display() {
+    assert_eq!(I256::from_i128(0).to_string(), "0");
+    assert_eq!(I256::from_i128(-42).to_string(), "-42");
+    assert_eq!(
+        I256::from_i128(i128::MAX).to_string(),
+        i128::MAX.to_string()
+    );
+    assert_eq!(
+        I256::from_i128(i128::MIN).to_string(),
+        i128::MIN.to_string()
+    );
+    assert_eq!(
+        I256::from_limbs([0, 0, 0, 1 << 62]).to_string(),
+        "28948022309329048855892746252171976963317496166410141009864396001978282409984",
+    );
+}
+
+#[test]
+fn test_parse_decimal256() {
+    fn parse(s: &str, precision: u8, scale: i8) -> Result<String> {
+        let mut buffer = [0; BUFFER_SIZE_I256];
+        let val = DecimalParser::new(precision, scale, false)
+            .parse_decimal256(&mut buffer, s.as_bytes())?;
+        Ok(val.to_string())
+    }
+
+    assert_eq!(parse("0", 5, 0).unwrap(), "0");
+    assert_eq!(parse("-42.50", 10, 2).unwrap(), "-4250");
+    assert_eq!(parse("+13.1", 10, 1).unwrap(), "131");
+
+    let max = "9".repeat(76);
+    assert_eq!(parse(&max, 76, 0).unwrap(), max);
+    assert_eq!(parse(&format!("-{max}"), 76, 0).unwrap(), format!("-{max}"));
+    assert_eq!(
+        parse("123456789012345678901234567890.123456789", 76, 10).unwrap(),
+        "1234567890123456789012345678901234567890",
+    );
+
+    assert!(parse("", 5, 0).is_err());
+    assert!(parse("-", 5, 0).is_err());
+    assert!(parse("123", 2, 0).is_err());
+}
+
+#[test]
+fn test_i256_to_i128() {
+    assert_eq!(I256::from_i128(-5).to_i128().unwrap(), -5);
+    assert_eq!(I256::from_i128(i128::MIN).to_i128().unwrap(), i128::MIN);
+    assert!(I256::from_limbs([0, 0, 1, 0]).to_i128().is_err());
+}
+
+#[test]
+fn test_format_decimal256() {
+    let mut buffer = [0; FORMAT_BUFFER_SIZE_I256];
+    let val = I256::from_limbs([0, 0, 0, 1 << 62]);
+    assert_eq!(
+        format_decimal(&mut buffer, val, 70),
+        "2894802.2309329048855892746252171976963317496166410141009864396001978282409984",
+    );
+    assert_eq!(
+        format_decimal(&mut buffer, I256::from_i128(-1234), 2),
+        "-12.34"
+    );
+    assert_eq!(
+        format_decimal_scientific(&mut buffer, I256::from_i128(-1200), 2),
+        "-1.2e1"
+    );
+}
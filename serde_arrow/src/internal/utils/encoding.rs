@@ -0,0 +1,63 @@
+//! Text encodings of binary data
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+/// Encode the bytes as base64 with the standard alphabet and padding (RFC 4648)
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut res = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied().unwrap_or_default();
+        let b2 = chunk.get(2).copied().unwrap_or_default();
+
+        res.push(BASE64_ALPHABET[usize::from(b0 >> 2)] as char);
+        res.push(BASE64_ALPHABET[usize::from(((b0 & 0b11) << 4) | (b1 >> 4))] as char);
+        if chunk.len() > 1 {
+            res.push(BASE64_ALPHABET[usize::from(((b1 & 0b1111) << 2) | (b2 >> 6))] as char);
+        } else {
+            res.push('=');
+        }
+        if chunk.len() > 2 {
+            res.push(BASE64_ALPHABET[usize::from(b2 & 0b11_1111)] as char);
+        } else {
+            res.push('=');
+        }
+    }
+    res
+}
+
+/// Encode the bytes as lowercase hex digits
+pub fn hex_encode(data: &[u8]) -> String {
+    let mut res = String::with_capacity(2 * data.len());
+    for byte in data {
+        res.push(HEX_ALPHABET[usize::from(byte >> 4)] as char);
+        res.push(HEX_ALPHABET[usize::from(byte & 0b1111)] as char);
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::{base64_encode, hex_encode};
+
+    #[test]
+    fn base64_rfc4648_test_vectors() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foob"), "Zm9vYg==");
+        assert_eq!(base64_encode(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64_encode(&[0xff, 0xfe, 0x00]), "//4A");
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(hex_encode(b""), "");
+        assert_eq!(hex_encode(&[0x00, 0x0f, 0xa5, 0xff]), "000fa5ff");
+    }
+}
@@ -2,17 +2,20 @@ pub mod array_ext;
 pub mod array_view_ext;
 pub mod decimal;
 pub mod dsl;
+pub mod encoding;
+pub mod quantization;
 pub mod value;
+pub mod xxhash;
 
 #[cfg(test)]
 mod test_value;
 
 use half::f16;
-use serde::{ser::SerializeSeq, Deserialize, Serialize};
+use serde::{de::Visitor, ser::SerializeSeq, Deserialize, Serialize};
 
-use crate::internal::error::Result;
+use crate::internal::error::{fail, Result};
 
-use super::arrow::{Field, FieldMeta};
+use super::arrow::{DayTimeInterval, Field, FieldMeta, MonthDayNanoInterval};
 
 /// A wrapper around a sequence of items
 ///
@@ -111,7 +114,7 @@ impl<T: Serialize> Serialize for Items<Vec<T>> {
     }
 }
 
-impl<'a, T: Serialize> Serialize for Items<&'a Vec<T>> {
+impl<T: Serialize> Serialize for Items<&Vec<T>> {
     fn serialize<S: serde::Serializer>(
         &self,
         serializer: S,
@@ -129,7 +132,7 @@ impl<const N: usize, T: Serialize> Serialize for Items<[T; N]> {
     }
 }
 
-impl<'a, const N: usize, T: Serialize> Serialize for Items<&'a [T; N]> {
+impl<const N: usize, T: Serialize> Serialize for Items<&[T; N]> {
     fn serialize<S: serde::Serializer>(
         &self,
         serializer: S,
@@ -138,7 +141,7 @@ impl<'a, const N: usize, T: Serialize> Serialize for Items<&'a [T; N]> {
     }
 }
 
-impl<'a, T: Serialize> Serialize for Items<&'a [T]> {
+impl<T: Serialize> Serialize for Items<&[T]> {
     fn serialize<S: serde::Serializer>(
         &self,
         serializer: S,
@@ -196,6 +199,77 @@ impl Offset for i64 {
     }
 }
 
+/// A trait to handle the different interval types via their integer components
+#[cfg_attr(not(feature = "types-temporal"), allow(dead_code))]
+pub trait IntervalValue: Clone + Copy + Default + 'static {
+    /// The data type used in error messages
+    const DATA_TYPE: &'static str;
+    /// The names of the components in the order of the arrow layout
+    const COMPONENTS: &'static [&'static str];
+
+    fn from_components(components: &[i64]) -> Result<Self>;
+    fn to_components(self) -> Vec<i64>;
+}
+
+impl IntervalValue for i32 {
+    const DATA_TYPE: &'static str = "Interval(YearMonth)";
+    const COMPONENTS: &'static [&'static str] = &["months"];
+
+    fn from_components(components: &[i64]) -> Result<Self> {
+        let [months] = components else {
+            fail!("Invalid number of components for {}", Self::DATA_TYPE);
+        };
+        Ok(i32::try_from(*months)?)
+    }
+
+    fn to_components(self) -> Vec<i64> {
+        vec![i64::from(self)]
+    }
+}
+
+impl IntervalValue for DayTimeInterval {
+    const DATA_TYPE: &'static str = "Interval(DayTime)";
+    const COMPONENTS: &'static [&'static str] = &["days", "milliseconds"];
+
+    fn from_components(components: &[i64]) -> Result<Self> {
+        let [days, milliseconds] = components else {
+            fail!("Invalid number of components for {}", Self::DATA_TYPE);
+        };
+        Ok(Self {
+            days: i32::try_from(*days)?,
+            milliseconds: i32::try_from(*milliseconds)?,
+        })
+    }
+
+    fn to_components(self) -> Vec<i64> {
+        vec![i64::from(self.days), i64::from(self.milliseconds)]
+    }
+}
+
+impl IntervalValue for MonthDayNanoInterval {
+    const DATA_TYPE: &'static str = "Interval(MonthDayNano)";
+    const COMPONENTS: &'static [&'static str] = &["months", "days", "nanoseconds"];
+
+    fn from_components(components: &[i64]) -> Result<Self> {
+        let [months, days, nanoseconds] = components else {
+            fail!("Invalid number of components for {}", Self::DATA_TYPE);
+        };
+        Ok(Self {
+            months: i32::try_from(*months)?,
+            days: i32::try_from(*days)?,
+            nanoseconds: *nanoseconds,
+        })
+    }
+
+    fn to_components(self) -> Vec<i64> {
+        vec![
+            i64::from(self.months),
+            i64::from(self.days),
+            self.nanoseconds,
+        ]
+    }
+}
+
 pub fn meta_from_field(field: Field) -> FieldMeta {
     FieldMeta {
         name: field.name,
@@ -230,3 +304,20 @@ impl<'a> std::fmt::Display for ChildName<'a> {
         }
     }
 }
+
+/// Check whether the visitor expects a non-zero integer, e.g.,
+/// `std::num::NonZeroU32`
+///
+/// Serde does not expose the target type, therefore the check relies on the
+/// `expecting` message of the visitor (`"a nonzero u32"`).
+pub fn expects_non_zero<'de, V: Visitor<'de>>(visitor: &V) -> bool {
+    struct Expecting<'a, V>(&'a V);
+
+    impl<'a, 'de, V: Visitor<'de>> std::fmt::Display for Expecting<'a, V> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.expecting(f)
+        }
+    }
+
+    Expecting(visitor).to_string().starts_with("a nonzero")
+}
@@ -0,0 +1,57 @@
+//! Linear quantization of floats into 8 bit integers, see
+//! [`Strategy::Quantized`][crate::schema::Strategy::Quantized]
+use crate::internal::{
+    arrow::DataType,
+    error::{fail, Result},
+    schema::DataTypeDisplay,
+};
+
+/// The parameters of a quantized field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantization {
+    pub scale: f32,
+    pub zero_point: i32,
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Quantization {
+    pub fn new(scale: f32, zero_point: i32, data_type: &DataType) -> Result<Self> {
+        let (min, max) = match data_type {
+            DataType::UInt8 => (i64::from(u8::MIN), i64::from(u8::MAX)),
+            DataType::Int8 => (i64::from(i8::MIN), i64::from(i8::MAX)),
+            data_type => fail!(
+                "The Quantized strategy is not supported for {data_type}",
+                data_type = DataTypeDisplay(data_type),
+            ),
+        };
+        if !scale.is_finite() || scale <= 0.0 {
+            fail!("Invalid scale {scale} of the Quantized strategy, it must be positive");
+        }
+        if !(min..=max).contains(&i64::from(zero_point)) {
+            fail!(
+                "Invalid zero point {zero_point} of the Quantized strategy for {data_type}, it must be in [{min}, {max}]",
+                data_type = DataTypeDisplay(data_type),
+            );
+        }
+        Ok(Self {
+            scale,
+            zero_point,
+            min,
+            max,
+        })
+    }
+
+    /// Map the value to the nearest integer, clamped to the range of the field
+    pub fn quantize(&self, value: f64) -> Result<i64> {
+        if value.is_nan() {
+            fail!("Cannot quantize NaN");
+        }
+        let quantized = (value / f64::from(self.scale)).round() + f64::from(self.zero_point);
+        Ok(quantized.clamp(self.min as f64, self.max as f64) as i64)
+    }
+
+    pub fn dequantize(&self, value: i64) -> f64 {
+        (value - i64::from(self.zero_point)) as f64 * f64::from(self.scale)
+    }
+}
@@ -87,6 +87,139 @@ pub fn transmute<T: DeserializeOwned>(value: impl Serialize) -> Result<T> {
     T::deserialize(ValueDeserializer::new(&value))
 }
 
+impl Serialize for Value {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{
+            SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+            SerializeTupleStruct, SerializeTupleVariant,
+        };
+
+        match self {
+            Self::Bool(v) => serializer.serialize_bool(*v),
+            Self::U8(v) => serializer.serialize_u8(*v),
+            Self::U16(v) => serializer.serialize_u16(*v),
+            Self::U32(v) => serializer.serialize_u32(*v),
+            Self::U64(v) => serializer.serialize_u64(*v),
+            Self::I8(v) => serializer.serialize_i8(*v),
+            Self::I16(v) => serializer.serialize_i16(*v),
+            Self::I32(v) => serializer.serialize_i32(*v),
+            Self::I64(v) => serializer.serialize_i64(*v),
+            Self::F32(v) => serializer.serialize_f32(v.0),
+            Self::F64(v) => serializer.serialize_f64(v.0),
+            Self::StaticStr(v) => serializer.serialize_str(v),
+            Self::String(v) => serializer.serialize_str(v),
+            Self::Char(v) => serializer.serialize_char(*v),
+            Self::Bytes(v) => serializer.serialize_bytes(v),
+            Self::None => serializer.serialize_none(),
+            Self::Some(v) => serializer.serialize_some(v.as_ref()),
+            Self::Unit => serializer.serialize_unit(),
+            Self::Tuple(values) => {
+                let mut s = serializer.serialize_tuple(values.len())?;
+                for value in values {
+                    s.serialize_element(value)?;
+                }
+                s.end()
+            }
+            Self::Seq(values) => {
+                let mut s = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    s.serialize_element(value)?;
+                }
+                s.end()
+            }
+            Self::Map(entries) => {
+                let mut s = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    s.serialize_entry(key, value)?;
+                }
+                s.end()
+            }
+            Self::Struct(name, fields) => {
+                let mut s = serializer.serialize_struct(name, fields.len())?;
+                for (key, value) in fields {
+                    s.serialize_field(key, value)?;
+                }
+                s.end()
+            }
+            Self::NewtypeStruct(name, value) => {
+                serializer.serialize_newtype_struct(name, value.as_ref())
+            }
+            Self::UnitStruct(name) => serializer.serialize_unit_struct(name),
+            Self::TupleStruct(name, values) => {
+                let mut s = serializer.serialize_tuple_struct(name, values.len())?;
+                for value in values {
+                    s.serialize_field(value)?;
+                }
+                s.end()
+            }
+            Self::StructVariant(Variant(idx, name), fields) => {
+                let mut s = serializer.serialize_struct_variant("", *idx, name, fields.len())?;
+                for (key, value) in fields {
+                    s.serialize_field(key, value)?;
+                }
+                s.end()
+            }
+            Self::TupleVariant(Variant(idx, name), values) => {
+                let mut s = serializer.serialize_tuple_variant("", *idx, name, values.len())?;
+                for value in values {
+                    s.serialize_field(value)?;
+                }
+                s.end()
+            }
+            Self::UnitVariant(Variant(idx, name)) => {
+                serializer.serialize_unit_variant("", *idx, name)
+            }
+            Self::NewtypeVariant(Variant(idx, name), value) => {
+                serializer.serialize_newtype_variant("", *idx, name, value.as_ref())
+            }
+        }
+    }
+}
+
+/// Compare two values used as map keys
+///
+/// Integers are compared by value independent of their type, strings by their
+/// bytes and unit variants by their index. Other keys cannot be compared.
+pub fn compare_keys(a: &Value, b: &Value) -> Result<std::cmp::Ordering> {
+    use Value as V;
+
+    fn as_int(value: &Value) -> Option<i128> {
+        match *value {
+            V::U8(v) => Some(v.into()),
+            V::U16(v) => Some(v.into()),
+            V::U32(v) => Some(v.into()),
+            V::U64(v) => Some(v.into()),
+            V::I8(v) => Some(v.into()),
+            V::I16(v) => Some(v.into()),
+            V::I32(v) => Some(v.into()),
+            V::I64(v) => Some(v.into()),
+            _ => None,
+        }
+    }
+
+    fn unwrap(value: &Value) -> &Value {
+        match value {
+            V::Some(inner) | V::NewtypeStruct(_, inner) => unwrap(inner),
+            value => value,
+        }
+    }
+
+    let (a, b) = (unwrap(a), unwrap(b));
+    if let (Some(a), Some(b)) = (as_int(a), as_int(b)) {
+        return Ok(a.cmp(&b));
+    }
+    if let (Ok(a), Ok(b)) = (<&str>::try_from(a), <&str>::try_from(b)) {
+        return Ok(a.cmp(b));
+    }
+    match (a, b) {
+        (V::Bool(a), V::Bool(b)) => Ok(a.cmp(b)),
+        (V::Char(a), V::Char(b)) => Ok(a.cmp(b)),
+        (V::Bytes(a), V::Bytes(b)) => Ok(a.cmp(b)),
+        (V::UnitVariant(Variant(a, _)), V::UnitVariant(Variant(b, _))) => Ok(a.cmp(b)),
+        (a, b) => fail!("Cannot compare map keys {a:?} and {b:?}"),
+    }
+}
+
 impl<'a> TryFrom<&'a Value> for &'a str {
     type Error = Error;
 
@@ -794,6 +927,10 @@ impl<'a> StructDeserializer<'a> {
 impl<'de, 'a> serde::de::MapAccess<'de> for StructDeserializer<'a> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+
     fn next_key_seed<K: serde::de::DeserializeSeed<'de>>(
         &mut self,
         seed: K,
@@ -828,6 +965,10 @@ impl<'a> SeqDeserializer<'a> {
 impl<'de, 'a> serde::de::SeqAccess<'de> for SeqDeserializer<'a> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+
     fn next_element_seed<T: serde::de::DeserializeSeed<'de>>(
         &mut self,
         seed: T,
@@ -852,6 +993,10 @@ impl<'a> MapDeserializer<'a> {
 impl<'de, 'a> serde::de::MapAccess<'de> for MapDeserializer<'a> {
     type Error = Error;
 
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+
     fn next_key_seed<K: serde::de::DeserializeSeed<'de>>(
         &mut self,
         seed: K,
@@ -0,0 +1,110 @@
+//! A minimal implementation of the 64 bit variant of xxHash (XXH64)
+//!
+//! See the [specification][spec] for details. The hash is stable across
+//! platforms and versions.
+//!
+//! [spec]: https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md
+
+const PRIME64_1: u64 = 0x9E3779B185EBCA87;
+const PRIME64_2: u64 = 0xC2B2AE3D27D4EB4F;
+const PRIME64_3: u64 = 0x165667B19E3779F9;
+const PRIME64_4: u64 = 0x85EBCA77C2B2AE63;
+const PRIME64_5: u64 = 0x27D4EB2F165667C5;
+
+pub fn xxh64(data: &[u8], seed: u64) -> u64 {
+    let mut rest = data;
+
+    let mut hash = if data.len() >= 32 {
+        let mut acc = [
+            seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2),
+            seed.wrapping_add(PRIME64_2),
+            seed,
+            seed.wrapping_sub(PRIME64_1),
+        ];
+        while rest.len() >= 32 {
+            for (idx, acc) in acc.iter_mut().enumerate() {
+                *acc = round(*acc, read_u64(&rest[8 * idx..]));
+            }
+            rest = &rest[32..];
+        }
+
+        let mut hash = acc[0]
+            .rotate_left(1)
+            .wrapping_add(acc[1].rotate_left(7))
+            .wrapping_add(acc[2].rotate_left(12))
+            .wrapping_add(acc[3].rotate_left(18));
+        for acc in acc {
+            hash = merge_round(hash, acc);
+        }
+        hash
+    } else {
+        seed.wrapping_add(PRIME64_5)
+    };
+
+    hash = hash.wrapping_add(data.len() as u64);
+
+    while rest.len() >= 8 {
+        hash ^= round(0, read_u64(rest));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4);
+        rest = &rest[8..];
+    }
+
+    if rest.len() >= 4 {
+        hash ^= (read_u32(rest) as u64).wrapping_mul(PRIME64_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(PRIME64_2)
+            .wrapping_add(PRIME64_3);
+        rest = &rest[4..];
+    }
+
+    for &byte in rest {
+        hash ^= (byte as u64).wrapping_mul(PRIME64_5);
+        hash = hash.rotate_left(11).wrapping_mul(PRIME64_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME64_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME64_3);
+    hash ^= hash >> 32;
+    hash
+}
+
+fn round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+fn merge_round(acc: u64, value: u64) -> u64 {
+    (acc ^ round(0, value))
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(PRIME64_4)
+}
+
+fn read_u64(data: &[u8]) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&data[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+fn read_u32(data: &[u8]) -> u32 {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(&data[..4]);
+    u32::from_le_bytes(bytes)
+}
+
+#[test]
+fn test_xxh64() {
+    assert_eq!(xxh64(b"", 0), 0xEF46DB3751D8E999);
+    assert_eq!(xxh64(b"a", 0), 0xD24EC4F1A98C6E5B);
+    assert_eq!(xxh64(b"abc", 0), 0x44BC2CF5AD770999);
+    assert_eq!(
+        xxh64(b"Nobody inspects the spammish repetition", 0),
+        0xFBCEA83C8A378BF1
+    );
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+
+use crate::internal::{
+    deserialization::array_deserializer::ArrayDeserializer,
+    error::{fail, Result},
+    utils::Mut,
+};
+
+type Extractor<T> =
+    Box<dyn for<'a, 'de> Fn(Mut<'a, ArrayDeserializer<'de>>) -> Result<T> + Send + Sync>;
+
+/// A mapping from union variant names to fallible conversion functions
+///
+/// Variant extractors allow to deserialize union fields into types that do
+/// not implement `Deserialize` themselves, e.g., trait objects whose
+/// implementations are defined across different crates. For each variant, the
+/// value is deserialized into the registered type and then passed to the
+/// registered function. Use [`Deserializer::extract_union_field`][crate::Deserializer::extract_union_field]
+/// to apply the extractors to a union field.
+///
+/// ```rust
+/// # #[cfg(all(has_arrow, feature = "types-union"))]
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::FieldRef;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::{
+///     schema::{SchemaLike, TracingOptions},
+///     Deserializer, VariantExtractors,
+/// };
+///
+/// trait Event {
+///     fn describe(&self) -> String;
+/// }
+///
+/// ##[derive(Deserialize)]
+/// struct Click {
+///     x: i32,
+/// }
+///
+/// impl Event for Click {
+///     fn describe(&self) -> String {
+///         format!("click at {}", self.x)
+///     }
+/// }
+///
+/// ##[derive(Deserialize)]
+/// struct Scroll(f32);
+///
+/// impl Event for Scroll {
+///     fn describe(&self) -> String {
+///         format!("scroll by {}", self.0)
+///     }
+/// }
+///
+/// ##[derive(Serialize, Deserialize)]
+/// enum RawEvent {
+///     Click { x: i32 },
+///     Scroll(f32),
+/// }
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     event: RawEvent,
+/// }
+///
+/// let items = vec![
+///     Record { event: RawEvent::Click { x: 13 } },
+///     Record { event: RawEvent::Scroll(0.5) },
+/// ];
+/// let fields = Vec::<FieldRef>::from_type::<Record>(TracingOptions::default())?;
+/// let batch = serde_arrow::to_record_batch(&fields, &items)?;
+///
+/// let extractors = VariantExtractors::<Box<dyn Event>>::new()
+///     .register("Click", |click: Click| Ok(Box::new(click)))
+///     .register("Scroll", |scroll: Scroll| Ok(Box::new(scroll)));
+///
+/// let events = Deserializer::from_record_batch(&batch)?
+///     .extract_union_field("event", &extractors)?;
+/// let descriptions = events
+///     .iter()
+///     .map(|event| event.as_ref().unwrap().describe())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(descriptions, ["click at 13", "scroll by 0.5"]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(all(has_arrow, feature = "types-union")))]
+/// # fn main() {}
+/// ```
+pub struct VariantExtractors<T> {
+    extractors: HashMap<String, Extractor<T>>,
+}
+
+impl<T> VariantExtractors<T> {
+    /// Construct a mapping without any registered variants
+    pub fn new() -> Self {
+        Self {
+            extractors: HashMap::new(),
+        }
+    }
+
+    /// Register the function to convert the values of the given variant
+    ///
+    /// The value of the variant is deserialized into `V` and then passed to
+    /// `extract`. Errors returned by `extract` are forwarded to the caller.
+    /// Registering a variant twice replaces the previous function.
+    pub fn register<V, F>(mut self, variant: &str, extract: F) -> Self
+    where
+        V: DeserializeOwned,
+        F: Fn(V) -> Result<T> + Send + Sync + 'static,
+    {
+        self.extractors.insert(
+            variant.to_owned(),
+            Box::new(move |deserializer| extract(V::deserialize(deserializer)?)),
+        );
+        self
+    }
+
+    /// Check whether a function is registered for the given variant
+    pub fn contains(&self, variant: &str) -> bool {
+        self.extractors.contains_key(variant)
+    }
+
+    #[cfg_attr(not(feature = "types-union"), allow(dead_code))]
+    pub(crate) fn extract(
+        &self,
+        variant: &str,
+        deserializer: &mut ArrayDeserializer<'_>,
+    ) -> Result<T> {
+        let Some(extractor) = self.extractors.get(variant) else {
+            fail!("No extractor registered for variant {variant:?}");
+        };
+        extractor(Mut(deserializer))
+    }
+}
+
+impl<T> Default for VariantExtractors<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for VariantExtractors<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut variants = self.extractors.keys().collect::<Vec<_>>();
+        variants.sort();
+        f.debug_struct("VariantExtractors")
+            .field("variants", &variants)
+            .finish()
+    }
+}
@@ -164,6 +164,44 @@
 //! | `arrow-37`    | `arrow=37`    |
 //! | `arrow2-0-17` | `arrow2=0.17` |
 //! | `arrow2-0-16` | `arrow2=0.16` |
+//!
+//! Support for some families of data types can be compiled out to reduce the
+//! binary size, e.g., for WASM targets. Per default all data types are
+//! supported via the `full` feature. To select a subset, disable the default
+//! features and activate the required ones:
+//!
+//! | Type Feature     | Data Types                                                      |
+//! |------------------|-----------------------------------------------------------------|
+//! | `types-temporal` | `Date32`, `Date64`, `Timestamp`, `Time32`, `Time64`, `Duration` |
+//! | `types-decimal`  | `Decimal128`, `Decimal256`                                      |
+//! | `types-union`    | `Union`, the `UnknownVariant` strategy                          |
+//!
+//! Schemas containing data types of disabled families can still be traced and
+//! converted, but building or deserializing arrays of these types results in
+//! an error.
+//!
+//! The optional `serde_json` feature adds strict `TryFrom` conversions between
+//! [`SerdeArrowSchema`][crate::schema::SerdeArrowSchema] and
+//! `serde_json::Value`.
+//!
+//! The optional `ipc` feature adds [`ipc::open_mmap`] to deserialize
+//! memory-mapped arrow IPC files batch by batch. It enables the most recent
+// arrow-version:replace: //! supported arrow version (`arrow-{version}`) and cannot be combined with
+//! supported arrow version (`arrow-53`) and cannot be combined with
+//! older `arrow-*` features, as these would be replaced silently.
+//!
+//! The optional `http` feature adds [`http::to_ipc_response_body`] and
+//! [`http::from_ipc_body`] to serve and read items in the arrow IPC streaming
+//! format. Like `ipc`, it enables the most recent supported arrow version and
+//! cannot be combined with older `arrow-*` features.
+//!
+//! The optional `spill` feature adds [`spill::SpillWriter`] and
+//! [`spill::SpillReader`] to spill records as CBOR and to later push them into
+//! builders with the existing schema.
+//!
+//! The optional `rayon` feature adds [`from_record_batch_parallel`] to
+//! deserialize the rows of a record batch and [`to_record_batch_parallel`] to
+//! serialize the columns of a record batch on a thread pool.
 
 // be more forgiving without any active implementation
 #[cfg_attr(not(any(has_arrow, has_arrow2)), allow(unused))]
@@ -200,7 +238,7 @@ pub mod _impl {
                     pub use $arrow_schema as schema;
                 }
                 pub mod array {
-                    pub use $arrow_array::RecordBatch;
+                    pub use $arrow_array::{RecordBatch, RecordBatchOptions};
                     pub use $arrow_array::array::{
                         Array,
                         ArrayRef,
@@ -234,6 +272,7 @@ pub mod _impl {
                         Date32Type,
                         Date64Type,
                         Decimal128Type,
+                        Decimal256Type,
                         DurationMicrosecondType,
                         DurationMillisecondType,
                         DurationNanosecondType,
@@ -245,6 +284,9 @@ pub mod _impl {
                         Int32Type,
                         Int64Type,
                         Int8Type,
+                        IntervalDayTimeType,
+                        IntervalMonthDayNanoType,
+                        IntervalYearMonthType,
                         Time32MillisecondType,
                         Time32SecondType,
                         Time64MicrosecondType,
@@ -258,8 +300,8 @@ pub mod _impl {
                         UInt64Type,
                         UInt8Type,
                     };
-                    pub use $arrow_buffer::ArrowNativeType;
-                    pub use $arrow_schema::{DataType, Field, FieldRef, Schema, TimeUnit, UnionMode};
+                    pub use $arrow_buffer::{i256, ArrowNativeType};
+                    pub use $arrow_schema::{DataType, Field, FieldRef, Fields, IntervalUnit, Schema, SchemaRef, TimeUnit, UnionMode};
                 }
                 pub mod error {
                     pub use $arrow_schema::ArrowError;
@@ -287,6 +329,11 @@ pub mod _impl {
     #[cfg(has_arrow_38)] build_arrow_crate!(arrow_array_38, arrow_buffer_38, arrow_data_38, arrow_schema_38);
     #[cfg(has_arrow_37)] build_arrow_crate!(arrow_array_37, arrow_buffer_37, arrow_data_37, arrow_schema_37);
 
+    /// Entry points for fuzz targets
+    pub mod fuzz {
+        pub use crate::internal::fuzz::deserialize_raw;
+    }
+
     /// Documentation
     pub mod docs {
         #[doc(hidden)]
@@ -313,18 +360,44 @@ mod test_with_arrow;
 #[cfg(test)]
 mod test;
 
-pub use crate::internal::error::{Error, Result};
+pub use crate::internal::error::{Error, ErrorKind, Result};
 
 pub use crate::internal::deserializer::Deserializer;
+pub use crate::internal::deserializer_options::{
+    BinaryFormat, DecimalFormat, DeserializerOptions, DurationFormat,
+};
 pub use crate::internal::serializer::Serializer;
+pub use crate::internal::serializer_options::{
+    Deduplication, DuplicateMapKeyPolicy, F16Overflow, F16Rounding, OversizedStringPolicy,
+    SerializerOptions, SortOrder, StringLengthLimit, UtcOffsetPolicy,
+};
+pub use crate::internal::session::Session;
+pub use crate::internal::shared_dictionaries::{DictionaryUpdate, SharedDictionaries};
 
 pub use crate::internal::array_builder::ArrayBuilder;
+pub use crate::internal::deserialization::custom_deserializer::{
+    CustomArrayDeserializer, CustomSource, CustomValue,
+};
+pub use crate::internal::row_sink::{RowSink, SinkState};
+pub use crate::internal::serialization::custom_builder::{CustomArrayBuilder, CustomStorage};
+pub use crate::internal::string_interner::StringInterner;
+pub use crate::internal::variant_extractors::VariantExtractors;
 
 #[cfg(has_arrow)]
 mod arrow_impl;
 
 #[cfg(has_arrow)]
-pub use arrow_impl::api::{from_arrow, from_record_batch, to_arrow, to_record_batch};
+pub use arrow_impl::api::{
+    check_alignment, collect_exploded, concat_batches_with_union_schema, empty_record_batch,
+    explode, from_arrow, from_record_batch, from_record_batch_range, patch_record_batch,
+    projection_for, reserialize_record_batch, split, to_arrow, to_record_batch,
+};
+
+#[cfg(has_arrow)]
+pub use arrow_impl::chunked::RecordBatchIter;
+
+#[cfg(all(has_arrow, feature = "rayon"))]
+pub use arrow_impl::api::{from_record_batch_parallel, to_record_batch_parallel};
 
 #[cfg(has_arrow2)]
 mod arrow2_impl;
@@ -332,12 +405,55 @@ mod arrow2_impl;
 #[cfg(has_arrow2)]
 pub use arrow2_impl::api::{from_arrow2, to_arrow2};
 
+#[cfg(has_arrow_ipc_version_conflict)]
+// arrow-version:replace: compile_error!("The `ipc` and `http` features require `arrow-{version}` and cannot be combined with older `arrow-*` features");
+compile_error!("The `ipc` and `http` features require `arrow-53` and cannot be combined with older `arrow-*` features");
+
+#[cfg(feature = "ipc")]
+#[deny(missing_docs)]
+/// Deserialization of memory-mapped arrow IPC files (*requires the `ipc`
+/// feature*)
+pub mod ipc {
+    pub use crate::arrow_impl::ipc::{open_mmap, IpcBatches};
+}
+
+#[cfg(feature = "http")]
+#[deny(missing_docs)]
+/// Serving and reading items as arrow IPC streams, e.g., in HTTP bodies
+/// (*requires the `http` feature*)
+pub mod http {
+    pub use crate::arrow_impl::http::{
+        from_ipc_body, to_ipc_response_body, IpcResponseBody, DEFAULT_BATCH_SIZE,
+        IPC_STREAM_CONTENT_TYPE,
+    };
+}
+
+#[cfg(feature = "spill")]
+#[deny(missing_docs)]
+/// Spilling records to temporary storage to build batches out-of-core
+/// (*requires the `spill` feature*)
+pub mod spill {
+    pub use crate::internal::spill::{SpillReader, SpillWriter};
+}
+
+#[deny(missing_docs)]
+/// Generation of Rust type definitions from schemas
+pub mod codegen {
+    pub use crate::internal::schema::codegen::rust_types_for;
+}
+
 #[deny(missing_docs)]
 /// Helpers that may be useful when using `serde_arrow`
 pub mod utils {
     pub use crate::internal::utils::{Item, Items};
 }
 
+#[deny(missing_docs)]
+/// Order preserving binary keys for rows in the format of `arrow-row`
+pub mod row_format {
+    pub use crate::internal::row_format::{compare, encode};
+}
+
 /// The mapping between Rust and Arrow types
 ///
 /// To convert between Rust objects and Arrow types, `serde_arrows` requires
@@ -363,6 +479,10 @@ pub mod utils {
 ///   depends on the union type: Field-less variants are mapped to `NULL`. New
 ///   type variants are mapped according to their inner type. Other variant
 ///   types are mapped to struct types.
+/// - Optional enums: nullable dense Unions. As Arrow unions cannot store nulls
+///   directly, an additional `Null` child marked with the
+///   [`NullVariant`][crate::schema::Strategy::NullVariant] strategy is used to
+///   encode `None`.
 ///
 /// All customization of the types happens by including a suitable
 /// [`Strategy`][crate::schema::Strategy] in the metadata of the fields. For
@@ -381,13 +501,23 @@ pub mod utils {
 /// # #[cfg(not(feature="has_arrow2"))]
 /// # fn main() {}
 /// ```
-
+///
+/// Custom strategies of other crates can be stored next to the strategies of
+/// `serde_arrow` under namespaced metadata keys, see
+/// [`MetadataStrategy`][crate::schema::MetadataStrategy].
 #[deny(missing_docs)]
 pub mod schema {
     pub use crate::internal::schema::{
-        Overwrites, SchemaLike, SerdeArrowSchema, Strategy, TracingOptions, STRATEGY_KEY,
+        get_strategy_from_metadata, strategy_key, DuplicateFieldPolicy, ListType, MetadataStrategy,
+        MixedTypePolicy, Overwrites, SchemaLike, SecondsType, SerdeArrowSchema, Strategy,
+        StringType, TracingOptions, DEFAULT_VALUE_KEY, STRATEGY_KEY,
     };
 
+    pub use crate::internal::arrow::TimeUnit;
+
+    #[cfg(has_arrow)]
+    pub use crate::arrow_impl::schema::IntoFields;
+
     /// Support for [canonical extension types][ext-docs]. This module is experimental without semver guarantees.
     ///
     /// [ext-docs]: https://arrow.apache.org/docs/format/CanonicalExtensions.html
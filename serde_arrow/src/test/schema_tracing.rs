@@ -426,3 +426,397 @@ mod untagged_enum_number_coercion {
         [(Num::U8(0),), (Num::U16(0),), (Num::Null(()),)]
     );
 }
+
+/// Structs with duplicate field names are handled according to `duplicate_fields`
+mod duplicate_fields {
+    use serde::{Deserialize, Serialize, Serializer};
+
+    use crate::internal::{schema::DuplicateFieldPolicy, testing::assert_error_contains};
+
+    use super::*;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct SameTypes {
+        a: u32,
+        #[serde(rename = "a")]
+        b: u32,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct DifferentTypes {
+        a: u32,
+        #[serde(rename = "a")]
+        b: String,
+    }
+
+    /// A map with repeated keys, traced as a struct
+    struct RepeatedKeys;
+
+    impl Serialize for RepeatedKeys {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::SerializeMap;
+
+            let mut map = serializer.serialize_map(Some(3))?;
+            map.serialize_entry("a", &1_u32)?;
+            map.serialize_entry("a", &2_u32)?;
+            map.serialize_entry("b", &3_u32)?;
+            map.end()
+        }
+    }
+
+    #[test]
+    fn from_type_error() {
+        let res = SerdeArrowSchema::from_type::<SameTypes>(TracingOptions::default());
+        assert_error_contains(&res, "Encountered duplicate field a");
+        assert_error_contains(&res, "setting `duplicate_fields` in `TracingOptions`");
+    }
+
+    #[test]
+    fn from_type_suffix() -> PanicOnError<()> {
+        let options = TracingOptions::default().duplicate_fields(DuplicateFieldPolicy::Suffix);
+        let actual = SerdeArrowSchema::from_type::<DifferentTypes>(options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U32"},
+            {"name": "a_1", "data_type": "LargeUtf8"},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn from_type_merge() -> PanicOnError<()> {
+        let options = TracingOptions::default().duplicate_fields(DuplicateFieldPolicy::Merge);
+        let actual = SerdeArrowSchema::from_type::<SameTypes>(options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U32"},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn from_type_merge_different_types() {
+        let options = TracingOptions::default().duplicate_fields(DuplicateFieldPolicy::Merge);
+        let res = SerdeArrowSchema::from_type::<DifferentTypes>(options);
+        assert_error_contains(
+            &res,
+            "Cannot merge duplicate fields \"a\" with different types",
+        );
+    }
+
+    #[test]
+    fn from_samples_error() {
+        let res = SerdeArrowSchema::from_samples(&[RepeatedKeys], TracingOptions::default());
+        assert_error_contains(&res, "Encountered duplicate field a");
+    }
+
+    #[test]
+    fn from_samples_suffix() -> PanicOnError<()> {
+        let options = TracingOptions::default().duplicate_fields(DuplicateFieldPolicy::Suffix);
+        let actual = SerdeArrowSchema::from_samples(&[RepeatedKeys, RepeatedKeys], options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U32"},
+            {"name": "a_1", "data_type": "U32"},
+            {"name": "b", "data_type": "U32"},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn from_samples_merge() -> PanicOnError<()> {
+        let options = TracingOptions::default().duplicate_fields(DuplicateFieldPolicy::Merge);
+        let actual = SerdeArrowSchema::from_samples(&[RepeatedKeys, RepeatedKeys], options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U32"},
+            {"name": "b", "data_type": "U32"},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn from_samples_repeated_samples_are_not_duplicates() -> PanicOnError<()> {
+        let data = json!([{"a": 1, "b": 2}, {"b": 3, "a": 4}]);
+        let actual = SerdeArrowSchema::from_samples(&data, TracingOptions::default())?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U64"},
+            {"name": "b", "data_type": "U64"},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
+
+/// The data types of strings and lists can be configured globally
+mod default_types {
+    use serde::Serialize;
+
+    use crate::internal::schema::{ListType, StringType};
+
+    use super::*;
+
+    #[derive(Serialize, serde::Deserialize)]
+    struct Record {
+        name: String,
+        tags: Vec<String>,
+        nested: Vec<Vec<u8>>,
+    }
+
+    fn samples() -> Vec<Record> {
+        vec![Record {
+            name: String::from("foo"),
+            tags: vec![String::from("a"), String::from("b")],
+            nested: vec![vec![1, 2], vec![3, 4]],
+        }]
+    }
+
+    #[test]
+    fn defaults() -> PanicOnError<()> {
+        let actual = SerdeArrowSchema::from_samples(samples(), TracingOptions::default())?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "name", "data_type": "LargeUtf8"},
+            {"name": "tags", "data_type": "LargeList", "children": [
+                {"name": "element", "data_type": "LargeUtf8"},
+            ]},
+            {"name": "nested", "data_type": "LargeList", "children": [
+                {"name": "element", "data_type": "LargeList", "children": [
+                    {"name": "element", "data_type": "U8"},
+                ]},
+            ]},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn small_offsets() -> PanicOnError<()> {
+        let options = TracingOptions::default()
+            .default_string_type(StringType::Utf8)
+            .default_list_type(ListType::List);
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "name", "data_type": "Utf8"},
+            {"name": "tags", "data_type": "List", "children": [
+                {"name": "element", "data_type": "Utf8"},
+            ]},
+            {"name": "nested", "data_type": "List", "children": [
+                {"name": "element", "data_type": "List", "children": [
+                    {"name": "element", "data_type": "U8"},
+                ]},
+            ]},
+        ]))?;
+
+        let actual = SerdeArrowSchema::from_samples(samples(), options.clone())?;
+        assert_eq!(actual, expected);
+
+        let actual = SerdeArrowSchema::from_type::<Record>(options)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn fixed_size_lists() -> PanicOnError<()> {
+        let options = TracingOptions::default().default_list_type(ListType::FixedSizeList(2));
+        let actual = SerdeArrowSchema::from_samples(samples(), options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "name", "data_type": "LargeUtf8"},
+            {"name": "tags", "data_type": "FixedSizeList(2)", "children": [
+                {"name": "element", "data_type": "LargeUtf8"},
+            ]},
+            {"name": "nested", "data_type": "FixedSizeList(2)", "children": [
+                {"name": "element", "data_type": "FixedSizeList(2)", "children": [
+                    {"name": "element", "data_type": "U8"},
+                ]},
+            ]},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn dictionary_values() -> PanicOnError<()> {
+        let options = TracingOptions::default()
+            .string_dictionary_encoding(true)
+            .default_string_type(StringType::Utf8);
+        let actual = SerdeArrowSchema::from_samples(json!([{"a": "x"}]), options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "Dictionary", "children": [
+                {"name": "key", "data_type": "U32"},
+                {"name": "value", "data_type": "Utf8"},
+            ]},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn overwrites_take_precedence() -> PanicOnError<()> {
+        let options = TracingOptions::default()
+            .default_string_type(StringType::Utf8)
+            .overwrite("name", json!({"name": "name", "data_type": "LargeUtf8"}))?;
+        let actual = SerdeArrowSchema::from_samples(json!([{"name": "x", "b": "y"}]), options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "b", "data_type": "Utf8"},
+            {"name": "name", "data_type": "LargeUtf8"},
+        ]))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
+
+/// `from_samples` can stop before all samples are traced
+mod sample_budgets {
+    use crate::internal::testing::assert_error_contains;
+
+    use super::*;
+
+    #[test]
+    fn max_samples() -> PanicOnError<()> {
+        let samples = json!([{"a": 1}, {"a": 2}, {"a": 3, "b": "x"}]);
+        let options = TracingOptions::default().max_samples(Some(2));
+        let (actual, num_samples) = SerdeArrowSchema::from_samples_with_count(&samples, options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U64"},
+        ]))?;
+        assert_eq!(actual, expected);
+        assert_eq!(num_samples, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn all_samples_are_traced_by_default() -> PanicOnError<()> {
+        let samples = json!([{"a": 1}, {"a": 2}, {"a": 3, "b": "x"}]);
+        let (actual, num_samples) =
+            SerdeArrowSchema::from_samples_with_count(&samples, TracingOptions::default())?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U64"},
+            {"name": "b", "data_type": "LargeUtf8", "nullable": true},
+        ]))?;
+        assert_eq!(actual, expected);
+        assert_eq!(num_samples, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn stable_samples() -> PanicOnError<()> {
+        let samples = json!([
+            {"a": 1},
+            {"a": 2, "b": "x"},
+            {"a": 3, "b": "y"},
+            {"a": 4, "b": "z"},
+            {"a": 5},
+            {"a": 6, "c": true},
+        ]);
+        let options = TracingOptions::default().stable_samples(Some(2));
+        let (actual, num_samples) = SerdeArrowSchema::from_samples_with_count(&samples, options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U64"},
+            {"name": "b", "data_type": "LargeUtf8", "nullable": true},
+        ]))?;
+        assert_eq!(actual, expected);
+        assert_eq!(num_samples, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn nullability_changes_reset_stability() -> PanicOnError<()> {
+        let samples = json!([{"a": 1}, {"a": 2}, {}, {"a": 4}, {"a": 5}, {"b": 6}]);
+        let options = TracingOptions::default().stable_samples(Some(2));
+        let (actual, num_samples) = SerdeArrowSchema::from_samples_with_count(&samples, options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U64", "nullable": true},
+        ]))?;
+        assert_eq!(actual, expected);
+        assert_eq!(num_samples, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn null_samples_are_not_stable() -> PanicOnError<()> {
+        let samples = json!([{"a": null}, {"a": null}, {"a": null}, {"a": 1}]);
+        let options = TracingOptions::default().stable_samples(Some(1));
+        let (actual, num_samples) = SerdeArrowSchema::from_samples_with_count(&samples, options)?;
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "U64", "nullable": true},
+        ]))?;
+        assert_eq!(actual, expected);
+        assert_eq!(num_samples, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn zero_samples() {
+        let samples = json!([{"a": 1}]);
+        let options = TracingOptions::default().max_samples(Some(0));
+        let res = SerdeArrowSchema::from_samples(&samples, options);
+        assert_error_contains(&res, "Encountered null only field");
+    }
+}
+
+/// Strings encoding decimals are traced as decimals with minimal precision and
+/// scale
+mod json_decimals {
+    use super::*;
+
+    macro_rules! test {
+        ($name:ident, $data_type:expr, $nullable:expr, $($data:tt)*) => {
+            #[test]
+            fn $name() -> PanicOnError<()> {
+                let expected = SerdeArrowSchema::from_value(json!([
+                    {
+                        "name": "value",
+                        "data_type": $data_type,
+                        "nullable": $nullable,
+                    },
+                ]))?;
+
+                let data = json!($($data)*);
+                let actual = SerdeArrowSchema::from_samples(&data, TracingOptions::default().guess_decimals(true))?;
+                assert_eq!(actual, expected);
+                Ok(())
+            }
+        };
+    }
+
+    test!(single, "Decimal128(3, 2)", false, [{"value": "1.23"}]);
+    test!(negative, "Decimal128(3, 2)", false, [{"value": "-1.23"}]);
+    test!(merged, "Decimal128(5, 2)", false, [{"value": "1.23"}, {"value": "123.4"}]);
+    test!(leading_zeros, "Decimal128(2, 2)", false, [{"value": "0.50"}, {"value": "0.05"}]);
+    test!(nullable, "Decimal128(4, 1)", true, [{"value": "100.5"}, {"value": null}]);
+    test!(null_first, "Decimal128(4, 1)", true, [{"value": null}, {"value": "100.5"}]);
+    test!(integer_strings, "LargeUtf8", false, [{"value": "1.5"}, {"value": "15"}]);
+    test!(other_strings, "LargeUtf8", true, [{"value": "foo"}, {"value": null}, {"value": "1.5"}]);
+
+    #[test]
+    fn exceeding_max_precision() -> PanicOnError<()> {
+        let options = TracingOptions::default()
+            .guess_decimals(true)
+            .max_decimal_precision(4);
+
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "Decimal128(4, 2)"},
+            {"name": "b", "data_type": "LargeUtf8"},
+            {"name": "c", "data_type": "LargeUtf8"},
+        ]))?;
+
+        let data = json!([
+            {"a": "12.34", "b": "123.45", "c": "123.4"},
+            {"a": "0.12", "b": "1.23", "c": "1.234"},
+        ]);
+        let actual = SerdeArrowSchema::from_samples(&data, options)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn disabled_per_default() -> PanicOnError<()> {
+        let expected = SerdeArrowSchema::from_value(json!([
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]))?;
+
+        let data = json!([{"value": "1.23"}]);
+        let actual = SerdeArrowSchema::from_samples(&data, TracingOptions::default())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}
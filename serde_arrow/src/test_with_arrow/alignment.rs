@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    ArrayBuilder, SerializerOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Value {
+    Int(i64),
+    Text(String),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: u32,
+    b: Option<String>,
+    c: Vec<Option<f64>>,
+    d: BTreeMap<String, bool>,
+    e: Value,
+    f: Inner,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    g: u8,
+    h: String,
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            a: 1,
+            b: Some(String::from("foo")),
+            c: vec![Some(1.5), None],
+            d: BTreeMap::from([(String::from("x"), true)]),
+            e: Value::Int(13),
+            f: Inner {
+                g: 2,
+                h: String::from("bar"),
+            },
+        },
+        Record {
+            a: 2,
+            b: None,
+            c: Vec::new(),
+            d: BTreeMap::new(),
+            e: Value::Text(String::from("baz")),
+            f: Inner {
+                g: 3,
+                h: String::from("bar"),
+            },
+        },
+    ]
+}
+
+fn fields() -> Vec<FieldRef> {
+    let tracing_options = TracingOptions::default()
+        .map_as_struct(false)
+        .string_dictionary_encoding(true);
+    Vec::<FieldRef>::from_samples(items(), tracing_options).unwrap()
+}
+
+#[test]
+fn unaligned_buffers_are_reported() {
+    let batch = crate::to_record_batch(&fields(), &items()).unwrap();
+    let res = crate::check_alignment(&batch);
+    assert_error_contains(&res, "is not aligned to 64 bytes");
+}
+
+#[test]
+fn aligned_buffers_pass_the_check() {
+    let mut builder = ArrayBuilder::from_arrow(&fields())
+        .unwrap()
+        .with_options(SerializerOptions::default().aligned_buffers(true));
+    builder.extend(items()).unwrap();
+
+    let batch = builder.to_record_batch().unwrap();
+    crate::check_alignment(&batch).unwrap();
+
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(actual, items());
+}
+
+#[test]
+fn aligned_buffers_in_subsequent_batches() {
+    let mut builder = ArrayBuilder::from_arrow(&fields())
+        .unwrap()
+        .with_options(SerializerOptions::default().aligned_buffers(true));
+
+    for item in items() {
+        builder.push(item).unwrap();
+        let batch = builder.to_record_batch().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        crate::check_alignment(&batch).unwrap();
+    }
+}
+
+#[test]
+fn error_names_the_nested_field() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {
+            "name": "outer",
+            "data_type": "Struct",
+            "children": [{"name": "inner", "data_type": "U32"}],
+        },
+    ]))
+    .unwrap();
+
+    #[derive(Serialize)]
+    struct Outer {
+        outer: InnerU32,
+    }
+
+    #[derive(Serialize)]
+    struct InnerU32 {
+        inner: u32,
+    }
+
+    let batch = crate::to_record_batch(
+        &fields,
+        &[Outer {
+            outer: InnerU32 { inner: 1 },
+        }],
+    )
+    .unwrap();
+    let res = crate::check_alignment(&batch);
+    assert_error_contains(&res, "$.outer.inner");
+}
@@ -0,0 +1,167 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::Array, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    ArrayBuilder, SerializerOptions,
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: Option<u32>,
+    b: Option<bool>,
+    c: Option<String>,
+    d: Option<Vec<Option<i64>>>,
+    e: Option<BTreeMap<String, f32>>,
+    f: Option<Inner>,
+    g: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    h: u8,
+    i: Vec<String>,
+}
+
+fn record(idx: usize) -> Record {
+    Record {
+        a: Some(idx as u32),
+        b: Some(idx.is_multiple_of(3)),
+        c: Some(format!("value {idx}")),
+        d: Some(vec![Some(idx as i64), None]),
+        e: Some(BTreeMap::from([(format!("key {idx}"), idx as f32)])),
+        f: Some(Inner {
+            h: idx as u8,
+            i: vec![String::from("foo"); idx % 3],
+        }),
+        g: Some(String::from(["x", "y"][idx % 2])),
+    }
+}
+
+fn fields() -> Vec<FieldRef> {
+    let tracing_options = TracingOptions::default()
+        .map_as_struct(false)
+        .string_dictionary_encoding(true);
+    Vec::<FieldRef>::from_samples(&[record(1)], tracing_options).unwrap()
+}
+
+fn builder() -> ArrayBuilder {
+    ArrayBuilder::from_arrow(&fields()).unwrap()
+}
+
+/// Append the nulls in bulk and compare against pushing null records
+fn assert_matches_pushed_nulls(chunks: &[(usize, usize)]) {
+    let mut expected_items = Vec::new();
+    let mut bulk = builder();
+    let mut single = builder();
+
+    for &(num_values, num_nulls) in chunks {
+        for _ in 0..num_values {
+            let item = record(expected_items.len());
+            bulk.push(&item).unwrap();
+            single.push(&item).unwrap();
+            expected_items.push(item);
+        }
+        bulk.append_nulls(num_nulls).unwrap();
+        for _ in 0..num_nulls {
+            single.push(Record::default()).unwrap();
+            expected_items.push(Record::default());
+        }
+    }
+
+    let actual = bulk.to_record_batch().unwrap();
+    let expected = single.to_record_batch().unwrap();
+    assert_eq!(actual, expected);
+
+    let items: Vec<Record> = crate::from_record_batch(&actual).unwrap();
+    assert_eq!(items, expected_items);
+}
+
+#[test]
+fn nulls_only() {
+    assert_matches_pushed_nulls(&[(0, 5)]);
+}
+
+#[test]
+fn nulls_after_values() {
+    assert_matches_pushed_nulls(&[(3, 2)]);
+}
+
+#[test]
+fn nulls_spanning_multiple_bytes() {
+    assert_matches_pushed_nulls(&[(3, 30), (1, 1), (7, 0), (0, 17), (2, 64)]);
+}
+
+#[test]
+fn zero_nulls() {
+    assert_matches_pushed_nulls(&[(2, 0), (0, 0)]);
+}
+
+#[test]
+fn non_nullable_fields_are_rejected() {
+    #[derive(Serialize)]
+    struct Required {
+        a: Option<u32>,
+        b: String,
+    }
+
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U32", "nullable": true},
+        {"name": "b", "data_type": "LargeUtf8"},
+    ]))
+    .unwrap();
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    builder
+        .push(Required {
+            a: Some(1),
+            b: String::from("foo"),
+        })
+        .unwrap();
+
+    let res = builder.append_nulls(2);
+    assert_error_contains(&res, "field $.b is not nullable");
+
+    // no rows were added to any field
+    assert_eq!(builder.num_rows(), 1);
+    let arrays = builder.to_arrow().unwrap();
+    assert_eq!(arrays[0].len(), 1);
+    assert_eq!(arrays[1].len(), 1);
+}
+
+#[test]
+fn max_rows() {
+    let mut builder = builder().with_options(SerializerOptions::default().max_rows(Some(4)));
+    builder.push(record(0)).unwrap();
+    builder.append_nulls(3).unwrap();
+    assert_error_contains(&builder.append_nulls(1), "the batch is limited to 4 rows");
+    assert_eq!(builder.num_rows(), 4);
+
+    let batch = builder.to_record_batch().unwrap();
+    assert_eq!(batch.num_rows(), 4);
+    assert_eq!(batch.column(0).null_count(), 3);
+}
+
+#[test]
+fn progress_is_reported() {
+    let processed = Arc::new(AtomicUsize::new(0));
+    let reported = Arc::clone(&processed);
+    let mut builder = builder().on_progress(10, move |rows| {
+        reported.store(rows, Ordering::Relaxed);
+    });
+
+    builder.append_nulls(5).unwrap();
+    assert_eq!(processed.load(Ordering::Relaxed), 0);
+
+    builder.append_nulls(7).unwrap();
+    assert_eq!(processed.load(Ordering::Relaxed), 12);
+}
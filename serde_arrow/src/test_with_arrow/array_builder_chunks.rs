@@ -0,0 +1,97 @@
+use serde_json::json;
+
+use crate::{
+    _impl::{
+        arrow::datatypes::{DataType as ArrowDataType, Field as ArrowField, FieldRef},
+        arrow2::datatypes::Field as Arrow2Field,
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions, STRATEGY_KEY},
+    ArrayBuilder,
+};
+
+use super::fixtures::{fields, items, Record};
+
+fn chunks() -> Vec<Vec<Record>> {
+    vec![
+        items(),
+        vec![],
+        vec![Record {
+            a: 3,
+            b: Some(String::from("bar")),
+        }],
+    ]
+}
+
+#[test]
+fn arrow_many() {
+    let fields = fields::<Record>();
+    let mut builder = ArrayBuilder::from_arrow_unchecked(&fields).unwrap();
+
+    let arrays = builder.to_arrow_many(chunks()).unwrap();
+    let lengths = arrays
+        .iter()
+        .map(|arrays| arrays.iter().map(|a| a.len()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(lengths, vec![vec![2, 2], vec![0, 0], vec![1, 1]]);
+
+    let expected = crate::to_arrow(&fields, &chunks()[2]).unwrap();
+    assert_eq!(arrays[2], expected);
+}
+
+#[test]
+fn arrow_many_includes_pushed_items() {
+    let fields = fields::<Record>();
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+
+    builder.push(&Record { a: 0, b: None }).unwrap();
+    let arrays = builder.to_arrow_many(chunks()).unwrap();
+    assert_eq!(arrays[0][0].len(), 3);
+    assert_eq!(arrays[2][0].len(), 1);
+}
+
+#[test]
+fn record_batches() {
+    let fields = fields::<Record>();
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+
+    let batches = builder.to_record_batches(chunks()).unwrap();
+    let rows = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(rows, vec![2, 0, 1]);
+
+    let expected = crate::to_record_batch(&fields, &chunks()[0]).unwrap();
+    assert_eq!(batches[0], expected);
+}
+
+#[test]
+fn arrow2_many() {
+    let fields = Vec::<Arrow2Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let mut builder = ArrayBuilder::from_arrow2_unchecked(&fields).unwrap();
+
+    let arrays = builder.to_arrow2_many(chunks()).unwrap();
+    let lengths = arrays
+        .iter()
+        .map(|arrays| arrays.iter().map(|a| a.len()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(lengths, vec![vec![2, 2], vec![0, 0], vec![1, 1]]);
+}
+
+#[test]
+fn unchecked_skips_validation() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "a",
+        "data_type": "U32",
+        "strategy": "MapAsStruct",
+    }]));
+    assert_error_contains(&fields, "invalid strategy");
+
+    let field = ArrowField::new("a", ArrowDataType::UInt32, false).with_metadata(
+        [(STRATEGY_KEY.to_string(), "MapAsStruct".to_string())]
+            .into_iter()
+            .collect(),
+    );
+    let fields = vec![FieldRef::new(field)];
+
+    assert_error_contains(&ArrayBuilder::from_arrow(&fields), "invalid strategy");
+    assert!(ArrayBuilder::from_arrow_unchecked(&fields).is_ok());
+}
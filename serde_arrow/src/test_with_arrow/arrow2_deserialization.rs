@@ -0,0 +1,168 @@
+//! Deserialization of arrow2 arrays built outside of serde_arrow
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow2::{array::Array, datatypes::Field},
+    schema::{SchemaLike, SerdeArrowSchema},
+    utils::Item,
+};
+
+fn roundtrip_sliced<T>(schema: serde_json::Value, items: &[T])
+where
+    T: Serialize + for<'de> Deserialize<'de> + PartialEq + std::fmt::Debug,
+{
+    let fields: Vec<Field> = SerdeArrowSchema::from_value(schema)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let arrays = crate::to_arrow2(&fields, items).unwrap();
+
+    let actual: Vec<T> = crate::from_arrow2(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+
+    let sliced = arrays
+        .iter()
+        .map(|array| array.sliced(1, items.len() - 2))
+        .collect::<Vec<Box<dyn Array>>>();
+    let actual: Vec<T> = crate::from_arrow2(&fields, &sliced).unwrap();
+    assert_eq!(actual, &items[1..items.len() - 1]);
+}
+
+#[test]
+fn decimal128() {
+    roundtrip_sliced(
+        json!([{"name": "item", "data_type": "Decimal128(5, 2)", "nullable": true}]),
+        &[
+            Item(Some(String::from("1.23"))),
+            Item(None),
+            Item(Some(String::from("-4.56"))),
+            Item(Some(String::from("7.89"))),
+        ],
+    );
+}
+
+#[test]
+fn fixed_size_binary() {
+    roundtrip_sliced(
+        json!([{"name": "item", "data_type": "FixedSizeBinary(3)", "nullable": true}]),
+        &[
+            Item(Some(vec![1_u8, 2, 3])),
+            Item(None),
+            Item(Some(vec![4, 5, 6])),
+            Item(Some(vec![7, 8, 9])),
+        ],
+    );
+}
+
+#[test]
+fn fixed_size_list() {
+    roundtrip_sliced(
+        json!([{
+            "name": "item",
+            "data_type": "FixedSizeList(2)",
+            "nullable": true,
+            "children": [{"name": "element", "data_type": "U16"}],
+        }]),
+        &[
+            Item(Some(vec![1_u16, 2])),
+            Item(None),
+            Item(Some(vec![3, 4])),
+            Item(Some(vec![5, 6])),
+        ],
+    );
+}
+
+#[test]
+fn binary() {
+    roundtrip_sliced(
+        json!([{"name": "item", "data_type": "Binary", "nullable": true}]),
+        &[
+            Item(Some(vec![1_u8, 2, 3])),
+            Item(None),
+            Item(Some(vec![])),
+            Item(Some(vec![4, 5])),
+        ],
+    );
+}
+
+#[test]
+fn large_binary() {
+    roundtrip_sliced(
+        json!([{"name": "item", "data_type": "LargeBinary", "nullable": true}]),
+        &[
+            Item(Some(vec![1_u8, 2, 3])),
+            Item(None),
+            Item(Some(vec![])),
+            Item(Some(vec![4, 5])),
+        ],
+    );
+}
+
+#[test]
+fn date32() {
+    roundtrip_sliced(
+        json!([{"name": "item", "data_type": "Date32", "nullable": true}]),
+        &[
+            Item(Some(String::from("2024-01-01"))),
+            Item(None),
+            Item(Some(String::from("1970-01-01"))),
+            Item(Some(String::from("1900-12-31"))),
+        ],
+    );
+}
+
+#[test]
+fn time32() {
+    roundtrip_sliced(
+        json!([{"name": "item", "data_type": "Time32(Millisecond)", "nullable": true}]),
+        &[
+            Item(Some(String::from("12:00:00"))),
+            Item(None),
+            Item(Some(String::from("13:14:15.016"))),
+            Item(Some(String::from("23:59:59.999"))),
+        ],
+    );
+}
+
+#[test]
+fn time64() {
+    roundtrip_sliced(
+        json!([{"name": "item", "data_type": "Time64(Nanosecond)", "nullable": true}]),
+        &[
+            Item(Some(String::from("12:00:00"))),
+            Item(None),
+            Item(Some(String::from("13:14:15.000000016"))),
+            Item(Some(String::from("23:59:59.999999999"))),
+        ],
+    );
+}
+
+#[test]
+fn duration() {
+    roundtrip_sliced(
+        json!([{"name": "item", "data_type": "Duration(Millisecond)", "nullable": true}]),
+        &[Item(Some(-1_i64)), Item(None), Item(Some(2)), Item(Some(3))],
+    );
+}
+
+#[test]
+fn dictionary() {
+    roundtrip_sliced(
+        json!([{
+            "name": "item",
+            "data_type": "Dictionary",
+            "nullable": true,
+            "children": [
+                {"name": "key", "data_type": "U16"},
+                {"name": "value", "data_type": "LargeUtf8"},
+            ],
+        }]),
+        &[
+            Item(Some(String::from("a"))),
+            Item(None),
+            Item(Some(String::from("b"))),
+            Item(Some(String::from("a"))),
+        ],
+    );
+}
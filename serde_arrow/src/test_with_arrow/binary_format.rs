@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef, schema::SchemaLike, BinaryFormat, Deserializer,
+    DeserializerOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    #[serde(with = "serde_bytes")]
+    a: Vec<u8>,
+    b: Option<serde_bytes::ByteBuf>,
+    c: serde_bytes::ByteBuf,
+    d: Vec<serde_bytes::ByteBuf>,
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            a: b"foobar".to_vec(),
+            b: Some(serde_bytes::ByteBuf::from(b"fo".to_vec())),
+            c: serde_bytes::ByteBuf::from(vec![0x00, 0xff]),
+            d: vec![serde_bytes::ByteBuf::from(b"f".to_vec())],
+        },
+        Record {
+            a: Vec::new(),
+            b: None,
+            c: serde_bytes::ByteBuf::from(vec![0x0f, 0xa5]),
+            d: Vec::new(),
+        },
+    ]
+}
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "LargeBinary"},
+        {"name": "b", "data_type": "Binary", "nullable": true},
+        {"name": "c", "data_type": "FixedSizeBinary(2)"},
+        {"name": "d", "data_type": "List", "children": [
+            {"name": "element", "data_type": "LargeBinary"},
+        ]},
+    ]))
+    .unwrap()
+}
+
+fn deserialize_json(format: BinaryFormat) -> serde_json::Value {
+    let fields = fields();
+    let arrays = crate::to_arrow(&fields, items()).unwrap();
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().binary_format(format));
+    serde_json::Value::deserialize(deserializer).unwrap()
+}
+
+#[test]
+fn bytes_format() {
+    assert_eq!(
+        deserialize_json(BinaryFormat::Bytes),
+        json!([
+            {"a": [102, 111, 111, 98, 97, 114], "b": [102, 111], "c": [0, 255], "d": [[102]]},
+            {"a": [], "b": null, "c": [15, 165], "d": []},
+        ]),
+    );
+}
+
+#[test]
+fn base64_format() {
+    assert_eq!(
+        deserialize_json(BinaryFormat::Base64),
+        json!([
+            {"a": "Zm9vYmFy", "b": "Zm8=", "c": "AP8=", "d": ["Zg=="]},
+            {"a": "", "b": null, "c": "D6U=", "d": []},
+        ]),
+    );
+}
+
+#[test]
+fn hex_format() {
+    assert_eq!(
+        deserialize_json(BinaryFormat::Hex),
+        json!([
+            {"a": "666f6f626172", "b": "666f", "c": "00ff", "d": ["66"]},
+            {"a": "", "b": null, "c": "0fa5", "d": []},
+        ]),
+    );
+}
+
+#[test]
+fn typed_deserialization_is_not_affected() {
+    let fields = fields();
+    let arrays = crate::to_arrow(&fields, items()).unwrap();
+    for format in [BinaryFormat::Base64, BinaryFormat::Hex] {
+        let deserializer = Deserializer::from_arrow(&fields, &arrays)
+            .unwrap()
+            .with_options(DeserializerOptions::default().binary_format(format));
+        let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+        assert_eq!(actual, items());
+    }
+}
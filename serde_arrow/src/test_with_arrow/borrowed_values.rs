@@ -0,0 +1,242 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::RecordBatch, datatypes::FieldRef},
+    schema::SchemaLike,
+    Deserializer, DeserializerOptions,
+};
+
+fn record_batch<T: Serialize>(fields: serde_json::Value, items: &T) -> RecordBatch {
+    let fields = Vec::<FieldRef>::from_value(fields).unwrap();
+    crate::to_record_batch(&fields, items).unwrap()
+}
+
+/// Check that the value points into the buffers of the record batch
+fn assert_borrowed(batch: &RecordBatch, value: &[u8]) {
+    let ptr = value.as_ptr() as usize;
+    let borrowed = batch.columns().iter().any(|column| {
+        let data = column.to_data();
+        let mut buffers = data.buffers().to_vec();
+        for child in data.child_data() {
+            buffers.extend(child.buffers().iter().cloned());
+        }
+        buffers.iter().any(|buffer| {
+            let start = buffer.as_ptr() as usize;
+            start <= ptr && ptr + value.len() <= start + buffer.len()
+        })
+    });
+    assert!(
+        borrowed,
+        "value {value:?} is not borrowed from the record batch"
+    );
+}
+
+#[test]
+fn borrowed_str_and_bytes_in_structs() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<'a> {
+        #[serde(borrow)]
+        name: &'a str,
+        #[serde(borrow)]
+        data: &'a [u8],
+        #[serde(borrow)]
+        maybe: Option<&'a str>,
+    }
+
+    let items = [
+        Record {
+            name: "foo",
+            data: b"bar",
+            maybe: Some("baz"),
+        },
+        Record {
+            name: "hello",
+            data: b"world",
+            maybe: None,
+        },
+    ];
+    for (string_type, binary_type) in [("Utf8", "Binary"), ("LargeUtf8", "LargeBinary")] {
+        let batch = record_batch(
+            json!([
+                {"name": "name", "data_type": string_type},
+                {"name": "data", "data_type": binary_type},
+                {"name": "maybe", "data_type": string_type, "nullable": true},
+            ]),
+            &items,
+        );
+
+        let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+        assert_eq!(actual, items);
+        for item in &actual {
+            assert_borrowed(&batch, item.name.as_bytes());
+            assert_borrowed(&batch, item.data);
+        }
+    }
+}
+
+#[test]
+fn cow_values_are_borrowed() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<'a> {
+        #[serde(borrow)]
+        name: Cow<'a, str>,
+    }
+
+    let items = [Record { name: "foo".into() }, Record { name: "bar".into() }];
+    let batch = record_batch(json!([{"name": "name", "data_type": "LargeUtf8"}]), &items);
+
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(actual, items);
+    for item in &actual {
+        assert!(matches!(item.name, Cow::Borrowed(_)));
+    }
+}
+
+#[test]
+fn bytes_are_borrowed_from_strings() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<T> {
+        value: T,
+    }
+
+    let items = [Record { value: "foo" }, Record { value: "bar" }];
+    let batch = record_batch(json!([{"name": "value", "data_type": "Utf8"}]), &items);
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct BytesRecord<'a> {
+        #[serde(borrow, with = "serde_bytes")]
+        value: &'a [u8],
+    }
+
+    let actual: Vec<BytesRecord> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(actual[0].value, b"foo");
+    assert_borrowed(&batch, actual[1].value);
+}
+
+#[test]
+fn strings_are_borrowed_from_binary_arrays() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<'a> {
+        #[serde(with = "serde_bytes")]
+        value: &'a [u8],
+    }
+
+    let items = [Record { value: b"foo" }, Record { value: b"bar" }];
+    let batch = record_batch(
+        json!([{"name": "value", "data_type": "LargeBinary"}]),
+        &items,
+    );
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct StrRecord<'a> {
+        value: &'a str,
+    }
+
+    let actual: Vec<StrRecord> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        actual,
+        [StrRecord { value: "foo" }, StrRecord { value: "bar" }]
+    );
+    assert_borrowed(&batch, actual[0].value.as_bytes());
+}
+
+#[test]
+fn nested_values_are_borrowed() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Inner<'a> {
+        #[serde(borrow)]
+        label: &'a str,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<'a> {
+        #[serde(borrow)]
+        tags: Vec<&'a str>,
+        #[serde(borrow)]
+        inner: Option<Inner<'a>>,
+        #[serde(borrow)]
+        attributes: BTreeMap<&'a str, &'a str>,
+    }
+
+    let items = [
+        Record {
+            tags: vec!["a", "b"],
+            inner: Some(Inner { label: "foo" }),
+            attributes: BTreeMap::from([("k", "v")]),
+        },
+        Record {
+            tags: vec![],
+            inner: None,
+            attributes: BTreeMap::new(),
+        },
+    ];
+    let batch = record_batch(
+        json!([
+            {"name": "tags", "data_type": "LargeList", "children": [
+                {"name": "element", "data_type": "LargeUtf8"},
+            ]},
+            {"name": "inner", "data_type": "Struct", "nullable": true, "children": [
+                {"name": "label", "data_type": "LargeUtf8"},
+            ]},
+            {"name": "attributes", "data_type": "Map", "children": [
+                {"name": "entries", "data_type": "Struct", "children": [
+                    {"name": "key", "data_type": "LargeUtf8"},
+                    {"name": "value", "data_type": "LargeUtf8"},
+                ]},
+            ]},
+        ]),
+        &items,
+    );
+
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn dictionary_values_are_borrowed() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<'a> {
+        #[serde(borrow)]
+        value: &'a str,
+    }
+
+    let items = [
+        Record { value: "foo" },
+        Record { value: "bar" },
+        Record { value: "foo" },
+    ];
+    let batch = record_batch(
+        json!([{"name": "value", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]}]),
+        &items,
+    );
+
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn values_are_borrowed_with_strict_nullability() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<'a> {
+        #[serde(borrow)]
+        value: Option<&'a str>,
+    }
+
+    let items = [Record { value: Some("foo") }, Record { value: None }];
+    let batch = record_batch(
+        json!([{"name": "value", "data_type": "LargeUtf8", "nullable": true}]),
+        &items,
+    );
+
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_options(DeserializerOptions::default().strict_nullability(true));
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, items);
+}
@@ -0,0 +1,156 @@
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    ArrayBuilder, SerializerOptions,
+};
+
+#[derive(Serialize)]
+struct Record {
+    a: u32,
+    b: String,
+}
+
+fn record(a: u32) -> Record {
+    Record {
+        a,
+        b: format!("value {a}"),
+    }
+}
+
+fn builder(options: SerializerOptions) -> ArrayBuilder {
+    let fields = Vec::<FieldRef>::from_samples(&[record(0)], TracingOptions::default()).unwrap();
+    ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(options)
+}
+
+#[test]
+fn max_rows() {
+    let mut builder = builder(SerializerOptions::default().max_rows(Some(2)));
+    builder.push(record(0)).unwrap();
+    builder.push(record(1)).unwrap();
+    assert_error_contains(&builder.push(record(2)), "the batch is limited to 2 rows");
+    assert_eq!(builder.num_rows(), 2);
+
+    // building the arrays resets the count
+    let batch = builder.to_record_batch().unwrap();
+    assert_eq!(batch.num_rows(), 2);
+    builder.push(record(2)).unwrap();
+    assert_eq!(builder.num_rows(), 1);
+}
+
+#[test]
+fn max_rows_with_extend() {
+    let mut builder = builder(SerializerOptions::default().max_rows(Some(2)));
+    let res = builder.extend(&[record(0), record(1), record(2)]);
+    assert_error_contains(&res, "the batch is limited to 2 rows");
+    assert_eq!(builder.num_rows(), 2);
+}
+
+#[test]
+fn max_bytes() {
+    // per row a u32 (4 bytes) and a LargeUtf8 string of 7 bytes with an 8
+    // byte offset, plus the initial offset
+    let mut builder = builder(SerializerOptions::default().max_bytes(Some(50)));
+    builder.push(record(0)).unwrap();
+    builder.push(record(1)).unwrap();
+
+    // the string of the third row is rejected before it is written
+    assert_error_contains(
+        &builder.push(record(2)),
+        "The buffered data of 53 bytes exceeds the limit of 50 bytes",
+    );
+
+    // subsequent pushes fail without writing the item
+    assert_error_contains(&builder.push(record(3)), "exceeds the limit of 50 bytes");
+
+    // building the arrays resets the count, the partially written item
+    // results in an error
+    assert_error_contains(&builder.to_record_batch(), "same length");
+    builder.push(record(4)).unwrap();
+    let batch = builder.to_record_batch().unwrap();
+    assert_eq!(batch.num_rows(), 1);
+}
+
+#[test]
+fn max_bytes_rejects_large_strings_while_writing() {
+    let mut builder = builder(SerializerOptions::default().max_bytes(Some(1000)));
+    let res = builder.push(Record {
+        a: 0,
+        b: "x".repeat(1_000_000),
+    });
+    assert_error_contains(&res, "exceeds the limit of 1000 bytes");
+    assert_error_contains(&res, "field: \"$.b\"");
+}
+
+#[test]
+fn max_bytes_counts_nested_data() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "U8"},
+        ]},
+    ]))
+    .unwrap();
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().max_bytes(Some(1000)));
+
+    // the list is rejected after its first elements exceed the limit
+    let res = builder.push(json!({"a": vec![0_u8; 2000]}));
+    assert_error_contains(
+        &res,
+        "The buffered data of 1001 bytes exceeds the limit of 1000 bytes",
+    );
+    assert_error_contains(&res, "field: \"$.a\"");
+}
+
+#[test]
+fn max_bytes_counts_fixed_sized_values() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U64"},
+    ]))
+    .unwrap();
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().max_bytes(Some(16)));
+
+    builder.push(json!({"a": 0})).unwrap();
+    builder.push(json!({"a": 1})).unwrap();
+    builder.push(json!({"a": 2})).unwrap();
+    assert_error_contains(
+        &builder.push(json!({"a": 3})),
+        "The buffered data of 24 bytes exceeds the limit of 16 bytes",
+    );
+    assert_eq!(builder.num_rows(), 3);
+}
+
+#[test]
+fn max_depth() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U8"},
+        {"name": "b", "data_type": "Struct", "children": [
+            {"name": "c", "data_type": "List", "children": [
+                {"name": "element", "data_type": "U8"},
+            ]},
+        ]},
+    ]))
+    .unwrap();
+
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().max_depth(Some(3)));
+    builder.push(json!({"a": 1, "b": {"c": [1, 2]}})).unwrap();
+
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().max_depth(Some(2)));
+    assert_error_contains(
+        &builder.push(json!({"a": 1, "b": {"c": [1, 2]}})),
+        "The field $.b.c.element exceeds the maximum nesting depth of 2",
+    );
+    assert_error_contains(&builder.to_record_batch(), "maximum nesting depth");
+}
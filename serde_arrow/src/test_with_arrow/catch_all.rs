@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, MapArray},
+        datatypes::FieldRef,
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    #[serde(flatten)]
+    extra: HashMap<String, String>,
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        Record {
+            id: 0,
+            extra: HashMap::from([
+                (String::from("color"), String::from("red")),
+                (String::from("size"), String::from("large")),
+            ]),
+        },
+        Record {
+            id: 1,
+            extra: HashMap::new(),
+        },
+        Record {
+            id: 2,
+            extra: HashMap::from([(String::from("shape"), String::from("round"))]),
+        },
+    ]
+}
+
+#[test]
+fn trace_flattened_map() {
+    let schema = SerdeArrowSchema::from_samples(
+        records(),
+        TracingOptions::default().catch_all("extra", &["id"]),
+    )
+    .unwrap();
+
+    let expected = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "extra",
+            "data_type": "Map",
+            "strategy": "CatchAll",
+            "children": [{
+                "name": "entries",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "key", "data_type": "LargeUtf8"},
+                    {"name": "value", "data_type": "LargeUtf8"},
+                ],
+            }],
+        },
+        {"name": "id", "data_type": "U32"},
+    ]))
+    .unwrap();
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn roundtrip_flattened_map() {
+    let items = records();
+    let fields = Vec::<FieldRef>::from_samples(
+        &items,
+        TracingOptions::default().catch_all("extra", &["id"]),
+    )
+    .unwrap();
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let extra = arrays[0].as_any().downcast_ref::<MapArray>().unwrap();
+    assert_eq!(extra.null_count(), 0);
+    assert_eq!(extra.value_offsets(), &[0, 2, 2, 3]);
+
+    let actual: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Outer {
+    inner: Inner,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    value: i64,
+    #[serde(flatten)]
+    rest: HashMap<String, i64>,
+}
+
+#[test]
+fn nested_catch_all() {
+    let items = vec![
+        Outer {
+            inner: Inner {
+                value: 1,
+                rest: HashMap::from([(String::from("a"), 2), (String::from("b"), 3)]),
+            },
+        },
+        Outer {
+            inner: Inner {
+                value: 4,
+                rest: HashMap::new(),
+            },
+        },
+    ];
+    let options = TracingOptions::default().catch_all("inner.rest", &["value"]);
+    let fields = Vec::<FieldRef>::from_samples(&items, options).unwrap();
+
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let actual: Vec<Outer> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn extra_entries_are_ignored_by_structs_without_flatten() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Plain {
+        id: u32,
+    }
+
+    let items = records();
+    let fields = Vec::<FieldRef>::from_samples(
+        &items,
+        TracingOptions::default().catch_all("extra", &["id"]),
+    )
+    .unwrap();
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let actual: Vec<Plain> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, [Plain { id: 0 }, Plain { id: 1 }, Plain { id: 2 }]);
+}
+
+#[test]
+fn invalid_catch_all_fields() {
+    fn map_field(name: &str, key_type: &str) -> serde_json::Value {
+        json!({
+            "name": name,
+            "data_type": "Map",
+            "strategy": "CatchAll",
+            "children": [{
+                "name": "entries",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "key", "data_type": key_type},
+                    {"name": "value", "data_type": "I64"},
+                ],
+            }],
+        })
+    }
+
+    let res = SerdeArrowSchema::from_value(json!([
+        {"name": "extra", "data_type": "I64", "strategy": "CatchAll"},
+    ]));
+    assert_error_contains(&res, "invalid strategy for Int64: CatchAll");
+
+    let res = SerdeArrowSchema::from_value(json!([map_field("extra", "I32")]));
+    assert_error_contains(&res, "CatchAll Map fields must have Utf8 or LargeUtf8 keys");
+
+    let res = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "Struct",
+        "children": [map_field("a", "Utf8"), map_field("b", "Utf8")],
+    }]));
+    assert_error_contains(&res, "more than one CatchAll child");
+}
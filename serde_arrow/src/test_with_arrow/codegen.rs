@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    codegen::rust_types_for,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+};
+
+#[test]
+fn traced_types() {
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        id: u32,
+        tags: Vec<String>,
+        location: Option<Location>,
+        pair: (i8, bool),
+        shape: Shape,
+        counts: HashMap<String, i64>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Location {
+        lat: f64,
+        lon: f64,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rect { width: f64, height: f64 },
+    }
+
+    let schema = SerdeArrowSchema::from_type::<Record>(
+        TracingOptions::default()
+            .map_as_struct(false)
+            .allow_null_fields(true),
+    )
+    .unwrap();
+
+    assert_eq!(
+        rust_types_for(&schema).unwrap(),
+        concat!(
+            "use serde::{Deserialize, Serialize};\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub struct Record {\n",
+            "    pub id: u32,\n",
+            "    pub tags: Vec<String>,\n",
+            "    pub location: Option<Location>,\n",
+            "    pub pair: (i8, bool),\n",
+            "    pub shape: Shape,\n",
+            "    pub counts: std::collections::HashMap<String, i64>,\n",
+            "}\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub struct Location {\n",
+            "    pub lat: f64,\n",
+            "    pub lon: f64,\n",
+            "}\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub enum Shape {\n",
+            "    Empty,\n",
+            "    Circle(f64),\n",
+            "    Rect {\n",
+            "        width: f64,\n",
+            "        height: f64,\n",
+            "    },\n",
+            "}\n",
+        ),
+    );
+}
+
+#[test]
+fn temporal_types() {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "date", "data_type": "Date32"},
+        {"name": "utc", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {"name": "naive", "data_type": "Timestamp(Microsecond, None)", "nullable": true},
+        {"name": "utc_str", "data_type": "Date64", "strategy": "UtcStrAsDate64"},
+        {"name": "millis", "data_type": "Date64"},
+        {"name": "time", "data_type": "Time64(Nanosecond)"},
+        {"name": "elapsed", "data_type": "Duration(Second)"},
+        {"name": "price", "data_type": "Decimal128(10, 2)"},
+        {"name": "cents", "data_type": "Decimal128(10, 2)", "strategy": "ScaledInt(2)"},
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        rust_types_for(&schema).unwrap(),
+        concat!(
+            "use serde::{Deserialize, Serialize};\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub struct Record {\n",
+            "    pub date: chrono::NaiveDate,\n",
+            "    pub utc: chrono::DateTime<chrono::Utc>,\n",
+            "    pub naive: Option<chrono::NaiveDateTime>,\n",
+            "    pub utc_str: chrono::DateTime<chrono::Utc>,\n",
+            "    pub millis: i64,\n",
+            "    pub time: chrono::NaiveTime,\n",
+            "    pub elapsed: i64,\n",
+            "    pub price: String,\n",
+            "    pub cents: i64,\n",
+            "}\n",
+        ),
+    );
+}
+
+#[test]
+fn names_are_converted_into_identifiers() {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "type", "data_type": "Utf8"},
+        {"name": "userId", "data_type": "I64"},
+        {"name": "first name", "data_type": "Utf8"},
+        {"name": "1st", "data_type": "Bool"},
+        {"name": "self", "data_type": "Bool"},
+        {"name": "item", "data_type": "Struct", "children": [
+            {"name": "value", "data_type": "I32"},
+        ]},
+        {"name": "items", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Struct", "children": [
+                {"name": "item", "data_type": "Struct", "children": [
+                    {"name": "value", "data_type": "I32"},
+                ]},
+            ]},
+        ]},
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        rust_types_for(&schema).unwrap(),
+        concat!(
+            "use serde::{Deserialize, Serialize};\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub struct Record {\n",
+            "    pub r#type: String,\n",
+            "    #[serde(rename = \"userId\")]\n",
+            "    pub user_id: i64,\n",
+            "    #[serde(rename = \"first name\")]\n",
+            "    pub first_name: String,\n",
+            "    #[serde(rename = \"1st\")]\n",
+            "    pub _1st: bool,\n",
+            "    #[serde(rename = \"self\")]\n",
+            "    pub self_: bool,\n",
+            "    pub item: Item,\n",
+            "    pub items: Vec<Items>,\n",
+            "}\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub struct Item {\n",
+            "    pub value: i32,\n",
+            "}\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub struct Items {\n",
+            "    pub item: Item2,\n",
+            "}\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub struct Item2 {\n",
+            "    pub value: i32,\n",
+            "}\n",
+        ),
+    );
+}
+
+#[test]
+fn optional_enums_and_tuple_variants() {
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        value: Option<Value>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    enum Value {
+        #[serde(rename = "int")]
+        Int(i64),
+        Pair(u8, String),
+    }
+
+    let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+
+    assert_eq!(
+        rust_types_for(&schema).unwrap(),
+        concat!(
+            "use serde::{Deserialize, Serialize};\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub struct Record {\n",
+            "    pub value: Option<Value>,\n",
+            "}\n",
+            "\n",
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n",
+            "pub enum Value {\n",
+            "    #[serde(rename = \"int\")]\n",
+            "    Int(i64),\n",
+            "    Pair(u8, String),\n",
+            "}\n",
+        ),
+    );
+}
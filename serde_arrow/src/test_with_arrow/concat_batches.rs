@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::RecordBatch,
+        datatypes::{DataType, FieldRef},
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+};
+
+fn record_batch<T: Serialize>(items: &[T]) -> RecordBatch {
+    let options = TracingOptions::default().allow_null_fields(true);
+    let fields = Vec::<FieldRef>::from_samples(items, options).unwrap();
+    crate::to_record_batch(&fields, &items).unwrap()
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Union {
+    a: Option<i64>,
+    b: Option<String>,
+    c: Option<f64>,
+}
+
+#[test]
+fn missing_columns_are_null_filled() {
+    #[derive(Serialize)]
+    struct First {
+        a: i64,
+        b: String,
+    }
+
+    #[derive(Serialize)]
+    struct Second {
+        c: f64,
+        a: i64,
+    }
+
+    let first = record_batch(&[
+        First {
+            a: 1,
+            b: String::from("foo"),
+        },
+        First {
+            a: 2,
+            b: String::from("bar"),
+        },
+    ]);
+    let second = record_batch(&[Second { c: 0.5, a: 3 }]);
+
+    let batch = crate::concat_batches_with_union_schema(&[first, second]).unwrap();
+
+    let schema = batch.schema();
+    let names = schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["a", "b", "c"]);
+    assert!(!schema.field(0).is_nullable());
+    assert!(schema.field(1).is_nullable());
+    assert!(schema.field(2).is_nullable());
+
+    let items: Vec<Union> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        items,
+        [
+            Union {
+                a: Some(1),
+                b: Some(String::from("foo")),
+                c: None,
+            },
+            Union {
+                a: Some(2),
+                b: Some(String::from("bar")),
+                c: None,
+            },
+            Union {
+                a: Some(3),
+                b: None,
+                c: Some(0.5),
+            },
+        ],
+    );
+}
+
+#[test]
+fn compatible_types_are_promoted() {
+    #[derive(Serialize)]
+    struct First {
+        a: u8,
+        b: Option<()>,
+        c: i32,
+    }
+
+    #[derive(Serialize)]
+    struct Second {
+        a: i16,
+        b: String,
+        c: f32,
+    }
+
+    let first = record_batch(&[First {
+        a: 200,
+        b: None,
+        c: -3,
+    }]);
+    let second = record_batch(&[Second {
+        a: -5,
+        b: String::from("foo"),
+        c: 0.25,
+    }]);
+
+    let batch = crate::concat_batches_with_union_schema(&[first, second]).unwrap();
+
+    let schema = batch.schema();
+    assert_eq!(schema.field(0).data_type(), &DataType::Int16);
+    assert_eq!(schema.field(1).data_type(), &DataType::LargeUtf8);
+    assert_eq!(schema.field(2).data_type(), &DataType::Float64);
+    assert!(!schema.field(0).is_nullable());
+    assert!(schema.field(1).is_nullable());
+
+    let items: Vec<Union> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        items,
+        [
+            Union {
+                a: Some(200),
+                b: None,
+                c: Some(-3.0),
+            },
+            Union {
+                a: Some(-5),
+                b: Some(String::from("foo")),
+                c: Some(0.25),
+            },
+        ],
+    );
+}
+
+#[test]
+fn string_and_binary_variants_are_promoted() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "s", "data_type": "Utf8"},
+        {"name": "b", "data_type": "Binary"},
+    ]))
+    .unwrap();
+    let first = crate::to_record_batch(
+        &fields,
+        &json!([{"s": "foo", "b": [1, 2]}]).as_array().unwrap(),
+    )
+    .unwrap();
+
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "s", "data_type": "LargeUtf8"},
+        {"name": "b", "data_type": "LargeBinary"},
+    ]))
+    .unwrap();
+    let second = crate::to_record_batch(
+        &fields,
+        &json!([{"s": "bar", "b": [3]}]).as_array().unwrap(),
+    )
+    .unwrap();
+
+    let batch = crate::concat_batches_with_union_schema(&[first, second]).unwrap();
+
+    let schema = batch.schema();
+    assert_eq!(schema.field(0).data_type(), &DataType::LargeUtf8);
+    assert_eq!(schema.field(1).data_type(), &DataType::LargeBinary);
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Item {
+        s: String,
+        b: Vec<u8>,
+    }
+
+    let items: Vec<Item> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        items,
+        [
+            Item {
+                s: String::from("foo"),
+                b: vec![1, 2],
+            },
+            Item {
+                s: String::from("bar"),
+                b: vec![3],
+            },
+        ],
+    );
+}
+
+#[test]
+fn nested_columns_are_concatenated() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        values: Vec<u32>,
+    }
+
+    let first = record_batch(&[Item { values: vec![1, 2] }]);
+    let second = record_batch(&[Item { values: vec![] }, Item { values: vec![3] }]);
+
+    let batch = crate::concat_batches_with_union_schema(&[first, second]).unwrap();
+    let items: Vec<Item> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        items,
+        [
+            Item { values: vec![1, 2] },
+            Item { values: vec![] },
+            Item { values: vec![3] },
+        ],
+    );
+}
+
+#[test]
+fn incompatible_types() {
+    #[derive(Serialize)]
+    struct First {
+        a: bool,
+    }
+
+    #[derive(Serialize)]
+    struct Second {
+        a: String,
+    }
+
+    let first = record_batch(&[First { a: true }]);
+    let second = record_batch(&[Second {
+        a: String::from("foo"),
+    }]);
+
+    let res = crate::concat_batches_with_union_schema(&[first, second]);
+    assert_error_contains(
+        &res,
+        "column \"a\" has incompatible types Boolean and LargeUtf8",
+    );
+
+    let res = crate::concat_batches_with_union_schema(&[]);
+    assert_error_contains(&res, "no batches given");
+}
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef, internal::testing::assert_error_contains,
+    schema::SchemaLike, ArrayBuilder, CustomArrayBuilder, CustomStorage, Result,
+};
+
+/// Collect the components of a struct into a fixed size list
+#[derive(Debug, Default)]
+struct Components(Vec<f64>);
+
+impl CustomArrayBuilder for Components {
+    fn clone_box(&self) -> Box<dyn CustomArrayBuilder> {
+        Box::new(Components::default())
+    }
+
+    fn serialize_struct_start(
+        &mut self,
+        _: &mut CustomStorage<'_>,
+        _: &'static str,
+        _: usize,
+    ) -> Result<()> {
+        self.0.clear();
+        Ok(())
+    }
+
+    fn serialize_struct_field(&mut self, _: &mut CustomStorage<'_>, _: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_f64(&mut self, _: &mut CustomStorage<'_>, v: f64) -> Result<()> {
+        self.0.push(v);
+        Ok(())
+    }
+
+    fn serialize_struct_end(&mut self, storage: &mut CustomStorage<'_>) -> Result<()> {
+        storage.push(&self.0)
+    }
+}
+
+/// Parse hex encoded strings into integers
+#[derive(Debug, Default)]
+struct Hex;
+
+impl CustomArrayBuilder for Hex {
+    fn clone_box(&self) -> Box<dyn CustomArrayBuilder> {
+        Box::new(Hex)
+    }
+
+    fn serialize_str(&mut self, storage: &mut CustomStorage<'_>, v: &str) -> Result<()> {
+        match u32::from_str_radix(v, 16) {
+            Ok(v) => storage.push(&v),
+            Err(err) => Err(crate::Error::custom(format!(
+                "invalid hex value {v:?}: {err}"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Record {
+    point: Option<Point>,
+    ids: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct RawRecord {
+    point: Option<Vec<f64>>,
+    ids: Vec<u32>,
+}
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {"name": "point", "data_type": "FixedSizeList(2)", "nullable": true, "children": [
+            {"name": "element", "data_type": "F64"},
+        ]},
+        {"name": "ids", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "U32"},
+        ]},
+    ]))
+    .unwrap()
+}
+
+fn new_builder() -> ArrayBuilder {
+    ArrayBuilder::from_arrow(&fields())
+        .unwrap()
+        .with_custom_builder("$.point", Components::default())
+        .with_custom_builder("$.ids.element", Hex)
+}
+
+#[test]
+fn custom_builders_write_to_their_storage() {
+    let mut builder = new_builder();
+    builder
+        .extend(&[
+            Record {
+                point: Some(Point { x: 1.0, y: 2.0 }),
+                ids: vec![String::from("ff"), String::from("10")],
+            },
+            Record {
+                point: None,
+                ids: vec![],
+            },
+        ])
+        .unwrap();
+    let batch = builder.to_record_batch().unwrap();
+    assert_eq!(batch.schema().fields()[..], fields()[..]);
+
+    let actual: Vec<RawRecord> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        actual,
+        [
+            RawRecord {
+                point: Some(vec![1.0, 2.0]),
+                ids: vec![255, 16],
+            },
+            RawRecord {
+                point: None,
+                ids: vec![],
+            },
+        ]
+    );
+
+    // the custom builders are kept after taking the arrays
+    builder
+        .push(Record {
+            point: Some(Point { x: 3.0, y: 4.0 }),
+            ids: vec![String::from("a")],
+        })
+        .unwrap();
+    let batch = builder.to_record_batch().unwrap();
+    let actual: Vec<RawRecord> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        actual,
+        [RawRecord {
+            point: Some(vec![3.0, 4.0]),
+            ids: vec![10],
+        }]
+    );
+}
+
+#[test]
+fn errors_of_custom_builders() {
+    let mut builder = new_builder();
+    let res = builder.push(Record {
+        point: None,
+        ids: vec![String::from("xyz")],
+    });
+    assert_error_contains(&res, "invalid hex value \"xyz\"");
+    assert_error_contains(&res, "$.ids.element");
+
+    let mut builder = new_builder();
+    let res = builder.push(json!({"point": null, "ids": [1]}));
+    assert_error_contains(&res, "serialize_u64 is not supported");
+}
+
+#[test]
+fn unknown_paths() {
+    let mut builder = ArrayBuilder::from_arrow(&fields())
+        .unwrap()
+        .with_custom_builder("$.missing", Hex);
+    let res = builder.to_arrow();
+    assert_error_contains(&res, "Cannot set a custom builder for \"$.missing\"");
+}
+
+#[test]
+fn custom_builders_require_empty_builders() {
+    let mut builder = ArrayBuilder::from_arrow(&fields()).unwrap();
+    builder.push(json!({"point": null, "ids": []})).unwrap();
+    let mut builder = builder.with_custom_builder("$.point", Components::default());
+    let res = builder.to_arrow();
+    assert_error_contains(&res, "already contains items");
+}
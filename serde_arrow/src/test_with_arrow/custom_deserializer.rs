@@ -0,0 +1,273 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde::Deserialize;
+
+use crate::{
+    _impl::arrow::{
+        _raw::{
+            array::{Int32Array, Int64Array, ListArray},
+            schema::{UnionFields, UnionMode},
+        },
+        array::{ArrayRef, RecordBatch, UnionArray},
+        buffer::ScalarBuffer,
+        datatypes::{DataType, Field, FieldRef, Int32Type, Schema},
+    },
+    internal::testing::assert_error_contains,
+    CustomArrayDeserializer, CustomSource, CustomValue, Deserializer, Result,
+};
+
+/// Format integers as hex strings
+struct Hex;
+
+impl CustomArrayDeserializer for Hex {
+    fn next(&mut self, source: &mut CustomSource<'_, '_>) -> Result<CustomValue> {
+        Ok(match source.read::<Option<i32>>()? {
+            Some(value) => CustomValue::String(format!("{value:x}")),
+            None => CustomValue::Null,
+        })
+    }
+}
+
+/// Return the same value for every row without reading the storage
+struct Constant(CustomValue);
+
+impl CustomArrayDeserializer for Constant {
+    fn next(&mut self, _: &mut CustomSource<'_, '_>) -> Result<CustomValue> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Try to read the storage, independent of its type
+struct Storage;
+
+impl CustomArrayDeserializer for Storage {
+    fn next(&mut self, source: &mut CustomSource<'_, '_>) -> Result<CustomValue> {
+        source.read::<i64>().map(CustomValue::I64)
+    }
+}
+
+fn batch(field: Field, array: ArrayRef) -> RecordBatch {
+    RecordBatch::try_new(Arc::new(Schema::new(vec![field])), vec![array]).unwrap()
+}
+
+fn hex_field(name: &str) -> Field {
+    Field::new(name, DataType::Int32, true).with_metadata(HashMap::from([(
+        String::from("ARROW:extension:name"),
+        String::from("example.hex"),
+    )]))
+}
+
+#[test]
+fn extension_name() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        a: Option<String>,
+    }
+
+    let batch = batch(
+        hex_field("a"),
+        Arc::new(Int32Array::from(vec![Some(10), None, Some(255)])),
+    );
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_custom_deserializer("example.hex", || Box::new(Hex));
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+
+    assert_eq!(
+        actual,
+        vec![
+            Record {
+                a: Some(String::from("a"))
+            },
+            Record { a: None },
+            Record {
+                a: Some(String::from("ff"))
+            },
+        ]
+    );
+}
+
+#[test]
+fn data_type_name_of_nested_fields() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        a: Vec<String>,
+    }
+
+    let field = Field::new(
+        "a",
+        DataType::List(Arc::new(Field::new("item", DataType::Int32, true))),
+        false,
+    );
+    let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+        Some(vec![Some(1), Some(16)]),
+        Some(vec![]),
+    ]);
+    let batch = batch(field, Arc::new(array));
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_custom_deserializer("Int32", || Box::new(Hex));
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+
+    assert_eq!(
+        actual,
+        vec![
+            Record {
+                a: vec![String::from("1"), String::from("10")]
+            },
+            Record { a: vec![] },
+        ]
+    );
+}
+
+#[test]
+fn extension_names_take_precedence_over_data_type_names() {
+    let batch = batch(hex_field("a"), Arc::new(Int32Array::from(vec![Some(42)])));
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_custom_deserializer("Int32", || Box::new(Constant(CustomValue::Null)))
+        .with_custom_deserializer("example.hex", || Box::new(Hex));
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        a: String,
+    }
+
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        vec![Record {
+            a: String::from("2a")
+        }]
+    );
+}
+
+fn unsupported_union() -> RecordBatch {
+    let fields = UnionFields::new(
+        vec![0, 2],
+        vec![
+            Field::new("A", DataType::Int32, false),
+            Field::new("B", DataType::Int64, false),
+        ],
+    );
+    let array = UnionArray::try_new(
+        fields.clone(),
+        ScalarBuffer::from(vec![0_i8, 2, 0]),
+        Some(ScalarBuffer::from(vec![0_i32, 0, 1])),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(Int64Array::from(vec![3])),
+        ],
+    )
+    .unwrap();
+    let field = Field::new("a", DataType::Union(fields, UnionMode::Dense), false);
+    batch(field, Arc::new(array))
+}
+
+#[test]
+fn unsupported_types_fail_without_custom_deserializer() {
+    #[derive(Debug, Deserialize)]
+    struct Record {
+        #[allow(dead_code)]
+        a: i64,
+    }
+
+    let batch = unsupported_union();
+    let deserializer = Deserializer::from_record_batch(&batch).unwrap();
+    let res = Vec::<Record>::deserialize(deserializer);
+    assert_error_contains(&res, "only unions with consecutive type ids");
+}
+
+#[test]
+fn unsupported_types_with_custom_deserializer() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Kind {
+        Constant,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        a: Kind,
+    }
+
+    let batch = unsupported_union();
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_custom_deserializer("Union", || {
+            Box::new(Constant(CustomValue::String(String::from("Constant"))))
+        });
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        vec![
+            Record { a: Kind::Constant },
+            Record { a: Kind::Constant },
+            Record { a: Kind::Constant },
+        ]
+    );
+
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_custom_deserializer("Union", || Box::new(Storage));
+    let res = Vec::<Record>::deserialize(deserializer);
+    assert_error_contains(&res, "The storage of the field cannot be read");
+    assert_error_contains(&res, "only unions with consecutive type ids");
+}
+
+#[test]
+fn map_values_as_structs() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Point {
+        x: i64,
+        y: Vec<bool>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        a: Point,
+    }
+
+    let value = CustomValue::Map(vec![
+        (CustomValue::String(String::from("x")), CustomValue::I64(3)),
+        (
+            CustomValue::String(String::from("y")),
+            CustomValue::List(vec![CustomValue::Bool(true), CustomValue::Bool(false)]),
+        ),
+    ]);
+    let batch = batch(hex_field("a"), Arc::new(Int32Array::from(vec![Some(0)])));
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_custom_deserializer("example.hex", move || Box::new(Constant(value.clone())));
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        vec![Record {
+            a: Point {
+                x: 3,
+                y: vec![true, false]
+            }
+        }]
+    );
+}
+
+#[test]
+fn type_names_can_be_used_with_fields_from_schemas() {
+    let fields = vec![Arc::new(hex_field("a")) as FieldRef];
+    let arrays = vec![Arc::new(Int32Array::from(vec![Some(7)])) as ArrayRef];
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        a: String,
+    }
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_custom_deserializer("example.hex", || Box::new(Hex));
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        vec![Record {
+            a: String::from("7")
+        }]
+    );
+}
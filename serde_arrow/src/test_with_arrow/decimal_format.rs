@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::ArrayRef, datatypes::FieldRef},
+    schema::SchemaLike,
+    DecimalFormat, Deserializer, DeserializerOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    value: Option<String>,
+    values: Vec<String>,
+}
+
+fn arrays() -> (Vec<FieldRef>, Vec<ArrayRef>) {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "Decimal128(10, 3)", "nullable": true},
+        {
+            "name": "values",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "Decimal128(10, 3)"}],
+        },
+    ]))
+    .unwrap();
+
+    let items = [
+        Record {
+            value: Some(String::from("1.5")),
+            values: vec![String::from("0"), String::from("-0.25")],
+        },
+        Record {
+            value: None,
+            values: vec![String::from("1200")],
+        },
+    ];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    (fields, arrays)
+}
+
+fn deserialize(format: Option<DecimalFormat>) -> Vec<Record> {
+    let (fields, arrays) = arrays();
+    let mut deserializer = Deserializer::from_arrow(&fields, &arrays).unwrap();
+    if let Some(format) = format {
+        deserializer =
+            deserializer.with_options(DeserializerOptions::default().decimal_format(format));
+    }
+    Vec::<Record>::deserialize(deserializer).unwrap()
+}
+
+fn record(value: Option<&str>, values: &[&str]) -> Record {
+    Record {
+        value: value.map(String::from),
+        values: values.iter().map(|value| String::from(*value)).collect(),
+    }
+}
+
+#[test]
+fn exact_by_default() {
+    assert_eq!(
+        deserialize(None),
+        [
+            record(Some("1.500"), &["0.000", "-0.250"]),
+            record(None, &["1200.000"]),
+        ]
+    );
+    assert_eq!(deserialize(Some(DecimalFormat::Exact)), deserialize(None));
+}
+
+#[test]
+fn normalized() {
+    assert_eq!(
+        deserialize(Some(DecimalFormat::Normalized)),
+        [
+            record(Some("1.5"), &["0", "-0.25"]),
+            record(None, &["1200"]),
+        ]
+    );
+}
+
+#[test]
+fn scientific() {
+    assert_eq!(
+        deserialize(Some(DecimalFormat::Scientific)),
+        [
+            record(Some("1.5e0"), &["0e0", "-2.5e-1"]),
+            record(None, &["1.2e3"]),
+        ]
+    );
+}
+
+#[test]
+fn self_describing_formats() {
+    let (fields, arrays) = arrays();
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().decimal_format(DecimalFormat::Normalized));
+    let actual = serde_json::Value::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        json!([
+            {"value": "1.5", "values": ["0", "-0.25"]},
+            {"value": null, "values": ["1200"]},
+        ])
+    );
+}
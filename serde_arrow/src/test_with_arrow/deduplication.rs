@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef, internal::testing::assert_error_contains,
+    schema::SchemaLike, utils::Item, ArrayBuilder, Deduplication, SerializerOptions,
+};
+
+use super::fixtures::fields;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Event {
+    id: u32,
+    op: String,
+    value: Option<i64>,
+}
+
+fn event(id: u32, op: &str, value: Option<i64>) -> Event {
+    Event {
+        id,
+        op: String::from(op),
+        value,
+    }
+}
+
+fn builder(deduplication: Deduplication) -> ArrayBuilder {
+    let fields = fields::<Event>();
+    ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().deduplication(deduplication))
+}
+
+fn build(builder: &mut ArrayBuilder) -> Vec<Event> {
+    let fields = fields::<Event>();
+    let arrays = builder.to_arrow().unwrap();
+    crate::from_arrow(&fields, &arrays).unwrap()
+}
+
+fn by_key(keys: &[&str], capacity: usize) -> Deduplication {
+    Deduplication::ByKey {
+        keys: keys.iter().map(|key| String::from(*key)).collect(),
+        capacity,
+    }
+}
+
+#[test]
+fn all_rows_are_kept_by_default() {
+    let mut builder = builder(Deduplication::None);
+    builder.push(event(1, "insert", Some(1))).unwrap();
+    builder.push(event(1, "insert", Some(1))).unwrap();
+
+    assert_eq!(builder.dropped_rows(), 0);
+    assert_eq!(build(&mut builder).len(), 2);
+}
+
+#[test]
+fn consecutive_duplicates_are_dropped() {
+    let mut builder = builder(Deduplication::Consecutive);
+    builder
+        .extend(&[
+            event(1, "insert", Some(1)),
+            event(1, "insert", Some(1)),
+            event(1, "insert", Some(1)),
+            event(1, "update", Some(2)),
+            event(1, "insert", Some(1)),
+            event(2, "insert", None),
+            event(2, "insert", None),
+        ])
+        .unwrap();
+
+    assert_eq!(builder.num_rows(), 4);
+    assert_eq!(builder.dropped_rows(), 3);
+    assert_eq!(
+        build(&mut builder),
+        vec![
+            event(1, "insert", Some(1)),
+            event(1, "update", Some(2)),
+            event(1, "insert", Some(1)),
+            event(2, "insert", None),
+        ]
+    );
+}
+
+#[test]
+fn duplicates_across_batches_are_dropped() {
+    let mut builder = builder(Deduplication::Consecutive);
+    builder.push(event(1, "insert", Some(1))).unwrap();
+    assert_eq!(build(&mut builder).len(), 1);
+
+    builder.push(event(1, "insert", Some(1))).unwrap();
+    builder.push(event(2, "insert", Some(1))).unwrap();
+    assert_eq!(build(&mut builder), vec![event(2, "insert", Some(1))]);
+    assert_eq!(builder.dropped_rows(), 1);
+}
+
+#[test]
+fn null_rows_interrupt_consecutive_duplicates() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32", "nullable": true},
+    ]))
+    .unwrap();
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().deduplication(Deduplication::Consecutive));
+
+    builder.push(Item(Some(1_u32))).unwrap();
+    builder.append_nulls(1).unwrap();
+    builder.push(Item(Some(1_u32))).unwrap();
+    assert_eq!(builder.num_rows(), 3);
+    assert_eq!(builder.dropped_rows(), 0);
+}
+
+#[test]
+fn duplicate_keys_are_dropped() {
+    let mut builder = builder(by_key(&["$.id", "$.op"], 100));
+    builder
+        .extend(&[
+            event(1, "insert", Some(1)),
+            event(2, "insert", Some(2)),
+            event(1, "insert", Some(3)),
+            event(1, "update", Some(4)),
+            event(2, "insert", None),
+            event(2, "delete", None),
+        ])
+        .unwrap();
+
+    assert_eq!(builder.dropped_rows(), 2);
+    assert_eq!(
+        build(&mut builder),
+        vec![
+            event(1, "insert", Some(1)),
+            event(2, "insert", Some(2)),
+            event(1, "update", Some(4)),
+            event(2, "delete", None),
+        ]
+    );
+}
+
+#[test]
+fn oldest_keys_are_evicted() {
+    let mut builder = builder(by_key(&["$.id"], 2));
+    for id in [1, 2, 1, 3, 1, 2, 2] {
+        builder.push(event(id, "insert", None)).unwrap();
+    }
+
+    let ids = build(&mut builder)
+        .into_iter()
+        .map(|event| event.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![1, 2, 3, 1, 2]);
+    assert_eq!(builder.dropped_rows(), 2);
+}
+
+#[test]
+fn keys_of_maps_are_independent_of_the_field_order() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "name", "data_type": "LargeUtf8", "nullable": true},
+    ]))
+    .unwrap();
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().deduplication(by_key(&["$.name"], 10)));
+
+    builder
+        .extend(json!([
+            {"id": 1, "name": "a"},
+            {"name": "a", "id": 2},
+            {"id": 3, "name": null},
+            {"id": 4},
+            {"id": 5},
+            {"id": 6, "name": null},
+        ]))
+        .unwrap();
+    assert_eq!(builder.num_rows(), 3);
+    assert_eq!(builder.dropped_rows(), 3);
+}
+
+#[test]
+fn dropped_rows_do_not_count_towards_max_rows() {
+    let fields = fields::<Event>();
+    let options = SerializerOptions::default()
+        .deduplication(Deduplication::Consecutive)
+        .max_rows(Some(1));
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(options);
+
+    builder.push(event(1, "insert", None)).unwrap();
+    builder.push(event(1, "insert", None)).unwrap();
+    assert_error_contains(&builder.push(event(2, "insert", None)), "limited to 1 rows");
+}
+
+#[test]
+fn failed_rows_are_not_remembered() {
+    let mut builder = builder(by_key(&["$.id"], 10));
+
+    #[derive(Serialize)]
+    struct Invalid {
+        id: u32,
+        op: Option<String>,
+    }
+
+    let res = builder.push(Invalid { id: 1, op: None });
+    assert!(res.is_err());
+
+    builder.push(event(1, "insert", None)).unwrap();
+    assert_eq!(builder.dropped_rows(), 0);
+}
+
+#[test]
+fn unknown_keys_are_rejected() {
+    let mut builder = builder(by_key(&["$.missing"], 10));
+    let res = builder.push(event(1, "insert", None));
+    assert_error_contains(&res, "no top-level field with this path");
+}
+
+#[test]
+fn invalid_configurations_are_rejected() {
+    let res = builder(by_key(&[], 10)).push(event(1, "insert", None));
+    assert_error_contains(&res, "no key fields given");
+
+    let res = builder(by_key(&["$.id"], 0)).push(event(1, "insert", None));
+    assert_error_contains(&res, "the capacity must be positive");
+}
+
+#[test]
+fn keys_require_structs_or_maps() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+    ]))
+    .unwrap();
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().deduplication(by_key(&["$.id"], 10)));
+
+    let res = builder.push((1_u32,));
+    assert_error_contains(&res, "records must be structs or maps, found tuple");
+}
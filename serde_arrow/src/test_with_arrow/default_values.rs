@@ -0,0 +1,243 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, ArrayRef, LargeStringArray, PrimitiveArray},
+        datatypes::{FieldRef, UInt32Type},
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, DEFAULT_VALUE_KEY},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Message {
+    id: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel: Option<String>,
+}
+
+/// The message before the `priority` and `channel` fields were added
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct OldMessage {
+    id: u32,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Priority {
+    #[allow(dead_code)]
+    priority: u32,
+}
+
+/// The message as read by consumers that require all fields
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct RequiredMessage {
+    id: u32,
+    priority: u32,
+    channel: String,
+}
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {
+            "name": "priority",
+            "data_type": "U32",
+            "nullable": true,
+            "metadata": {DEFAULT_VALUE_KEY: "5"},
+        },
+        {
+            "name": "channel",
+            "data_type": "LargeUtf8",
+            "metadata": {DEFAULT_VALUE_KEY: "\"email\""},
+        },
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn missing_fields_are_filled_with_defaults() {
+    let items = [
+        Message {
+            id: 0,
+            priority: Some(1),
+            channel: Some(String::from("sms")),
+        },
+        Message {
+            id: 1,
+            priority: None,
+            channel: None,
+        },
+    ];
+    let arrays = crate::to_arrow(&fields(), &items).unwrap();
+
+    let priority = arrays[1]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<UInt32Type>>()
+        .unwrap();
+    assert_eq!(priority.iter().collect::<Vec<_>>(), [Some(1), Some(5)]);
+
+    let round_tripped: Vec<RequiredMessage> = crate::from_arrow(&fields(), &arrays).unwrap();
+    assert_eq!(
+        round_tripped,
+        [
+            RequiredMessage {
+                id: 0,
+                priority: 1,
+                channel: String::from("sms"),
+            },
+            RequiredMessage {
+                id: 1,
+                priority: 5,
+                channel: String::from("email"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn old_messages_can_be_serialized() {
+    let items = [OldMessage { id: 0 }, OldMessage { id: 1 }];
+    let arrays = crate::to_arrow(&fields(), &items).unwrap();
+
+    let channel = arrays[2]
+        .as_any()
+        .downcast_ref::<LargeStringArray>()
+        .unwrap();
+    assert_eq!(channel.null_count(), 0);
+    assert_eq!(channel.value(1), "email");
+}
+
+#[test]
+fn null_values_are_replaced_by_defaults() {
+    let fields_without_defaults = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "priority", "data_type": "U32", "nullable": true},
+        {"name": "channel", "data_type": "LargeUtf8", "nullable": true},
+    ]))
+    .unwrap();
+    let items = [
+        Message {
+            id: 0,
+            priority: None,
+            channel: Some(String::from("sms")),
+        },
+        Message {
+            id: 1,
+            priority: Some(2),
+            channel: None,
+        },
+    ];
+    let arrays = crate::to_arrow(&fields_without_defaults, &items).unwrap();
+    assert_eq!(arrays[1].null_count(), 1);
+    assert_eq!(arrays[2].null_count(), 1);
+
+    let round_tripped: Vec<Message> = crate::from_arrow(&fields(), &arrays).unwrap();
+    assert_eq!(
+        round_tripped,
+        [
+            Message {
+                id: 0,
+                priority: Some(5),
+                channel: Some(String::from("sms")),
+            },
+            Message {
+                id: 1,
+                priority: Some(2),
+                channel: Some(String::from("email")),
+            },
+        ]
+    );
+}
+
+#[test]
+fn nested_defaults() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Outer {
+        inner: Vec<Inner>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Inner {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tags: Option<Vec<String>>,
+    }
+
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "inner",
+        "data_type": "LargeList",
+        "children": [{
+            "name": "element",
+            "data_type": "Struct",
+            "children": [{
+                "name": "tags",
+                "data_type": "LargeList",
+                "nullable": true,
+                "metadata": {DEFAULT_VALUE_KEY: "[\"a\", \"b\"]"},
+                "children": [{"name": "element", "data_type": "LargeUtf8"}],
+            }],
+        }],
+    }]))
+    .unwrap();
+
+    let items = [Outer {
+        inner: vec![
+            Inner { tags: None },
+            Inner {
+                tags: Some(vec![String::from("c")]),
+            },
+        ],
+    }];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let round_tripped: Vec<Outer> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(
+        round_tripped,
+        [Outer {
+            inner: vec![
+                Inner {
+                    tags: Some(vec![String::from("a"), String::from("b")]),
+                },
+                Inner {
+                    tags: Some(vec![String::from("c")]),
+                },
+            ],
+        }]
+    );
+}
+
+#[test]
+fn invalid_defaults_are_an_error() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "priority",
+        "data_type": "U32",
+        "metadata": {DEFAULT_VALUE_KEY: "not json"},
+    }]))
+    .unwrap();
+    let res = crate::to_arrow(&fields, &[OldMessage { id: 0 }]);
+    assert_error_contains(&res, "Invalid default value");
+
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "priority",
+        "data_type": "U32",
+        "metadata": {DEFAULT_VALUE_KEY: "\"high\""},
+    }]))
+    .unwrap();
+    let res = crate::to_arrow(&fields, &[OldMessage { id: 0 }]);
+    assert_error_contains(&res, "priority");
+
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "priority",
+        "data_type": "U32",
+        "nullable": true,
+        "metadata": {DEFAULT_VALUE_KEY: "\"high\""},
+    }]))
+    .unwrap();
+    let arrays: Vec<ArrayRef> = vec![Arc::new(PrimitiveArray::<UInt32Type>::from(vec![None]))];
+    let res = crate::from_arrow::<Vec<Priority>, _>(&fields, &arrays);
+    assert_error_contains(&res, "Cannot deserialize the default value");
+    assert_error_contains(&res, "$.priority");
+}
@@ -0,0 +1,147 @@
+use serde_json::{json, Value};
+
+use crate::{
+    _impl::fuzz::deserialize_raw,
+    schema::{SchemaLike, SerdeArrowSchema},
+};
+
+fn schema(field: Value) -> SerdeArrowSchema {
+    SerdeArrowSchema::from_value(json!([field])).unwrap()
+}
+
+#[test]
+fn binary_with_decreasing_offsets() {
+    let schema = schema(json!({"name": "a", "data_type": "Binary"}));
+    let buffer = [1, 0, 8, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 1, 2, 3, 4];
+    assert!(deserialize_raw(&schema, &buffer).is_err());
+}
+
+#[test]
+fn list_with_decreasing_offsets() {
+    let schema = schema(json!({
+        "name": "a",
+        "data_type": "List",
+        "children": [{"name": "element", "data_type": "I16"}],
+    }));
+    let buffer = [1, 0, 8, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 4, 0, 1, 0, 2, 0];
+    assert!(deserialize_raw(&schema, &buffer).is_err());
+}
+
+#[test]
+fn map_with_decreasing_offsets() {
+    let schema = schema(json!({
+        "name": "a",
+        "data_type": "Map",
+        "children": [{"name": "entries", "data_type": "Struct", "children": [
+            {"name": "key", "data_type": "U8"},
+            {"name": "value", "data_type": "U8"},
+        ]}],
+    }));
+    let buffer = [
+        1, 0, 8, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 2,
+    ];
+    assert!(deserialize_raw(&schema, &buffer).is_err());
+}
+
+#[test]
+fn struct_child_shorter_than_the_struct() {
+    let schema = schema(json!({
+        "name": "a",
+        "data_type": "Struct",
+        "children": [{"name": "x", "data_type": "U32"}],
+    }));
+    let buffer = [1, 0, 1, 0, 0, 0];
+    assert!(deserialize_raw(&schema, &buffer).is_err());
+}
+
+#[test]
+fn date32_out_of_range() {
+    let schema = schema(json!({"name": "a", "data_type": "Date32"}));
+    let buffer = [1, 0, 4, 0, 255, 255, 255, 127];
+    assert!(deserialize_raw(&schema, &buffer).is_err());
+}
+
+#[test]
+fn decimal_with_extreme_scale() {
+    let schema = schema(json!({"name": "a", "data_type": "Decimal128(38, -128)"}));
+    let mut buffer = vec![1, 0, 16, 0];
+    buffer.extend(i128::MIN.to_le_bytes());
+    deserialize_raw(&schema, &buffer).unwrap();
+}
+
+#[test]
+fn fixed_size_binary_with_zero_sized_elements() {
+    let schema = schema(json!({"name": "a", "data_type": "FixedSizeBinary(0)"}));
+    assert!(deserialize_raw(&schema, &[1, 0]).is_err());
+}
+
+#[test]
+fn random_buffers_do_not_panic() {
+    let fields = [
+        json!({"name": "a", "data_type": "Bool"}),
+        json!({"name": "a", "data_type": "I32"}),
+        json!({"name": "a", "data_type": "F16"}),
+        json!({"name": "a", "data_type": "Date32"}),
+        json!({"name": "a", "data_type": "Date64"}),
+        json!({"name": "a", "data_type": "Timestamp(Second, Some(\"UTC\"))"}),
+        json!({"name": "a", "data_type": "Time64(Nanosecond)"}),
+        json!({"name": "a", "data_type": "Duration(Second)"}),
+        json!({"name": "a", "data_type": "Decimal128(5, 2)"}),
+        json!({"name": "a", "data_type": "Utf8"}),
+        json!({"name": "a", "data_type": "LargeBinary"}),
+        json!({"name": "a", "data_type": "FixedSizeBinary(3)"}),
+        json!({"name": "a", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U8"},
+            {"name": "value", "data_type": "Utf8"},
+        ]}),
+        json!({"name": "a", "data_type": "List", "children": [
+            {"name": "element", "data_type": "I16"},
+        ]}),
+        json!({"name": "a", "data_type": "FixedSizeList(2)", "children": [
+            {"name": "element", "data_type": "U8"},
+        ]}),
+        json!({"name": "a", "data_type": "Struct", "children": [
+            {"name": "x", "data_type": "U8"},
+            {"name": "y", "data_type": "Bool", "nullable": true},
+        ]}),
+        json!({"name": "a", "data_type": "Map", "children": [
+            {"name": "entries", "data_type": "Struct", "children": [
+                {"name": "key", "data_type": "Utf8"},
+                {"name": "value", "data_type": "I32", "nullable": true},
+            ]},
+        ]}),
+        json!({"name": "a", "data_type": "Union", "children": [
+            {"name": "A", "data_type": "U8"},
+            {"name": "B", "data_type": "Utf8"},
+            {"name": "C", "data_type": "Null"},
+        ]}),
+    ];
+
+    // xorshift, small values are more likely to form valid lengths
+    let mut state = 0x1234_5678_9abc_def1_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    for field in fields {
+        for nullable in [false, true] {
+            let mut field = field.clone();
+            field["nullable"] = json!(nullable);
+            let schema = schema(field);
+
+            for _ in 0..500 {
+                let len = (next() % 64) as usize;
+                let buffer = (0..len)
+                    .map(|_| match next() {
+                        r if r % 3 == 0 => (r >> 8) as u8,
+                        r => ((r >> 8) % 6) as u8,
+                    })
+                    .collect::<Vec<_>>();
+                let _ = deserialize_raw(&schema, &buffer);
+            }
+        }
+    }
+}
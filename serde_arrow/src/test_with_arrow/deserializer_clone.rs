@@ -0,0 +1,180 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::RecordBatch, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+    CustomArrayDeserializer, CustomSource, CustomValue, DecimalFormat, Deserializer,
+    DeserializerOptions, Result,
+};
+
+use super::fixtures::fields;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    name: Option<String>,
+    values: Vec<i64>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Ids {
+    id: u32,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Names<'a> {
+    name: Option<&'a str>,
+}
+
+fn records() -> Vec<Record> {
+    (0..50)
+        .map(|id| Record {
+            id,
+            name: (id % 3 != 0).then(|| format!("name-{id}")),
+            values: (0..i64::from(id % 4)).collect(),
+        })
+        .collect()
+}
+
+fn batch() -> RecordBatch {
+    let fields = fields::<Record>();
+    crate::to_record_batch(&fields, &records()).unwrap()
+}
+
+#[test]
+fn clones_deserialize_different_projections() {
+    let batch = batch();
+    let deserializer = Deserializer::from_record_batch(&batch).unwrap();
+
+    let ids = Vec::<Ids>::deserialize(deserializer.clone()).unwrap();
+    let names = Vec::<Names>::deserialize(deserializer.clone()).unwrap();
+    let all = Vec::<Record>::deserialize(deserializer).unwrap();
+
+    assert_eq!(all, records());
+    assert_eq!(ids.len(), 50);
+    assert_eq!(ids[7], Ids { id: 7 });
+    assert_eq!(names[0], Names { name: None });
+    assert_eq!(
+        names[1],
+        Names {
+            name: Some("name-1")
+        }
+    );
+}
+
+#[test]
+fn clones_deserialize_on_different_threads() {
+    let batch = batch();
+    let deserializer = Deserializer::from_record_batch(&batch).unwrap();
+
+    let results = std::thread::scope(|scope| {
+        let handles = (0..4)
+            .map(|_| {
+                let deserializer = deserializer.clone();
+                scope.spawn(move || Vec::<Record>::deserialize(deserializer))
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Result<Vec<_>>>()
+    })
+    .unwrap();
+
+    for result in results {
+        assert_eq!(result, records());
+    }
+}
+
+#[test]
+fn clones_keep_the_options() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Value {
+        value: String,
+    }
+
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "Decimal128(5, 2)"},
+    ]))
+    .unwrap();
+    let batch = crate::to_record_batch(&fields, &json!([{"value": "1.50"}])).unwrap();
+
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_options(DeserializerOptions::default().decimal_format(DecimalFormat::Normalized));
+    let actual = Vec::<Value>::deserialize(deserializer.clone()).unwrap();
+    assert_eq!(
+        actual,
+        vec![Value {
+            value: String::from("1.5")
+        }]
+    );
+
+    let actual = Vec::<Value>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        vec![Value {
+            value: String::from("1.5")
+        }]
+    );
+}
+
+#[test]
+fn clones_recreate_custom_deserializers() {
+    struct Counter;
+
+    impl CustomArrayDeserializer for Counter {
+        fn next(&mut self, source: &mut CustomSource<'_, '_>) -> Result<CustomValue> {
+            let value = source.read::<u32>()?;
+            Ok(CustomValue::String(format!("#{value}")))
+        }
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Labels {
+        id: String,
+    }
+
+    let batch = batch();
+    let created = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&created);
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_custom_deserializer("UInt32", move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            Box::new(Counter)
+        });
+    assert_eq!(created.load(Ordering::Relaxed), 1);
+
+    let first = Vec::<Labels>::deserialize(deserializer.clone()).unwrap();
+    assert_eq!(created.load(Ordering::Relaxed), 2);
+
+    let second = Vec::<Labels>::deserialize(deserializer).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(first[3].id, "#3");
+}
+
+#[test]
+fn clones_report_construction_errors() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+    ]))
+    .unwrap();
+    let batch = crate::to_record_batch(&fields, &json!([{"id": 1}])).unwrap();
+
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_options(DeserializerOptions::default().override_strategy("$.missing", None));
+
+    let res = Vec::<Ids>::deserialize(deserializer.clone());
+    assert_error_contains(&res, "$.missing");
+    let res = Vec::<Ids>::deserialize(deserializer);
+    assert_error_contains(&res, "$.missing");
+}
@@ -0,0 +1,188 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, ArrayRef, FixedSizeBinaryArray, MapArray, StringArray},
+        datatypes::FieldRef,
+    },
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+    utils::Item,
+    ArrayBuilder, SerializerOptions,
+};
+
+fn map_fields(key_type: &str) -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([{
+        "name": "item",
+        "data_type": "Map",
+        "children": [{
+            "name": "entries",
+            "data_type": "Struct",
+            "children": [
+                {"name": "key", "data_type": key_type},
+                {"name": "value", "data_type": "U32"},
+            ],
+        }],
+    }]))
+    .unwrap()
+}
+
+fn build<T: Serialize>(fields: &[FieldRef], items: T) -> crate::Result<Vec<ArrayRef>> {
+    let mut builder = ArrayBuilder::from_arrow(fields)?
+        .with_options(SerializerOptions::default().deterministic(true));
+    builder.extend(items)?;
+    builder.to_arrow()
+}
+
+fn keys(array: &ArrayRef) -> Vec<String> {
+    let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+    let keys = array.keys().as_any().downcast_ref::<StringArray>().unwrap();
+    keys.iter().map(|key| key.unwrap().to_owned()).collect()
+}
+
+#[test]
+fn hash_map_entries_are_sorted() {
+    let fields = map_fields("Utf8");
+
+    let names = ["d", "a", "c", "b", "e", "f", "h", "g"];
+    let forward = names
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| (name.to_string(), idx as u32))
+        .collect::<HashMap<_, _>>();
+    let backward = names
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(idx, name)| (name.to_string(), idx as u32))
+        .collect::<HashMap<_, _>>();
+
+    let forward = build(&fields, [Item(forward)]).unwrap();
+    let backward = build(&fields, [Item(backward)]).unwrap();
+
+    assert_eq!(forward, backward);
+    assert_eq!(keys(&forward[0]), ["a", "b", "c", "d", "e", "f", "g", "h"]);
+}
+
+#[test]
+fn integer_keys_are_sorted_by_value() {
+    let fields = map_fields("I64");
+    let items = [Item(HashMap::from([(10_i64, 0_u32), (-2, 1), (3, 2)]))];
+
+    let arrays = build(&fields, items).unwrap();
+    let expected = crate::to_arrow(
+        &fields,
+        [Item(BTreeMap::from([(-2_i64, 1_u32), (3, 2), (10, 0)]))],
+    )
+    .unwrap();
+    assert_eq!(arrays, expected);
+}
+
+#[test]
+fn nested_maps_are_sorted() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "item",
+        "data_type": "LargeList",
+        "children": [{
+            "name": "element",
+            "data_type": "Map",
+            "children": [{
+                "name": "entries",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "key", "data_type": "LargeUtf8"},
+                    {"name": "value", "data_type": "U32"},
+                ],
+            }],
+        }],
+    }]))
+    .unwrap();
+
+    let items = [Item(vec![
+        HashMap::from([(String::from("b"), 1_u32), (String::from("a"), 2)]),
+        HashMap::from([(String::from("z"), 3_u32), (String::from("y"), 4)]),
+    ])];
+    let expected = [Item(vec![
+        BTreeMap::from([(String::from("a"), 2_u32), (String::from("b"), 1)]),
+        BTreeMap::from([(String::from("y"), 4_u32), (String::from("z"), 3)]),
+    ])];
+
+    let arrays = build(&fields, items).unwrap();
+    assert_eq!(arrays, crate::to_arrow(&fields, expected).unwrap());
+}
+
+#[test]
+fn unsupported_keys() {
+    let fields = map_fields("F64");
+    let items = [Item(MapWrapper(vec![(1.0, 0), (0.5, 1)]))];
+
+    let res = build(&fields, items);
+    assert_error_contains(&res, "Cannot compare map keys");
+}
+
+#[test]
+fn builder_recovers_after_failed_map() {
+    let fields = map_fields("F64");
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().deterministic(true));
+
+    let invalid = MapWrapper(vec![(1.0, 0), (0.5, 1)]);
+    assert!(builder.push(Item(invalid)).is_err());
+
+    let valid = MapWrapper(vec![(1.0, 0)]);
+    builder.push(Item(valid)).unwrap();
+}
+
+#[test]
+fn fixed_size_binary_nulls_are_zeroed() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "item", "data_type": "FixedSizeBinary(3)", "nullable": true},
+    ]))
+    .unwrap();
+    let items = [Item(Some(serde_bytes::ByteBuf::from(b"abc"))), Item(None)];
+
+    let arrays = build(&fields, items).unwrap();
+    let array = arrays[0]
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    assert!(array.is_null(1));
+    assert_eq!(array.value(1), &[0, 0, 0]);
+}
+
+#[test]
+fn dictionaries_use_order_of_first_occurrence() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "item",
+        "data_type": "Dictionary",
+        "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "Utf8"},
+        ],
+    }]))
+    .unwrap();
+    let items = [Item("b"), Item("a"), Item("b"), Item("c")];
+
+    let first = build(&fields, &items).unwrap();
+    let second = build(&fields, &items).unwrap();
+    assert_eq!(first, second);
+}
+
+/// A map with float keys, which cannot be ordered
+struct MapWrapper(Vec<(f64, u32)>);
+
+impl Serialize for MapWrapper {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::{DataType, FieldRef},
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+    ArrayBuilder, Deserializer, SerializerOptions, Session,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: Option<String>,
+}
+
+fn fields(key: &str) -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([{
+        "name": "a",
+        "data_type": "Dictionary",
+        "nullable": true,
+        "children": [
+            {"name": "key", "data_type": key},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ],
+    }]))
+    .unwrap()
+}
+
+fn items(n: usize) -> Vec<Record> {
+    let mut items = (0..n)
+        .map(|idx| Record {
+            a: Some(idx.to_string()),
+        })
+        .collect::<Vec<_>>();
+    items.push(Record { a: None });
+    items.push(Record {
+        a: Some(String::from("0")),
+    });
+    items
+}
+
+fn dictionary(key: DataType) -> DataType {
+    DataType::Dictionary(Box::new(key), Box::new(DataType::LargeUtf8))
+}
+
+#[test]
+fn key_widths_selected_by_the_schema() {
+    for (key, data_type) in [
+        ("U8", DataType::UInt8),
+        ("I16", DataType::Int16),
+        ("U64", DataType::UInt64),
+        ("I64", DataType::Int64),
+    ] {
+        let items = items(3);
+        let batch = crate::to_record_batch(&fields(key), &items).unwrap();
+        assert_eq!(batch.schema().field(0).data_type(), &dictionary(data_type));
+
+        let actual =
+            Vec::<Record>::deserialize(Deserializer::from_record_batch(&batch).unwrap()).unwrap();
+        assert_eq!(actual, items);
+    }
+}
+
+#[test]
+fn overflowing_keys_fail_by_default() {
+    let res = crate::to_record_batch(&fields("I8"), &items(200));
+    assert_error_contains(&res, "out of range integral type conversion");
+    assert_error_contains(&res, "field: \"$.a.key\"");
+}
+
+#[test]
+fn overflowing_keys_are_widened() {
+    for (key, data_type) in [
+        ("U8", DataType::UInt16),
+        ("I8", DataType::Int16),
+        ("U16", DataType::UInt16),
+    ] {
+        let items = items(300);
+        let mut builder = ArrayBuilder::from_arrow(&fields(key))
+            .unwrap()
+            .with_options(SerializerOptions::default().promote_dictionary_keys(true));
+        builder.extend(&items).unwrap();
+        let batch = builder.to_record_batch().unwrap();
+        assert_eq!(batch.schema().field(0).data_type(), &dictionary(data_type));
+
+        let actual =
+            Vec::<Record>::deserialize(Deserializer::from_record_batch(&batch).unwrap()).unwrap();
+        assert_eq!(actual, items);
+    }
+}
+
+#[test]
+fn widened_keys_are_kept_for_later_batches() {
+    let mut builder = ArrayBuilder::from_arrow(&fields("U8"))
+        .unwrap()
+        .with_options(SerializerOptions::default().promote_dictionary_keys(true));
+    let batches = builder
+        .to_record_batches([items(2), items(260), items(1)])
+        .unwrap();
+
+    let data_types = batches
+        .iter()
+        .map(|batch| batch.schema().field(0).data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![
+            dictionary(DataType::UInt8),
+            dictionary(DataType::UInt16),
+            dictionary(DataType::UInt16),
+        ]
+    );
+
+    let actual =
+        Vec::<Record>::deserialize(Deserializer::from_record_batch(&batches[2]).unwrap()).unwrap();
+    assert_eq!(actual, items(1));
+}
+
+#[test]
+fn sessions_use_the_widened_keys() {
+    let mut session = Session::from_arrow_fields(&fields("U8"))
+        .unwrap()
+        .with_serializer_options(SerializerOptions::default().promote_dictionary_keys(true));
+
+    let items = items(300);
+    let batch = session.to_record_batch(&items).unwrap();
+    assert_eq!(
+        batch.schema().field(0).data_type(),
+        &dictionary(DataType::UInt16)
+    );
+
+    let actual: Vec<Record> = session.from_record_batch(&batch).unwrap();
+    assert_eq!(actual, items);
+}
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, DictionaryArray, StringArray, StructArray},
+        datatypes::{FieldRef, UInt32Type},
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, Strategy},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Item {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct OptionalItem {
+    name: Option<String>,
+}
+
+fn fields(children: serde_json::Value) -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([{
+        "name": "name",
+        "data_type": "Struct",
+        "nullable": true,
+        "strategy": "DictionaryOverflow(8)",
+        "children": children,
+    }]))
+    .unwrap()
+}
+
+fn default_children() -> serde_json::Value {
+    json!([
+        {"name": "values", "data_type": "Dictionary", "nullable": true, "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "Utf8"},
+        ]},
+        {"name": "overflow", "data_type": "Utf8", "nullable": true},
+    ])
+}
+
+#[test]
+fn long_strings_are_stored_in_the_overflow_field() {
+    let items = Vec::from(
+        ["short", "a rather long string", "short", "exactly8"].map(|name| Item {
+            name: String::from(name),
+        }),
+    );
+    let fields = fields(default_children());
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let array = arrays[0].as_any().downcast_ref::<StructArray>().unwrap();
+    let values = array
+        .column(0)
+        .as_any()
+        .downcast_ref::<DictionaryArray<UInt32Type>>()
+        .unwrap();
+    let overflow = array
+        .column(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+
+    assert_eq!(values.values().len(), 2);
+    assert_eq!(
+        (0..4).map(|idx| values.is_valid(idx)).collect::<Vec<_>>(),
+        [true, false, true, true],
+    );
+    assert_eq!(
+        overflow.iter().collect::<Vec<_>>(),
+        [None, Some("a rather long string"), None, None],
+    );
+
+    let round_tripped: Vec<Item> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_tripped, items);
+}
+
+#[test]
+fn null_values_are_stored_as_null_structs() {
+    let items = Vec::from(
+        [Some("short"), None, Some("a rather long string"), None].map(|name| OptionalItem {
+            name: name.map(String::from),
+        }),
+    );
+    let fields = fields(default_children());
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let array = arrays[0].as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(array.null_count(), 2);
+
+    let round_tripped: Vec<OptionalItem> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_tripped, items);
+}
+
+#[test]
+fn null_values_require_options() {
+    let items = [OptionalItem { name: None }];
+    let fields = fields(default_children());
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let res = crate::from_arrow::<Vec<Item>, _>(&fields, &arrays);
+    assert_error_contains(&res, "Cannot deserialize a null value into a string");
+}
+
+#[test]
+fn invalid_children_are_rejected() {
+    let res = Vec::<FieldRef>::from_value(json!([{
+        "name": "name",
+        "data_type": "Struct",
+        "strategy": "DictionaryOverflow(8)",
+        "children": [
+            {"name": "values", "data_type": "Utf8", "nullable": true},
+            {"name": "overflow", "data_type": "Utf8", "nullable": true},
+        ],
+    }]));
+    assert_error_contains(&res, "must have a nullable string dictionary");
+
+    let res = Vec::<FieldRef>::from_value(json!([{
+        "name": "name",
+        "data_type": "Struct",
+        "strategy": "DictionaryOverflow(8)",
+        "children": [
+            {"name": "values", "data_type": "Dictionary", "nullable": true, "children": [
+                {"name": "key", "data_type": "U32"},
+                {"name": "value", "data_type": "Utf8"},
+            ]},
+            {"name": "overflow", "data_type": "Utf8"},
+        ],
+    }]));
+    assert_error_contains(&res, "must have a nullable string dictionary");
+}
+
+#[test]
+fn strategy_repr() {
+    let strategy: Strategy = "DictionaryOverflow(8)".parse().unwrap();
+    assert_eq!(strategy, Strategy::DictionaryOverflow { max_len: 8 });
+    assert_eq!(strategy.to_string(), "DictionaryOverflow(8)");
+
+    assert_error_contains(
+        &"DictionaryOverflow(-1)".parse::<Strategy>(),
+        "Invalid maximum length",
+    );
+}
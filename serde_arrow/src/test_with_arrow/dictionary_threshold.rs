@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    schema::{SchemaLike, TracingOptions},
+    utils::Item,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    country: String,
+    name: String,
+    comment: Option<String>,
+}
+
+fn items() -> Vec<Record> {
+    ["alice", "bob", "carol", "dave"]
+        .into_iter()
+        .enumerate()
+        .map(|(idx, name)| Record {
+            country: String::from(if idx % 2 == 0 { "DE" } else { "FR" }),
+            name: String::from(name),
+            comment: None,
+        })
+        .collect()
+}
+
+fn expected(country: serde_json::Value, name: serde_json::Value) -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        country,
+        name,
+        {"name": "comment", "data_type": "Null", "nullable": true},
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn low_cardinality_fields_are_dictionary_encoded() {
+    let items = items();
+    let options = TracingOptions::default()
+        .allow_null_fields(true)
+        .dictionary_threshold(Some(0.5));
+    let fields = Vec::<FieldRef>::from_samples(&items, options).unwrap();
+
+    assert_eq!(
+        fields,
+        expected(
+            json!({"name": "country", "data_type": "Dictionary", "children": [
+                {"name": "key", "data_type": "U32"},
+                {"name": "value", "data_type": "LargeUtf8"},
+            ]}),
+            json!({"name": "name", "data_type": "LargeUtf8"}),
+        ),
+    );
+
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let actual: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn without_threshold_no_fields_are_dictionary_encoded() {
+    let options = TracingOptions::default().allow_null_fields(true);
+    let fields = Vec::<FieldRef>::from_samples(items(), options).unwrap();
+
+    assert_eq!(
+        fields,
+        expected(
+            json!({"name": "country", "data_type": "LargeUtf8"}),
+            json!({"name": "name", "data_type": "LargeUtf8"}),
+        ),
+    );
+}
+
+#[test]
+fn global_switch_takes_precedence() {
+    let options = TracingOptions::default()
+        .allow_null_fields(true)
+        .string_dictionary_encoding(true)
+        .dictionary_threshold(Some(0.0));
+    let fields = Vec::<FieldRef>::from_samples(items(), options).unwrap();
+
+    let dictionary = |name: &str| {
+        json!({"name": name, "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]})
+    };
+    assert_eq!(fields, expected(dictionary("country"), dictionary("name")));
+}
+
+#[test]
+fn threshold_uses_the_value_type_of_the_options() {
+    let options = TracingOptions::default()
+        .allow_null_fields(true)
+        .default_string_type(crate::schema::StringType::Utf8)
+        .dictionary_threshold(Some(1.0));
+    let fields = Vec::<FieldRef>::from_samples(items(), options).unwrap();
+
+    let dictionary = |name: &str| {
+        json!({"name": name, "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "Utf8"},
+        ]})
+    };
+    assert_eq!(fields, expected(dictionary("country"), dictionary("name")));
+}
+
+#[test]
+fn long_unique_values_prevent_dictionary_encoding() {
+    let long = "error: ".repeat(10);
+    let items = [Item("ok"), Item("ok"), Item("ok"), Item(long.as_str())];
+
+    // half of the values are distinct, but the distinct values make up most
+    // of the bytes
+    let fields = Vec::<FieldRef>::from_samples(
+        &items,
+        TracingOptions::default().dictionary_threshold(Some(0.5)),
+    )
+    .unwrap();
+    assert_eq!(
+        fields,
+        Vec::<FieldRef>::from_value(json!([{"name": "item", "data_type": "LargeUtf8"}])).unwrap(),
+    );
+}
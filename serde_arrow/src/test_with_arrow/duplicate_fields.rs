@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{
+    _impl::arrow::{array::RecordBatch, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::{DuplicateFieldPolicy, SchemaLike, TracingOptions},
+    ArrayBuilder, SerializerOptions,
+};
+
+/// A map with repeated keys, traced as a struct
+struct RepeatedKeys(u32, u32, u32);
+
+impl Serialize for RepeatedKeys {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("a", &self.0)?;
+        map.serialize_entry("a", &self.1)?;
+        map.serialize_entry("b", &self.2)?;
+        map.end()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RenamedFields {
+    a: u32,
+    #[serde(rename = "a")]
+    b: String,
+}
+
+fn build<T: Serialize>(policy: DuplicateFieldPolicy, items: &[T]) -> crate::Result<RecordBatch> {
+    let tracing_options = TracingOptions::default().duplicate_fields(policy);
+    let fields = Vec::<FieldRef>::from_samples(items, tracing_options)?;
+    let mut builder = ArrayBuilder::from_arrow(&fields)?
+        .with_options(SerializerOptions::default().duplicate_fields(policy));
+    builder.extend(items)?;
+    builder.to_record_batch()
+}
+
+#[test]
+fn suffixed_fields_are_written() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Expected {
+        a: u32,
+        a_1: u32,
+        b: u32,
+    }
+
+    let items = [RepeatedKeys(1, 2, 3), RepeatedKeys(4, 5, 6)];
+    let batch = build(DuplicateFieldPolicy::Suffix, &items).unwrap();
+    let actual: Vec<Expected> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        actual,
+        vec![
+            Expected { a: 1, a_1: 2, b: 3 },
+            Expected { a: 4, a_1: 5, b: 6 },
+        ],
+    );
+}
+
+#[test]
+fn suffixed_fields_of_renamed_struct_fields_are_written() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Expected {
+        a: u32,
+        a_1: String,
+    }
+
+    let items = [RenamedFields {
+        a: 1,
+        b: String::from("x"),
+    }];
+    let batch = build(DuplicateFieldPolicy::Suffix, &items).unwrap();
+    let actual: Vec<Expected> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        actual,
+        vec![Expected {
+            a: 1,
+            a_1: String::from("x"),
+        }],
+    );
+}
+
+#[test]
+fn merged_fields_keep_the_first_value() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Expected {
+        a: u32,
+        b: u32,
+    }
+
+    let items = [RepeatedKeys(1, 2, 3), RepeatedKeys(4, 5, 6)];
+    let batch = build(DuplicateFieldPolicy::Merge, &items).unwrap();
+    let actual: Vec<Expected> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        actual,
+        vec![Expected { a: 1, b: 3 }, Expected { a: 4, b: 6 }],
+    );
+}
+
+#[test]
+fn duplicate_fields_fail_by_default() {
+    let fields = Vec::<FieldRef>::from_samples(
+        &[RepeatedKeys(1, 2, 3)],
+        TracingOptions::default().duplicate_fields(DuplicateFieldPolicy::Suffix),
+    )
+    .unwrap();
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    let res = builder.push(RepeatedKeys(1, 2, 3));
+    assert_error_contains(&res, "Duplicate field a");
+}
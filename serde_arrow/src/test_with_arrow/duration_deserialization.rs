@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::ArrayRef, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+    utils::Item,
+    Deserializer, DeserializerOptions, DurationFormat,
+};
+
+fn arrays(unit: &str, values: &[Option<i64>]) -> (Vec<FieldRef>, Vec<ArrayRef>) {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "item", "data_type": format!("Duration({unit})"), "nullable": true},
+    ]))
+    .unwrap();
+    let items = values.iter().map(|value| Item(*value)).collect::<Vec<_>>();
+    let arrays = crate::to_arrow(&fields, items).unwrap();
+    (fields, arrays)
+}
+
+fn deserialize<'de, T: Deserialize<'de>>(
+    fields: &[FieldRef],
+    arrays: &'de [ArrayRef],
+    options: DeserializerOptions,
+) -> crate::Result<Vec<T>> {
+    let deserializer = Deserializer::from_arrow(fields, arrays)?.with_options(options);
+    Vec::<T>::deserialize(deserializer)
+}
+
+#[test]
+fn std_durations() {
+    let (fields, arrays) = arrays("Millisecond", &[Some(1_500), None, Some(0)]);
+    let actual: Vec<Item<Option<Duration>>> =
+        deserialize(&fields, &arrays, DeserializerOptions::default()).unwrap();
+    assert_eq!(
+        actual,
+        vec![
+            Item(Some(Duration::from_millis(1_500))),
+            Item(None),
+            Item(Some(Duration::ZERO)),
+        ]
+    );
+}
+
+#[test]
+fn std_durations_with_nanoseconds() {
+    let (fields, arrays) = arrays("Nanosecond", &[Some(2_000_000_003)]);
+    let actual: Vec<Item<Duration>> =
+        deserialize(&fields, &arrays, DeserializerOptions::default()).unwrap();
+    assert_eq!(actual, vec![Item(Duration::new(2, 3))]);
+}
+
+#[test]
+fn negative_std_durations_are_rejected() {
+    let (fields, arrays) = arrays("Second", &[Some(-1)]);
+    let res: crate::Result<Vec<Item<Duration>>> =
+        deserialize(&fields, &arrays, DeserializerOptions::default());
+    assert_error_contains(&res, "Cannot deserialize negative duration -1");
+}
+
+#[test]
+fn integer_counts_keep_the_sign() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        item: i32,
+    }
+
+    let (fields, arrays) = arrays("Microsecond", &[Some(-5), Some(7)]);
+    let actual: Vec<Record> =
+        deserialize(&fields, &arrays, DeserializerOptions::default()).unwrap();
+    assert_eq!(actual, vec![Record { item: -5 }, Record { item: 7 }]);
+
+    let res: crate::Result<Vec<Item<u32>>> =
+        deserialize(&fields, &arrays, DeserializerOptions::default());
+    assert_error_contains(&res, "Duration -5 is out of range for u32");
+}
+
+#[test]
+fn chrono_durations_from_counts() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        #[serde(deserialize_with = "milliseconds")]
+        item: chrono::Duration,
+    }
+
+    fn milliseconds<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<chrono::Duration, D::Error> {
+        i64::deserialize(deserializer).map(chrono::Duration::milliseconds)
+    }
+
+    let (fields, arrays) = arrays("Millisecond", &[Some(-1_500)]);
+    let actual: Vec<Record> =
+        deserialize(&fields, &arrays, DeserializerOptions::default()).unwrap();
+    assert_eq!(
+        actual,
+        vec![Record {
+            item: chrono::Duration::milliseconds(-1_500)
+        }]
+    );
+}
+
+#[test]
+fn iso8601_strings_by_default() {
+    let (fields, arrays) = arrays("Millisecond", &[Some(90_500), Some(-1)]);
+    let actual: Vec<Item<String>> =
+        deserialize(&fields, &arrays, DeserializerOptions::default()).unwrap();
+    assert_eq!(
+        actual,
+        vec![
+            Item(String::from("PT90.500s")),
+            Item(String::from("-PT0.001s"))
+        ]
+    );
+}
+
+#[test]
+fn humantime_strings() {
+    let (fields, arrays) = arrays(
+        "Nanosecond",
+        &[
+            Some(90_500_000_000),
+            Some(-(86_400_000_000_000 + 3_600_000_000_000 + 2_001)),
+            Some(0),
+        ],
+    );
+    let options = DeserializerOptions::default().duration_format(DurationFormat::Humantime);
+    let actual: Vec<Item<String>> = deserialize(&fields, &arrays, options).unwrap();
+    assert_eq!(
+        actual,
+        vec![
+            Item(String::from("1m 30s 500ms")),
+            Item(String::from("-1d 1h 2us 1ns")),
+            Item(String::from("0s")),
+        ]
+    );
+}
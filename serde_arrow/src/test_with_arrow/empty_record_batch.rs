@@ -0,0 +1,91 @@
+use serde_json::{json, Value};
+
+use crate::{_impl::arrow::datatypes::FieldRef, schema::SchemaLike};
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U32"},
+        {"name": "null", "data_type": "Null", "nullable": true},
+        {
+            "name": "struct",
+            "data_type": "Struct",
+            "children": [
+                {"name": "x", "data_type": "Utf8"},
+                {
+                    "name": "y",
+                    "data_type": "LargeList",
+                    "children": [{"name": "element", "data_type": "I64"}],
+                },
+            ],
+        },
+        {
+            "name": "fixed_size_list",
+            "data_type": "FixedSizeList(2)",
+            "children": [{"name": "element", "data_type": "I64"}],
+        },
+        {"name": "fixed_size_binary", "data_type": "FixedSizeBinary(3)"},
+        {
+            "name": "map",
+            "data_type": "Map",
+            "children": [{
+                "name": "entries",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "key", "data_type": "Utf8"},
+                    {"name": "value", "data_type": "I32"},
+                ],
+            }],
+        },
+        {
+            "name": "dictionary",
+            "data_type": "Dictionary",
+            "children": [
+                {"name": "key", "data_type": "U32"},
+                {"name": "value", "data_type": "Utf8"},
+            ],
+        },
+        {
+            "name": "union",
+            "data_type": "Union",
+            "children": [
+                {"name": "A", "data_type": "I32"},
+                {"name": "B", "data_type": "Utf8"},
+            ],
+        },
+        {"name": "decimal", "data_type": "Decimal128(5, 2)"},
+        {"name": "timestamp", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {"name": "date", "data_type": "Date32"},
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn empty_record_batch_with_nested_fields() {
+    let fields = fields();
+    let batch = crate::empty_record_batch(&fields).unwrap();
+
+    assert_eq!(batch.num_rows(), 0);
+    assert_eq!(batch.num_columns(), fields.len());
+    assert_eq!(batch.schema().fields()[..], fields[..]);
+    for (column, field) in std::iter::zip(batch.columns(), &fields) {
+        assert_eq!(column.len(), 0);
+        assert_eq!(column.data_type(), field.data_type());
+    }
+
+    let items: Vec<Value> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(items, Vec::<Value>::new());
+}
+
+#[test]
+fn to_record_batch_without_items() {
+    let fields = fields();
+    let batch = crate::to_record_batch(&fields, &Vec::<Value>::new()).unwrap();
+    assert_eq!(batch, crate::empty_record_batch(&fields).unwrap());
+}
+
+#[test]
+fn empty_record_batch_without_fields() {
+    let batch = crate::empty_record_batch(Vec::<FieldRef>::new().as_slice()).unwrap();
+    assert_eq!(batch.num_rows(), 0);
+    assert_eq!(batch.num_columns(), 0);
+}
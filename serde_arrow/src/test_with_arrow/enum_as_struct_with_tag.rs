@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SerdeArrowSchema},
+    ArrayBuilder,
+};
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Rect { w: f64, h: f64 },
+    Pair(u8, u8),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Rect {
+    w: f64,
+    h: f64,
+}
+
+/// The layout written by other tools: a tag and one field per variant
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+struct Encoded {
+    tag: &'static str,
+    Circle: Option<f64>,
+    Rect: Option<Rect>,
+    Pair: Option<(u8, u8)>,
+}
+
+fn encoded(tag: &'static str) -> Encoded {
+    Encoded {
+        tag,
+        Circle: None,
+        Rect: None,
+        Pair: None,
+    }
+}
+
+fn shape_field(tag_type: Value, nullable: bool, strategy: Option<&str>) -> Value {
+    let mut field = json!({
+        "name": "shape",
+        "data_type": "Struct",
+        "nullable": nullable,
+        "children": [
+            tag_type,
+            {"name": "Circle", "data_type": "F64", "nullable": true},
+            {
+                "name": "Rect",
+                "data_type": "Struct",
+                "nullable": true,
+                "children": [
+                    {"name": "w", "data_type": "F64"},
+                    {"name": "h", "data_type": "F64"},
+                ],
+            },
+            {
+                "name": "Pair",
+                "data_type": "Struct",
+                "strategy": "TupleAsStruct",
+                "nullable": true,
+                "children": [
+                    {"name": "0", "data_type": "U8"},
+                    {"name": "1", "data_type": "U8"},
+                ],
+            },
+        ],
+    });
+    if let Some(strategy) = strategy {
+        field["strategy"] = json!(strategy);
+    }
+    field
+}
+
+fn dictionary_tag() -> Value {
+    json!({
+        "name": "tag",
+        "data_type": "Dictionary",
+        "children": [
+            {"name": "key", "data_type": "I8"},
+            {"name": "value", "data_type": "Utf8"},
+        ],
+    })
+}
+
+#[test]
+fn dictionary_tags() {
+    #[derive(Serialize)]
+    struct Row {
+        shape: Encoded,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        shape: Shape,
+    }
+
+    let rows = vec![
+        Row {
+            shape: Encoded {
+                Circle: Some(1.5),
+                ..encoded("Circle")
+            },
+        },
+        Row {
+            shape: encoded("Empty"),
+        },
+        Row {
+            shape: Encoded {
+                Rect: Some(Rect { w: 2.0, h: 3.0 }),
+                ..encoded("Rect")
+            },
+        },
+        Row {
+            shape: Encoded {
+                Pair: Some((4, 5)),
+                ..encoded("Pair")
+            },
+        },
+        Row {
+            shape: Encoded {
+                Circle: Some(6.0),
+                ..encoded("Circle")
+            },
+        },
+    ];
+
+    let writer_fields =
+        Vec::<FieldRef>::from_value(json!([shape_field(dictionary_tag(), false, None)])).unwrap();
+    let arrays = crate::to_arrow(&writer_fields, &rows).unwrap();
+
+    let fields = Vec::<FieldRef>::from_value(json!([shape_field(
+        dictionary_tag(),
+        false,
+        Some("EnumAsStructWithTag"),
+    )]))
+    .unwrap();
+    let actual: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(
+        actual,
+        [
+            Record {
+                shape: Shape::Circle(1.5)
+            },
+            Record {
+                shape: Shape::Empty
+            },
+            Record {
+                shape: Shape::Rect { w: 2.0, h: 3.0 }
+            },
+            Record {
+                shape: Shape::Pair(4, 5)
+            },
+            Record {
+                shape: Shape::Circle(6.0)
+            },
+        ],
+    );
+}
+
+#[test]
+fn nullable_string_tags() {
+    #[derive(Serialize)]
+    struct Row {
+        shape: Option<Encoded>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        shape: Option<Shape>,
+    }
+
+    let tag = json!({"name": "tag", "data_type": "LargeUtf8"});
+    let rows = vec![
+        Row { shape: None },
+        Row {
+            shape: Some(Encoded {
+                Circle: Some(2.0),
+                ..encoded("Circle")
+            }),
+        },
+        Row {
+            shape: Some(encoded("Empty")),
+        },
+    ];
+
+    let writer_fields =
+        Vec::<FieldRef>::from_value(json!([shape_field(tag.clone(), true, None)])).unwrap();
+    let arrays = crate::to_arrow(&writer_fields, &rows).unwrap();
+
+    let fields =
+        Vec::<FieldRef>::from_value(json!([shape_field(tag, true, Some("EnumAsStructWithTag"))]))
+            .unwrap();
+    let actual: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(
+        actual,
+        [
+            Record { shape: None },
+            Record {
+                shape: Some(Shape::Circle(2.0))
+            },
+            Record {
+                shape: Some(Shape::Empty)
+            },
+        ],
+    );
+}
+
+#[test]
+fn unknown_variants_and_missing_data_fields() {
+    #[derive(Serialize)]
+    struct Row {
+        shape: Encoded,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    enum Other {
+        Circle(f64),
+        Triangle(f64),
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Record<T> {
+        shape: T,
+    }
+
+    let writer_fields =
+        Vec::<FieldRef>::from_value(json!([shape_field(dictionary_tag(), false, None)])).unwrap();
+    let fields = Vec::<FieldRef>::from_value(json!([shape_field(
+        dictionary_tag(),
+        false,
+        Some("EnumAsStructWithTag"),
+    )]))
+    .unwrap();
+
+    let arrays = crate::to_arrow(
+        &writer_fields,
+        &[Row {
+            shape: encoded("Empty"),
+        }],
+    )
+    .unwrap();
+    let res = crate::from_arrow::<Vec<Record<Other>>, _>(&fields, &arrays);
+    assert_error_contains(&res, "unknown variant `Empty`");
+
+    let arrays = crate::to_arrow(
+        &writer_fields,
+        &[Row {
+            shape: encoded("Triangle"),
+        }],
+    )
+    .unwrap();
+    let res = crate::from_arrow::<Vec<Record<Other>>, _>(&fields, &arrays);
+    assert_error_contains(&res, "Variant Triangle has no data field");
+}
+
+#[test]
+fn invalid_schemas() {
+    let res = SerdeArrowSchema::from_value(json!([shape_field(
+        json!({"name": "tag", "data_type": "I32"}),
+        false,
+        Some("EnumAsStructWithTag"),
+    )]));
+    assert_error_contains(
+        &res,
+        "must have a string or dictionary tag as its first child",
+    );
+
+    let fields = Vec::<FieldRef>::from_value(json!([shape_field(
+        dictionary_tag(),
+        false,
+        Some("EnumAsStructWithTag"),
+    )]))
+    .unwrap();
+    let res = ArrayBuilder::from_arrow(&fields);
+    assert_error_contains(&res, "only supported in deserialization");
+}
@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, BooleanArray, StructArray},
+        datatypes::FieldRef,
+    },
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Item {
+    color: Color,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct OptionalItem {
+    color: Option<Color>,
+}
+
+/// The flags as written by other tools or without the strategy
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+struct Flags {
+    Red: bool,
+    Green: bool,
+    Blue: bool,
+}
+
+#[derive(Serialize)]
+struct FlagsItem {
+    color: Flags,
+}
+
+fn color_fields(nullable: bool, strategy: Option<&str>) -> Vec<FieldRef> {
+    let mut field = json!({
+        "name": "color",
+        "data_type": "Struct",
+        "nullable": nullable,
+        "children": [
+            {"name": "Red", "data_type": "Bool"},
+            {"name": "Green", "data_type": "Bool"},
+            {"name": "Blue", "data_type": "Bool"},
+        ],
+    });
+    if let Some(strategy) = strategy {
+        field["strategy"] = json!(strategy);
+    }
+    Vec::<FieldRef>::from_value(json!([field])).unwrap()
+}
+
+fn flags(red: bool, green: bool, blue: bool) -> FlagsItem {
+    FlagsItem {
+        color: Flags {
+            Red: red,
+            Green: green,
+            Blue: blue,
+        },
+    }
+}
+
+#[test]
+fn serialization() {
+    let fields = color_fields(false, Some("EnumOneHot"));
+    let items = [Color::Red, Color::Blue, Color::Blue, Color::Green].map(|color| Item { color });
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let array = arrays[0].as_any().downcast_ref::<StructArray>().unwrap();
+    let flags = |idx: usize| -> Vec<bool> {
+        let column = array
+            .column(idx)
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        (0..column.len()).map(|row| column.value(row)).collect()
+    };
+    assert_eq!(array.null_count(), 0);
+    assert_eq!(flags(0), [true, false, false, false]);
+    assert_eq!(flags(1), [false, false, false, true]);
+    assert_eq!(flags(2), [false, true, true, false]);
+
+    let actual: Vec<Item> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn flags_are_plain_bool_columns() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct FlagsTarget {
+        color: Flags,
+    }
+
+    let fields = color_fields(false, Some("EnumOneHot"));
+    let items = [Item {
+        color: Color::Green,
+    }];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let actual: Vec<FlagsTarget> = crate::from_arrow(&color_fields(false, None), &arrays).unwrap();
+    let expected = [FlagsTarget {
+        color: Flags {
+            Red: false,
+            Green: true,
+            Blue: false,
+        },
+    }];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn nullable_enums() {
+    let fields = color_fields(true, Some("EnumOneHot"));
+    let items = [
+        OptionalItem {
+            color: Some(Color::Green),
+        },
+        OptionalItem { color: None },
+        OptionalItem {
+            color: Some(Color::Red),
+        },
+    ];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let array = arrays[0].as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(array.null_count(), 1);
+    assert!(array.is_null(1));
+    for column in array.columns() {
+        let column = column.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert!(!column.value(1));
+    }
+
+    let actual: Vec<OptionalItem> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn self_describing_deserialization() {
+    let fields = color_fields(true, Some("EnumOneHot"));
+    let items = [
+        OptionalItem {
+            color: Some(Color::Blue),
+        },
+        OptionalItem { color: None },
+    ];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let actual: Vec<Value> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, [json!({"color": "Blue"}), json!({"color": null})]);
+}
+
+#[test]
+fn multiple_set_flags_are_rejected() {
+    let arrays = crate::to_arrow(&color_fields(false, None), [flags(true, false, true)]).unwrap();
+    let res: crate::Result<Vec<Item>> =
+        crate::from_arrow(&color_fields(false, Some("EnumOneHot")), &arrays);
+    assert_error_contains(&res, "The flags of multiple variants are set: Red and Blue");
+    assert_error_contains(&res, "$.color");
+}
+
+#[test]
+fn missing_flags_are_rejected() {
+    let arrays = crate::to_arrow(&color_fields(false, None), [flags(false, false, false)]).unwrap();
+    let res: crate::Result<Vec<Item>> =
+        crate::from_arrow(&color_fields(false, Some("EnumOneHot")), &arrays);
+    assert_error_contains(&res, "None of the variant flags is set");
+}
+
+#[test]
+fn unknown_variants_are_rejected() {
+    #[derive(Serialize)]
+    enum Extended {
+        Red,
+        Yellow,
+    }
+
+    #[derive(Serialize)]
+    struct ExtendedItem {
+        color: Extended,
+    }
+
+    let fields = color_fields(false, Some("EnumOneHot"));
+    let res = crate::to_arrow(
+        &fields,
+        [
+            ExtendedItem {
+                color: Extended::Red,
+            },
+            ExtendedItem {
+                color: Extended::Yellow,
+            },
+        ],
+    );
+    assert_error_contains(&res, "Unknown variant Yellow");
+}
+
+#[test]
+fn variants_with_data_are_rejected() {
+    #[derive(Serialize)]
+    enum WithData {
+        #[allow(dead_code)]
+        Red(u8),
+    }
+
+    #[derive(Serialize)]
+    struct WithDataItem {
+        color: WithData,
+    }
+
+    let fields = color_fields(false, Some("EnumOneHot"));
+    let res = crate::to_arrow(
+        &fields,
+        [WithDataItem {
+            color: WithData::Red(1),
+        }],
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn structs_without_the_strategy_reject_enums() {
+    let fields = color_fields(false, None);
+    let res = crate::to_arrow(&fields, [Item { color: Color::Red }]);
+    assert_error_contains(&res, "without the EnumOneHot strategy");
+}
+
+#[test]
+fn non_bool_children_are_rejected() {
+    let res = Vec::<FieldRef>::from_value(json!([{
+        "name": "color",
+        "data_type": "Struct",
+        "strategy": "EnumOneHot",
+        "children": [
+            {"name": "Red", "data_type": "Bool"},
+            {"name": "Green", "data_type": "U8"},
+        ],
+    }]));
+    assert_error_contains(&res, "must only have Boolean children, Green is");
+}
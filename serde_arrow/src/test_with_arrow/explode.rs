@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, RecordBatch},
+        datatypes::{DataType, Field, FieldRef},
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Order {
+    id: u32,
+    items: Option<Vec<Item>>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Item {
+    name: String,
+    quantity: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Row {
+    parent_index: u64,
+    name: String,
+    quantity: Option<u32>,
+}
+
+fn item(name: &str, quantity: Option<u32>) -> Item {
+    Item {
+        name: String::from(name),
+        quantity,
+    }
+}
+
+fn orders() -> Vec<Order> {
+    vec![
+        Order {
+            id: 0,
+            items: Some(vec![item("a", Some(1)), item("b", None)]),
+        },
+        Order { id: 1, items: None },
+        Order {
+            id: 2,
+            items: Some(vec![]),
+        },
+        Order {
+            id: 3,
+            items: Some(vec![item("c", Some(3))]),
+        },
+        Order { id: 4, items: None },
+    ]
+}
+
+fn batch(orders: &[Order]) -> RecordBatch {
+    let fields = Vec::<FieldRef>::from_samples(orders, TracingOptions::default()).unwrap();
+    crate::to_record_batch(&fields, &orders).unwrap()
+}
+
+#[test]
+fn explode_and_collect() {
+    let batch = batch(&orders());
+    let exploded = crate::explode(&batch, "items").unwrap();
+
+    assert_eq!(exploded.schema().field(0).name(), "parent_index");
+    assert_eq!(exploded.schema().field(0).data_type(), &DataType::UInt64);
+
+    let rows: Vec<Row> = crate::from_record_batch(&exploded).unwrap();
+    assert_eq!(
+        rows,
+        [
+            Row {
+                parent_index: 0,
+                name: String::from("a"),
+                quantity: Some(1),
+            },
+            Row {
+                parent_index: 0,
+                name: String::from("b"),
+                quantity: None,
+            },
+            Row {
+                parent_index: 3,
+                name: String::from("c"),
+                quantity: Some(3),
+            },
+        ],
+    );
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Items {
+        items: Vec<Item>,
+    }
+
+    let collected = crate::collect_exploded(&exploded, batch.num_rows()).unwrap();
+    assert_eq!(collected.len(), 5);
+
+    let field = Field::new("items", collected.data_type().clone(), false);
+    let actual: Vec<Items> = crate::from_arrow(std::slice::from_ref(&field), &[collected]).unwrap();
+    assert_eq!(
+        actual,
+        [
+            Items {
+                items: vec![item("a", Some(1)), item("b", None)],
+            },
+            Items { items: vec![] },
+            Items { items: vec![] },
+            Items {
+                items: vec![item("c", Some(3))],
+            },
+            Items { items: vec![] },
+        ],
+    );
+}
+
+#[test]
+fn explode_sliced_batches() {
+    let batch = batch(&orders()).slice(2, 2);
+    let exploded = crate::explode(&batch, "items").unwrap();
+
+    let rows: Vec<Row> = crate::from_record_batch(&exploded).unwrap();
+    assert_eq!(
+        rows,
+        [Row {
+            parent_index: 1,
+            name: String::from("c"),
+            quantity: Some(3),
+        }],
+    );
+}
+
+#[test]
+fn explode_errors() {
+    let batch = batch(&orders());
+
+    let res = crate::explode(&batch, "missing");
+    assert_error_contains(&res, "no such column");
+
+    let res = crate::explode(&batch, "id");
+    assert_error_contains(&res, "expected a list of structs");
+
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "items",
+        "data_type": "LargeList",
+        "children": [{
+            "name": "element",
+            "data_type": "Struct",
+            "nullable": true,
+            "children": [{"name": "a", "data_type": "U8"}],
+        }],
+    }]))
+    .unwrap();
+    let batch = crate::to_record_batch(
+        &fields,
+        &json!([{"items": [{"a": 1}, null]}]).as_array().unwrap(),
+    )
+    .unwrap();
+    let res = crate::explode(&batch, "items");
+    assert_error_contains(&res, "null structs are not supported");
+}
+
+#[test]
+fn collect_errors() {
+    #[derive(Serialize)]
+    struct Child {
+        parent_index: u64,
+    }
+
+    let unsorted = [Child { parent_index: 1 }, Child { parent_index: 0 }];
+    let batch = crate::to_record_batch(
+        &Vec::<FieldRef>::from_samples(&unsorted, TracingOptions::default()).unwrap(),
+        &unsorted,
+    )
+    .unwrap();
+
+    let res = crate::collect_exploded(&batch, 2);
+    assert_error_contains(&res, "parent indices must be sorted");
+
+    let res = crate::collect_exploded(&batch, 1);
+    assert_error_contains(&res, "out of bounds");
+}
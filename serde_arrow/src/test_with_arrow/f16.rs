@@ -0,0 +1,177 @@
+use half::f16;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, GenericListArray, PrimitiveArray},
+        datatypes::{DataType, FieldRef, Float16Type},
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    ArrayBuilder, F16Overflow, F16Rounding, SerializerOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Item {
+    value: f64,
+}
+
+fn serialize(options: SerializerOptions, values: &[f64]) -> crate::Result<Vec<f16>> {
+    let fields = Vec::<FieldRef>::from_value(json!([{"name": "value", "data_type": "F16"}]))?;
+    let items = values
+        .iter()
+        .map(|&value| Item { value })
+        .collect::<Vec<_>>();
+
+    let mut builder = ArrayBuilder::from_arrow(&fields)?.with_options(options);
+    builder.extend(&items)?;
+    let arrays = builder.to_arrow()?;
+
+    let array = arrays[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<Float16Type>>()
+        .unwrap();
+    Ok(array.values().to_vec())
+}
+
+#[test]
+fn nearest_rounding_is_the_default() {
+    let actual = serialize(SerializerOptions::default(), &[0.3, -0.3, 0.1, 1e6]).unwrap();
+    assert_eq!(
+        actual,
+        [
+            f16::from_bits(0x34cd),
+            f16::from_bits(0xb4cd),
+            f16::from_bits(0x2e66),
+            f16::INFINITY,
+        ],
+    );
+}
+
+#[test]
+fn toward_zero_rounding() {
+    let options = SerializerOptions::default().f16_rounding(F16Rounding::TowardZero);
+    let actual = serialize(options, &[0.3, -0.3, 0.1, 1e6, -1e6, 0.0]).unwrap();
+    assert_eq!(
+        actual,
+        [
+            f16::from_bits(0x34cc),
+            f16::from_bits(0xb4cc),
+            f16::from_bits(0x2e66),
+            f16::MAX,
+            f16::MIN,
+            f16::ZERO,
+        ],
+    );
+}
+
+#[test]
+fn overflow_error() {
+    let options = SerializerOptions::default().f16_overflow(F16Overflow::Error);
+
+    let actual = serialize(options.clone(), &[65504.0, 65519.0]).unwrap();
+    assert_eq!(actual, [f16::MAX, f16::MAX]);
+
+    let res = serialize(options.clone(), &[1.0, 65520.0]);
+    assert_error_contains(&res, "Value 65520 is outside the range of Float16");
+    assert_error_contains(&res, "field: \"$.value\"");
+
+    let res = serialize(options.f16_rounding(F16Rounding::TowardZero), &[-1e6]);
+    assert_error_contains(&res, "outside the range of Float16");
+}
+
+#[test]
+fn non_finite_values_are_kept() {
+    let options = SerializerOptions::default().f16_overflow(F16Overflow::Error);
+    let actual = serialize(options, &[f64::INFINITY, f64::NEG_INFINITY, f64::NAN]).unwrap();
+    assert_eq!(actual[0], f16::INFINITY);
+    assert_eq!(actual[1], f16::NEG_INFINITY);
+    assert!(actual[2].is_nan());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Embedding {
+    id: u32,
+    score: f32,
+    embedding: Vec<f32>,
+}
+
+#[test]
+fn trace_forced_f16_embeddings() {
+    let items = vec![
+        Embedding {
+            id: 0,
+            score: 0.5,
+            embedding: vec![0.5, -1.25, 2.0],
+        },
+        Embedding {
+            id: 1,
+            score: 0.25,
+            embedding: vec![0.3],
+        },
+    ];
+    let fields =
+        Vec::<FieldRef>::from_samples(&items, TracingOptions::default().force_f16("embedding"))
+            .unwrap();
+
+    assert_eq!(fields[1].data_type(), &DataType::Float32);
+    let DataType::LargeList(element) = fields[2].data_type() else {
+        panic!("unexpected data type {:?}", fields[2].data_type());
+    };
+    assert_eq!(element.data_type(), &DataType::Float16);
+
+    let from_type =
+        Vec::<FieldRef>::from_type::<Embedding>(TracingOptions::default().force_f16("embedding"))
+            .unwrap();
+    assert_eq!(from_type, fields);
+
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().f16_rounding(F16Rounding::TowardZero));
+    builder.extend(&items).unwrap();
+    let arrays = builder.to_arrow().unwrap();
+
+    let embedding = arrays[2]
+        .as_any()
+        .downcast_ref::<GenericListArray<i64>>()
+        .unwrap();
+    let values = embedding
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<Float16Type>>()
+        .unwrap();
+    assert_eq!(
+        values.values().to_vec(),
+        [
+            f16::from_f32(0.5),
+            f16::from_f32(-1.25),
+            f16::from_f32(2.0),
+            f16::from_bits(0x34cc),
+        ],
+    );
+
+    let actual: Vec<Embedding> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual[0], items[0]);
+    assert_eq!(actual[1].embedding, [f16::from_bits(0x34cc).to_f32()]);
+}
+
+#[test]
+fn forced_f16_paths_match_whole_segments() {
+    #[derive(Serialize)]
+    struct Record {
+        embedding: f32,
+        embedding_norm: f32,
+    }
+
+    let fields = Vec::<FieldRef>::from_samples(
+        &[Record {
+            embedding: 1.0,
+            embedding_norm: 1.0,
+        }],
+        TracingOptions::default().force_f16("embedding"),
+    )
+    .unwrap();
+    assert_eq!(fields[0].data_type(), &DataType::Float16);
+    assert_eq!(fields[1].data_type(), &DataType::Float32);
+}
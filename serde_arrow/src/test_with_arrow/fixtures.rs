@@ -0,0 +1,54 @@
+//! Records shared between the tests
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    schema::{SchemaLike, TracingOptions},
+};
+
+/// A record with a non-nullable and a nullable field
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub a: u32,
+    pub b: Option<String>,
+}
+
+pub fn items() -> Vec<Record> {
+    vec![
+        Record {
+            a: 1,
+            b: Some(String::from("foo")),
+        },
+        Record { a: 2, b: None },
+    ]
+}
+
+/// A record with a list field, generated from its id
+#[cfg(any(feature = "ipc", feature = "http"))]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Tagged {
+    pub id: u32,
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
+#[cfg(any(feature = "ipc", feature = "http"))]
+impl Tagged {
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            name: format!("item {id}"),
+            tags: (0..id % 3).map(|idx| format!("tag {id} {idx}")).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+pub fn tagged(num: u32) -> Vec<Tagged> {
+    (0..num).map(Tagged::new).collect()
+}
+
+/// The arrow fields of `T` traced with the default options
+pub fn fields<'de, T: Deserialize<'de>>() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_type::<T>(TracingOptions::default()).unwrap()
+}
@@ -0,0 +1,81 @@
+// arrow-version:replace: use arrow_ipc_{version}::reader::StreamReader;
+use arrow_ipc_53::reader::StreamReader;
+
+use crate::{
+    _impl::arrow::datatypes::{DataType, FieldRef},
+    http::{from_ipc_body, to_ipc_response_body},
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, StringType, TracingOptions},
+};
+
+use super::fixtures::{fields, tagged, Tagged};
+
+#[test]
+fn roundtrip_in_multiple_batches() {
+    let items = tagged(10);
+    let chunks = to_ipc_response_body(&fields::<Tagged>(), &items)
+        .unwrap()
+        .with_batch_size(4)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    // three batches and the end-of-stream marker
+    assert_eq!(chunks.len(), 4);
+
+    let round_tripped: Vec<Tagged> = from_ipc_body(chunks.concat().as_slice()).unwrap();
+    assert_eq!(round_tripped, items);
+}
+
+#[test]
+fn empty_items_only_contain_the_schema() {
+    let items = tagged(0);
+    let chunks = to_ipc_response_body(&fields::<Tagged>(), &items)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(chunks.len(), 1);
+
+    let round_tripped: Vec<Tagged> = from_ipc_body(chunks.concat().as_slice()).unwrap();
+    assert_eq!(round_tripped, items);
+}
+
+#[test]
+fn zero_batch_size_is_an_error() {
+    let items = tagged(2);
+    let mut body = to_ipc_response_body(&fields::<Tagged>(), &items)
+        .unwrap()
+        .with_batch_size(0);
+    assert_error_contains(&body.next().unwrap(), "must be positive");
+    assert!(body.next().is_none());
+}
+
+#[test]
+fn invalid_bodies_are_an_error() {
+    let res = from_ipc_body::<Tagged>(b"not an ipc stream".as_slice());
+    assert!(res.is_err());
+}
+
+#[test]
+fn adaptive_strings_are_streamed_with_the_field_types() {
+    let options = TracingOptions::default().default_string_type(StringType::Adaptive);
+    let fields = Vec::<FieldRef>::from_type::<Tagged>(options).unwrap();
+    assert_eq!(fields[1].data_type(), &DataType::LargeUtf8);
+
+    let items = tagged(10);
+    let body = to_ipc_response_body(&fields, &items)
+        .unwrap()
+        .with_batch_size(4);
+    let schema = body.schema().clone();
+    let body = body.collect::<Result<Vec<_>, _>>().unwrap().concat();
+
+    let reader = StreamReader::try_new(body.as_slice(), None).unwrap();
+    assert_eq!(reader.schema(), schema);
+    for batch in reader {
+        let batch = batch.unwrap();
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(batch.column(1).data_type(), &DataType::LargeUtf8);
+    }
+
+    let round_tripped: Vec<Tagged> = from_ipc_body(body.as_slice()).unwrap();
+    assert_eq!(round_tripped, items);
+}
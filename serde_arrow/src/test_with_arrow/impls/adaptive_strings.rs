@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::RecordBatch,
+        datatypes::{DataType, FieldRef, Schema},
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, Strategy, StringType, TracingOptions, STRATEGY_KEY},
+    ArrayBuilder,
+};
+
+use super::utils::Test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    name: Option<String>,
+    tags: Vec<String>,
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            name: Some(String::from("foo")),
+            tags: vec![String::from("a"), String::from("b")],
+        },
+        Record {
+            name: None,
+            tags: vec![],
+        },
+    ]
+}
+
+fn schema(data_type: &str) -> serde_json::Value {
+    json!([
+        {"name": "name", "data_type": data_type, "nullable": true, "strategy": "AdaptiveString"},
+        {"name": "tags", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": data_type, "strategy": "AdaptiveString"},
+        ]},
+    ])
+}
+
+fn fields(data_type: &str) -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(schema(data_type)).unwrap()
+}
+
+#[test]
+fn utf8_fields() {
+    let items = items();
+    Test::new()
+        .with_schema(schema("Utf8"))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true], &[false, false]]);
+}
+
+#[test]
+fn small_strings_are_built_as_utf8() {
+    for data_type in ["Utf8", "LargeUtf8"] {
+        let fields = fields(data_type);
+        let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+        builder.extend(items()).unwrap();
+        let batch = builder.to_record_batch().unwrap();
+
+        let schema = batch.schema();
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+        let DataType::LargeList(element) = schema.field(1).data_type() else {
+            panic!("unexpected data type {:?}", schema.field(1).data_type());
+        };
+        assert_eq!(element.data_type(), &DataType::Utf8);
+        assert_eq!(
+            schema
+                .field(0)
+                .metadata()
+                .get(STRATEGY_KEY)
+                .map(String::as_str),
+            Some("AdaptiveString"),
+        );
+
+        let round_tripped: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+        assert_eq!(round_tripped, items());
+    }
+}
+
+#[test]
+fn tracing_with_adaptive_string_type() {
+    let options = TracingOptions::default().default_string_type(StringType::Adaptive);
+    let fields = Vec::<FieldRef>::from_type::<Record>(options).unwrap();
+
+    assert_eq!(fields[0].data_type(), &DataType::LargeUtf8);
+    assert_eq!(
+        fields[0].metadata().get(STRATEGY_KEY).map(String::as_str),
+        Some("AdaptiveString"),
+    );
+
+    let arrays = crate::to_arrow(&fields, items()).unwrap();
+    assert_eq!(arrays[0].data_type(), &DataType::Utf8);
+
+    let round_tripped: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_tripped, items());
+}
+
+#[test]
+fn empty_record_batches_use_the_data_types_of_the_arrays() {
+    let batch = crate::empty_record_batch(&fields("LargeUtf8")).unwrap();
+    assert_eq!(batch.schema().field(0).data_type(), &DataType::Utf8);
+    assert_eq!(batch.column(0).data_type(), &DataType::Utf8);
+}
+
+#[test]
+fn patched_record_batches_use_the_data_types_of_the_arrays() {
+    #[derive(Serialize)]
+    struct Patch {
+        name: Option<String>,
+    }
+
+    // a record batch with `LargeUtf8` arrays, e.g., written by another tool
+    let plain_fields = Vec::<FieldRef>::from_type::<Record>(
+        TracingOptions::default().default_string_type(StringType::LargeUtf8),
+    )
+    .unwrap();
+    let plain = crate::to_record_batch(&plain_fields, &items()).unwrap();
+    let schema = Schema::new(vec![
+        fields("LargeUtf8")[0].clone(),
+        plain_fields[1].clone(),
+    ]);
+    let batch = RecordBatch::try_new(Arc::new(schema), plain.columns().to_vec()).unwrap();
+    assert_eq!(batch.column(0).data_type(), &DataType::LargeUtf8);
+
+    let patched = crate::patch_record_batch(
+        &batch,
+        &[
+            Patch {
+                name: Some(String::from("bar")),
+            },
+            Patch { name: None },
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(patched.column(0).data_type(), &DataType::Utf8);
+    assert_eq!(patched.schema().field(0).data_type(), &DataType::Utf8);
+    assert_eq!(
+        patched.schema().field(1).data_type(),
+        batch.schema().field(1).data_type()
+    );
+}
+
+#[cfg(has_arrow_view_support)]
+#[test]
+fn utf8_view_fields_are_built_as_views() {
+    use crate::_impl::arrow::_raw::array::array::StringViewArray;
+
+    let fields = fields("Utf8View");
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    builder.extend(items()).unwrap();
+    let batch = builder.to_record_batch().unwrap();
+
+    assert_eq!(batch.schema().fields()[..], fields[..]);
+    let names = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringViewArray>()
+        .unwrap();
+    assert_eq!(names.iter().collect::<Vec<_>>(), [Some("foo"), None]);
+}
+
+#[cfg(not(has_arrow_view_support))]
+#[test]
+fn utf8_view_fields_require_view_support() {
+    let res = Vec::<FieldRef>::from_value(json!([
+        {"name": "name", "data_type": "Utf8View", "strategy": "AdaptiveString"},
+    ]));
+    assert_error_contains(
+        &res,
+        "Utf8View is not supported by the selected arrow version",
+    );
+}
+
+#[test]
+fn strategy_is_only_valid_for_strings() {
+    let res = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U32", "strategy": "AdaptiveString"},
+    ]));
+    assert_error_contains(&res, "invalid strategy");
+}
+
+#[test]
+fn strategy_repr() {
+    let strategy: Strategy = "AdaptiveString".parse().unwrap();
+    assert_eq!(strategy, Strategy::AdaptiveString);
+    assert_eq!(strategy.to_string(), "AdaptiveString");
+}
@@ -324,20 +324,43 @@ fn time_chrono() {
         .check_nulls(&[&[false, false, false]]);
 }
 
+#[test]
+fn time_strings_keep_sub_second_precision() {
+    let items = [
+        Item(NaiveTime::from_hms_milli_opt(8, 0, 1, 500).unwrap()),
+        Item(NaiveTime::from_hms_milli_opt(23, 59, 59, 999).unwrap()),
+    ];
+    let expected = [
+        Item(String::from("08:00:01.500")),
+        Item(String::from("23:59:59.999")),
+    ];
+
+    for data_type in [
+        "Time32(Millisecond)",
+        "Time64(Microsecond)",
+        "Time64(Nanosecond)",
+    ] {
+        Test::new()
+            .with_schema(json!([{"name": "item", "data_type": data_type}]))
+            .serialize(&items)
+            .deserialize(&expected);
+    }
+}
+
 #[test]
 fn time64_type_invalid_units() {
     // Note: the arrow docs state: that the time unit "[m]ust be either
     // microseconds or nanoseconds."
 
     assert_error_contains(
-        &SerdeArrowSchema::from_value(&json!([{
+        &SerdeArrowSchema::from_value(json!([{
             "name": "item",
             "data_type": "Time64(Millisecond)",
         }])),
         "Error: Time64 field must have Microsecond or Nanosecond unit",
     );
     assert_error_contains(
-        &SerdeArrowSchema::from_value(&json!([{
+        &SerdeArrowSchema::from_value(json!([{
             "name": "item",
             "data_type": "Time64(Second)",
         }])),
@@ -345,14 +368,14 @@ fn time64_type_invalid_units() {
     );
 
     assert_error_contains(
-        &SerdeArrowSchema::from_value(&json!([{
+        &SerdeArrowSchema::from_value(json!([{
             "name": "item",
             "data_type": "Time32(Microsecond)",
         }])),
         "Error: Time32 field must have Second or Millisecond unit",
     );
     assert_error_contains(
-        &SerdeArrowSchema::from_value(&json!([{
+        &SerdeArrowSchema::from_value(json!([{
             "name": "item",
             "data_type": "Time32(Nanosecond)",
         }])),
@@ -682,7 +705,7 @@ mod naive_time {
             .with_schema(json!([{"name": "item", "data_type": "LargeUtf8"}]))
             .trace_schema_from_samples(&items, TracingOptions::default())
             .serialize(&items)
-            .deserialize(&items);
+            .deserialize(items);
     }
 
     #[test]
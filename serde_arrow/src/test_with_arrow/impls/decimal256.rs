@@ -0,0 +1,211 @@
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, PrimitiveArray},
+        datatypes::{i256, Decimal256Type},
+    },
+    internal::{arrow::DataType, testing::assert_error_contains},
+    schema::{SchemaLike, SerdeArrowSchema},
+    utils::Item,
+};
+
+use super::utils::Test;
+
+const LARGE: &str = "-123456789012345678901234567890123456789012345678.90";
+
+fn get_i256_values(test: &Test) -> &[i256] {
+    let arrays = test.arrays.arrow.as_ref().unwrap();
+    let arr = arrays[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<Decimal256Type>>()
+        .unwrap();
+    arr.values()
+}
+
+fn i256_from_str(s: &str) -> i256 {
+    i256::from_string(s).unwrap()
+}
+
+#[test]
+fn schema_repr() {
+    let schema = json!([
+        {"name": "value", "data_type": "Decimal256(60, 2)"},
+        {"name": "nullable", "data_type": "Decimal256(76, 0)", "nullable": true},
+    ]);
+    let parsed = SerdeArrowSchema::from_value(&schema).unwrap();
+    assert_eq!(parsed.fields[0].data_type, DataType::Decimal256(60, 2));
+    assert_eq!(parsed.fields[1].data_type, DataType::Decimal256(76, 0));
+
+    let value = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(value["fields"], schema);
+}
+
+#[test]
+fn precision_is_validated() {
+    let res = SerdeArrowSchema::from_value(json!([
+        {"name": "value", "data_type": "Decimal256(77, 2)"},
+    ]));
+    assert_error_contains(&res, "at most 76 digits");
+}
+
+#[test]
+fn strings() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        value: String,
+        nullable: Option<String>,
+    }
+
+    let items = [
+        Record {
+            value: String::from(LARGE),
+            nullable: Some("9".repeat(76)),
+        },
+        Record {
+            value: String::from("0.05"),
+            nullable: None,
+        },
+        Record {
+            value: String::from("-1.00"),
+            nullable: Some(format!("-{}", "9".repeat(76))),
+        },
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "value", "data_type": "Decimal256(60, 2)"},
+            {"name": "nullable", "data_type": "Decimal256(76, 0)", "nullable": true},
+        ]))
+        .serialize(&items)
+        .also(|it| {
+            let values = get_i256_values(it);
+            assert_eq!(
+                values[0],
+                i256_from_str("-12345678901234567890123456789012345678901234567890")
+            );
+            assert_eq!(values[1], i256::from_i128(5));
+            assert_eq!(values[2], i256::from_i128(-100));
+        })
+        .deserialize(&items)
+        .check_nulls(&[&[false, false, false], &[false, true, false]]);
+}
+
+#[test]
+fn rust_decimal_str_repr() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper {
+        #[serde(with = "rust_decimal::serde::str")]
+        value: Decimal,
+    }
+
+    let items = [
+        Wrapper {
+            value: Decimal::from_str("0.2000").unwrap(),
+        },
+        Wrapper {
+            value: Decimal::from_str("-12345678901234.5678").unwrap(),
+        },
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "value", "data_type": "Decimal256(40, 4)"},
+        ]))
+        .serialize(&items)
+        .also(|it| {
+            assert_eq!(
+                get_i256_values(it),
+                &[i256::from_i128(2000), i256::from_i128(-123456789012345678)]
+            )
+        })
+        .deserialize(&items);
+}
+
+#[test]
+fn scaled_ints() {
+    let items = [Item(12_i64), Item(-3_i64)];
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "Decimal256(40, 2)", "strategy": "ScaledInt(0)"},
+        ]))
+        .serialize(&items)
+        .also(|it| {
+            assert_eq!(
+                get_i256_values(it),
+                &[i256::from_i128(1200), i256::from_i128(-300)]
+            )
+        })
+        .deserialize(&items);
+}
+
+#[test]
+fn large_scaled_ints() {
+    let items = [Item(i64::MAX), Item(-12_i64)];
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "Decimal256(60, 30)", "strategy": "ScaledInt(0)"},
+        ]))
+        .serialize(&items)
+        .also(|it| {
+            assert_eq!(
+                get_i256_values(it),
+                &[
+                    i256_from_str(&format!("{}{}", i64::MAX, "0".repeat(30))),
+                    i256_from_str(&format!("-12{}", "0".repeat(30))),
+                ]
+            )
+        })
+        .deserialize(&items);
+
+    let res = Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "Decimal256(40, 30)", "strategy": "ScaledInt(0)"},
+        ]))
+        .try_serialize_arrow(&[Item(i64::MAX)]);
+    assert_error_contains(&res, "does not fit into a decimal with precision 40");
+}
+
+#[test]
+fn insufficient_precision_is_rejected() {
+    let res = Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "Decimal256(40, 2)"},
+        ]))
+        .try_serialize_arrow(&[Item(LARGE)]);
+    assert_error_contains(&res, "not enough precision");
+}
+
+#[test]
+fn large_floats() {
+    let items = [Item(1e40_f64), Item(-2.5e45_f64), Item(0.25_f64)];
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "Decimal256(60, 2)"},
+        ]))
+        .serialize(&items)
+        .also(|it| {
+            // the values are the exact integers of the scaled floats
+            assert_eq!(
+                get_i256_values(it),
+                &[
+                    i256_from_str(&format!("{:.0}", 1e40_f64 * 100.0)),
+                    i256_from_str(&format!("{:.0}", -2.5e45_f64 * 100.0)),
+                    i256::from_i128(25),
+                ]
+            );
+
+            let res = it.try_serialize_arrow(&[Item(1e60_f64)]);
+            assert_error_contains(&res, "does not fit into a decimal with precision 60");
+
+            let res = it.try_serialize_arrow(&[Item(f64::NAN)]);
+            assert_error_contains(&res, "cannot be represented as a 256 bit decimal");
+        });
+}
@@ -61,7 +61,7 @@ fn example_nullable_with_nulls() {
 fn incorrect_number_of_elements() {
     let items = [Item(vec![0_u8, 1]), Item(vec![2])];
 
-    let fields = Vec::<FieldRef>::from_value(&json!([{
+    let fields = Vec::<FieldRef>::from_value(json!([{
         "name": "item",
         "data_type": "FixedSizeList(2)",
         "children": [{"name": "element", "data_type": "U8"}],
@@ -74,7 +74,7 @@ fn incorrect_number_of_elements() {
 
 #[test]
 fn deserialize_from_schema() {
-    let fields = Vec::<FieldRef>::from_value(&json!([{
+    let fields = Vec::<FieldRef>::from_value(json!([{
         "name": "item",
         "data_type": "FixedSizeList(2)",
         "children": [{"name": "element", "data_type": "U8"}],
@@ -85,3 +85,35 @@ fn deserialize_from_schema() {
 
     assert_eq!(fields, fields_from_fields);
 }
+
+#[test]
+fn fixed_size_arrays() {
+    let items = [Item([0_u8, 1]), Item([2, 3]), Item([4, 5])];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeList(2)",
+            "children": [{"name": "element", "data_type": "U8"}],
+        }]))
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn fixed_size_arrays_with_incorrect_length() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "item",
+        "data_type": "FixedSizeList(2)",
+        "children": [{"name": "element", "data_type": "U8"}],
+    }]))
+    .unwrap();
+
+    let items = [Item([0_u8, 1]), Item([2, 3])];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let res = crate::from_arrow::<Vec<Item<[u8; 3]>>, _>(&fields, &arrays);
+    assert_error_contains(
+        &res,
+        "Cannot deserialize list 0 of length 2 into a fixed size container of length 3",
+    );
+}
@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, PrimitiveArray},
+        datatypes::{
+            DataType as ArrowDataType, FieldRef, IntervalDayTimeType, IntervalUnit as ArrowUnit,
+            IntervalYearMonthType,
+        },
+    },
+    internal::{
+        arrow::{DataType, IntervalUnit},
+        testing::assert_error_contains,
+    },
+    schema::{SchemaLike, SerdeArrowSchema},
+    utils::Item,
+};
+
+use super::utils::Test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Months(u32);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DayTime {
+    days: i32,
+    milliseconds: i32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    year_month: Months,
+    day_time: Option<DayTime>,
+    month_day_nano: (i32, i32, i64),
+}
+
+fn schema() -> serde_json::Value {
+    json!([
+        {"name": "year_month", "data_type": "Interval(YearMonth)"},
+        {"name": "day_time", "data_type": "Interval(DayTime)", "nullable": true},
+        {"name": "month_day_nano", "data_type": "Interval(MonthDayNano)"},
+    ])
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            year_month: Months(13),
+            day_time: Some(DayTime {
+                days: 2,
+                milliseconds: 500,
+            }),
+            month_day_nano: (1, -2, 3_000_000_000),
+        },
+        Record {
+            year_month: Months(0),
+            day_time: None,
+            month_day_nano: (-1, 0, -5),
+        },
+        Record {
+            year_month: Months(1),
+            day_time: Some(DayTime {
+                days: -3,
+                milliseconds: 0,
+            }),
+            month_day_nano: (0, 0, 0),
+        },
+    ]
+}
+
+/// Deserializing `DayTime` intervals requires arrow>=52
+fn skip_unsupported_arrow(test: &mut Test) {
+    test.impls.arrow &= cfg!(has_arrow_interval_struct_support);
+}
+
+#[test]
+fn schema_repr() {
+    let schema = SerdeArrowSchema::from_value(schema()).unwrap();
+    assert_eq!(
+        schema.fields[0].data_type,
+        DataType::Interval(IntervalUnit::YearMonth)
+    );
+    assert_eq!(
+        schema.fields[1].data_type,
+        DataType::Interval(IntervalUnit::DayTime)
+    );
+    assert_eq!(
+        schema.fields[2].data_type,
+        DataType::Interval(IntervalUnit::MonthDayNano)
+    );
+
+    let value = serde_json::to_value(&schema).unwrap();
+    assert_eq!(value["fields"], self::schema());
+}
+
+#[test]
+fn invalid_units_are_rejected() {
+    let res = SerdeArrowSchema::from_value(json!([
+        {"name": "item", "data_type": "Interval(Week)"},
+    ]));
+    assert_error_contains(&res, "Invalid IntervalUnit: Week");
+}
+
+#[test]
+fn arrow_field_repr() {
+    let fields = Vec::<FieldRef>::from_value(schema()).unwrap();
+    assert_eq!(
+        fields[0].data_type(),
+        &ArrowDataType::Interval(ArrowUnit::YearMonth)
+    );
+    assert_eq!(
+        fields[1].data_type(),
+        &ArrowDataType::Interval(ArrowUnit::DayTime)
+    );
+    assert_eq!(
+        fields[2].data_type(),
+        &ArrowDataType::Interval(ArrowUnit::MonthDayNano)
+    );
+
+    let schema = SerdeArrowSchema::try_from(fields.as_slice()).unwrap();
+    assert_eq!(
+        serde_json::to_value(&schema).unwrap()["fields"],
+        self::schema()
+    );
+}
+
+#[test]
+fn roundtrip() {
+    let items = items();
+    Test::new()
+        .with_schema(schema())
+        .serialize(&items)
+        .also(|it| {
+            let arrays = it.arrays.arrow.as_ref().unwrap();
+
+            let year_month = arrays[0]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<IntervalYearMonthType>>()
+                .unwrap();
+            assert_eq!(year_month.values().as_ref(), &[13, 0, 1]);
+
+            let day_time = arrays[1]
+                .as_any()
+                .downcast_ref::<PrimitiveArray<IntervalDayTimeType>>()
+                .unwrap();
+            assert_eq!(day_time.value(0), IntervalDayTimeType::make_value(2, 500));
+            assert_eq!(day_time.value(2), IntervalDayTimeType::make_value(-3, 0));
+        })
+        .check_nulls(&[
+            &[false, false, false],
+            &[false, true, false],
+            &[false, false, false],
+        ])
+        .also(skip_unsupported_arrow)
+        .deserialize(&items);
+}
+
+#[cfg(not(has_arrow_interval_struct_support))]
+#[test]
+fn arrow_day_time_views_are_unsupported() {
+    let fields = Vec::<FieldRef>::from_value(schema()).unwrap();
+    let arrays = crate::to_arrow(&fields, items()).unwrap();
+
+    let res = crate::from_arrow::<Vec<Record>, _>(&fields, &arrays);
+    assert_error_contains(
+        &res,
+        "Interval(DayTime) arrays are not supported for arrow<52",
+    );
+}
+
+#[test]
+fn day_time_structs_allow_missing_fields() {
+    #[derive(Serialize)]
+    struct Days {
+        days: i32,
+    }
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "Interval(DayTime)"},
+        ]))
+        .serialize(&[Item(Days { days: 7 })])
+        .also(skip_unsupported_arrow)
+        .deserialize(&[Item((7, 0))]);
+}
+
+#[test]
+fn unknown_struct_fields_are_rejected() {
+    #[derive(Serialize)]
+    struct Weeks {
+        weeks: i32,
+    }
+
+    let res = Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "Interval(DayTime)"},
+        ]))
+        .try_serialize_arrow(&[Item(Weeks { weeks: 1 })]);
+    assert_error_contains(&res, "Unknown field \"weeks\" for Interval(DayTime)");
+}
+
+#[test]
+fn integers_require_year_month_intervals() {
+    let res = Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "Interval(MonthDayNano)"},
+        ]))
+        .try_serialize_arrow(&[Item(3)]);
+    assert_error_contains(
+        &res,
+        "Interval(MonthDayNano) values must be given as a sequence",
+    );
+}
+
+#[test]
+fn wrong_number_of_components_is_rejected() {
+    let res = Test::new()
+        .with_schema(json!([
+            {"name": "item", "data_type": "Interval(MonthDayNano)"},
+        ]))
+        .try_serialize_arrow(&[Item((1, 2))]);
+    assert_error_contains(
+        &res,
+        "Invalid number of components for Interval(MonthDayNano): got 2, expected 3",
+    );
+}
@@ -131,3 +131,33 @@ fn bigdecimal_too_small_precision() {
         .expect_err("Expected error");
     assert!(err.to_string().contains("not enough precision"));
 }
+
+#[test]
+fn rust_decimal_guessed_from_samples() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper {
+        #[serde(with = "rust_decimal::serde::str")]
+        value: Decimal,
+    }
+
+    let items = [
+        Wrapper {
+            value: Decimal::from_str("0.20").unwrap(),
+        },
+        Wrapper {
+            value: Decimal::from_str("-13.5").unwrap(),
+        },
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "value", "data_type": "Decimal128(4, 2)"},
+        ]))
+        .trace_schema_from_samples(
+            &items,
+            crate::schema::TracingOptions::default().guess_decimals(true),
+        )
+        .serialize(&items)
+        .also(|it| assert_eq!(get_i128_values(it), &[20, -1350]))
+        .deserialize(&items);
+}
@@ -184,3 +184,126 @@ fn serde_json_nullable_strings_non_nullable_field() {
     assert_error_contains(&res, "Cannot push null for non-nullable array");
     assert_error_contains(&res, "field: \"$.a\"");
 }
+
+#[test]
+fn serde_json_temporal_types_as_strings() {
+    let items = json!([
+        {
+            "date32": "2024-09-30",
+            "date64": "2024-09-30T12:00:00",
+            "timestamp": "2024-09-30T12:00:00Z",
+            "time64": "12:30:00",
+            "duration": "PT10s",
+        },
+        {
+            "date32": null,
+            "date64": null,
+            "timestamp": null,
+            "time64": null,
+            "duration": null,
+        },
+    ]);
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "date32", "data_type": "Date32", "nullable": true},
+            {
+                "name": "date64",
+                "data_type": "Date64",
+                "nullable": true,
+                "strategy": "NaiveStrAsDate64",
+            },
+            {"name": "timestamp", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))", "nullable": true},
+            {"name": "time64", "data_type": "Time64(Nanosecond)", "nullable": true},
+            {"name": "duration", "data_type": "Duration(Second)", "nullable": true},
+        ]))
+        .serialize(&items)
+        .deserialize_json(&items);
+}
+
+#[test]
+fn serde_json_decimals_as_strings() {
+    let items = json!([{ "a": "1.23" }, { "a": "-0.50" }]);
+
+    Test::new()
+        .with_schema(json!([{"name": "a", "data_type": "Decimal128(5, 2)"}]))
+        .serialize(&items)
+        .deserialize_json(&items);
+}
+
+#[test]
+fn serde_json_binary_as_arrays() {
+    #[derive(serde::Serialize)]
+    struct Record<'a> {
+        #[serde(with = "serde_bytes")]
+        a: &'a [u8],
+    }
+
+    let items = [Record { a: b"\x00\x01" }, Record { a: b"\xff" }];
+
+    Test::new()
+        .with_schema(json!([{"name": "a", "data_type": "LargeBinary"}]))
+        .serialize(&items)
+        .deserialize_json(&json!([{ "a": [0, 1] }, { "a": [255] }]));
+}
+
+#[test]
+fn serde_json_unions_as_externally_tagged() {
+    #[derive(serde::Serialize)]
+    enum U {
+        A,
+        B(u8),
+        C { value: String },
+    }
+
+    #[derive(serde::Serialize)]
+    struct Record {
+        u: U,
+    }
+
+    let items = [
+        Record { u: U::A },
+        Record { u: U::B(13) },
+        Record {
+            u: U::C {
+                value: String::from("hello"),
+            },
+        },
+    ];
+
+    Test::new()
+        .trace_schema_from_samples(&items, TracingOptions::default().allow_null_fields(true))
+        .serialize(&items)
+        .deserialize_json(&json!([
+            { "u": { "A": null } },
+            { "u": { "B": 13 } },
+            { "u": { "C": { "value": "hello" } } },
+        ]));
+}
+
+#[test]
+fn serde_json_nested_struct_into_hash_map() {
+    let items = json!([
+        { "a": { "b": "2024-09-30", "c": "1.50" } },
+        { "a": { "b": "2024-10-01", "c": "2.00" } },
+    ]);
+
+    let test = Test::new()
+        .with_schema(json!([{
+            "name": "a",
+            "data_type": "Struct",
+            "children": [
+                {"name": "b", "data_type": "Date32"},
+                {"name": "c", "data_type": "Decimal128(5, 2)"},
+            ],
+        }]))
+        .serialize(&items);
+
+    let fields = test.get_arrow_fields();
+    let actual: Vec<HashMap<String, Value>> =
+        crate::from_arrow(&fields, test.arrays.arrow.as_ref().unwrap()).unwrap();
+
+    assert_eq!(actual.len(), 2);
+    assert_eq!(actual[0]["a"], json!({ "b": "2024-09-30", "c": "1.50" }));
+    assert_eq!(actual[1]["a"], json!({ "b": "2024-10-01", "c": "2.00" }));
+}
@@ -1,6 +1,17 @@
+use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+
+use arrayvec::ArrayVec;
+use serde::{
+    de::{SeqAccess, Visitor},
+    Deserialize,
+};
 use serde_json::json;
 
-use crate::internal::{schema::TracingOptions, utils::Item};
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::{schema::TracingOptions, testing::assert_error_contains, utils::Item},
+    schema::SchemaLike,
+};
 
 use super::utils::Test;
 
@@ -185,3 +196,266 @@ fn byte_arrays() {
         .serialize(&items)
         .deserialize(&items);
 }
+
+#[test]
+fn fixed_size_arrays_from_lists() {
+    let items = [Item([0_u32, 1, 2]), Item([3, 4, 5])];
+
+    for data_type in ["List", "LargeList"] {
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": data_type,
+                "children": [{"name": "element", "data_type": "U32"}],
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+}
+
+#[test]
+fn nullable_fixed_size_arrays_from_lists() {
+    let items = [Item(Some([0_u32, 1])), Item(None), Item(Some([2, 3]))];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "LargeList",
+            "nullable": true,
+            "children": [{"name": "element", "data_type": "U32"}],
+        }]))
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn fixed_size_arrays_from_lists_with_incorrect_length() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "item",
+        "data_type": "LargeList",
+        "nullable": true,
+        "children": [{"name": "element", "data_type": "U32"}],
+    }]))
+    .unwrap();
+
+    let items = [Item(Some(vec![0_u32, 1, 2])), Item(Some(vec![3, 4]))];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let res = crate::from_arrow::<Vec<Item<[u32; 3]>>, _>(&fields, &arrays);
+    assert_error_contains(
+        &res,
+        "Cannot deserialize list 1 of length 2 into a fixed size container of length 3",
+    );
+    assert_error_contains(&res, "$.item");
+
+    let items = [Item(Some(vec![0_u32, 1, 2])), Item(None)];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let res = crate::from_arrow::<Vec<Item<[u32; 3]>>, _>(&fields, &arrays);
+    assert_error_contains(&res, "Cannot deserialize null list 1");
+}
+
+#[test]
+fn array_vecs() {
+    let items = [
+        Item(ArrayVec::<u32, 3>::from_iter([0, 1])),
+        Item(ArrayVec::from_iter([2, 3, 4])),
+        Item(ArrayVec::new()),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "U32"}],
+        }]))
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn array_vecs_exceeding_capacity() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "item",
+        "data_type": "List",
+        "children": [{"name": "element", "data_type": "U32"}],
+    }]))
+    .unwrap();
+
+    let items = [Item(vec![0_u32, 1]), Item(vec![2, 3, 4, 5])];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let res = crate::from_arrow::<Vec<Item<ArrayVec<u32, 3>>>, _>(&fields, &arrays);
+    assert_error_contains(&res, "list length: 4");
+    assert_error_contains(&res, "$.item");
+}
+
+#[test]
+fn vec_deques() {
+    let items = [
+        Item(VecDeque::from([0_u32, 1])),
+        Item(VecDeque::from([2, 3, 4])),
+        Item(VecDeque::new()),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "U32"}],
+        }]))
+        .trace_schema_from_type::<Item<VecDeque<u32>>>(TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn btree_sets() {
+    let items = [
+        Item(BTreeSet::from([String::from("a"), String::from("b")])),
+        Item(BTreeSet::new()),
+        Item(BTreeSet::from([String::from("c")])),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "LargeUtf8"}],
+        }]))
+        .trace_schema_from_type::<Item<BTreeSet<String>>>(TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn hash_sets() {
+    let items = [
+        Item(HashSet::from([0_i64, -1, 2])),
+        Item(HashSet::new()),
+        Item(HashSet::from([3])),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "I64"}],
+        }]))
+        .trace_schema_from_type::<Item<HashSet<i64>>>(TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn linked_lists_and_boxed_slices() {
+    let items = [
+        Item((LinkedList::from([0_u8, 1]), Box::<[u8]>::from([2, 3, 4]))),
+        Item((LinkedList::new(), Box::<[u8]>::from([]))),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Struct",
+            "children": [
+                {
+                    "name": "0",
+                    "data_type": "List",
+                    "children": [{"name": "element", "data_type": "U8"}],
+                },
+                {
+                    "name": "1",
+                    "data_type": "List",
+                    "children": [{"name": "element", "data_type": "U8"}],
+                },
+            ],
+            "strategy": "TupleAsStruct",
+        }]))
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn sets_from_fixed_size_lists() {
+    let items = [
+        Item(BTreeSet::from([0_u16, 1])),
+        Item(BTreeSet::from([2, 3])),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeList(2)",
+            "children": [{"name": "element", "data_type": "U16"}],
+        }]))
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn vecs_are_preallocated_with_the_list_length() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "item",
+        "data_type": "List",
+        "children": [{"name": "element", "data_type": "U32"}],
+    }]))
+    .unwrap();
+
+    let items = [
+        Item(vec![0_u32, 1, 2, 3, 4]),
+        Item(vec![]),
+        Item(vec![5; 17]),
+    ];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let actual = crate::from_arrow::<Vec<Item<Vec<u32>>>, _>(&fields, &arrays).unwrap();
+
+    assert_eq!(actual, items);
+    for Item(list) in &actual {
+        assert_eq!(list.capacity(), list.len());
+    }
+}
+
+/// Record the size hints reported at the start of each list
+#[derive(Debug, PartialEq)]
+struct SizeHints(Vec<Option<usize>>);
+
+impl<'de> Deserialize<'de> for SizeHints {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SizeHintsVisitor;
+
+        impl<'de> Visitor<'de> for SizeHintsVisitor {
+            type Value = SizeHints;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(formatter, "a sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<SizeHints, A::Error> {
+                let mut hints = vec![seq.size_hint()];
+                while seq.next_element::<u32>()?.is_some() {
+                    hints.push(seq.size_hint());
+                }
+                Ok(SizeHints(hints))
+            }
+        }
+
+        deserializer.deserialize_seq(SizeHintsVisitor)
+    }
+}
+
+#[test]
+fn size_hints_of_lists() {
+    for data_type in ["List", "LargeList", "FixedSizeList(3)"] {
+        let fields = Vec::<FieldRef>::from_value(json!([{
+            "name": "item",
+            "data_type": data_type,
+            "children": [{"name": "element", "data_type": "U32"}],
+        }]))
+        .unwrap();
+
+        let items = [Item(vec![0_u32, 1, 2]), Item(vec![3, 4, 5])];
+        let arrays = crate::to_arrow(&fields, &items).unwrap();
+        let actual = crate::from_arrow::<Vec<Item<SizeHints>>, _>(&fields, &arrays).unwrap();
+
+        let expected = || Item(SizeHints(vec![Some(3), Some(2), Some(1), Some(0)]));
+        assert_eq!(actual, [expected(), expected()], "{data_type}");
+    }
+}
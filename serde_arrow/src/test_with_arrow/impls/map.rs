@@ -1,10 +1,11 @@
 use std::collections::{BTreeMap, HashMap};
 
+use serde::Serialize;
 use serde_json::json;
 
 use crate::internal::{
-    schema::TracingOptions,
-    testing::hash_map,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    testing::{assert_error_contains, hash_map},
     utils::{btree_map, Item},
 };
 
@@ -294,6 +295,111 @@ fn map_as_map_int_keys() {
         .deserialize(values);
 }
 
+#[test]
+fn map_as_struct_int_keys() {
+    // maps with non-string keys are traced as maps even with map_as_struct
+    let tracing_options = TracingOptions::default();
+    type Ty = Option<BTreeMap<u8, u32>>;
+    let values: &[Item<Ty>] = &[
+        Item(None),
+        Item(Some(btree_map! {})),
+        Item(Some(btree_map! { 1_u8 => 1_u32, 2_u8 => 2_u32 })),
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {
+                "name": "item",
+                "data_type": "Map",
+                "nullable": true,
+                "children": [
+                    {
+                        "name": "entries",
+                        "data_type": "Struct",
+                        "children": [
+                            {"name": "key", "data_type": "U8"},
+                            {"name": "value", "data_type": "U32"},
+                        ],
+                    },
+                ],
+            },
+        ]))
+        .trace_schema_from_samples(values, tracing_options.clone())
+        .serialize(values)
+        .deserialize(values);
+}
+
+#[test]
+fn map_as_struct_mixed_keys() {
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Serialize)]
+    #[serde(untagged)]
+    enum Key {
+        Str(String),
+        Int(i32),
+    }
+
+    let values: [Item<BTreeMap<Key, u32>>; 2] = [
+        Item(btree_map! { Key::Str(String::from("a")) => 1_u32 }),
+        Item(btree_map! { Key::Int(1) => 2_u32 }),
+    ];
+    let res = SerdeArrowSchema::from_samples(&values, TracingOptions::default());
+    assert_error_contains(&res, "Consider setting `map_as_struct` to `false`");
+}
+
+#[test]
+fn map_as_map_datetime_keys() {
+    let tracing_options = TracingOptions::default()
+        .map_as_struct(false)
+        .guess_dates(true);
+    type Ty = BTreeMap<String, u32>;
+    let values: &[Item<Ty>] = &[
+        Item(btree_map! { "2024-01-01T00:00:00" => 1_u32 }),
+        Item(btree_map! { "2024-01-02T12:30:00" => 2_u32 }),
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {
+                "name": "item",
+                "data_type": "Map",
+                "children": [
+                    {
+                        "name": "entries",
+                        "data_type": "Struct",
+                        "children": [
+                            {"name": "key", "data_type": "Date64", "strategy": "NaiveStrAsDate64"},
+                            {"name": "value", "data_type": "U32"},
+                        ],
+                    },
+                ],
+            },
+        ]))
+        .trace_schema_from_samples(values, tracing_options.clone())
+        .serialize(values)
+        .deserialize(values);
+}
+
+#[test]
+fn map_null_keys_are_rejected() {
+    let res = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "item",
+            "data_type": "Map",
+            "children": [
+                {
+                    "name": "entries",
+                    "data_type": "Struct",
+                    "children": [
+                        {"name": "key", "data_type": "Null", "nullable": true},
+                        {"name": "value", "data_type": "U32"},
+                    ],
+                },
+            ],
+        },
+    ]));
+    assert_error_contains(&res, "keys must not be of type Null");
+}
+
 #[test]
 fn hash_maps() {
     let tracing_options = TracingOptions::new().map_as_struct(false);
@@ -459,3 +565,85 @@ fn btree_maps() {
         .serialize(values)
         .deserialize(values);
 }
+
+#[test]
+fn btree_maps_dictionary_keys() {
+    type Ty = BTreeMap<String, u32>;
+    let values: &[Item<Ty>] = &[
+        Item(btree_map! {"a" => 1_u32, "b" => 2_u32}),
+        Item(btree_map! {"b" => 3_u32}),
+        Item(btree_map! {}),
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {
+                "name": "item",
+                "data_type": "Map",
+                "children": [
+                    {
+                        "name": "entries",
+                        "data_type": "Struct",
+                        "children": [
+                            {
+                                "name": "key",
+                                "data_type": "Dictionary",
+                                "children": [
+                                    {"name": "key", "data_type": "U32"},
+                                    {"name": "value", "data_type": "Utf8"},
+                                ],
+                            },
+                            {"name": "value", "data_type": "U32"},
+                        ],
+                    },
+                ],
+            },
+        ]))
+        .serialize(values)
+        .deserialize(values);
+}
+
+#[test]
+fn btree_maps_dictionary_keys_traced() {
+    let tracing_options = TracingOptions::default()
+        .map_as_struct(false)
+        .string_dictionary_encoding(true);
+    type Ty = BTreeMap<String, u32>;
+    let values: &[Item<Ty>] = &[
+        Item(btree_map! {"a" => 1_u32, "b" => 2_u32}),
+        Item(btree_map! {"b" => 3_u32}),
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {
+                "name": "item",
+                "data_type": "Map",
+                "children": [
+                    {
+                        "name": "entries",
+                        "data_type": "Struct",
+                        "children": [
+                            {
+                                "name": "key",
+                                "data_type": "Dictionary",
+                                "children": [
+                                    {"name": "key", "data_type": "U32"},
+                                    {"name": "value", "data_type": "LargeUtf8"},
+                                ],
+                            },
+                            {"name": "value", "data_type": "U32"},
+                        ],
+                    },
+                ],
+            },
+        ]))
+        .trace_schema_from_samples(values, tracing_options.clone())
+        .trace_schema_from_type::<Item<Ty>>(tracing_options)
+        .serialize(values)
+        .deserialize(values)
+        .deserialize_borrowed::<Item<BTreeMap<&str, u32>>>(&[
+            Item(btree_map! {"a" => 1_u32, "b" => 2_u32}),
+            Item(btree_map! {"b" => 3_u32}),
+        ]);
+}
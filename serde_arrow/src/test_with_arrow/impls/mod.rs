@@ -1,17 +1,26 @@
 mod utils;
 
+mod adaptive_strings;
 mod bool8;
 mod bytes;
 mod chrono;
+mod decimal256;
 mod dictionary;
 mod examples;
 mod fixed_size_list;
+mod intervals;
 mod jiff;
 mod json_values;
 mod list;
 mod map;
+mod non_zero;
+mod ordered_float;
 mod primitives;
+mod row_hash;
+mod scaled_int;
+mod string_hash;
 mod r#struct;
+mod time;
 mod tuple;
 mod r#union;
 mod wrappers;
@@ -0,0 +1,91 @@
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
+
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::{schema::TracingOptions, testing::assert_error_contains, utils::Item},
+    schema::SchemaLike,
+};
+
+use super::utils::Test;
+
+macro_rules! test_non_zero {
+    ($name:ident, $ty:ident, $data_type:expr, [$($value:expr),*]) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn values() {
+                let items = [$(Item($ty::new($value).unwrap())),*];
+                Test::new()
+                    .with_schema(json!([{"name": "item", "data_type": $data_type}]))
+                    .trace_schema_from_type::<Item<$ty>>(TracingOptions::default())
+                    .trace_schema_from_samples(&items, TracingOptions::default())
+                    .serialize(&items)
+                    .deserialize(&items);
+            }
+
+            #[test]
+            fn options() {
+                let items = [$(Item(Some($ty::new($value).unwrap())),)* Item(None)];
+                Test::new()
+                    .with_schema(json!([{"name": "item", "data_type": $data_type, "nullable": true}]))
+                    .trace_schema_from_type::<Item<Option<$ty>>>(TracingOptions::default())
+                    .trace_schema_from_samples(&items, TracingOptions::default())
+                    .serialize(&items)
+                    .deserialize(&items);
+            }
+        }
+    };
+}
+
+test_non_zero!(u8, NonZeroU8, "U8", [1, 42, u8::MAX]);
+test_non_zero!(u16, NonZeroU16, "U16", [1, 42, u16::MAX]);
+test_non_zero!(u32, NonZeroU32, "U32", [1, 42, u32::MAX]);
+test_non_zero!(u64, NonZeroU64, "U64", [1, 42, u64::MAX]);
+test_non_zero!(i8, NonZeroI8, "I8", [i8::MIN, -1, 1, i8::MAX]);
+test_non_zero!(i16, NonZeroI16, "I16", [i16::MIN, -1, 1, i16::MAX]);
+test_non_zero!(i32, NonZeroI32, "I32", [i32::MIN, -1, 1, i32::MAX]);
+test_non_zero!(i64, NonZeroI64, "I64", [i64::MIN, -1, 1, i64::MAX]);
+
+#[test]
+fn zero_values_are_reported_with_their_row() {
+    let fields =
+        Vec::<FieldRef>::from_value(json!([{"name": "item", "data_type": "U32"}])).unwrap();
+    let arrays = crate::to_arrow(&fields, &[Item(1_u32), Item(2), Item(0)]).unwrap();
+
+    let res = crate::from_arrow::<Vec<Item<NonZeroU32>>, _>(&fields, &arrays);
+    assert_error_contains(
+        &res,
+        "Value 0 in row 2 cannot be deserialized as a non-zero u32",
+    );
+    assert_error_contains(&res, "field: \"$.item\"");
+}
+
+#[test]
+fn zero_values_in_options_are_errors() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "item", "data_type": "I64", "nullable": true},
+    ]))
+    .unwrap();
+    let arrays = crate::to_arrow(&fields, &[Item(None), Item(Some(0_i64))]).unwrap();
+
+    let res = crate::from_arrow::<Vec<Item<Option<NonZeroI64>>>, _>(&fields, &arrays);
+    assert_error_contains(
+        &res,
+        "Value 0 in row 1 cannot be deserialized as a non-zero i64",
+    );
+}
+
+#[test]
+fn zero_values_are_allowed_for_plain_integers() {
+    let items = [Item(0_u32), Item(1)];
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "U32"}]))
+        .trace_schema_from_type::<Item<u32>>(TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items);
+}
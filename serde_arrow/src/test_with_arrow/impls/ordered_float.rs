@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use ordered_float::{NotNan, OrderedFloat};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, ArrayRef, PrimitiveArray},
+        datatypes::{FieldRef, Float64Type},
+    },
+    internal::{schema::TracingOptions, testing::assert_error_contains, utils::Item},
+    schema::SchemaLike,
+    test_with_arrow::impls::utils::Test,
+};
+
+#[test]
+fn ordered_float() {
+    let items = [
+        Item(OrderedFloat(1.5)),
+        Item(OrderedFloat(f64::INFINITY)),
+        Item(OrderedFloat(-0.25)),
+    ];
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "F64"}]))
+        .trace_schema_from_type::<Item<OrderedFloat<f64>>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn nullable_ordered_float() {
+    let items = [
+        Item(Some(OrderedFloat(1.5))),
+        Item(None),
+        Item(Some(OrderedFloat(-0.25))),
+    ];
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "F64", "nullable": true}]))
+        .trace_schema_from_type::<Item<Option<OrderedFloat<f64>>>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn ordered_float_f32() {
+    let items = [Item(OrderedFloat(1.5_f32)), Item(OrderedFloat(-0.25_f32))];
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "F32"}]))
+        .trace_schema_from_type::<Item<OrderedFloat<f32>>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn not_nan() {
+    let items = [
+        Item(NotNan::new(1.5).unwrap()),
+        Item(NotNan::new(-0.25).unwrap()),
+    ];
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "F64"}]))
+        .trace_schema_from_type::<Item<NotNan<f64>>>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn newtype_wrappers() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Price(NotNan<f64>);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        price: Price,
+        weight: OrderedFloat<f64>,
+    }
+
+    let items = [
+        Record {
+            price: Price(NotNan::new(9.99).unwrap()),
+            weight: OrderedFloat(2.0),
+        },
+        Record {
+            price: Price(NotNan::new(0.5).unwrap()),
+            weight: OrderedFloat(f64::INFINITY),
+        },
+    ];
+    Test::new()
+        .with_schema(json!([
+            {"name": "price", "data_type": "F64"},
+            {"name": "weight", "data_type": "F64"},
+        ]))
+        .trace_schema_from_type::<Record>(TracingOptions::default())
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items);
+}
+
+#[test]
+fn not_nan_rejects_nan_values() {
+    let fields =
+        Vec::<FieldRef>::from_value(json!([{"name": "item", "data_type": "F64"}])).unwrap();
+    let values = PrimitiveArray::<Float64Type>::from(vec![1.0, 2.0, f64::NAN, 4.0]);
+    let arrays: Vec<ArrayRef> = vec![Arc::new(values)];
+
+    let res = crate::from_arrow::<Vec<Item<NotNan<f64>>>, _>(&fields, &arrays);
+    assert_error_contains(&res, "NaN");
+    assert_error_contains(&res, "$.item");
+
+    let items: Vec<Item<OrderedFloat<f64>>> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert!(items[2].0.is_nan());
+
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let values = arrays[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<Float64Type>>()
+        .unwrap();
+    assert!(values.value(2).is_nan());
+}
@@ -32,6 +32,55 @@ fn null() {
     // nulls = [true, true, true],
 }
 
+#[test]
+fn null_into_options_of_any_type() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        a: u32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Nested {
+        a: Option<u64>,
+        b: Option<String>,
+        c: Option<Vec<u32>>,
+        d: Option<Record>,
+        e: Option<Option<bool>>,
+    }
+
+    let items = &[
+        Item(Nested {
+            a: None,
+            b: None,
+            c: None,
+            d: None,
+            e: None,
+        }),
+        Item(Nested {
+            a: None,
+            b: None,
+            c: None,
+            d: None,
+            e: None,
+        }),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Struct",
+            "children": [
+                {"name": "a", "data_type": "Null", "nullable": true},
+                {"name": "b", "data_type": "Null", "nullable": true},
+                {"name": "c", "data_type": "Null", "nullable": true},
+                {"name": "d", "data_type": "Null", "nullable": true},
+                {"name": "e", "data_type": "Null", "nullable": true},
+            ],
+        }]))
+        .serialize(items)
+        .deserialize(items);
+}
+
 #[test]
 fn bool() {
     let items = &[Item(true), Item(false)];
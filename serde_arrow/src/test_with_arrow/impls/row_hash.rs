@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, FixedSizeBinaryArray, PrimitiveArray, StructArray},
+        datatypes::UInt64Type,
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SerdeArrowSchema, Strategy},
+};
+
+use super::utils::Test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Record {
+    id: u32,
+    name: Option<String>,
+    tags: Vec<String>,
+}
+
+fn record(id: u32, name: Option<&str>, tags: &[&str]) -> Record {
+    Record {
+        id,
+        name: name.map(String::from),
+        tags: tags.iter().map(|tag| String::from(*tag)).collect(),
+    }
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        record(0, Some("a"), &["x"]),
+        record(0, Some("a"), &["x"]),
+        record(0, None, &["x"]),
+        record(0, Some("a"), &[]),
+        record(0, Some("ax"), &[]),
+        record(1, Some("a"), &["x"]),
+    ]
+}
+
+fn schema(hash_type: Value) -> Value {
+    let mut hash = json!({"name": "row_hash", "strategy": "RowHash"});
+    hash["data_type"] = hash_type;
+    json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "name", "data_type": "LargeUtf8", "nullable": true},
+        hash,
+        {
+            "name": "tags",
+            "data_type": "LargeList",
+            "children": [{"name": "element", "data_type": "LargeUtf8"}],
+        },
+    ])
+}
+
+fn get_hashes(test: &Test, idx: usize) -> Vec<u64> {
+    hashes(test.arrays.arrow.as_ref().unwrap()[idx].as_ref())
+}
+
+fn hashes(array: &dyn Array) -> Vec<u64> {
+    array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<UInt64Type>>()
+        .unwrap()
+        .values()
+        .to_vec()
+}
+
+#[test]
+fn strategy_roundtrip() {
+    let strategy: Strategy = "RowHash".parse().unwrap();
+    assert_eq!(strategy, Strategy::RowHash);
+    assert_eq!(strategy.to_string(), "RowHash");
+}
+
+#[test]
+fn equal_rows_have_equal_hashes() {
+    let items = items();
+    Test::new()
+        .with_schema(schema(json!("U64")))
+        .serialize(&items)
+        .also(|it| {
+            let hashes = get_hashes(it, 2);
+            assert_eq!(hashes[0], hashes[1]);
+            for (idx, hash) in hashes.iter().enumerate().skip(1) {
+                for other in &hashes[idx + 1..] {
+                    assert_ne!(hash, other);
+                }
+            }
+        })
+        .deserialize(&items);
+}
+
+#[test]
+fn fixed_size_binary_hashes() {
+    let items = items();
+    let expected = get_hashes(
+        &Test::new()
+            .with_schema(schema(json!("U64")))
+            .serialize(&items),
+        2,
+    );
+
+    Test::new()
+        .with_schema(schema(json!("FixedSizeBinary(8)")))
+        .serialize(&items)
+        .also(|it| {
+            let actual = it.arrays.arrow.as_ref().unwrap()[2]
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap()
+                .iter()
+                .map(|value| u64::from_be_bytes(value.unwrap().try_into().unwrap()))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected);
+        })
+        .deserialize(&items);
+}
+
+#[test]
+fn nested_structs_hash_their_fields() {
+    #[derive(Serialize)]
+    struct Outer {
+        id: u32,
+        inner: Inner,
+    }
+
+    #[derive(Serialize)]
+    struct Inner {
+        value: i64,
+    }
+
+    let items = [
+        Outer {
+            id: 0,
+            inner: Inner { value: 1 },
+        },
+        Outer {
+            id: 1,
+            inner: Inner { value: 1 },
+        },
+    ];
+    Test::new()
+        .with_schema(json!([
+            {"name": "id", "data_type": "U32"},
+            {"name": "hash", "data_type": "U64", "strategy": "RowHash"},
+            {
+                "name": "inner",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "value", "data_type": "I64"},
+                    {"name": "hash", "data_type": "U64", "strategy": "RowHash"},
+                ],
+            },
+        ]))
+        .serialize(&items)
+        .also(|it| {
+            let outer = get_hashes(it, 1);
+            assert_ne!(outer[0], outer[1]);
+
+            let arrays = it.arrays.arrow.as_ref().unwrap();
+            let inner = arrays[2].as_any().downcast_ref::<StructArray>().unwrap();
+            let inner = hashes(inner.column(1).as_ref());
+            assert_eq!(inner[0], inner[1]);
+            assert_ne!(inner[0], outer[0]);
+        });
+}
+
+#[test]
+fn invalid_schemas() {
+    let res = SerdeArrowSchema::from_value(json!([
+        {"name": "hash", "data_type": "FixedSizeBinary(4)", "strategy": "RowHash"},
+    ]));
+    assert_error_contains(
+        &res,
+        "invalid strategy for FixedSizeBinary(4) field: RowHash",
+    );
+
+    let res = SerdeArrowSchema::from_value(json!([
+        {"name": "hash", "data_type": "I64", "strategy": "RowHash"},
+    ]));
+    assert_error_contains(&res, "RowHash");
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{self, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SerdeArrowSchema, Strategy, TracingOptions},
+    utils::Item,
+};
+
+use super::utils::Test;
+
+fn get_i128_values(test: &Test) -> &[i128] {
+    let arrays = test.arrays.arrow.as_ref().unwrap();
+    let arr = arrays[0]
+        .as_any()
+        .downcast_ref::<arrow::array::PrimitiveArray<arrow::datatypes::Decimal128Type>>()
+        .unwrap();
+    arr.values()
+}
+
+#[test]
+fn strategy_repr() {
+    let strategy: Strategy = "ScaledInt(2)".parse().unwrap();
+    assert_eq!(strategy, Strategy::ScaledInt { scale: 2 });
+    assert_eq!(strategy.to_string(), "ScaledInt(2)");
+
+    let strategy: Strategy = "ScaledInt(-3)".parse().unwrap();
+    assert_eq!(strategy, Strategy::ScaledInt { scale: -3 });
+
+    assert_error_contains(&"ScaledInt(x)".parse::<Strategy>(), "Invalid scale");
+}
+
+#[test]
+fn cents_as_decimals() {
+    let items = [Item(1234_i64), Item(-5_i64), Item(0_i64)];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Decimal128(10, 2)",
+            "strategy": "ScaledInt(2)",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false, false]])
+        .also(|test| assert_eq!(get_i128_values(test), &[1234, -5, 0]));
+}
+
+#[test]
+fn nullable_cents_as_decimals() {
+    let items = [Item(Some(1234_u32)), Item(None), Item(Some(7))];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Decimal128(10, 2)",
+            "strategy": "ScaledInt(2)",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn rescaled_to_the_scale_of_the_field() {
+    let items = [Item(1234_i64), Item(-5_i64)];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Decimal128(12, 4)",
+            "strategy": "ScaledInt(2)",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| assert_eq!(get_i128_values(test), &[123400, -500]));
+
+    let items = [Item(12300_i64), Item(-500_i64)];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Decimal128(10, 2)",
+            "strategy": "ScaledInt(4)",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .also(|test| assert_eq!(get_i128_values(test), &[123, -5]));
+}
+
+#[test]
+fn lost_digits_are_rejected() {
+    let items = [Item(12345_i64)];
+
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Decimal128(10, 2)",
+        "strategy": "ScaledInt(4)",
+    }]));
+    assert_error_contains(&test.try_serialize_arrow(&items), "without losing digits");
+    assert_error_contains(&test.try_serialize_arrow2(&items), "without losing digits");
+}
+
+#[test]
+fn precision_overflow_is_rejected() {
+    let items = [Item(999_i64), Item(1000_i64)];
+
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Decimal128(3, 2)",
+        "strategy": "ScaledInt(2)",
+    }]));
+    assert_error_contains(
+        &test.try_serialize_arrow(&items),
+        "Value 1000 does not fit into a decimal with precision 3",
+    );
+}
+
+#[test]
+fn integer_overflow_on_deserialization() {
+    let items = [Item(1000_i64)];
+    let test = Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Decimal128(10, 2)",
+            "strategy": "ScaledInt(2)",
+        }]))
+        .serialize(&items);
+
+    let fields = test.get_arrow_fields();
+    let res = crate::from_arrow::<Vec<Item<i8>>, _>(&fields, test.arrays.arrow.as_ref().unwrap());
+    assert_error_contains(&res, "field: \"$.item\"");
+}
+
+#[test]
+fn integers_require_the_strategy() {
+    let items = [Item(1234_i64)];
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Decimal128(10, 2)",
+    }]));
+    assert_error_contains(
+        &test.try_serialize_arrow(&items),
+        "Integers can only be serialized into decimals with the ScaledInt strategy",
+    );
+}
+
+#[test]
+fn strategy_requires_decimal_fields() {
+    let res = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "I64",
+        "strategy": "ScaledInt(2)",
+    }]));
+    assert_error_contains(&res, "invalid strategy");
+}
+
+#[test]
+fn configured_via_overwrites() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u32,
+        price_cents: i64,
+    }
+
+    let options = TracingOptions::default()
+        .overwrite(
+            "price_cents",
+            json!({
+                "name": "price_cents",
+                "data_type": "Decimal128(18, 2)",
+                "strategy": "ScaledInt(2)",
+            }),
+        )
+        .unwrap();
+    let fields = Vec::<FieldRef>::from_type::<Order>(options).unwrap();
+
+    let items = vec![
+        Order {
+            id: 0,
+            price_cents: 1999,
+        },
+        Order {
+            id: 1,
+            price_cents: 250,
+        },
+    ];
+    let record_batch = crate::to_record_batch(&fields, &items).unwrap();
+    let actual: Vec<Order> = crate::from_record_batch(&record_batch).unwrap();
+    assert_eq!(actual, items);
+
+    let values: serde_json::Value = crate::from_record_batch(&record_batch).unwrap();
+    assert_eq!(
+        values,
+        json!([{"id": 0, "price_cents": 1999}, {"id": 1, "price_cents": 250}])
+    );
+}
@@ -0,0 +1,224 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, PrimitiveArray, StructArray},
+        datatypes::{FieldRef, UInt64Type},
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, Strategy},
+    ArrayBuilder, SerializerOptions,
+};
+
+use super::utils::Test;
+
+const HASH_ABC: u64 = 0x44BC2CF5AD770999;
+const HASH_A: u64 = 0xD24EC4F1A98C6E5B;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Record {
+    id: u32,
+    name: Option<String>,
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            id: 0,
+            name: Some(String::from("abc")),
+        },
+        Record { id: 1, name: None },
+        Record {
+            id: 2,
+            name: Some(String::from("a")),
+        },
+    ]
+}
+
+fn schema(name_type: &str) -> serde_json::Value {
+    json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "name", "data_type": name_type, "nullable": true},
+        {"name": "name_hash", "data_type": "U64", "nullable": true, "strategy": "StringHash(name)"},
+    ])
+}
+
+fn get_hashes(test: &Test, idx: usize) -> Vec<Option<u64>> {
+    hashes(test.arrays.arrow.as_ref().unwrap()[idx].as_ref())
+}
+
+fn hashes(array: &dyn Array) -> Vec<Option<u64>> {
+    array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<UInt64Type>>()
+        .unwrap()
+        .iter()
+        .collect()
+}
+
+#[test]
+fn strategy_roundtrip() {
+    let strategy: Strategy = "StringHash(name)".parse().unwrap();
+    assert_eq!(
+        strategy,
+        Strategy::StringHash {
+            source: String::from("name")
+        }
+    );
+    assert_eq!(strategy.to_string(), "StringHash(name)");
+}
+
+#[test]
+fn hash_column_is_filled() {
+    for name_type in ["Utf8", "LargeUtf8"] {
+        let items = items();
+        Test::new()
+            .with_schema(schema(name_type))
+            .serialize(&items)
+            .also(|it| assert_eq!(get_hashes(it, 2), vec![Some(HASH_ABC), None, Some(HASH_A)]))
+            .deserialize(&items)
+            .check_nulls(&[
+                &[false, false, false],
+                &[false, true, false],
+                &[false, true, false],
+            ]);
+    }
+}
+
+#[test]
+fn hash_column_of_dictionary() {
+    let items = items();
+    Test::new()
+        .with_schema(json!([
+            {"name": "id", "data_type": "U32"},
+            {
+                "name": "name",
+                "data_type": "Dictionary",
+                "nullable": true,
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "LargeUtf8"},
+                ],
+            },
+            {
+                "name": "name_hash",
+                "data_type": "U64",
+                "nullable": true,
+                "strategy": "StringHash(name)",
+            },
+        ]))
+        .serialize(&items)
+        .also(|it| assert_eq!(get_hashes(it, 2), vec![Some(HASH_ABC), None, Some(HASH_A)]))
+        .deserialize(&items);
+}
+
+#[test]
+fn hash_column_is_ignored_in_self_describing_formats() {
+    let fields = Vec::<FieldRef>::from_value(schema("LargeUtf8")).unwrap();
+
+    let arrays = crate::to_arrow(&fields, items()).unwrap();
+    let actual: serde_json::Value = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(
+        actual,
+        json!([
+            {"id": 0, "name": "abc"},
+            {"id": 1, "name": null},
+            {"id": 2, "name": "a"},
+        ])
+    );
+}
+
+#[test]
+fn nested_hash_column() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Outer {
+        inner: Inner,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Inner {
+        key: String,
+    }
+
+    let items = vec![
+        Outer {
+            inner: Inner {
+                key: String::from("a"),
+            },
+        },
+        Outer {
+            inner: Inner {
+                key: String::from("abc"),
+            },
+        },
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {"name": "inner", "data_type": "Struct", "children": [
+                {"name": "key_hash", "data_type": "U64", "strategy": "StringHash(key)"},
+                {"name": "key", "data_type": "LargeUtf8"},
+            ]},
+        ]))
+        .serialize(&items)
+        .also(|it| {
+            let arrays = it.arrays.arrow.as_ref().unwrap();
+            let inner = arrays[0].as_any().downcast_ref::<StructArray>().unwrap();
+            assert_eq!(
+                hashes(inner.column(0).as_ref()),
+                vec![Some(HASH_A), Some(HASH_ABC)]
+            );
+        })
+        .deserialize(&items);
+}
+
+#[test]
+fn strict_schema_accepts_hash_columns() {
+    let fields = Vec::<FieldRef>::from_value(schema("LargeUtf8")).unwrap();
+
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().strict_schema(true));
+    builder.extend(items()).unwrap();
+    let arrays = builder.to_arrow().unwrap();
+    assert_eq!(
+        hashes(arrays[2].as_ref()),
+        vec![Some(HASH_ABC), None, Some(HASH_A)]
+    );
+}
+
+#[test]
+fn missing_source_field() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "name_hash", "data_type": "U64", "strategy": "StringHash(name)"},
+    ]))
+    .unwrap();
+
+    let res = ArrayBuilder::from_arrow(&fields);
+    assert_error_contains(&res, "Source field \"name\" of field \"name_hash\"");
+}
+
+#[test]
+fn non_string_source_field() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "id_hash", "data_type": "U64", "strategy": "StringHash(id)"},
+    ]))
+    .unwrap();
+
+    let res = ArrayBuilder::from_arrow(&fields);
+    assert_error_contains(&res, "must be a string field");
+}
+
+#[test]
+fn invalid_data_type() {
+    let res = Vec::<FieldRef>::from_value(json!([
+        {"name": "name", "data_type": "LargeUtf8"},
+        {"name": "name_hash", "data_type": "U32", "strategy": "StringHash(name)"},
+    ]));
+    assert_error_contains(&res, "invalid strategy for UInt32: StringHash(name)");
+}
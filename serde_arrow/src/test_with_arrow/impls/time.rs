@@ -0,0 +1,182 @@
+//! Tests for the string representations of the `time` crate
+//!
+//! The `time` crate is not a dependency, therefore the tests use the strings
+//! it produces in human-readable formats
+use super::utils::Test;
+use crate::{
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SerdeArrowSchema},
+    utils::Item,
+};
+
+use serde_json::json;
+
+#[test]
+fn offset_date_time_as_timestamp() {
+    let items = [
+        Item(String::from("2020-12-24 08:30:00.0 +00:00:00")),
+        Item(String::from("2023-05-05 16:06:00.123 +00:00:00")),
+    ];
+
+    for unit in ["Millisecond", "Microsecond", "Nanosecond"] {
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": format!("Timestamp({unit}, Some(\"UTC\"))"),
+                "strategy": "TimeCrateStr",
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .check_nulls(&[&[false, false]]);
+    }
+}
+
+#[test]
+fn offset_date_time_with_offset_is_converted_to_utc() {
+    let items = [
+        Item(String::from("2020-12-24 08:30:00.0 +02:00:00")),
+        Item(String::from("2020-12-24 08:30:00.0 -01:30:00")),
+    ];
+    let expected = [
+        Item(String::from("2020-12-24 06:30:00.0 +00:00:00")),
+        Item(String::from("2020-12-24 10:00:00.0 +00:00:00")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Second, Some(\"UTC\"))",
+            "strategy": "TimeCrateStr",
+        }]))
+        .serialize(&items)
+        .deserialize(&expected);
+}
+
+#[test]
+fn primitive_date_time_as_timestamp() {
+    let items = [
+        Item(Some(String::from("2020-12-24 08:30:00.0"))),
+        Item(None),
+        Item(Some(String::from("-0012-05-05 16:06:00.000001"))),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Microsecond, None)",
+            "strategy": "TimeCrateStr",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn time_crate_strings_require_the_strategy() {
+    let items = [Item(String::from("2020-12-24 08:30:00.0 +00:00:00"))];
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Timestamp(Second, Some(\"UTC\"))",
+    }]));
+    assert!(test.try_serialize_arrow(&items).is_err());
+
+    let items = [Item(String::from("2020-12-24 08:30:00.0"))];
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Timestamp(Second, None)",
+    }]));
+    assert!(test.try_serialize_arrow(&items).is_err());
+}
+
+#[test]
+fn primitive_date_time_requires_naive_timestamps() {
+    let items = [Item(String::from("2020-12-24 08:30:00.0"))];
+
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Timestamp(Second, Some(\"UTC\"))",
+        "strategy": "TimeCrateStr",
+    }]));
+    assert_error_contains(&test.try_serialize_arrow(&items), "");
+
+    let items = [Item(String::from("2020-12-24 08:30:00.0 +00:00:00"))];
+
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Timestamp(Second, None)",
+        "strategy": "TimeCrateStr",
+    }]));
+    assert_error_contains(&test.try_serialize_arrow(&items), "");
+}
+
+#[test]
+fn time_as_time64() {
+    let items = [
+        Item(String::from("12:30:00.0")),
+        Item(String::from("23:59:59.999999")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Time64(Microsecond)",
+            "strategy": "TimeCrateStr",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn time_as_time32() {
+    let items = [
+        Item(String::from("12:30:00.0")),
+        Item(String::from("08:00:01.5")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Time32(Millisecond)",
+            "strategy": "TimeCrateStr",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn date_as_date32() {
+    let items = [
+        Item(String::from("2024-09-30")),
+        Item(String::from("-0010-10-30")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Date32",
+            "strategy": "TimeCrateStr",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn invalid_strategies() {
+    let res = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "Timestamp(Second, Some(\"Europe/Berlin\"))",
+        "strategy": "TimeCrateStr",
+    }]));
+    assert_error_contains(&res, "invalid strategy for Timestamp");
+
+    let res = SerdeArrowSchema::from_value(json!([{
+        "name": "item",
+        "data_type": "Date64",
+        "strategy": "TimeCrateStr",
+    }]));
+    assert_error_contains(&res, "invalid strategy for Date64");
+}
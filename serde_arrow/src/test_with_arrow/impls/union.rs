@@ -1,9 +1,14 @@
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
-use crate::internal::{
-    schema::{SchemaLike, TracingOptions},
-    utils::{Item, Items},
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::{
+        schema::{SchemaLike, TracingOptions},
+        testing::assert_error_contains,
+        utils::{Item, Items},
+    },
+    ArrayBuilder, SerializerOptions,
 };
 
 use super::utils::Test;
@@ -361,7 +366,6 @@ fn enums_union() {
 fn missing_union_variants() {
     use crate::_impl::arrow::datatypes::FieldRef;
 
-    use crate::internal::testing::assert_error_contains;
     use crate::schema::TracingOptions;
     use serde::{Deserialize, Serialize};
 
@@ -373,10 +377,10 @@ fn missing_union_variants() {
     }
 
     let tracing_options = TracingOptions::default().allow_null_fields(true);
-    let fields = Vec::<FieldRef>::from_samples(&Items(&[U::A, U::C]), tracing_options).unwrap();
+    let fields = Vec::<FieldRef>::from_samples(Items(&[U::A, U::C]), tracing_options).unwrap();
 
     // NOTE: variant B was never encountered during tracing
-    let res = crate::to_arrow(&fields, &Items(&[U::A, U::B, U::C]));
+    let res = crate::to_arrow(&fields, Items(&[U::A, U::B, U::C]));
     assert_error_contains(&res, "Unknown variant does not support serialize_unit");
     assert_error_contains(&res, "field: \"$.item.<empty>\"")
 }
@@ -441,3 +445,304 @@ fn fieldless_unions_as_large_utf8() {
         .serialize(&values)
         .deserialize(&values);
 }
+
+#[test]
+fn fieldless_unions_as_nullable_utf8() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A,
+        B,
+        C,
+    }
+
+    let values = [
+        Item(Some(U::A)),
+        Item(None),
+        Item(Some(U::C)),
+        Item(Some(U::A)),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "LargeUtf8", "nullable": true}]))
+        .serialize(&values)
+        .deserialize(&values);
+}
+
+#[test]
+fn fieldless_unions_with_renamed_variants_as_utf8() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    enum U {
+        FirstVariant,
+        #[serde(rename = "second")]
+        SecondVariant,
+    }
+
+    let values = [Item(U::FirstVariant), Item(U::SecondVariant)];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Utf8"}]))
+        .serialize(&values)
+        .deserialize(&values);
+}
+
+#[test]
+fn fieldless_unions_from_utf8_unknown_variant() {
+    use crate::_impl::arrow::datatypes::FieldRef;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A,
+        B,
+    }
+
+    let fields =
+        Vec::<FieldRef>::from_value(json!([{"name": "item", "data_type": "Utf8"}])).unwrap();
+    let arrays = crate::to_arrow(&fields, Items(&["A", "C"])).unwrap();
+
+    let res = crate::from_arrow::<Vec<Item<U>>, _>(&fields, &arrays);
+    assert_error_contains(&res, "unknown variant `C`");
+}
+
+#[test]
+fn nullable_union() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A(u8),
+        B(String),
+    }
+
+    let tracing_options = TracingOptions::default();
+    let values = [
+        Item(Some(U::A(13))),
+        Item(None),
+        Item(Some(U::B(String::from("hello")))),
+        Item(None),
+    ];
+
+    Test::new()
+        .with_schema(json!([
+            {
+                "name": "item",
+                "data_type": "Union",
+                "nullable": true,
+                "children": [
+                    {"name": "A", "data_type": "U8"},
+                    {"name": "B", "data_type": "LargeUtf8"},
+                    {"name": "", "data_type": "Null", "nullable": true, "strategy": "NullVariant"},
+                ],
+            },
+        ]))
+        .trace_schema_from_type::<Item<Option<U>>>(tracing_options.clone())
+        .trace_schema_from_samples(&values, tracing_options.clone())
+        .serialize(&values)
+        .deserialize(&values)
+        .deserialize_json(&json!([
+            {"item": {"A": 13}},
+            {"item": null},
+            {"item": {"B": "hello"}},
+            {"item": null},
+        ]));
+}
+
+#[test]
+fn nullable_fieldless_union() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A,
+        B,
+    }
+
+    let tracing_options = TracingOptions::default().allow_null_fields(true);
+    let values = [Item(Some(U::A)), Item(None), Item(Some(U::B))];
+
+    Test::new()
+        .with_schema(json!([
+            {
+                "name": "item",
+                "data_type": "Union",
+                "nullable": true,
+                "children": [
+                    {"name": "A", "data_type": "Null", "nullable": true},
+                    {"name": "B", "data_type": "Null", "nullable": true},
+                    {"name": "", "data_type": "Null", "nullable": true, "strategy": "NullVariant"},
+                ],
+            },
+        ]))
+        .trace_schema_from_type::<Item<Option<U>>>(tracing_options.clone())
+        .trace_schema_from_samples(&values, tracing_options.clone())
+        .serialize(&values)
+        .deserialize(&values);
+}
+
+#[test]
+fn nullable_fieldless_union_as_dictionary() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A,
+        B,
+    }
+
+    let tracing_options = TracingOptions::default().enums_without_data_as_strings(true);
+    let values = [Item(Some(U::A)), Item(None), Item(Some(U::B))];
+
+    Test::new()
+        .with_schema(json!([
+            {
+                "name": "item",
+                "data_type": "Dictionary",
+                "nullable": true,
+                "children": [
+                    {"name": "key", "data_type": "U32"},
+                    {"name": "value", "data_type": "LargeUtf8"},
+                ],
+            },
+        ]))
+        .trace_schema_from_type::<Item<Option<U>>>(tracing_options.clone())
+        .trace_schema_from_samples(&values, tracing_options.clone())
+        .serialize(&values)
+        .deserialize(&values)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn nullable_union_in_nullable_struct() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A(u8),
+        B(bool),
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct S {
+        u: Option<U>,
+    }
+
+    let tracing_options = TracingOptions::default();
+    let values = [
+        Item(Some(S { u: Some(U::A(1)) })),
+        Item(None),
+        Item(Some(S { u: None })),
+        Item(Some(S {
+            u: Some(U::B(true)),
+        })),
+    ];
+
+    Test::new()
+        .trace_schema_from_type::<Item<Option<S>>>(tracing_options.clone())
+        .trace_schema_from_samples(&values, tracing_options.clone())
+        .serialize(&values)
+        .deserialize(&values);
+}
+
+#[test]
+fn null_into_non_nullable_union() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A(u8),
+        B(bool),
+    }
+
+    let values = [Item(Some(U::A(1))), Item(None)];
+
+    let mut test = Test::new().with_schema(json!([
+        {
+            "name": "item",
+            "data_type": "Union",
+            "children": [
+                {"name": "A", "data_type": "U8"},
+                {"name": "B", "data_type": "Bool"},
+            ],
+        },
+    ]));
+
+    let res = test.try_serialize_arrow(&values);
+    assert_error_contains(
+        &res,
+        "Cannot push null for union without a NullVariant child",
+    );
+
+    let res = test.try_serialize_arrow2(&values);
+    assert_error_contains(
+        &res,
+        "Cannot push null for union without a NullVariant child",
+    );
+}
+
+#[test]
+fn null_variant_into_non_optional_enum() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A(u8),
+        B(bool),
+    }
+
+    let values = [Item(Some(U::A(1))), Item(None)];
+    let test = Test::new()
+        .trace_schema_from_type::<Item<Option<U>>>(TracingOptions::default())
+        .serialize(&values);
+
+    let fields = test.get_arrow_fields();
+    let res = crate::from_arrow::<Vec<Item<U>>, _>(&fields, test.arrays.arrow.as_ref().unwrap());
+    assert_error_contains(&res, "Cannot deserialize a null value into an enum");
+}
+
+#[test]
+fn union_capacities() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A(u32),
+        B(String),
+        C { x: i64 },
+    }
+
+    let fields = Vec::<FieldRef>::from_type::<Item<U>>(TracingOptions::default()).unwrap();
+    let options = SerializerOptions::default()
+        .union_capacity("$.item", "A", 100)
+        .union_capacity("$.item", "B", 10);
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(options);
+
+    let batches = [
+        vec![Item(U::A(1)), Item(U::B(String::from("b"))), Item(U::A(2))],
+        vec![Item(U::C { x: 3 }), Item(U::A(4))],
+    ];
+    for items in &batches {
+        builder.extend(items).unwrap();
+        let arrays = builder.to_arrow().unwrap();
+        let actual: Vec<Item<U>> = crate::from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(&actual, items);
+    }
+}
+
+#[test]
+fn union_capacities_of_unknown_fields() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum U {
+        A(u32),
+        B(bool),
+    }
+
+    let fields = Vec::<FieldRef>::from_type::<Item<U>>(TracingOptions::default()).unwrap();
+
+    let options = SerializerOptions::default().union_capacity("$.missing", "A", 10);
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(options);
+    let res = builder.push(Item(U::A(1)));
+    assert_error_contains(
+        &res,
+        "Cannot set the union capacities of \"$.missing\": no union field with this path",
+    );
+
+    let options = SerializerOptions::default().union_capacity("$.item", "C", 10);
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(options);
+    let res = builder.push(Item(U::A(1)));
+    assert_error_contains(
+        &res,
+        "Cannot set the capacity of variant \"C\" of \"$.item\": no variant with this name",
+    );
+}
@@ -0,0 +1,79 @@
+use std::{borrow::Cow, sync::Arc};
+
+use serde::Deserialize;
+
+use crate::{
+    _impl::arrow::datatypes::{Field, FieldRef, Schema, SchemaRef},
+    schema::{SchemaLike, TracingOptions},
+    ArrayBuilder, Deserializer,
+};
+
+use super::fixtures::{fields, items, Record};
+
+#[test]
+fn all_field_types_produce_the_same_arrays() {
+    let field_refs = fields::<Record>();
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let schema = Schema::new(field_refs.clone());
+    let schema_ref: SchemaRef = Arc::new(schema.clone());
+
+    let expected = crate::to_arrow(&field_refs, items()).unwrap();
+
+    assert_eq!(
+        crate::to_arrow(field_refs.as_slice(), items()).unwrap(),
+        expected
+    );
+    assert_eq!(crate::to_arrow(&fields, items()).unwrap(), expected);
+    assert_eq!(
+        crate::to_arrow(fields.as_slice(), items()).unwrap(),
+        expected
+    );
+    assert_eq!(crate::to_arrow(schema.fields(), items()).unwrap(), expected);
+    assert_eq!(crate::to_arrow(&schema, items()).unwrap(), expected);
+    assert_eq!(crate::to_arrow(&schema_ref, items()).unwrap(), expected);
+    assert_eq!(crate::to_arrow(schema_ref, items()).unwrap(), expected);
+}
+
+#[test]
+fn schema_ref_roundtrip() {
+    let fields = fields::<Record>();
+    let schema: SchemaRef = Arc::new(Schema::new(fields));
+
+    let batch = crate::to_record_batch(&schema, &items()).unwrap();
+    assert_eq!(batch.schema(), schema);
+
+    let actual: Vec<Record> = crate::from_arrow(&schema, batch.columns()).unwrap();
+    assert_eq!(actual, items());
+
+    let deserializer = Deserializer::from_arrow(schema.as_ref(), batch.columns()).unwrap();
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, items());
+
+    let mut builder = ArrayBuilder::from_arrow(schema).unwrap();
+    builder.extend(items()).unwrap();
+    assert_eq!(builder.to_record_batch().unwrap(), batch);
+}
+
+#[test]
+fn containers_of_field_refs_are_accepted() {
+    let field_refs = fields::<Record>();
+    let expected = crate::to_arrow(&field_refs, items()).unwrap();
+
+    let array: [FieldRef; 2] = [field_refs[0].clone(), field_refs[1].clone()];
+    let slice: &[FieldRef] = &field_refs;
+    let borrowed: Cow<'_, [FieldRef]> = Cow::Borrowed(&field_refs);
+    let owned: Cow<'_, [FieldRef]> = Cow::Owned(field_refs.clone());
+    let shared: Arc<[FieldRef]> = Arc::from(field_refs.clone());
+    let boxed: Box<[FieldRef]> = field_refs.clone().into_boxed_slice();
+
+    assert_eq!(crate::to_arrow(&array, items()).unwrap(), expected);
+    assert_eq!(crate::to_arrow(slice, items()).unwrap(), expected);
+    assert_eq!(crate::to_arrow(&borrowed, items()).unwrap(), expected);
+    assert_eq!(crate::to_arrow(&owned, items()).unwrap(), expected);
+    assert_eq!(crate::to_arrow(&shared, items()).unwrap(), expected);
+    assert_eq!(crate::to_arrow(&boxed, items()).unwrap(), expected);
+
+    let arrays = crate::to_arrow(&array, items()).unwrap();
+    let actual: Vec<Record> = crate::from_arrow(&field_refs, &arrays).unwrap();
+    assert_eq!(actual, items());
+}
@@ -0,0 +1,125 @@
+use std::{path::PathBuf, sync::Arc};
+
+// arrow-version:replace: use arrow_ipc_{version}::writer::FileWriter;
+use arrow_ipc_53::writer::FileWriter;
+use serde::Deserialize;
+
+use crate::{
+    _impl::arrow::{
+        array::{ArrayRef, DictionaryArray, RecordBatch},
+        datatypes::Int32Type,
+    },
+    internal::testing::assert_error_contains,
+    ipc::{open_mmap, IpcBatches},
+};
+
+use super::fixtures::{fields, Tagged};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "serde_arrow_{name}_{pid}.arrow",
+        pid = std::process::id()
+    ))
+}
+
+fn open<T: serde::de::DeserializeOwned>(path: &PathBuf) -> crate::Result<IpcBatches<T>> {
+    // SAFETY: the files of the tests are not modified while they are read
+    unsafe { open_mmap(path) }
+}
+
+fn write_file(path: &PathBuf, batches: &[RecordBatch]) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut writer = FileWriter::try_new(file, &batches[0].schema()).unwrap();
+    for batch in batches {
+        writer.write(batch).unwrap();
+    }
+    writer.finish().unwrap();
+}
+
+#[test]
+fn batches_are_deserialized_lazily() {
+    let path = temp_path("ipc_batches");
+    let fields = fields::<Tagged>();
+    let batches = [
+        crate::to_record_batch(&fields, &[Tagged::new(0), Tagged::new(1)]).unwrap(),
+        crate::to_record_batch(&fields, &Vec::<Tagged>::new()).unwrap(),
+        crate::to_record_batch(&fields, &[Tagged::new(2)]).unwrap(),
+    ];
+    write_file(&path, &batches);
+
+    let mut iter = open::<Tagged>(&path).unwrap();
+    assert_eq!(iter.schema().fields()[..], fields[..]);
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+
+    assert_eq!(
+        iter.next().unwrap().unwrap(),
+        [Tagged::new(0), Tagged::new(1)]
+    );
+    assert_eq!(iter.next().unwrap().unwrap(), []);
+    assert_eq!(iter.next().unwrap().unwrap(), [Tagged::new(2)]);
+    assert!(iter.next().is_none());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn dictionaries_are_supported() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Item {
+        value: String,
+    }
+
+    let path = temp_path("ipc_dictionaries");
+    let array = DictionaryArray::<Int32Type>::from_iter(["a", "b", "a"]);
+    let batch = RecordBatch::try_from_iter([("value", Arc::new(array) as ArrayRef)]).unwrap();
+    write_file(&path, &[batch]);
+
+    let actual = open::<Item>(&path)
+        .unwrap()
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+    assert_eq!(
+        actual,
+        ["a", "b", "a"].map(|value| Item {
+            value: value.to_owned()
+        })
+    );
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn deserialization_errors_are_reported_per_batch() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Other {
+        id: String,
+    }
+
+    let path = temp_path("ipc_errors");
+    let fields = fields::<Tagged>();
+    let batch = crate::to_record_batch(&fields, &[Tagged::new(1)]).unwrap();
+    write_file(&path, &[batch]);
+
+    let mut iter = open::<Other>(&path).unwrap();
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn invalid_files() {
+    let path = temp_path("ipc_invalid");
+
+    std::fs::write(&path, b"").unwrap();
+    assert_error_contains(&open::<Tagged>(&path), "the file is too short");
+
+    std::fs::write(&path, b"not an arrow ipc file").unwrap();
+    assert_error_contains(&open::<Tagged>(&path), "footer");
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_error_contains(&open::<Tagged>(&path), "Cannot open");
+}
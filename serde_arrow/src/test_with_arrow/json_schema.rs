@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::schema::{SchemaLike, SerdeArrowSchema, TracingOptions};
+
+fn json_schema(schema: &SerdeArrowSchema) -> Value {
+    serde_json::from_str(&schema.to_json_schema().unwrap()).unwrap()
+}
+
+#[test]
+fn primitives() {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "flag", "data_type": "Bool"},
+        {"name": "count", "data_type": "I64", "nullable": true},
+        {"name": "ratio", "data_type": "F32"},
+        {"name": "date", "data_type": "Date32"},
+        {"name": "at", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {"name": "naive", "data_type": "Timestamp(Millisecond, None)"},
+        {"name": "price", "data_type": "Decimal128(10, 2)"},
+        {"name": "cents", "data_type": "Decimal128(10, 2)", "strategy": "ScaledInt(2)"},
+        {"name": "digest", "data_type": "FixedSizeBinary(2)"},
+        {"name": "nothing", "data_type": "Null", "nullable": true},
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        json_schema(&schema)["properties"],
+        json!({
+            "flag": {"type": "boolean"},
+            "count": {"type": ["integer", "null"]},
+            "ratio": {"type": "number"},
+            "date": {"type": "string", "format": "date"},
+            "at": {"type": "string", "format": "date-time"},
+            "naive": {"type": "string"},
+            "price": {"type": "string"},
+            "cents": {"type": "integer"},
+            "digest": {
+                "type": "array",
+                "items": {"type": "integer", "minimum": 0, "maximum": 255},
+                "minItems": 2,
+                "maxItems": 2,
+            },
+            "nothing": {"type": "null"},
+        }),
+    );
+}
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    tags: Vec<String>,
+    location: Option<Location>,
+    pair: (i8, bool),
+    shape: Shape,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Location {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+enum Shape {
+    Empty,
+    Circle(f32),
+}
+
+#[test]
+fn traced_record() {
+    let schema =
+        SerdeArrowSchema::from_type::<Record>(TracingOptions::default().allow_null_fields(true))
+            .unwrap();
+
+    assert_eq!(
+        json_schema(&schema),
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer", "minimum": 0},
+                "tags": {"type": "array", "items": {"type": "string"}},
+                "location": {
+                    "type": ["object", "null"],
+                    "properties": {
+                        "lat": {"type": "number"},
+                        "lon": {"type": "number"},
+                    },
+                    "required": ["lat", "lon"],
+                    "additionalProperties": false,
+                },
+                "pair": {
+                    "type": "array",
+                    "items": [{"type": "integer"}, {"type": "boolean"}],
+                    "minItems": 2,
+                    "maxItems": 2,
+                },
+                "shape": {
+                    "oneOf": [
+                        {"const": "Empty"},
+                        {
+                            "type": "object",
+                            "properties": {"Circle": {"type": "number"}},
+                            "required": ["Circle"],
+                            "additionalProperties": false,
+                        },
+                    ],
+                },
+            },
+            "required": ["id", "tags", "location", "pair", "shape"],
+            "additionalProperties": false,
+        }),
+    );
+}
+
+#[test]
+fn nullable_unions_use_any_of() {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "value",
+            "data_type": "Union",
+            "nullable": true,
+            "children": [
+                {"name": "A", "data_type": "I32"},
+                {"name": "B", "data_type": "Utf8"},
+            ],
+        },
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        json_schema(&schema)["properties"]["value"],
+        json!({
+            "anyOf": [
+                {
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": {"A": {"type": "integer"}},
+                            "required": ["A"],
+                            "additionalProperties": false,
+                        },
+                        {
+                            "type": "object",
+                            "properties": {"B": {"type": "string"}},
+                            "required": ["B"],
+                            "additionalProperties": false,
+                        },
+                    ],
+                },
+                {"type": "null"},
+            ],
+        }),
+    );
+}
+
+#[test]
+fn maps_and_dictionaries() {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {
+            "name": "counts",
+            "data_type": "Map",
+            "children": [{
+                "name": "entries",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "key", "data_type": "Utf8"},
+                    {"name": "value", "data_type": "U64"},
+                ],
+            }],
+        },
+        {
+            "name": "category",
+            "data_type": "Dictionary",
+            "nullable": true,
+            "children": [
+                {"name": "key", "data_type": "U32"},
+                {"name": "value", "data_type": "Utf8"},
+            ],
+        },
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        json_schema(&schema)["properties"],
+        json!({
+            "counts": {
+                "type": "object",
+                "additionalProperties": {"type": "integer", "minimum": 0},
+            },
+            "category": {"type": ["string", "null"]},
+        }),
+    );
+}
+
+#[test]
+fn field_names_are_escaped() {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "quote\"back\\slash\nnewline", "data_type": "Bool"},
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        json_schema(&schema)["required"],
+        json!(["quote\"back\\slash\nnewline"]),
+    );
+}
+
+#[test]
+fn catch_all_maps_describe_additional_properties() {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {
+            "name": "extra",
+            "data_type": "Map",
+            "strategy": "CatchAll",
+            "children": [{
+                "name": "entries",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "key", "data_type": "Utf8"},
+                    {"name": "value", "data_type": "Utf8"},
+                ],
+            }],
+        },
+    ]))
+    .unwrap();
+
+    let json_schema = json_schema(&schema);
+    assert_eq!(
+        json_schema["properties"],
+        json!({"id": {"type": "integer", "minimum": 0}}),
+    );
+    assert_eq!(json_schema["required"], json!(["id"]));
+    assert_eq!(
+        json_schema["additionalProperties"],
+        json!({"type": "string"}),
+    );
+}
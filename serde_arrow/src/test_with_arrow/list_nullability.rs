@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+};
+
+fn trace(items: serde_json::Value, options: TracingOptions) -> crate::Result<SerdeArrowSchema> {
+    SerdeArrowSchema::from_samples(&items, options)
+}
+
+#[test]
+fn samples_without_nulls_are_not_nullable() {
+    let schema = trace(json!([{"a": [1, 2]}, {"a": []}]), TracingOptions::default()).unwrap();
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "a", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "U64"},
+        ]},
+    ]))
+    .unwrap();
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn nullable_list_with_non_nullable_elements() {
+    let schema = trace(
+        json!([{"a": [1, 2]}, {"a": []}]),
+        TracingOptions::default().list_nullability("a", true, false),
+    )
+    .unwrap();
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "a", "data_type": "LargeList", "nullable": true, "children": [
+            {"name": "element", "data_type": "U64"},
+        ]},
+    ]))
+    .unwrap();
+    assert_eq!(schema, expected);
+}
+
+#[test]
+fn non_nullable_list_with_nullable_elements() {
+    // the null could be either a null list or a null element
+    let schema = trace(
+        json!([{"a": [1, 2]}, {"a": null}]),
+        TracingOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        schema,
+        SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "LargeList", "nullable": true, "children": [
+                {"name": "element", "data_type": "U64"},
+            ]},
+        ]))
+        .unwrap(),
+    );
+
+    let schema = trace(
+        json!([{"a": [1, 2]}, {"a": [3]}]),
+        TracingOptions::default().list_nullability("a", false, true),
+    )
+    .unwrap();
+    assert_eq!(
+        schema,
+        SerdeArrowSchema::from_value(json!([
+            {"name": "a", "data_type": "LargeList", "children": [
+                {"name": "element", "data_type": "U64", "nullable": true},
+            ]},
+        ]))
+        .unwrap(),
+    );
+}
+
+#[test]
+fn nested_lists() {
+    #[derive(Serialize, Deserialize)]
+    struct Inner {
+        values: Vec<Vec<u8>>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Outer {
+        inner: Inner,
+    }
+
+    let options = TracingOptions::default()
+        .list_nullability("inner.values", true, true)
+        .list_nullability("inner.values.element", false, true);
+    let expected = SerdeArrowSchema::from_value(json!([
+        {"name": "inner", "data_type": "Struct", "children": [
+            {"name": "values", "data_type": "LargeList", "nullable": true, "children": [
+                {"name": "element", "data_type": "LargeList", "nullable": true, "children": [
+                    {"name": "element", "data_type": "U8", "nullable": true},
+                ]},
+            ]},
+        ]},
+    ]))
+    .unwrap();
+
+    let items = [Outer {
+        inner: Inner {
+            values: vec![vec![1, 2]],
+        },
+    }];
+    assert_eq!(
+        SerdeArrowSchema::from_samples(&items, options.clone()).unwrap(),
+        expected
+    );
+    assert_eq!(
+        SerdeArrowSchema::from_type::<Outer>(options).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn configured_nullability_is_used_when_serializing() {
+    let fields = Vec::<FieldRef>::from_samples(
+        json!([{"a": [1, 2]}]),
+        TracingOptions::default().list_nullability("a", true, false),
+    )
+    .unwrap();
+
+    let batch = crate::to_record_batch(&fields, &[json!({"a": null}), json!({"a": [3]})]).unwrap();
+    assert_eq!(batch.column(0).null_count(), 1);
+
+    let res = crate::to_record_batch(&fields, &[json!({"a": [null]})]);
+    assert_error_contains(&res, "$.a.element");
+}
+
+#[test]
+fn paths_must_refer_to_lists() {
+    let res = trace(
+        json!([{"a": 1, "b": [1]}]),
+        TracingOptions::default()
+            .list_nullability("a", true, true)
+            .list_nullability("c", true, true),
+    );
+    assert_error_contains(
+        &res,
+        r#"List nullability configured for fields that are not lists: ["a", "c"]"#,
+    );
+}
@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::ArrayRef, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+    Deserializer, DeserializerOptions,
+};
+
+fn map_fields(nullable: bool) -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([{
+        "name": "record",
+        "data_type": "Map",
+        "nullable": nullable,
+        "children": [{
+            "name": "entries",
+            "data_type": "Struct",
+            "children": [
+                {"name": "key", "data_type": "Utf8"},
+                {"name": "value", "data_type": "I64", "nullable": true},
+            ],
+        }],
+    }]))
+    .unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct MapItem {
+    record: Option<BTreeMap<String, Option<i64>>>,
+}
+
+fn map_item(entries: &[(&str, Option<i64>)]) -> MapItem {
+    MapItem {
+        record: Some(
+            entries
+                .iter()
+                .map(|(key, value)| (key.to_string(), *value))
+                .collect(),
+        ),
+    }
+}
+
+fn deserialize<'de, T: Deserialize<'de>>(
+    fields: &[FieldRef],
+    arrays: &'de [ArrayRef],
+) -> crate::Result<T> {
+    let deserializer = Deserializer::from_arrow(fields, arrays)?
+        .with_options(DeserializerOptions::default().map_as_struct(true));
+    T::deserialize(deserializer)
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Item {
+    record: Record,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Record {
+    a: i64,
+    b: Option<i64>,
+    #[serde(default)]
+    c: i64,
+}
+
+#[test]
+fn maps_are_deserialized_as_structs() {
+    let fields = map_fields(false);
+    let items = [
+        map_item(&[("a", Some(1)), ("b", Some(2)), ("c", Some(3))]),
+        map_item(&[("c", Some(4)), ("a", Some(5))]),
+        map_item(&[("a", Some(6)), ("b", None), ("unknown", Some(7))]),
+    ];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let actual: Vec<Item> = deserialize(&fields, &arrays).unwrap();
+    let expected = [
+        Item {
+            record: Record {
+                a: 1,
+                b: Some(2),
+                c: 3,
+            },
+        },
+        Item {
+            record: Record {
+                a: 5,
+                b: None,
+                c: 4,
+            },
+        },
+        Item {
+            record: Record {
+                a: 6,
+                b: None,
+                c: 0,
+            },
+        },
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn maps_require_the_option() {
+    let fields = map_fields(false);
+    let items = [map_item(&[("a", Some(1))])];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let res = Vec::<Item>::deserialize(Deserializer::from_arrow(&fields, &arrays).unwrap());
+    assert_error_contains(&res, "without the `map_as_struct` option");
+    assert_error_contains(&res, "$.record");
+}
+
+#[test]
+fn missing_required_keys_are_reported() {
+    let fields = map_fields(false);
+    let items = [map_item(&[("b", Some(1))])];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let res: crate::Result<Vec<Item>> = deserialize(&fields, &arrays);
+    assert_error_contains(&res, "missing field `a`");
+}
+
+#[test]
+fn unknown_keys_can_be_denied() {
+    #[derive(Debug, Deserialize)]
+    struct StrictItem {
+        #[allow(dead_code)]
+        record: StrictRecord,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[allow(dead_code)]
+    struct StrictRecord {
+        a: i64,
+    }
+
+    let fields = map_fields(false);
+    let items = [map_item(&[("a", Some(1)), ("z", Some(2))])];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let res: crate::Result<Vec<StrictItem>> = deserialize(&fields, &arrays);
+    assert_error_contains(&res, "unknown field `z`");
+}
+
+#[test]
+fn null_maps() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct OptionalItem {
+        record: Option<OptionalRecord>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct OptionalRecord {
+        a: Option<i64>,
+    }
+
+    let fields = map_fields(true);
+    let items = [
+        map_item(&[("a", Some(1))]),
+        MapItem { record: None },
+        map_item(&[]),
+    ];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let actual: Vec<OptionalItem> = deserialize(&fields, &arrays).unwrap();
+    let expected = [
+        OptionalItem {
+            record: Some(OptionalRecord { a: Some(1) }),
+        },
+        OptionalItem { record: None },
+        OptionalItem {
+            record: Some(OptionalRecord { a: None }),
+        },
+    ];
+    assert_eq!(actual, expected);
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct RequiredItem {
+        record: OptionalRecord,
+    }
+
+    let actual: Vec<RequiredItem> = deserialize(&fields, &arrays).unwrap();
+    let expected = [
+        RequiredItem {
+            record: OptionalRecord { a: Some(1) },
+        },
+        RequiredItem {
+            record: OptionalRecord { a: None },
+        },
+        RequiredItem {
+            record: OptionalRecord { a: None },
+        },
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn nested_maps_in_lists() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "records",
+        "data_type": "LargeList",
+        "children": [{
+            "name": "element",
+            "data_type": "Map",
+            "children": [{
+                "name": "entries",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "key", "data_type": "LargeUtf8"},
+                    {"name": "value", "data_type": "Utf8"},
+                ],
+            }],
+        }],
+    }]))
+    .unwrap();
+
+    #[derive(Serialize)]
+    struct ListItem {
+        records: Vec<BTreeMap<String, String>>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct ListTarget {
+        records: Vec<Named>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Named {
+        name: String,
+    }
+
+    let items = [ListItem {
+        records: vec![
+            BTreeMap::from([(String::from("name"), String::from("foo"))]),
+            BTreeMap::from([
+                (String::from("name"), String::from("bar")),
+                (String::from("other"), String::from("baz")),
+            ]),
+        ],
+    }];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let actual: Vec<ListTarget> = deserialize(&fields, &arrays).unwrap();
+    let expected = [ListTarget {
+        records: vec![
+            Named {
+                name: String::from("foo"),
+            },
+            Named {
+                name: String::from("bar"),
+            },
+        ],
+    }];
+    assert_eq!(actual, expected);
+}
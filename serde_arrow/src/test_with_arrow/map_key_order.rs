@@ -0,0 +1,133 @@
+use serde::{Serialize, Serializer};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, ArrayRef, MapArray, PrimitiveArray, StringArray},
+        datatypes::{FieldRef, UInt32Type},
+    },
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+    utils::Item,
+    ArrayBuilder, DuplicateMapKeyPolicy, SerializerOptions,
+};
+
+/// A map that may contain duplicate keys, serialized in the given order
+struct Entries(Vec<(&'static str, u32)>);
+
+impl Serialize for Entries {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(k, v)| (k, v)))
+    }
+}
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([{
+        "name": "item",
+        "data_type": "Map",
+        "children": [{
+            "name": "entries",
+            "data_type": "Struct",
+            "children": [
+                {"name": "key", "data_type": "Utf8"},
+                {"name": "value", "data_type": "U32"},
+            ],
+        }],
+    }]))
+    .unwrap()
+}
+
+fn build(options: SerializerOptions, entries: Vec<(&'static str, u32)>) -> crate::Result<ArrayRef> {
+    let mut builder = ArrayBuilder::from_arrow(&fields())?.with_options(options);
+    builder.push(Item(Entries(entries)))?;
+    Ok(builder.to_arrow()?.remove(0))
+}
+
+fn entries(array: &ArrayRef) -> Vec<(String, u32)> {
+    let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+    let keys = array.keys().as_any().downcast_ref::<StringArray>().unwrap();
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<UInt32Type>>()
+        .unwrap();
+    keys.iter()
+        .zip(values.iter())
+        .map(|(key, value)| (key.unwrap().to_owned(), value.unwrap()))
+        .collect()
+}
+
+fn expected(entries: &[(&str, u32)]) -> Vec<(String, u32)> {
+    entries.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+}
+
+#[test]
+fn entries_keep_their_order_by_default() {
+    let array = build(
+        SerializerOptions::default(),
+        vec![("b", 0), ("a", 1), ("b", 2)],
+    )
+    .unwrap();
+    assert_eq!(entries(&array), expected(&[("b", 0), ("a", 1), ("b", 2)]));
+}
+
+#[test]
+fn sorted_entries_keep_duplicates_in_order() {
+    let array = build(
+        SerializerOptions::default().sort_map_keys(true),
+        vec![("c", 0), ("a", 1), ("c", 2), ("b", 3)],
+    )
+    .unwrap();
+    assert_eq!(
+        entries(&array),
+        expected(&[("a", 1), ("b", 3), ("c", 0), ("c", 2)])
+    );
+}
+
+#[test]
+fn keep_first_duplicate() {
+    let options = SerializerOptions::default().duplicate_map_keys(DuplicateMapKeyPolicy::First);
+    let array = build(options, vec![("c", 0), ("a", 1), ("c", 2), ("b", 3)]).unwrap();
+    assert_eq!(entries(&array), expected(&[("c", 0), ("a", 1), ("b", 3)]));
+}
+
+#[test]
+fn keep_last_duplicate() {
+    let options = SerializerOptions::default().duplicate_map_keys(DuplicateMapKeyPolicy::Last);
+    let array = build(options, vec![("c", 0), ("a", 1), ("c", 2), ("b", 3)]).unwrap();
+    assert_eq!(entries(&array), expected(&[("a", 1), ("c", 2), ("b", 3)]));
+}
+
+#[test]
+fn keep_last_duplicate_sorted() {
+    let options = SerializerOptions::default()
+        .sort_map_keys(true)
+        .duplicate_map_keys(DuplicateMapKeyPolicy::Last);
+    let array = build(options, vec![("c", 0), ("a", 1), ("c", 2), ("b", 3)]).unwrap();
+    assert_eq!(entries(&array), expected(&[("a", 1), ("b", 3), ("c", 2)]));
+}
+
+#[test]
+fn duplicate_keys_are_an_error() {
+    let options = SerializerOptions::default().duplicate_map_keys(DuplicateMapKeyPolicy::Error);
+    let res = build(options.clone(), vec![("c", 0), ("a", 1), ("c", 2)]);
+    assert_error_contains(&res, "Duplicate map key");
+    assert_error_contains(&res, "$.item");
+
+    let array = build(options, vec![("c", 0), ("a", 1)]).unwrap();
+    assert_eq!(entries(&array), expected(&[("c", 0), ("a", 1)]));
+}
+
+#[test]
+fn duplicates_are_detected_per_map() {
+    let options = SerializerOptions::default().duplicate_map_keys(DuplicateMapKeyPolicy::Error);
+    let mut builder = ArrayBuilder::from_arrow(&fields())
+        .unwrap()
+        .with_options(options);
+    builder.push(Item(Entries(vec![("a", 0)]))).unwrap();
+    builder.push(Item(Entries(vec![("a", 1)]))).unwrap();
+
+    let arrays = builder.to_arrow().unwrap();
+    assert_eq!(entries(&arrays[0]), expected(&[("a", 0), ("a", 1)]));
+    assert_eq!(arrays[0].len(), 2);
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::{
+        arrow::{
+            array::{Array, GenericListArray, MapArray},
+            datatypes::FieldRef,
+        },
+        arrow2::datatypes::Field as Arrow2Field,
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+};
+
+type Lists = BTreeMap<String, Option<Vec<i64>>>;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    lists: Lists,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Nested {
+    nested: BTreeMap<String, Lists>,
+}
+
+fn lists(entries: &[(&str, Option<&[i64]>)]) -> Lists {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.map(<[i64]>::to_vec)))
+        .collect()
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            lists: lists(&[("a", Some(&[1, 2])), ("b", None), ("c", Some(&[]))]),
+        },
+        Record {
+            lists: lists(&[("c", None)]),
+        },
+        Record { lists: lists(&[]) },
+        Record {
+            lists: lists(&[("a", Some(&[])), ("b", Some(&[3]))]),
+        },
+    ]
+}
+
+fn nested_items() -> Vec<Nested> {
+    vec![
+        Nested {
+            nested: BTreeMap::from([
+                (String::from("x"), lists(&[("a", None), ("b", Some(&[]))])),
+                (String::from("y"), lists(&[])),
+            ]),
+        },
+        Nested {
+            nested: BTreeMap::from([(String::from("z"), lists(&[("a", Some(&[4]))]))]),
+        },
+    ]
+}
+
+#[test]
+fn arrow_roundtrip_from_samples() {
+    let items = items();
+    let options = TracingOptions::default().map_as_struct_at("lists", false);
+    let fields = Vec::<FieldRef>::from_samples(&items, options).unwrap();
+
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let actual: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn arrow_roundtrip_from_type() {
+    let items = items();
+    let options = TracingOptions::default().map_as_struct_at("lists", false);
+    let fields = Vec::<FieldRef>::from_type::<Record>(options).unwrap();
+
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let actual: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn arrow2_roundtrip() {
+    let items = items();
+    let options = TracingOptions::default().map_as_struct_at("lists", false);
+    let fields = Vec::<Arrow2Field>::from_samples(&items, options).unwrap();
+
+    let arrays = crate::to_arrow2(&fields, &items).unwrap();
+    let actual: Vec<Record> = crate::from_arrow2(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn nested_maps_roundtrip() {
+    let items = nested_items();
+    let options = TracingOptions::default()
+        .map_as_struct_at("nested", false)
+        .map_as_struct_at("nested.value", false);
+    let fields = Vec::<FieldRef>::from_samples(&items, options.clone()).unwrap();
+
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let actual: Vec<Nested> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+
+    let fields = Vec::<Arrow2Field>::from_samples(&items, options).unwrap();
+    let arrays = crate::to_arrow2(&fields, &items).unwrap();
+    let actual: Vec<Nested> = crate::from_arrow2(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn null_and_empty_lists_are_stored_distinctly() {
+    let items = items();
+    let options = TracingOptions::default().map_as_struct_at("lists", false);
+    let fields = Vec::<FieldRef>::from_samples(&items, options).unwrap();
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let map = arrays[0].as_any().downcast_ref::<MapArray>().unwrap();
+    assert_eq!(map.value_offsets(), &[0, 3, 4, 4, 6]);
+
+    let values = map
+        .values()
+        .as_any()
+        .downcast_ref::<GenericListArray<i64>>()
+        .unwrap();
+    let nulls = (0..values.len())
+        .map(|idx| values.is_null(idx))
+        .collect::<Vec<_>>();
+    assert_eq!(nulls, vec![false, true, false, true, false, false]);
+    assert_eq!(values.value_offsets(), &[0, 2, 2, 2, 2, 2, 3]);
+}
+
+#[test]
+fn path_overwrites_the_global_option() {
+    #[derive(Serialize)]
+    struct Pair {
+        a: Lists,
+        b: Lists,
+    }
+
+    let items = [Pair {
+        a: lists(&[("x", Some(&[1]))]),
+        b: lists(&[("x", Some(&[1]))]),
+    }];
+
+    let options = TracingOptions::default().map_as_struct_at("b", false);
+    let schema = SerdeArrowSchema::from_samples(&items, options).unwrap();
+    let schema = serde_json::to_value(&schema).unwrap();
+    assert_eq!(schema["fields"][0]["data_type"], "Struct");
+    assert_eq!(schema["fields"][1]["data_type"], "Map");
+
+    let options = TracingOptions::default()
+        .map_as_struct(false)
+        .map_as_struct_at("b", true);
+    let schema = SerdeArrowSchema::from_samples(&items, options).unwrap();
+    let schema = serde_json::to_value(&schema).unwrap();
+    assert_eq!(schema["fields"][0]["data_type"], "Map");
+    assert_eq!(schema["fields"][1]["data_type"], "Struct");
+}
+
+#[test]
+fn from_type_rejects_maps_as_structs_at_path() {
+    let options = TracingOptions::default()
+        .map_as_struct(false)
+        .map_as_struct_at("lists", true);
+    let res = Vec::<FieldRef>::from_type::<Record>(options);
+    assert_error_contains(&res, "Cannot trace maps as structs with `from_type`");
+}
+
+#[test]
+fn list_nullability_of_map_values() {
+    let samples = vec![Record {
+        lists: lists(&[("a", Some(&[1]))]),
+    }];
+    let options = TracingOptions::default()
+        .map_as_struct_at("lists", false)
+        .list_nullability("lists.value", true, false);
+    let schema = SerdeArrowSchema::from_samples(&samples, options).unwrap();
+
+    let schema = serde_json::to_value(&schema).unwrap();
+    let value = &schema["fields"][0]["children"][0]["children"][1];
+    assert_eq!(value["data_type"], "LargeList");
+    assert_eq!(value["nullable"], true);
+    assert_eq!(value["children"][0].get("nullable"), None);
+
+    let fields = Vec::<FieldRef>::from_value(&schema["fields"]).unwrap();
+    let items = items();
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let actual: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn null_values_require_nullable_value_fields() {
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "lists",
+        "data_type": "Map",
+        "children": [{
+            "name": "entries",
+            "data_type": "Struct",
+            "children": [
+                {"name": "key", "data_type": "LargeUtf8"},
+                {"name": "value", "data_type": "LargeList", "children": [
+                    {"name": "element", "data_type": "I64"},
+                ]},
+            ],
+        }],
+    }]))
+    .unwrap();
+
+    let res = crate::to_arrow(&fields, items());
+    assert_error_contains(&res, "value");
+}
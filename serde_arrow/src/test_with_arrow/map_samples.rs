@@ -0,0 +1,100 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    schema::{SerdeArrowSchema, TracingOptions},
+    Deserializer,
+};
+
+fn samples() -> Vec<HashMap<String, i64>> {
+    vec![
+        HashMap::from([(String::from("b"), 1), (String::from("a"), 2)]),
+        HashMap::from([(String::from("c"), 3)]),
+        HashMap::new(),
+    ]
+}
+
+fn names(schema: &SerdeArrowSchema) -> Vec<&str> {
+    schema
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect()
+}
+
+#[test]
+fn union_of_keys_as_sorted_nullable_fields() {
+    let schema = SerdeArrowSchema::from_map_samples(samples(), TracingOptions::default()).unwrap();
+    assert_eq!(names(&schema), vec!["a", "b", "c"]);
+    assert!(schema.fields.iter().all(|field| field.nullable));
+}
+
+#[test]
+fn field_order_does_not_depend_on_the_sample_order() {
+    let mut reversed_samples = samples();
+    reversed_samples.reverse();
+    let reversed =
+        SerdeArrowSchema::from_map_samples(&reversed_samples, TracingOptions::default()).unwrap();
+    let ordered = SerdeArrowSchema::from_map_samples(samples(), TracingOptions::default()).unwrap();
+    assert_eq!(reversed, ordered);
+}
+
+#[test]
+fn roundtrip_without_wrappers() {
+    let samples = samples();
+    let fields = Vec::<FieldRef>::try_from(
+        SerdeArrowSchema::from_map_samples(&samples, TracingOptions::default()).unwrap(),
+    )
+    .unwrap();
+    let batch = crate::to_record_batch(&fields, &samples).unwrap();
+    assert_eq!(batch.num_rows(), 3);
+
+    let actual = Vec::<BTreeMap<String, Option<i64>>>::deserialize(
+        Deserializer::from_record_batch(&batch).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        actual,
+        vec![
+            BTreeMap::from([
+                (String::from("a"), Some(2)),
+                (String::from("b"), Some(1)),
+                (String::from("c"), None),
+            ]),
+            BTreeMap::from([
+                (String::from("a"), None),
+                (String::from("b"), None),
+                (String::from("c"), Some(3)),
+            ]),
+            BTreeMap::from([
+                (String::from("a"), None),
+                (String::from("b"), None),
+                (String::from("c"), None),
+            ]),
+        ]
+    );
+}
+
+#[test]
+fn nested_values() {
+    let samples: Vec<HashMap<String, Value>> = vec![
+        serde_json::from_value(json!({"id": 1, "tags": ["x"]})).unwrap(),
+        serde_json::from_value(json!({"id": 2, "meta": {"ok": true}})).unwrap(),
+    ];
+    let schema = SerdeArrowSchema::from_map_samples(&samples, TracingOptions::default()).unwrap();
+    assert_eq!(names(&schema), vec!["id", "meta", "tags"]);
+
+    let fields = Vec::<FieldRef>::try_from(schema).unwrap();
+    let batch = crate::to_record_batch(&fields, &samples).unwrap();
+    let actual = Value::deserialize(Deserializer::from_record_batch(&batch).unwrap()).unwrap();
+    assert_eq!(
+        actual,
+        json!([
+            {"id": 1, "meta": null, "tags": ["x"]},
+            {"id": 2, "meta": {"ok": true}, "tags": null},
+        ])
+    );
+}
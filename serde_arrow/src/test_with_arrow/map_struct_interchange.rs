@@ -0,0 +1,184 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef, internal::testing::assert_error_contains,
+    schema::SchemaLike, ArrayBuilder, Deserializer, SerializerOptions,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Point {
+    y: i64,
+    x: i64,
+}
+
+fn map_fields(key: &str) -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([{
+        "name": "point",
+        "data_type": "Map",
+        "children": [{
+            "name": "entries",
+            "data_type": "Struct",
+            "children": [
+                {"name": "key", "data_type": key},
+                {"name": "value", "data_type": "I64"},
+            ],
+        }],
+    }]))
+    .unwrap()
+}
+
+fn struct_fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([{
+        "name": "point",
+        "data_type": "Struct",
+        "children": [
+            {"name": "0", "data_type": "I64"},
+            {"name": "1", "data_type": "I64"},
+        ],
+    }]))
+    .unwrap()
+}
+
+fn build<T: Serialize>(
+    fields: &[FieldRef],
+    items: &[T],
+    options: SerializerOptions,
+) -> crate::Result<crate::_impl::arrow::array::RecordBatch> {
+    let mut builder = ArrayBuilder::from_arrow(fields)?.with_options(options);
+    builder.extend(items)?;
+    builder.to_record_batch()
+}
+
+#[derive(Debug, Serialize)]
+struct StructRecord {
+    point: Point,
+}
+
+#[test]
+fn structs_into_map_fields() {
+    let items = [StructRecord {
+        point: Point { y: 2, x: 1 },
+    }];
+    let options = SerializerOptions::default().map_struct_interchange(true);
+    let batch = build(&map_fields("LargeUtf8"), &items, options).unwrap();
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct MapRecord {
+        point: BTreeMap<String, i64>,
+    }
+
+    let actual =
+        Vec::<MapRecord>::deserialize(Deserializer::from_record_batch(&batch).unwrap()).unwrap();
+    assert_eq!(
+        actual,
+        vec![MapRecord {
+            point: BTreeMap::from([(String::from("x"), 1), (String::from("y"), 2)]),
+        }]
+    );
+}
+
+#[test]
+fn structs_into_map_fields_keep_the_field_order() {
+    let items = [StructRecord {
+        point: Point { y: 2, x: 1 },
+    }];
+    let options = SerializerOptions::default().map_struct_interchange(true);
+    let batch = build(&map_fields("Utf8"), &items, options).unwrap();
+    let actual =
+        serde_json::Value::deserialize(Deserializer::from_record_batch(&batch).unwrap()).unwrap();
+    assert_eq!(actual, json!([{"point": {"y": 2, "x": 1}}]));
+
+    let options = SerializerOptions::default()
+        .map_struct_interchange(true)
+        .deterministic(true);
+    let batch = build(&map_fields("Utf8"), &items, options).unwrap();
+    let actual =
+        serde_json::Value::deserialize(Deserializer::from_record_batch(&batch).unwrap()).unwrap();
+    assert_eq!(actual, json!([{"point": {"x": 1, "y": 2}}]));
+}
+
+#[test]
+fn structs_into_map_fields_require_the_option() {
+    let items = [StructRecord {
+        point: Point { y: 2, x: 1 },
+    }];
+    let res = build(&map_fields("Utf8"), &items, SerializerOptions::default());
+    assert_error_contains(&res, "without the `map_struct_interchange` option");
+    assert_error_contains(&res, "field: \"$.point\"");
+}
+
+#[test]
+fn structs_into_map_fields_with_incompatible_keys() {
+    let items = [StructRecord {
+        point: Point { y: 2, x: 1 },
+    }];
+    let options = SerializerOptions::default().map_struct_interchange(true);
+    let res = build(&map_fields("I32"), &items, options);
+    assert_error_contains(&res, "Cannot write struct field \"y\" as map key");
+}
+
+#[test]
+fn serialize_with_switching_to_a_struct() {
+    #[derive(Serialize)]
+    struct Record {
+        #[serde(serialize_with = "as_point")]
+        point: (i64, i64),
+    }
+
+    fn as_point<S: Serializer>(value: &(i64, i64), serializer: S) -> Result<S::Ok, S::Error> {
+        Point {
+            x: value.0,
+            y: value.1,
+        }
+        .serialize(serializer)
+    }
+
+    let options = SerializerOptions::default().map_struct_interchange(true);
+    let batch = build(&map_fields("Utf8"), &[Record { point: (3, 4) }], options).unwrap();
+    let actual =
+        serde_json::Value::deserialize(Deserializer::from_record_batch(&batch).unwrap()).unwrap();
+    assert_eq!(actual, json!([{"point": {"y": 4, "x": 3}}]));
+}
+
+#[derive(Debug, Serialize)]
+struct IndexedRecord {
+    point: BTreeMap<u8, i64>,
+}
+
+#[test]
+fn maps_with_integer_keys_into_struct_fields() {
+    let items = [
+        IndexedRecord {
+            point: BTreeMap::from([(1, 20), (0, 10)]),
+        },
+        IndexedRecord {
+            point: BTreeMap::from([(0, 30), (1, 40), (7, 0)]),
+        },
+    ];
+    let options = SerializerOptions::default().map_struct_interchange(true);
+    let batch = build(&struct_fields(), &items, options).unwrap();
+    let actual =
+        serde_json::Value::deserialize(Deserializer::from_record_batch(&batch).unwrap()).unwrap();
+    assert_eq!(
+        actual,
+        json!([
+            {"point": {"0": 10, "1": 20}},
+            {"point": {"0": 30, "1": 40}},
+        ])
+    );
+}
+
+#[test]
+fn maps_with_integer_keys_into_struct_fields_require_the_option() {
+    let items = [IndexedRecord {
+        point: BTreeMap::from([(0, 10), (1, 20)]),
+    }];
+    let res = build(&struct_fields(), &items, SerializerOptions::default());
+    assert_error_contains(
+        &res,
+        "Cannot use u8 keys for struct fields without the `map_struct_interchange` option",
+    );
+}
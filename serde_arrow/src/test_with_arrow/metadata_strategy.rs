@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::{DataType, Field, FieldRef},
+    internal::testing::assert_error_contains,
+    schema::{
+        get_strategy_from_metadata, strategy_key, MetadataStrategy, SchemaLike, SerdeArrowSchema,
+        Strategy, STRATEGY_KEY,
+    },
+    Result,
+};
+
+#[derive(Debug, PartialEq)]
+enum Encoding {
+    Plain,
+    Compressed { level: u8 },
+}
+
+impl MetadataStrategy for Encoding {
+    const NAMESPACE: &'static str = "my_crate";
+
+    fn to_metadata_value(&self) -> String {
+        match self {
+            Self::Plain => String::from("Plain"),
+            Self::Compressed { level } => format!("Compressed({level})"),
+        }
+    }
+
+    fn from_metadata_value(value: &str) -> Result<Self> {
+        if value == "Plain" {
+            return Ok(Self::Plain);
+        }
+        let level = value
+            .strip_prefix("Compressed(")
+            .and_then(|value| value.strip_suffix(')'))
+            .and_then(|level| level.parse().ok());
+        match level {
+            Some(level) => Ok(Self::Compressed { level }),
+            None => Err(crate::Error::custom(format!("Unknown encoding {value}"))),
+        }
+    }
+}
+
+#[test]
+fn builtin_strategies_keep_their_key() {
+    assert_eq!(strategy_key(Strategy::NAMESPACE), STRATEGY_KEY);
+    assert_eq!(Strategy::MapAsStruct.metadata_key(), STRATEGY_KEY);
+
+    let metadata = HashMap::<String, String>::from(Strategy::ScaledInt { scale: 3 });
+    assert_eq!(metadata[STRATEGY_KEY], "ScaledInt(3)");
+    assert_eq!(
+        Strategy::from_metadata(&metadata).unwrap(),
+        Some(Strategy::ScaledInt { scale: 3 })
+    );
+}
+
+#[test]
+fn custom_strategies_are_namespaced() {
+    let mut metadata = HashMap::new();
+    Strategy::UtcStrAsDate64.write_metadata(&mut metadata);
+    Encoding::Compressed { level: 3 }.write_metadata(&mut metadata);
+
+    assert_eq!(metadata["my_crate:strategy"], "Compressed(3)");
+    assert_eq!(metadata[STRATEGY_KEY], "UtcStrAsDate64");
+    assert_eq!(
+        Encoding::from_metadata(&metadata).unwrap(),
+        Some(Encoding::Compressed { level: 3 })
+    );
+    assert_eq!(
+        get_strategy_from_metadata(&metadata).unwrap(),
+        Some(Strategy::UtcStrAsDate64)
+    );
+
+    Encoding::Plain.write_metadata(&mut metadata);
+    assert_eq!(
+        Encoding::from_metadata(&metadata).unwrap(),
+        Some(Encoding::Plain)
+    );
+
+    assert_eq!(
+        Encoding::remove_from_metadata(&mut metadata),
+        Some(String::from("Plain"))
+    );
+    assert_eq!(Encoding::from_metadata(&metadata).unwrap(), None);
+    assert_eq!(metadata.len(), 1);
+}
+
+#[test]
+fn invalid_custom_strategies() {
+    let metadata = HashMap::from([(strategy_key("my_crate"), String::from("Unknown"))]);
+    assert_error_contains(
+        &Encoding::from_metadata(&metadata),
+        "Unknown encoding Unknown",
+    );
+}
+
+#[test]
+fn custom_strategies_are_kept_during_conversion() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        date: String,
+        value: u32,
+    }
+
+    let mut date_metadata = HashMap::from(Strategy::UtcStrAsDate64);
+    Encoding::Compressed { level: 1 }.write_metadata(&mut date_metadata);
+    let mut value_metadata = HashMap::new();
+    Encoding::Plain.write_metadata(&mut value_metadata);
+
+    let fields: Vec<FieldRef> = vec![
+        Field::new("date", DataType::Date64, false)
+            .with_metadata(date_metadata)
+            .into(),
+        Field::new("value", DataType::UInt32, false)
+            .with_metadata(value_metadata)
+            .into(),
+    ];
+
+    let items = [Record {
+        date: String::from("2024-01-01T00:00:00Z"),
+        value: 13,
+    }];
+    let batch = crate::to_record_batch(&fields, &items).unwrap();
+    assert_eq!(batch.schema().fields()[..], fields[..]);
+
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(actual, items);
+
+    let schema = SerdeArrowSchema::try_from(fields.as_slice()).unwrap();
+    let value = serde_json::to_value(&schema).unwrap();
+    assert_eq!(
+        value,
+        json!({"fields": [
+            {
+                "name": "date",
+                "data_type": "Date64",
+                "strategy": "UtcStrAsDate64",
+                "metadata": {"my_crate:strategy": "Compressed(1)"},
+            },
+            {
+                "name": "value",
+                "data_type": "U32",
+                "metadata": {"my_crate:strategy": "Plain"},
+            },
+        ]})
+    );
+    assert_eq!(Vec::<FieldRef>::from_value(&value).unwrap(), fields);
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef, internal::testing::assert_error_contains,
+    schema::SchemaLike, ArrayBuilder, SerializerOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    values: Vec<Child>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Child {
+    #[serde(default, skip_serializing_if = "is_zero")]
+    a: i64,
+    b: bool,
+}
+
+fn is_zero(v: &i64) -> bool {
+    *v == 0
+}
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "name", "data_type": "LargeUtf8"},
+        {"name": "values", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Struct", "children": [
+                {"name": "a", "data_type": "I64"},
+                {"name": "b", "data_type": "Bool"},
+            ]},
+        ]},
+        {"name": "comment", "data_type": "LargeUtf8", "nullable": true},
+    ]))
+    .unwrap()
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            id: 0,
+            name: String::from("foo"),
+            values: vec![Child { a: 1, b: true }, Child { a: 0, b: false }],
+            comment: Some(String::from("bar")),
+        },
+        Record {
+            id: 1,
+            name: String::new(),
+            values: vec![],
+            comment: None,
+        },
+    ]
+}
+
+#[test]
+fn missing_non_nullable_fields_fail_by_default() {
+    let mut builder = ArrayBuilder::from_arrow(&fields()).unwrap();
+    let res = builder.extend(items());
+    assert_error_contains(&res, "Missing non-nullable field");
+}
+
+#[test]
+fn missing_fields_are_filled_with_defaults() {
+    let fields = fields();
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().missing_fields_as_default(true));
+    builder.extend(items()).unwrap();
+    let record_batch = builder.to_record_batch().unwrap();
+
+    assert_eq!(record_batch.num_rows(), 2);
+    assert_eq!(record_batch.column(1).null_count(), 0);
+    assert_eq!(record_batch.column(2).null_count(), 0);
+    assert_eq!(record_batch.column(3).null_count(), 1);
+
+    let actual: Vec<Record> = crate::from_record_batch(&record_batch).unwrap();
+    assert_eq!(actual, items());
+}
+
+#[test]
+fn option_is_kept_across_batches() {
+    let mut builder = ArrayBuilder::from_arrow(&fields())
+        .unwrap()
+        .with_options(SerializerOptions::default().missing_fields_as_default(true));
+
+    for _ in 0..2 {
+        builder.extend(items()).unwrap();
+        let record_batch = builder.to_record_batch().unwrap();
+        let actual: Vec<Record> = crate::from_record_batch(&record_batch).unwrap();
+        assert_eq!(actual, items());
+    }
+}
@@ -0,0 +1,154 @@
+use serde_json::{json, Value};
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::testing::assert_error_contains,
+    schema::{MixedTypePolicy, SchemaLike, SerdeArrowSchema, TracingOptions},
+};
+
+fn samples() -> Value {
+    json!([
+        {"value": 1},
+        {"value": "foo"},
+        {"value": true},
+        {"value": null},
+        {"value": -2},
+        {"value": 2.5},
+    ])
+}
+
+fn roundtrip(fields: &[FieldRef], items: &Value) -> Value {
+    let batch = crate::to_record_batch(fields, items).unwrap();
+    let actual: Vec<Value> = crate::from_record_batch(&batch).unwrap();
+    Value::Array(actual)
+}
+
+#[test]
+fn mixed_types_fail_per_default() {
+    let res = SerdeArrowSchema::from_samples(samples(), TracingOptions::default());
+    assert_error_contains(&res, "consider setting `mixed_type_policy`");
+}
+
+#[test]
+fn stringify_all() {
+    let options = TracingOptions::default().mixed_type_policy(MixedTypePolicy::StringifyAll);
+    let schema = SerdeArrowSchema::from_samples(samples(), options).unwrap();
+    assert_eq!(
+        serde_json::to_value(&schema).unwrap(),
+        json!({"fields": [
+            {"name": "value", "data_type": "LargeUtf8", "nullable": true, "strategy": "Stringify"},
+        ]}),
+    );
+
+    let fields = Vec::<FieldRef>::try_from(&schema).unwrap();
+    assert_eq!(
+        roundtrip(&fields, &samples()),
+        json!([
+            {"value": "1"},
+            {"value": "foo"},
+            {"value": "true"},
+            {"value": null},
+            {"value": "-2"},
+            {"value": "2.5"},
+        ]),
+    );
+}
+
+#[test]
+fn union() {
+    let options = TracingOptions::default().mixed_type_policy(MixedTypePolicy::Union);
+    let schema = SerdeArrowSchema::from_samples(samples(), options).unwrap();
+    assert_eq!(
+        serde_json::to_value(&schema).unwrap(),
+        json!({"fields": [
+            {
+                "name": "value",
+                "data_type": "Union",
+                "nullable": true,
+                "strategy": "UntaggedUnion",
+                "children": [
+                    {"name": "UInt64", "data_type": "U64"},
+                    {"name": "LargeUtf8", "data_type": "LargeUtf8"},
+                    {"name": "Boolean", "data_type": "Bool"},
+                    {"name": "Int64", "data_type": "I64"},
+                    {"name": "Float64", "data_type": "F64"},
+                    {"name": "", "data_type": "Null", "nullable": true, "strategy": "NullVariant"},
+                ],
+            },
+        ]}),
+    );
+
+    let json_schema: Value = serde_json::from_str(&schema.to_json_schema().unwrap()).unwrap();
+    assert_eq!(
+        json_schema["properties"]["value"],
+        json!({"anyOf": [
+            {"anyOf": [
+                {"type": "integer", "minimum": 0},
+                {"type": "string"},
+                {"type": "boolean"},
+                {"type": "integer"},
+                {"type": "number"},
+            ]},
+            {"type": "null"},
+        ]}),
+    );
+
+    let fields = Vec::<FieldRef>::try_from(&schema).unwrap();
+    assert_eq!(roundtrip(&fields, &samples()), samples());
+}
+
+#[test]
+fn widest_numeric() {
+    let items = json!([{"value": 1}, {"value": -2}, {"value": 2.5}]);
+    let options = TracingOptions::default().mixed_type_policy(MixedTypePolicy::WidestNumeric);
+    let schema = SerdeArrowSchema::from_samples(&items, options.clone()).unwrap();
+    assert_eq!(
+        serde_json::to_value(&schema).unwrap(),
+        json!({"fields": [{"name": "value", "data_type": "F64"}]}),
+    );
+
+    let res = SerdeArrowSchema::from_samples(samples(), options);
+    assert_error_contains(&res, "Cannot accept");
+}
+
+#[test]
+fn policy_of_individual_fields() {
+    let items = json!([
+        {"a": 1, "b": 1},
+        {"a": "foo", "b": "foo"},
+    ]);
+
+    let options =
+        TracingOptions::default().mixed_type_policy_at("a", MixedTypePolicy::StringifyAll);
+    let res = SerdeArrowSchema::from_samples(&items, options);
+    assert_error_contains(&res, "path: \"$.b\"");
+
+    let options = TracingOptions::default()
+        .mixed_type_policy(MixedTypePolicy::Union)
+        .mixed_type_policy_at("a", MixedTypePolicy::StringifyAll);
+    let schema = SerdeArrowSchema::from_samples(&items, options).unwrap();
+    let schema = serde_json::to_value(&schema).unwrap();
+    assert_eq!(schema["fields"][0]["strategy"], "Stringify");
+    assert_eq!(schema["fields"][1]["strategy"], "UntaggedUnion");
+}
+
+#[test]
+fn stringify_requires_string_fields() {
+    let res = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "I64", "strategy": "Stringify"},
+    ]));
+    assert_error_contains(&res, "invalid strategy");
+}
+
+#[test]
+fn values_without_matching_child_fail() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "Union", "strategy": "UntaggedUnion", "children": [
+            {"name": "Int64", "data_type": "I64"},
+        ]},
+    ]))
+    .unwrap();
+
+    let res = crate::to_record_batch(&fields, &json!([{"value": "foo"}]));
+    assert_error_contains(&res, "the union has no child of a matching type");
+}
@@ -1,9 +1,90 @@
 //! Test with an arrow implementation
 //!
+mod alignment;
+mod append_nulls;
+mod array_builder_chunks;
+mod arrow2_deserialization;
+mod binary_format;
+mod borrowed_values;
+mod build_limits;
+mod catch_all;
+mod codegen;
+mod concat_batches;
+mod custom_builder;
+mod custom_deserializer;
+mod decimal_format;
+mod deduplication;
+#[cfg(feature = "serde_json")]
+mod default_values;
+mod deserialize_raw;
+mod deserializer_clone;
+mod deterministic;
+mod dictionary_key_widths;
+mod dictionary_overflow;
+mod dictionary_threshold;
+mod duplicate_fields;
+mod duration_deserialization;
+mod empty_record_batch;
+mod enum_as_struct_with_tag;
+mod enum_one_hot;
+mod explode;
+mod f16;
+mod fixtures;
+#[cfg(feature = "http")]
+mod http;
 mod impls;
+mod into_fields;
+#[cfg(feature = "ipc")]
+mod ipc;
 mod issue_137_schema_like_from_arrow_schema;
 mod issue_35_preserve_metadata;
 mod issue_90_top_level_nulls_in_structs;
 mod items_wrapper;
+mod json_schema;
+mod list_nullability;
+mod map_as_struct;
+mod map_key_order;
+mod map_list_values;
+mod map_samples;
+mod map_struct_interchange;
+mod metadata_strategy;
+mod missing_fields;
+mod mixed_type_policy;
+mod null_variants_as_none;
+mod numeric_coercion;
+mod patch_record_batch;
+mod progress;
+mod projection;
+mod quantized;
+mod record_batch_iter;
+#[cfg(feature = "rayon")]
+mod record_batch_parallel;
+mod record_batch_split;
+mod reserialize_record_batch;
+mod row_format;
+mod row_sink;
+mod schema_from_record_batch;
+mod schema_metadata;
 mod schema_overwrites;
+mod seconds_strategy;
 mod serializer_deserializer;
+mod session;
+mod shared_dictionaries;
+mod sort_by;
+mod sparse_unions;
+#[cfg(feature = "spill")]
+mod spill;
+mod strategy_overrides;
+mod strict_nullability;
+mod strict_schema;
+mod string_interner;
+mod string_length_limit;
+mod struct_field_order;
+mod timestamp_unit;
+mod transcode;
+mod union_bulk_writes;
+mod unsupported_data_types;
+mod utc_offsets;
+mod variant_extractors;
+mod view_types;
+mod zero_columns_rows;
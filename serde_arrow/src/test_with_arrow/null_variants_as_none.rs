@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::ArrayRef, datatypes::FieldRef},
+    schema::{SchemaLike, TracingOptions},
+    utils::Item,
+    Deserializer, DeserializerOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Value {
+    Empty,
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    value: Value,
+    values: Vec<Value>,
+}
+
+fn items() -> Vec<Record> {
+    vec![
+        Record {
+            value: Value::Number(1),
+            values: vec![Value::Empty, Value::Text(String::from("foo"))],
+        },
+        Record {
+            value: Value::Empty,
+            values: vec![],
+        },
+    ]
+}
+
+fn arrays() -> (Vec<FieldRef>, Vec<ArrayRef>) {
+    let items = items();
+    let fields =
+        Vec::<FieldRef>::from_samples(&items, TracingOptions::default().allow_null_fields(true))
+            .unwrap();
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    (fields, arrays)
+}
+
+#[test]
+fn null_variants_are_enums_by_default() {
+    let (fields, arrays) = arrays();
+    let deserializer = Deserializer::from_arrow(&fields, &arrays).unwrap();
+    let actual = serde_json::Value::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        json!([
+            {"value": {"Number": 1}, "values": [{"Empty": null}, {"Text": "foo"}]},
+            {"value": {"Empty": null}, "values": []},
+        ])
+    );
+}
+
+#[test]
+fn null_variants_as_none() {
+    let (fields, arrays) = arrays();
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().null_variants_as_none(true));
+    let actual = serde_json::Value::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        json!([
+            {"value": {"Number": 1}, "values": [null, {"Text": "foo"}]},
+            {"value": null, "values": []},
+        ])
+    );
+}
+
+#[test]
+fn rust_enums_are_not_affected() {
+    let (fields, arrays) = arrays();
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().null_variants_as_none(true));
+    let actual = Vec::<Record>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, items());
+}
+
+#[test]
+fn optional_enums_are_not_affected() {
+    let items = [
+        Item(Some(Value::Empty)),
+        Item(None),
+        Item(Some(Value::Number(2))),
+    ];
+    let fields =
+        Vec::<FieldRef>::from_samples(&items, TracingOptions::default().allow_null_fields(true))
+            .unwrap();
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().null_variants_as_none(true));
+    let actual = Vec::<Item<Option<Value>>>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, items);
+}
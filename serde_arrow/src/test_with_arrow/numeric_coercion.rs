@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{
+        array::{ArrayRef, PrimitiveArray, RecordBatch},
+        datatypes::{Field, Float32Type, Float64Type, Int64Type, Schema, UInt8Type},
+    },
+    internal::testing::assert_error_contains,
+    Deserializer, DeserializerOptions,
+};
+
+use super::fixtures::fields;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Record<T> {
+    value: T,
+}
+
+fn deserialize<T: for<'de> Deserialize<'de>>(
+    array: ArrayRef,
+    coerce: bool,
+) -> crate::Result<Vec<T>> {
+    let field = Field::new("value", array.data_type().clone(), true);
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(vec![field])), vec![array]).unwrap();
+    let deserializer = Deserializer::from_record_batch(&batch)?
+        .with_options(DeserializerOptions::default().numeric_coercion(coerce));
+    Vec::<T>::deserialize(deserializer)
+}
+
+fn int64(values: Vec<i64>) -> ArrayRef {
+    Arc::new(PrimitiveArray::<Int64Type>::from(values))
+}
+
+fn float64(values: Vec<f64>) -> ArrayRef {
+    Arc::new(PrimitiveArray::<Float64Type>::from(values))
+}
+
+#[test]
+fn integers_are_narrowed_with_checks() {
+    let res = deserialize::<Record<i32>>(int64(vec![1, -2, 3]), false).unwrap();
+    assert_eq!(
+        res,
+        [
+            Record { value: 1 },
+            Record { value: -2 },
+            Record { value: 3 }
+        ]
+    );
+
+    let res = deserialize::<Record<i32>>(int64(vec![1, i64::MAX]), false);
+    assert_error_contains(
+        &res,
+        "Value 9223372036854775807 in row 1 is out of range for i32",
+    );
+    assert_error_contains(&res, "$.value");
+
+    let res = deserialize::<Record<u8>>(int64(vec![-1]), true);
+    assert_error_contains(&res, "Value -1 in row 0 is out of range for u8");
+}
+
+#[test]
+fn integers_as_floats_require_coercion() {
+    let res = deserialize::<Record<f64>>(int64(vec![1, 2]), false);
+    assert_error_contains(&res, "requires numeric coercion");
+
+    let res = deserialize::<Record<f64>>(int64(vec![1, 2]), true).unwrap();
+    assert_eq!(res, [Record { value: 1.0 }, Record { value: 2.0 }]);
+
+    let array: ArrayRef = Arc::new(PrimitiveArray::<UInt8Type>::from(vec![255]));
+    let res = deserialize::<Record<f32>>(array, true).unwrap();
+    assert_eq!(res, [Record { value: 255.0 }]);
+}
+
+#[test]
+fn floats_as_integers_require_coercion() {
+    let res = deserialize::<Record<i32>>(float64(vec![1.0]), false);
+    assert_error_contains(&res, "requires numeric coercion");
+
+    let res = deserialize::<Record<i32>>(float64(vec![1.0, -4.0]), true).unwrap();
+    assert_eq!(res, [Record { value: 1 }, Record { value: -4 }]);
+
+    let array: ArrayRef = Arc::new(PrimitiveArray::<Float32Type>::from(vec![7.0]));
+    let res = deserialize::<Record<u64>>(array, true).unwrap();
+    assert_eq!(res, [Record { value: 7 }]);
+}
+
+#[test]
+fn floats_as_integers_must_be_exact() {
+    let res = deserialize::<Record<i32>>(float64(vec![1.0, 1.5]), true);
+    assert_error_contains(&res, "Value 1.5 in row 1 is not an integer");
+
+    let res = deserialize::<Record<i32>>(float64(vec![f64::NAN]), true);
+    assert_error_contains(&res, "is not an integer");
+
+    let res = deserialize::<Record<i32>>(float64(vec![1e10]), true);
+    assert_error_contains(&res, "in row 0 is out of range for i32");
+
+    let res = deserialize::<Record<u64>>(float64(vec![1e30]), true);
+    assert_error_contains(&res, "out of range for u64");
+
+    let res = deserialize::<Record<u32>>(float64(vec![-1.0]), true);
+    assert_error_contains(&res, "out of range for u32");
+}
+
+#[test]
+fn float_narrowing_is_checked_with_coercion() {
+    let res = deserialize::<Record<f32>>(float64(vec![1e300]), false).unwrap();
+    assert_eq!(
+        res,
+        [Record {
+            value: f32::INFINITY
+        }]
+    );
+
+    let res = deserialize::<Record<f32>>(float64(vec![0.5, 1e300]), true);
+    assert_error_contains(&res, "in row 1 is out of range for f32");
+
+    let res = deserialize::<Record<f32>>(float64(vec![0.5, f64::INFINITY]), true).unwrap();
+    assert_eq!(
+        res,
+        [
+            Record { value: 0.5 },
+            Record {
+                value: f32::INFINITY
+            }
+        ]
+    );
+}
+
+#[test]
+fn nested_values_are_coerced() {
+    #[derive(Serialize, Deserialize)]
+    struct Input {
+        value: Vec<i64>,
+    }
+
+    let items = [Input { value: vec![1, 2] }, Input { value: vec![3] }];
+    let fields = fields::<Input>();
+    let batch = crate::to_record_batch(&fields, &items).unwrap();
+
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_options(DeserializerOptions::default().numeric_coercion(true));
+    let res = Vec::<Record<Vec<f64>>>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        res,
+        [
+            Record {
+                value: vec![1.0, 2.0]
+            },
+            Record { value: vec![3.0] },
+        ]
+    );
+}
@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::RecordBatch, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u64,
+    name: String,
+    score: Option<f32>,
+    tags: Vec<String>,
+}
+
+fn record_batch() -> RecordBatch {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U64"},
+        {"name": "name", "data_type": "Utf8"},
+        {"name": "score", "data_type": "F32", "nullable": true},
+        {"name": "tags", "data_type": "List", "children": [
+            {"name": "element", "data_type": "Utf8"},
+        ]},
+    ]))
+    .unwrap();
+    crate::to_record_batch(
+        &fields,
+        &[
+            Record {
+                id: 0,
+                name: String::from("foo"),
+                score: None,
+                tags: vec![String::from("a")],
+            },
+            Record {
+                id: 1,
+                name: String::from("bar"),
+                score: Some(2.0),
+                tags: vec![],
+            },
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn patch_single_column() {
+    #[derive(Serialize)]
+    struct Patch {
+        score: Option<f32>,
+    }
+
+    let original = record_batch();
+    let patched = crate::patch_record_batch(
+        &original,
+        &[Patch { score: Some(1.0) }, Patch { score: None }],
+    )
+    .unwrap();
+
+    assert_eq!(patched.schema(), original.schema());
+    assert!(std::sync::Arc::ptr_eq(
+        patched.column(0),
+        original.column(0)
+    ));
+    assert!(std::sync::Arc::ptr_eq(
+        patched.column(3),
+        original.column(3)
+    ));
+
+    let items: Vec<Record> = crate::from_record_batch(&patched).unwrap();
+    assert_eq!(
+        items,
+        [
+            Record {
+                id: 0,
+                name: String::from("foo"),
+                score: Some(1.0),
+                tags: vec![String::from("a")],
+            },
+            Record {
+                id: 1,
+                name: String::from("bar"),
+                score: None,
+                tags: vec![],
+            },
+        ]
+    );
+}
+
+#[test]
+fn patch_multiple_columns_in_any_order() {
+    let original = record_batch();
+    let patched = crate::patch_record_batch(
+        &original,
+        &json!([
+            {"tags": ["x", "y"], "name": "FOO"},
+            {"tags": [], "name": "BAR"},
+        ]),
+    )
+    .unwrap();
+
+    let items: Vec<Record> = crate::from_record_batch(&patched).unwrap();
+    assert_eq!(
+        items,
+        [
+            Record {
+                id: 0,
+                name: String::from("FOO"),
+                score: None,
+                tags: vec![String::from("x"), String::from("y")],
+            },
+            Record {
+                id: 1,
+                name: String::from("BAR"),
+                score: Some(2.0),
+                tags: vec![],
+            },
+        ]
+    );
+}
+
+#[test]
+fn unknown_fields_are_rejected() {
+    let res = crate::patch_record_batch(&record_batch(), &json!([{"foo": 1}, {"foo": 2}]));
+    assert_error_contains(&res, "field \"foo\" does not exist");
+}
+
+#[test]
+fn number_of_items_must_match() {
+    let res = crate::patch_record_batch(&record_batch(), &json!([{"id": 1}]));
+    assert_error_contains(&res, "Cannot patch record batch with 2 rows using 1 items");
+}
+
+#[test]
+fn incompatible_values_are_rejected() {
+    let res = crate::patch_record_batch(&record_batch(), &json!([{"id": "a"}, {"id": "b"}]));
+    assert_error_contains(&res, "field: \"$.id\"");
+}
+
+#[test]
+fn types_are_taken_from_the_record_batch() {
+    // tracing the patch would fail: the values are nulls and numbers of mixed types
+    let original = record_batch();
+    let patched =
+        crate::patch_record_batch(&original, &json!([{"score": null}, {"score": 4}])).unwrap();
+
+    assert_eq!(patched.schema(), original.schema());
+    let items: Vec<Record> = crate::from_record_batch(&patched).unwrap();
+    assert_eq!(items[0].score, None);
+    assert_eq!(items[1].score, Some(4.0));
+}
+
+#[test]
+fn empty_record_batches_are_returned_unchanged() {
+    let original = record_batch().slice(0, 0);
+    let patched = crate::patch_record_batch(&original, &json!([])).unwrap();
+    assert_eq!(patched, original);
+
+    let res = crate::patch_record_batch(&original, &json!([{"id": 1}]));
+    assert_error_contains(&res, "Cannot patch record batch with 0 rows using 1 items");
+}
+
+#[test]
+fn empty_items_are_rejected_for_non_empty_record_batches() {
+    let res = crate::patch_record_batch(&record_batch(), &json!([]));
+    assert_error_contains(&res, "Cannot patch record batch with 2 rows using 0 items");
+}
+
+#[test]
+fn non_record_items_are_rejected() {
+    let res = crate::patch_record_batch(&record_batch(), &json!([1, 2]));
+    assert_error_contains(&res, "the items must be structs or maps");
+}
@@ -0,0 +1,75 @@
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ArrayBuilder;
+
+use super::fixtures::fields;
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    a: u32,
+}
+
+fn records(n: u32) -> Vec<Record> {
+    (0..n).map(|a| Record { a }).collect()
+}
+
+#[test]
+fn num_rows() {
+    let mut builder = ArrayBuilder::from_arrow(&fields::<Record>()).unwrap();
+    assert_eq!(builder.num_rows(), 0);
+
+    builder.extend(records(3)).unwrap();
+    builder.push(Record { a: 4 }).unwrap();
+    assert_eq!(builder.num_rows(), 4);
+
+    let batch = builder.to_record_batch().unwrap();
+    assert_eq!(batch.num_rows(), 4);
+    assert_eq!(builder.num_rows(), 0);
+}
+
+#[test]
+fn progress_is_reported_across_batches() {
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reported);
+
+    let mut builder = ArrayBuilder::from_arrow(&fields::<Record>())
+        .unwrap()
+        .on_progress(2, move |rows| sink.lock().unwrap().push(rows));
+
+    builder.extend(records(5)).unwrap();
+    builder.to_record_batch().unwrap();
+    builder.push(Record { a: 5 }).unwrap();
+
+    assert_eq!(*reported.lock().unwrap(), [2, 4, 6]);
+}
+
+#[test]
+fn failed_rows_are_not_counted() {
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reported);
+
+    let mut builder = ArrayBuilder::from_arrow(&fields::<Record>())
+        .unwrap()
+        .on_progress(1, move |rows| sink.lock().unwrap().push(rows));
+
+    builder.push(Record { a: 0 }).unwrap();
+    assert!(builder.push("not a record").is_err());
+    builder.push(Record { a: 1 }).unwrap();
+
+    assert_eq!(*reported.lock().unwrap(), [1, 2]);
+}
+
+#[test]
+fn zero_interval_disables_progress() {
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reported);
+
+    let mut builder = ArrayBuilder::from_arrow(&fields::<Record>())
+        .unwrap()
+        .on_progress(0, move |rows| sink.lock().unwrap().push(rows));
+    builder.extend(records(3)).unwrap();
+
+    assert!(reported.lock().unwrap().is_empty());
+}
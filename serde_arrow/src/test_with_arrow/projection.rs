@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::_impl::arrow::datatypes::{DataType, Field, Fields, Schema};
+
+fn schema() -> Schema {
+    let meta = Fields::from(vec![
+        Field::new("created", DataType::Int64, false),
+        Field::new("tags", DataType::Utf8, true),
+    ]);
+    let item = Fields::from(vec![
+        Field::new("name", DataType::Utf8, false),
+        Field::new("quantity", DataType::UInt32, true),
+    ]);
+    let entries = Fields::from(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Float64, true),
+    ]);
+
+    Schema::new(vec![
+        // leaf 0
+        Field::new("id", DataType::UInt64, false),
+        // leaves 1, 2
+        Field::new("meta", DataType::Struct(meta), true),
+        // leaves 3, 4
+        Field::new(
+            "items",
+            DataType::LargeList(Field::new("element", DataType::Struct(item), false).into()),
+            false,
+        ),
+        // leaves 5, 6
+        Field::new(
+            "scores",
+            DataType::Map(
+                Field::new("entries", DataType::Struct(entries), false).into(),
+                false,
+            ),
+            false,
+        ),
+        // leaf 7
+        Field::new("payload", DataType::LargeBinary, true),
+    ])
+}
+
+#[test]
+fn top_level_fields() {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        id: u64,
+        payload: Option<Vec<u8>>,
+    }
+
+    let projection = crate::projection_for::<Record>(&schema()).unwrap();
+    assert_eq!(projection, [0, 7]);
+}
+
+#[test]
+fn nested_struct_fields() {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Meta {
+        tags: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        meta: Option<Meta>,
+    }
+
+    let projection = crate::projection_for::<Record>(&schema()).unwrap();
+    assert_eq!(projection, [2]);
+}
+
+#[test]
+fn list_of_struct_fields() {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Item {
+        quantity: Option<u32>,
+    }
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        id: u64,
+        items: Vec<Item>,
+    }
+
+    let projection = crate::projection_for::<Record>(&schema()).unwrap();
+    assert_eq!(projection, [0, 4]);
+}
+
+#[test]
+fn maps_use_keys_and_values() {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        scores: HashMap<String, Option<f64>>,
+    }
+
+    let projection = crate::projection_for::<Record>(&schema()).unwrap();
+    assert_eq!(projection, [5, 6]);
+}
+
+#[test]
+fn unexplored_fields_are_kept() {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        meta: serde_json::Value,
+    }
+
+    let projection = crate::projection_for::<Record>(&schema()).unwrap();
+    assert_eq!(projection, [1, 2]);
+}
+
+#[test]
+fn flattened_fields_keep_all_columns() {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        id: u64,
+        #[serde(flatten)]
+        rest: HashMap<String, serde_json::Value>,
+    }
+
+    let projection = crate::projection_for::<Record>(&schema()).unwrap();
+    assert_eq!(projection, [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn enums_and_newtypes() {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    enum Name {
+        Foo,
+        Bar,
+    }
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Id(u64);
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Item {
+        name: Name,
+    }
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        id: Id,
+        items: Vec<Item>,
+        payload: Option<Vec<u8>>,
+    }
+
+    let projection = crate::projection_for::<Record>(&schema()).unwrap();
+    assert_eq!(projection, [0, 3, 7]);
+}
+
+#[test]
+fn unknown_fields_are_ignored() {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        id: u64,
+        missing: Option<String>,
+    }
+
+    let projection = crate::projection_for::<Record>(&schema()).unwrap();
+    assert_eq!(projection, [0]);
+}
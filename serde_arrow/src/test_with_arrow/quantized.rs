@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, GenericListArray, PrimitiveArray},
+        datatypes::{FieldRef, Int8Type, UInt8Type},
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, Strategy},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    embedding: Vec<f32>,
+}
+
+fn embedding_fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([{
+        "name": "embedding",
+        "data_type": "LargeList",
+        "children": [
+            {"name": "element", "data_type": "U8", "strategy": "Quantized(0.5, 128)"},
+        ],
+    }]))
+    .unwrap()
+}
+
+#[test]
+fn embeddings_round_trip() {
+    let items = [
+        Record {
+            embedding: vec![0.0, 0.5, -1.0, 10.0],
+        },
+        Record {
+            embedding: vec![-64.0, 63.5],
+        },
+    ];
+    let fields = embedding_fields();
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let list = arrays[0]
+        .as_any()
+        .downcast_ref::<GenericListArray<i64>>()
+        .unwrap();
+    let values = list
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<UInt8Type>>()
+        .unwrap();
+    assert_eq!(values.values().as_ref(), [128, 129, 126, 148, 0, 255]);
+
+    let round_tripped: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_tripped, items);
+}
+
+#[test]
+fn values_are_rounded_and_clamped() {
+    let items = [Record {
+        embedding: vec![0.3, -0.3, 1000.0, -1000.0, f32::INFINITY, f32::NEG_INFINITY],
+    }];
+    let fields = embedding_fields();
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let round_tripped: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(
+        round_tripped,
+        [Record {
+            embedding: vec![0.5, -0.5, 63.5, -64.0, 63.5, -64.0],
+        }],
+    );
+}
+
+#[test]
+fn nan_values_are_rejected() {
+    let items = [Record {
+        embedding: vec![f32::NAN],
+    }];
+    let res = crate::to_arrow(&embedding_fields(), &items);
+    assert_error_contains(&res, "Cannot quantize NaN");
+}
+
+#[test]
+fn signed_fields_and_f64_values() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        value: Option<f64>,
+    }
+
+    let fields = Vec::<FieldRef>::from_value(json!([{
+        "name": "value",
+        "data_type": "I8",
+        "nullable": true,
+        "strategy": "Quantized(0.25, 0)",
+    }]))
+    .unwrap();
+    let items = [
+        Item { value: Some(-1.0) },
+        Item { value: None },
+        Item { value: Some(2.25) },
+    ];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let array = arrays[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<Int8Type>>()
+        .unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), [Some(-4), None, Some(9)],);
+
+    let round_tripped: Vec<Item> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_tripped, items);
+}
+
+#[test]
+fn integers_are_not_converted() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        value: u8,
+    }
+
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "U8", "strategy": "Quantized(0.5, 128)"},
+    ]))
+    .unwrap();
+    let items = [Item { value: 3 }];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let array = arrays[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<UInt8Type>>()
+        .unwrap();
+    assert_eq!(array.value(0), 3);
+
+    let round_tripped: Vec<Item> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_tripped, items);
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    let res = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "U8", "strategy": "Quantized(0.0, 128)"},
+    ]));
+    assert_error_contains(&res, "it must be positive");
+
+    let res = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "I8", "strategy": "Quantized(0.5, 128)"},
+    ]));
+    assert_error_contains(&res, "it must be in [-128, 127]");
+
+    let res = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "I16", "strategy": "Quantized(0.5, 0)"},
+    ]));
+    assert_error_contains(&res, "invalid strategy for Int16");
+}
+
+#[test]
+fn strategy_repr() {
+    let strategy: Strategy = "Quantized(0.05, 128)".parse().unwrap();
+    assert_eq!(
+        strategy,
+        Strategy::Quantized {
+            scale: 0.05,
+            zero_point: 128
+        }
+    );
+    assert_eq!(strategy.to_string(), "Quantized(0.05, 128)");
+
+    assert_error_contains(&"Quantized(0.05)".parse::<Strategy>(), "Invalid arguments");
+    assert_error_contains(&"Quantized(x, 1)".parse::<Strategy>(), "Invalid scale");
+    assert_error_contains(
+        &"Quantized(0.5, x)".parse::<Strategy>(),
+        "Invalid zero point",
+    );
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef, internal::testing::assert_error_contains,
+    schema::SchemaLike, ArrayBuilder, SerializerOptions,
+};
+
+use super::fixtures::fields;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: u32,
+    b: String,
+}
+
+fn record(a: u32) -> Record {
+    Record {
+        a,
+        b: a.to_string(),
+    }
+}
+
+fn builder() -> ArrayBuilder {
+    let fields = fields::<Record>();
+    ArrayBuilder::from_arrow(&fields).unwrap()
+}
+
+#[test]
+fn batches_are_emitted_every_batch_size_rows() {
+    let mut builder = builder();
+    let batches = builder
+        .to_record_batch_iter((0..7).map(record), 3)
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+
+    let num_rows = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![3, 3, 1]);
+
+    let mut items = Vec::new();
+    for batch in &batches {
+        items.extend(crate::from_record_batch::<Vec<Record>>(batch).unwrap());
+    }
+    assert_eq!(items, (0..7).map(record).collect::<Vec<_>>());
+    assert_eq!(builder.num_rows(), 0);
+}
+
+#[test]
+fn items_are_pulled_lazily() {
+    let mut builder = builder();
+    let pulled = std::cell::Cell::new(0);
+    let items = (0..10).map(|a| {
+        pulled.set(pulled.get() + 1);
+        record(a)
+    });
+    let mut batches = builder.to_record_batch_iter(items, 4);
+
+    let batch = batches.next().unwrap().unwrap();
+    assert_eq!(batch.num_rows(), 4);
+    assert_eq!(pulled.get(), 4);
+}
+
+#[test]
+fn exact_multiples_do_not_emit_empty_batches() {
+    let mut builder = builder();
+    let num_rows = builder
+        .to_record_batch_iter((0..4).map(record), 2)
+        .map(|batch| batch.unwrap().num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![2, 2]);
+
+    let mut builder = self::builder();
+    let mut batches = builder.to_record_batch_iter(Vec::<Record>::new(), 2);
+    assert!(batches.next().is_none());
+}
+
+#[test]
+fn previously_pushed_items_are_included_in_the_first_batch() {
+    let mut builder = builder();
+    builder.push(record(0)).unwrap();
+
+    let num_rows = builder
+        .to_record_batch_iter((1..4).map(record), 2)
+        .map(|batch| batch.unwrap().num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![2, 2]);
+}
+
+#[test]
+fn the_builder_can_be_reused() {
+    let mut builder = builder().with_options(
+        SerializerOptions::default().schema_metadata([("k".into(), "v".into())].into()),
+    );
+
+    for _ in 0..2 {
+        for batch in builder.to_record_batch_iter((0..3).map(record), 2) {
+            let batch = batch.unwrap();
+            assert_eq!(
+                batch.schema().metadata().get("k").map(String::as_str),
+                Some("v")
+            );
+        }
+    }
+}
+
+#[test]
+fn zero_batch_size_is_an_error() {
+    let mut builder = builder();
+    let mut batches = builder.to_record_batch_iter((0..3).map(record), 0);
+    assert_error_contains(&batches.next().unwrap(), "batch size must be positive");
+    assert!(batches.next().is_none());
+}
+
+#[test]
+fn errors_end_the_iteration() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U8"},
+    ]))
+    .unwrap();
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+
+    #[derive(Serialize)]
+    struct Item {
+        a: u32,
+    }
+
+    let items = [1, 2, 300, 4].map(|a| Item { a });
+    let mut batches = builder.to_record_batch_iter(items, 2);
+    assert_eq!(batches.next().unwrap().unwrap().num_rows(), 2);
+    assert_error_contains(&batches.next().unwrap(), "field: \"$.a\"");
+    assert!(batches.next().is_none());
+}
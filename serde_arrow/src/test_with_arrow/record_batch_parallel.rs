@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::RecordBatch, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+};
+
+use super::fixtures::fields;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u64,
+    name: Option<String>,
+    values: Vec<u32>,
+}
+
+fn records(n: u64) -> Vec<Record> {
+    (0..n)
+        .map(|i| Record {
+            id: i,
+            name: (i % 3 != 0).then(|| format!("name-{i}")),
+            values: (0..i % 5).map(|j| (i * 10 + j) as u32).collect(),
+        })
+        .collect()
+}
+
+fn batch(records: &[Record]) -> RecordBatch {
+    let fields = fields::<Record>();
+    crate::to_record_batch(&fields, &records).unwrap()
+}
+
+#[test]
+fn parallel_matches_sequential() {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+
+    for n in [0, 1, 3, 4, 5, 17, 1000] {
+        let records = records(n);
+        let batch = batch(&records);
+
+        let actual: Vec<Record> = pool
+            .install(|| crate::from_record_batch_parallel(&batch))
+            .unwrap();
+        assert_eq!(actual, records, "{n} records");
+    }
+}
+
+#[test]
+fn parallel_borrows_from_the_batch() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Borrowed<'a> {
+        name: Option<&'a str>,
+    }
+
+    let records = records(100);
+    let batch = batch(&records);
+
+    let actual: Vec<Borrowed<'_>> = crate::from_record_batch_parallel(&batch).unwrap();
+    assert_eq!(actual.len(), 100);
+    assert_eq!(actual[1].name, Some("name-1"));
+    assert_eq!(actual[99].name, None);
+}
+
+#[test]
+fn errors_are_reported() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Other {
+        id: String,
+    }
+
+    let batch = batch(&records(20));
+    let res = crate::from_record_batch_parallel::<Other>(&batch);
+    assert_error_contains(&res, "$.id");
+}
+
+fn wide_records(n: usize, columns: usize) -> Vec<BTreeMap<String, Option<u32>>> {
+    (0..n)
+        .map(|row| {
+            (0..columns)
+                .map(|col| {
+                    let value = ((row + col) % 4 != 0).then_some((row * columns + col) as u32);
+                    (format!("c{col:03}"), value)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn parallel_serialization_matches_sequential() {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+
+    for n in [0, 1, 17, 1000] {
+        let records = records(n);
+        let fields = fields::<Record>();
+
+        let actual = pool
+            .install(|| crate::to_record_batch_parallel(&fields, &records))
+            .unwrap();
+        assert_eq!(actual, batch(&records), "{n} records");
+    }
+}
+
+#[test]
+fn wide_tables_are_serialized_in_column_groups() {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(3)
+        .build()
+        .unwrap();
+
+    let records = wide_records(50, 200);
+    let fields = Vec::<FieldRef>::from_samples(&records, TracingOptions::default()).unwrap();
+
+    let actual = pool
+        .install(|| crate::to_record_batch_parallel(&fields, &records))
+        .unwrap();
+    let expected = crate::to_record_batch(&fields, &records).unwrap();
+    assert_eq!(actual, expected);
+    assert_eq!(actual.num_columns(), 200);
+    assert_eq!(actual.schema().field(0).name(), "c000");
+    assert_eq!(actual.schema().field(199).name(), "c199");
+}
+
+#[test]
+fn more_threads_than_columns() {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(8)
+        .build()
+        .unwrap();
+
+    let records = records(10);
+    let fields = fields::<Record>();
+
+    let actual = pool
+        .install(|| crate::to_record_batch_parallel(&fields, &records))
+        .unwrap();
+    assert_eq!(actual, batch(&records));
+}
+
+#[test]
+fn dependent_fields_are_serialized_together() {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U64"},
+        {"name": "name", "data_type": "LargeUtf8", "nullable": true},
+        {"name": "name_hash", "data_type": "U64", "nullable": true, "strategy": "StringHash(name)"},
+        {"name": "row_hash", "data_type": "U64", "strategy": "RowHash"},
+    ]))
+    .unwrap();
+    let records = records(20);
+
+    let actual = pool
+        .install(|| crate::to_record_batch_parallel(&fields, &records))
+        .unwrap();
+    let expected = crate::to_record_batch(&fields, &records).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn serialization_errors_are_reported() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U64"},
+        {"name": "name", "data_type": "LargeUtf8"},
+    ]))
+    .unwrap();
+
+    let res = crate::to_record_batch_parallel(&fields, &records(5));
+    assert_error_contains(&res, "name");
+}
+
+#[test]
+fn tuples_are_serialized_as_a_single_group() {
+    #[derive(Debug, Serialize)]
+    struct TupleRecord(u64, Option<String>, u32);
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(3)
+        .build()
+        .unwrap();
+
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U64"},
+        {"name": "name", "data_type": "LargeUtf8", "nullable": true},
+        {"name": "value", "data_type": "U32"},
+    ]))
+    .unwrap();
+
+    let tuple_structs = (0..10)
+        .map(|i| TupleRecord(i, Some(format!("name-{i}")), i as u32 * 2))
+        .collect::<Vec<_>>();
+    let actual = pool
+        .install(|| crate::to_record_batch_parallel(&fields, &tuple_structs))
+        .unwrap();
+    let expected = crate::to_record_batch(&fields, &tuple_structs).unwrap();
+    assert_eq!(actual, expected);
+
+    let tuples = (0..10)
+        .map(|i| (i, Some(format!("name-{i}")), i as u32 * 2))
+        .collect::<Vec<_>>();
+    let actual = pool
+        .install(|| crate::to_record_batch_parallel(&fields, &tuples))
+        .unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn empty_fields_keep_the_number_of_rows() {
+    let fields: Vec<FieldRef> = Vec::new();
+    let actual = crate::to_record_batch_parallel(&fields, &records(7)).unwrap();
+    assert_eq!(actual.num_columns(), 0);
+    assert_eq!(actual.num_rows(), 7);
+}
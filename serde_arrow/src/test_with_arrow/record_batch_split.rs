@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    utils::Item,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    flag: bool,
+    value: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Kind {
+    A(u8),
+    B(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u64,
+    name: Option<String>,
+    flag: bool,
+    tags: Vec<String>,
+    inner: Option<Inner>,
+    pair: [u16; 2],
+    map: HashMap<String, i64>,
+    kind: Kind,
+}
+
+fn records() -> Vec<Record> {
+    (0..11)
+        .map(|i| Record {
+            id: i,
+            name: (i % 3 != 0).then(|| format!("name-{i}")),
+            flag: i % 2 == 0,
+            tags: (0..i % 4).map(|j| format!("tag-{i}-{j}")).collect(),
+            inner: (i % 4 != 1).then_some(Inner {
+                flag: i % 3 == 0,
+                value: (i % 2 == 1).then_some(i as i32 * 10),
+            }),
+            pair: [i as u16, 2 * i as u16],
+            map: (0..i % 3).map(|j| (format!("k{j}"), j as i64)).collect(),
+            kind: if i % 2 == 0 {
+                Kind::A(i as u8)
+            } else {
+                Kind::B(format!("b-{i}"))
+            },
+        })
+        .collect()
+}
+
+fn batch() -> crate::_impl::arrow::array::RecordBatch {
+    let fields = Vec::<FieldRef>::from_type::<Record>(
+        TracingOptions::default()
+            .map_as_struct(false)
+            .enums_without_data_as_strings(true),
+    )
+    .unwrap();
+    crate::to_record_batch(&fields, &records()).unwrap()
+}
+
+#[test]
+fn ranges_match_sliced_items() {
+    let batch = batch();
+    let records = records();
+
+    for start in 0..=records.len() {
+        for end in start..=records.len() {
+            let actual: Vec<Record> = crate::from_record_batch_range(&batch, start..end).unwrap();
+            assert_eq!(actual, records[start..end], "range {start}..{end}");
+        }
+    }
+}
+
+#[test]
+fn ranges_of_sliced_batches() {
+    let batch = batch().slice(3, 6);
+    let records = records();
+
+    let actual: Vec<Record> = crate::from_record_batch_range(&batch, 1..4).unwrap();
+    assert_eq!(actual, records[4..7]);
+}
+
+#[test]
+fn dictionary_ranges() {
+    let items = ["a", "b", "a", "c", "b"];
+    let fields = Vec::<FieldRef>::from_samples(
+        &[Item("a")],
+        TracingOptions::default().string_dictionary_encoding(true),
+    )
+    .unwrap();
+    let batch = crate::to_record_batch(&fields, &items.map(Item)).unwrap();
+
+    let actual: Vec<Item<String>> = crate::from_record_batch_range(&batch, 2..5).unwrap();
+    assert_eq!(actual, ["a", "c", "b"].map(|s| Item(s.to_owned())));
+}
+
+#[test]
+fn invalid_ranges() {
+    let batch = batch();
+
+    let res = crate::from_record_batch_range::<Vec<Record>>(&batch, 5..12);
+    assert_error_contains(&res, "Invalid range 5..12 for a record batch with 11 rows");
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let res = crate::from_record_batch_range::<Vec<Record>>(&batch, 5..4);
+    assert_error_contains(&res, "Invalid range 5..4");
+}
+
+#[test]
+fn split_into_chunks() {
+    let batch = batch();
+    let records = records();
+
+    let chunks = crate::split(&batch, 4).unwrap();
+    assert_eq!(
+        chunks.iter().map(|c| c.num_rows()).collect::<Vec<_>>(),
+        [4, 4, 3]
+    );
+
+    let mut actual = Vec::new();
+    for chunk in &chunks {
+        actual.extend(crate::from_record_batch::<Vec<Record>>(chunk).unwrap());
+    }
+    assert_eq!(actual, records);
+
+    assert_eq!(crate::split(&batch, 11).unwrap().len(), 1);
+    assert_eq!(crate::split(&batch, 100).unwrap().len(), 1);
+    assert_eq!(crate::split(&batch.slice(0, 0), 3).unwrap().len(), 0);
+}
+
+#[test]
+fn split_requires_non_zero_chunks() {
+    let res = crate::split(&batch(), 0);
+    assert_error_contains(&res, "chunks of zero rows");
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::RecordBatch, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    category: Option<String>,
+    label: String,
+    value: u32,
+}
+
+fn record(category: Option<&str>, label: &str, value: u32) -> Record {
+    Record {
+        category: category.map(String::from),
+        label: String::from(label),
+        value,
+    }
+}
+
+fn source() -> RecordBatch {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "category", "data_type": "Dictionary", "nullable": true, "children": [
+            {"name": "key", "data_type": "U16"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]},
+        {"name": "label", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "I32"},
+            {"name": "value", "data_type": "Utf8"},
+        ]},
+        {"name": "value", "data_type": "U32"},
+    ]))
+    .unwrap();
+    crate::to_record_batch(
+        &fields,
+        &[
+            record(Some("a"), "x", 1),
+            record(None, "y", 2),
+            record(Some("b"), "x", 3),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn unchanged_dictionary_columns_are_passed_through() {
+    let source = source();
+    let mut items: Vec<Record> = crate::from_record_batch(&source).unwrap();
+    for item in &mut items {
+        item.value += 10;
+    }
+
+    let result = crate::reserialize_record_batch(&source, &items).unwrap();
+    assert!(Arc::ptr_eq(result.column(0), source.column(0)));
+    assert!(Arc::ptr_eq(result.column(1), source.column(1)));
+    assert_eq!(result.schema(), source.schema());
+
+    let actual: Vec<Record> = crate::from_record_batch(&result).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn changed_dictionary_columns_are_encoded() {
+    let source = source();
+    let mut items: Vec<Record> = crate::from_record_batch(&source).unwrap();
+    items[2].label = String::from("z");
+    items[1].category = Some(String::from("c"));
+
+    let result = crate::reserialize_record_batch(&source, &items).unwrap();
+    assert!(!Arc::ptr_eq(result.column(0), source.column(0)));
+    assert!(!Arc::ptr_eq(result.column(1), source.column(1)));
+
+    let actual: Vec<Record> = crate::from_record_batch(&result).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn changed_nulls_are_detected() {
+    let source = source();
+    let mut items: Vec<Record> = crate::from_record_batch(&source).unwrap();
+    items[0].category = None;
+
+    let result = crate::reserialize_record_batch(&source, &items).unwrap();
+    assert!(!Arc::ptr_eq(result.column(0), source.column(0)));
+    assert!(Arc::ptr_eq(result.column(1), source.column(1)));
+
+    let actual: Vec<Record> = crate::from_record_batch(&result).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn fewer_items_result_in_slices() {
+    let source = source();
+    let items: Vec<Record> = crate::from_record_batch(&source).unwrap();
+
+    let result = crate::reserialize_record_batch(&source, &items[..2]).unwrap();
+    assert_eq!(result.num_rows(), 2);
+    assert_eq!(
+        result.column(1).to_data(),
+        source.column(1).slice(0, 2).to_data()
+    );
+
+    let actual: Vec<Record> = crate::from_record_batch(&result).unwrap();
+    assert_eq!(actual, items[..2]);
+}
+
+#[test]
+fn more_items_are_encoded() {
+    let source = source();
+    let mut items: Vec<Record> = crate::from_record_batch(&source).unwrap();
+    items.push(record(Some("a"), "x", 4));
+
+    let result = crate::reserialize_record_batch(&source, &items).unwrap();
+    assert_eq!(result.num_rows(), 4);
+
+    let actual: Vec<Record> = crate::from_record_batch(&result).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn invalid_items_are_reported() {
+    #[derive(Serialize)]
+    struct Invalid {
+        category: Option<String>,
+        label: String,
+        value: i64,
+    }
+
+    let source = source();
+    let res = crate::reserialize_record_batch(
+        &source,
+        &[Invalid {
+            category: None,
+            label: String::from("x"),
+            value: -1,
+        }],
+    );
+    assert_error_contains(&res, "field: \"$.value\"");
+}
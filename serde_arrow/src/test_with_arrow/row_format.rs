@@ -0,0 +1,198 @@
+use serde_json::json;
+
+use crate::{
+    internal::testing::assert_error_contains,
+    row_format,
+    schema::{SchemaLike, SerdeArrowSchema},
+};
+
+#[test]
+fn keys_preserve_order() {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "a", "data_type": "I32"},
+        {"name": "b", "data_type": "F64", "nullable": true},
+        {"name": "c", "data_type": "LargeUtf8"},
+    ]))
+    .unwrap();
+
+    let items = [
+        (1_i32, Some(0.5_f64), "b"),
+        (-1, Some(3.0), "a"),
+        (1, None, "z"),
+        (1, Some(-2.0), "a"),
+        (1, Some(0.5), "ab"),
+        (1, Some(0.5), ""),
+    ];
+    let keys = row_format::encode(&schema, items).unwrap();
+
+    let mut order = (0..items.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| row_format::compare(&keys[a], &keys[b]));
+    assert_eq!(order, [1, 2, 3, 5, 4, 0]);
+}
+
+#[test]
+fn long_strings_preserve_order() {
+    let schema = SerdeArrowSchema::from_value(json!([{"name": "s", "data_type": "Utf8"}])).unwrap();
+
+    let mut items = vec![
+        "x".repeat(64),
+        "x".repeat(33),
+        "x".repeat(32),
+        "x".repeat(31),
+        format!("{}a", "x".repeat(40)),
+        "y".to_owned(),
+    ];
+    let keys = row_format::encode(&schema, items.iter().map(|s| (s,)).collect::<Vec<_>>()).unwrap();
+
+    let mut sorted = items.iter().zip(&keys).collect::<Vec<_>>();
+    sorted.sort_by(|a, b| row_format::compare(a.1, b.1));
+    let sorted = sorted
+        .into_iter()
+        .map(|(s, _)| s.clone())
+        .collect::<Vec<_>>();
+
+    items.sort();
+    assert_eq!(sorted, items);
+}
+
+#[test]
+fn dictionaries_are_encoded_as_values() {
+    let dictionary = SerdeArrowSchema::from_value(json!([{
+        "name": "s",
+        "data_type": "Dictionary",
+        "nullable": true,
+        "children": [
+            {"name": "key", "data_type": "U16"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ],
+    }]))
+    .unwrap();
+    let plain = SerdeArrowSchema::from_value(json!([
+        {"name": "s", "data_type": "Utf8", "nullable": true},
+    ]))
+    .unwrap();
+
+    let items = [(Some("b"),), (None,), (Some("a"),), (Some("b"),)];
+    assert_eq!(
+        row_format::encode(&dictionary, items).unwrap(),
+        row_format::encode(&plain, items).unwrap(),
+    );
+}
+
+#[test]
+fn lists_are_not_supported() {
+    let schema = SerdeArrowSchema::from_value(json!([{
+        "name": "a",
+        "data_type": "LargeList",
+        "children": [{"name": "element", "data_type": "U8"}],
+    }]))
+    .unwrap();
+
+    let res = row_format::encode(&schema, [(vec![1_u8],)]);
+    assert_error_contains(&res, "Row encoding does not support lists");
+}
+
+#[cfg(has_arrow_53)]
+mod arrow_row {
+    use arrow_row_53::{RowConverter, SortField};
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        _impl::arrow::datatypes::FieldRef,
+        row_format,
+        schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    };
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Inner {
+        x: Option<u8>,
+        y: Option<String>,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Record {
+        a: i32,
+        b: Option<f64>,
+        c: String,
+        d: bool,
+        e: Option<Inner>,
+        f: Option<i64>,
+    }
+
+    fn records() -> Vec<Record> {
+        let long = "a long string that spans more than a single block of data";
+        vec![
+            Record {
+                a: 1,
+                b: Some(-0.5),
+                c: String::from("foo"),
+                d: true,
+                e: Some(Inner {
+                    x: Some(3),
+                    y: None,
+                }),
+                f: Some(-20),
+            },
+            Record {
+                a: -3,
+                b: None,
+                c: String::new(),
+                d: false,
+                e: None,
+                f: None,
+            },
+            Record {
+                a: 1,
+                b: Some(2.0),
+                c: String::from(long),
+                d: false,
+                e: Some(Inner {
+                    x: None,
+                    y: Some(String::from(long)),
+                }),
+                f: Some(i64::MAX),
+            },
+            Record {
+                a: 1,
+                b: Some(2.0),
+                c: long[..32].to_owned(),
+                d: true,
+                e: Some(Inner {
+                    x: Some(0),
+                    y: Some(String::from("bar")),
+                }),
+                f: Some(i64::MIN),
+            },
+        ]
+    }
+
+    fn schema() -> SerdeArrowSchema {
+        SerdeArrowSchema::from_type::<Record>(TracingOptions::default().allow_null_fields(true))
+            .unwrap()
+    }
+
+    #[test]
+    fn matches_arrow_row() {
+        let items = records();
+        let schema = schema();
+
+        let fields = Vec::<FieldRef>::try_from(&schema).unwrap();
+        let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+        let converter = RowConverter::new(
+            fields
+                .iter()
+                .map(|field| SortField::new(field.data_type().clone()))
+                .collect(),
+        )
+        .unwrap();
+        let expected = converter.convert_columns(&arrays).unwrap();
+        let expected = expected
+            .iter()
+            .map(|row| row.as_ref().to_vec())
+            .collect::<Vec<_>>();
+
+        let actual = row_format::encode(&schema, &items).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{utils::Item, ArrayBuilder, RowSink, SinkState};
+
+use super::fixtures::{fields, Record};
+
+#[test]
+fn signals_full_after_max_rows() {
+    let fields = fields::<Record>();
+    let mut sink = RowSink::new(ArrayBuilder::from_arrow(&fields).unwrap(), 2);
+
+    assert!(sink.is_empty());
+    assert_eq!(sink.state(), SinkState::Accepting);
+
+    let state = sink.push(&Record { a: 0, b: None }).unwrap();
+    assert_eq!(state, SinkState::Accepting);
+    assert_eq!(sink.num_rows(), 1);
+
+    let state = sink
+        .push(&Record {
+            a: 1,
+            b: Some(String::from("foo")),
+        })
+        .unwrap();
+    assert_eq!(state, SinkState::Full);
+
+    // pushing into a full sink is allowed
+    let state = sink.push(&Record { a: 2, b: None }).unwrap();
+    assert_eq!(state, SinkState::Full);
+    assert_eq!(sink.num_rows(), 3);
+
+    let batch = sink.as_mut().to_record_batch().unwrap();
+    assert_eq!(batch.num_rows(), 3);
+    assert!(sink.is_empty());
+    assert_eq!(sink.state(), SinkState::Accepting);
+
+    let items: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(items[2], Record { a: 2, b: None });
+}
+
+#[test]
+fn cut_batches() {
+    let fields = fields::<Item<u32>>();
+    let mut sink = RowSink::new(ArrayBuilder::from_arrow(&fields).unwrap(), 3);
+
+    let mut batches = Vec::new();
+    for item in 0..7 {
+        if sink.push(&Item(item)).unwrap() == SinkState::Full {
+            batches.push(sink.as_mut().to_arrow().unwrap());
+        }
+    }
+    if !sink.is_empty() {
+        batches.push(sink.as_mut().to_arrow().unwrap());
+    }
+
+    let lengths = batches.iter().map(|b| b[0].len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![3, 3, 1]);
+}
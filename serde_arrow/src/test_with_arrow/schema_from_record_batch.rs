@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    schema::{SchemaLike, SerdeArrowSchema, Strategy, TracingOptions, STRATEGY_KEY},
+};
+
+fn strategy(schema: &SerdeArrowSchema, idx: usize) -> Option<Strategy> {
+    schema.fields[idx]
+        .metadata
+        .get(STRATEGY_KEY)
+        .map(|strategy| strategy.parse().unwrap())
+}
+
+#[test]
+fn strategies_are_kept() {
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        date: chrono::NaiveDateTime,
+        pair: (u8, String),
+    }
+
+    let items = [Record {
+        date: chrono::NaiveDateTime::default(),
+        pair: (1, String::from("foo")),
+    }];
+    let fields =
+        Vec::<FieldRef>::from_samples(&items, TracingOptions::default().guess_dates(true)).unwrap();
+    let batch = crate::to_record_batch(&fields, &items).unwrap();
+
+    let schema = SerdeArrowSchema::from_record_batch(&batch).unwrap();
+    assert_eq!(strategy(&schema, 0), Some(Strategy::NaiveStrAsDate64));
+    assert_eq!(strategy(&schema, 1), Some(Strategy::TupleAsStruct));
+    assert_eq!(
+        schema,
+        SerdeArrowSchema::from_samples(&items, TracingOptions::default().guess_dates(true))
+            .unwrap(),
+    );
+}
+
+#[test]
+fn strategies_are_recovered() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "date", "data_type": "Date64", "nullable": true},
+        {
+            "name": "pair",
+            "data_type": "Struct",
+            "children": [
+                {"name": "0", "data_type": "U8"},
+                {"name": "1", "data_type": "LargeUtf8"},
+            ],
+        },
+        {
+            "name": "items",
+            "data_type": "LargeList",
+            "children": [{
+                "name": "element",
+                "data_type": "Struct",
+                "children": [{"name": "x", "data_type": "Date64"}],
+            }],
+        },
+    ]))
+    .unwrap();
+    let batch = crate::to_record_batch(
+        &fields,
+        &json!([{
+            "id": 0,
+            "date": "2023-11-14T22:13:20Z",
+            "pair": {"0": 1, "1": "foo"},
+            "items": [{"x": "1970-01-01T00:00:00Z"}],
+        }])
+        .as_array()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let schema = SerdeArrowSchema::from_record_batch(&batch).unwrap();
+    assert_eq!(strategy(&schema, 0), None);
+    assert_eq!(strategy(&schema, 1), Some(Strategy::UtcStrAsDate64));
+    assert_eq!(strategy(&schema, 2), Some(Strategy::TupleAsStruct));
+    assert_eq!(strategy(&schema, 3), None);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        id: u32,
+        date: Option<chrono::DateTime<chrono::Utc>>,
+        pair: (u8, String),
+        items: Vec<Item>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        x: String,
+    }
+
+    let items: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(items[0].items[0].x, "1970-01-01T00:00:00Z");
+
+    let fields = Vec::<FieldRef>::try_from(&schema).unwrap();
+    let roundtripped = crate::to_record_batch(&fields, &items).unwrap();
+    for idx in 0..3 {
+        assert_eq!(roundtripped.column(idx), batch.column(idx));
+    }
+
+    let actual: Vec<Record> = crate::from_record_batch(&roundtripped).unwrap();
+    assert_eq!(actual, items);
+}
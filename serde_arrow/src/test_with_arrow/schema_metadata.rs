@@ -0,0 +1,76 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ArrayBuilder, SerializerOptions, Session};
+
+use super::fixtures::fields;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: u32,
+}
+
+fn metadata() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        (String::from("job_id"), String::from("42")),
+        (String::from("source"), String::from("sensors")),
+    ])
+}
+
+fn expected() -> HashMap<String, String> {
+    metadata().into_iter().collect()
+}
+
+#[test]
+fn record_batches_carry_the_metadata() {
+    let mut builder = ArrayBuilder::from_arrow(&fields::<Record>())
+        .unwrap()
+        .with_options(SerializerOptions::default().schema_metadata(metadata()));
+
+    builder.push(Record { a: 1 }).unwrap();
+    let batch = builder.to_record_batch().unwrap();
+    assert_eq!(batch.schema().metadata(), &expected());
+
+    // the metadata does not interfere with deserialization
+    let items: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(items, vec![Record { a: 1 }]);
+
+    // the metadata is kept for later batches
+    builder.push(Record { a: 2 }).unwrap();
+    let batch = builder.to_record_batch().unwrap();
+    assert_eq!(batch.schema().metadata(), &expected());
+}
+
+#[test]
+fn record_batches_of_chunks_carry_the_metadata() {
+    let mut builder = ArrayBuilder::from_arrow(&fields::<Record>())
+        .unwrap()
+        .with_options(SerializerOptions::default().schema_metadata(metadata()));
+
+    let batches = builder
+        .to_record_batches([vec![Record { a: 1 }], vec![Record { a: 2 }]])
+        .unwrap();
+    assert_eq!(batches.len(), 2);
+    for batch in &batches {
+        assert_eq!(batch.schema().metadata(), &expected());
+    }
+}
+
+#[test]
+fn sessions_carry_the_metadata() {
+    let mut session = Session::from_arrow_fields(&fields::<Record>())
+        .unwrap()
+        .with_serializer_options(SerializerOptions::default().schema_metadata(metadata()));
+
+    let batch = session.to_record_batch([Record { a: 1 }]).unwrap();
+    assert_eq!(batch.schema().metadata(), &expected());
+}
+
+#[test]
+fn no_metadata_by_default() {
+    let mut builder = ArrayBuilder::from_arrow(&fields::<Record>()).unwrap();
+    builder.push(Record { a: 1 }).unwrap();
+    let batch = builder.to_record_batch().unwrap();
+    assert!(batch.schema().metadata().is_empty());
+}
@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::{DataType, FieldRef, TimeUnit},
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SecondsType, Strategy, TracingOptions, STRATEGY_KEY},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record<T> {
+    value: T,
+}
+
+fn fields(data_type: &str) -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": data_type, "nullable": true, "strategy": "Seconds"},
+    ]))
+    .unwrap()
+}
+
+fn round_trip<T>(fields: &[FieldRef], items: &[T]) -> Vec<T>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let arrays = crate::to_arrow(fields, items).unwrap();
+    crate::from_arrow(fields, &arrays).unwrap()
+}
+
+#[test]
+fn integer_durations() {
+    let items = [
+        Record {
+            value: Some(30_u64),
+        },
+        Record { value: None },
+        Record { value: Some(0_u64) },
+    ];
+    for unit in ["Second", "Millisecond", "Microsecond", "Nanosecond"] {
+        let fields = fields(&format!("Duration({unit})"));
+        assert_eq!(round_trip(&fields, &items), items);
+    }
+
+    // without the strategy, the raw values in the unit of the field are used
+    let arrays = crate::to_arrow(&fields("Duration(Millisecond)"), &items).unwrap();
+    let raw_fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "Duration(Millisecond)", "nullable": true},
+    ]))
+    .unwrap();
+    let raw: Vec<Record<Option<i64>>> = crate::from_arrow(&raw_fields, &arrays).unwrap();
+    assert_eq!(raw[0].value, Some(30_000));
+}
+
+#[test]
+fn fractional_durations() {
+    let items = [Record { value: 1.5_f64 }, Record { value: -0.25_f64 }];
+    let fields = fields("Duration(Millisecond)");
+    assert_eq!(round_trip(&fields, &items), items);
+}
+
+#[test]
+fn string_durations() {
+    let items = [
+        Record {
+            value: String::from("1.5"),
+        },
+        Record {
+            value: String::from("30"),
+        },
+    ];
+    let fields = fields("Duration(Microsecond)");
+    assert_eq!(round_trip(&fields, &items), items);
+}
+
+#[test]
+fn fractional_values_cannot_be_deserialized_as_integers() {
+    let fields = fields("Duration(Millisecond)");
+    let arrays = crate::to_arrow(&fields, [Record { value: 1.5_f64 }]).unwrap();
+    let res = crate::from_arrow::<Vec<Record<i64>>, _>(&fields, &arrays);
+    assert_error_contains(&res, "Value 1.5 cannot be deserialized as whole seconds");
+}
+
+#[test]
+fn floats_require_the_seconds_strategy() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "Duration(Second)"},
+    ]))
+    .unwrap();
+    let res = crate::to_arrow(&fields, [Record { value: 1.5_f64 }]);
+    assert_error_contains(&res, "Floats can only be serialized into durations");
+}
+
+#[test]
+fn timestamps() {
+    let items = [
+        Record {
+            value: Some(1_700_000_000_i64),
+        },
+        Record { value: None },
+    ];
+    let fields = fields("Timestamp(Millisecond, Some(\"UTC\"))");
+    assert_eq!(round_trip(&fields, &items), items);
+
+    let items = [Record {
+        value: 1_700_000_000.25_f64,
+    }];
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "Timestamp(Microsecond, None)", "strategy": "Seconds"},
+    ]))
+    .unwrap();
+    assert_eq!(round_trip(&fields, &items), items);
+}
+
+#[test]
+fn tracing_from_samples() {
+    let items = json!([
+        {"timeout": 30, "created": "1700000000.5"},
+        {"timeout": null, "created": "1700000060"},
+    ]);
+    let options = TracingOptions::default()
+        .allow_null_fields(true)
+        .seconds_at("timeout", SecondsType::Duration)
+        .seconds_at("created", SecondsType::Timestamp);
+    let fields = Vec::<FieldRef>::from_samples(&items, options).unwrap();
+
+    assert_eq!(fields[0].name(), "created");
+    assert_eq!(
+        fields[0].data_type(),
+        &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+    );
+    assert_eq!(fields[1].name(), "timeout");
+    assert_eq!(fields[1].data_type(), &DataType::Duration(TimeUnit::Second));
+    assert!(fields[1].is_nullable());
+    for field in &fields {
+        assert_eq!(
+            field.metadata().get(STRATEGY_KEY).map(String::as_str),
+            Some("Seconds"),
+        );
+    }
+}
+
+#[test]
+fn tracing_from_type() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Nested {
+        timeout: f64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Outer {
+        inner: Nested,
+        created: u64,
+    }
+
+    let options = TracingOptions::default()
+        .seconds_at("inner.timeout", SecondsType::Duration)
+        .seconds_at("created", SecondsType::Timestamp);
+    let fields = Vec::<FieldRef>::from_type::<Outer>(options).unwrap();
+
+    let DataType::Struct(children) = fields[0].data_type() else {
+        panic!("unexpected data type {:?}", fields[0].data_type());
+    };
+    assert_eq!(
+        children[0].data_type(),
+        &DataType::Duration(TimeUnit::Microsecond),
+    );
+    assert_eq!(
+        fields[1].data_type(),
+        &DataType::Timestamp(TimeUnit::Second, Some("UTC".into())),
+    );
+
+    let items = [Outer {
+        inner: Nested { timeout: 0.5 },
+        created: 1_700_000_000,
+    }];
+    assert_eq!(round_trip(&fields, &items), items);
+}
+
+#[test]
+fn tracing_other_values_fails() {
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        value: bool,
+    }
+
+    let options = TracingOptions::default().seconds_at("value", SecondsType::Duration);
+    let res = Vec::<FieldRef>::from_type::<Record>(options);
+    assert_error_contains(&res, "Cannot trace Boolean values as seconds");
+}
+
+#[test]
+fn strategy_is_only_valid_for_temporal_fields() {
+    let res = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "I64", "strategy": "Seconds"},
+    ]));
+    assert_error_contains(&res, "invalid strategy");
+}
+
+#[test]
+fn strategy_repr() {
+    let strategy: Strategy = "Seconds".parse().unwrap();
+    assert_eq!(strategy, Strategy::Seconds);
+    assert_eq!(strategy.to_string(), "Seconds");
+}
@@ -0,0 +1,134 @@
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    _impl::{arrow::datatypes::FieldRef, arrow2::datatypes::Field as Arrow2Field},
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    Deduplication, DeserializerOptions, SerializerOptions, Session, StringInterner,
+};
+
+use super::fixtures::{fields, items, Record};
+
+#[test]
+fn record_batch_roundtrip() {
+    let fields = fields::<Record>();
+    let mut session = Session::from_arrow_fields(&fields).unwrap();
+
+    let batch = session.to_record_batch(items()).unwrap();
+    assert_eq!(batch, crate::to_record_batch(&fields, &items()).unwrap());
+
+    let actual: Vec<Record> = session.from_record_batch(&batch).unwrap();
+    assert_eq!(actual, items());
+
+    let batch = session.to_record_batch(&items()[1..]).unwrap();
+    assert_eq!(batch.num_rows(), 1);
+}
+
+#[test]
+fn arrow2_roundtrip() {
+    let fields = Vec::<Arrow2Field>::from_type::<Record>(TracingOptions::default()).unwrap();
+    let mut session = Session::from_arrow2_fields(&fields).unwrap();
+
+    let arrays = session.to_arrow2(items()).unwrap();
+    assert_eq!(arrays, crate::to_arrow2(&fields, items()).unwrap());
+
+    let actual: Vec<Record> = session.from_arrow2(&arrays).unwrap();
+    assert_eq!(actual, items());
+}
+
+#[test]
+fn options_are_applied() {
+    #[derive(Serialize)]
+    struct Partial {
+        a: u32,
+    }
+
+    let schema = SerdeArrowSchema::from_type::<Record>(TracingOptions::default()).unwrap();
+    let mut session = Session::new(schema)
+        .unwrap()
+        .with_serializer_options(SerializerOptions::default().missing_fields_as_default(true))
+        .with_deserializer_options(DeserializerOptions::default().null_variants_as_none(true));
+
+    assert!(session.serializer_options().missing_fields_as_default);
+    assert!(session.deserializer_options().null_variants_as_none);
+
+    let arrays = session.to_arrow(&[Partial { a: 3 }]).unwrap();
+    let actual: Vec<Record> = session.from_arrow(&arrays).unwrap();
+    assert_eq!(actual, vec![Record { a: 3, b: None }]);
+}
+
+#[test]
+fn failed_serialization_discards_partial_items() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U8"},
+        {"name": "b", "data_type": "LargeUtf8", "nullable": true},
+    ]))
+    .unwrap();
+    let mut session = Session::from_arrow_fields(&fields).unwrap();
+
+    let items = vec![Record { a: 1, b: None }, Record { a: 1_000, b: None }];
+    let res = session.to_arrow(&items);
+    assert_error_contains(&res, "$.a");
+
+    let batch = session.to_record_batch(&items[..1]).unwrap();
+    assert_eq!(batch.num_rows(), 1);
+}
+
+#[test]
+fn failed_serialization_keeps_the_builder_state() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U8"},
+        {"name": "b", "data_type": "LargeUtf8", "nullable": true},
+    ]))
+    .unwrap();
+    let deduplication = Deduplication::ByKey {
+        keys: vec![String::from("$.a")],
+        capacity: 10,
+    };
+    let mut session = Session::from_arrow_fields(&fields)
+        .unwrap()
+        .with_serializer_options(SerializerOptions::default().deduplication(deduplication));
+
+    session.to_arrow(&[Record { a: 1, b: None }]).unwrap();
+
+    let res = session.to_arrow(&[Record { a: 1_000, b: None }]);
+    assert_error_contains(&res, "$.a");
+
+    // the key of the first batch is still remembered
+    let items = vec![Record { a: 1, b: None }, Record { a: 2, b: None }];
+    let batch = session.to_record_batch(&items).unwrap();
+    assert_eq!(batch.num_rows(), 1);
+}
+
+#[test]
+fn interned_dictionaries_are_kept() {
+    let fields = Vec::<FieldRef>::from_type::<Record>(
+        TracingOptions::default().string_dictionary_encoding(true),
+    )
+    .unwrap();
+    let mut session = Session::from_arrow_fields(&fields).unwrap();
+    session.intern_strings(&StringInterner::new()).unwrap();
+
+    session.to_record_batch(items()).unwrap();
+    let items = vec![Record {
+        a: 3,
+        b: Some(String::from("bar")),
+    }];
+    session.to_record_batch(&items).unwrap();
+
+    assert_eq!(
+        session.string_interner().get("$.b"),
+        Some(&[String::from("foo"), String::from("bar")][..]),
+    );
+}
+
+#[test]
+fn mismatched_number_of_arrays() {
+    let fields = fields::<Record>();
+    let mut session = Session::from_arrow_fields(&fields).unwrap();
+
+    let arrays = session.to_arrow(items()).unwrap();
+    let res = session.from_arrow::<Vec<Record>, _>(&arrays[..1]);
+    assert_error_contains(&res, "different number of fields (2) and arrays (1)");
+}
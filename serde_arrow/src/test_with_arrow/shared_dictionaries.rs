@@ -0,0 +1,320 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::{
+    _impl::arrow::{
+        array::{
+            ArrayRef, DictionaryArray, LargeStringArray, PrimitiveArray, RecordBatch, StringArray,
+            StructArray,
+        },
+        datatypes::{Decimal128Type, Field, Int32Type, Schema, UInt32Type},
+    },
+    internal::testing::assert_error_contains,
+    DecimalFormat, Deserializer, DeserializerOptions, DictionaryUpdate, SharedDictionaries,
+};
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Record {
+    a: Option<String>,
+}
+
+/// Build a dictionary array whose keys may index into values of previous
+/// batches, as produced by readers of delta dictionaries
+fn delta_dictionary(keys: Vec<Option<u32>>, values: ArrayRef) -> ArrayRef {
+    let keys = PrimitiveArray::<UInt32Type>::from(keys);
+    // SAFETY: the arrays are only accessed via serde_arrow, which checks the
+    // keys against the accumulated values
+    Arc::new(unsafe { DictionaryArray::<UInt32Type>::new_unchecked(keys, values) })
+}
+
+fn dictionary(keys: Vec<Option<u32>>, values: Vec<&str>) -> ArrayRef {
+    delta_dictionary(keys, Arc::new(StringArray::from(values)))
+}
+
+fn batch(array: ArrayRef) -> RecordBatch {
+    let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+    RecordBatch::try_new(Arc::new(schema), vec![array]).unwrap()
+}
+
+fn deserialize_all(batches: &[(DictionaryUpdate, RecordBatch)]) -> Vec<Option<String>> {
+    let mut dictionaries = SharedDictionaries::new();
+    let mut items = Vec::new();
+    for (update, batch) in batches {
+        dictionaries
+            .update_from_record_batch(batch, *update)
+            .unwrap();
+        let deserializer = Deserializer::from_record_batch(batch)
+            .unwrap()
+            .with_shared_dictionaries(&dictionaries);
+        items.extend(
+            Vec::<Record>::deserialize(deserializer)
+                .unwrap()
+                .into_iter()
+                .map(|item| item.a),
+        );
+    }
+    items
+}
+
+fn strings(values: &[Option<&str>]) -> Vec<Option<String>> {
+    values.iter().map(|value| value.map(String::from)).collect()
+}
+
+#[test]
+fn delta_dictionaries() {
+    let batches = [
+        (
+            DictionaryUpdate::Replacement,
+            batch(dictionary(vec![Some(0), Some(1)], vec!["foo", "bar"])),
+        ),
+        (
+            DictionaryUpdate::Delta,
+            batch(dictionary(vec![Some(2), None, Some(0)], vec!["baz"])),
+        ),
+        (
+            DictionaryUpdate::Delta,
+            batch(dictionary(vec![Some(3), Some(1)], vec!["qux"])),
+        ),
+    ];
+    assert_eq!(
+        deserialize_all(&batches),
+        strings(&[
+            Some("foo"),
+            Some("bar"),
+            Some("baz"),
+            None,
+            Some("foo"),
+            Some("qux"),
+            Some("bar"),
+        ]),
+    );
+}
+
+#[test]
+fn complete_dictionaries_are_not_duplicated() {
+    let batches = [
+        (
+            DictionaryUpdate::Replacement,
+            batch(dictionary(vec![Some(0)], vec!["foo", "bar"])),
+        ),
+        (
+            DictionaryUpdate::Replacement,
+            batch(dictionary(
+                vec![Some(2), Some(1)],
+                vec!["foo", "bar", "baz"],
+            )),
+        ),
+        (
+            DictionaryUpdate::Delta,
+            batch(dictionary(vec![Some(3)], vec!["qux"])),
+        ),
+    ];
+
+    let mut dictionaries = SharedDictionaries::new();
+    for (update, batch) in &batches[..2] {
+        dictionaries
+            .update_from_record_batch(batch, *update)
+            .unwrap();
+    }
+    assert_eq!(dictionaries.len("$.a"), Some(3));
+
+    assert_eq!(
+        deserialize_all(&batches),
+        strings(&[Some("foo"), Some("baz"), Some("bar"), Some("qux")]),
+    );
+}
+
+#[test]
+fn replacements_need_not_start_with_the_accumulated_values() {
+    let batches = [
+        (
+            DictionaryUpdate::Replacement,
+            batch(dictionary(vec![Some(0), Some(1)], vec!["foo", "bar"])),
+        ),
+        (
+            DictionaryUpdate::Replacement,
+            batch(dictionary(vec![Some(0)], vec!["baz"])),
+        ),
+        (
+            DictionaryUpdate::Delta,
+            batch(dictionary(vec![Some(1), Some(0)], vec!["foo"])),
+        ),
+    ];
+    assert_eq!(
+        deserialize_all(&batches),
+        strings(&[
+            Some("foo"),
+            Some("bar"),
+            Some("baz"),
+            Some("foo"),
+            Some("baz"),
+        ]),
+    );
+}
+
+#[test]
+fn deltas_may_repeat_the_accumulated_values() {
+    let batches = [
+        (
+            DictionaryUpdate::Replacement,
+            batch(dictionary(vec![Some(0)], vec!["foo"])),
+        ),
+        (
+            DictionaryUpdate::Delta,
+            batch(dictionary(vec![Some(1), Some(0)], vec!["foo"])),
+        ),
+    ];
+    assert_eq!(
+        deserialize_all(&batches),
+        strings(&[Some("foo"), Some("foo"), Some("foo")]),
+    );
+}
+
+#[test]
+fn options_are_kept_when_attaching_shared_dictionaries() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Value {
+        a: String,
+        value: String,
+    }
+
+    let decimals: ArrayRef = Arc::new(
+        PrimitiveArray::<Decimal128Type>::from(vec![150])
+            .with_precision_and_scale(5, 2)
+            .unwrap(),
+    );
+    let first = batch(dictionary(vec![Some(0)], vec!["foo"]));
+    let schema = Schema::new(vec![
+        first.schema().field(0).clone(),
+        Field::new("value", decimals.data_type().clone(), false),
+    ]);
+    let batch =
+        RecordBatch::try_new(Arc::new(schema), vec![first.column(0).clone(), decimals]).unwrap();
+
+    let mut dictionaries = SharedDictionaries::new();
+    dictionaries
+        .update_from_record_batch(&batch, DictionaryUpdate::Replacement)
+        .unwrap();
+    let deserializer = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .with_options(DeserializerOptions::default().decimal_format(DecimalFormat::Normalized))
+        .with_shared_dictionaries(&dictionaries);
+    let items = Vec::<Value>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        items,
+        [Value {
+            a: String::from("foo"),
+            value: String::from("1.5"),
+        }],
+    );
+}
+
+#[test]
+fn large_utf8_values() {
+    let delta = delta_dictionary(
+        vec![Some(1), Some(0)],
+        Arc::new(LargeStringArray::from(vec!["bar"])),
+    );
+    let batches = [
+        (
+            DictionaryUpdate::Replacement,
+            batch(dictionary(vec![Some(0)], vec!["foo"])),
+        ),
+        (DictionaryUpdate::Delta, batch(delta)),
+    ];
+    assert_eq!(
+        deserialize_all(&batches),
+        strings(&[Some("foo"), Some("bar"), Some("foo")]),
+    );
+}
+
+#[test]
+fn nested_dictionaries() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Outer {
+        a: Inner,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Inner {
+        b: String,
+    }
+
+    let nested = |keys: Vec<Option<u32>>, values: Vec<&str>| -> ArrayRef {
+        let child = dictionary(keys, values);
+        let field = Field::new("b", child.data_type().clone(), false);
+        Arc::new(StructArray::from(vec![(Arc::new(field), child)]))
+    };
+    let batches = [
+        (
+            DictionaryUpdate::Replacement,
+            batch(nested(vec![Some(0)], vec!["foo"])),
+        ),
+        (
+            DictionaryUpdate::Delta,
+            batch(nested(vec![Some(1), Some(0)], vec!["bar"])),
+        ),
+    ];
+
+    let mut dictionaries = SharedDictionaries::new();
+    let mut items = Vec::new();
+    for (update, batch) in &batches {
+        dictionaries
+            .update_from_record_batch(batch, *update)
+            .unwrap();
+        let deserializer = Deserializer::from_record_batch(batch)
+            .unwrap()
+            .with_shared_dictionaries(&dictionaries);
+        items.extend(Vec::<Outer>::deserialize(deserializer).unwrap());
+    }
+
+    assert_eq!(dictionaries.len("$.a.b"), Some(2));
+    let values: Vec<_> = items.iter().map(|item| item.a.b.as_str()).collect();
+    assert_eq!(values, ["foo", "bar", "foo"]);
+}
+
+#[test]
+fn delta_batches_require_shared_dictionaries() {
+    let delta = batch(dictionary(vec![Some(1)], vec!["bar"]));
+    let res = Vec::<Record>::deserialize(Deserializer::from_record_batch(&delta).unwrap());
+    assert!(res.is_err());
+}
+
+#[test]
+fn non_string_values_are_rejected() {
+    let array: ArrayRef = Arc::new(
+        DictionaryArray::<UInt32Type>::try_new(
+            PrimitiveArray::<UInt32Type>::from(vec![0]),
+            Arc::new(PrimitiveArray::<Int32Type>::from(vec![13])),
+        )
+        .unwrap(),
+    );
+    let mut dictionaries = SharedDictionaries::new();
+    let res = dictionaries.update_from_record_batch(&batch(array), DictionaryUpdate::Replacement);
+    assert_error_contains(&res, "Expected string values");
+    assert!(dictionaries.is_empty());
+}
+
+#[test]
+fn unused_dictionaries_leave_batches_unchanged() {
+    let first = batch(dictionary(vec![Some(0), Some(1)], vec!["foo", "bar"]));
+    let dictionaries = SharedDictionaries::new();
+    let items = Vec::<Record>::deserialize(
+        Deserializer::from_record_batch(&first)
+            .unwrap()
+            .with_shared_dictionaries(&dictionaries),
+    )
+    .unwrap();
+    assert_eq!(
+        items,
+        [
+            Record {
+                a: Some(String::from("foo"))
+            },
+            Record {
+                a: Some(String::from("bar"))
+            },
+        ],
+    );
+}
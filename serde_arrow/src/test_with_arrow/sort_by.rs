@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{array::RecordBatch, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    ArrayBuilder, SerializerOptions, SortOrder,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Meta {
+    priority: Option<u8>,
+    label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Payload {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    group: Option<String>,
+    id: u32,
+    meta: Meta,
+    tags: Vec<String>,
+    attributes: BTreeMap<String, u16>,
+    pair: [bool; 2],
+    payload: Payload,
+}
+
+fn record(id: u32, group: Option<&str>, priority: Option<u8>) -> Record {
+    Record {
+        group: group.map(String::from),
+        id,
+        meta: Meta {
+            priority,
+            label: format!("label-{id}"),
+        },
+        tags: (0..id % 3).map(|i| format!("tag-{id}-{i}")).collect(),
+        attributes: (0..id % 2 + 1)
+            .map(|i| (format!("attr-{id}-{i}"), (id * 10 + i) as u16))
+            .collect(),
+        pair: [id.is_multiple_of(2), id.is_multiple_of(3)],
+        payload: if id.is_multiple_of(2) {
+            Payload::Number(-(id as i64))
+        } else {
+            Payload::Text(format!("text-{id}"))
+        },
+    }
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        record(0, Some("b"), Some(2)),
+        record(1, Some("a"), None),
+        record(2, None, Some(1)),
+        record(3, Some("b"), Some(1)),
+        record(4, Some("a"), Some(3)),
+        record(5, Some("c"), Some(2)),
+        record(6, None, None),
+    ]
+}
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_type::<Record>(TracingOptions::default().map_as_struct(false)).unwrap()
+}
+
+fn build(options: SerializerOptions, items: &[Record]) -> crate::Result<RecordBatch> {
+    let mut builder = ArrayBuilder::from_arrow(&fields())?.with_options(options);
+    builder.extend(items)?;
+    builder.to_record_batch()
+}
+
+fn ids(batch: &RecordBatch) -> Vec<u32> {
+    let items: Vec<Record> = crate::from_record_batch(batch).unwrap();
+    items.iter().map(|item| item.id).collect()
+}
+
+#[test]
+fn rows_are_kept_in_order_by_default() {
+    let batch = build(SerializerOptions::default(), &records()).unwrap();
+    assert_eq!(ids(&batch), [0, 1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn sort_ascending_with_nulls_first() {
+    let options = SerializerOptions::default().sort_by("$.group", SortOrder::Ascending);
+    let batch = build(options, &records()).unwrap();
+    assert_eq!(ids(&batch), [2, 6, 1, 4, 0, 3, 5]);
+}
+
+#[test]
+fn sort_descending_with_nulls_first() {
+    let options = SerializerOptions::default().sort_by("$.group", SortOrder::Descending);
+    let batch = build(options, &records()).unwrap();
+    assert_eq!(ids(&batch), [2, 6, 5, 0, 3, 1, 4]);
+}
+
+#[test]
+fn ties_are_broken_by_later_keys() {
+    let options = SerializerOptions::default()
+        .sort_by("$.group", SortOrder::Ascending)
+        .sort_by("$.meta.priority", SortOrder::Descending);
+    let batch = build(options, &records()).unwrap();
+    assert_eq!(ids(&batch), [6, 2, 1, 4, 0, 3, 5]);
+}
+
+#[test]
+fn sort_by_nested_struct_fields() {
+    let options = SerializerOptions::default().sort_by("$.meta", SortOrder::Ascending);
+    let batch = build(options, &records()).unwrap();
+    assert_eq!(ids(&batch), [1, 6, 2, 3, 0, 5, 4]);
+}
+
+#[test]
+fn nested_columns_are_reordered_consistently() {
+    let options = SerializerOptions::default().sort_by("$.id", SortOrder::Descending);
+    let batch = build(options, &records()).unwrap();
+
+    let mut expected = records();
+    expected.reverse();
+
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn each_batch_is_sorted_separately() {
+    let records = records();
+    let options = SerializerOptions::default().sort_by("$.id", SortOrder::Descending);
+    let mut builder = ArrayBuilder::from_arrow(&fields())
+        .unwrap()
+        .with_options(options);
+
+    builder.extend(&records[..3]).unwrap();
+    assert_eq!(ids(&builder.to_record_batch().unwrap()), [2, 1, 0]);
+
+    builder.extend(&records[3..]).unwrap();
+    assert_eq!(ids(&builder.to_record_batch().unwrap()), [6, 5, 4, 3]);
+}
+
+#[test]
+fn unknown_keys_are_an_error() {
+    let options = SerializerOptions::default().sort_by("$.missing", SortOrder::Ascending);
+    let res = build(options, &records());
+    assert_error_contains(&res, "Cannot sort by \"$.missing\": no field with this path");
+}
+
+#[test]
+fn list_keys_are_an_error() {
+    let options = SerializerOptions::default().sort_by("$.tags", SortOrder::Ascending);
+    let res = build(options, &records());
+    assert_error_contains(&res, "List fields are not supported as keys");
+
+    let options = SerializerOptions::default().sort_by("$.payload", SortOrder::Ascending);
+    let res = build(options, &records());
+    assert_error_contains(&res, "Union fields are not supported as keys");
+
+    let options = SerializerOptions::default().sort_by("$.tags.element", SortOrder::Ascending);
+    let res = build(options, &records());
+    assert_error_contains(&res, "no field with this path outside of lists");
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::{
+    _impl::arrow::{
+        _raw::{
+            array::{Int32Array, StringArray},
+            schema::{UnionFields, UnionMode},
+        },
+        array::{Array, UnionArray},
+        datatypes::{DataType, Field, FieldRef},
+    },
+    internal::testing::assert_error_contains,
+    utils::Item,
+};
+
+#[derive(Serialize)]
+enum Value {
+    Int(i32),
+    Str(String),
+}
+
+fn fields(mode: UnionMode) -> Vec<FieldRef> {
+    let union_fields = UnionFields::new(
+        [0, 1],
+        [
+            Field::new("Int", DataType::Int32, false),
+            Field::new("Str", DataType::Utf8, true),
+        ],
+    );
+    vec![Arc::new(Field::new(
+        "item",
+        DataType::Union(union_fields, mode),
+        false,
+    ))]
+}
+
+fn items() -> Vec<Item<Value>> {
+    vec![
+        Item(Value::Int(1)),
+        Item(Value::Str(String::from("a"))),
+        Item(Value::Int(2)),
+        Item(Value::Int(3)),
+    ]
+}
+
+#[test]
+fn sparse_children_are_padded_to_the_union_length() {
+    let arrays = crate::to_arrow(&fields(UnionMode::Sparse), items()).unwrap();
+    let array = arrays[0].as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(array.len(), 4);
+    assert!(array.offsets().is_none());
+    assert_eq!(array.type_ids().to_vec(), vec![0, 1, 0, 0]);
+
+    let ints = array
+        .child(0)
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(ints, &Int32Array::from(vec![1, 0, 2, 3]));
+
+    let strs = array
+        .child(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(strs, &StringArray::from(vec![None, Some("a"), None, None]));
+}
+
+#[test]
+fn dense_children_keep_their_own_lengths() {
+    let arrays = crate::to_arrow(&fields(UnionMode::Dense), items()).unwrap();
+    let array = arrays[0].as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(array.offsets().unwrap().to_vec(), vec![0, 0, 1, 2]);
+    assert_eq!(array.child(0).len(), 3);
+    assert_eq!(array.child(1).len(), 1);
+}
+
+#[test]
+fn sparse_batches_can_be_reused() {
+    let mut builder = crate::ArrayBuilder::from_arrow(&fields(UnionMode::Sparse)).unwrap();
+    for _ in 0..2 {
+        builder.extend(items()).unwrap();
+        let batch = builder.to_record_batch().unwrap();
+        let array = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<UnionArray>()
+            .unwrap();
+        assert_eq!(array.child(0).len(), 4);
+        assert_eq!(array.child(1).len(), 4);
+    }
+}
+
+#[test]
+fn unpaddable_variants_are_reported() {
+    #[derive(Serialize)]
+    enum Outer {
+        Int(i32),
+        Nested(Value),
+    }
+
+    let inner = UnionFields::new(
+        [0, 1],
+        [
+            Field::new("Int", DataType::Int32, false),
+            Field::new("Str", DataType::Utf8, true),
+        ],
+    );
+    let outer = UnionFields::new(
+        [0, 1],
+        [
+            Field::new("Int", DataType::Int32, false),
+            Field::new("Nested", DataType::Union(inner, UnionMode::Sparse), false),
+        ],
+    );
+    let fields = vec![Arc::new(Field::new(
+        "item",
+        DataType::Union(outer, UnionMode::Sparse),
+        false,
+    ))];
+
+    let res = crate::to_arrow(
+        &fields,
+        [Item(Outer::Nested(Value::Int(0))), Item(Outer::Int(1))],
+    );
+    assert_error_contains(
+        &res,
+        "Cannot pad variant \"Nested\" of sparse union in row 1",
+    );
+    assert_error_contains(&res, "field: \"$.item\"");
+}
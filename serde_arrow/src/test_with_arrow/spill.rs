@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    spill::{SpillReader, SpillWriter},
+    ArrayBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Enum {
+    A(i32),
+    B { x: Option<u8> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: i64,
+    b: Option<String>,
+    c: Vec<f32>,
+    d: BTreeMap<String, u16>,
+    e: Enum,
+    #[serde(with = "serde_bytes")]
+    f: Vec<u8>,
+}
+
+fn records(n: usize) -> Vec<Record> {
+    (0..n)
+        .map(|i| Record {
+            a: i as i64 - 3,
+            b: (i % 2 == 0).then(|| format!("s{i}")),
+            c: vec![i as f32; i % 3],
+            d: BTreeMap::from([(format!("k{i}"), i as u16)]),
+            e: if i % 2 == 0 {
+                Enum::A(i as i32)
+            } else {
+                Enum::B { x: Some(i as u8) }
+            },
+            f: vec![i as u8; i % 4],
+        })
+        .collect()
+}
+
+fn spill(items: &[Record]) -> Vec<u8> {
+    let mut writer = SpillWriter::new(Vec::new());
+    writer.extend(items).unwrap();
+    assert_eq!(writer.len(), items.len());
+    writer.finish().unwrap()
+}
+
+#[test]
+fn spilled_records_round_trip() {
+    let items = records(7);
+    let spilled = spill(&items);
+
+    let reader = SpillReader::<_, Record>::new(spilled.as_slice());
+    let actual = reader.collect::<crate::Result<Vec<_>>>().unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn spilled_records_build_the_same_batches() {
+    let items = records(7);
+    let spilled = spill(&items);
+
+    let fields =
+        Vec::<FieldRef>::from_samples(&items, TracingOptions::default().map_as_struct(false))
+            .unwrap();
+
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    let mut reader = SpillReader::<_, Record>::new(spilled.as_slice());
+    let mut batches = Vec::new();
+    loop {
+        let num_rows = reader.read_into(&mut builder, 3).unwrap();
+        if num_rows == 0 {
+            break;
+        }
+        batches.push(builder.to_record_batch().unwrap());
+    }
+    assert_eq!(reader.len(), 7);
+    assert_eq!(
+        batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+        vec![3, 3, 1]
+    );
+
+    for (idx, batch) in batches.iter().enumerate() {
+        let expected =
+            crate::to_record_batch(&fields, &items[3 * idx..(3 * idx + 3).min(7)].to_vec())
+                .unwrap();
+        assert_eq!(batch, &expected);
+    }
+}
+
+#[test]
+fn empty_spill() {
+    let spilled = spill(&[]);
+    assert!(spilled.is_empty());
+
+    let mut reader = SpillReader::<_, Record>::new(spilled.as_slice());
+    assert!(reader.read_next().unwrap().is_none());
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn truncated_spill() {
+    let spilled = spill(&records(2));
+
+    let mut reader = SpillReader::<_, Record>::new(&spilled[..spilled.len() - 1]);
+    assert!(reader.read_next().unwrap().is_some());
+    assert_error_contains(&reader.read_next(), "Cannot read spilled record 1 of");
+
+    let mut reader = SpillReader::<_, Record>::new(&spilled[..2]);
+    assert_error_contains(&reader.read_next(), "Truncated header of spilled record 0");
+}
+
+#[test]
+fn oversized_headers_are_not_trusted() {
+    let mut spilled = u32::MAX.to_le_bytes().to_vec();
+    spilled.extend([1, 2, 3]);
+
+    let mut reader = SpillReader::<_, Record>::new(spilled.as_slice());
+    assert_error_contains(&reader.read_next(), "unexpected end of input after 3 bytes");
+}
+
+#[test]
+fn records_failing_to_push_are_skipped() {
+    let items = records(3);
+    let spilled = spill(&items);
+
+    let fields = Vec::<FieldRef>::from_value(serde_json::json!([
+        {"name": "a", "data_type": "U8"},
+    ]))
+    .unwrap();
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    let mut reader = SpillReader::<_, Record>::new(spilled.as_slice());
+
+    // the first record has a negative value of a
+    assert!(reader.read_into(&mut builder, 3).is_err());
+    assert_eq!(reader.len(), 1);
+}
+
+#[test]
+fn decoding_errors() {
+    let mut writer = SpillWriter::new(Vec::new());
+    writer.push(&("not", "a", "record")).unwrap();
+    let spilled = writer.finish().unwrap();
+
+    let mut reader = SpillReader::<_, Record>::new(spilled.as_slice());
+    assert_error_contains(&reader.read_next(), "Cannot decode spilled record 0");
+}
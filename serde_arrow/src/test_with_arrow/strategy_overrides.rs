@@ -0,0 +1,258 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, Strategy},
+    ArrayBuilder, Deserializer, DeserializerOptions, SerializerOptions,
+};
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {"name": "id", "data_type": "U32"},
+        {"name": "meta", "data_type": "Struct", "children": [
+            {"name": "created", "data_type": "Date64", "strategy": "UtcStrAsDate64"},
+        ]},
+    ]))
+    .unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    meta: Meta,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Meta {
+    created: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct RawRecord {
+    id: u32,
+    meta: RawMeta,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct RawMeta {
+    created: i64,
+}
+
+#[test]
+fn deserialize_date64_as_integers() {
+    let fields = fields();
+    let items = [Record {
+        id: 1,
+        meta: Meta {
+            created: String::from("1970-01-01T00:00:01Z"),
+        },
+    }];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().override_strategy("$.meta.created", None));
+    let actual = Vec::<RawRecord>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        [RawRecord {
+            id: 1,
+            meta: RawMeta { created: 1000 },
+        }]
+    );
+
+    // the fields are not modified
+    assert_eq!(fields, self::fields());
+    let actual: Vec<Record> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn nested_overrides_change_deserialize_any() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "items", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Timestamp(Second, Some(\"UTC\"))"},
+        ]},
+    ]))
+    .unwrap();
+    let arrays = crate::to_arrow(&fields, &[json!({"items": ["1970-01-01T00:00:01Z"]})]).unwrap();
+
+    let actual =
+        serde_json::Value::deserialize(Deserializer::from_arrow(&fields, &arrays).unwrap())
+            .unwrap();
+    assert_eq!(actual, json!([{"items": ["1970-01-01T00:00:01Z"]}]));
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(
+            DeserializerOptions::default()
+                .override_strategy("$.items.element", Some(Strategy::TimeCrateStr)),
+        );
+    let actual = serde_json::Value::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        json!([{"items": ["1970-01-01 00:00:01.0 +00:00:00"]}])
+    );
+}
+
+#[test]
+fn serialize_date64_from_integers() {
+    let fields = fields();
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().override_strategy("$.meta.created", None));
+    builder
+        .push(RawRecord {
+            id: 1,
+            meta: RawMeta { created: 2000 },
+        })
+        .unwrap();
+    let batch = builder.to_record_batch().unwrap();
+
+    // the strategy of the fields of the batch is kept
+    assert_eq!(batch.schema().fields()[..], fields[..]);
+
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        actual,
+        [Record {
+            id: 1,
+            meta: Meta {
+                created: String::from("1970-01-01T00:00:02Z"),
+            },
+        }]
+    );
+}
+
+#[test]
+fn add_strategy() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "created", "data_type": "Date64"},
+    ]))
+    .unwrap();
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Item {
+        created: String,
+    }
+
+    let items = [Item {
+        created: String::from("2024-01-01T00:00:00"),
+    }];
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap().with_options(
+        SerializerOptions::default()
+            .override_strategy("$.created", Some(Strategy::NaiveStrAsDate64)),
+    );
+    builder.extend(&items).unwrap();
+    let arrays = builder.to_arrow().unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(
+            DeserializerOptions::default()
+                .override_strategy("$.created", Some(Strategy::NaiveStrAsDate64)),
+        );
+    let actual = Vec::<Item>::deserialize(deserializer).unwrap();
+    assert_eq!(actual, items);
+}
+
+#[test]
+fn unknown_paths() {
+    let fields = fields();
+    let arrays = crate::to_arrow(&fields, &[] as &[Record]).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().override_strategy("$.meta.missing", None));
+    let res = Vec::<Record>::deserialize(deserializer);
+    assert_error_contains(&res, "Cannot override the strategy of \"$.meta.missing\"");
+
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().override_strategy("$.missing", None));
+    let res = builder.to_arrow();
+    assert_error_contains(&res, "Cannot override the strategy of \"$.missing\"");
+}
+
+#[test]
+fn invalid_strategies() {
+    let fields = fields();
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap().with_options(
+        SerializerOptions::default().override_strategy("$.id", Some(Strategy::UtcStrAsDate64)),
+    );
+    let res = builder.push(RawRecord {
+        id: 1,
+        meta: RawMeta { created: 0 },
+    });
+    assert_error_contains(&res, "Cannot override the strategy of \"$.id\"");
+}
+
+#[test]
+fn overrides_require_empty_builders() {
+    let fields = fields();
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    builder
+        .push(Record {
+            id: 1,
+            meta: Meta {
+                created: String::from("1970-01-01T00:00:00Z"),
+            },
+        })
+        .unwrap();
+
+    let mut builder = builder
+        .with_options(SerializerOptions::default().override_strategy("$.meta.created", None));
+    let res = builder.to_arrow();
+    assert_error_contains(&res, "already contains items");
+}
+
+#[test]
+fn overrides_are_kept_when_registering_custom_deserializers() {
+    use crate::{CustomArrayDeserializer, CustomSource, CustomValue, Result};
+
+    struct Prefixed;
+
+    impl CustomArrayDeserializer for Prefixed {
+        fn next(&mut self, source: &mut CustomSource<'_, '_>) -> Result<CustomValue> {
+            let value = source.read::<u32>()?;
+            Ok(CustomValue::U64(u64::from(value) + 100))
+        }
+    }
+
+    let fields = fields();
+    let items = [Record {
+        id: 1,
+        meta: Meta {
+            created: String::from("1970-01-01T00:00:01Z"),
+        },
+    }];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().override_strategy("$.meta.created", None))
+        .with_custom_deserializer("UInt32", || Box::new(Prefixed));
+    let actual = Vec::<RawRecord>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        [RawRecord {
+            id: 101,
+            meta: RawMeta { created: 1000 },
+        }]
+    );
+}
+
+#[test]
+fn errors_are_kept_when_registering_custom_deserializers() {
+    let fields = fields();
+    let arrays = crate::to_arrow(&fields, &[] as &[Record]).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().override_strategy("$.meta.missing", None))
+        .with_custom_deserializer("Date64", || unreachable!());
+    let res = Vec::<Record>::deserialize(deserializer);
+    assert_error_contains(&res, "Cannot override the strategy of \"$.meta.missing\"");
+}
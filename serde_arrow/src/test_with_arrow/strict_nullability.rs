@@ -0,0 +1,236 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::ArrayRef, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+    ArrayBuilder, Deserializer, DeserializerOptions, SerializerOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    value: u32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record<T> {
+    a: T,
+}
+
+fn fields(field: serde_json::Value) -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([field])).unwrap()
+}
+
+fn arrays<T: Serialize>(fields: &[FieldRef], items: &[Record<T>]) -> Vec<ArrayRef> {
+    crate::to_arrow(fields, items).unwrap()
+}
+
+fn deserialize<'de, T: Deserialize<'de>>(
+    fields: &[FieldRef],
+    arrays: &'de [ArrayRef],
+    strict: bool,
+) -> crate::Result<Vec<T>> {
+    let deserializer = Deserializer::from_arrow(fields, arrays)?
+        .with_options(DeserializerOptions::default().strict_nullability(strict));
+    Vec::<T>::deserialize(deserializer)
+}
+
+#[test]
+fn serializing_nulls_into_non_nullable_fields_reports_the_row() {
+    let fields = fields(json!({"name": "a", "data_type": "U32"}));
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().strict_nullability(true));
+
+    builder.push(Record { a: Some(1_u32) }).unwrap();
+    builder.push(Record { a: Some(2_u32) }).unwrap();
+    let res = builder.push(Record::<Option<u32>> { a: None });
+    assert_error_contains(&res, "Cannot push null for non-nullable array");
+    assert_error_contains(&res, "field: \"$.a\"");
+    assert_error_contains(&res, "row: \"2\"");
+}
+
+#[test]
+fn serializing_nested_nulls_reports_the_row() {
+    let fields = fields(json!({
+        "name": "a",
+        "data_type": "Struct",
+        "nullable": true,
+        "children": [{"name": "value", "data_type": "U32"}],
+    }));
+    #[derive(Serialize)]
+    struct OptionalInner {
+        value: Option<u32>,
+    }
+
+    let mut builder = ArrayBuilder::from_arrow(&fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().strict_nullability(true));
+    builder
+        .push(Record {
+            a: OptionalInner { value: Some(1) },
+        })
+        .unwrap();
+    let res = builder.push(Record {
+        a: OptionalInner { value: None },
+    });
+    assert_error_contains(&res, "field: \"$.a.value\"");
+    assert_error_contains(&res, "row: \"1\"");
+}
+
+#[test]
+fn serialization_errors_without_strict_nullability_have_no_row() {
+    let fields = fields(json!({"name": "a", "data_type": "U32"}));
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    let res = builder.push(Record::<Option<u32>> { a: None });
+    assert_error_contains(&res, "Cannot push null for non-nullable array");
+    assert!(!res.unwrap_err().to_string().contains("row:"));
+}
+
+#[test]
+fn null_primitives_report_the_first_offending_row() {
+    let fields = fields(json!({"name": "a", "data_type": "U32", "nullable": true}));
+    let arrays = arrays(
+        &fields,
+        &[
+            Record { a: Some(1_u32) },
+            Record { a: None },
+            Record { a: None },
+        ],
+    );
+
+    let res = deserialize::<Record<u32>>(&fields, &arrays, true);
+    assert_error_contains(
+        &res,
+        "Cannot deserialize a null value into a non-optional type",
+    );
+    assert_error_contains(&res, "field: \"$.a\"");
+    assert_error_contains(&res, "row: \"1\"");
+}
+
+#[test]
+fn null_strings_report_the_first_offending_row() {
+    let fields = fields(json!({"name": "a", "data_type": "LargeUtf8", "nullable": true}));
+    let arrays = arrays(
+        &fields,
+        &[
+            Record { a: Some("foo") },
+            Record { a: Some("bar") },
+            Record { a: None },
+        ],
+    );
+
+    let res = deserialize::<Record<String>>(&fields, &arrays, true);
+    assert_error_contains(
+        &res,
+        "Cannot deserialize a null value into a non-optional type",
+    );
+    assert_error_contains(&res, "row: \"2\"");
+}
+
+#[test]
+fn null_structs_are_rejected() {
+    let fields = fields(json!({
+        "name": "a",
+        "data_type": "Struct",
+        "nullable": true,
+        "children": [{"name": "value", "data_type": "U32"}],
+    }));
+    let arrays = arrays(
+        &fields,
+        &[
+            Record { a: None },
+            Record {
+                a: Some(Inner { value: 1 }),
+            },
+        ],
+    );
+
+    // without strict nullability, null structs are filled with defaults
+    let res = deserialize::<Record<Inner>>(&fields, &arrays, false).unwrap();
+    assert_eq!(res.len(), 2);
+
+    let res = deserialize::<Record<Inner>>(&fields, &arrays, true);
+    assert_error_contains(
+        &res,
+        "Cannot deserialize a null value into a non-optional type",
+    );
+    assert_error_contains(&res, "field: \"$.a\"");
+    assert_error_contains(&res, "row: \"0\"");
+}
+
+#[test]
+fn null_lists_are_rejected() {
+    let fields = fields(json!({
+        "name": "a",
+        "data_type": "LargeList",
+        "nullable": true,
+        "children": [{"name": "element", "data_type": "U32"}],
+    }));
+    let arrays = arrays(
+        &fields,
+        &[
+            Record {
+                a: Some(vec![1_u32]),
+            },
+            Record { a: Some(vec![]) },
+            Record { a: None },
+        ],
+    );
+
+    let res = deserialize::<Record<Vec<u32>>>(&fields, &arrays, true);
+    assert_error_contains(
+        &res,
+        "Cannot deserialize a null value into a non-optional type",
+    );
+    assert_error_contains(&res, "row: \"2\"");
+}
+
+#[test]
+fn options_accept_nulls() {
+    let fields = fields(json!({
+        "name": "a",
+        "data_type": "Struct",
+        "nullable": true,
+        "children": [{"name": "value", "data_type": "U32", "nullable": true}],
+    }));
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct OptionalInner {
+        value: Option<u32>,
+    }
+
+    let items = [
+        Record {
+            a: Some(OptionalInner { value: Some(1) }),
+        },
+        Record {
+            a: Some(OptionalInner { value: None }),
+        },
+        Record { a: None },
+    ];
+    let arrays = arrays(&fields, &items);
+
+    let res = deserialize::<Record<Option<OptionalInner>>>(&fields, &arrays, true).unwrap();
+    assert_eq!(res, items);
+}
+
+#[test]
+fn columns_without_nulls_are_not_affected() {
+    let fields = fields(json!({"name": "a", "data_type": "U32", "nullable": true}));
+    let items = [Record { a: 1_u32 }, Record { a: 2_u32 }];
+    let arrays = arrays(&fields, &items);
+
+    let res = deserialize::<Record<u32>>(&fields, &arrays, true).unwrap();
+    assert_eq!(res, items);
+}
+
+#[test]
+fn other_errors_are_annotated_with_the_row() {
+    let fields = fields(json!({"name": "a", "data_type": "I64"}));
+    let arrays = arrays(&fields, &[Record { a: 1_i64 }, Record { a: -1_i64 }]);
+
+    let res = deserialize::<Record<u32>>(&fields, &arrays, true);
+    assert_error_contains(&res, "row: \"1\"");
+}
@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    ArrayBuilder, SerializerOptions,
+};
+
+#[derive(Debug, Serialize)]
+struct Inner {
+    x: u32,
+}
+
+#[derive(Debug, Serialize)]
+struct Record {
+    a: u32,
+    b: Vec<u32>,
+    c: Inner,
+    d: BTreeMap<String, u32>,
+    e: Option<String>,
+}
+
+fn record() -> Record {
+    Record {
+        a: 1,
+        b: vec![2, 3],
+        c: Inner { x: 4 },
+        d: BTreeMap::from([(String::from("k"), 5)]),
+        e: None,
+    }
+}
+
+fn strict_builder(fields: &[FieldRef]) -> ArrayBuilder {
+    ArrayBuilder::from_arrow(fields)
+        .unwrap()
+        .with_options(SerializerOptions::default().strict_schema(true))
+}
+
+#[test]
+fn matching_items_are_accepted() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U64"},
+        {"name": "b", "data_type": "LargeList", "children": [{"name": "element", "data_type": "U32"}]},
+        {"name": "c", "data_type": "Struct", "children": [{"name": "x", "data_type": "U32"}]},
+        {
+            "name": "d",
+            "data_type": "Map",
+            "children": [{
+                "name": "entries",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "key", "data_type": "Utf8"},
+                    {"name": "value", "data_type": "U32"},
+                ],
+            }],
+        },
+        {"name": "e", "data_type": "Date32", "nullable": true},
+    ]))
+    .unwrap();
+
+    let mut builder = strict_builder(&fields);
+    builder.extend([record(), record()]).unwrap();
+    let batch = builder.to_record_batch().unwrap();
+    assert_eq!(batch.num_rows(), 2);
+}
+
+#[test]
+fn traced_fields_are_accepted() {
+    let fields = Vec::<FieldRef>::from_samples(
+        &[record()],
+        TracingOptions::default()
+            .allow_null_fields(true)
+            .map_as_struct(false),
+    )
+    .unwrap();
+
+    let mut builder = strict_builder(&fields);
+    builder.push(record()).unwrap();
+}
+
+#[test]
+fn all_mismatches_are_reported() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "Struct", "children": [{"name": "x", "data_type": "U32"}]},
+        {"name": "b", "data_type": "U32"},
+        {"name": "c", "data_type": "Struct", "children": [{"name": "y", "data_type": "U32"}]},
+        {"name": "f", "data_type": "U32"},
+    ]))
+    .unwrap();
+
+    let mut builder = strict_builder(&fields);
+    let res = builder.push(record());
+    assert_error_contains(&res, "Strict schema check failed");
+    assert_error_contains(&res, "field $.a expected struct, found primitive");
+    assert_error_contains(&res, "field $.b expected primitive, found list");
+    assert_error_contains(&res, "missing non-nullable field $.c.y");
+    assert_error_contains(&res, "missing non-nullable field $.f");
+
+    // the builder is not modified by the rejected item
+    assert_eq!(builder.num_rows(), 0);
+}
+
+#[test]
+fn nested_list_elements_are_checked() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "item", "data_type": "LargeList", "children": [{"name": "element", "data_type": "Struct", "children": [{"name": "x", "data_type": "U32"}]}]},
+    ]))
+    .unwrap();
+
+    #[derive(Serialize)]
+    struct Item {
+        item: Vec<u32>,
+    }
+
+    let mut builder = strict_builder(&fields);
+    let res = builder.push(Item { item: vec![1] });
+    assert_error_contains(
+        &res,
+        "field $.item.element expected struct, found primitive",
+    );
+}
+
+#[test]
+fn only_the_first_item_of_each_batch_is_checked() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U32"},
+        {"name": "b", "data_type": "U32", "nullable": true},
+    ]))
+    .unwrap();
+
+    #[derive(Serialize)]
+    struct First {
+        a: u32,
+    }
+
+    #[derive(Serialize)]
+    struct Second {
+        a: u32,
+        b: Vec<u32>,
+    }
+
+    let mut builder = strict_builder(&fields);
+    builder.push(First { a: 0 }).unwrap();
+    let res = builder.push(Second { a: 1, b: vec![2] });
+    assert!(res.is_err());
+    assert!(!res
+        .unwrap_err()
+        .to_string()
+        .contains("Strict schema check failed"));
+    builder.to_arrow().unwrap();
+
+    let res = builder.push(Second { a: 1, b: vec![2] });
+    assert_error_contains(&res, "field $.b expected primitive, found list");
+}
+
+#[test]
+fn additional_fields_of_items_are_accepted() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U32"},
+        {"name": "c", "data_type": "Struct", "children": [{"name": "y", "data_type": "U32", "nullable": true}]},
+    ]))
+    .unwrap();
+
+    let mut builder = strict_builder(&fields);
+    builder.push(record()).unwrap();
+    assert_eq!(builder.num_rows(), 1);
+}
+
+#[test]
+fn non_strict_builders_do_not_check() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "a", "data_type": "U32"},
+    ]))
+    .unwrap();
+
+    #[derive(Serialize)]
+    struct Extra {
+        a: u32,
+        b: u32,
+    }
+
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    builder.push(Extra { a: 0, b: 1 }).unwrap();
+}
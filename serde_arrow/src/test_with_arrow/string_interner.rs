@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, ArrayRef, DictionaryArray, GenericListArray, LargeStringArray},
+        datatypes::{FieldRef, UInt32Type},
+    },
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    ArrayBuilder, StringInterner,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    a: String,
+    b: Vec<String>,
+}
+
+impl Record {
+    fn new(a: &str, b: &[&str]) -> Self {
+        Self {
+            a: a.to_string(),
+            b: b.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+fn fields() -> Vec<FieldRef> {
+    let options = TracingOptions::default().string_dictionary_encoding(true);
+    Vec::<FieldRef>::from_type::<Record>(options).unwrap()
+}
+
+fn keys_and_values(array: &dyn Array) -> (Vec<u32>, Vec<String>) {
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<UInt32Type>>()
+        .unwrap();
+    let keys = array.keys().values().to_vec();
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<LargeStringArray>()
+        .unwrap()
+        .iter()
+        .map(|v| v.unwrap().to_string())
+        .collect();
+    (keys, values)
+}
+
+fn list_values(array: &ArrayRef) -> ArrayRef {
+    array
+        .as_any()
+        .downcast_ref::<GenericListArray<i64>>()
+        .unwrap()
+        .values()
+        .clone()
+}
+
+#[test]
+fn stable_dictionaries_across_builders() {
+    let fields = fields();
+    let mut interner = StringInterner::new();
+
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    builder.intern_strings(&interner).unwrap();
+    builder.push(Record::new("foo", &["x"])).unwrap();
+    builder.push(Record::new("bar", &["y", "x"])).unwrap();
+    let arrays = builder.to_arrow().unwrap();
+    interner = builder.string_interner();
+
+    assert_eq!(
+        keys_and_values(&arrays[0]),
+        (vec![0, 1], vec![String::from("foo"), String::from("bar")])
+    );
+
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    builder.intern_strings(&interner).unwrap();
+    builder.push(Record::new("baz", &["z"])).unwrap();
+    builder.push(Record::new("foo", &["x"])).unwrap();
+    let arrays = builder.to_arrow().unwrap();
+
+    assert_eq!(
+        keys_and_values(&arrays[0]),
+        (
+            vec![2, 0],
+            vec![
+                String::from("foo"),
+                String::from("bar"),
+                String::from("baz")
+            ]
+        )
+    );
+    assert_eq!(
+        keys_and_values(&list_values(&arrays[1])),
+        (
+            vec![2, 0],
+            vec![String::from("x"), String::from("y"), String::from("z")]
+        )
+    );
+}
+
+#[test]
+fn stable_dictionaries_with_the_same_builder() {
+    let fields = fields();
+
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    builder.intern_strings(&StringInterner::new()).unwrap();
+
+    builder.push(Record::new("foo", &[])).unwrap();
+    let first = builder.to_arrow().unwrap();
+
+    builder.push(Record::new("bar", &[])).unwrap();
+    builder.push(Record::new("foo", &[])).unwrap();
+    let second = builder.to_arrow().unwrap();
+
+    assert_eq!(
+        keys_and_values(&first[0]),
+        (vec![0], vec![String::from("foo")])
+    );
+    assert_eq!(
+        keys_and_values(&second[0]),
+        (vec![1, 0], vec![String::from("foo"), String::from("bar")])
+    );
+
+    let items: Vec<Record> = crate::from_arrow(&fields, &second).unwrap();
+    assert_eq!(
+        items,
+        vec![Record::new("bar", &[]), Record::new("foo", &[])]
+    );
+}
+
+#[test]
+fn dictionaries_are_not_kept_without_interning() {
+    let fields = fields();
+
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    builder.push(Record::new("foo", &[])).unwrap();
+    builder.to_arrow().unwrap();
+    assert_eq!(builder.string_interner().get("$.a"), Some(&[][..]));
+
+    builder.push(Record::new("bar", &[])).unwrap();
+    let arrays = builder.to_arrow().unwrap();
+    assert_eq!(
+        keys_and_values(&arrays[0]),
+        (vec![0], vec![String::from("bar")])
+    );
+}
+
+#[test]
+fn incompatible_interner() {
+    let fields = fields();
+
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+    builder.intern_strings(&StringInterner::new()).unwrap();
+    builder.push(Record::new("foo", &[])).unwrap();
+
+    let mut other = ArrayBuilder::from_arrow(&fields).unwrap();
+    other.push(Record::new("bar", &[])).unwrap();
+
+    let res = builder.intern_strings(&other.string_interner());
+    assert_error_contains(&res, "Interned values are not compatible");
+    assert_error_contains(&res, "$.a");
+}
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::{testing::assert_error_contains, utils::xxhash::xxh64},
+    schema::SchemaLike,
+    ArrayBuilder, OversizedStringPolicy, SerializerOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    name: String,
+    tags: Vec<String>,
+    category: String,
+}
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {"name": "name", "data_type": "LargeUtf8"},
+        {"name": "tags", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Utf8"},
+        ]},
+        {"name": "category", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "LargeUtf8"},
+        ]},
+    ]))
+    .unwrap()
+}
+
+fn record(name: &str, tags: &[&str], category: &str) -> Record {
+    Record {
+        name: name.to_owned(),
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        category: category.to_owned(),
+    }
+}
+
+fn build(options: SerializerOptions, items: &[Record]) -> crate::Result<Vec<Record>> {
+    let fields = fields();
+    let mut builder = ArrayBuilder::from_arrow(&fields)?.with_options(options);
+    builder.extend(items)?;
+    let batch = builder.to_record_batch()?;
+    crate::from_record_batch(&batch)
+}
+
+#[test]
+fn values_within_the_limit_are_unchanged() {
+    let items = [record("short", &["a", "b"], "x")];
+    let options = SerializerOptions::default()
+        .max_string_length("$.name", 5, OversizedStringPolicy::Error)
+        .max_string_length("$.tags.element", 1, OversizedStringPolicy::Error)
+        .max_string_length("$.category", 1, OversizedStringPolicy::Error);
+    assert_eq!(build(options, &items).unwrap(), items);
+}
+
+#[test]
+fn oversized_values_error() {
+    let options = SerializerOptions::default().max_string_length(
+        "$.tags.element",
+        3,
+        OversizedStringPolicy::Error,
+    );
+    let res = build(options, &[record("a", &["abc", "abcd"], "x")]);
+    assert_error_contains(
+        &res,
+        "String of 4 bytes exceeds the maximum length of 3 bytes",
+    );
+    assert_error_contains(&res, "$.tags.element");
+}
+
+#[test]
+fn oversized_values_are_truncated() {
+    let options = SerializerOptions::default()
+        .max_string_length(
+            "$.name",
+            6,
+            OversizedStringPolicy::Truncate {
+                marker: String::from("..."),
+            },
+        )
+        .max_string_length(
+            "$.category",
+            4,
+            OversizedStringPolicy::Truncate {
+                marker: String::from("~"),
+            },
+        );
+    let items = [
+        record("abcdefgh", &[], "long value"),
+        // truncation respects character boundaries: "ä" uses two bytes
+        record("abä", &[], "long other"),
+        record("abäää", &[], "xyz"),
+    ];
+    assert_eq!(
+        build(options, &items).unwrap(),
+        [
+            record("abc...", &[], "lon~"),
+            record("abä", &[], "lon~"),
+            record("ab...", &[], "xyz"),
+        ]
+    );
+}
+
+#[test]
+fn oversized_values_are_hashed() {
+    let options =
+        SerializerOptions::default().max_string_length("$.name", 16, OversizedStringPolicy::Hash);
+    let long = "a value that does not fit into 16 bytes";
+    let items = [record(long, &[], "x"), record("short", &[], "x")];
+    assert_eq!(
+        build(options, &items).unwrap(),
+        [
+            record(&format!("{:016x}", xxh64(long.as_bytes(), 0)), &[], "x"),
+            record("short", &[], "x"),
+        ]
+    );
+}
+
+#[test]
+fn unknown_paths() {
+    let options = SerializerOptions::default().max_string_length(
+        "$.missing",
+        3,
+        OversizedStringPolicy::Error,
+    );
+    let res = build(options, &[]);
+    assert_error_contains(&res, "Cannot limit the string length of \"$.missing\"");
+
+    let options =
+        SerializerOptions::default().max_string_length("$.tags", 3, OversizedStringPolicy::Error);
+    let res = build(options, &[]);
+    assert_error_contains(&res, "no string field with this path");
+}
+
+#[test]
+fn limits_shorter_than_the_policy_requires() {
+    let options = SerializerOptions::default().max_string_length(
+        "$.name",
+        2,
+        OversizedStringPolicy::Truncate {
+            marker: String::from("..."),
+        },
+    );
+    let res = build(options, &[]);
+    assert_error_contains(&res, "the maximum length of 2 is shorter than the 3 bytes");
+
+    let options =
+        SerializerOptions::default().max_string_length("$.name", 8, OversizedStringPolicy::Hash);
+    let res = build(options, &[]);
+    assert_error_contains(&res, "the maximum length of 8 is shorter than the 16 bytes");
+}
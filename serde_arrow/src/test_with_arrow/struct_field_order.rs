@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef, internal::testing::assert_error_contains,
+    schema::SchemaLike, Deserializer, DeserializerOptions,
+};
+
+fn reordered_fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {"name": "c", "data_type": "Struct", "nullable": true, "children": [
+            {"name": "y", "data_type": "Utf8"},
+            {"name": "x", "data_type": "I32"},
+        ]},
+        {"name": "b", "data_type": "Utf8"},
+        {"name": "a", "data_type": "U8"},
+    ]))
+    .unwrap()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Outer {
+    a: u8,
+    b: String,
+    c: Option<Inner>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    x: i32,
+    y: String,
+}
+
+fn items() -> Vec<Outer> {
+    vec![
+        Outer {
+            a: 1,
+            b: String::from("foo"),
+            c: Some(Inner {
+                x: 13,
+                y: String::from("bar"),
+            }),
+        },
+        Outer {
+            a: 2,
+            b: String::from("baz"),
+            c: None,
+        },
+    ]
+}
+
+#[test]
+fn reordered_children_are_matched_by_name() {
+    let fields = reordered_fields();
+    let arrays = crate::to_arrow(&fields, items()).unwrap();
+
+    let actual: Vec<Outer> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items());
+}
+
+#[test]
+fn children_matched_by_position() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Renamed {
+        first: Option<RenamedInner>,
+        second: String,
+        third: u8,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct RenamedInner {
+        label: String,
+        value: i32,
+    }
+
+    let fields = reordered_fields();
+    let arrays = crate::to_arrow(&fields, items()).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().struct_fields_by_position(true));
+    let actual = Vec::<Renamed>::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        [
+            Renamed {
+                first: Some(RenamedInner {
+                    label: String::from("bar"),
+                    value: 13,
+                }),
+                second: String::from("foo"),
+                third: 1,
+            },
+            Renamed {
+                first: None,
+                second: String::from("baz"),
+                third: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn by_position_does_not_affect_deserialize_any() {
+    let fields = reordered_fields();
+    let arrays = crate::to_arrow(&fields, &items()[..1]).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().struct_fields_by_position(true));
+    let actual = serde_json::Value::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        json!([{"c": {"y": "bar", "x": 13}, "b": "foo", "a": 1}]),
+    );
+}
+
+#[test]
+fn by_position_requires_matching_number_of_fields() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Short {
+        first: Option<Inner>,
+        second: String,
+    }
+
+    let fields = reordered_fields();
+    let arrays = crate::to_arrow(&fields, items()).unwrap();
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().struct_fields_by_position(true));
+    let res = Vec::<Short>::deserialize(deserializer);
+    assert_error_contains(&res, "the struct has 2 fields, the array 3 children");
+}
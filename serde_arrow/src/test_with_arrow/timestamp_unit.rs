@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::ArrayRef, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TimeUnit},
+    Deserializer, DeserializerOptions,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    ts: Option<i64>,
+    date: i64,
+}
+
+fn arrays(unit: &str, items: &[Record]) -> (Vec<FieldRef>, Vec<ArrayRef>) {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "ts", "data_type": format!("Timestamp({unit}, None)"), "nullable": true},
+        {"name": "date", "data_type": "Date64"},
+    ]))
+    .unwrap();
+    let arrays = crate::to_arrow(&fields, items).unwrap();
+    (fields, arrays)
+}
+
+fn deserialize(
+    fields: &[FieldRef],
+    arrays: &[ArrayRef],
+    unit: Option<TimeUnit>,
+) -> crate::Result<Vec<Record>> {
+    let deserializer = Deserializer::from_arrow(fields, arrays)?
+        .with_options(DeserializerOptions::default().timestamp_unit(unit));
+    Vec::<Record>::deserialize(deserializer)
+}
+
+#[test]
+fn stored_values_by_default() {
+    let items = [
+        Record {
+            ts: Some(1_500_000_000),
+            date: 1_500,
+        },
+        Record { ts: None, date: -1 },
+    ];
+    let (fields, arrays) = arrays("Nanosecond", &items);
+    assert_eq!(deserialize(&fields, &arrays, None).unwrap(), items);
+}
+
+#[test]
+fn nanoseconds_as_milliseconds() {
+    let (fields, arrays) = arrays(
+        "Nanosecond",
+        &[
+            Record {
+                ts: Some(1_500_000_000),
+                date: 1_500,
+            },
+            Record {
+                ts: Some(-1),
+                date: 0,
+            },
+            Record { ts: None, date: 0 },
+        ],
+    );
+
+    assert_eq!(
+        deserialize(&fields, &arrays, Some(TimeUnit::Millisecond)).unwrap(),
+        [
+            Record {
+                ts: Some(1_500),
+                date: 1_500,
+            },
+            Record {
+                ts: Some(-1),
+                date: 0,
+            },
+            Record { ts: None, date: 0 },
+        ]
+    );
+}
+
+#[test]
+fn seconds_as_microseconds() {
+    let (fields, arrays) = arrays(
+        "Second",
+        &[Record {
+            ts: Some(3),
+            date: 2_000,
+        }],
+    );
+
+    assert_eq!(
+        deserialize(&fields, &arrays, Some(TimeUnit::Microsecond)).unwrap(),
+        [Record {
+            ts: Some(3_000_000),
+            date: 2_000_000,
+        }]
+    );
+}
+
+#[test]
+fn overflow_is_an_error() {
+    let (fields, arrays) = arrays(
+        "Second",
+        &[Record {
+            ts: Some(i64::MAX / 10),
+            date: 0,
+        }],
+    );
+
+    let res = deserialize(&fields, &arrays, Some(TimeUnit::Nanosecond));
+    assert_error_contains(&res, "cannot be represented with unit Nanosecond");
+}
+
+#[test]
+fn strings_are_not_affected() {
+    let (fields, arrays) = arrays(
+        "Nanosecond",
+        &[Record {
+            ts: Some(1_500_000_000),
+            date: 0,
+        }],
+    );
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays)
+        .unwrap()
+        .with_options(DeserializerOptions::default().timestamp_unit(Some(TimeUnit::Second)));
+    let actual = serde_json::Value::deserialize(deserializer).unwrap();
+    assert_eq!(
+        actual,
+        json!([{"ts": "1970-01-01T00:00:01.500", "date": "1970-01-01T00:00:00Z"}])
+    );
+}
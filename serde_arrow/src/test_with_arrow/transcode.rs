@@ -0,0 +1,257 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{
+    de::{EnumAccess, Error as _, MapAccess, SeqAccess, VariantAccess, Visitor},
+    Deserialize, Serialize,
+};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{array::RecordBatch, datatypes::FieldRef},
+    schema::{SchemaLike, TracingOptions},
+    utils::Item,
+    Deserializer,
+};
+
+fn transcode_to_json(batch: &RecordBatch) -> crate::Result<String> {
+    let deserializer = Deserializer::from_record_batch(batch)?;
+    let mut buffer = Vec::new();
+    let mut serializer = serde_json::Serializer::new(&mut buffer);
+    serde_transcode::transcode(deserializer, &mut serializer)
+        .map_err(|err| crate::Error::custom(format!("Cannot transcode: {err}")))?;
+    Ok(String::from_utf8(buffer).unwrap())
+}
+
+/// Deserialize any value and check the size hints of all sequences and maps
+struct CheckedSizeHints;
+
+impl<'de> Deserialize<'de> for CheckedSizeHints {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(CheckedSizeHints)
+    }
+}
+
+impl<'de> Visitor<'de> for CheckedSizeHints {
+    type Value = CheckedSizeHints;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "any value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_bytes<E>(self, _: &[u8]) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_none<E>(self) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self, E> {
+        Ok(self)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Self, D::Error> {
+        CheckedSizeHints::deserialize(deserializer)
+    }
+
+    fn visit_newtype_struct<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        CheckedSizeHints::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self, A::Error> {
+        let hint = seq.size_hint();
+        let mut len = 0;
+        while seq.next_element::<CheckedSizeHints>()?.is_some() {
+            len += 1;
+        }
+        if hint != Some(len) {
+            return Err(A::Error::custom(format!(
+                "size hint {hint:?} of sequence with {len} elements"
+            )));
+        }
+        Ok(self)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self, A::Error> {
+        let hint = map.size_hint();
+        let mut len = 0;
+        while map
+            .next_entry::<CheckedSizeHints, CheckedSizeHints>()?
+            .is_some()
+        {
+            len += 1;
+        }
+        if hint != Some(len) {
+            return Err(A::Error::custom(format!(
+                "size hint {hint:?} of map with {len} entries"
+            )));
+        }
+        Ok(self)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self, A::Error> {
+        let (_, variant) = data.variant::<CheckedSizeHints>()?;
+        variant.newtype_variant::<CheckedSizeHints>()
+    }
+}
+
+#[test]
+fn transcode_nested_values_to_json() {
+    #[derive(Serialize)]
+    struct Record {
+        a: i32,
+        b: Option<String>,
+        c: Vec<u16>,
+        d: BTreeMap<String, bool>,
+        e: Item<Option<f64>>,
+    }
+
+    let items = vec![
+        Record {
+            a: 1,
+            b: Some(String::from("x")),
+            c: vec![1, 2],
+            d: BTreeMap::from([(String::from("k"), true)]),
+            e: Item(Some(0.5)),
+        },
+        Record {
+            a: -2,
+            b: None,
+            c: vec![],
+            d: BTreeMap::new(),
+            e: Item(None),
+        },
+    ];
+    let fields =
+        Vec::<FieldRef>::from_samples(&items, TracingOptions::default().map_as_struct(false))
+            .unwrap();
+    let batch = crate::to_record_batch(&fields, &items).unwrap();
+
+    assert_eq!(
+        transcode_to_json(&batch).unwrap(),
+        concat!(
+            r#"[{"a":1,"b":"x","c":[1,2],"d":{"k":true},"e":{"item":0.5}},"#,
+            r#"{"a":-2,"b":null,"c":[],"d":{},"e":{"item":null}}]"#,
+        ),
+    );
+}
+
+#[test]
+fn transcode_logical_types_to_json() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "date", "data_type": "Date32"},
+        {"name": "ts", "data_type": "Timestamp(Millisecond, Some(\"UTC\"))"},
+        {"name": "dec", "data_type": "Decimal128(5, 2)"},
+        {"name": "dict", "data_type": "Dictionary", "children": [
+            {"name": "key", "data_type": "U32"},
+            {"name": "value", "data_type": "Utf8"},
+        ]},
+    ]))
+    .unwrap();
+
+    #[derive(Serialize)]
+    struct Record {
+        date: &'static str,
+        ts: &'static str,
+        dec: &'static str,
+        dict: &'static str,
+    }
+
+    let items = [Record {
+        date: "2024-01-02",
+        ts: "2024-01-02T03:04:05Z",
+        dec: "1.25",
+        dict: "a",
+    }];
+    let batch = crate::to_record_batch(&fields, &items).unwrap();
+
+    assert_eq!(
+        transcode_to_json(&batch).unwrap(),
+        r#"[{"date":"2024-01-02","ts":"2024-01-02T03:04:05Z","dec":"1.25","dict":"a"}]"#,
+    );
+}
+
+#[test]
+fn transcode_enums_to_json() {
+    #[derive(Serialize)]
+    enum Enum {
+        A(i32),
+        B { x: u8 },
+    }
+
+    #[derive(Serialize)]
+    struct Record {
+        e: Enum,
+    }
+
+    let items = [
+        Record { e: Enum::A(3) },
+        Record {
+            e: Enum::B { x: 4 },
+        },
+    ];
+    let fields = Vec::<FieldRef>::from_samples(&items, TracingOptions::default()).unwrap();
+    let batch = crate::to_record_batch(&fields, &items).unwrap();
+
+    assert_eq!(
+        transcode_to_json(&batch).unwrap(),
+        r#"[{"e":{"A":3}},{"e":{"B":{"x":4}}}]"#,
+    );
+}
+
+#[test]
+fn size_hints_match_the_number_of_elements() {
+    #[derive(Serialize)]
+    struct Record {
+        a: Vec<Vec<u8>>,
+        b: BTreeMap<i32, Option<String>>,
+        #[serde(with = "serde_bytes")]
+        c: Vec<u8>,
+        d: Option<Vec<bool>>,
+    }
+
+    let items = vec![
+        Record {
+            a: vec![vec![1, 2, 3], vec![]],
+            b: BTreeMap::from([(1, None), (2, Some(String::from("x")))]),
+            c: vec![4, 5],
+            d: None,
+        },
+        Record {
+            a: vec![],
+            b: BTreeMap::new(),
+            c: vec![],
+            d: Some(vec![true]),
+        },
+    ];
+    let fields =
+        Vec::<FieldRef>::from_samples(&items, TracingOptions::default().map_as_struct(false))
+            .unwrap();
+    let batch = crate::to_record_batch(&fields, &items).unwrap();
+
+    let deserializer = Deserializer::from_record_batch(&batch).unwrap();
+    CheckedSizeHints::deserialize(deserializer).unwrap();
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        _raw::schema::UnionMode,
+        array::{Array, ArrayRef, UnionArray},
+        datatypes::{DataType, Field, FieldRef},
+    },
+    schema::SchemaLike,
+    utils::Item,
+    ArrayBuilder,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Value {
+    Int(i32),
+    Str(String),
+}
+
+fn fields(mode: UnionMode) -> Vec<FieldRef> {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {
+            "name": "item",
+            "data_type": "Union",
+            "nullable": true,
+            "children": [
+                {"name": "Int", "data_type": "I32"},
+                {"name": "Str", "data_type": "Utf8", "nullable": true},
+                {"name": "", "data_type": "Null", "nullable": true, "strategy": "NullVariant"},
+            ],
+        },
+    ]))
+    .unwrap();
+
+    let DataType::Union(union_fields, _) = fields[0].data_type() else {
+        unreachable!();
+    };
+    vec![Arc::new(Field::new(
+        "item",
+        DataType::Union(union_fields.clone(), mode),
+        true,
+    ))]
+}
+
+fn items() -> Vec<Item<Option<Value>>> {
+    vec![
+        Item(Some(Value::Int(1))),
+        Item(None),
+        Item(None),
+        Item(None),
+        Item(Some(Value::Str(String::from("a")))),
+        Item(None),
+        Item(None),
+        Item(Some(Value::Int(2))),
+    ]
+}
+
+/// Build the items, appending consecutive nulls in bulk
+fn build(builder: &mut ArrayBuilder) -> UnionArray {
+    builder.push(Item(Some(Value::Int(1)))).unwrap();
+    builder.append_nulls(3).unwrap();
+    builder
+        .push(Item(Some(Value::Str(String::from("a")))))
+        .unwrap();
+    builder.append_nulls(2).unwrap();
+    builder.push(Item(Some(Value::Int(2)))).unwrap();
+
+    let batch = builder.to_record_batch().unwrap();
+    batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<UnionArray>()
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn dense_nulls_are_appended_in_bulk() {
+    let fields = fields(UnionMode::Dense);
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+
+    let array = build(&mut builder);
+    assert_eq!(array.type_ids().to_vec(), vec![0, 2, 2, 2, 1, 2, 2, 0]);
+    assert_eq!(
+        array.offsets().unwrap().to_vec(),
+        vec![0, 0, 1, 2, 0, 3, 4, 1]
+    );
+    assert_eq!(array.child(0).len(), 2);
+    assert_eq!(array.child(1).len(), 1);
+    assert_eq!(array.child(2).len(), 5);
+
+    let arrays: Vec<ArrayRef> = vec![Arc::new(array)];
+    let actual: Vec<Item<Option<Value>>> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(actual, items());
+}
+
+#[test]
+fn sparse_nulls_pad_all_children_in_bulk() {
+    let fields = fields(UnionMode::Sparse);
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+
+    let array = build(&mut builder);
+    assert_eq!(array.type_ids().to_vec(), vec![0, 2, 2, 2, 1, 2, 2, 0]);
+    assert!(array.offsets().is_none());
+    for child in 0..3 {
+        assert_eq!(array.child(child).len(), 8);
+    }
+}
+
+#[test]
+fn offsets_restart_for_reused_builders() {
+    let fields = fields(UnionMode::Dense);
+    let mut builder = ArrayBuilder::from_arrow(&fields).unwrap();
+
+    let first = build(&mut builder);
+    let second = build(&mut builder);
+    assert_eq!(first.offsets().unwrap(), second.offsets().unwrap());
+    assert_eq!(first.type_ids(), second.type_ids());
+}
+
+#[test]
+fn untagged_values_switch_between_children() {
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "value", "data_type": "Union", "strategy": "UntaggedUnion", "children": [
+            {"name": "Int64", "data_type": "I64"},
+            {"name": "LargeUtf8", "data_type": "LargeUtf8"},
+            {"name": "Float64", "data_type": "F64"},
+        ]},
+    ]))
+    .unwrap();
+
+    let items = json!([
+        {"value": 1},
+        {"value": 2},
+        {"value": "a"},
+        {"value": 3},
+        {"value": 0.5},
+        {"value": "b"},
+        {"value": "c"},
+    ]);
+    let batch = crate::to_record_batch(&fields, &items).unwrap();
+    let array = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<UnionArray>()
+        .unwrap();
+    assert_eq!(array.type_ids().to_vec(), vec![0, 0, 1, 0, 2, 1, 1]);
+    assert_eq!(array.offsets().unwrap().to_vec(), vec![0, 1, 0, 2, 0, 1, 2]);
+}
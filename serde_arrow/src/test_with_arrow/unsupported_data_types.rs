@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{
+        _raw::{
+            array::{StructArray, TimestampNanosecondArray},
+            schema::{UnionFields, UnionMode},
+        },
+        array::{ArrayRef, RecordBatch},
+        datatypes::{DataType, Field, FieldRef, Schema, TimeUnit},
+    },
+    schema::{SchemaLike, SerdeArrowSchema},
+    utils::Item,
+    Deserializer, ErrorKind,
+};
+
+fn unsupported(err: &crate::Error) -> (&str, &str, &[String]) {
+    match err.kind() {
+        ErrorKind::UnsupportedDataType {
+            path,
+            data_type,
+            supported_alternatives,
+        } => (path, data_type, supported_alternatives),
+        kind => panic!("unexpected error kind {kind:?}: {err}"),
+    }
+}
+
+fn berlin_timestamps() -> ArrayRef {
+    Arc::new(TimestampNanosecondArray::from(vec![0]).with_timezone("Europe/Berlin"))
+}
+
+fn berlin_timestamp_type() -> DataType {
+    DataType::Timestamp(TimeUnit::Nanosecond, Some("Europe/Berlin".into()))
+}
+
+#[test]
+fn timestamps_with_unsupported_timezones() {
+    let fields: Vec<FieldRef> = vec![Arc::new(Field::new("ts", berlin_timestamp_type(), false))];
+    let arrays = vec![berlin_timestamps()];
+
+    let deserializer = Deserializer::from_arrow(&fields, &arrays).unwrap();
+    let err = Vec::<Item<String>>::deserialize(deserializer).unwrap_err();
+    let (path, data_type, alternatives) = unsupported(&err);
+    assert_eq!(path, "$.ts");
+    assert_eq!(data_type, "Timestamp(Nanosecond, Some(\"Europe/Berlin\"))");
+    assert_eq!(
+        alternatives,
+        [
+            "Timestamp(Nanosecond, Some(\"UTC\"))",
+            "Timestamp(Nanosecond, None)",
+            "Int64",
+        ]
+    );
+    assert!(err
+        .to_string()
+        .contains("supported alternatives: Timestamp"));
+}
+
+#[test]
+fn nested_timestamps_with_unsupported_timezones() {
+    let inner = Field::new("ts", berlin_timestamp_type(), false);
+    let outer = Field::new("outer", DataType::Struct(vec![inner.clone()].into()), false);
+    let array = StructArray::from(vec![(Arc::new(inner), berlin_timestamps())]);
+    let batch = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![outer])),
+        vec![Arc::new(array) as ArrayRef],
+    )
+    .unwrap();
+
+    let err = serde_json::Value::deserialize(Deserializer::from_record_batch(&batch).unwrap())
+        .unwrap_err();
+    let (path, _, _) = unsupported(&err);
+    assert_eq!(path, "$.outer.ts");
+}
+
+#[test]
+fn unsupported_arrow_data_types_in_the_schema() {
+    let run_ends = Arc::new(Field::new("run_ends", DataType::Int32, false));
+    let values = Arc::new(Field::new("values", DataType::Utf8, true));
+    let fields = vec![Arc::new(Field::new(
+        "items",
+        DataType::List(Arc::new(Field::new(
+            "element",
+            DataType::RunEndEncoded(run_ends, values),
+            true,
+        ))),
+        false,
+    ))];
+    let err = SerdeArrowSchema::try_from(fields.as_slice()).unwrap_err();
+    let (path, data_type, alternatives) = unsupported(&err);
+    assert_eq!(path, "$.items.element");
+    assert!(data_type.starts_with("RunEndEncoded"));
+    assert!(alternatives.is_empty());
+}
+
+#[test]
+fn sparse_unions() {
+    #[derive(Serialize)]
+    enum Value {
+        Int(i32),
+    }
+
+    let union_fields = UnionFields::new([0], [Field::new("Int", DataType::Int32, false)]);
+    let fields = vec![Arc::new(Field::new(
+        "item",
+        DataType::Union(union_fields, UnionMode::Sparse),
+        false,
+    ))];
+    let arrays = crate::to_arrow(&fields, [Item(Value::Int(1))]).unwrap();
+
+    let err = Deserializer::from_arrow(&fields, &arrays).err().unwrap();
+    let (path, data_type, alternatives) = unsupported(&err);
+    assert_eq!(path, "$.item");
+    assert_eq!(data_type, "Union(Sparse)");
+    assert_eq!(alternatives, ["Union(Dense)"]);
+}
+
+#[test]
+fn other_errors_are_custom() {
+    let fields = Vec::<FieldRef>::from_value(serde_json::json!([
+        {"name": "a", "data_type": "I32"},
+    ]))
+    .unwrap();
+    let err = crate::to_arrow(&fields, [Item("not a number")]).unwrap_err();
+    assert_eq!(err.kind(), &ErrorKind::Custom);
+}
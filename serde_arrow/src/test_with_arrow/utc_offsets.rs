@@ -0,0 +1,120 @@
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, PrimitiveArray},
+        datatypes::{FieldRef, TimestampSecondType},
+    },
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+    ArrayBuilder, SerializerOptions, UtcOffsetPolicy,
+};
+
+fn fields() -> Vec<FieldRef> {
+    Vec::<FieldRef>::from_value(json!([
+        {
+            "name": "ts",
+            "data_type": "Timestamp(Second, Some(\"UTC\"))",
+            "strategy": "TimeCrateStr",
+        },
+        {"name": "date", "data_type": "Date64", "strategy": "UtcStrAsDate64"},
+        {"name": "naive", "data_type": "Timestamp(Second, None)"},
+    ]))
+    .unwrap()
+}
+
+fn builder(options: SerializerOptions) -> ArrayBuilder {
+    ArrayBuilder::from_arrow(&fields())
+        .unwrap()
+        .with_options(options)
+}
+
+const NAIVE: &str = "2024-09-30T12:30:00";
+
+#[test]
+fn offsets_are_normalized_by_default() {
+    let mut builder = builder(SerializerOptions::default());
+    builder
+        .push(("2024-09-30T14:30:00+02:00", "2024-09-30T12:30:00Z", NAIVE))
+        .unwrap();
+    assert!(builder.non_utc_offsets().is_empty());
+
+    let arrays = builder.to_arrow().unwrap();
+    let ts = arrays[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<TimestampSecondType>>()
+        .unwrap();
+    assert_eq!(ts.value(0), 1727699400);
+}
+
+#[test]
+fn non_utc_offsets_are_counted() {
+    let options = SerializerOptions::default()
+        .utc_offset_policy("$.ts", UtcOffsetPolicy::Count)
+        .utc_offset_policy("$.date", UtcOffsetPolicy::Count);
+    let mut builder = builder(options);
+    builder
+        .push(("2024-09-30T14:30:00+02:00", "2024-09-30T12:30:00Z", NAIVE))
+        .unwrap();
+    builder
+        .push((
+            "2024-09-30T12:30:00+00:00",
+            "2024-09-30T11:30:00-01:00",
+            NAIVE,
+        ))
+        .unwrap();
+    builder
+        .push((
+            "2024-09-30 14:30:00.0 +02:00:00",
+            "2024-09-30T12:30:00+00:00",
+            NAIVE,
+        ))
+        .unwrap();
+
+    let counts = builder.non_utc_offsets();
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts["$.ts"], 2);
+    assert_eq!(counts["$.date"], 1);
+
+    // the counts include the values of arrays already built
+    builder.to_arrow().unwrap();
+    builder
+        .push(("2024-09-30T14:30:00+02:00", "2024-09-30T12:30:00Z", NAIVE))
+        .unwrap();
+    assert_eq!(builder.non_utc_offsets()["$.ts"], 3);
+}
+
+#[test]
+fn non_utc_offsets_are_an_error() {
+    let options = SerializerOptions::default().utc_offset_policy("$.ts", UtcOffsetPolicy::Error);
+    let mut builder = builder(options);
+    builder
+        .push(("2024-09-30T12:30:00Z", "2024-09-30T14:30:00+02:00", NAIVE))
+        .unwrap();
+    builder
+        .push(("2024-09-30T12:30:00+00:00", "2024-09-30T12:30:00Z", NAIVE))
+        .unwrap();
+
+    let res = builder.push(("2024-09-30T14:30:00+02:00", "2024-09-30T12:30:00Z", NAIVE));
+    assert_error_contains(&res, "non-UTC offset of 7200 seconds");
+    assert_error_contains(&res, "$.ts");
+
+    let res = builder.push((
+        "2024-09-30 11:30:00.0 -01:00:00",
+        "2024-09-30T12:30:00Z",
+        NAIVE,
+    ));
+    assert_error_contains(&res, "non-UTC offset of -3600 seconds");
+}
+
+#[test]
+fn policies_require_utc_timestamp_fields() {
+    let options = SerializerOptions::default().utc_offset_policy("$.naive", UtcOffsetPolicy::Error);
+    let res = builder(options).push(("2024-09-30T12:30:00Z", "2024-09-30T12:30:00Z", NAIVE));
+    assert_error_contains(&res, "no UTC timestamp field with this path");
+
+    let options =
+        SerializerOptions::default().utc_offset_policy("$.missing", UtcOffsetPolicy::Count);
+    let res = builder(options).push(("2024-09-30T12:30:00Z", "2024-09-30T12:30:00Z", NAIVE));
+    assert_error_contains(&res, "no UTC timestamp field with this path");
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{array::RecordBatch, datatypes::FieldRef},
+    internal::testing::assert_error_contains,
+    schema::{SchemaLike, TracingOptions},
+    Deserializer, Error, VariantExtractors,
+};
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+#[derive(Debug, Deserialize)]
+struct Square {
+    side: f64,
+}
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Rectangle(f64, f64);
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.0 * self.1
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum RawShape {
+    Square { side: f64 },
+    Rectangle(f64, f64),
+    Point,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    shape: Option<RawShape>,
+}
+
+fn batch(items: &[Record]) -> RecordBatch {
+    let fields =
+        Vec::<FieldRef>::from_type::<Record>(TracingOptions::default().allow_null_fields(true))
+            .unwrap();
+    crate::to_record_batch(&fields, &items).unwrap()
+}
+
+fn extractors() -> VariantExtractors<Box<dyn Shape>> {
+    VariantExtractors::<Box<dyn Shape>>::new()
+        .register("Square", |square: Square| Ok(Box::new(square)))
+        .register("Rectangle", |rectangle: Rectangle| Ok(Box::new(rectangle)))
+}
+
+fn areas(shapes: &[Option<Box<dyn Shape>>]) -> Vec<Option<f64>> {
+    shapes
+        .iter()
+        .map(|shape| shape.as_ref().map(|shape| shape.area()))
+        .collect()
+}
+
+#[test]
+fn extract_trait_objects() {
+    let batch = batch(&[
+        Record {
+            id: 0,
+            shape: Some(RawShape::Square { side: 2.0 }),
+        },
+        Record { id: 1, shape: None },
+        Record {
+            id: 2,
+            shape: Some(RawShape::Rectangle(2.0, 3.0)),
+        },
+    ]);
+
+    let shapes = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .extract_union_field("shape", &extractors())
+        .unwrap();
+    assert_eq!(areas(&shapes), [Some(4.0), None, Some(6.0)]);
+}
+
+#[test]
+fn missing_extractor() {
+    let batch = batch(&[Record {
+        id: 0,
+        shape: Some(RawShape::Point),
+    }]);
+
+    let res = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .extract_union_field("shape", &extractors());
+    assert_error_contains(&res, "No extractor registered for variant \"Point\"");
+    assert_error_contains(&res, "field: \"$.shape\"");
+}
+
+#[test]
+fn extractor_errors_are_forwarded() {
+    let batch = batch(&[Record {
+        id: 0,
+        shape: Some(RawShape::Square { side: -1.0 }),
+    }]);
+
+    let extractors = VariantExtractors::<Box<dyn Shape>>::new().register(
+        "Square",
+        |square: Square| -> crate::Result<Box<dyn Shape>> {
+            if square.side < 0.0 {
+                return Err(Error::custom(String::from("negative side")));
+            }
+            Ok(Box::new(square))
+        },
+    );
+
+    let res = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .extract_union_field("shape", &extractors);
+    assert_error_contains(&res, "negative side");
+}
+
+#[test]
+fn invalid_fields() {
+    let batch = batch(&[Record { id: 0, shape: None }]);
+
+    let res = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .extract_union_field("id", &extractors());
+    assert_error_contains(&res, "not a union field");
+
+    let res = Deserializer::from_record_batch(&batch)
+        .unwrap()
+        .extract_union_field("unknown", &extractors());
+    assert_error_contains(&res, "unknown field \"unknown\"");
+}
+
+#[test]
+fn registered_variants() {
+    let extractors = extractors();
+    assert!(extractors.contains("Square"));
+    assert!(!extractors.contains("Point"));
+    assert_eq!(
+        format!("{extractors:?}"),
+        "VariantExtractors { variants: [\"Rectangle\", \"Square\"] }"
+    );
+}
@@ -0,0 +1,150 @@
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    _impl::arrow::datatypes::FieldRef,
+    internal::{
+        arrow::DataType,
+        schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    },
+    ArrayBuilder, ErrorKind,
+};
+
+fn schema() -> serde_json::Value {
+    json!({
+        "fields": [
+            {"name": "str", "data_type": "Utf8View"},
+            {"name": "bytes", "data_type": "BinaryView", "nullable": true},
+            {"name": "list", "data_type": "ListView", "children": [
+                {"name": "element", "data_type": "Utf8View"},
+            ]},
+            {"name": "large_list", "data_type": "LargeListView", "children": [
+                {"name": "element", "data_type": "I32", "nullable": true},
+            ]},
+        ]
+    })
+}
+
+#[test]
+fn schema_roundtrip() {
+    let schema = SerdeArrowSchema::from_value(schema()).unwrap();
+    assert_eq!(schema.fields[0].data_type, DataType::Utf8View);
+    assert_eq!(schema.fields[1].data_type, DataType::BinaryView);
+    assert!(matches!(schema.fields[2].data_type, DataType::ListView(_)));
+    assert!(matches!(
+        schema.fields[3].data_type,
+        DataType::LargeListView(_)
+    ));
+
+    let value = serde_json::to_value(&schema).unwrap();
+    assert_eq!(value, self::schema());
+}
+
+#[test]
+fn list_views_require_a_single_child() {
+    let err = SerdeArrowSchema::from_value(json!([
+        {"name": "list", "data_type": "ListView"},
+    ]))
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Invalid children for ListView: expected one child"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn strategies_are_rejected() {
+    let err = SerdeArrowSchema::from_value(json!([
+        {"name": "str", "data_type": "Utf8View", "strategy": "NaiveStrAsDate64"},
+    ]))
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("invalid strategy for Utf8View: NaiveStrAsDate64"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn overwrites_accept_view_types() {
+    #[derive(Serialize)]
+    struct Record {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    let options = TracingOptions::default()
+        .overwrite("name", json!({"name": "name", "data_type": "Utf8View"}))
+        .unwrap()
+        .overwrite(
+            "tags",
+            json!({"name": "tags", "data_type": "ListView", "children": [
+                {"name": "element", "data_type": "Utf8View"},
+            ]}),
+        )
+        .unwrap();
+    let schema = SerdeArrowSchema::from_samples(
+        &[Record {
+            name: String::from("foo"),
+            tags: vec![String::from("bar")],
+        }],
+        options,
+    )
+    .unwrap();
+
+    let value = serde_json::to_value(&schema).unwrap();
+    assert_eq!(value["fields"][0]["data_type"], "Utf8View");
+    assert_eq!(value["fields"][1]["data_type"], "ListView");
+    assert_eq!(value["fields"][1]["children"][0]["data_type"], "Utf8View");
+}
+
+#[test]
+fn builders_report_view_types_as_unsupported() {
+    let schema = SerdeArrowSchema::from_value(json!([
+        {"name": "str", "data_type": "Utf8View"},
+    ]))
+    .unwrap();
+
+    let err = ArrayBuilder::new(schema).unwrap_err();
+    let ErrorKind::UnsupportedDataType {
+        path,
+        data_type,
+        supported_alternatives,
+    } = err.kind()
+    else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(path, "$.str");
+    assert_eq!(data_type, "Utf8View");
+    assert_eq!(supported_alternatives, &["Utf8", "LargeUtf8"]);
+}
+
+#[cfg(has_arrow_view_support)]
+#[test]
+fn arrow_roundtrip() {
+    use crate::_impl::arrow::datatypes::DataType as ArrowDataType;
+
+    let fields = Vec::<FieldRef>::from_value(schema()).unwrap();
+    assert_eq!(fields[0].data_type(), &ArrowDataType::Utf8View);
+    assert_eq!(fields[1].data_type(), &ArrowDataType::BinaryView);
+    assert!(matches!(fields[2].data_type(), ArrowDataType::ListView(_)));
+    assert!(matches!(
+        fields[3].data_type(),
+        ArrowDataType::LargeListView(_)
+    ));
+
+    let schema = SerdeArrowSchema::try_from(fields.as_slice()).unwrap();
+    assert_eq!(serde_json::to_value(&schema).unwrap(), self::schema());
+}
+
+#[cfg(not(has_arrow_view_support))]
+#[test]
+fn conversion_to_arrow_fails() {
+    let err = Vec::<FieldRef>::from_value(schema()).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Utf8View is not supported by the selected arrow version"),
+        "unexpected error: {err}"
+    );
+}
@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    _impl::arrow::{
+        _raw::{
+            array::{Int32Array, LargeListArray, UInt32Array},
+            buffer::OffsetBuffer,
+            schema::UnionFields,
+        },
+        array::{
+            Array, ArrayRef, DictionaryArray, RecordBatch, RecordBatchOptions, StringArray,
+            StructArray, UnionArray,
+        },
+        buffer::ScalarBuffer,
+        datatypes::{DataType, Field, FieldRef, Schema, UInt32Type},
+    },
+    internal::testing::assert_error_contains,
+    schema::SchemaLike,
+};
+
+fn zero_column_batch(num_rows: usize) -> RecordBatch {
+    RecordBatch::try_new_with_options(
+        Arc::new(Schema::empty()),
+        Vec::new(),
+        &RecordBatchOptions::new().with_row_count(Some(num_rows)),
+    )
+    .unwrap()
+}
+
+#[test]
+fn zero_columns_into_defaulted_structs() {
+    #[derive(Debug, Default, PartialEq, Deserialize)]
+    struct Record {
+        #[serde(default)]
+        a: Option<i32>,
+        #[serde(default)]
+        b: String,
+    }
+
+    let batch = zero_column_batch(3);
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(
+        actual,
+        [Record::default(), Record::default(), Record::default()]
+    );
+}
+
+#[test]
+fn zero_columns_into_values() {
+    let batch = zero_column_batch(2);
+    let actual: Vec<Value> = crate::from_record_batch(&batch).unwrap();
+    assert_eq!(actual, [json!({}), json!({})]);
+}
+
+#[test]
+fn zero_columns_with_required_fields() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        a: i32,
+    }
+
+    let batch = zero_column_batch(1);
+    let res = crate::from_record_batch::<Vec<Record>>(&batch);
+    assert_error_contains(&res, "missing field `a`");
+}
+
+#[test]
+fn zero_columns_zero_rows() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Record {
+        a: i32,
+    }
+
+    let batch = zero_column_batch(0);
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert!(actual.is_empty());
+}
+
+#[test]
+fn zero_rows_with_nested_dictionaries_and_unions() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Value {
+        A(i32),
+        B(String),
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        tags: Vec<String>,
+        value: Value,
+        nested: Nested,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Nested {
+        tag: Option<String>,
+        values: Vec<Value>,
+    }
+
+    let fields = Vec::<FieldRef>::from_value(json!([
+        {"name": "tags", "data_type": "LargeList", "children": [
+            {"name": "element", "data_type": "Dictionary", "children": [
+                {"name": "key", "data_type": "U32"},
+                {"name": "value", "data_type": "Utf8"},
+            ]},
+        ]},
+        {"name": "value", "data_type": "Union", "children": [
+            {"name": "A", "data_type": "I32"},
+            {"name": "B", "data_type": "Dictionary", "children": [
+                {"name": "key", "data_type": "U32"},
+                {"name": "value", "data_type": "LargeUtf8"},
+            ]},
+        ]},
+        {"name": "nested", "data_type": "Struct", "children": [
+            {"name": "tag", "data_type": "Dictionary", "nullable": true, "children": [
+                {"name": "key", "data_type": "U32"},
+                {"name": "value", "data_type": "Utf8"},
+            ]},
+            {"name": "values", "data_type": "LargeList", "children": [
+                {"name": "element", "data_type": "Union", "children": [
+                    {"name": "A", "data_type": "I32"},
+                    {"name": "B", "data_type": "Utf8"},
+                ]},
+            ]},
+        ]},
+    ]))
+    .unwrap();
+
+    let batch = crate::empty_record_batch(&fields).unwrap();
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert!(actual.is_empty());
+
+    let items = [Record {
+        tags: vec![String::from("a")],
+        value: Value::B(String::from("b")),
+        nested: Nested {
+            tag: Some(String::from("c")),
+            values: vec![Value::A(1)],
+        },
+    }];
+    let batch = crate::to_record_batch(&fields, &items).unwrap();
+    let batch = batch.slice(1, 0);
+    let actual: Vec<Record> = crate::from_record_batch(&batch).unwrap();
+    assert!(actual.is_empty());
+    let actual: Vec<serde_json::Value> = crate::from_record_batch(&batch).unwrap();
+    assert!(actual.is_empty());
+}
+
+#[test]
+fn zero_rows_with_empty_dictionary_values() {
+    let keys = UInt32Array::from(Vec::<u32>::new());
+    let values: ArrayRef = Arc::new(StringArray::from(Vec::<&str>::new()));
+    let dictionary = DictionaryArray::<UInt32Type>::try_new(keys, values).unwrap();
+
+    let element = Arc::new(Field::new("element", dictionary.data_type().clone(), false));
+    let list = LargeListArray::new(
+        element.clone(),
+        OffsetBuffer::new_empty(),
+        Arc::new(dictionary.clone()),
+        None,
+    );
+
+    let union_fields = UnionFields::new(
+        vec![0, 1],
+        vec![
+            Field::new("A", DataType::Int32, false),
+            Field::new("B", dictionary.data_type().clone(), false),
+        ],
+    );
+    let union = UnionArray::try_new(
+        union_fields,
+        ScalarBuffer::from(Vec::<i8>::new()),
+        Some(ScalarBuffer::from(Vec::<i32>::new())),
+        vec![
+            Arc::new(Int32Array::from(Vec::<i32>::new())),
+            Arc::new(dictionary.clone()),
+        ],
+    )
+    .unwrap();
+
+    let nested = StructArray::from(vec![(
+        Arc::new(Field::new("tag", dictionary.data_type().clone(), false)),
+        Arc::new(dictionary) as ArrayRef,
+    )]);
+
+    let schema = Schema::new(vec![
+        Field::new("tags", list.data_type().clone(), false),
+        Field::new("value", union.data_type().clone(), false),
+        Field::new("nested", nested.data_type().clone(), false),
+    ]);
+    let batch = RecordBatch::try_new(
+        Arc::new(schema),
+        vec![Arc::new(list), Arc::new(union), Arc::new(nested)],
+    )
+    .unwrap();
+
+    let actual: Vec<Value> = crate::from_record_batch(&batch).unwrap();
+    assert!(actual.is_empty());
+}
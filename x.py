@@ -27,7 +27,7 @@ all_arrow_features = [
     "arrow-37",
 ]
 all_arrow2_features = ["arrow2-0-17", "arrow2-0-16"]
-default_features = f"{all_arrow2_features[0]},{all_arrow_features[0]}"
+default_features = f"{all_arrow2_features[0]},{all_arrow_features[0]},serde_json,ipc,http"
 
 CHECKS_PLACEHOLDER = "<<< checks >>>"
 
@@ -149,6 +149,10 @@ def _generate_workflow_check_steps():
             "run": f"cargo check --features {feature}",
         }
 
+    yield {
+        "name": "Check without default features",
+        "run": f"cargo check --no-default-features --features {default_features}",
+    }
     yield {
         "name": "Check format",
         "run": "cargo fmt --check",